@@ -29,6 +29,112 @@ pub enum LocksmithError {
     InvalidMint,
     /// Lock duration exceeds maximum of 10 years
     LockDurationExceeded,
+    /// Lock has been frozen by the compliance authority
+    LockFrozen,
+    /// Unlock requires a valid credential from the lock's credential issuer
+    InvalidCredential,
+    /// Standing order's next execution is not due yet
+    StandingOrderNotDue,
+    /// Forwarding destination is not approved or does not match the lock
+    InvalidForwardingDestination,
+    /// Hook is not registered, does not match the lock, or got the wrong accounts
+    InvalidHook,
+    /// Hook callback used more compute units than its allowance
+    HookComputeExceeded,
+    /// Lock has not gone unclaimed long enough to be swept or claimed by its
+    /// fallback owner
+    LockNotAbandoned,
+    /// Donation share or destination is invalid
+    InvalidDonation,
+    /// Lock backs a loan and cannot be unlocked or changed until released
+    LockCollateralized,
+    /// The instruction's `valid_until` deadline has passed
+    TransactionExpired,
+    /// No fee mint migration to this mint is pending, or its timelock has not elapsed
+    FeeMintNotReady,
+    /// Claim code is wrong or expired, or its lock options are not allowed
+    InvalidClaimCode,
+    /// Lock can still be claimed with its claim code, so its owner cannot unlock it yet
+    ClaimCodeActive,
+    /// No ed25519 signature by the owner over the expected message precedes the instruction
+    InvalidSignature,
+    /// Session key is unknown or expired, or the unlock is outside its scope
+    InvalidSession,
+    /// Lock is already recorded in its mint's index
+    LockAlreadyIndexed,
+    /// Index entry still refers to an open lock
+    IndexEntryActive,
+    /// No successor program is declared, or the migration does not target its lock PDA
+    InvalidSuccessorProgram,
+    /// The program has been sunset and no longer creates locks
+    ProgramSunset,
+    /// Certificate still describes an open lock
+    CertificateActive,
+    /// No vested tokens are left to claim yet
+    NothingVested,
+    /// Vesting lock is not revocable
+    VestingNotRevocable,
+    /// Lock does not allow emergency unlocks
+    EarlyUnlockNotAllowed,
+    /// Lock is permanent and can never be unlocked
+    LockIsPermanent,
+    /// Receipt still represents an open lock
+    ReceiptActive,
+    /// Lock's unlock right follows its receipt, or the lock cannot be bound to one
+    LockBoundToReceipt,
+    /// Fallback owner or delay is invalid
+    InvalidFallback,
+    /// Payout is not to the lock's committed unlock destination, or the
+    /// lock's options pay out elsewhere
+    InvalidUnlockDestination,
+    /// Beneficiaries or shares are invalid, or the lock cannot be split
+    InvalidShares,
+    /// Lock is split among beneficiaries, who claim their shares instead
+    LockShared,
+    /// Beneficiary has already claimed their share
+    ShareClaimed,
+    /// Approvers or threshold are invalid, or the lock cannot require
+    /// approvals
+    InvalidApprovers,
+    /// Lock unlocks only with `ExecuteUnlock`, once enough of its approvers
+    /// have approved
+    ApprovalsRequired,
+    /// Price condition is invalid, or cannot be combined with the lock's
+    /// other options
+    InvalidPriceCondition,
+    /// Price update is missing, not a fully verified Pyth update, or for
+    /// another feed
+    InvalidPriceFeed,
+    /// Price update is too old or its confidence interval too wide
+    PriceUnreliable,
+    /// Price is below the lock's threshold
+    PriceConditionNotMet,
+    /// Arbiter is invalid, or cannot be combined with the lock's other
+    /// options
+    InvalidArbiter,
+    /// Lock's arbiter has not approved its milestone
+    MilestoneNotApproved,
+    /// Counterparty is invalid, or cannot be combined with the lock's other
+    /// options
+    InvalidCounterparty,
+    /// Weight snapshot still describes an open lock
+    WeightSnapshotActive,
+    /// Lock creation is paused
+    ProgramPaused,
+    /// Mint cannot be locked under the mint policy
+    MintNotAllowed,
+    /// Mint still has a freeze authority, which could freeze the escrow
+    MintHasFreezeAuthority,
+    /// Lock proof still describes an open lock
+    LockProofActive,
+    /// Lock still holds tokens, so it cannot be closed
+    LockNotEmpty,
+    /// Owner already holds as many unclosed locks as their cap allows
+    LockCapReached,
+    /// Lock's fee was priced on the amount or duration being changed
+    FeeDependsOnTerms,
+    /// Gift lock options would give someone besides the owner a claim
+    InvalidGiftOptions,
 }
 
 impl From<LocksmithError> for ProgramError {
@@ -56,6 +162,55 @@ mod tests {
         assert_eq!(LocksmithError::InvalidPDA as u32, 9);
         assert_eq!(LocksmithError::InvalidMint as u32, 10);
         assert_eq!(LocksmithError::LockDurationExceeded as u32, 11);
+        assert_eq!(LocksmithError::LockFrozen as u32, 12);
+        assert_eq!(LocksmithError::InvalidCredential as u32, 13);
+        assert_eq!(LocksmithError::StandingOrderNotDue as u32, 14);
+        assert_eq!(LocksmithError::InvalidForwardingDestination as u32, 15);
+        assert_eq!(LocksmithError::InvalidHook as u32, 16);
+        assert_eq!(LocksmithError::HookComputeExceeded as u32, 17);
+        assert_eq!(LocksmithError::LockNotAbandoned as u32, 18);
+        assert_eq!(LocksmithError::InvalidDonation as u32, 19);
+        assert_eq!(LocksmithError::LockCollateralized as u32, 20);
+        assert_eq!(LocksmithError::TransactionExpired as u32, 21);
+        assert_eq!(LocksmithError::FeeMintNotReady as u32, 22);
+        assert_eq!(LocksmithError::InvalidClaimCode as u32, 23);
+        assert_eq!(LocksmithError::ClaimCodeActive as u32, 24);
+        assert_eq!(LocksmithError::InvalidSignature as u32, 25);
+        assert_eq!(LocksmithError::InvalidSession as u32, 26);
+        assert_eq!(LocksmithError::LockAlreadyIndexed as u32, 27);
+        assert_eq!(LocksmithError::IndexEntryActive as u32, 28);
+        assert_eq!(LocksmithError::InvalidSuccessorProgram as u32, 29);
+        assert_eq!(LocksmithError::ProgramSunset as u32, 30);
+        assert_eq!(LocksmithError::CertificateActive as u32, 31);
+        assert_eq!(LocksmithError::NothingVested as u32, 32);
+        assert_eq!(LocksmithError::VestingNotRevocable as u32, 33);
+        assert_eq!(LocksmithError::EarlyUnlockNotAllowed as u32, 34);
+        assert_eq!(LocksmithError::LockIsPermanent as u32, 35);
+        assert_eq!(LocksmithError::ReceiptActive as u32, 36);
+        assert_eq!(LocksmithError::LockBoundToReceipt as u32, 37);
+        assert_eq!(LocksmithError::InvalidFallback as u32, 38);
+        assert_eq!(LocksmithError::InvalidUnlockDestination as u32, 39);
+        assert_eq!(LocksmithError::InvalidShares as u32, 40);
+        assert_eq!(LocksmithError::LockShared as u32, 41);
+        assert_eq!(LocksmithError::ShareClaimed as u32, 42);
+        assert_eq!(LocksmithError::InvalidApprovers as u32, 43);
+        assert_eq!(LocksmithError::ApprovalsRequired as u32, 44);
+        assert_eq!(LocksmithError::InvalidPriceCondition as u32, 45);
+        assert_eq!(LocksmithError::InvalidPriceFeed as u32, 46);
+        assert_eq!(LocksmithError::PriceUnreliable as u32, 47);
+        assert_eq!(LocksmithError::PriceConditionNotMet as u32, 48);
+        assert_eq!(LocksmithError::InvalidArbiter as u32, 49);
+        assert_eq!(LocksmithError::MilestoneNotApproved as u32, 50);
+        assert_eq!(LocksmithError::InvalidCounterparty as u32, 51);
+        assert_eq!(LocksmithError::WeightSnapshotActive as u32, 52);
+        assert_eq!(LocksmithError::ProgramPaused as u32, 53);
+        assert_eq!(LocksmithError::MintNotAllowed as u32, 54);
+        assert_eq!(LocksmithError::MintHasFreezeAuthority as u32, 55);
+        assert_eq!(LocksmithError::LockProofActive as u32, 56);
+        assert_eq!(LocksmithError::LockNotEmpty as u32, 57);
+        assert_eq!(LocksmithError::LockCapReached as u32, 58);
+        assert_eq!(LocksmithError::FeeDependsOnTerms as u32, 59);
+        assert_eq!(LocksmithError::InvalidGiftOptions as u32, 60);
     }
 
     /// Tests the From<LocksmithError> for ProgramError conversion
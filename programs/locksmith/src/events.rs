@@ -0,0 +1,269 @@
+use shank::ShankType;
+use solana_program::{log::sol_log_data, program::set_return_data, pubkey::Pubkey};
+
+/// Outcome of a lock audit
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ShankType)]
+#[repr(u8)]
+pub enum AuditStatus {
+    /// All invariants hold
+    Consistent = 0,
+    /// Lock account is not at the PDA derived from its own fields
+    LockPdaMismatch,
+    /// Escrow token account does not exist or is not an SPL token account
+    EscrowMissing,
+    /// Escrow is not owned by the lock PDA or holds a different mint
+    EscrowMismatch,
+    /// Escrow balance differs from the recorded lock amount
+    BalanceMismatch,
+    /// Recorded timestamps are outside the bounds enforced at creation
+    InvalidTimestamps,
+}
+
+/// Emitted by `AuditLock` via `sol_log_data`, visible in transaction logs
+/// as a base64 `Program data:` line.
+#[derive(Debug, PartialEq, ShankType)]
+pub struct LockAuditEvent {
+    /// Event discriminator
+    pub discriminator: [u8; 8],
+    /// Audited lock account
+    pub lock: Pubkey,
+    /// Lock owner as recorded
+    pub owner: Pubkey,
+    /// Lock mint as recorded
+    pub mint: Pubkey,
+    /// Amount recorded in the lock account
+    pub recorded_amount: u64,
+    /// Amount held by the escrow (0 if missing)
+    pub escrow_amount: u64,
+    /// Audit outcome
+    pub status: AuditStatus,
+    /// Slot the audit ran in
+    pub slot: u64,
+    /// Unix timestamp the audit ran at
+    pub timestamp: i64,
+}
+
+impl LockAuditEvent {
+    pub const DISCRIMINATOR: [u8; 8] = *b"AUDIT\0\0\0";
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1 + 8 + 8;
+
+    pub fn pack(&self) -> [u8; Self::SIZE] {
+        let mut dst = [0u8; Self::SIZE];
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.lock.as_ref());
+        dst[40..72].copy_from_slice(self.owner.as_ref());
+        dst[72..104].copy_from_slice(self.mint.as_ref());
+        dst[104..112].copy_from_slice(&self.recorded_amount.to_le_bytes());
+        dst[112..120].copy_from_slice(&self.escrow_amount.to_le_bytes());
+        dst[120] = self.status as u8;
+        dst[121..129].copy_from_slice(&self.slot.to_le_bytes());
+        dst[129..137].copy_from_slice(&self.timestamp.to_le_bytes());
+        dst
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.pack()]);
+    }
+}
+
+/// Action recorded by a `ComplianceEvent`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ShankType)]
+#[repr(u8)]
+pub enum ComplianceAction {
+    /// Admin appointed a compliance authority, effective after the timelock
+    AuthorityAppointed = 0,
+    /// Admin removed the compliance authority, effective immediately
+    AuthorityRemoved,
+    /// Lock frozen; it cannot be unlocked until unfrozen
+    LockFrozen,
+    /// Lock unfrozen
+    LockUnfrozen,
+}
+
+/// Emitted by `SetComplianceAuthority`, `FreezeLock` and `UnfreezeLock`,
+/// so every use of the compliance role leaves a record in the logs.
+#[derive(Debug, PartialEq, ShankType)]
+pub struct ComplianceEvent {
+    /// Event discriminator
+    pub discriminator: [u8; 8],
+    /// What happened
+    pub action: ComplianceAction,
+    /// Signer that performed the action (admin or compliance authority)
+    pub actor: Pubkey,
+    /// Affected lock, or the appointed authority for authority changes
+    pub target: Pubkey,
+    /// Unix timestamp from which the action takes effect
+    pub effective_at: i64,
+    /// Slot the action ran in
+    pub slot: u64,
+    /// Unix timestamp the action ran at
+    pub timestamp: i64,
+}
+
+impl ComplianceEvent {
+    pub const DISCRIMINATOR: [u8; 8] = *b"COMPLY\0\0";
+    pub const SIZE: usize = 8 + 1 + 32 + 32 + 8 + 8 + 8;
+
+    pub fn pack(&self) -> [u8; Self::SIZE] {
+        let mut dst = [0u8; Self::SIZE];
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8] = self.action as u8;
+        dst[9..41].copy_from_slice(self.actor.as_ref());
+        dst[41..73].copy_from_slice(self.target.as_ref());
+        dst[73..81].copy_from_slice(&self.effective_at.to_le_bytes());
+        dst[81..89].copy_from_slice(&self.slot.to_le_bytes());
+        dst[89..97].copy_from_slice(&self.timestamp.to_le_bytes());
+        dst
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.pack()]);
+    }
+}
+
+/// Returned by `VerifyCollateral` via `set_return_data`, so a lending
+/// program can check a lock through CPI in the same transaction (and
+/// clients via simulation).
+#[derive(Debug, PartialEq, ShankType)]
+pub struct CollateralAttestation {
+    /// Attestation discriminator
+    pub discriminator: [u8; 8],
+    /// Verified lock account
+    pub lock: Pubkey,
+    /// Lock owner as recorded
+    pub owner: Pubkey,
+    /// Lock mint as recorded
+    pub mint: Pubkey,
+    /// Amount held in escrow
+    pub amount: u64,
+    /// Unix timestamp when the lock can be unlocked
+    pub unlock_timestamp: i64,
+    /// Lender authority the lock backs a loan for (default pubkey = none)
+    pub collateral_holder: Pubkey,
+    /// Whether the admin or compliance authority has frozen the lock
+    pub frozen: bool,
+    /// Outcome of the same invariant checks as `AuditLock`
+    pub status: AuditStatus,
+}
+
+impl CollateralAttestation {
+    pub const DISCRIMINATOR: [u8; 8] = *b"COLLATRL";
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 32 + 1 + 1;
+
+    pub fn pack(&self) -> [u8; Self::SIZE] {
+        let mut dst = [0u8; Self::SIZE];
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.lock.as_ref());
+        dst[40..72].copy_from_slice(self.owner.as_ref());
+        dst[72..104].copy_from_slice(self.mint.as_ref());
+        dst[104..112].copy_from_slice(&self.amount.to_le_bytes());
+        dst[112..120].copy_from_slice(&self.unlock_timestamp.to_le_bytes());
+        dst[120..152].copy_from_slice(self.collateral_holder.as_ref());
+        dst[152] = self.frozen as u8;
+        dst[153] = self.status as u8;
+        dst
+    }
+
+    pub fn set_return_data(&self) {
+        set_return_data(&self.pack());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_audit_event_size() {
+        assert_eq!(LockAuditEvent::SIZE, 137);
+    }
+
+    #[test]
+    fn test_audit_status_codes_are_stable() {
+        assert_eq!(AuditStatus::Consistent as u8, 0);
+        assert_eq!(AuditStatus::LockPdaMismatch as u8, 1);
+        assert_eq!(AuditStatus::EscrowMissing as u8, 2);
+        assert_eq!(AuditStatus::EscrowMismatch as u8, 3);
+        assert_eq!(AuditStatus::BalanceMismatch as u8, 4);
+        assert_eq!(AuditStatus::InvalidTimestamps as u8, 5);
+    }
+
+    #[test]
+    fn test_lock_audit_event_byte_layout() {
+        let event = LockAuditEvent {
+            discriminator: LockAuditEvent::DISCRIMINATOR,
+            lock: Pubkey::from([1u8; 32]),
+            owner: Pubkey::from([2u8; 32]),
+            mint: Pubkey::from([3u8; 32]),
+            recorded_amount: 0x0102030405060708,
+            escrow_amount: 0x1112131415161718,
+            status: AuditStatus::BalanceMismatch,
+            slot: 0x2122232425262728,
+            timestamp: -1,
+        };
+
+        let data = event.pack();
+
+        assert_eq!(&data[0..8], b"AUDIT\0\0\0");
+        assert_eq!(&data[8..40], &[1u8; 32]);
+        assert_eq!(&data[40..72], &[2u8; 32]);
+        assert_eq!(&data[72..104], &[3u8; 32]);
+        assert_eq!(u64::from_le_bytes(data[104..112].try_into().unwrap()), 0x0102030405060708);
+        assert_eq!(u64::from_le_bytes(data[112..120].try_into().unwrap()), 0x1112131415161718);
+        assert_eq!(data[120], 4);
+        assert_eq!(u64::from_le_bytes(data[121..129].try_into().unwrap()), 0x2122232425262728);
+        assert_eq!(i64::from_le_bytes(data[129..137].try_into().unwrap()), -1);
+    }
+
+    #[test]
+    fn test_collateral_attestation_byte_layout() {
+        let attestation = CollateralAttestation {
+            discriminator: CollateralAttestation::DISCRIMINATOR,
+            lock: Pubkey::from([1u8; 32]),
+            owner: Pubkey::from([2u8; 32]),
+            mint: Pubkey::from([3u8; 32]),
+            amount: 0x0102030405060708,
+            unlock_timestamp: -1,
+            collateral_holder: Pubkey::from([4u8; 32]),
+            frozen: true,
+            status: AuditStatus::Consistent,
+        };
+
+        let data = attestation.pack();
+
+        assert_eq!(CollateralAttestation::SIZE, 154);
+        assert_eq!(&data[0..8], b"COLLATRL");
+        assert_eq!(&data[8..40], &[1u8; 32]);
+        assert_eq!(&data[40..72], &[2u8; 32]);
+        assert_eq!(&data[72..104], &[3u8; 32]);
+        assert_eq!(u64::from_le_bytes(data[104..112].try_into().unwrap()), 0x0102030405060708);
+        assert_eq!(i64::from_le_bytes(data[112..120].try_into().unwrap()), -1);
+        assert_eq!(&data[120..152], &[4u8; 32]);
+        assert_eq!(data[152], 1);
+        assert_eq!(data[153], 0);
+    }
+
+    #[test]
+    fn test_compliance_event_byte_layout() {
+        let event = ComplianceEvent {
+            discriminator: ComplianceEvent::DISCRIMINATOR,
+            action: ComplianceAction::LockUnfrozen,
+            actor: Pubkey::from([1u8; 32]),
+            target: Pubkey::from([2u8; 32]),
+            effective_at: 0x0102030405060708,
+            slot: 0x1112131415161718,
+            timestamp: -1,
+        };
+
+        let data = event.pack();
+
+        assert_eq!(ComplianceEvent::SIZE, 97);
+        assert_eq!(&data[0..8], b"COMPLY\0\0");
+        assert_eq!(data[8], 3);
+        assert_eq!(&data[9..41], &[1u8; 32]);
+        assert_eq!(&data[41..73], &[2u8; 32]);
+        assert_eq!(i64::from_le_bytes(data[73..81].try_into().unwrap()), 0x0102030405060708);
+        assert_eq!(u64::from_le_bytes(data[81..89].try_into().unwrap()), 0x1112131415161718);
+        assert_eq!(i64::from_le_bytes(data[89..97].try_into().unwrap()), -1);
+    }
+}
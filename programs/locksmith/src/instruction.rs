@@ -1,7 +1,271 @@
-use shank::ShankInstruction;
-use solana_program::program_error::ProgramError;
+use shank::{ShankInstruction, ShankType};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
 use crate::error::LocksmithError;
+use crate::state::{
+    CLAIM_LOCK_DOMAIN, MAX_NOTE_LEN, METADATA_URI_LEN, MINT_POLICY_DENYLIST, RELAYED_LOCK_DOMAIN,
+};
+
+/// Optional settings for `InitializeLockWithOptions`.
+///
+/// Fields may be appended over time; any field missing from the
+/// instruction data takes its default, so older clients stay valid.
+#[derive(Debug, Clone, Copy, PartialEq, ShankType)]
+pub struct LockOptions {
+    /// Program whose credential PDA ["credential", owner] the owner must
+    /// present to unlock (default pubkey = not gated)
+    pub credential_issuer: Pubkey,
+    /// Permanently bar the lock from changing owner, e.g. for grant
+    /// allocations that must not be sold
+    pub non_transferable: bool,
+    /// Registered `HookAccount` to notify of the lock's creation and unlock
+    /// (default pubkey = no hook)
+    pub hook: Pubkey,
+    /// Wallet that may receive the tokens via `SweepAbandonedLock` once they
+    /// have gone unclaimed for 5 years after unlock (default pubkey = never)
+    pub recovery_address: Pubkey,
+    /// Wallet receiving `donation_bps` of the tokens at unlock (default
+    /// pubkey = no donation)
+    pub donation_address: Pubkey,
+    /// Share of the tokens donated at unlock, in basis points
+    pub donation_bps: u16,
+    /// Unix timestamp after which the instruction is rejected, so a
+    /// transaction signed long ago cannot land with stale intent (0 = no
+    /// deadline). Not stored on the lock.
+    pub valid_until: i64,
+    /// Unlock at this slot rather than at `unlock_timestamp`, which must
+    /// still be given as an estimate (0 = timestamp-based)
+    pub unlock_slot: u64,
+    /// Unlock at the start of this epoch rather than at
+    /// `unlock_timestamp`, which must still be given as an estimate
+    /// (0 = timestamp-based; exclusive with `unlock_slot`)
+    pub unlock_epoch: u64,
+    /// Public key of the ed25519 key pair whose seed is a secret claim
+    /// code; whoever holds the code may claim the unlocked tokens with
+    /// `ClaimLock` (zeroes = no claim code)
+    pub claim_key: [u8; 32],
+    /// Unix timestamp the claim code expires at, after which only the
+    /// owner can unlock; must be after `unlock_timestamp`
+    pub claim_expires_at: i64,
+    /// Let the owner release the lock early with `EmergencyUnlock`, paying
+    /// the configured penalty. Leave unset for locks meant as a commitment.
+    pub early_unlock_allowed: bool,
+    /// Never release the tokens, e.g. for burned LP positions. The 10-year
+    /// limit on `unlock_timestamp` does not apply, and the lock cannot have
+    /// an unlock slot or epoch, claim code, recovery or donation address or
+    /// emergency unlocks.
+    pub permanent: bool,
+    /// Name for the lock, UTF-8 padded with zeroes (see `UpdateLockMetadata`)
+    pub label: [u8; 32],
+    /// URI of a JSON document describing the lock's terms, e.g. the
+    /// beneficiary's name and a hash of the legal agreement, UTF-8 padded
+    /// with zeroes (zeroes = none). Cannot be changed after creation.
+    pub metadata_uri: [u8; 128],
+    /// Wallet that may claim the tokens with `ClaimAsFallback` if the owner
+    /// has not unlocked `fallback_after` seconds after `unlock_timestamp`
+    /// (default pubkey = none)
+    pub fallback_owner: Pubkey,
+    /// Seconds past `unlock_timestamp` the owner has before the fallback
+    /// owner may claim
+    pub fallback_after: i64,
+    /// Token account every payout must go to, e.g. a compliance-approved
+    /// treasury (default pubkey = any account of the owner). It must be the
+    /// owner's account for the lock's mint, or the lock can never unlock,
+    /// and cannot be changed. Locks paying elsewhere (a recovery, fallback
+    /// or donation address, a claim code or emergency unlocks) cannot
+    /// commit to one.
+    pub unlock_destination: Pubkey,
+    /// Pyth feed ID whose price must also be at least `price_threshold` ×
+    /// 10^`price_exponent` to unlock, e.g. to release team tokens only
+    /// above a target price (zeroes = no price condition). Cannot be
+    /// changed, and locks released other than by unlocking (claim codes,
+    /// recovery addresses, emergency unlocks) cannot have one.
+    pub price_feed_id: [u8; 32],
+    /// Minimum price, scaled by 10^`price_exponent`
+    pub price_threshold: i64,
+    /// Decimal exponent of `price_threshold`, within ±`MAX_PRICE_EXPONENT`
+    pub price_exponent: i32,
+    /// Reviewer who must approve the lock's milestone with
+    /// `ApproveMilestone` before it can unlock, e.g. for one tranche of a
+    /// grant (default pubkey = none). Cannot be the owner or be changed, and
+    /// locks released other than by unlocking (claim codes, recovery
+    /// addresses, emergency unlocks) cannot have one.
+    pub arbiter: Pubkey,
+    /// Counterparty of an OTC escrow: with the owner's signature too,
+    /// `ReleaseEscrow` pays them the tokens at any time, and after
+    /// `unlock_timestamp` the owner alone may unlock them back (default
+    /// pubkey = not an escrow). Cannot be the owner or be changed, and
+    /// escrows can only have the credential, hook and fallback options.
+    pub counterparty: Pubkey,
+    /// Pay the configured flat fee as the config's `fee_lamports` from the
+    /// paying wallet into the SOL fee vault (see `SetSolFee`), leaving the
+    /// fee account unread. Not stored on the lock.
+    pub fee_in_lamports: bool,
+    /// Lock lamports from the paying wallet, passed in place of its token
+    /// account, wrapped into the escrow; native mint only. Stored on the
+    /// lock, whose unlock pays them back out as lamports.
+    pub wrap_lamports: bool,
+}
+
+impl Default for LockOptions {
+    fn default() -> Self {
+        Self {
+            credential_issuer: Pubkey::default(),
+            non_transferable: false,
+            hook: Pubkey::default(),
+            recovery_address: Pubkey::default(),
+            donation_address: Pubkey::default(),
+            donation_bps: 0,
+            valid_until: 0,
+            unlock_slot: 0,
+            unlock_epoch: 0,
+            claim_key: [0u8; 32],
+            claim_expires_at: 0,
+            early_unlock_allowed: false,
+            permanent: false,
+            label: [0u8; 32],
+            metadata_uri: [0u8; METADATA_URI_LEN],
+            fallback_owner: Pubkey::default(),
+            fallback_after: 0,
+            unlock_destination: Pubkey::default(),
+            price_feed_id: [0u8; 32],
+            price_threshold: 0,
+            price_exponent: 0,
+            arbiter: Pubkey::default(),
+            counterparty: Pubkey::default(),
+            fee_in_lamports: false,
+            wrap_lamports: false,
+        }
+    }
+}
+
+impl LockOptions {
+    /// Offsets at which each field ends. Older clients send the fields up to
+    /// one of these, leaving the rest at their defaults, but never part of a
+    /// field, which would otherwise be silently dropped.
+    const FIELD_ENDS: [usize; 25] = [
+        32, 33, 65, 97, 129, 131, 139, 147, 155, 187, 195, 196, 197, 229, 357, 389, 397, 429,
+        461, 469, 473, 505, 537, 538, 539,
+    ];
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if !data.is_empty() && !Self::FIELD_ENDS.contains(&data.len()) {
+            return Err(LocksmithError::InvalidInstruction.into());
+        }
+        let mut options = Self::default();
+        if let Some(issuer) = data.get(0..32) {
+            options.credential_issuer = Pubkey::try_from(issuer).unwrap();
+        }
+        if let Some(&non_transferable) = data.get(32) {
+            options.non_transferable = non_transferable != 0;
+        }
+        if let Some(hook) = data.get(33..65) {
+            options.hook = Pubkey::try_from(hook).unwrap();
+        }
+        if let Some(recovery) = data.get(65..97) {
+            options.recovery_address = Pubkey::try_from(recovery).unwrap();
+        }
+        if let Some(donation) = data.get(97..129) {
+            options.donation_address = Pubkey::try_from(donation).unwrap();
+        }
+        if let Some(bps) = data.get(129..131) {
+            options.donation_bps = u16::from_le_bytes(bps.try_into().unwrap());
+        }
+        if let Some(valid_until) = data.get(131..139) {
+            options.valid_until = i64::from_le_bytes(valid_until.try_into().unwrap());
+        }
+        if let Some(slot) = data.get(139..147) {
+            options.unlock_slot = u64::from_le_bytes(slot.try_into().unwrap());
+        }
+        if let Some(epoch) = data.get(147..155) {
+            options.unlock_epoch = u64::from_le_bytes(epoch.try_into().unwrap());
+        }
+        if let Some(key) = data.get(155..187) {
+            options.claim_key = key.try_into().unwrap();
+        }
+        if let Some(expires_at) = data.get(187..195) {
+            options.claim_expires_at = i64::from_le_bytes(expires_at.try_into().unwrap());
+        }
+        if let Some(&early_unlock_allowed) = data.get(195) {
+            options.early_unlock_allowed = early_unlock_allowed != 0;
+        }
+        if let Some(&permanent) = data.get(196) {
+            options.permanent = permanent != 0;
+        }
+        if let Some(label) = data.get(197..229) {
+            options.label = label.try_into().unwrap();
+        }
+        if let Some(uri) = data.get(229..357) {
+            options.metadata_uri = uri.try_into().unwrap();
+        }
+        if let Some(fallback_owner) = data.get(357..389) {
+            options.fallback_owner = Pubkey::try_from(fallback_owner).unwrap();
+        }
+        if let Some(fallback_after) = data.get(389..397) {
+            options.fallback_after = i64::from_le_bytes(fallback_after.try_into().unwrap());
+        }
+        if let Some(destination) = data.get(397..429) {
+            options.unlock_destination = Pubkey::try_from(destination).unwrap();
+        }
+        if let Some(feed_id) = data.get(429..461) {
+            options.price_feed_id = feed_id.try_into().unwrap();
+        }
+        if let Some(threshold) = data.get(461..469) {
+            options.price_threshold = i64::from_le_bytes(threshold.try_into().unwrap());
+        }
+        if let Some(exponent) = data.get(469..473) {
+            options.price_exponent = i32::from_le_bytes(exponent.try_into().unwrap());
+        }
+        if let Some(arbiter) = data.get(473..505) {
+            options.arbiter = Pubkey::try_from(arbiter).unwrap();
+        }
+        if let Some(counterparty) = data.get(505..537) {
+            options.counterparty = Pubkey::try_from(counterparty).unwrap();
+        }
+        if let Some(&fee_in_lamports) = data.get(537) {
+            options.fee_in_lamports = fee_in_lamports != 0;
+        }
+        if let Some(&wrap_lamports) = data.get(538) {
+            options.wrap_lamports = wrap_lamports != 0;
+        }
+        Ok(options)
+    }
+}
+
+/// Schedule of a standing order (see `CreateStandingOrder`).
+#[derive(Debug, Clone, Copy, PartialEq, ShankType)]
+pub struct StandingOrderParams {
+    /// Tokens locked by each execution
+    pub amount_per_lock: u64,
+    /// Seconds between executions
+    pub interval_seconds: i64,
+    /// Seconds from execution until each lock unlocks
+    pub lock_duration_seconds: i64,
+    /// Unix timestamp of the first execution (a past timestamp means now)
+    pub start_at: i64,
+    /// Number of locks to create
+    pub executions: u32,
+    /// Lock ID of the first lock; each execution uses the next ID
+    pub first_lock_id: u64,
+}
+
+impl StandingOrderParams {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 4 + 8;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::LEN {
+            return Err(LocksmithError::InvalidInstruction.into());
+        }
+        Ok(Self {
+            amount_per_lock: u64::from_le_bytes(data[0..8].try_into().unwrap()),
+            interval_seconds: i64::from_le_bytes(data[8..16].try_into().unwrap()),
+            lock_duration_seconds: i64::from_le_bytes(data[16..24].try_into().unwrap()),
+            start_at: i64::from_le_bytes(data[24..32].try_into().unwrap()),
+            executions: u32::from_le_bytes(data[32..36].try_into().unwrap()),
+            first_lock_id: u64::from_le_bytes(data[36..44].try_into().unwrap()),
+        })
+    }
+}
 
 #[derive(Debug, PartialEq, ShankInstruction)]
 pub enum LocksmithInstruction {
@@ -15,143 +279,3057 @@ pub enum LocksmithInstruction {
     #[account(5, name = "system_program", desc = "System program")]
     InitializeConfig,
 
-    /// Transfer admin role to a new wallet.
-    #[account(0, signer, name = "admin", desc = "Current admin")]
-    #[account(1, name = "new_admin", desc = "New admin pubkey")]
-    #[account(2, writable, name = "config", desc = "Config account")]
-    TransferAdmin,
+    /// Transfer admin role to a new wallet.
+    #[account(0, signer, name = "admin", desc = "Current admin")]
+    #[account(1, name = "new_admin", desc = "New admin pubkey")]
+    #[account(2, writable, name = "config", desc = "Config account")]
+    TransferAdmin,
+
+    /// Withdraw accumulated USDC fees to any token account of the fee mint,
+    /// e.g. the admin's own or a treasury's.
+    #[account(0, signer, name = "admin", desc = "Admin withdrawing fees")]
+    #[account(1, name = "config", desc = "Config account for admin verification")]
+    #[account(2, writable, name = "fee_vault", desc = "Fee vault holding USDC fees")]
+    #[account(3, writable, name = "admin_token_account", desc = "Fee mint token account receiving the fees, not necessarily the admin's")]
+    #[account(4, name = "token_program", desc = "SPL Token program")]
+    WithdrawFees {
+        /// Fees to withdraw (0 = the vault's whole balance); may be omitted
+        /// by older clients
+        amount: u64,
+    },
+
+    /// Create a new token lock.
+    /// Locks SPL tokens until a specified Unix timestamp.
+    /// Charges a 0.15 USDC fee by default, or the configured SOL fee with
+    /// `LockOptions::fee_in_lamports`. A lock charged no USDC, e.g. one
+    /// paying in SOL or under a percentage fee, may pass any account as
+    /// `owner_usdc_account`, such as its `owner_token_account`.
+    /// Locking lamports from the owner's wallet, wrapped into the escrow,
+    /// takes `InitializeLockWithOptions` with `LockOptions::wrap_lamports`.
+    /// Every lock creation path needs the config PDA among its trailing
+    /// accounts, and while the config caps locks per owner the owner's
+    /// counter PDA too, created on first use, except for gift locks.
+    /// Since 0.2 a transaction without the config PDA fails with
+    /// `NotEnoughAccountKeys`.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner who pays for creation")]
+    #[account(1, writable, name = "owner_token_account", desc = "Owner's token account for the locked mint")]
+    #[account(2, writable, name = "owner_usdc_account", desc = "Owner's USDC account for fee payment")]
+    #[account(3, name = "mint", desc = "Token mint being locked")]
+    #[account(4, writable, name = "lock_account", desc = "Lock PDA to be created")]
+    #[account(5, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(6, writable, name = "fee_vault", desc = "Fee vault to receive USDC fee")]
+    #[account(7, name = "token_program", desc = "SPL Token program")]
+    #[account(8, name = "system_program", desc = "System program")]
+    InitializeLock {
+        /// Tokens to lock; `LOCK_FULL_BALANCE` (u64::MAX) locks the owner
+        /// token account's whole balance at execution, less the fee if it
+        /// is also the fee account
+        amount: u64,
+        unlock_timestamp: i64,
+        lock_id: u64,
+        /// Rejected after this unix timestamp (0 = no deadline); may be
+        /// omitted by older clients
+        valid_until: i64,
+    },
+
+    /// Unlock tokens after the unlock timestamp has passed.
+    /// Returns tokens to the owner and closes the lock account.
+    /// Credential-gated locks also require the owner's credential PDA.
+    /// Locks with a forwarding destination also require the destination and
+    /// its program, locks with a hook the hook, its program and state, and
+    /// locks with a donation the donation address's token account. Any
+    /// further accounts are passed to the forwarding deposit instruction,
+    /// except that a price-conditional lock takes a recent Pyth price update
+    /// for its feed as the last account. Closing a lock counted towards its
+    /// owner's lock cap needs the owner's counter PDA among the accounts.
+    /// A lock created with `LockOptions::wrap_lamports` pays out lamports
+    /// instead of wSOL, with the recipient's wallet as `owner_token_account`.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner receiving tokens")]
+    #[account(1, writable, name = "owner_token_account", desc = "Destination for unlocked tokens")]
+    #[account(2, writable, name = "lock_account", desc = "Lock account to be closed")]
+    #[account(3, writable, name = "lock_token_account", desc = "Lock's token account to be closed")]
+    #[account(4, name = "token_program", desc = "SPL Token program")]
+    #[account(5, optional, name = "credential", desc = "Owner's credential PDA, required if the lock is credential-gated")]
+    #[account(6, optional, name = "forwarding_destination", desc = "Lock's forwarding destination, required if set")]
+    #[account(7, optional, name = "destination_program", desc = "Program of the forwarding destination")]
+    #[account(8, optional, name = "hook", desc = "Lock's hook, required if set")]
+    #[account(9, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(10, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    #[account(11, optional, writable, name = "donation_token_account", desc = "Donation address's token account, required if the lock donates")]
+    Unlock { lock_id: u64 },
+
+    /// Permissionlessly re-check a lock's invariants.
+    /// Emits a `LockAuditEvent` with the outcome; inconsistencies are reported
+    /// in the event rather than failing the transaction.
+    #[account(0, name = "lock_account", desc = "Lock account to audit")]
+    #[account(1, name = "lock_token_account", desc = "Lock's token escrow account")]
+    AuditLock,
+
+    /// Appoint or remove the compliance authority.
+    /// A new authority can act only after a 48 hour timelock; passing the
+    /// default pubkey removes the authority immediately.
+    #[account(0, signer, writable, name = "admin", desc = "Admin, pays to grow legacy configs")]
+    #[account(1, writable, name = "config", desc = "Config account")]
+    #[account(2, name = "new_compliance_authority", desc = "New compliance authority, or the default pubkey to remove it")]
+    #[account(3, name = "system_program", desc = "System program")]
+    SetComplianceAuthority,
+
+    /// Freeze a lock so it cannot be unlocked until unfrozen, e.g. to pause
+    /// one project hit by an exploit or a court order. The admin may freeze
+    /// as well as the compliance authority, without its timelock.
+    #[account(0, signer, writable, name = "authority", desc = "Admin or compliance authority, pays to grow legacy locks")]
+    #[account(1, name = "config", desc = "Config account")]
+    #[account(2, writable, name = "lock_account", desc = "Lock to freeze")]
+    #[account(3, name = "system_program", desc = "System program")]
+    FreezeLock,
+
+    /// Unfreeze a lock.
+    /// The admin may also unfreeze, so locks are not stranded if the
+    /// compliance authority is removed.
+    #[account(0, signer, name = "authority", desc = "Admin or compliance authority")]
+    #[account(1, name = "config", desc = "Config account")]
+    #[account(2, writable, name = "lock_account", desc = "Lock to unfreeze")]
+    UnfreezeLock,
+
+    /// Create a new token lock with optional settings (see `LockOptions`).
+    /// Same accounts and fee as `InitializeLock`, plus the hook accounts if
+    /// `options.hook` is set.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner who pays for creation")]
+    #[account(1, writable, name = "owner_token_account", desc = "Owner's token account for the locked mint")]
+    #[account(2, writable, name = "owner_usdc_account", desc = "Owner's USDC account for fee payment")]
+    #[account(3, name = "mint", desc = "Token mint being locked")]
+    #[account(4, writable, name = "lock_account", desc = "Lock PDA to be created")]
+    #[account(5, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(6, writable, name = "fee_vault", desc = "Fee vault to receive USDC fee")]
+    #[account(7, name = "token_program", desc = "SPL Token program")]
+    #[account(8, name = "system_program", desc = "System program")]
+    #[account(9, optional, name = "hook", desc = "Hook named in the options")]
+    #[account(10, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(11, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    InitializeLockWithOptions {
+        amount: u64,
+        unlock_timestamp: i64,
+        lock_id: u64,
+        options: LockOptions,
+    },
+
+    /// Create or update a lock template (admin only).
+    /// Locks created from it unlock `duration_seconds` after creation, pay
+    /// `fee_usdc` instead of the standard fee and carry `options`, which
+    /// must not name a hook, recovery address or donation.
+    #[account(0, signer, writable, name = "admin", desc = "Admin, pays for a new template")]
+    #[account(1, name = "config", desc = "Config account for admin verification")]
+    #[account(2, writable, name = "template", desc = "Template PDA to create or update")]
+    #[account(3, name = "system_program", desc = "System program")]
+    SetLockTemplate {
+        template_id: u64,
+        duration_seconds: i64,
+        fee_usdc: u64,
+        options: LockOptions,
+    },
+
+    /// Create a new token lock using an admin-defined template's duration,
+    /// fee and options. Same accounts as `InitializeLock` plus the template.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner who pays for creation")]
+    #[account(1, writable, name = "owner_token_account", desc = "Owner's token account for the locked mint")]
+    #[account(2, writable, name = "owner_usdc_account", desc = "Owner's USDC account for fee payment")]
+    #[account(3, name = "mint", desc = "Token mint being locked")]
+    #[account(4, writable, name = "lock_account", desc = "Lock PDA to be created")]
+    #[account(5, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(6, writable, name = "fee_vault", desc = "Fee vault to receive USDC fee")]
+    #[account(7, name = "token_program", desc = "SPL Token program")]
+    #[account(8, name = "system_program", desc = "System program")]
+    #[account(9, name = "template", desc = "Template PDA supplying the lock parameters")]
+    InitializeLockFromTemplate {
+        amount: u64,
+        lock_id: u64,
+        template_id: u64,
+        /// Rejected after this unix timestamp (0 = no deadline); may be
+        /// omitted by older clients
+        valid_until: i64,
+    },
+
+    /// Create a standing order that locks `amount_per_lock` tokens every
+    /// `interval_seconds`, `executions` times. Approves the order PDA as
+    /// delegate for the total tokens and fees, and pre-funds the rent of
+    /// every lock it will create. Each execution's fee is approved at the
+    /// configured fee, or the order's tier if higher, which caps what the
+    /// execution charges; the config PDA (and the fee schedule PDA while
+    /// tiers are on) go among the trailing accounts.
+    #[account(0, signer, writable, name = "owner", desc = "Owner of the future locks, pays rent up front")]
+    #[account(1, name = "owner_token_account", desc = "Owner's token account to draw locked tokens from")]
+    #[account(2, name = "owner_usdc_account", desc = "Owner's USDC account to draw fees from")]
+    #[account(3, name = "mint", desc = "Token mint to lock")]
+    #[account(4, writable, name = "standing_order", desc = "Standing order PDA to be created")]
+    #[account(5, name = "token_program", desc = "SPL Token program")]
+    #[account(6, name = "system_program", desc = "System program")]
+    CreateStandingOrder {
+        order_id: u64,
+        params: StandingOrderParams,
+    },
+
+    /// Permissionlessly run a due standing order, creating one lock.
+    /// The cranker pays the lock's rent and is reimbursed from the order.
+    /// The final execution closes the order and returns its lamports to the owner.
+    /// Charged the fee of any other lock, so its trailing accounts are those
+    /// of `InitializeLock`, the config PDA included.
+    #[account(0, signer, writable, name = "cranker", desc = "Anyone; pays and is reimbursed the lock rent")]
+    #[account(1, writable, name = "owner", desc = "Order owner, receives the order's lamports when it completes")]
+    #[account(2, writable, name = "standing_order", desc = "Standing order to execute")]
+    #[account(3, writable, name = "owner_token_account", desc = "Owner's token account recorded in the order")]
+    #[account(4, writable, name = "owner_usdc_account", desc = "Owner's USDC account recorded in the order")]
+    #[account(5, name = "mint", desc = "Token mint being locked")]
+    #[account(6, writable, name = "lock_account", desc = "Lock PDA to be created")]
+    #[account(7, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(8, writable, name = "fee_vault", desc = "Fee vault to receive USDC fee")]
+    #[account(9, name = "token_program", desc = "SPL Token program")]
+    #[account(10, name = "system_program", desc = "System program")]
+    ExecuteStandingOrder,
+
+    /// Cancel a standing order: revoke its delegations and close it,
+    /// refunding the unused rent to the owner.
+    #[account(0, signer, writable, name = "owner", desc = "Order owner")]
+    #[account(1, writable, name = "standing_order", desc = "Standing order to close")]
+    #[account(2, writable, name = "owner_token_account", desc = "Owner's token account recorded in the order")]
+    #[account(3, writable, name = "owner_usdc_account", desc = "Owner's USDC account recorded in the order")]
+    #[account(4, name = "token_program", desc = "SPL Token program")]
+    CancelStandingOrder,
+
+    /// Approve (or revoke) a program instruction as a forwarding destination
+    /// for unlocked tokens (admin only).
+    #[account(0, signer, writable, name = "admin", desc = "Admin, pays for and receives the destination's rent")]
+    #[account(1, name = "config", desc = "Config account for admin verification")]
+    #[account(2, name = "destination_program", desc = "Program to invoke with unlocked tokens")]
+    #[account(3, writable, name = "forwarding_destination", desc = "Forwarding destination PDA")]
+    #[account(4, name = "system_program", desc = "System program")]
+    SetForwardingDestination {
+        instruction_prefix: [u8; 8],
+        prefix_len: u8,
+        approved: bool,
+    },
+
+    /// Set or clear the forwarding destination of a lock (owner only).
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner, pays to grow legacy locks")]
+    #[account(1, writable, name = "lock_account", desc = "Lock to update")]
+    #[account(2, name = "forwarding_destination", desc = "Approved forwarding destination, or the default pubkey to clear")]
+    #[account(3, name = "system_program", desc = "System program")]
+    SetLockForwarding,
+
+    /// Register (or deregister) a program to be called back when locks that
+    /// opted in are created or unlocked (admin only). The callback gets
+    /// `instruction_prefix[..prefix_len]`, the event, lock ID and amount,
+    /// and may use at most `compute_allowance` compute units.
+    #[account(0, signer, writable, name = "admin", desc = "Admin, pays for and receives the hook's rent")]
+    #[account(1, name = "config", desc = "Config account for admin verification")]
+    #[account(2, name = "hook_program", desc = "Program to call back")]
+    #[account(3, writable, name = "hook", desc = "Hook PDA")]
+    #[account(4, name = "system_program", desc = "System program")]
+    SetHook {
+        instruction_prefix: [u8; 8],
+        prefix_len: u8,
+        events: u8,
+        compute_allowance: u32,
+        approved: bool,
+    },
+
+    /// Permissionlessly sweep a lock that went unclaimed for
+    /// `ABANDONMENT_PERIOD_SECONDS` after unlock to its recovery address.
+    /// Closes the lock and escrow, returning their rent to the recovery
+    /// address. Credential-gated locks require the recovery address's
+    /// credential, and locks with a hook the hook accounts, as in `Unlock`.
+    #[account(0, writable, name = "lock_account", desc = "Abandoned lock to close")]
+    #[account(1, writable, name = "lock_token_account", desc = "Lock's token escrow account to close")]
+    #[account(2, writable, name = "recovery_token_account", desc = "Recovery address's token account for the lock's mint")]
+    #[account(3, writable, name = "recovery_address", desc = "Lock's recovery address, receives the rent")]
+    #[account(4, name = "owner", desc = "Lock owner")]
+    #[account(5, name = "token_program", desc = "SPL Token program")]
+    #[account(6, optional, name = "credential", desc = "Recovery address's credential PDA, required if the lock is credential-gated")]
+    #[account(7, optional, name = "hook", desc = "Lock's hook, required if set")]
+    #[account(8, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(9, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    SweepAbandonedLock,
+
+    /// Approve (or revoke) a lender authority, typically a PDA of a lending
+    /// program, that may mark locks as collateral (admin only).
+    #[account(0, signer, writable, name = "admin", desc = "Admin, pays for and receives the lender's rent")]
+    #[account(1, name = "config", desc = "Config account for admin verification")]
+    #[account(2, name = "lender_authority", desc = "Lender authority to approve or revoke")]
+    #[account(3, writable, name = "lender", desc = "Lender PDA")]
+    #[account(4, name = "system_program", desc = "System program")]
+    SetLender { approved: bool },
+
+    /// Mark a lock as backing a loan, or release it. Marking needs both the
+    /// owner and an approved lender authority; only that authority can
+    /// release. A collateralized lock cannot be unlocked, swept or given a
+    /// new forwarding destination.
+    #[account(0, signer, name = "lender_authority", desc = "Approved lender authority")]
+    #[account(1, name = "lender", desc = "Lender PDA of the authority")]
+    #[account(2, writable, name = "lock_account", desc = "Lock to mark or release")]
+    #[account(3, writable, name = "owner", desc = "Lock owner; must sign and pays to grow legacy locks when marking")]
+    #[account(4, name = "system_program", desc = "System program")]
+    SetCollateralized { collateralized: bool },
+
+    /// Check a lock's invariants and collateral status for a lender.
+    /// Returns a `CollateralAttestation` as return data.
+    #[account(0, name = "lock_account", desc = "Lock to verify")]
+    #[account(1, name = "lock_token_account", desc = "Lock's token escrow account")]
+    VerifyCollateral,
+
+    /// Create a lock that unlocks `duration_seconds` after the on-chain
+    /// clock at execution, so clients need not compute a timestamp.
+    /// Same accounts and fee as `InitializeLock`.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner who pays for creation")]
+    #[account(1, writable, name = "owner_token_account", desc = "Owner's token account for the locked mint")]
+    #[account(2, writable, name = "owner_usdc_account", desc = "Owner's USDC account for fee payment")]
+    #[account(3, name = "mint", desc = "Token mint being locked")]
+    #[account(4, writable, name = "lock_account", desc = "Lock PDA to be created")]
+    #[account(5, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(6, writable, name = "fee_vault", desc = "Fee vault to receive USDC fee")]
+    #[account(7, name = "token_program", desc = "SPL Token program")]
+    #[account(8, name = "system_program", desc = "System program")]
+    InitializeLockForDuration {
+        amount: u64,
+        duration_seconds: i64,
+        lock_id: u64,
+        /// Rejected after this unix timestamp (0 = no deadline)
+        valid_until: i64,
+    },
+
+    /// Propose a new fee mint (admin only). The migration can be carried
+    /// out with `MigrateFeeMint` once `FEE_MINT_TIMELOCK_SECONDS` have
+    /// passed. Proposing the current fee mint cancels a pending proposal.
+    #[account(0, signer, writable, name = "admin", desc = "Admin, pays to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config account")]
+    #[account(2, name = "new_fee_mint", desc = "Proposed fee mint")]
+    #[account(3, name = "system_program", desc = "System program")]
+    ProposeFeeMint,
+
+    /// Switch to the proposed fee mint once its timelock has elapsed (admin
+    /// only). Creates the new mint's fee vault, pays the old vault's balance
+    /// out to the admin and closes it, after which fees in the old mint are
+    /// refused.
+    #[account(0, signer, writable, name = "admin", desc = "Admin, pays for the new vault and receives the old vault's rent")]
+    #[account(1, writable, name = "config", desc = "Config account")]
+    #[account(2, writable, name = "old_fee_vault", desc = "Fee vault of the current fee mint, closed")]
+    #[account(3, writable, name = "admin_token_account", desc = "Admin's token account for the current fee mint")]
+    #[account(4, name = "new_fee_mint", desc = "Proposed fee mint")]
+    #[account(5, writable, name = "new_fee_vault", desc = "Fee vault PDA of the new fee mint to be created")]
+    #[account(6, name = "token_program", desc = "SPL Token program")]
+    #[account(7, name = "system_program", desc = "System program")]
+    MigrateFeeMint,
+
+    /// `InitializeLockWithOptions` with the lock and escrow rent paid by a
+    /// separate payer, e.g. a wallet provider sponsoring its users. The
+    /// owner still signs for the tokens and fee and owns the lock; the
+    /// payer gets no control over it.
+    #[account(0, signer, writable, name = "payer", desc = "Pays the lock and escrow rent")]
+    #[account(1, signer, name = "owner", desc = "Lock owner, authority of the token and fee accounts")]
+    #[account(2, writable, name = "owner_token_account", desc = "Owner's token account for the locked mint")]
+    #[account(3, writable, name = "owner_usdc_account", desc = "Owner's USDC account for fee payment")]
+    #[account(4, name = "mint", desc = "Token mint being locked")]
+    #[account(5, writable, name = "lock_account", desc = "Lock PDA to be created")]
+    #[account(6, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(7, writable, name = "fee_vault", desc = "Fee vault to receive USDC fee")]
+    #[account(8, name = "token_program", desc = "SPL Token program")]
+    #[account(9, name = "system_program", desc = "System program")]
+    #[account(10, optional, name = "hook", desc = "Hook named in the options")]
+    #[account(11, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(12, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    InitializeLockWithPayer {
+        amount: u64,
+        unlock_timestamp: i64,
+        lock_id: u64,
+        options: LockOptions,
+    },
+
+    /// `InitializeLockWithOptions` for a lock owned by someone else: the
+    /// funder pays the tokens, fee and rent, and the owner never signs. The
+    /// funder gets no control over the lock once created, so the options
+    /// cannot name a fallback owner, recovery or donation address, claim
+    /// code or unlock destination.
+    #[account(0, signer, writable, name = "funder", desc = "Pays the tokens, fee and rent")]
+    #[account(1, name = "owner", desc = "Owner of the new lock")]
+    #[account(2, writable, name = "funder_token_account", desc = "Funder's token account for the locked mint")]
+    #[account(3, writable, name = "funder_usdc_account", desc = "Funder's USDC account for fee payment")]
+    #[account(4, name = "mint", desc = "Token mint being locked")]
+    #[account(5, writable, name = "lock_account", desc = "Lock PDA to be created")]
+    #[account(6, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(7, writable, name = "fee_vault", desc = "Fee vault to receive USDC fee")]
+    #[account(8, name = "token_program", desc = "SPL Token program")]
+    #[account(9, name = "system_program", desc = "System program")]
+    #[account(10, optional, name = "hook", desc = "Hook named in the options")]
+    #[account(11, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(12, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    InitializeGiftLock {
+        amount: u64,
+        unlock_timestamp: i64,
+        lock_id: u64,
+        options: LockOptions,
+    },
+
+    /// Claim an unlocked claim-code lock before its code expires. The
+    /// instruction must directly follow an ed25519 program instruction in
+    /// which the lock's claim key signs `claim_lock_message` for the
+    /// claimant's token account, so the code itself never goes on chain
+    /// and a signature cannot be redirected to another account. The tokens
+    /// go to the claimant and the lock and escrow rent back to the owner;
+    /// the lock is closed, so a code can only be redeemed once.
+    #[account(0, signer, name = "claimant", desc = "Holder of the claim code")]
+    #[account(1, writable, name = "claimant_token_account", desc = "Claimant's token account for the locked mint, signed over by the claim key")]
+    #[account(2, writable, name = "lock_account", desc = "Lock account to be closed")]
+    #[account(3, writable, name = "lock_token_account", desc = "Lock's token account to be closed")]
+    #[account(4, writable, name = "owner", desc = "Lock owner receiving the rent")]
+    #[account(5, name = "token_program", desc = "SPL Token program")]
+    #[account(6, name = "instructions_sysvar", desc = "Instructions sysvar, to find the claim key's signature")]
+    ClaimLock,
+
+    /// `InitializeLock` submitted by a relayer for an owner who signed
+    /// `relayed_lock_message` off-chain instead of the transaction, so the
+    /// owner needs no SOL. The instruction must directly follow an ed25519
+    /// program instruction verifying that signature. Tokens and fee move via
+    /// the relay delegate PDA, which the owner must have approved on both
+    /// token accounts. `valid_until` is required and must not be after
+    /// `unlock_timestamp`, so the message cannot be replayed once the lock
+    /// has been unlocked.
+    #[account(0, signer, writable, name = "relayer", desc = "Submits the transaction and pays the lock and escrow rent")]
+    #[account(1, name = "owner", desc = "Lock owner who signed the relayed lock message")]
+    #[account(2, writable, name = "owner_token_account", desc = "Owner's token account for the locked mint")]
+    #[account(3, writable, name = "owner_usdc_account", desc = "Owner's USDC account for fee payment")]
+    #[account(4, name = "mint", desc = "Token mint being locked")]
+    #[account(5, writable, name = "lock_account", desc = "Lock PDA to be created")]
+    #[account(6, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(7, writable, name = "fee_vault", desc = "Fee vault to receive USDC fee")]
+    #[account(8, name = "token_program", desc = "SPL Token program")]
+    #[account(9, name = "system_program", desc = "System program")]
+    #[account(10, name = "relay_delegate", desc = "Owner's relay delegate PDA, approved on both token accounts")]
+    #[account(11, name = "instructions_sysvar", desc = "Instructions sysvar, to find the ed25519 signature check")]
+    InitializeRelayedLock {
+        amount: u64,
+        unlock_timestamp: i64,
+        lock_id: u64,
+        valid_until: i64,
+    },
+
+    /// Authorize a session key to unlock the owner's locks of `mint`
+    /// (default pubkey = any mint) into `destination` until `expires_at`,
+    /// or update its scope. An `expires_at` of 0 revokes the session key.
+    #[account(0, signer, writable, name = "owner", desc = "Owner authorizing the session key, pays for the session")]
+    #[account(1, writable, name = "session", desc = "Session PDA of the owner and session key")]
+    #[account(2, name = "session_key", desc = "Key allowed to unlock on the owner's behalf")]
+    #[account(3, name = "destination", desc = "Owner's token account unlocked tokens must go to")]
+    #[account(4, name = "system_program", desc = "System program")]
+    SetSession { mint: Pubkey, expires_at: i64 },
+
+    /// `Unlock` signed by an unexpired session key instead of the owner.
+    /// The lock must be within the session's scope, the tokens must go to
+    /// its destination, and locks with unlock forwarding are refused since
+    /// the forwarding deposit needs the owner's signature. The remaining
+    /// accounts are those of `Unlock`.
+    #[account(0, signer, name = "session_key", desc = "Session key signing for the owner")]
+    #[account(1, name = "session", desc = "Session PDA of the owner and session key")]
+    #[account(2, writable, name = "owner", desc = "Lock owner receiving the rent")]
+    #[account(3, writable, name = "owner_token_account", desc = "Session's destination for unlocked tokens")]
+    #[account(4, writable, name = "lock_account", desc = "Lock account to be closed")]
+    #[account(5, writable, name = "lock_token_account", desc = "Lock's token account to be closed")]
+    #[account(6, name = "token_program", desc = "SPL Token program")]
+    #[account(7, optional, name = "credential", desc = "Owner's credential PDA, required if the lock is credential-gated")]
+    #[account(8, optional, name = "forwarding_destination", desc = "Unused placeholder keeping `Unlock`'s account positions")]
+    #[account(9, optional, name = "destination_program", desc = "Unused placeholder keeping `Unlock`'s account positions")]
+    #[account(10, optional, name = "hook", desc = "Lock's hook, required if set")]
+    #[account(11, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(12, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    #[account(13, optional, writable, name = "donation_token_account", desc = "Donation address's token account, required if the lock donates")]
+    UnlockWithSession { lock_id: u64 },
+
+    /// Attach `note[..note_len]`, an opaque blob the client encrypted, to a
+    /// lock, replacing any earlier note (owner only). A `note_len` of 0
+    /// deletes the note; once the lock is closed, its last owner may still
+    /// delete it to recover the rent.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner, pays for and receives the note's rent")]
+    #[account(1, name = "lock_account", desc = "Lock the note is attached to")]
+    #[account(2, writable, name = "note_account", desc = "Note PDA of the lock")]
+    #[account(3, name = "system_program", desc = "System program")]
+    SetLockNote {
+        note: [u8; 256],
+        note_len: u16,
+    },
+
+    /// Permissionlessly record a lock in its mint's index, creating the
+    /// index on the mint's first lock. The lock gets the next sequence
+    /// number and an `IndexEntryAccount` at the PDA of that number.
+    #[account(0, signer, writable, name = "payer", desc = "Pays for the entry, the index if new, and growing legacy locks")]
+    #[account(1, writable, name = "lock_account", desc = "Lock to index")]
+    #[account(2, writable, name = "mint_index", desc = "Mint index PDA of the lock's mint")]
+    #[account(3, writable, name = "index_entry", desc = "Index entry PDA of the index's next sequence number")]
+    #[account(4, name = "system_program", desc = "System program")]
+    IndexLock,
+
+    /// Permissionlessly remove the index entry of a closed lock, refunding
+    /// the entry's rent to whoever paid for it.
+    #[account(0, name = "lock_account", desc = "Closed lock recorded in the entry")]
+    #[account(1, writable, name = "mint_index", desc = "Mint index PDA of the lock's mint")]
+    #[account(2, writable, name = "index_entry", desc = "Index entry to close")]
+    #[account(3, writable, name = "payer", desc = "Payer recorded in the entry, receives its rent")]
+    UnindexLock,
+
+    /// Move the lamports a lock account holds above its rent-exempt
+    /// minimum, e.g. from a mistaken transfer, to the owner (owner only).
+    /// The escrow's surplus cannot be withdrawn, as SPL Token only releases
+    /// an account's lamports by closing it; it returns at unlock.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner, receives the surplus")]
+    #[account(1, writable, name = "lock_account", desc = "Lock holding surplus lamports")]
+    WithdrawSurplusRent,
+
+    /// Declare the program locks may be migrated to with `MigrateLock`
+    /// (admin only). The default pubkey withdraws the declaration.
+    #[account(0, signer, writable, name = "admin", desc = "Admin, pays to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config account")]
+    #[account(2, name = "system_program", desc = "System program")]
+    SetSuccessorProgram { successor_program: Pubkey },
+
+    /// Move a lock's escrowed tokens to the config's successor program,
+    /// with both the owner and the admin signing. The tokens go to a token
+    /// account owned by the successor's PDA at the lock's seeds, and the
+    /// lock and escrow are closed with their rent refunded to the owner.
+    /// Frozen, collateralized and claimable locks cannot be migrated.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner consenting to the migration, receives the rent")]
+    #[account(1, signer, name = "admin", desc = "Admin consenting to the migration")]
+    #[account(2, name = "config", desc = "Config account declaring the successor program")]
+    #[account(3, writable, name = "lock_account", desc = "Lock being migrated")]
+    #[account(4, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(5, name = "successor", desc = "Successor program declared in the config")]
+    #[account(6, writable, name = "successor_token_account", desc = "Token account owned by the successor's lock PDA")]
+    #[account(7, name = "token_program", desc = "SPL Token program")]
+    MigrateLock,
+
+    /// Permanently stop creating locks (admin only). Unlocks, fee
+    /// withdrawal and reads keep working; nothing can undo a sunset.
+    #[account(0, signer, writable, name = "admin", desc = "Admin, pays to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config account")]
+    #[account(2, name = "system_program", desc = "System program")]
+    Sunset,
+
+    /// Permissionlessly publish a consistent lock's terms to its
+    /// certificate PDA, creating it or refreshing an existing one.
+    #[account(0, signer, writable, name = "payer", desc = "Pays for a new certificate")]
+    #[account(1, name = "lock_account", desc = "Lock to certify")]
+    #[account(2, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(3, writable, name = "certificate", desc = "Certificate PDA of the lock")]
+    #[account(4, name = "system_program", desc = "System program")]
+    PublishLockCertificate,
+
+    /// Permissionlessly close the certificate of a closed lock, refunding
+    /// its rent to whoever paid it.
+    #[account(0, name = "lock_account", desc = "Certified lock, already closed")]
+    #[account(1, writable, name = "certificate", desc = "Certificate PDA of the lock")]
+    #[account(2, writable, name = "payer", desc = "Payer recorded in the certificate, receives the rent")]
+    CloseLockCertificate,
+
+    /// Push a timestamp-based lock's unlock date further out (owner only).
+    /// The new date must be later than the current one and within
+    /// `MAX_LOCK_DURATION_SECONDS` of the lock's creation. Locks charged a
+    /// fee schedule tier cannot be extended.
+    #[account(0, signer, name = "owner", desc = "Lock owner")]
+    #[account(1, writable, name = "lock_account", desc = "Lock to extend")]
+    ExtendLock {
+        lock_id: u64,
+        new_unlock_timestamp: i64,
+    },
+
+    /// Add tokens of the lock's mint to its escrow (owner only). An
+    /// `additional_amount` of `LOCK_FULL_BALANCE` adds the owner token
+    /// account's whole balance. Locks charged a flat fee pay no more; locks
+    /// charged a percentage fee pay it again on the added tokens, out of
+    /// them, and then need the config PDA, the mint's fee cap PDA and token
+    /// fee vault, the mint and the System program after these accounts.
+    /// Locks charged a fee schedule tier cannot be topped up.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner, pays to create the token fee vault")]
+    #[account(1, writable, name = "owner_token_account", desc = "Owner's token account for the locked mint")]
+    #[account(2, writable, name = "lock_account", desc = "Lock to top up")]
+    #[account(3, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(4, name = "token_program", desc = "SPL Token program")]
+    IncreaseLockAmount {
+        lock_id: u64,
+        additional_amount: u64,
+    },
+
+    /// Create a vesting lock releasing `total_amount` linearly from
+    /// `start_timestamp` to `end_timestamp`, claimable from
+    /// `cliff_timestamp` on. The funder pays the tokens, fee and rent; the
+    /// owner claims with `ClaimVested`. A `total_amount` of
+    /// `LOCK_FULL_BALANCE` vests the funder token account's whole balance.
+    /// The funder is recorded as the grantor, who can claw back unvested
+    /// tokens with `RevokeVesting` if `revocable`. Charged the fee of a
+    /// lock of the same tokens until `end_timestamp`, so its trailing
+    /// accounts are those of `InitializeLock`, the config PDA included.
+    #[account(0, signer, writable, name = "funder", desc = "Pays the tokens, fee and rent")]
+    #[account(1, name = "owner", desc = "Beneficiary of the vesting lock")]
+    #[account(2, writable, name = "funder_token_account", desc = "Funder's token account for the vesting mint")]
+    #[account(3, writable, name = "funder_usdc_account", desc = "Funder's USDC account for fee payment")]
+    #[account(4, name = "mint", desc = "Token mint vesting")]
+    #[account(5, writable, name = "vesting_account", desc = "Vesting lock PDA to be created")]
+    #[account(6, writable, name = "vesting_token_account", desc = "Vesting lock's token escrow account")]
+    #[account(7, writable, name = "fee_vault", desc = "Fee vault to receive USDC fee")]
+    #[account(8, name = "token_program", desc = "SPL Token program")]
+    #[account(9, name = "system_program", desc = "System program")]
+    InitializeVesting {
+        total_amount: u64,
+        start_timestamp: i64,
+        end_timestamp: i64,
+        vesting_id: u64,
+        /// Nothing is claimable before this unix timestamp (0 = the start);
+        /// may be omitted by older clients
+        cliff_timestamp: i64,
+        /// Whether the grantor may revoke; may be omitted by older clients
+        revocable: bool,
+        /// Pay the fee in lamports, as with `LockOptions::fee_in_lamports`;
+        /// may be omitted by older clients
+        fee_in_lamports: bool,
+    },
+
+    /// Claim everything vested and not yet claimed (owner only). The final
+    /// claim closes the vesting lock and escrow, refunding their rent.
+    #[account(0, signer, writable, name = "owner", desc = "Beneficiary, receives the rent on the final claim")]
+    #[account(1, writable, name = "owner_token_account", desc = "Owner's token account for the vesting mint")]
+    #[account(2, writable, name = "vesting_account", desc = "Vesting lock")]
+    #[account(3, writable, name = "vesting_token_account", desc = "Vesting lock's token escrow account")]
+    #[account(4, name = "token_program", desc = "SPL Token program")]
+    ClaimVested { vesting_id: u64 },
+
+    /// Grantor revokes a revocable vesting lock, taking back the unvested
+    /// remainder. The vested portion stays claimable by the owner; if none
+    /// is left the vesting lock and escrow are closed to the grantor.
+    #[account(0, signer, writable, name = "grantor", desc = "Grantor of the vesting lock, receives any rent")]
+    #[account(1, writable, name = "grantor_token_account", desc = "Grantor's token account for the vesting mint")]
+    #[account(2, writable, name = "vesting_account", desc = "Vesting lock")]
+    #[account(3, writable, name = "vesting_token_account", desc = "Vesting lock's token escrow account")]
+    #[account(4, name = "token_program", desc = "SPL Token program")]
+    RevokeVesting { vesting_id: u64 },
+
+    /// Set the share of a lock's tokens `EmergencyUnlock` forfeits, at most
+    /// `MAX_EARLY_UNLOCK_PENALTY_BPS` (admin only). Applies to existing
+    /// locks too.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config PDA")]
+    #[account(2, name = "system_program", desc = "System program")]
+    SetEarlyUnlockPenalty { penalty_bps: u16 },
+
+    /// Release a lock created with `early_unlock_allowed` before it unlocks
+    /// (owner only). The configured penalty goes to the mint's penalty
+    /// vault, created on first use, and the rest to the owner. Donations,
+    /// forwarding and hooks do not apply.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner, receives the rent")]
+    #[account(1, writable, name = "owner_token_account", desc = "Owner's token account to receive the tokens")]
+    #[account(2, writable, name = "lock_account", desc = "Lock PDA to close")]
+    #[account(3, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(4, name = "config", desc = "Config PDA")]
+    #[account(5, writable, name = "penalty_vault", desc = "Penalty vault PDA of the lock's mint")]
+    #[account(6, name = "mint", desc = "Mint of the locked tokens")]
+    #[account(7, name = "token_program", desc = "SPL Token program")]
+    #[account(8, name = "system_program", desc = "System program")]
+    EmergencyUnlock { lock_id: u64 },
+
+    /// Withdraw a penalty vault's early unlock penalties to a token account
+    /// (admin only)
+    #[account(0, signer, name = "admin", desc = "Admin authority")]
+    #[account(1, name = "config", desc = "Config PDA")]
+    #[account(2, writable, name = "penalty_vault", desc = "Penalty vault PDA to withdraw from")]
+    #[account(3, writable, name = "admin_token_account", desc = "Admin's token account for the vault's mint")]
+    #[account(4, name = "token_program", desc = "SPL Token program")]
+    WithdrawPenalties,
+
+    /// Start a new term for a matured lock (owner only): the lock unlocks at
+    /// `new_unlock_timestamp`, at most `MAX_LOCK_DURATION_SECONDS` from now,
+    /// without its tokens leaving escrow or another fee. Slot- and
+    /// epoch-based locks become timestamp-based. Collateralized locks and
+    /// locks charged a fee schedule tier cannot be relocked.
+    #[account(0, signer, name = "owner", desc = "Lock owner")]
+    #[account(1, writable, name = "lock_account", desc = "Lock to relock")]
+    Relock {
+        lock_id: u64,
+        new_unlock_timestamp: i64,
+    },
+
+    /// Permissionlessly unlock a matured lock to its owner's associated
+    /// token account. `CRANK_TIP_LAMPORTS` of the lock account's rent go to
+    /// the cranker and the rest of the rent to the owner. Credential-gated
+    /// locks require the owner's credential, and locks with a hook, donation
+    /// or price condition the same accounts as in `Unlock`, the price update
+    /// last. Locks created with `LockOptions::wrap_lamports` pay out
+    /// lamports, with the owner's wallet as `owner_token_account`. Locks
+    /// with a forwarding destination need the owner's signature and cannot
+    /// be cranked.
+    #[account(0, signer, writable, name = "cranker", desc = "Anyone, receives the tip")]
+    #[account(1, writable, name = "owner", desc = "Lock owner, receives the rest of the rent")]
+    #[account(2, writable, name = "owner_token_account", desc = "Owner's associated token account for the lock's mint, or the owner for a lamport lock")]
+    #[account(3, writable, name = "lock_account", desc = "Matured lock to close")]
+    #[account(4, writable, name = "lock_token_account", desc = "Lock's token escrow account to close")]
+    #[account(5, name = "token_program", desc = "SPL Token program")]
+    #[account(6, optional, name = "credential", desc = "Owner's credential PDA, required if the lock is credential-gated")]
+    #[account(7, optional, name = "hook", desc = "Lock's hook, required if set")]
+    #[account(8, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(9, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    #[account(10, optional, writable, name = "donation_token_account", desc = "Donation address's token account, required if the lock pledges a donation")]
+    CrankUnlock { lock_id: u64 },
+
+    /// Create gift locks of the same mint, `amount`, unlock timestamp and
+    /// `lock_id` for several owners, funded by one funder. Accounts 8 to 10
+    /// are the first lock's owner, lock PDA and escrow; each further lock
+    /// appends the same three. The fee is charged once per batch, or per
+    /// lock if the config's `batch_fee_per_lock` is set or the mint pays a
+    /// percentage fee. The accounts the locks need by key follow the last
+    /// lock: the mint's policy PDA under a mint policy mode, and under a
+    /// percentage fee the mint's fee cap PDA, plus its token fee vault for a
+    /// capped mint.
+    #[account(0, signer, writable, name = "funder", desc = "Pays the tokens, fees and rent")]
+    #[account(1, writable, name = "funder_token_account", desc = "Funder's token account for the locked mint")]
+    #[account(2, writable, name = "funder_usdc_account", desc = "Funder's USDC account for fee payment")]
+    #[account(3, name = "mint", desc = "Token mint being locked")]
+    #[account(4, name = "config", desc = "Config PDA")]
+    #[account(5, writable, name = "fee_vault", desc = "Fee vault to receive USDC fees")]
+    #[account(6, name = "token_program", desc = "SPL Token program")]
+    #[account(7, name = "system_program", desc = "System program")]
+    #[account(8, name = "owner", desc = "Owner of the first lock")]
+    #[account(9, writable, name = "lock_account", desc = "First lock PDA to be created")]
+    #[account(10, writable, name = "lock_token_account", desc = "First lock's token escrow account")]
+    InitializeLockBatch {
+        amount: u64,
+        unlock_timestamp: i64,
+        lock_id: u64,
+    },
+
+    /// Charge `InitializeLockBatch` the fee for every lock, or once per
+    /// batch (admin only)
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config PDA")]
+    #[account(2, name = "system_program", desc = "System program")]
+    SetBatchFeeMode { per_lock: bool },
+
+    /// Mint the owner a non-fungible receipt for their lock, with Token
+    /// Metadata naming the lock and giving its amount and unlock date. The
+    /// receipt stays frozen in the owner's associated token account unless
+    /// it is `transferable`, in which case the right to unlock follows it.
+    /// A lock has at most one receipt, recorded on the lock. Receipt mints
+    /// outlive their locks, so the receipt mint PDA is seeded with a
+    /// `nonce` that a later lock at the same address must choose afresh.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner, pays for the receipt")]
+    #[account(1, writable, name = "lock_account", desc = "Lock to mint a receipt for, grown to record it")]
+    #[account(2, writable, name = "receipt_mint", desc = "Receipt mint PDA of the lock and nonce, created here")]
+    #[account(3, writable, name = "owner_receipt_account", desc = "Owner's associated token account for the receipt mint")]
+    #[account(4, writable, name = "metadata", desc = "Token Metadata PDA of the receipt mint")]
+    #[account(5, name = "receipt_authority", desc = "Receipt authority PDA")]
+    #[account(6, name = "token_program", desc = "SPL Token program")]
+    #[account(7, name = "associated_token_program", desc = "SPL Associated Token Account program")]
+    #[account(8, name = "token_metadata_program", desc = "Metaplex Token Metadata program")]
+    #[account(9, name = "system_program", desc = "System program")]
+    MintLockReceipt {
+        /// Bind the lock to the receipt, which is left unfrozen; may be
+        /// omitted by older clients
+        transferable: bool,
+        /// Seed of the receipt mint PDA, unused by any earlier receipt of a
+        /// lock at this address (0 if omitted)
+        nonce: u64,
+    },
+
+    /// Burn the receipt of a closed lock, e.g. after `Unlock` in the same
+    /// transaction, and close the token account holding it. The lock's
+    /// address may since hold a new lock, as long as this is not its
+    /// receipt.
+    #[account(0, signer, writable, name = "holder", desc = "Receipt holder, receives the token account's rent")]
+    #[account(1, name = "lock_account", desc = "Lock of the receipt, already closed")]
+    #[account(2, writable, name = "receipt_mint", desc = "Receipt mint PDA of the lock and nonce")]
+    #[account(3, writable, name = "holder_receipt_account", desc = "Holder's token account for the receipt mint")]
+    #[account(4, name = "receipt_authority", desc = "Receipt authority PDA")]
+    #[account(5, name = "token_program", desc = "SPL Token program")]
+    BurnLockReceipt {
+        /// Nonce the receipt was minted with
+        nonce: u64,
+    },
+
+    /// `Unlock` a receipt-bound lock as the holder of its receipt, who
+    /// receives the tokens and the rent. The lock's owner need not sign.
+    #[account(0, signer, writable, name = "holder", desc = "Receipt holder, receives the rent")]
+    #[account(1, name = "holder_receipt_account", desc = "Holder's token account holding the receipt")]
+    #[account(2, name = "owner", desc = "Lock owner")]
+    #[account(3, writable, name = "holder_token_account", desc = "Holder's destination for unlocked tokens")]
+    #[account(4, writable, name = "lock_account", desc = "Lock account to be closed")]
+    #[account(5, writable, name = "lock_token_account", desc = "Lock's token account to be closed")]
+    #[account(6, name = "token_program", desc = "SPL Token program")]
+    #[account(7, optional, name = "credential", desc = "Unused placeholder keeping `Unlock`'s account positions")]
+    #[account(8, optional, name = "forwarding_destination", desc = "Unused placeholder keeping `Unlock`'s account positions")]
+    #[account(9, optional, name = "destination_program", desc = "Unused placeholder keeping `Unlock`'s account positions")]
+    #[account(10, optional, name = "hook", desc = "Lock's hook, required if set")]
+    #[account(11, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(12, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    #[account(13, optional, writable, name = "donation_token_account", desc = "Donation address's token account, required if the lock donates")]
+    UnlockWithReceipt { lock_id: u64 },
+
+    /// Set the lock's label, so an owner with many locks can tell them
+    /// apart (owner only). All zeroes clears it.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner, pays to grow older locks")]
+    #[account(1, writable, name = "lock_account", desc = "Lock to label")]
+    #[account(2, name = "system_program", desc = "System program")]
+    UpdateLockMetadata { label: [u8; 32] },
+
+    /// `Unlock` a lock as its fallback owner, who receives the tokens and
+    /// the rent, once the owner has left it unlocked for `fallback_after`
+    /// seconds past `unlock_timestamp`. The lock's owner need not sign.
+    /// Credential-gated locks require the fallback owner's credential; the
+    /// lock's forwarding destination is not used.
+    #[account(0, signer, writable, name = "fallback_owner", desc = "Lock's fallback owner, receives the rent")]
+    #[account(1, name = "owner", desc = "Lock owner")]
+    #[account(2, writable, name = "fallback_token_account", desc = "Fallback owner's destination for the tokens")]
+    #[account(3, writable, name = "lock_account", desc = "Lock account to be closed")]
+    #[account(4, writable, name = "lock_token_account", desc = "Lock's token account to be closed")]
+    #[account(5, name = "token_program", desc = "SPL Token program")]
+    #[account(6, optional, name = "credential", desc = "Fallback owner's credential PDA, required if the lock is credential-gated")]
+    #[account(7, optional, name = "forwarding_destination", desc = "Unused placeholder keeping `Unlock`'s account positions")]
+    #[account(8, optional, name = "destination_program", desc = "Unused placeholder keeping `Unlock`'s account positions")]
+    #[account(9, optional, name = "hook", desc = "Lock's hook, required if set")]
+    #[account(10, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(11, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    #[account(12, optional, writable, name = "donation_token_account", desc = "Donation address's token account, required if the lock donates")]
+    ClaimAsFallback { lock_id: u64 },
+
+    /// Split the lock among up to eight beneficiaries with basis-point
+    /// shares summing to 10,000 (owner only). Each then claims their share
+    /// with `ClaimShare` once the lock unlocks, and the owner can no longer
+    /// unlock it. Unused slots are the default pubkey with a zero share.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner, pays for the shares account and to grow older locks")]
+    #[account(1, writable, name = "lock_account", desc = "Lock to split")]
+    #[account(2, writable, name = "lock_shares", desc = "Lock's shares PDA, created here")]
+    #[account(3, name = "system_program", desc = "System program")]
+    SetLockShares {
+        beneficiaries: [Pubkey; 8],
+        share_bps: [u16; 8],
+    },
+
+    /// Pay a beneficiary of a split lock their share once it unlocks. The
+    /// last claim also receives any rounding dust, and closes the lock, its
+    /// escrow and shares account, returning their rent to the owner.
+    #[account(0, signer, name = "beneficiary", desc = "Beneficiary claiming their share")]
+    #[account(1, writable, name = "beneficiary_token_account", desc = "Beneficiary's destination for the share")]
+    #[account(2, writable, name = "owner", desc = "Lock owner, receives the rent after the last claim")]
+    #[account(3, writable, name = "lock_account", desc = "Split lock")]
+    #[account(4, writable, name = "lock_token_account", desc = "Lock's token account")]
+    #[account(5, writable, name = "lock_shares", desc = "Lock's shares PDA")]
+    #[account(6, name = "token_program", desc = "SPL Token program")]
+    ClaimShare,
+
+    /// Require `threshold` of up to eight approvers to approve before the
+    /// lock can be unlocked, with `ExecuteUnlock` only (owner only). Unused
+    /// slots are the default pubkey. Cannot be undone.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner, pays for the approvals account and to grow older locks")]
+    #[account(1, writable, name = "lock_account", desc = "Lock to require approvals for")]
+    #[account(2, writable, name = "unlock_approvals", desc = "Lock's approvals PDA, created here")]
+    #[account(3, name = "system_program", desc = "System program")]
+    SetUnlockApprovers {
+        approvers: [Pubkey; 8],
+        threshold: u8,
+    },
+
+    /// Approve unlocking a lock as one of its approvers, at any time before
+    /// it is unlocked.
+    #[account(0, signer, name = "approver", desc = "One of the lock's approvers")]
+    #[account(1, name = "lock_account", desc = "Lock to approve unlocking")]
+    #[account(2, writable, name = "unlock_approvals", desc = "Lock's approvals PDA")]
+    ApproveUnlock,
+
+    /// `Unlock` a lock requiring approvals once enough of its approvers have
+    /// approved, closing its approvals account too. Takes `Unlock`'s
+    /// accounts after the approvals account.
+    #[account(0, writable, name = "unlock_approvals", desc = "Lock's approvals PDA, closed to the owner")]
+    #[account(1, signer, writable, name = "owner", desc = "Lock owner receiving tokens")]
+    #[account(2, writable, name = "owner_token_account", desc = "Destination for unlocked tokens")]
+    #[account(3, writable, name = "lock_account", desc = "Lock account to be closed")]
+    #[account(4, writable, name = "lock_token_account", desc = "Lock's token account to be closed")]
+    #[account(5, name = "token_program", desc = "SPL Token program")]
+    #[account(6, optional, name = "credential", desc = "Owner's credential PDA, required if the lock is credential-gated")]
+    #[account(7, optional, name = "forwarding_destination", desc = "Lock's forwarding destination, required if set")]
+    #[account(8, optional, name = "destination_program", desc = "Program of the forwarding destination")]
+    #[account(9, optional, name = "hook", desc = "Lock's hook, required if set")]
+    #[account(10, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(11, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    #[account(12, optional, writable, name = "donation_token_account", desc = "Donation address's token account, required if the lock donates")]
+    ExecuteUnlock { lock_id: u64 },
+
+    /// Approve (or withdraw approval of) the milestone of a lock with an
+    /// arbiter, letting its owner unlock it once it matures (arbiter only).
+    /// Sign it in the unlock's transaction to co-sign instead.
+    #[account(0, signer, name = "arbiter", desc = "Lock's arbiter")]
+    #[account(1, writable, name = "lock_account", desc = "Lock whose milestone is approved")]
+    ApproveMilestone { approved: bool },
+
+    /// Settle an escrow lock: pay its tokens, and the rent, to its
+    /// counterparty, who signs along with the owner. Needs no unlock time.
+    /// Credential-gated locks require the counterparty's credential; the
+    /// lock's forwarding destination is not used.
+    #[account(0, signer, writable, name = "counterparty", desc = "Lock's counterparty, receives the rent")]
+    #[account(1, signer, name = "owner", desc = "Lock owner")]
+    #[account(2, writable, name = "counterparty_token_account", desc = "Counterparty's destination for the tokens")]
+    #[account(3, writable, name = "lock_account", desc = "Lock account to be closed")]
+    #[account(4, writable, name = "lock_token_account", desc = "Lock's token account to be closed")]
+    #[account(5, name = "token_program", desc = "SPL Token program")]
+    #[account(6, optional, name = "credential", desc = "Counterparty's credential PDA, required if the lock is credential-gated")]
+    #[account(7, optional, name = "forwarding_destination", desc = "Unused placeholder keeping `Unlock`'s account positions")]
+    #[account(8, optional, name = "destination_program", desc = "Unused placeholder keeping `Unlock`'s account positions")]
+    #[account(9, optional, name = "hook", desc = "Lock's hook, required if set")]
+    #[account(10, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(11, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    ReleaseEscrow { lock_id: u64 },
+
+    /// Permissionlessly write a consistent lock's current voting weight and
+    /// the slot to its vote weight PDA, creating it or refreshing an
+    /// existing one.
+    #[account(0, signer, writable, name = "payer", desc = "Pays for a new snapshot")]
+    #[account(1, name = "lock_account", desc = "Lock to snapshot")]
+    #[account(2, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(3, writable, name = "weight_snapshot", desc = "Vote weight PDA of the lock")]
+    #[account(4, name = "system_program", desc = "System program")]
+    SnapshotWeight,
+
+    /// Permissionlessly close the vote weight snapshot of a closed lock,
+    /// refunding its rent to whoever paid it.
+    #[account(0, name = "lock_account", desc = "Snapshotted lock, already closed")]
+    #[account(1, writable, name = "weight_snapshot", desc = "Vote weight PDA of the lock")]
+    #[account(2, writable, name = "payer", desc = "Payer recorded in the snapshot, receives the rent")]
+    CloseWeightSnapshot,
+
+    /// Pause or resume lock creation (admin only). Unlocks, fee withdrawal
+    /// and reads keep working while paused.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config PDA")]
+    #[account(2, name = "system_program", desc = "System program")]
+    SetPaused { paused: bool },
+
+    /// Allow or deny locking a mint under the mint policy mode (admin only),
+    /// creating its mint policy PDA if needed.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays for a new policy")]
+    #[account(1, name = "config", desc = "Config PDA")]
+    #[account(2, name = "mint", desc = "Mint the policy is for")]
+    #[account(3, writable, name = "mint_policy", desc = "Mint policy PDA of the mint")]
+    #[account(4, name = "system_program", desc = "System program")]
+    SetMintPolicy { allowed: bool },
+
+    /// Remove a mint's policy (admin only), refunding its rent to the admin.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, receives the rent")]
+    #[account(1, name = "config", desc = "Config PDA")]
+    #[account(2, writable, name = "mint_policy", desc = "Mint policy PDA to close")]
+    CloseMintPolicy,
+
+    /// Choose which mints can be locked (admin only): any
+    /// (`MINT_POLICY_NONE`), only allowed ones (`MINT_POLICY_ALLOWLIST`) or
+    /// all but denied ones (`MINT_POLICY_DENYLIST`). Under a policy, lock
+    /// creation needs the mint's policy PDA among its accounts.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config PDA")]
+    #[account(2, name = "system_program", desc = "System program")]
+    SetMintPolicyMode { mode: u8 },
+
+    /// Refuse or accept locking mints that still have a freeze authority
+    /// (admin only). The mint's freeze authority could otherwise freeze a
+    /// lock's escrow, stranding its tokens past the unlock.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config PDA")]
+    #[account(2, name = "system_program", desc = "System program")]
+    SetRejectFreezableMints { reject: bool },
+
+    /// Permissionlessly attest what share of its mint's supply a consistent
+    /// lock holds, until when, and whether the mint's mint and freeze
+    /// authorities are revoked, writing the lock proof PDA or refreshing an
+    /// existing one.
+    #[account(0, signer, writable, name = "payer", desc = "Pays for a new proof")]
+    #[account(1, name = "lock_account", desc = "Lock to attest")]
+    #[account(2, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(3, name = "mint", desc = "Mint of the locked tokens")]
+    #[account(4, writable, name = "lock_proof", desc = "Lock proof PDA of the lock")]
+    #[account(5, name = "system_program", desc = "System program")]
+    RecordLockProof,
+
+    /// Permissionlessly close the lock proof of a closed lock, refunding its
+    /// rent to whoever paid it.
+    #[account(0, name = "lock_account", desc = "Attested lock, already closed")]
+    #[account(1, writable, name = "lock_proof", desc = "Lock proof PDA of the lock")]
+    #[account(2, writable, name = "payer", desc = "Payer recorded in the proof, receives the rent")]
+    CloseLockProof,
+
+    /// Unlock tokens like `Unlock`, with the same accounts, but leave the
+    /// emptied lock account and escrow open for `CloseLock` to reclaim their
+    /// rent later. Locks created with `LockOptions::wrap_lamports` cannot
+    /// be unlocked this way.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner receiving tokens")]
+    #[account(1, writable, name = "owner_token_account", desc = "Destination for unlocked tokens")]
+    #[account(2, writable, name = "lock_account", desc = "Lock account, left empty")]
+    #[account(3, writable, name = "lock_token_account", desc = "Lock's token account, left empty")]
+    #[account(4, name = "token_program", desc = "SPL Token program")]
+    #[account(5, optional, name = "credential", desc = "Owner's credential PDA, required if the lock is credential-gated")]
+    #[account(6, optional, name = "forwarding_destination", desc = "Lock's forwarding destination, required if set")]
+    #[account(7, optional, name = "destination_program", desc = "Program of the forwarding destination")]
+    #[account(8, optional, name = "hook", desc = "Lock's hook, required if set")]
+    #[account(9, optional, name = "hook_program", desc = "Program of the hook")]
+    #[account(10, optional, writable, name = "hook_state", desc = "Hook program's account updated by the callback")]
+    #[account(11, optional, writable, name = "donation_token_account", desc = "Donation address's token account, required if the lock donates")]
+    UnlockWithoutClosing { lock_id: u64 },
+
+    /// Permissionlessly close an emptied lock and its escrow, refunding their
+    /// rent to the lock's owner, who need not sign. Tokens anyone sent to the
+    /// escrow since are first swept to the lock's unlock destination, or else
+    /// the owner's associated token account, passed after these accounts.
+    #[account(0, writable, name = "owner", desc = "Lock owner, receives the rent")]
+    #[account(1, writable, name = "lock_account", desc = "Emptied lock account to close")]
+    #[account(2, writable, name = "lock_token_account", desc = "Lock's emptied token account to close")]
+    #[account(3, name = "token_program", desc = "SPL Token program")]
+    CloseLock,
+
+    /// Unlock tokens like `Unlock`, into the owner's associated token account
+    /// for the lock's mint, creating it first if the owner closed it or never
+    /// had one. The lock's optional accounts follow, in `Unlock`'s order.
+    #[account(0, signer, writable, name = "owner", desc = "Lock owner receiving tokens, pays for a new account")]
+    #[account(1, writable, name = "owner_token_account", desc = "Owner's associated token account for the mint, created if missing")]
+    #[account(2, writable, name = "lock_account", desc = "Lock account to be closed")]
+    #[account(3, writable, name = "lock_token_account", desc = "Lock's token account to be closed")]
+    #[account(4, name = "token_program", desc = "SPL Token program")]
+    #[account(5, name = "mint", desc = "Mint of the locked tokens")]
+    #[account(6, name = "system_program", desc = "System program")]
+    #[account(7, name = "associated_token_program", desc = "SPL Associated Token Account program")]
+    UnlockToAssociatedAccount { lock_id: u64 },
+
+    /// Move the tokens a lock's escrow holds above the locked amount, e.g.
+    /// from a mistaken transfer or an airdrop, to the owner (owner only).
+    /// The locked amount stays in escrow.
+    #[account(0, signer, name = "owner", desc = "Lock owner")]
+    #[account(1, writable, name = "owner_token_account", desc = "Owner's token account receiving the excess")]
+    #[account(2, name = "lock_account", desc = "Lock whose escrow holds excess tokens")]
+    #[account(3, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
+    #[account(4, name = "token_program", desc = "SPL Token program")]
+    SweepExcess { lock_id: u64 },
+
+    /// Move tokens of another mint that were sent to a fee vault or lock
+    /// account, and so sit in a token account that PDA controls, to a
+    /// recovery account (admin only). The fee vault's fee mint and the
+    /// lock's mint cannot be recovered this way.
+    #[account(0, signer, name = "admin", desc = "Admin authority")]
+    #[account(1, name = "config", desc = "Config PDA")]
+    #[account(2, name = "authority", desc = "Fee vault or lock account controlling the stranded tokens")]
+    #[account(3, writable, name = "stranded_token_account", desc = "Token account holding the stranded tokens")]
+    #[account(4, writable, name = "recovery_token_account", desc = "Token account receiving the stranded tokens")]
+    #[account(5, name = "token_program", desc = "SPL Token program")]
+    RecoverStrandedTokens,
+
+    /// Set the fee charged per lock, at most `MAX_FEE_USDC` (admin only).
+    /// Templates keep their own fees, and NFT locks stay free.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config PDA")]
+    #[account(2, name = "system_program", desc = "System program")]
+    UpdateFeeConfig { new_fee: u64 },
+
+    /// Charge each lock of a mint with a fee cap `fee_bps` of its tokens,
+    /// at most `MAX_FEE_BPS` and no more than the mint's cap, instead of the
+    /// flat fee, or return to the flat fee with a `fee_bps` of 0 (admin
+    /// only). The fee is deducted from the deposit into the mint's token fee
+    /// vault. Lock creations then need the mint's fee cap PDA among their
+    /// accounts, created or not, or fail with `NotEnoughAccountKeys`.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config PDA")]
+    #[account(2, name = "system_program", desc = "System program")]
+    SetPercentageFee { fee_bps: u16 },
+
+    /// Let lockers pay `fee_lamports`, at most `MAX_FEE_LAMPORTS`, into the
+    /// SOL fee vault instead of the flat USDC fee, or stop accepting SOL
+    /// with 0 (admin only). A locker pays in SOL by setting
+    /// `LockOptions::fee_in_lamports`.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, funds the vault's rent and grows older configs")]
+    #[account(1, writable, name = "config", desc = "Config PDA")]
+    #[account(2, writable, name = "sol_fee_vault", desc = "SOL fee vault PDA")]
+    #[account(3, name = "system_program", desc = "System program")]
+    SetSolFee { fee_lamports: u64 },
+
+    /// Withdraw lamport fees from the SOL fee vault to any account (admin
+    /// only).
+    #[account(0, signer, name = "admin", desc = "Admin withdrawing fees")]
+    #[account(1, name = "config", desc = "Config account for admin verification")]
+    #[account(2, writable, name = "sol_fee_vault", desc = "SOL fee vault PDA")]
+    #[account(3, writable, name = "destination", desc = "Account receiving the lamports")]
+    #[account(4, name = "system_program", desc = "System program")]
+    WithdrawSolFees {
+        /// Lamports to withdraw (0 = everything above the vault's rent
+        /// exemption)
+        amount: u64,
+    },
+
+    /// Exempt a wallet from fees on the locks it pays for (admin only),
+    /// creating its fee exemption PDA. Lock creation waives the fee when
+    /// the payer's exemption PDA is among its accounts.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays for the exemption")]
+    #[account(1, name = "config", desc = "Config PDA")]
+    #[account(2, name = "wallet", desc = "Wallet to exempt")]
+    #[account(3, writable, name = "fee_exemption", desc = "Fee exemption PDA of the wallet")]
+    #[account(4, name = "system_program", desc = "System program")]
+    SetFeeExemption,
+
+    /// Revoke a wallet's fee exemption (admin only), refunding its rent to
+    /// the admin.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, receives the rent")]
+    #[account(1, name = "config", desc = "Config PDA")]
+    #[account(2, writable, name = "fee_exemption", desc = "Fee exemption PDA to close")]
+    CloseFeeExemption,
+
+    /// Set the flat fee by lock duration and amount with up to eight tiers
+    /// (admin only), creating the fee schedule PDA if needed. A lock pays
+    /// the fee of the last of the first `tier_count` tiers whose minimum
+    /// duration, in seconds, and amount it meets, or the configured fee if
+    /// none; a `tier_count` of 0 returns to the configured fee. Fees are at
+    /// most `MAX_FEE_USDC`. Lock creation then needs the schedule PDA among
+    /// its accounts.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays for the schedule and to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config PDA")]
+    #[account(2, writable, name = "fee_schedule", desc = "Fee schedule PDA")]
+    #[account(3, name = "system_program", desc = "System program")]
+    SetFeeSchedule {
+        tier_count: u8,
+        min_durations: [u64; 8],
+        min_amounts: [u64; 8],
+        fees_usdc: [u64; 8],
+    },
+
+    /// Set the most tokens of a mint the percentage fee takes from one lock
+    /// (admin only), creating the mint's fee cap PDA if needed. Only mints
+    /// with a cap pay the percentage fee.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays for a new cap")]
+    #[account(1, name = "config", desc = "Config PDA")]
+    #[account(2, name = "mint", desc = "Mint the cap is for")]
+    #[account(3, writable, name = "fee_cap", desc = "Fee cap PDA of the mint")]
+    #[account(4, name = "system_program", desc = "System program")]
+    SetFeeCap { cap: u64 },
+
+    /// Remove a mint's fee cap (admin only), returning its locks to the flat
+    /// fee and refunding its rent to the admin.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, receives the rent")]
+    #[account(1, name = "config", desc = "Config PDA")]
+    #[account(2, writable, name = "fee_cap", desc = "Fee cap PDA to close")]
+    CloseFeeCap,
+
+    /// Withdraw a token fee vault's percentage fees to a token account
+    /// (admin only)
+    #[account(0, signer, name = "admin", desc = "Admin authority")]
+    #[account(1, name = "config", desc = "Config PDA")]
+    #[account(2, writable, name = "token_fee_vault", desc = "Token fee vault PDA to withdraw from")]
+    #[account(3, writable, name = "admin_token_account", desc = "Admin's token account for the vault's mint")]
+    #[account(4, name = "token_program", desc = "SPL Token program")]
+    WithdrawTokenFees,
+
+    /// Cap the unclosed locks each owner may hold (admin only); 0 lifts the
+    /// cap. Locks are counted only while a cap is set, on the owner's
+    /// counter PDA, which lock creation then needs among its accounts.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays to grow older configs")]
+    #[account(1, writable, name = "config", desc = "Config PDA")]
+    #[account(2, name = "system_program", desc = "System program")]
+    SetMaxLocksPerOwner { max_locks: u32 },
+
+    /// Give an owner their own lock cap in place of the config's (admin
+    /// only), creating their counter PDA if needed; 0 returns them to the
+    /// config's cap and `u32::MAX` exempts them.
+    #[account(0, signer, writable, name = "admin", desc = "Admin authority, pays for a new counter")]
+    #[account(1, name = "config", desc = "Config PDA")]
+    #[account(2, name = "owner", desc = "Owner the cap is for")]
+    #[account(3, writable, name = "owner_counter", desc = "Owner counter PDA of the owner")]
+    #[account(4, name = "system_program", desc = "System program")]
+    SetOwnerLockCap { lock_cap: u32 },
+}
+
+/// Anchor-style discriminators, `sha256("global:<snake_case_name>")[..8]`,
+/// indexed by the tag of the same instruction. `unpack` accepts either, so
+/// Anchor CPI clients and sighash-based tooling can call the program.
+pub const ANCHOR_DISCRIMINATORS: [[u8; 8]; 90] = [
+    [208, 127, 21, 1, 194, 190, 196, 70], // 0: initialize_config
+    [42, 242, 66, 106, 228, 10, 111, 156], // 1: transfer_admin
+    [198, 212, 171, 109, 144, 215, 174, 89], // 2: withdraw_fees
+    [182, 214, 195, 105, 58, 73, 81, 124], // 3: initialize_lock
+    [101, 155, 40, 21, 158, 189, 56, 203], // 4: unlock
+    [240, 95, 152, 254, 126, 211, 228, 160], // 5: audit_lock
+    [107, 141, 114, 171, 0, 68, 105, 10], // 6: set_compliance_authority
+    [83, 18, 110, 31, 228, 86, 133, 109], // 7: freeze_lock
+    [145, 25, 92, 114, 120, 10, 153, 184], // 8: unfreeze_lock
+    [77, 135, 49, 84, 122, 244, 201, 200], // 9: initialize_lock_with_options
+    [233, 177, 13, 116, 99, 185, 163, 244], // 10: set_lock_template
+    [9, 173, 110, 99, 129, 205, 62, 112], // 11: initialize_lock_from_template
+    [169, 231, 47, 49, 207, 233, 232, 52], // 12: create_standing_order
+    [4, 16, 235, 215, 113, 52, 92, 47], // 13: execute_standing_order
+    [200, 119, 126, 254, 235, 80, 63, 212], // 14: cancel_standing_order
+    [181, 157, 218, 109, 153, 72, 77, 34], // 15: set_forwarding_destination
+    [245, 153, 31, 35, 124, 196, 113, 191], // 16: set_lock_forwarding
+    [175, 16, 187, 252, 19, 54, 111, 221], // 17: set_hook
+    [95, 113, 127, 129, 112, 166, 214, 82], // 18: sweep_abandoned_lock
+    [73, 224, 7, 7, 238, 167, 91, 154], // 19: set_lender
+    [25, 54, 100, 224, 118, 253, 231, 59], // 20: set_collateralized
+    [231, 229, 238, 91, 25, 110, 168, 83], // 21: verify_collateral
+    [250, 86, 23, 107, 104, 104, 192, 204], // 22: initialize_lock_for_duration
+    [28, 29, 142, 247, 88, 251, 170, 55], // 23: propose_fee_mint
+    [184, 66, 49, 131, 147, 19, 105, 19], // 24: migrate_fee_mint
+    [75, 51, 172, 25, 12, 242, 119, 88], // 25: initialize_lock_with_payer
+    [122, 102, 225, 94, 60, 17, 179, 194], // 26: initialize_gift_lock
+    [27, 108, 194, 40, 200, 46, 241, 210], // 27: claim_lock
+    [31, 246, 115, 8, 100, 38, 72, 150], // 28: initialize_relayed_lock
+    [156, 135, 126, 111, 184, 206, 194, 141], // 29: set_session
+    [153, 224, 180, 131, 53, 69, 234, 8], // 30: unlock_with_session
+    [23, 233, 190, 109, 126, 130, 51, 119], // 31: set_lock_note
+    [211, 54, 226, 162, 68, 13, 78, 125], // 32: index_lock
+    [56, 99, 209, 68, 21, 216, 76, 80], // 33: unindex_lock
+    [168, 121, 24, 244, 81, 22, 205, 5], // 34: withdraw_surplus_rent
+    [134, 213, 40, 9, 38, 171, 204, 40], // 35: set_successor_program
+    [65, 245, 153, 220, 203, 85, 155, 165], // 36: migrate_lock
+    [138, 4, 22, 231, 106, 164, 103, 11], // 37: sunset
+    [20, 193, 203, 36, 28, 112, 201, 80], // 38: publish_lock_certificate
+    [86, 52, 88, 10, 173, 166, 137, 79], // 39: close_lock_certificate
+    [68, 151, 140, 144, 139, 122, 118, 170], // 40: extend_lock
+    [206, 251, 114, 148, 41, 88, 205, 9], // 41: increase_lock_amount
+    [5, 29, 245, 237, 50, 242, 35, 13], // 42: initialize_vesting
+    [208, 190, 166, 114, 203, 225, 140, 208], // 43: claim_vested
+    [12, 252, 252, 168, 39, 101, 98, 9], // 44: revoke_vesting
+    [137, 93, 65, 82, 206, 90, 214, 223], // 45: set_early_unlock_penalty
+    [17, 106, 80, 63, 244, 220, 225, 70], // 46: emergency_unlock
+    [102, 242, 17, 13, 61, 184, 206, 221], // 47: withdraw_penalties
+    [235, 187, 184, 112, 143, 63, 95, 102], // 48: relock
+    [67, 38, 152, 247, 246, 44, 63, 57], // 49: crank_unlock
+    [10, 225, 143, 36, 157, 61, 175, 99], // 50: initialize_lock_batch
+    [233, 111, 212, 194, 176, 47, 208, 75], // 51: set_batch_fee_mode
+    [117, 120, 177, 60, 181, 61, 113, 228], // 52: mint_lock_receipt
+    [17, 157, 42, 208, 25, 123, 216, 172], // 53: burn_lock_receipt
+    [134, 115, 145, 110, 32, 79, 18, 90], // 54: unlock_with_receipt
+    [26, 108, 49, 177, 171, 96, 124, 246], // 55: update_lock_metadata
+    [150, 167, 67, 130, 212, 226, 144, 144], // 56: claim_as_fallback
+    [211, 225, 140, 165, 88, 219, 220, 67], // 57: set_lock_shares
+    [42, 18, 161, 15, 129, 155, 240, 52], // 58: claim_share
+    [142, 205, 178, 45, 200, 90, 160, 61], // 59: set_unlock_approvers
+    [218, 66, 192, 101, 74, 237, 227, 127], // 60: approve_unlock
+    [157, 225, 130, 66, 232, 152, 173, 161], // 61: execute_unlock
+    [145, 85, 92, 60, 50, 130, 219, 106], // 62: approve_milestone
+    [146, 253, 129, 233, 20, 145, 181, 206], // 63: release_escrow
+    [101, 193, 187, 58, 169, 69, 59, 71], // 64: snapshot_weight
+    [183, 208, 203, 142, 87, 118, 202, 63], // 65: close_weight_snapshot
+    [91, 60, 125, 192, 176, 225, 166, 218], // 66: set_paused
+    [12, 208, 252, 52, 166, 250, 137, 169], // 67: set_mint_policy
+    [46, 135, 161, 219, 4, 252, 38, 29], // 68: close_mint_policy
+    [210, 116, 79, 163, 171, 253, 87, 37], // 69: set_mint_policy_mode
+    [162, 74, 85, 217, 29, 247, 18, 98], // 70: set_reject_freezable_mints
+    [28, 175, 83, 183, 1, 32, 179, 224], // 71: record_lock_proof
+    [48, 78, 196, 6, 25, 77, 128, 108], // 72: close_lock_proof
+    [16, 45, 156, 245, 39, 2, 239, 121], // 73: unlock_without_closing
+    [58, 254, 183, 130, 151, 238, 95, 54], // 74: close_lock
+    [79, 161, 255, 162, 46, 127, 48, 23], // 75: unlock_to_associated_account
+    [255, 74, 219, 182, 1, 126, 233, 6], // 76: sweep_excess
+    [34, 162, 3, 152, 251, 204, 250, 255], // 77: recover_stranded_tokens
+    [104, 184, 103, 242, 88, 151, 107, 20], // 78: update_fee_config
+    [241, 201, 132, 94, 156, 15, 43, 174], // 79: set_percentage_fee
+    [142, 208, 86, 117, 93, 181, 230, 82], // 80: set_sol_fee
+    [191, 53, 166, 97, 124, 212, 228, 219], // 81: withdraw_sol_fees
+    [49, 221, 99, 185, 22, 228, 186, 160], // 82: set_fee_exemption
+    [130, 123, 18, 197, 235, 108, 30, 180], // 83: close_fee_exemption
+    [239, 37, 205, 178, 164, 47, 23, 13], // 84: set_fee_schedule
+    [86, 171, 246, 3, 253, 252, 149, 63], // 85: set_fee_cap
+    [121, 10, 79, 224, 215, 125, 248, 174], // 86: close_fee_cap
+    [148, 11, 90, 7, 99, 98, 153, 104], // 87: withdraw_token_fees
+    [8, 11, 119, 108, 65, 12, 137, 164], // 88: set_max_locks_per_owner
+    [47, 82, 149, 104, 217, 52, 149, 56], // 89: set_owner_lock_cap
+];
+
+impl LocksmithInstruction {
+    /// Unpacks an instruction prefixed with its one-byte tag or its
+    /// 8-byte Anchor discriminator. Both are followed by the same data.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let anchor_tag = input
+            .get(..8)
+            .and_then(|prefix| ANCHOR_DISCRIMINATORS.iter().position(|d| d == prefix));
+        if let Some(tag) = anchor_tag {
+            return Self::unpack_tagged(tag as u8, &input[8..]);
+        }
+
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(LocksmithError::InvalidInstruction)?;
+        Self::unpack_tagged(tag, rest)
+    }
+
+    fn unpack_tagged(tag: u8, rest: &[u8]) -> Result<Self, ProgramError> {
+        Ok(match tag {
+            0 => Self::InitializeConfig,
+            1 => Self::TransferAdmin,
+            2 => Self::WithdrawFees {
+                amount: trailing_u64(rest),
+            },
+            3 | 9 | 25 | 26 => {
+                if rest.len() < 24 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                let amount = u64::from_le_bytes(rest[0..8].try_into().unwrap());
+                let unlock_timestamp = i64::from_le_bytes(rest[8..16].try_into().unwrap());
+                let lock_id = u64::from_le_bytes(rest[16..24].try_into().unwrap());
+                match tag {
+                    3 => Self::InitializeLock {
+                        amount,
+                        unlock_timestamp,
+                        lock_id,
+                        valid_until: trailing_i64(&rest[24..]),
+                    },
+                    9 => Self::InitializeLockWithOptions {
+                        amount,
+                        unlock_timestamp,
+                        lock_id,
+                        options: LockOptions::unpack(&rest[24..])?,
+                    },
+                    25 => Self::InitializeLockWithPayer {
+                        amount,
+                        unlock_timestamp,
+                        lock_id,
+                        options: LockOptions::unpack(&rest[24..])?,
+                    },
+                    _ => Self::InitializeGiftLock {
+                        amount,
+                        unlock_timestamp,
+                        lock_id,
+                        options: LockOptions::unpack(&rest[24..])?,
+                    },
+                }
+            }
+            4 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                let lock_id = u64::from_le_bytes(rest[0..8].try_into().unwrap());
+                Self::Unlock { lock_id }
+            }
+            5 => Self::AuditLock,
+            6 => Self::SetComplianceAuthority,
+            7 => Self::FreezeLock,
+            8 => Self::UnfreezeLock,
+            10 => {
+                if rest.len() < 24 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetLockTemplate {
+                    template_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                    duration_seconds: i64::from_le_bytes(rest[8..16].try_into().unwrap()),
+                    fee_usdc: u64::from_le_bytes(rest[16..24].try_into().unwrap()),
+                    options: LockOptions::unpack(&rest[24..])?,
+                }
+            }
+            11 => {
+                if rest.len() < 24 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::InitializeLockFromTemplate {
+                    amount: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                    lock_id: u64::from_le_bytes(rest[8..16].try_into().unwrap()),
+                    template_id: u64::from_le_bytes(rest[16..24].try_into().unwrap()),
+                    valid_until: trailing_i64(&rest[24..]),
+                }
+            }
+            12 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::CreateStandingOrder {
+                    order_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                    params: StandingOrderParams::unpack(&rest[8..])?,
+                }
+            }
+            13 => Self::ExecuteStandingOrder,
+            14 => Self::CancelStandingOrder,
+            15 => {
+                if rest.len() < 10 || rest[8] > 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetForwardingDestination {
+                    instruction_prefix: rest[0..8].try_into().unwrap(),
+                    prefix_len: rest[8],
+                    approved: rest[9] != 0,
+                }
+            }
+            16 => Self::SetLockForwarding,
+            17 => {
+                if rest.len() < 15 || rest[8] > 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetHook {
+                    instruction_prefix: rest[0..8].try_into().unwrap(),
+                    prefix_len: rest[8],
+                    events: rest[9],
+                    compute_allowance: u32::from_le_bytes(rest[10..14].try_into().unwrap()),
+                    approved: rest[14] != 0,
+                }
+            }
+            18 => Self::SweepAbandonedLock,
+            19 | 20 => {
+                let &flag = rest.first().ok_or(LocksmithError::InvalidInstruction)?;
+                if tag == 19 {
+                    Self::SetLender { approved: flag != 0 }
+                } else {
+                    Self::SetCollateralized {
+                        collateralized: flag != 0,
+                    }
+                }
+            }
+            21 => Self::VerifyCollateral,
+            22 => {
+                if rest.len() < 24 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::InitializeLockForDuration {
+                    amount: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                    duration_seconds: i64::from_le_bytes(rest[8..16].try_into().unwrap()),
+                    lock_id: u64::from_le_bytes(rest[16..24].try_into().unwrap()),
+                    valid_until: trailing_i64(&rest[24..]),
+                }
+            }
+            23 => Self::ProposeFeeMint,
+            24 => Self::MigrateFeeMint,
+            27 => Self::ClaimLock,
+            28 => {
+                if rest.len() < 32 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::InitializeRelayedLock {
+                    amount: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                    unlock_timestamp: i64::from_le_bytes(rest[8..16].try_into().unwrap()),
+                    lock_id: u64::from_le_bytes(rest[16..24].try_into().unwrap()),
+                    valid_until: i64::from_le_bytes(rest[24..32].try_into().unwrap()),
+                }
+            }
+            29 => {
+                if rest.len() < 40 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetSession {
+                    mint: Pubkey::try_from(&rest[0..32]).unwrap(),
+                    expires_at: i64::from_le_bytes(rest[32..40].try_into().unwrap()),
+                }
+            }
+            30 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::UnlockWithSession {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            31 => {
+                if rest.len() < MAX_NOTE_LEN + 2 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                let note_len =
+                    u16::from_le_bytes(rest[MAX_NOTE_LEN..MAX_NOTE_LEN + 2].try_into().unwrap());
+                if note_len as usize > MAX_NOTE_LEN {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetLockNote {
+                    note: rest[..MAX_NOTE_LEN].try_into().unwrap(),
+                    note_len,
+                }
+            }
+            32 => Self::IndexLock,
+            33 => Self::UnindexLock,
+            34 => Self::WithdrawSurplusRent,
+            35 => {
+                let successor_program = rest
+                    .get(0..32)
+                    .ok_or(LocksmithError::InvalidInstruction)?;
+                Self::SetSuccessorProgram {
+                    successor_program: Pubkey::try_from(successor_program).unwrap(),
+                }
+            }
+            36 => Self::MigrateLock,
+            37 => Self::Sunset,
+            38 => Self::PublishLockCertificate,
+            39 => Self::CloseLockCertificate,
+            40 => {
+                if rest.len() < 16 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::ExtendLock {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                    new_unlock_timestamp: i64::from_le_bytes(rest[8..16].try_into().unwrap()),
+                }
+            }
+            41 => {
+                if rest.len() < 16 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::IncreaseLockAmount {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                    additional_amount: u64::from_le_bytes(rest[8..16].try_into().unwrap()),
+                }
+            }
+            42 => {
+                if rest.len() < 32 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::InitializeVesting {
+                    total_amount: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                    start_timestamp: i64::from_le_bytes(rest[8..16].try_into().unwrap()),
+                    end_timestamp: i64::from_le_bytes(rest[16..24].try_into().unwrap()),
+                    vesting_id: u64::from_le_bytes(rest[24..32].try_into().unwrap()),
+                    cliff_timestamp: trailing_i64(&rest[32..]),
+                    revocable: rest.get(40).is_some_and(|&flag| flag != 0),
+                    fee_in_lamports: rest.get(41).is_some_and(|&flag| flag != 0),
+                }
+            }
+            43 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::ClaimVested {
+                    vesting_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            44 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::RevokeVesting {
+                    vesting_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            45 => {
+                if rest.len() < 2 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetEarlyUnlockPenalty {
+                    penalty_bps: u16::from_le_bytes(rest[0..2].try_into().unwrap()),
+                }
+            }
+            46 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::EmergencyUnlock {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            47 => Self::WithdrawPenalties,
+            48 => {
+                if rest.len() < 16 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::Relock {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                    new_unlock_timestamp: i64::from_le_bytes(rest[8..16].try_into().unwrap()),
+                }
+            }
+            49 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::CrankUnlock {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            50 => {
+                if rest.len() < 24 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::InitializeLockBatch {
+                    amount: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                    unlock_timestamp: i64::from_le_bytes(rest[8..16].try_into().unwrap()),
+                    lock_id: u64::from_le_bytes(rest[16..24].try_into().unwrap()),
+                }
+            }
+            51 => {
+                let &per_lock = rest.first().ok_or(LocksmithError::InvalidInstruction)?;
+                Self::SetBatchFeeMode { per_lock: per_lock != 0 }
+            }
+            52 => Self::MintLockReceipt {
+                transferable: rest.first().is_some_and(|&b| b != 0),
+                nonce: rest.get(1..).map_or(0, trailing_u64),
+            },
+            53 => Self::BurnLockReceipt {
+                nonce: trailing_u64(rest),
+            },
+            54 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::UnlockWithReceipt {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            55 => {
+                if rest.len() < 32 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::UpdateLockMetadata {
+                    label: rest[0..32].try_into().unwrap(),
+                }
+            }
+            56 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::ClaimAsFallback {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            57 => {
+                if rest.len() < 32 * 8 + 2 * 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                let mut beneficiaries = [Pubkey::default(); 8];
+                let mut share_bps = [0u16; 8];
+                for i in 0..8 {
+                    beneficiaries[i] = Pubkey::try_from(&rest[32 * i..32 * i + 32]).unwrap();
+                    share_bps[i] =
+                        u16::from_le_bytes(rest[256 + 2 * i..258 + 2 * i].try_into().unwrap());
+                }
+                Self::SetLockShares {
+                    beneficiaries,
+                    share_bps,
+                }
+            }
+            58 => Self::ClaimShare,
+            59 => {
+                if rest.len() < 32 * 8 + 1 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                let mut approvers = [Pubkey::default(); 8];
+                for (i, approver) in approvers.iter_mut().enumerate() {
+                    *approver = Pubkey::try_from(&rest[32 * i..32 * i + 32]).unwrap();
+                }
+                Self::SetUnlockApprovers {
+                    approvers,
+                    threshold: rest[256],
+                }
+            }
+            60 => Self::ApproveUnlock,
+            61 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::ExecuteUnlock {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            62 => {
+                let &approved = rest.first().ok_or(LocksmithError::InvalidInstruction)?;
+                Self::ApproveMilestone {
+                    approved: approved != 0,
+                }
+            }
+            63 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::ReleaseEscrow {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            64 => Self::SnapshotWeight,
+            65 => Self::CloseWeightSnapshot,
+            66 => {
+                let &paused = rest.first().ok_or(LocksmithError::InvalidInstruction)?;
+                Self::SetPaused { paused: paused != 0 }
+            }
+            67 => {
+                let &allowed = rest.first().ok_or(LocksmithError::InvalidInstruction)?;
+                Self::SetMintPolicy { allowed: allowed != 0 }
+            }
+            68 => Self::CloseMintPolicy,
+            69 => {
+                let &mode = rest.first().ok_or(LocksmithError::InvalidInstruction)?;
+                if mode > MINT_POLICY_DENYLIST {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetMintPolicyMode { mode }
+            }
+            70 => {
+                let &reject = rest.first().ok_or(LocksmithError::InvalidInstruction)?;
+                Self::SetRejectFreezableMints { reject: reject != 0 }
+            }
+            71 => Self::RecordLockProof,
+            72 => Self::CloseLockProof,
+            73 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::UnlockWithoutClosing {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            74 => Self::CloseLock,
+            75 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::UnlockToAssociatedAccount {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            76 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SweepExcess {
+                    lock_id: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            77 => Self::RecoverStrandedTokens,
+            78 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::UpdateFeeConfig {
+                    new_fee: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            79 => {
+                if rest.len() < 2 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetPercentageFee {
+                    fee_bps: u16::from_le_bytes(rest[0..2].try_into().unwrap()),
+                }
+            }
+            80 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetSolFee {
+                    fee_lamports: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            81 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::WithdrawSolFees {
+                    amount: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            82 => Self::SetFeeExemption,
+            83 => Self::CloseFeeExemption,
+            84 => {
+                if rest.len() < 1 + 8 * 8 * 3 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                let u64_at = |i: usize| u64::from_le_bytes(rest[i..i + 8].try_into().unwrap());
+                let mut min_durations = [0u64; 8];
+                let mut min_amounts = [0u64; 8];
+                let mut fees_usdc = [0u64; 8];
+                for i in 0..8 {
+                    min_durations[i] = u64_at(1 + 8 * i);
+                    min_amounts[i] = u64_at(65 + 8 * i);
+                    fees_usdc[i] = u64_at(129 + 8 * i);
+                }
+                Self::SetFeeSchedule {
+                    tier_count: rest[0],
+                    min_durations,
+                    min_amounts,
+                    fees_usdc,
+                }
+            }
+            85 => {
+                if rest.len() < 8 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetFeeCap {
+                    cap: u64::from_le_bytes(rest[0..8].try_into().unwrap()),
+                }
+            }
+            86 => Self::CloseFeeCap,
+            87 => Self::WithdrawTokenFees,
+            88 => {
+                if rest.len() < 4 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetMaxLocksPerOwner {
+                    max_locks: u32::from_le_bytes(rest[0..4].try_into().unwrap()),
+                }
+            }
+            89 => {
+                if rest.len() < 4 {
+                    return Err(LocksmithError::InvalidInstruction.into());
+                }
+                Self::SetOwnerLockCap {
+                    lock_cap: u32::from_le_bytes(rest[0..4].try_into().unwrap()),
+                }
+            }
+            _ => return Err(LocksmithError::InvalidInstruction.into()),
+        })
+    }
+}
+
+/// The message an owner signs to have `InitializeRelayedLock` create a lock
+/// on their behalf.
+pub fn relayed_lock_message(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+    unlock_timestamp: i64,
+    lock_id: u64,
+    valid_until: i64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(RELAYED_LOCK_DOMAIN.len() + 32 * 3 + 8 * 4);
+    message.extend_from_slice(RELAYED_LOCK_DOMAIN);
+    message.extend_from_slice(program_id.as_ref());
+    message.extend_from_slice(owner.as_ref());
+    message.extend_from_slice(mint.as_ref());
+    message.extend_from_slice(&amount.to_le_bytes());
+    message.extend_from_slice(&unlock_timestamp.to_le_bytes());
+    message.extend_from_slice(&lock_id.to_le_bytes());
+    message.extend_from_slice(&valid_until.to_le_bytes());
+    message
+}
+
+/// The message a lock's claim key signs to have `ClaimLock` pay the lock
+/// out to `claimant_token_account`.
+pub fn claim_lock_message(
+    program_id: &Pubkey,
+    lock: &Pubkey,
+    claimant_token_account: &Pubkey,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(CLAIM_LOCK_DOMAIN.len() + 32 * 3);
+    message.extend_from_slice(CLAIM_LOCK_DOMAIN);
+    message.extend_from_slice(program_id.as_ref());
+    message.extend_from_slice(lock.as_ref());
+    message.extend_from_slice(claimant_token_account.as_ref());
+    message
+}
+
+/// Reads an optional trailing i64 argument, 0 when older clients omit it.
+fn trailing_i64(data: &[u8]) -> i64 {
+    data.get(0..8)
+        .map_or(0, |b| i64::from_le_bytes(b.try_into().unwrap()))
+}
+
+/// Reads an optional trailing u64 argument, 0 when older clients omit it.
+fn trailing_u64(data: &[u8]) -> u64 {
+    data.get(0..8)
+        .map_or(0, |b| u64::from_le_bytes(b.try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============================================================================
+    // INSTRUCTION PARSING TESTS
+    // ============================================================================
+
+    #[test]
+    fn test_unpack_initialize_config() {
+        let data = [0u8];
+        let instruction = LocksmithInstruction::unpack(&data).unwrap();
+        assert_eq!(instruction, LocksmithInstruction::InitializeConfig);
+    }
+
+    #[test]
+    fn test_unpack_transfer_admin() {
+        let data = [1u8];
+        let instruction = LocksmithInstruction::unpack(&data).unwrap();
+        assert_eq!(instruction, LocksmithInstruction::TransferAdmin);
+    }
+
+    #[test]
+    fn test_unpack_withdraw_fees() {
+        let data = [2u8];
+        let instruction = LocksmithInstruction::unpack(&data).unwrap();
+        assert_eq!(instruction, LocksmithInstruction::WithdrawFees { amount: 0 });
+
+        let mut data = vec![2u8];
+        data.extend_from_slice(&250_000u64.to_le_bytes());
+        let instruction = LocksmithInstruction::unpack(&data).unwrap();
+        assert_eq!(instruction, LocksmithInstruction::WithdrawFees { amount: 250_000 });
+    }
+
+    #[test]
+    fn test_unpack_initialize_lock() {
+        let amount: u64 = 1_000_000;
+        let unlock_timestamp: i64 = 1700000000;
+        let lock_id: u64 = 42;
+
+        let mut data = vec![3u8];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.extend_from_slice(&unlock_timestamp.to_le_bytes());
+        data.extend_from_slice(&lock_id.to_le_bytes());
+
+        let instruction = LocksmithInstruction::unpack(&data).unwrap();
+        assert_eq!(
+            instruction,
+            LocksmithInstruction::InitializeLock {
+                amount,
+                unlock_timestamp,
+                lock_id,
+                valid_until: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_initialize_lock_with_deadline() {
+        let mut data = vec![3u8];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&1700000000i64.to_le_bytes());
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&1690000000i64.to_le_bytes());
+
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeLock {
+                amount: 1_000,
+                unlock_timestamp: 1700000000,
+                lock_id: 42,
+                valid_until: 1690000000,
+            }
+        );
+
+        // A truncated deadline is treated as absent
+        data.pop();
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeLock {
+                amount: 1_000,
+                unlock_timestamp: 1700000000,
+                lock_id: 42,
+                valid_until: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_initialize_lock_for_duration() {
+        let mut data = vec![22u8];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&86_400i64.to_le_bytes());
+        data.extend_from_slice(&42u64.to_le_bytes());
+
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeLockForDuration {
+                amount: 1_000,
+                duration_seconds: 86_400,
+                lock_id: 42,
+                valid_until: 0,
+            }
+        );
+
+        data.extend_from_slice(&1690000000i64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeLockForDuration {
+                amount: 1_000,
+                duration_seconds: 86_400,
+                lock_id: 42,
+                valid_until: 1690000000,
+            }
+        );
+
+        assert!(LocksmithInstruction::unpack(&data[..24]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_unlock() {
+        let lock_id: u64 = 42;
+
+        let mut data = vec![4u8];
+        data.extend_from_slice(&lock_id.to_le_bytes());
+
+        let instruction = LocksmithInstruction::unpack(&data).unwrap();
+        assert_eq!(instruction, LocksmithInstruction::Unlock { lock_id });
+    }
+
+    #[test]
+    fn test_unpack_audit_lock() {
+        let data = [5u8];
+        let instruction = LocksmithInstruction::unpack(&data).unwrap();
+        assert_eq!(instruction, LocksmithInstruction::AuditLock);
+    }
+
+    #[test]
+    fn test_unpack_initialize_lock_with_options() {
+        let issuer = Pubkey::new_unique();
+
+        let mut data = vec![9u8];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&1700000000i64.to_le_bytes());
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(issuer.as_ref());
+        data.push(1);
+        let hook = Pubkey::new_unique();
+        data.extend_from_slice(hook.as_ref());
+        let recovery_address = Pubkey::new_unique();
+        data.extend_from_slice(recovery_address.as_ref());
+        let donation_address = Pubkey::new_unique();
+        data.extend_from_slice(donation_address.as_ref());
+        data.extend_from_slice(&250u16.to_le_bytes());
+
+        let instruction = LocksmithInstruction::unpack(&data).unwrap();
+        assert_eq!(
+            instruction,
+            LocksmithInstruction::InitializeLockWithOptions {
+                amount: 1_000,
+                unlock_timestamp: 1700000000,
+                lock_id: 42,
+                options: LockOptions {
+                    credential_issuer: issuer,
+                    non_transferable: true,
+                    hook,
+                    recovery_address,
+                    donation_address,
+                    donation_bps: 250,
+                    valid_until: 0,
+                    unlock_slot: 0,
+                    unlock_epoch: 0,
+                    claim_key: [0u8; 32],
+                    claim_expires_at: 0,
+                    early_unlock_allowed: false,
+                    permanent: false,
+                    label: [0u8; 32],
+                    metadata_uri: [0u8; METADATA_URI_LEN],
+                    fallback_owner: Pubkey::default(),
+                    fallback_after: 0,
+                    unlock_destination: Pubkey::default(),
+                    price_feed_id: [0u8; 32],
+                    price_threshold: 0,
+                    price_exponent: 0,
+                    arbiter: Pubkey::default(),
+                    counterparty: Pubkey::default(),
+                    fee_in_lamports: false,
+                    wrap_lamports: false,
+                },
+            }
+        );
+
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(&300_000_000u64.to_le_bytes());
+        data.extend_from_slice(&700u64.to_le_bytes());
+        data.extend_from_slice(&[7u8; 32]);
+        data.extend_from_slice(&1_800_000_000i64.to_le_bytes());
+        data.push(1);
+        data.push(1);
+        data.extend_from_slice(&[9u8; 32]);
+        data.extend_from_slice(&[10u8; METADATA_URI_LEN]);
+        let fallback_owner = Pubkey::new_unique();
+        data.extend_from_slice(fallback_owner.as_ref());
+        data.extend_from_slice(&86_400i64.to_le_bytes());
+        let unlock_destination = Pubkey::new_unique();
+        data.extend_from_slice(unlock_destination.as_ref());
+        data.extend_from_slice(&[11u8; 32]);
+        data.extend_from_slice(&250i64.to_le_bytes());
+        data.extend_from_slice(&(-2i32).to_le_bytes());
+        let arbiter = Pubkey::new_unique();
+        data.extend_from_slice(arbiter.as_ref());
+        let counterparty = Pubkey::new_unique();
+        data.extend_from_slice(counterparty.as_ref());
+        data.push(1);
+        data.push(1);
+        match LocksmithInstruction::unpack(&data).unwrap() {
+            LocksmithInstruction::InitializeLockWithOptions { options, .. } => {
+                assert_eq!(options.valid_until, 1_700_000_000);
+                assert_eq!(options.unlock_slot, 300_000_000);
+                assert_eq!(options.unlock_epoch, 700);
+                assert_eq!(options.claim_key, [7u8; 32]);
+                assert_eq!(options.claim_expires_at, 1_800_000_000);
+                assert!(options.early_unlock_allowed);
+                assert!(options.permanent);
+                assert_eq!(options.label, [9u8; 32]);
+                assert_eq!(options.metadata_uri, [10u8; METADATA_URI_LEN]);
+                assert_eq!(options.fallback_owner, fallback_owner);
+                assert_eq!(options.fallback_after, 86_400);
+                assert_eq!(options.unlock_destination, unlock_destination);
+                assert_eq!(options.price_feed_id, [11u8; 32]);
+                assert_eq!(options.price_threshold, 250);
+                assert_eq!(options.price_exponent, -2);
+                assert_eq!(options.arbiter, arbiter);
+                assert_eq!(options.counterparty, counterparty);
+                assert!(options.fee_in_lamports);
+                assert!(options.wrap_lamports);
+            }
+            other => panic!("unexpected instruction {other:?}"),
+        }
+        data.truncate(data.len() - 24 - 40 - 2 - 32 - METADATA_URI_LEN - 40 - 32 - 44 - 64 - 2);
+
+        // Clients that predate the donation, `recovery_address`, `hook` or
+        // `non_transferable` omit them
+        data.truncate(data.len() - 34 - 64);
+        match LocksmithInstruction::unpack(&data).unwrap() {
+            LocksmithInstruction::InitializeLockWithOptions { options, .. } => {
+                assert!(options.non_transferable);
+                assert_eq!(options.hook, Pubkey::default());
+            }
+            other => panic!("unexpected instruction {other:?}"),
+        }
+        data.pop();
+        match LocksmithInstruction::unpack(&data).unwrap() {
+            LocksmithInstruction::InitializeLockWithOptions { options, .. } => {
+                assert_eq!(options.credential_issuer, issuer);
+                assert!(!options.non_transferable);
+            }
+            other => panic!("unexpected instruction {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_initialize_lock_with_missing_options_uses_defaults() {
+        let mut data = vec![9u8];
+        data.extend_from_slice(&[0u8; 24]);
+
+        let instruction = LocksmithInstruction::unpack(&data).unwrap();
+        assert_eq!(
+            instruction,
+            LocksmithInstruction::InitializeLockWithOptions {
+                amount: 0,
+                unlock_timestamp: 0,
+                lock_id: 0,
+                options: LockOptions::default(),
+            }
+        );
+
+        assert!(LocksmithInstruction::unpack(&[9u8; 24]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_lock_options_rejects_partial_fields() {
+        let slot = [7u8; 147];
+        assert_eq!(LockOptions::unpack(&slot).unwrap().unlock_slot, 0x0707_0707_0707_0707);
+        assert_eq!(LockOptions::unpack(&slot[..139]).unwrap().unlock_slot, 0);
+        assert!(LockOptions::unpack(&[0u8; 539]).is_ok());
+
+        for len in [20, 32 + 1 + 20, 140, 538 + 1 + 1] {
+            assert_eq!(
+                LockOptions::unpack(&vec![0u8; len]).unwrap_err(),
+                ProgramError::Custom(LocksmithError::InvalidInstruction as u32)
+            );
+        }
+
+        let mut data = vec![9u8];
+        data.extend_from_slice(&[0u8; 24 + 140]);
+        assert!(LocksmithInstruction::unpack(&data).is_err());
+    }
+
+    #[test]
+    fn test_unpack_sponsored_lock_instructions() {
+        let recovery_address = Pubkey::new_unique();
+        let mut data = vec![25u8];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&1700000000i64.to_le_bytes());
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&[0u8; 33]);
+        data.extend_from_slice(&[0u8; 32]);
+        data.extend_from_slice(recovery_address.as_ref());
+
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeLockWithPayer {
+                amount: 1_000,
+                unlock_timestamp: 1700000000,
+                lock_id: 42,
+                options: LockOptions {
+                    recovery_address,
+                    ..LockOptions::default()
+                },
+            }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..24]).is_err());
+
+        data[0] = 26;
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeGiftLock {
+                amount: 1_000,
+                unlock_timestamp: 1700000000,
+                lock_id: 42,
+                options: LockOptions {
+                    recovery_address,
+                    ..LockOptions::default()
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_claim_lock() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[27u8]).unwrap(),
+            LocksmithInstruction::ClaimLock
+        );
+    }
+
+    #[test]
+    fn test_unpack_initialize_relayed_lock() {
+        let mut data = vec![28u8];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&1_690_000_000i64.to_le_bytes());
+
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeRelayedLock {
+                amount: 1_000,
+                unlock_timestamp: 1_700_000_000,
+                lock_id: 42,
+                valid_until: 1_690_000_000,
+            }
+        );
+        // The deadline is not optional
+        assert!(LocksmithInstruction::unpack(&data[..25]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_session_instructions() {
+        let mint = Pubkey::new_unique();
+        let mut data = vec![29u8];
+        data.extend_from_slice(mint.as_ref());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetSession {
+                mint,
+                expires_at: 1_700_000_000
+            }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..39]).is_err());
+
+        let mut data = vec![30u8];
+        data.extend_from_slice(&42u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::UnlockWithSession { lock_id: 42 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_set_lock_note() {
+        let mut data = vec![31u8];
+        data.extend_from_slice(&[7u8; MAX_NOTE_LEN]);
+        data.extend_from_slice(&(MAX_NOTE_LEN as u16).to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetLockNote {
+                note: [7u8; MAX_NOTE_LEN],
+                note_len: MAX_NOTE_LEN as u16,
+            }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..MAX_NOTE_LEN + 2]).is_err());
+
+        // Longer than the note buffer
+        let len = data.len();
+        data[len - 2..].copy_from_slice(&(MAX_NOTE_LEN as u16 + 1).to_le_bytes());
+        assert!(LocksmithInstruction::unpack(&data).is_err());
+    }
+
+    #[test]
+    fn test_unpack_early_unlock_instructions() {
+        let mut data = vec![45u8];
+        data.extend_from_slice(&500u16.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetEarlyUnlockPenalty { penalty_bps: 500 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..2]).is_err());
+
+        let mut data = vec![46u8];
+        data.extend_from_slice(&7u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::EmergencyUnlock { lock_id: 7 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+
+        assert_eq!(
+            LocksmithInstruction::unpack(&[47u8]).unwrap(),
+            LocksmithInstruction::WithdrawPenalties
+        );
+    }
+
+    #[test]
+    fn test_unpack_vesting_instructions() {
+        let mut data = vec![42u8];
+        data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(&1_800_000_000i64.to_le_bytes());
+        data.extend_from_slice(&9u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeVesting {
+                total_amount: 1_000_000,
+                start_timestamp: 1_700_000_000,
+                end_timestamp: 1_800_000_000,
+                vesting_id: 9,
+                cliff_timestamp: 0,
+                revocable: false,
+                fee_in_lamports: false,
+            }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..32]).is_err());
+
+        data.extend_from_slice(&1_725_000_000i64.to_le_bytes());
+        assert!(matches!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeVesting {
+                cliff_timestamp: 1_725_000_000,
+                revocable: false,
+                ..
+            }
+        ));
+
+        data.push(1);
+        assert!(matches!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeVesting {
+                revocable: true,
+                fee_in_lamports: false,
+                ..
+            }
+        ));
+
+        data.push(1);
+        assert!(matches!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeVesting {
+                fee_in_lamports: true,
+                ..
+            }
+        ));
+
+        let mut data = vec![43u8];
+        data.extend_from_slice(&9u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::ClaimVested { vesting_id: 9 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+
+        data[0] = 44;
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::RevokeVesting { vesting_id: 9 }
+        );
+    }
+
+    #[test]
+    fn test_relayed_lock_message_layout() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let message = relayed_lock_message(&program_id, &owner, &mint, 1_000, 2_000, 7, 1_500);
+
+        assert_eq!(message.len(), 25 + 96 + 32);
+        assert_eq!(&message[..25], b"locksmith-relayed-lock-v1");
+        assert_eq!(&message[25..57], program_id.as_ref());
+        assert_eq!(&message[57..89], owner.as_ref());
+        assert_eq!(&message[89..121], mint.as_ref());
+        assert_eq!(u64::from_le_bytes(message[121..129].try_into().unwrap()), 1_000);
+        assert_eq!(i64::from_le_bytes(message[129..137].try_into().unwrap()), 2_000);
+        assert_eq!(u64::from_le_bytes(message[137..145].try_into().unwrap()), 7);
+        assert_eq!(i64::from_le_bytes(message[145..153].try_into().unwrap()), 1_500);
+    }
+
+    #[test]
+    fn test_claim_lock_message_layout() {
+        let program_id = Pubkey::new_unique();
+        let lock = Pubkey::new_unique();
+        let claimant_token_account = Pubkey::new_unique();
+        let message = claim_lock_message(&program_id, &lock, &claimant_token_account);
+
+        assert_eq!(message.len(), 23 + 96);
+        assert_eq!(&message[..23], b"locksmith-claim-lock-v1");
+        assert_eq!(&message[23..55], program_id.as_ref());
+        assert_eq!(&message[55..87], lock.as_ref());
+        assert_eq!(&message[87..119], claimant_token_account.as_ref());
+    }
+
+    #[test]
+    fn test_unpack_compliance_instructions() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[6u8]).unwrap(),
+            LocksmithInstruction::SetComplianceAuthority
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[7u8]).unwrap(),
+            LocksmithInstruction::FreezeLock
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[8u8]).unwrap(),
+            LocksmithInstruction::UnfreezeLock
+        );
+    }
+
+    #[test]
+    fn test_unpack_template_instructions() {
+        let mut data = vec![10u8];
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.extend_from_slice(&86_400i64.to_le_bytes());
+        data.extend_from_slice(&50_000u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetLockTemplate {
+                template_id: 7,
+                duration_seconds: 86_400,
+                fee_usdc: 50_000,
+                options: LockOptions::default(),
+            }
+        );
+
+        let mut data = vec![11u8];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&7u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeLockFromTemplate {
+                amount: 1_000,
+                lock_id: 42,
+                template_id: 7,
+                valid_until: 0,
+            }
+        );
+
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeLockFromTemplate {
+                amount: 1_000,
+                lock_id: 42,
+                template_id: 7,
+                valid_until: 1_700_000_000,
+            }
+        );
+
+        assert!(LocksmithInstruction::unpack(&[10u8; 24]).is_err());
+        assert!(LocksmithInstruction::unpack(&[11u8; 24]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_standing_order_instructions() {
+        let mut data = vec![12u8];
+        data.extend_from_slice(&5u64.to_le_bytes());
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&2_592_000i64.to_le_bytes());
+        data.extend_from_slice(&31_536_000i64.to_le_bytes());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(&12u32.to_le_bytes());
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::CreateStandingOrder {
+                order_id: 5,
+                params: StandingOrderParams {
+                    amount_per_lock: 100,
+                    interval_seconds: 2_592_000,
+                    lock_duration_seconds: 31_536_000,
+                    start_at: 1_700_000_000,
+                    executions: 12,
+                    first_lock_id: 1_000,
+                },
+            }
+        );
+
+        data.pop();
+        assert!(LocksmithInstruction::unpack(&data).is_err());
+
+        assert_eq!(
+            LocksmithInstruction::unpack(&[13u8]).unwrap(),
+            LocksmithInstruction::ExecuteStandingOrder
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[14u8]).unwrap(),
+            LocksmithInstruction::CancelStandingOrder
+        );
+    }
+
+    #[test]
+    fn test_unpack_forwarding_instructions() {
+        let mut data = vec![15u8];
+        data.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        data.extend_from_slice(&[8, 1]);
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetForwardingDestination {
+                instruction_prefix: [1, 2, 3, 4, 5, 6, 7, 8],
+                prefix_len: 8,
+                approved: true,
+            }
+        );
+
+        // Prefix longer than its 8-byte buffer
+        data[9] = 9;
+        assert!(LocksmithInstruction::unpack(&data).is_err());
+        assert!(LocksmithInstruction::unpack(&data[..10]).is_err());
+
+        assert_eq!(
+            LocksmithInstruction::unpack(&[16u8]).unwrap(),
+            LocksmithInstruction::SetLockForwarding
+        );
+    }
+
+    #[test]
+    fn test_unpack_set_hook() {
+        let mut data = vec![17u8];
+        data.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        data.extend_from_slice(&[8, 3]);
+        data.extend_from_slice(&20_000u32.to_le_bytes());
+        data.push(1);
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetHook {
+                instruction_prefix: [1, 2, 3, 4, 5, 6, 7, 8],
+                prefix_len: 8,
+                events: 3,
+                compute_allowance: 20_000,
+                approved: true,
+            }
+        );
+
+        assert!(LocksmithInstruction::unpack(&data[..15]).is_err());
+        data[9] = 9;
+        assert!(LocksmithInstruction::unpack(&data).is_err());
+    }
+
+    #[test]
+    fn test_unpack_index_instructions() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[32u8]).unwrap(),
+            LocksmithInstruction::IndexLock
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[33u8]).unwrap(),
+            LocksmithInstruction::UnindexLock
+        );
+    }
+
+    #[test]
+    fn test_unpack_withdraw_surplus_rent() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[34u8]).unwrap(),
+            LocksmithInstruction::WithdrawSurplusRent
+        );
+    }
+
+    #[test]
+    fn test_unpack_lock_migration_instructions() {
+        let successor_program = Pubkey::new_unique();
+        let mut data = vec![35u8];
+        data.extend_from_slice(successor_program.as_ref());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetSuccessorProgram { successor_program }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..32]).is_err());
+
+        assert_eq!(
+            LocksmithInstruction::unpack(&[36u8]).unwrap(),
+            LocksmithInstruction::MigrateLock
+        );
+    }
+
+    #[test]
+    fn test_unpack_sunset() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[37u8]).unwrap(),
+            LocksmithInstruction::Sunset
+        );
+    }
+
+    #[test]
+    fn test_unpack_lock_certificate_instructions() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[38u8]).unwrap(),
+            LocksmithInstruction::PublishLockCertificate
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[39u8]).unwrap(),
+            LocksmithInstruction::CloseLockCertificate
+        );
+    }
+
+    #[test]
+    fn test_unpack_extend_lock() {
+        let mut data = vec![40u8];
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&1_900_000_000i64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::ExtendLock {
+                lock_id: 42,
+                new_unlock_timestamp: 1_900_000_000,
+            }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..16]).is_err());
+
+        data[0] = 41;
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::IncreaseLockAmount {
+                lock_id: 42,
+                additional_amount: 1_900_000_000,
+            }
+        );
+
+        data[0] = 48;
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::Relock {
+                lock_id: 42,
+                new_unlock_timestamp: 1_900_000_000,
+            }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..16]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_collateral_instructions() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[19u8, 1]).unwrap(),
+            LocksmithInstruction::SetLender { approved: true }
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[20u8, 0]).unwrap(),
+            LocksmithInstruction::SetCollateralized {
+                collateralized: false
+            }
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[21u8]).unwrap(),
+            LocksmithInstruction::VerifyCollateral
+        );
+        assert!(LocksmithInstruction::unpack(&[19u8]).is_err());
+        assert!(LocksmithInstruction::unpack(&[20u8]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_fee_mint_instructions() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[23u8]).unwrap(),
+            LocksmithInstruction::ProposeFeeMint
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[24u8]).unwrap(),
+            LocksmithInstruction::MigrateFeeMint
+        );
+    }
+
+    #[test]
+    fn test_unpack_sweep_abandoned_lock() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[18u8]).unwrap(),
+            LocksmithInstruction::SweepAbandonedLock
+        );
+    }
+
+    #[test]
+    fn test_unpack_crank_unlock() {
+        let mut data = vec![49u8];
+        data.extend_from_slice(&42u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::CrankUnlock { lock_id: 42 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_batch_instructions() {
+        let mut data = vec![50u8];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&1_800_000_000i64.to_le_bytes());
+        data.extend_from_slice(&7u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeLockBatch {
+                amount: 1_000,
+                unlock_timestamp: 1_800_000_000,
+                lock_id: 7,
+            }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..24]).is_err());
+
+        assert_eq!(
+            LocksmithInstruction::unpack(&[51u8, 1]).unwrap(),
+            LocksmithInstruction::SetBatchFeeMode { per_lock: true }
+        );
+        assert!(LocksmithInstruction::unpack(&[51u8]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_receipt_instructions() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[52u8]).unwrap(),
+            LocksmithInstruction::MintLockReceipt {
+                transferable: false,
+                nonce: 0
+            }
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[52u8, 1]).unwrap(),
+            LocksmithInstruction::MintLockReceipt {
+                transferable: true,
+                nonce: 0
+            }
+        );
+        let mut data = vec![52u8, 0];
+        data.extend_from_slice(&7u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::MintLockReceipt {
+                transferable: false,
+                nonce: 7
+            }
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[53u8]).unwrap(),
+            LocksmithInstruction::BurnLockReceipt { nonce: 0 }
+        );
+        let mut data = vec![53u8];
+        data.extend_from_slice(&7u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::BurnLockReceipt { nonce: 7 }
+        );
+
+        let mut data = vec![54u8];
+        data.extend_from_slice(&9u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::UnlockWithReceipt { lock_id: 9 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+    }
 
-    /// Withdraw accumulated USDC fees to admin's wallet.
-    #[account(0, signer, name = "admin", desc = "Admin withdrawing fees")]
-    #[account(1, name = "config", desc = "Config account for admin verification")]
-    #[account(2, writable, name = "fee_vault", desc = "Fee vault holding USDC fees")]
-    #[account(3, writable, name = "admin_token_account", desc = "Admin's USDC token account")]
-    #[account(4, name = "token_program", desc = "SPL Token program")]
-    WithdrawFees,
+    #[test]
+    fn test_unpack_update_lock_metadata() {
+        let mut label = [0u8; 32];
+        label[..10].copy_from_slice(b"team cliff");
+        let mut data = vec![55u8];
+        data.extend_from_slice(&label);
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::UpdateLockMetadata { label }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..32]).is_err());
+    }
 
-    /// Create a new token lock.
-    /// Locks SPL tokens until a specified Unix timestamp.
-    /// Charges a 0.15 USDC fee.
-    #[account(0, signer, writable, name = "owner", desc = "Lock owner who pays for creation")]
-    #[account(1, writable, name = "owner_token_account", desc = "Owner's token account for the locked mint")]
-    #[account(2, writable, name = "owner_usdc_account", desc = "Owner's USDC account for fee payment")]
-    #[account(3, name = "mint", desc = "Token mint being locked")]
-    #[account(4, writable, name = "lock_account", desc = "Lock PDA to be created")]
-    #[account(5, writable, name = "lock_token_account", desc = "Lock's token escrow account")]
-    #[account(6, writable, name = "fee_vault", desc = "Fee vault to receive USDC fee")]
-    #[account(7, name = "token_program", desc = "SPL Token program")]
-    #[account(8, name = "system_program", desc = "System program")]
-    InitializeLock {
-        amount: u64,
-        unlock_timestamp: i64,
-        lock_id: u64,
-    },
+    #[test]
+    fn test_unpack_claim_as_fallback() {
+        let mut data = vec![56u8];
+        data.extend_from_slice(&3u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::ClaimAsFallback { lock_id: 3 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+    }
 
-    /// Unlock tokens after the unlock timestamp has passed.
-    /// Returns tokens to the owner and closes the lock account.
-    #[account(0, signer, writable, name = "owner", desc = "Lock owner receiving tokens")]
-    #[account(1, writable, name = "owner_token_account", desc = "Destination for unlocked tokens")]
-    #[account(2, writable, name = "lock_account", desc = "Lock account to be closed")]
-    #[account(3, writable, name = "lock_token_account", desc = "Lock's token account to be closed")]
-    #[account(4, name = "token_program", desc = "SPL Token program")]
-    Unlock { lock_id: u64 },
-}
+    #[test]
+    fn test_unpack_set_lock_shares() {
+        let mut beneficiaries = [Pubkey::default(); 8];
+        beneficiaries[0] = Pubkey::new_unique();
+        beneficiaries[1] = Pubkey::new_unique();
+        let mut share_bps = [0u16; 8];
+        share_bps[0] = 7_500;
+        share_bps[1] = 2_500;
 
-impl LocksmithInstruction {
-    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
-        let (&tag, rest) = input
-            .split_first()
-            .ok_or(LocksmithError::InvalidInstruction)?;
+        let mut data = vec![57u8];
+        for beneficiary in &beneficiaries {
+            data.extend_from_slice(beneficiary.as_ref());
+        }
+        for bps in share_bps {
+            data.extend_from_slice(&bps.to_le_bytes());
+        }
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetLockShares {
+                beneficiaries,
+                share_bps
+            }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..272]).is_err());
+        assert_eq!(
+            LocksmithInstruction::unpack(&[58u8]).unwrap(),
+            LocksmithInstruction::ClaimShare
+        );
+    }
 
-        Ok(match tag {
-            0 => Self::InitializeConfig,
-            1 => Self::TransferAdmin,
-            2 => Self::WithdrawFees,
-            3 => {
-                if rest.len() < 24 {
-                    return Err(LocksmithError::InvalidInstruction.into());
-                }
-                let amount = u64::from_le_bytes(rest[0..8].try_into().unwrap());
-                let unlock_timestamp = i64::from_le_bytes(rest[8..16].try_into().unwrap());
-                let lock_id = u64::from_le_bytes(rest[16..24].try_into().unwrap());
-                Self::InitializeLock {
-                    amount,
-                    unlock_timestamp,
-                    lock_id,
-                }
+    #[test]
+    fn test_unpack_unlock_approvals() {
+        let mut approvers = [Pubkey::default(); 8];
+        approvers[0] = Pubkey::new_unique();
+        approvers[1] = Pubkey::new_unique();
+        approvers[2] = Pubkey::new_unique();
+
+        let mut data = vec![59u8];
+        for approver in &approvers {
+            data.extend_from_slice(approver.as_ref());
+        }
+        data.push(2);
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetUnlockApprovers {
+                approvers,
+                threshold: 2
             }
-            4 => {
-                if rest.len() < 8 {
-                    return Err(LocksmithError::InvalidInstruction.into());
-                }
-                let lock_id = u64::from_le_bytes(rest[0..8].try_into().unwrap());
-                Self::Unlock { lock_id }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..257]).is_err());
+
+        assert_eq!(
+            LocksmithInstruction::unpack(&[60u8]).unwrap(),
+            LocksmithInstruction::ApproveUnlock
+        );
+
+        let mut data = vec![61u8];
+        data.extend_from_slice(&4u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::ExecuteUnlock { lock_id: 4 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_approve_milestone() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[62u8, 1]).unwrap(),
+            LocksmithInstruction::ApproveMilestone { approved: true }
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[62u8, 0]).unwrap(),
+            LocksmithInstruction::ApproveMilestone { approved: false }
+        );
+        assert!(LocksmithInstruction::unpack(&[62u8]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_release_escrow() {
+        let mut data = vec![63u8];
+        data.extend_from_slice(&9u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::ReleaseEscrow { lock_id: 9 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_weight_snapshot_instructions() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[64u8]).unwrap(),
+            LocksmithInstruction::SnapshotWeight
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[65u8]).unwrap(),
+            LocksmithInstruction::CloseWeightSnapshot
+        );
+    }
+
+    #[test]
+    fn test_unpack_set_paused() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[66u8, 1]).unwrap(),
+            LocksmithInstruction::SetPaused { paused: true }
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[66u8, 0]).unwrap(),
+            LocksmithInstruction::SetPaused { paused: false }
+        );
+        assert!(LocksmithInstruction::unpack(&[66u8]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_mint_policy_instructions() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[67u8, 1]).unwrap(),
+            LocksmithInstruction::SetMintPolicy { allowed: true }
+        );
+        assert!(LocksmithInstruction::unpack(&[67u8]).is_err());
+        assert_eq!(
+            LocksmithInstruction::unpack(&[68u8]).unwrap(),
+            LocksmithInstruction::CloseMintPolicy
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[69u8, MINT_POLICY_DENYLIST]).unwrap(),
+            LocksmithInstruction::SetMintPolicyMode {
+                mode: MINT_POLICY_DENYLIST
             }
-            _ => return Err(LocksmithError::InvalidInstruction.into()),
-        })
+        );
+        assert!(LocksmithInstruction::unpack(&[69u8, 3]).is_err());
+        assert!(LocksmithInstruction::unpack(&[69u8]).is_err());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_unpack_set_reject_freezable_mints() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[70u8, 1]).unwrap(),
+            LocksmithInstruction::SetRejectFreezableMints { reject: true }
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[70u8, 0]).unwrap(),
+            LocksmithInstruction::SetRejectFreezableMints { reject: false }
+        );
+        assert!(LocksmithInstruction::unpack(&[70u8]).is_err());
+    }
 
-    // ============================================================================
-    // INSTRUCTION PARSING TESTS
-    // ============================================================================
+    #[test]
+    fn test_unpack_lock_proof_instructions() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[71u8]).unwrap(),
+            LocksmithInstruction::RecordLockProof
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[72u8]).unwrap(),
+            LocksmithInstruction::CloseLockProof
+        );
+    }
 
     #[test]
-    fn test_unpack_initialize_config() {
-        let data = [0u8];
-        let instruction = LocksmithInstruction::unpack(&data).unwrap();
-        assert_eq!(instruction, LocksmithInstruction::InitializeConfig);
+    fn test_unpack_split_unlock_instructions() {
+        let mut data = vec![73u8];
+        data.extend_from_slice(&9u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::UnlockWithoutClosing { lock_id: 9 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+        assert_eq!(
+            LocksmithInstruction::unpack(&[74u8]).unwrap(),
+            LocksmithInstruction::CloseLock
+        );
     }
 
     #[test]
-    fn test_unpack_transfer_admin() {
-        let data = [1u8];
-        let instruction = LocksmithInstruction::unpack(&data).unwrap();
-        assert_eq!(instruction, LocksmithInstruction::TransferAdmin);
+    fn test_unpack_unlock_to_associated_account() {
+        let mut data = vec![75u8];
+        data.extend_from_slice(&3u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::UnlockToAssociatedAccount { lock_id: 3 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..5]).is_err());
     }
 
     #[test]
-    fn test_unpack_withdraw_fees() {
-        let data = [2u8];
-        let instruction = LocksmithInstruction::unpack(&data).unwrap();
-        assert_eq!(instruction, LocksmithInstruction::WithdrawFees);
+    fn test_unpack_sweep_excess() {
+        let mut data = vec![76u8];
+        data.extend_from_slice(&11u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SweepExcess { lock_id: 11 }
+        );
+        assert!(LocksmithInstruction::unpack(&[76u8]).is_err());
     }
 
     #[test]
-    fn test_unpack_initialize_lock() {
-        let amount: u64 = 1_000_000;
-        let unlock_timestamp: i64 = 1700000000;
-        let lock_id: u64 = 42;
+    fn test_unpack_recover_stranded_tokens() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[77u8]).unwrap(),
+            LocksmithInstruction::RecoverStrandedTokens
+        );
+    }
 
-        let mut data = vec![3u8];
-        data.extend_from_slice(&amount.to_le_bytes());
-        data.extend_from_slice(&unlock_timestamp.to_le_bytes());
-        data.extend_from_slice(&lock_id.to_le_bytes());
+    #[test]
+    fn test_unpack_update_fee_config() {
+        let mut data = vec![78u8];
+        data.extend_from_slice(&250_000u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::UpdateFeeConfig { new_fee: 250_000 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+    }
 
-        let instruction = LocksmithInstruction::unpack(&data).unwrap();
+    #[test]
+    fn test_unpack_set_percentage_fee() {
+        let mut data = vec![79u8];
+        data.extend_from_slice(&50u16.to_le_bytes());
         assert_eq!(
-            instruction,
-            LocksmithInstruction::InitializeLock {
-                amount,
-                unlock_timestamp,
-                lock_id
-            }
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetPercentageFee { fee_bps: 50 }
         );
+        assert!(LocksmithInstruction::unpack(&data[..2]).is_err());
     }
 
     #[test]
-    fn test_unpack_unlock() {
-        let lock_id: u64 = 42;
+    fn test_unpack_fee_cap_instructions() {
+        let mut data = vec![85u8];
+        data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetFeeCap { cap: 1_000_000 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+        assert_eq!(
+            LocksmithInstruction::unpack(&[86u8]).unwrap(),
+            LocksmithInstruction::CloseFeeCap
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[87u8]).unwrap(),
+            LocksmithInstruction::WithdrawTokenFees
+        );
+    }
 
-        let mut data = vec![4u8];
-        data.extend_from_slice(&lock_id.to_le_bytes());
+    #[test]
+    fn test_unpack_lock_cap_instructions() {
+        let mut data = vec![88u8];
+        data.extend_from_slice(&25u32.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetMaxLocksPerOwner { max_locks: 25 }
+        );
 
-        let instruction = LocksmithInstruction::unpack(&data).unwrap();
-        assert_eq!(instruction, LocksmithInstruction::Unlock { lock_id });
+        data[0] = 89;
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetOwnerLockCap { lock_cap: 25 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..4]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_sol_fee_instructions() {
+        let mut data = vec![80u8];
+        data.extend_from_slice(&2_000_000u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetSolFee {
+                fee_lamports: 2_000_000
+            }
+        );
+
+        data[0] = 81;
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::WithdrawSolFees { amount: 2_000_000 }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..8]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_fee_exemption_instructions() {
+        assert_eq!(
+            LocksmithInstruction::unpack(&[82u8]).unwrap(),
+            LocksmithInstruction::SetFeeExemption
+        );
+        assert_eq!(
+            LocksmithInstruction::unpack(&[83u8]).unwrap(),
+            LocksmithInstruction::CloseFeeExemption
+        );
+    }
+
+    #[test]
+    fn test_unpack_set_fee_schedule() {
+        let mut data = vec![84u8, 2];
+        for value in 0..24u64 {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::SetFeeSchedule {
+                tier_count: 2,
+                min_durations: [0, 1, 2, 3, 4, 5, 6, 7],
+                min_amounts: [8, 9, 10, 11, 12, 13, 14, 15],
+                fees_usdc: [16, 17, 18, 19, 20, 21, 22, 23],
+            }
+        );
+        assert!(LocksmithInstruction::unpack(&data[..193]).is_err());
     }
 
     // ============================================================================
@@ -169,10 +3347,155 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_anchor_discriminators_golden_vectors() {
+        let names = [
+            "initialize_config",
+            "transfer_admin",
+            "withdraw_fees",
+            "initialize_lock",
+            "unlock",
+            "audit_lock",
+            "set_compliance_authority",
+            "freeze_lock",
+            "unfreeze_lock",
+            "initialize_lock_with_options",
+            "set_lock_template",
+            "initialize_lock_from_template",
+            "create_standing_order",
+            "execute_standing_order",
+            "cancel_standing_order",
+            "set_forwarding_destination",
+            "set_lock_forwarding",
+            "set_hook",
+            "sweep_abandoned_lock",
+            "set_lender",
+            "set_collateralized",
+            "verify_collateral",
+            "initialize_lock_for_duration",
+            "propose_fee_mint",
+            "migrate_fee_mint",
+            "initialize_lock_with_payer",
+            "initialize_gift_lock",
+            "claim_lock",
+            "initialize_relayed_lock",
+            "set_session",
+            "unlock_with_session",
+            "set_lock_note",
+            "index_lock",
+            "unindex_lock",
+            "withdraw_surplus_rent",
+            "set_successor_program",
+            "migrate_lock",
+            "sunset",
+            "publish_lock_certificate",
+            "close_lock_certificate",
+            "extend_lock",
+            "increase_lock_amount",
+            "initialize_vesting",
+            "claim_vested",
+            "revoke_vesting",
+            "set_early_unlock_penalty",
+            "emergency_unlock",
+            "withdraw_penalties",
+            "relock",
+            "crank_unlock",
+            "initialize_lock_batch",
+            "set_batch_fee_mode",
+            "mint_lock_receipt",
+            "burn_lock_receipt",
+            "unlock_with_receipt",
+            "update_lock_metadata",
+            "claim_as_fallback",
+            "set_lock_shares",
+            "claim_share",
+            "set_unlock_approvers",
+            "approve_unlock",
+            "execute_unlock",
+            "approve_milestone",
+            "release_escrow",
+            "snapshot_weight",
+            "close_weight_snapshot",
+            "set_paused",
+            "set_mint_policy",
+            "close_mint_policy",
+            "set_mint_policy_mode",
+            "set_reject_freezable_mints",
+            "record_lock_proof",
+            "close_lock_proof",
+            "unlock_without_closing",
+            "close_lock",
+            "unlock_to_associated_account",
+            "sweep_excess",
+            "recover_stranded_tokens",
+            "update_fee_config",
+            "set_percentage_fee",
+            "set_sol_fee",
+            "withdraw_sol_fees",
+            "set_fee_exemption",
+            "close_fee_exemption",
+            "set_fee_schedule",
+            "set_fee_cap",
+            "close_fee_cap",
+            "withdraw_token_fees",
+            "set_max_locks_per_owner",
+            "set_owner_lock_cap",
+        ];
+        assert_eq!(names.len(), ANCHOR_DISCRIMINATORS.len());
+        for (tag, name) in names.iter().enumerate() {
+            let hash = solana_program::hash::hashv(&[b"global:", name.as_bytes()]);
+            assert_eq!(ANCHOR_DISCRIMINATORS[tag], hash.to_bytes()[..8], "{}", name);
+        }
+
+        assert_eq!(ANCHOR_DISCRIMINATORS[3], [182, 214, 195, 105, 58, 73, 81, 124]);
+        assert_eq!(ANCHOR_DISCRIMINATORS[4], [101, 155, 40, 21, 158, 189, 56, 203]);
+    }
+
+    #[test]
+    fn test_unpack_anchor_and_tag_dispatch_agree() {
+        let args: Vec<u8> = (0..=255u8).chain(0..=255u8).collect();
+        for (tag, discriminator) in ANCHOR_DISCRIMINATORS.iter().enumerate() {
+            for len in [0, 8, 24, 32, 40, 258, args.len()] {
+                let mut tagged = vec![tag as u8];
+                tagged.extend_from_slice(&args[..len]);
+                let mut anchor = discriminator.to_vec();
+                anchor.extend_from_slice(&args[..len]);
+                assert_eq!(
+                    LocksmithInstruction::unpack(&anchor),
+                    LocksmithInstruction::unpack(&tagged),
+                    "tag {}, {} bytes of data",
+                    tag,
+                    len
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_unpack_anchor_initialize_lock() {
+        let mut data = vec![182, 214, 195, 105, 58, 73, 81, 124];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&1_800_000_000i64.to_le_bytes());
+        data.extend_from_slice(&7u64.to_le_bytes());
+        assert_eq!(
+            LocksmithInstruction::unpack(&data).unwrap(),
+            LocksmithInstruction::InitializeLock {
+                amount: 1_000,
+                unlock_timestamp: 1_800_000_000,
+                lock_id: 7,
+                valid_until: 0,
+            }
+        );
+
+        // An unknown 8-byte prefix falls back to its first byte as the tag
+        data[1] = 0;
+        assert!(LocksmithInstruction::unpack(&data).is_err());
+    }
+
     #[test]
     fn test_unpack_invalid_tag_returns_error() {
         // Test all invalid tags
-        for invalid_tag in [5u8, 6, 100, 255] {
+        for invalid_tag in [90u8, 100, 255] {
             let data = [invalid_tag];
             let result = LocksmithInstruction::unpack(&data);
             assert!(
@@ -248,7 +3571,8 @@ mod tests {
             LocksmithInstruction::InitializeLock {
                 amount,
                 unlock_timestamp,
-                lock_id
+                lock_id,
+                valid_until: 0,
             }
         );
     }
@@ -270,7 +3594,8 @@ mod tests {
             LocksmithInstruction::InitializeLock {
                 amount,
                 unlock_timestamp,
-                lock_id
+                lock_id,
+                valid_until: 0,
             }
         );
     }
@@ -293,7 +3618,8 @@ mod tests {
             LocksmithInstruction::InitializeLock {
                 amount,
                 unlock_timestamp,
-                lock_id
+                lock_id,
+                valid_until: 0,
             }
         );
     }
@@ -342,7 +3668,7 @@ mod tests {
         data.extend_from_slice(&amount.to_le_bytes());
         data.extend_from_slice(&unlock_timestamp.to_le_bytes());
         data.extend_from_slice(&lock_id.to_le_bytes());
-        // Add extra garbage data
+        // Bytes after `valid_until` are ignored
         data.extend_from_slice(&[0xFF; 100]);
 
         let instruction = LocksmithInstruction::unpack(&data).unwrap();
@@ -351,7 +3677,8 @@ mod tests {
             LocksmithInstruction::InitializeLock {
                 amount,
                 unlock_timestamp,
-                lock_id
+                lock_id,
+                valid_until: -1,
             }
         );
     }
@@ -390,6 +3717,7 @@ mod tests {
                 amount,
                 unlock_timestamp,
                 lock_id,
+                ..
             } => {
                 assert_eq!(amount, 0x0102030405060708);
                 assert_eq!(unlock_timestamp, 0x090A0B0C0D0E0F10_u64 as i64);
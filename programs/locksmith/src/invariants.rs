@@ -0,0 +1,70 @@
+//! Extra runtime assertions, compiled in only with the `strict-invariants`
+//! feature.
+//!
+//! Devnet and staging builds enable the feature so integration tests catch
+//! balance or lamport regressions right after the CPI that caused them.
+//! Mainnet builds leave it off and pay no compute for these checks.
+
+use std::fmt::Debug;
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    program_pack::Pack,
+};
+use spl_token::state::Account as TokenAccount;
+
+use crate::error::LocksmithError;
+
+/// Reads the token balance of an SPL token account.
+pub fn token_amount(info: &AccountInfo) -> Result<u64, ProgramError> {
+    Ok(TokenAccount::unpack(&info.data.borrow())?.amount)
+}
+
+/// Fails with `InconsistentState` if `actual != expected`, logging both.
+pub fn expect_eq<T: PartialEq + Debug>(what: &str, actual: T, expected: T) -> ProgramResult {
+    if actual != expected {
+        msg!(
+            "Invariant violated: {} is {:?}, expected {:?}",
+            what,
+            actual,
+            expected
+        );
+        return Err(LocksmithError::InconsistentState.into());
+    }
+    Ok(())
+}
+
+/// Fails with `InconsistentState` unless a closed account holds no lamports
+/// and its data is zeroed.
+pub fn expect_closed(what: &str, lamports: u64, data: &[u8]) -> ProgramResult {
+    expect_eq(what, lamports, 0)?;
+    if data.iter().any(|&b| b != 0) {
+        msg!("Invariant violated: {} data not zeroed", what);
+        return Err(LocksmithError::InconsistentState.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expect_eq() {
+        assert!(expect_eq("value", 1u64, 1u64).is_ok());
+        assert_eq!(
+            expect_eq("value", 1u64, 2u64).unwrap_err(),
+            ProgramError::Custom(LocksmithError::InconsistentState as u32)
+        );
+    }
+
+    #[test]
+    fn test_expect_closed() {
+        let mut data = [0u8; 8];
+        assert!(expect_closed("account", 0, &data).is_ok());
+        assert!(expect_closed("account", 1, &data).is_err());
+
+        data[3] = 1;
+        assert!(expect_closed("account", 0, &data).is_err());
+    }
+}
@@ -1,5 +1,8 @@
 pub mod error;
+pub mod events;
 pub mod instruction;
+#[cfg(feature = "strict-invariants")]
+pub mod invariants;
 pub mod processor;
 pub mod state;
 
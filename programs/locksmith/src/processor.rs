@@ -1,7 +1,11 @@
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
+    compute_units::sol_remaining_compute_units,
+    ed25519_program,
     entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
@@ -11,13 +15,38 @@ use solana_program::{
     sysvar::Sysvar,
 };
 use solana_system_interface::instruction as system_instruction;
-use spl_token::state::Account as TokenAccount;
+use spl_token::state::{Account as TokenAccount, Mint};
 
 use crate::error::LocksmithError;
-use crate::instruction::LocksmithInstruction;
+use crate::events::{
+    AuditStatus, CollateralAttestation, ComplianceAction, ComplianceEvent, LockAuditEvent,
+};
+use crate::instruction::{
+    claim_lock_message, relayed_lock_message, LockOptions, LocksmithInstruction,
+    StandingOrderParams,
+};
+#[cfg(feature = "strict-invariants")]
+use crate::invariants;
 use crate::state::{
-    ConfigAccount, LockAccount, CONFIG_SEED, FEE_USDC, FEE_VAULT_SEED, LOCK_SEED, LOCK_TOKEN_SEED,
-    MAX_LOCK_DURATION_SECONDS, USDC_MINT,
+    ConfigAccount, FeeCapAccount, FeeExemptionAccount, FeeScheduleAccount,
+    ForwardingDestinationAccount, HookAccount, IndexEntryAccount, LenderAccount, LockAccount,
+    LockCertificateAccount, LockProofAccount, LockSharesAccount, MintIndexAccount,
+    MintPolicyAccount, NoteAccount, OwnerCounterAccount, PythPriceUpdate, SessionAccount,
+    StandingOrderAccount, TemplateAccount, UnlockApprovalsAccount, VestingLockAccount,
+    VoteWeightSnapshotAccount, ABANDONMENT_PERIOD_SECONDS, APPROVALS_SEED,
+    ASSOCIATED_TOKEN_PROGRAM_ID, BPS_DENOMINATOR, CERTIFICATE_SEED, COMPLIANCE_TIMELOCK_SECONDS,
+    CONFIG_SEED, CRANK_TIP_LAMPORTS, CREDENTIAL_SEED, FEE_CAP_SEED, FEE_EXEMPTION_SEED,
+    FEE_MINT_DECIMALS, FEE_MINT_TIMELOCK_SECONDS, FEE_SCHEDULE_SEED, FEE_USDC, FEE_VAULT_SEED,
+    FORWARDING_SEED, HOOK_EVENT_CREATED, HOOK_EVENT_UNLOCKED, HOOK_SEED, LENDER_SEED,
+    LOCK_FEE_FLAT, LOCK_FEE_PERCENTAGE, LOCK_FEE_TIERED, LOCK_FULL_BALANCE, LOCK_PROOF_SEED,
+    LOCK_SEED, LOCK_TOKEN_SEED, MAX_APPROVERS, MAX_BENEFICIARIES, MAX_EARLY_UNLOCK_PENALTY_BPS,
+    MAX_FEE_BPS, MAX_FEE_LAMPORTS, MAX_FEE_TIERS, MAX_FEE_USDC, MAX_HOOK_COMPUTE_UNITS,
+    MAX_LOCK_DURATION_EPOCHS, MAX_LOCK_DURATION_SECONDS, MAX_LOCK_DURATION_SLOTS, MAX_NOTE_LEN,
+    MAX_PRICE_AGE_SECONDS, MAX_PRICE_EXPONENT, METADATA_URI_LEN, MINT_INDEX_SEED,
+    MINT_POLICY_ALLOWLIST, MINT_POLICY_DENYLIST, MINT_POLICY_NONE, MINT_POLICY_SEED, NOTE_SEED,
+    OWNER_COUNTER_SEED, PENALTY_VAULT_SEED, PYTH_RECEIVER_PROGRAM_ID, RECEIPT_SEED, RELAY_SEED,
+    SESSION_SEED, SHARES_SEED, SOL_FEE_VAULT_SEED, STANDING_ORDER_SEED, TEMPLATE_SEED,
+    TOKEN_FEE_VAULT_SEED, TOKEN_METADATA_PROGRAM_ID, USDC_MINT, VESTING_SEED, VOTE_WEIGHT_SEED,
 };
 
 pub fn process_instruction(
@@ -30,13 +59,382 @@ pub fn process_instruction(
     match instruction {
         LocksmithInstruction::InitializeConfig => process_initialize_config(program_id, accounts),
         LocksmithInstruction::TransferAdmin => process_transfer_admin(program_id, accounts),
-        LocksmithInstruction::WithdrawFees => process_withdraw_fees(program_id, accounts),
+        LocksmithInstruction::WithdrawFees { amount } => {
+            process_withdraw_fees(program_id, accounts, amount)
+        }
         LocksmithInstruction::InitializeLock {
             amount,
             unlock_timestamp,
             lock_id,
-        } => process_initialize_lock(program_id, accounts, amount, unlock_timestamp, lock_id),
-        LocksmithInstruction::Unlock { lock_id } => process_unlock(program_id, accounts, lock_id),
+            valid_until,
+        } => process_initialize_lock(
+            program_id,
+            accounts,
+            amount,
+            unlock_timestamp,
+            lock_id,
+            LockOptions {
+                valid_until,
+                ..LockOptions::default()
+            },
+            None,
+            LockFunding::Owner,
+        ),
+        LocksmithInstruction::Unlock { lock_id } => {
+            process_unlock(program_id, accounts, lock_id, UnlockAuthority::Owner)
+        }
+        LocksmithInstruction::AuditLock => process_audit_lock(program_id, accounts),
+        LocksmithInstruction::SetComplianceAuthority => {
+            process_set_compliance_authority(program_id, accounts)
+        }
+        LocksmithInstruction::FreezeLock => process_set_lock_frozen(program_id, accounts, true),
+        LocksmithInstruction::UnfreezeLock => process_set_lock_frozen(program_id, accounts, false),
+        LocksmithInstruction::InitializeLockWithOptions {
+            amount,
+            unlock_timestamp,
+            lock_id,
+            options,
+        } => process_initialize_lock(
+            program_id,
+            accounts,
+            amount,
+            unlock_timestamp,
+            lock_id,
+            options,
+            None,
+            LockFunding::Owner,
+        ),
+        LocksmithInstruction::SetLockTemplate {
+            template_id,
+            duration_seconds,
+            fee_usdc,
+            options,
+        } => process_set_lock_template(
+            program_id,
+            accounts,
+            template_id,
+            duration_seconds,
+            fee_usdc,
+            options,
+        ),
+        LocksmithInstruction::InitializeLockFromTemplate {
+            amount,
+            lock_id,
+            template_id,
+            valid_until,
+        } => process_initialize_lock_from_template(
+            program_id,
+            accounts,
+            amount,
+            lock_id,
+            template_id,
+            valid_until,
+        ),
+        LocksmithInstruction::CreateStandingOrder { order_id, params } => {
+            process_create_standing_order(program_id, accounts, order_id, params)
+        }
+        LocksmithInstruction::ExecuteStandingOrder => {
+            process_execute_standing_order(program_id, accounts)
+        }
+        LocksmithInstruction::CancelStandingOrder => {
+            process_cancel_standing_order(program_id, accounts)
+        }
+        LocksmithInstruction::SetForwardingDestination {
+            instruction_prefix,
+            prefix_len,
+            approved,
+        } => process_set_forwarding_destination(
+            program_id,
+            accounts,
+            instruction_prefix,
+            prefix_len,
+            approved,
+        ),
+        LocksmithInstruction::SetLockForwarding => process_set_lock_forwarding(program_id, accounts),
+        LocksmithInstruction::SetHook {
+            instruction_prefix,
+            prefix_len,
+            events,
+            compute_allowance,
+            approved,
+        } => process_set_hook(
+            program_id,
+            accounts,
+            instruction_prefix,
+            prefix_len,
+            events,
+            compute_allowance,
+            approved,
+        ),
+        LocksmithInstruction::SweepAbandonedLock => {
+            process_sweep_abandoned_lock(program_id, accounts)
+        }
+        LocksmithInstruction::ClaimAsFallback { lock_id } => {
+            process_claim_as_fallback(program_id, accounts, lock_id)
+        }
+        LocksmithInstruction::SetLender { approved } => {
+            process_set_lender(program_id, accounts, approved)
+        }
+        LocksmithInstruction::SetCollateralized { collateralized } => {
+            process_set_collateralized(program_id, accounts, collateralized)
+        }
+        LocksmithInstruction::VerifyCollateral => process_verify_collateral(program_id, accounts),
+        LocksmithInstruction::ProposeFeeMint => process_propose_fee_mint(program_id, accounts),
+        LocksmithInstruction::MigrateFeeMint => process_migrate_fee_mint(program_id, accounts),
+        LocksmithInstruction::InitializeLockWithPayer {
+            amount,
+            unlock_timestamp,
+            lock_id,
+            options,
+        } => {
+            let (payer_info, accounts) = accounts
+                .split_first()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            process_initialize_lock(
+                program_id,
+                accounts,
+                amount,
+                unlock_timestamp,
+                lock_id,
+                options,
+                None,
+                LockFunding::RentPayer(payer_info),
+            )
+        }
+        LocksmithInstruction::InitializeGiftLock {
+            amount,
+            unlock_timestamp,
+            lock_id,
+            options,
+        } => {
+            let (funder_info, accounts) = accounts
+                .split_first()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            process_initialize_lock(
+                program_id,
+                accounts,
+                amount,
+                unlock_timestamp,
+                lock_id,
+                options,
+                None,
+                LockFunding::Funder(funder_info),
+            )
+        }
+        LocksmithInstruction::InitializeLockForDuration {
+            amount,
+            duration_seconds,
+            lock_id,
+            valid_until,
+        } => process_initialize_lock_for_duration(
+            program_id,
+            accounts,
+            amount,
+            duration_seconds,
+            lock_id,
+            valid_until,
+        ),
+        LocksmithInstruction::ClaimLock => process_claim_lock(program_id, accounts),
+        LocksmithInstruction::SetLockNote { note, note_len } => {
+            process_set_lock_note(program_id, accounts, &note, note_len)
+        }
+        LocksmithInstruction::IndexLock => process_index_lock(program_id, accounts),
+        LocksmithInstruction::UnindexLock => process_unindex_lock(program_id, accounts),
+        LocksmithInstruction::WithdrawSurplusRent => {
+            process_withdraw_surplus_rent(program_id, accounts)
+        }
+        LocksmithInstruction::SetSuccessorProgram { successor_program } => {
+            process_set_successor_program(program_id, accounts, successor_program)
+        }
+        LocksmithInstruction::MigrateLock => process_migrate_lock(program_id, accounts),
+        LocksmithInstruction::Sunset => process_sunset(program_id, accounts),
+        LocksmithInstruction::PublishLockCertificate => {
+            process_publish_lock_certificate(program_id, accounts)
+        }
+        LocksmithInstruction::CloseLockCertificate => {
+            process_close_lock_certificate(program_id, accounts)
+        }
+        LocksmithInstruction::ExtendLock {
+            lock_id,
+            new_unlock_timestamp,
+        } => process_extend_lock(program_id, accounts, lock_id, new_unlock_timestamp),
+        LocksmithInstruction::IncreaseLockAmount {
+            lock_id,
+            additional_amount,
+        } => process_increase_lock_amount(program_id, accounts, lock_id, additional_amount),
+        LocksmithInstruction::InitializeVesting {
+            total_amount,
+            start_timestamp,
+            end_timestamp,
+            vesting_id,
+            cliff_timestamp,
+            revocable,
+            fee_in_lamports,
+        } => process_initialize_vesting(
+            program_id,
+            accounts,
+            total_amount,
+            start_timestamp,
+            end_timestamp,
+            vesting_id,
+            cliff_timestamp,
+            revocable,
+            fee_in_lamports,
+        ),
+        LocksmithInstruction::ClaimVested { vesting_id } => {
+            process_claim_vested(program_id, accounts, vesting_id)
+        }
+        LocksmithInstruction::RevokeVesting { vesting_id } => {
+            process_revoke_vesting(program_id, accounts, vesting_id)
+        }
+        LocksmithInstruction::SetEarlyUnlockPenalty { penalty_bps } => {
+            process_set_early_unlock_penalty(program_id, accounts, penalty_bps)
+        }
+        LocksmithInstruction::EmergencyUnlock { lock_id } => {
+            process_emergency_unlock(program_id, accounts, lock_id)
+        }
+        LocksmithInstruction::WithdrawPenalties => process_withdraw_penalties(program_id, accounts),
+        LocksmithInstruction::Relock {
+            lock_id,
+            new_unlock_timestamp,
+        } => process_relock(program_id, accounts, lock_id, new_unlock_timestamp),
+        LocksmithInstruction::CrankUnlock { lock_id } => {
+            process_crank_unlock(program_id, accounts, lock_id)
+        }
+        LocksmithInstruction::InitializeLockBatch {
+            amount,
+            unlock_timestamp,
+            lock_id,
+        } => process_initialize_lock_batch(program_id, accounts, amount, unlock_timestamp, lock_id),
+        LocksmithInstruction::SetBatchFeeMode { per_lock } => {
+            process_set_batch_fee_mode(program_id, accounts, per_lock)
+        }
+        LocksmithInstruction::MintLockReceipt {
+            transferable,
+            nonce,
+        } => process_mint_lock_receipt(program_id, accounts, transferable, nonce),
+        LocksmithInstruction::BurnLockReceipt { nonce } => {
+            process_burn_lock_receipt(program_id, accounts, nonce)
+        }
+        LocksmithInstruction::UnlockWithReceipt { lock_id } => {
+            process_unlock_with_receipt(program_id, accounts, lock_id)
+        }
+        LocksmithInstruction::UpdateLockMetadata { label } => {
+            process_update_lock_metadata(program_id, accounts, label)
+        }
+        LocksmithInstruction::SetLockShares {
+            beneficiaries,
+            share_bps,
+        } => process_set_lock_shares(program_id, accounts, &beneficiaries, &share_bps),
+        LocksmithInstruction::ClaimShare => process_claim_share(program_id, accounts),
+        LocksmithInstruction::SetUnlockApprovers {
+            approvers,
+            threshold,
+        } => process_set_unlock_approvers(program_id, accounts, &approvers, threshold),
+        LocksmithInstruction::ApproveUnlock => process_approve_unlock(program_id, accounts),
+        LocksmithInstruction::ExecuteUnlock { lock_id } => {
+            process_execute_unlock(program_id, accounts, lock_id)
+        }
+        LocksmithInstruction::ApproveMilestone { approved } => {
+            process_approve_milestone(program_id, accounts, approved)
+        }
+        LocksmithInstruction::ReleaseEscrow { lock_id } => {
+            process_release_escrow(program_id, accounts, lock_id)
+        }
+        LocksmithInstruction::SnapshotWeight => process_snapshot_weight(program_id, accounts),
+        LocksmithInstruction::CloseWeightSnapshot => {
+            process_close_weight_snapshot(program_id, accounts)
+        }
+        LocksmithInstruction::SetPaused { paused } => {
+            process_set_paused(program_id, accounts, paused)
+        }
+        LocksmithInstruction::SetMintPolicy { allowed } => {
+            process_set_mint_policy(program_id, accounts, allowed)
+        }
+        LocksmithInstruction::CloseMintPolicy => process_close_mint_policy(program_id, accounts),
+        LocksmithInstruction::SetMintPolicyMode { mode } => {
+            process_set_mint_policy_mode(program_id, accounts, mode)
+        }
+        LocksmithInstruction::SetRejectFreezableMints { reject } => {
+            process_set_reject_freezable_mints(program_id, accounts, reject)
+        }
+        LocksmithInstruction::RecordLockProof => process_record_lock_proof(program_id, accounts),
+        LocksmithInstruction::CloseLockProof => process_close_lock_proof(program_id, accounts),
+        LocksmithInstruction::UnlockWithoutClosing { lock_id } => process_unlock(
+            program_id,
+            accounts,
+            lock_id,
+            UnlockAuthority::OwnerKeepingAccounts,
+        ),
+        LocksmithInstruction::CloseLock => process_close_lock(program_id, accounts),
+        LocksmithInstruction::UnlockToAssociatedAccount { lock_id } => {
+            process_unlock_to_associated_account(program_id, accounts, lock_id)
+        }
+        LocksmithInstruction::SweepExcess { lock_id } => {
+            process_sweep_excess(program_id, accounts, lock_id)
+        }
+        LocksmithInstruction::RecoverStrandedTokens => {
+            process_recover_stranded_tokens(program_id, accounts)
+        }
+        LocksmithInstruction::UpdateFeeConfig { new_fee } => {
+            process_update_fee_config(program_id, accounts, new_fee)
+        }
+        LocksmithInstruction::SetPercentageFee { fee_bps } => {
+            process_set_percentage_fee(program_id, accounts, fee_bps)
+        }
+        LocksmithInstruction::SetSolFee { fee_lamports } => {
+            process_set_sol_fee(program_id, accounts, fee_lamports)
+        }
+        LocksmithInstruction::WithdrawSolFees { amount } => {
+            process_withdraw_sol_fees(program_id, accounts, amount)
+        }
+        LocksmithInstruction::SetFeeExemption => process_set_fee_exemption(program_id, accounts),
+        LocksmithInstruction::CloseFeeExemption => {
+            process_close_fee_exemption(program_id, accounts)
+        }
+        LocksmithInstruction::SetFeeSchedule {
+            tier_count,
+            min_durations,
+            min_amounts,
+            fees_usdc,
+        } => process_set_fee_schedule(
+            program_id,
+            accounts,
+            tier_count,
+            min_durations,
+            min_amounts,
+            fees_usdc,
+        ),
+        LocksmithInstruction::SetFeeCap { cap } => process_set_fee_cap(program_id, accounts, cap),
+        LocksmithInstruction::CloseFeeCap => process_close_fee_cap(program_id, accounts),
+        LocksmithInstruction::WithdrawTokenFees => {
+            process_withdraw_token_fees(program_id, accounts)
+        }
+        LocksmithInstruction::SetMaxLocksPerOwner { max_locks } => {
+            process_set_max_locks_per_owner(program_id, accounts, max_locks)
+        }
+        LocksmithInstruction::SetOwnerLockCap { lock_cap } => {
+            process_set_owner_lock_cap(program_id, accounts, lock_cap)
+        }
+        LocksmithInstruction::SetSession { mint, expires_at } => {
+            process_set_session(program_id, accounts, &mint, expires_at)
+        }
+        LocksmithInstruction::UnlockWithSession { lock_id } => {
+            process_unlock_with_session(program_id, accounts, lock_id)
+        }
+        LocksmithInstruction::InitializeRelayedLock {
+            amount,
+            unlock_timestamp,
+            lock_id,
+            valid_until,
+        } => process_initialize_relayed_lock(
+            program_id,
+            accounts,
+            amount,
+            unlock_timestamp,
+            lock_id,
+            valid_until,
+        ),
     }
 }
 
@@ -105,6 +503,24 @@ fn process_initialize_config(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
         discriminator: ConfigAccount::DISCRIMINATOR,
         admin: *admin_info.key,
         bump: config_bump,
+        compliance_authority: Pubkey::default(),
+        pending_compliance_authority: Pubkey::default(),
+        compliance_authority_effective_at: 0,
+        fee_mint: USDC_MINT,
+        pending_fee_mint: Pubkey::default(),
+        fee_mint_effective_at: 0,
+        successor_program: Pubkey::default(),
+        sunset_at: 0,
+        early_unlock_penalty_bps: 0,
+        batch_fee_per_lock: false,
+        paused: false,
+        mint_policy_mode: MINT_POLICY_NONE,
+        reject_freezable_mints: false,
+        fee_usdc: FEE_USDC,
+        fee_bps: 0,
+        fee_lamports: 0,
+        fee_tiered: false,
+        max_locks_per_owner: 0,
     };
     config.pack(&mut config_info.data.borrow_mut());
 
@@ -168,7 +584,13 @@ fn process_transfer_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> Prog
     Ok(())
 }
 
-fn process_withdraw_fees(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+/// Withdraws `amount` of the collected fees, or all of them if 0, to any
+/// token account of the fee mint.
+fn process_withdraw_fees(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
 
     let admin_info = next_account_info(account_info_iter)?;
@@ -186,11 +608,7 @@ fn process_withdraw_fees(program_id: &Pubkey, accounts: &[AccountInfo]) -> Progr
         return Err(LocksmithError::InvalidPDA.into());
     }
 
-    let (fee_vault_pda, fee_vault_bump) =
-        Pubkey::find_program_address(&[FEE_VAULT_SEED], program_id);
-    if *fee_vault_info.key != fee_vault_pda {
-        return Err(LocksmithError::InvalidPDA.into());
-    }
+    let (fee_mint, fee_vault_bump) = load_fee_vault(program_id, fee_vault_info)?;
 
     let config = ConfigAccount::unpack(&config_info.data.borrow())?;
 
@@ -204,11 +622,17 @@ fn process_withdraw_fees(program_id: &Pubkey, accounts: &[AccountInfo]) -> Progr
     }
 
     let fee_vault = TokenAccount::unpack(&fee_vault_info.data.borrow())?;
-    let amount = fee_vault.amount;
+    let amount = if amount == 0 { fee_vault.amount } else { amount };
 
     if amount == 0 {
         return Ok(());
     }
+    if amount > fee_vault.amount {
+        return Err(LocksmithError::InsufficientFunds.into());
+    }
+
+    #[cfg(feature = "strict-invariants")]
+    let admin_token_before = invariants::token_amount(admin_token_info)?;
 
     invoke_signed(
         &spl_token::instruction::transfer(
@@ -224,19 +648,155 @@ fn process_withdraw_fees(program_id: &Pubkey, accounts: &[AccountInfo]) -> Progr
             admin_token_info.clone(),
             fee_vault_info.clone(),
         ],
-        &[&[FEE_VAULT_SEED, &[fee_vault_bump]]],
+        &[&[FEE_VAULT_SEED, fee_vault_mint_seed(&fee_mint), &[fee_vault_bump]]],
+    )?;
+
+    #[cfg(feature = "strict-invariants")]
+    {
+        invariants::expect_eq(
+            "fee vault balance",
+            Some(invariants::token_amount(fee_vault_info)?),
+            fee_vault.amount.checked_sub(amount),
+        )?;
+        invariants::expect_eq(
+            "admin token balance",
+            admin_token_before.checked_add(amount),
+            Some(invariants::token_amount(admin_token_info)?),
+        )?;
+    }
+
+    msg!("Withdrawn {} of fee mint {} to {}", amount, fee_mint, admin_token_info.key);
+    Ok(())
+}
+
+/// Moves tokens of a foreign mint out of a token account controlled by a
+/// fee vault or lock account, where mistaken transfers to those addresses
+/// end up. Neither PDA signs for anything of its own mint here, so fees and
+/// locked tokens stay out of reach.
+fn process_recover_stranded_tokens(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let stranded_token_info = next_account_info(account_info_iter)?;
+    let recovery_token_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    // A lock account signs for its escrow and a fee vault for itself
+    let (protected_mint, seeds): (Pubkey, Vec<Vec<u8>>) = if authority_info.owner == program_id {
+        let lock = LockAccount::unpack(&authority_info.data.borrow())?;
+        let seeds = vec![
+            LOCK_SEED.to_vec(),
+            lock.owner.to_bytes().to_vec(),
+            lock.mint.to_bytes().to_vec(),
+            lock.lock_id.to_le_bytes().to_vec(),
+            vec![lock.bump],
+        ];
+        (lock.mint, seeds)
+    } else {
+        let (fee_mint, bump) = load_fee_vault(program_id, authority_info)?;
+        let seeds = vec![
+            FEE_VAULT_SEED.to_vec(),
+            fee_vault_mint_seed(&fee_mint).to_vec(),
+            vec![bump],
+        ];
+        (fee_mint, seeds)
+    };
+    let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+    let authority_pda = Pubkey::create_program_address(&seeds, program_id)
+        .map_err(|_| LocksmithError::InvalidPDA)?;
+    if *authority_info.key != authority_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let stranded_token = TokenAccount::unpack(&stranded_token_info.data.borrow())?;
+    if stranded_token.owner != *authority_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if stranded_token.mint == protected_mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+    let amount = stranded_token.amount;
+    if amount == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            stranded_token_info.key,
+            recovery_token_info.key,
+            authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            stranded_token_info.clone(),
+            recovery_token_info.clone(),
+            authority_info.clone(),
+        ],
+        &[&seeds],
     )?;
 
-    msg!("Withdrawn {} USDC to admin", amount);
+    msg!(
+        "Recovered {} of mint {} from {}",
+        amount,
+        stranded_token.mint,
+        authority_info.key
+    );
     Ok(())
 }
 
-fn process_initialize_lock(
+/// Who pays for a new lock
+#[derive(Clone, Copy)]
+enum LockFunding<'b, 'a> {
+    /// The owner signs and pays for everything
+    Owner,
+    /// This signer pays the rent; the owner signs for the tokens and fee
+    RentPayer(&'b AccountInfo<'a>),
+    /// This signer pays the rent, tokens and fee; the owner need not sign
+    /// and the funder gets no control over the lock
+    Funder(&'b AccountInfo<'a>),
+    /// This signer pays the rent; the owner signed a `relayed_lock_message`
+    /// and the tokens and fee move via their relay delegate PDA
+    Relayed {
+        relayer_info: &'b AccountInfo<'a>,
+        delegate_info: &'b AccountInfo<'a>,
+        delegate_bump: u8,
+    },
+}
+
+/// Creates a lock, paid for as `funding` says, charging `fee_usdc` or, if
+/// `None`, the configured fee. An `amount` of `LOCK_FULL_BALANCE` locks the
+/// owner token account's balance at execution.
+#[allow(clippy::too_many_arguments)]
+fn process_initialize_lock<'a>(
     program_id: &Pubkey,
-    accounts: &[AccountInfo],
+    accounts: &[AccountInfo<'a>],
     amount: u64,
     unlock_timestamp: i64,
     lock_id: u64,
+    options: LockOptions,
+    fee_usdc: Option<u64>,
+    funding: LockFunding<'_, 'a>,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
 
@@ -250,7 +810,15 @@ fn process_initialize_lock(
     let token_program_info = next_account_info(account_info_iter)?;
     let system_program_info = next_account_info(account_info_iter)?;
 
-    if !owner_info.is_signer {
+    let (payer_info, authority_info) = match funding {
+        LockFunding::Owner => (owner_info, owner_info),
+        LockFunding::RentPayer(payer_info) => (payer_info, owner_info),
+        LockFunding::Funder(funder_info) => (funder_info, funder_info),
+        LockFunding::Relayed { relayer_info, .. } => (relayer_info, owner_info),
+    };
+    // A relayed owner's signature was checked against their signed message
+    let relayed = matches!(funding, LockFunding::Relayed { .. });
+    if !payer_info.is_signer || !(authority_info.is_signer || relayed) {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
@@ -268,114 +836,521 @@ fn process_initialize_lock(
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    // Validate fee vault PDA
-    let (fee_vault_pda, _) = Pubkey::find_program_address(&[FEE_VAULT_SEED], program_id);
-    if *fee_vault_info.key != fee_vault_pda {
-        return Err(LocksmithError::InvalidPDA.into());
-    }
+    let (config, fee_mint) =
+        load_config_for_new_lock(program_id, fee_vault_info, mint_info, accounts)?;
 
     let clock = Clock::get()?;
+    check_deadline(options.valid_until, clock.unix_timestamp)?;
     if unlock_timestamp <= clock.unix_timestamp {
         return Err(LocksmithError::InvalidTimestamp.into());
     }
 
-    // Validate lock duration does not exceed maximum (10 years)
+    // Validate lock duration does not exceed maximum (10 years) unless the
+    // lock never unlocks anyway
     let max_unlock_timestamp = clock
         .unix_timestamp
         .checked_add(MAX_LOCK_DURATION_SECONDS)
         .ok_or(ProgramError::ArithmeticOverflow)?;
-    if unlock_timestamp > max_unlock_timestamp {
+    if unlock_timestamp > max_unlock_timestamp && !options.permanent {
         return Err(LocksmithError::LockDurationExceeded.into());
     }
+    validate_permanent(&options)?;
+    if options.unlock_slot != 0 && options.unlock_epoch != 0 {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+    if options.unlock_slot != 0 {
+        validate_unlock_target(options.unlock_slot, clock.slot, MAX_LOCK_DURATION_SLOTS)?;
+    }
+    if options.unlock_epoch != 0 {
+        validate_unlock_target(options.unlock_epoch, clock.epoch, MAX_LOCK_DURATION_EPOCHS)?;
+    }
 
-    let lock_id_bytes = lock_id.to_le_bytes();
-    let (lock_pda, lock_bump) = Pubkey::find_program_address(
-        &[
-            LOCK_SEED,
-            owner_info.key.as_ref(),
-            mint_info.key.as_ref(),
-            &lock_id_bytes,
-        ],
+    // Lamports are wrapped into the escrow, so a relay delegate cannot
+    // stand in for the owner and there is no token balance to lock in full
+    let wrap = wraps_lamports(options.wrap_lamports, mint_info.key)?;
+    let owner_balance = if wrap {
+        if relayed {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if amount == LOCK_FULL_BALANCE {
+            return Err(LocksmithError::InvalidAmount.into());
+        }
+        if owner_token_info.key != authority_info.key {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        authority_info.lamports()
+    } else {
+        let owner_token = TokenAccount::unpack(&owner_token_info.data.borrow())?;
+        if owner_token.owner != *authority_info.key {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        if owner_token.mint != *mint_info.key {
+            return Err(LocksmithError::InvalidMint.into());
+        }
+        owner_token.amount
+    };
+
+    // An NFT lock holds the mint's single token. A full-balance lock is
+    // tiered by the whole balance and a permanent one as the longest.
+    let is_nft = is_nft_mint(&Mint::unpack(&mint_info.data.borrow())?);
+    let duration = if options.permanent {
+        u64::MAX
+    } else {
+        unlock_timestamp.abs_diff(clock.unix_timestamp)
+    };
+    let tier_amount = if amount == LOCK_FULL_BALANCE {
+        owner_balance
+    } else {
+        amount
+    };
+    let fee = lock_fee(
         program_id,
+        accounts,
+        &config,
+        mint_info.key,
+        is_nft,
+        authority_info.key,
+        fee_usdc,
+        duration,
+        tier_amount,
+        options.fee_in_lamports,
+    )?;
+    // A relay delegate cannot pay lamports for the owner
+    if relayed && fee.sol.is_some() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let LockFee {
+        usdc: fee_usdc,
+        sol: sol_fee,
+        bps_cap: fee_cap,
+        mode: fee_mode,
+    } = fee;
+
+    let fee_from_same_account = if owner_token_info.key == owner_usdc_info.key {
+        fee_usdc
+    } else {
+        0
+    };
+    let amount = resolve_lock_amount(amount, owner_balance, fee_from_same_account);
+    if amount == 0 || (is_nft && amount != 1) {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+    if owner_balance < amount {
+        return Err(LocksmithError::InsufficientFunds.into());
+    }
+
+    // With no fee in the fee mint, e.g. a percentage fee taken from the
+    // deposit into the token fee vault, the fee account goes unread and the
+    // owner token account can stand in for it
+    if fee_usdc > 0 {
+        let owner_usdc = TokenAccount::unpack(&owner_usdc_info.data.borrow())?;
+        if owner_usdc.owner != *authority_info.key {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        if owner_usdc.mint != fee_mint {
+            return Err(LocksmithError::InvalidMint.into());
+        }
+        if owner_usdc.amount < fee_usdc {
+            return Err(LocksmithError::InsufficientFunds.into());
+        }
+    }
+
+    // The percentage fee comes out of the deposit, so the lock holds the rest
+    let token_fee = if let Some(cap) = fee_cap {
+        Some(load_bps_fee(program_id, accounts, &config, cap, mint_info.key, amount)?)
+    } else {
+        None
+    };
+    let locked_amount = amount - token_fee.map_or(0, |(fee, _, _)| fee);
+    if locked_amount == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    // Gift locks, batches included, are not the owner's doing
+    let gifted = matches!(
+        funding,
+        LockFunding::Funder(funder_info) if funder_info.key != owner_info.key
     );
-    if *lock_account_info.key != lock_pda {
-        return Err(LocksmithError::InvalidPDA.into());
+    if gifted {
+        validate_gift(&options)?;
+    }
+    validate_donation(&options, owner_info.key)?;
+    validate_fallback(&options, owner_info.key)?;
+    validate_unlock_destination(&options)?;
+    validate_price_condition(&options)?;
+    validate_arbiter(&options, owner_info.key)?;
+    validate_counterparty(&options, owner_info.key)?;
+    // An emergency unlock skips the credential check and hook callback
+    if options.early_unlock_allowed
+        && (options.credential_issuer != Pubkey::default() || options.hook != Pubkey::default())
+    {
+        return Err(LocksmithError::EarlyUnlockNotAllowed.into());
+    }
+    if options.claim_key != [0u8; 32] {
+        validate_claim_code(&options, unlock_timestamp, max_unlock_timestamp)?;
     }
 
-    let (lock_token_pda, lock_token_bump) =
-        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
-    if *lock_token_info.key != lock_token_pda {
-        return Err(LocksmithError::InvalidPDA.into());
+    let hook = if options.hook != Pubkey::default() {
+        let hook_info = accounts.get(9).ok_or(LocksmithError::InvalidHook)?;
+        Some(load_hook(program_id, hook_info, &options.hook)?.ok_or(LocksmithError::InvalidHook)?)
+    } else {
+        None
+    };
+
+    #[cfg(feature = "strict-invariants")]
+    let fee_vault_before = invariants::token_amount(fee_vault_info)?;
+    #[cfg(feature = "strict-invariants")]
+    let token_fee_vault_before = match token_fee {
+        Some((_, info, _)) if !info.data_is_empty() => invariants::token_amount(info)?,
+        _ => 0,
+    };
+
+    // Gift locks do not take up the owner's lock cap
+    let owner_counted = !gifted
+        && count_owner_lock(
+            program_id,
+            accounts,
+            &config,
+            owner_info.key,
+            payer_info,
+            system_program_info,
+        )?;
+
+    let lock_bump = create_lock_accounts(
+        program_id,
+        payer_info,
+        owner_info.key,
+        mint_info,
+        lock_account_info,
+        lock_token_info,
+        system_program_info,
+        lock_id,
+    )?;
+
+    let lock = LockAccount {
+        discriminator: LockAccount::DISCRIMINATOR,
+        owner: *owner_info.key,
+        mint: *mint_info.key,
+        amount: locked_amount,
+        unlock_timestamp,
+        created_at: clock.unix_timestamp,
+        lock_id,
+        bump: lock_bump,
+        frozen: false,
+        credential_issuer: options.credential_issuer,
+        non_transferable: options.non_transferable,
+        forward_destination: Pubkey::default(),
+        hook: options.hook,
+        recovery_address: options.recovery_address,
+        donation_address: options.donation_address,
+        donation_bps: options.donation_bps,
+        collateral_holder: Pubkey::default(),
+        layout_version: LockAccount::LAYOUT_VERSION,
+        unlock_slot: options.unlock_slot,
+        unlock_epoch: options.unlock_epoch,
+        claim_key: options.claim_key,
+        claim_expires_at: options.claim_expires_at,
+        index_seq: 0,
+        early_unlock_allowed: options.early_unlock_allowed,
+        permanent: options.permanent,
+        is_nft,
+        receipt_bound: false,
+        label: options.label,
+        metadata_uri: options.metadata_uri,
+        fallback_owner: options.fallback_owner,
+        fallback_after: options.fallback_after,
+        unlock_destination: options.unlock_destination,
+        shared: false,
+        approvals_required: false,
+        price_feed_id: options.price_feed_id,
+        price_threshold: options.price_threshold,
+        price_exponent: options.price_exponent,
+        arbiter: options.arbiter,
+        milestone_approved: false,
+        counterparty: options.counterparty,
+        owner_counted,
+        wrapped_lamports: wrap,
+        receipt_mint: Pubkey::default(),
+        fee_mode,
+        reserved: [0u8; 29],
+    };
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    let (transfer_authority_info, delegate_bump) = match funding {
+        LockFunding::Relayed {
+            delegate_info,
+            delegate_bump,
+            ..
+        } => (delegate_info, delegate_bump),
+        _ => (authority_info, 0),
+    };
+    let delegate_bump = [delegate_bump];
+    let delegate_seeds: &[&[u8]] = &[RELAY_SEED, owner_info.key.as_ref(), &delegate_bump];
+    let signers: &[&[&[u8]]] = if relayed { &[delegate_seeds] } else { &[] };
+
+    if wrap {
+        invoke(
+            &system_instruction::transfer(authority_info.key, lock_token_info.key, amount),
+            &[
+                authority_info.clone(),
+                lock_token_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+        invoke(
+            &spl_token::instruction::sync_native(token_program_info.key, lock_token_info.key)?,
+            &[lock_token_info.clone(), token_program_info.clone()],
+        )?;
+    } else {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                owner_token_info.key,
+                lock_token_info.key,
+                transfer_authority_info.key,
+                &[],
+                amount,
+            )?,
+            &[
+                owner_token_info.clone(),
+                lock_token_info.clone(),
+                transfer_authority_info.clone(),
+            ],
+            signers,
+        )?;
     }
 
-    let owner_token = TokenAccount::unpack(&owner_token_info.data.borrow())?;
-    if owner_token.owner != *owner_info.key {
-        return Err(LocksmithError::Unauthorized.into());
+    if let Some((fee_lamports, sol_fee_vault_info)) = sol_fee {
+        invoke(
+            &system_instruction::transfer(
+                authority_info.key,
+                sol_fee_vault_info.key,
+                fee_lamports,
+            ),
+            &[
+                authority_info.clone(),
+                sol_fee_vault_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+        msg!("Fee paid in {} lamports", fee_lamports);
+    } else if fee_usdc > 0 {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                owner_usdc_info.key,
+                fee_vault_info.key,
+                transfer_authority_info.key,
+                &[],
+                fee_usdc,
+            )?,
+            &[
+                owner_usdc_info.clone(),
+                fee_vault_info.clone(),
+                transfer_authority_info.clone(),
+            ],
+            signers,
+        )?;
     }
-    if owner_token.mint != *mint_info.key {
-        return Err(LocksmithError::InvalidMint.into());
+
+    if let Some((token_fee, token_fee_vault_info, token_fee_vault_bump)) = token_fee {
+        let lock_id_bytes = lock_id.to_le_bytes();
+        pay_token_fee(
+            token_fee,
+            lock_token_info,
+            lock_account_info,
+            &[&[
+                LOCK_SEED,
+                owner_info.key.as_ref(),
+                mint_info.key.as_ref(),
+                &lock_id_bytes,
+                &[lock_bump],
+            ]],
+            token_fee_vault_info,
+            token_fee_vault_bump,
+            payer_info,
+            mint_info,
+            token_program_info,
+            system_program_info,
+        )?;
     }
-    if owner_token.amount < amount {
-        return Err(LocksmithError::InsufficientFunds.into());
+
+    #[cfg(feature = "strict-invariants")]
+    {
+        // The locked mint may itself be USDC, paid from the same account
+        let owner_spent = if owner_token_info.key == owner_usdc_info.key {
+            amount.checked_add(fee_usdc)
+        } else {
+            Some(amount)
+        };
+        invariants::expect_eq(
+            "escrow balance",
+            invariants::token_amount(lock_token_info)?,
+            locked_amount,
+        )?;
+        // A wrapping owner's lamports also paid the rent
+        if !wrap {
+            invariants::expect_eq(
+                "owner token balance",
+                owner_spent.and_then(|spent| owner_balance.checked_sub(spent)),
+                Some(invariants::token_amount(owner_token_info)?),
+            )?;
+        }
+        invariants::expect_eq(
+            "fee vault balance",
+            fee_vault_before.checked_add(fee_usdc),
+            Some(invariants::token_amount(fee_vault_info)?),
+        )?;
+        if let Some((token_fee, token_fee_vault_info, _)) =
+            token_fee.filter(|(fee, _, _)| *fee > 0)
+        {
+            invariants::expect_eq(
+                "token fee vault balance",
+                token_fee_vault_before.checked_add(token_fee),
+                Some(invariants::token_amount(token_fee_vault_info)?),
+            )?;
+        }
     }
 
-    let owner_usdc = TokenAccount::unpack(&owner_usdc_info.data.borrow())?;
-    if owner_usdc.owner != *owner_info.key {
-        return Err(LocksmithError::Unauthorized.into());
+    msg!(
+        "Lock created: {} tokens locked until {}",
+        locked_amount,
+        unlock_timestamp
+    );
+    match funding {
+        LockFunding::Owner => {}
+        LockFunding::RentPayer(payer_info) => msg!("Rent paid by {}", payer_info.key),
+        LockFunding::Funder(funder_info) => msg!("Gifted by {}", funder_info.key),
+        LockFunding::Relayed { relayer_info, .. } => msg!("Relayed by {}", relayer_info.key),
     }
-    if owner_usdc.mint != USDC_MINT {
-        return Err(LocksmithError::InvalidMint.into());
+    if options.credential_issuer != Pubkey::default() {
+        msg!("Unlock gated on credentials from {}", options.credential_issuer);
     }
-    if owner_usdc.amount < FEE_USDC {
-        return Err(LocksmithError::InsufficientFunds.into());
+    if options.non_transferable {
+        msg!("Lock is non-transferable");
+    }
+    if options.permanent {
+        msg!("Lock is permanent");
+    }
+    if wrap {
+        msg!("Wrapped {} lamports", amount);
+    }
+    if is_nft {
+        msg!("Lock holds an NFT");
+    }
+    if options.claim_key != [0u8; 32] {
+        msg!("Claimable with its claim code until {}", options.claim_expires_at);
+    }
+    if options.recovery_address != Pubkey::default() {
+        msg!("Sweepable to {} if abandoned", options.recovery_address);
+    }
+    if options.fallback_owner != Pubkey::default() {
+        msg!(
+            "Claimable by {} from {} seconds after unlock",
+            options.fallback_owner,
+            options.fallback_after
+        );
+    }
+    if options.unlock_destination != Pubkey::default() {
+        msg!("Pays out only to {}", options.unlock_destination);
+    }
+    if options.price_feed_id != [0u8; 32] {
+        msg!(
+            "Unlocks only at a price of at least {}e{}",
+            options.price_threshold,
+            options.price_exponent
+        );
+    }
+    if options.arbiter != Pubkey::default() {
+        msg!("Unlocks only once {} approves its milestone", options.arbiter);
+    }
+    if options.counterparty != Pubkey::default() {
+        msg!("Escrow for {}", options.counterparty);
+    }
+    if options.donation_bps > 0 {
+        msg!(
+            "Donating {} bps to {} at unlock",
+            options.donation_bps,
+            options.donation_address
+        );
+    }
+
+    if let Some(hook) = hook {
+        invoke_hook(
+            &hook,
+            HOOK_EVENT_CREATED,
+            lock_account_info,
+            owner_info,
+            accounts.get(10..12).unwrap_or(&[]),
+            lock_id,
+            locked_amount,
+        )?;
+    }
+    Ok(())
+}
+
+/// Creates a lock PDA and its token escrow, rent paid by `payer`, after
+/// checking both addresses against their seeds. Returns the lock's bump.
+#[allow(clippy::too_many_arguments)]
+fn create_lock_accounts<'a>(
+    program_id: &Pubkey,
+    payer_info: &AccountInfo<'a>,
+    owner: &Pubkey,
+    mint_info: &AccountInfo<'a>,
+    lock_account_info: &AccountInfo<'a>,
+    lock_token_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    lock_id: u64,
+) -> Result<u8, ProgramError> {
+    let lock_id_bytes = lock_id.to_le_bytes();
+    let (lock_pda, lock_bump) = Pubkey::find_program_address(
+        &[LOCK_SEED, owner.as_ref(), mint_info.key.as_ref(), &lock_id_bytes],
+        program_id,
+    );
+    if *lock_account_info.key != lock_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let (lock_token_pda, lock_token_bump) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
     }
 
     let rent = Rent::get()?;
 
     invoke_signed(
         &system_instruction::create_account(
-            owner_info.key,
+            payer_info.key,
             lock_account_info.key,
             rent.minimum_balance(LockAccount::SIZE),
             LockAccount::SIZE as u64,
             program_id,
         ),
         &[
-            owner_info.clone(),
+            payer_info.clone(),
             lock_account_info.clone(),
             system_program_info.clone(),
         ],
         &[&[
             LOCK_SEED,
-            owner_info.key.as_ref(),
+            owner.as_ref(),
             mint_info.key.as_ref(),
             &lock_id_bytes,
             &[lock_bump],
         ]],
     )?;
 
-    let lock = LockAccount {
-        discriminator: LockAccount::DISCRIMINATOR,
-        owner: *owner_info.key,
-        mint: *mint_info.key,
-        amount,
-        unlock_timestamp,
-        created_at: clock.unix_timestamp,
-        lock_id,
-        bump: lock_bump,
-    };
-    lock.pack(&mut lock_account_info.data.borrow_mut());
-
     invoke_signed(
         &system_instruction::create_account(
-            owner_info.key,
+            payer_info.key,
             lock_token_info.key,
             rent.minimum_balance(TokenAccount::LEN),
             TokenAccount::LEN as u64,
             &spl_token::id(),
         ),
         &[
-            owner_info.clone(),
+            payer_info.clone(),
             lock_token_info.clone(),
             system_program_info.clone(),
         ],
@@ -392,348 +1367,9955 @@ fn process_initialize_lock(
         &[lock_token_info.clone(), mint_info.clone()],
     )?;
 
-    invoke(
-        &spl_token::instruction::transfer(
-            token_program_info.key,
-            owner_token_info.key,
-            lock_token_info.key,
-            owner_info.key,
-            &[],
-            amount,
-        )?,
-        &[
-            owner_token_info.clone(),
-            lock_token_info.clone(),
-            owner_info.clone(),
-        ],
-    )?;
-
-    invoke(
-        &spl_token::instruction::transfer(
-            token_program_info.key,
-            owner_usdc_info.key,
-            fee_vault_info.key,
-            owner_info.key,
-            &[],
-            FEE_USDC,
-        )?,
-        &[
-            owner_usdc_info.clone(),
-            fee_vault_info.clone(),
-            owner_info.clone(),
-        ],
-    )?;
-
-    msg!(
-        "Lock created: {} tokens locked until {}",
-        amount,
-        unlock_timestamp
-    );
-    Ok(())
+    Ok(lock_bump)
 }
 
-/// Unlocks tokens after the unlock timestamp has passed.
-///
-/// # Destination Token Account
-///
-/// The owner may specify any token account they own (with the correct mint) as the
-/// destination for unlocked tokens. This provides flexibility for the lock owner to
-/// receive tokens in whichever of their accounts they prefer.
-fn process_unlock(program_id: &Pubkey, accounts: &[AccountInfo], lock_id: u64) -> ProgramResult {
+/// Creates or updates a lock template. Changes apply to locks created
+/// afterwards; existing locks keep the terms they were created with.
+fn process_set_lock_template(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    template_id: u64,
+    duration_seconds: i64,
+    fee_usdc: u64,
+    options: LockOptions,
+) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
 
-    let owner_info = next_account_info(account_info_iter)?;
-    let owner_token_info = next_account_info(account_info_iter)?;
-    let lock_account_info = next_account_info(account_info_iter)?;
-    let lock_token_info = next_account_info(account_info_iter)?;
-    let token_program_info = next_account_info(account_info_iter)?;
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let template_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
 
-    if !owner_info.is_signer {
+    if !admin_info.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    // Validate token program is the official SPL Token program
-    if *token_program_info.key != spl_token::id() {
+    // Validate system program is the official System program
+    if !solana_system_interface::program::check_id(system_program_info.key) {
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
 
-    if lock.owner != *owner_info.key {
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+    if config.admin != *admin_info.key {
         return Err(LocksmithError::Unauthorized.into());
     }
 
-    let lock_id_bytes = lock_id.to_le_bytes();
-    let (lock_pda, _) = Pubkey::find_program_address(
-        &[
-            LOCK_SEED,
-            owner_info.key.as_ref(),
-            lock.mint.as_ref(),
-            &lock_id_bytes,
-        ],
-        program_id,
-    );
-    if *lock_account_info.key != lock_pda {
-        return Err(LocksmithError::InvalidPDA.into());
+    validate_lock_duration(duration_seconds)?;
+    // The deadline applies to this update; it is not part of the template
+    check_deadline(options.valid_until, Clock::get()?.unix_timestamp)?;
+
+    // Template locks are created without the hook accounts, and recovery,
+    // fallback, donation and unlock destination addresses belong to each
+    // owner rather than to a template
+    if options.hook != Pubkey::default() {
+        return Err(LocksmithError::InvalidHook.into());
+    }
+    if options.recovery_address != Pubkey::default() {
+        return Err(LocksmithError::InvalidInstruction.into());
+    }
+    if options.fallback_owner != Pubkey::default() {
+        return Err(LocksmithError::InvalidFallback.into());
+    }
+    if options.unlock_destination != Pubkey::default() {
+        return Err(LocksmithError::InvalidUnlockDestination.into());
+    }
+    // Templates store no price condition, arbiter or counterparty
+    if options.price_feed_id != [0u8; 32] {
+        return Err(LocksmithError::InvalidPriceCondition.into());
+    }
+    if options.arbiter != Pubkey::default() {
+        return Err(LocksmithError::InvalidArbiter.into());
+    }
+    if options.counterparty != Pubkey::default() {
+        return Err(LocksmithError::InvalidCounterparty.into());
+    }
+    // Template locks unlock `duration_seconds` after creation
+    if options.unlock_slot != 0 || options.unlock_epoch != 0 || options.permanent {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+    if options.donation_address != Pubkey::default() || options.donation_bps != 0 {
+        return Err(LocksmithError::InvalidDonation.into());
+    }
+    // Claim hashes commit to a single lock's address
+    if options.claim_key != [0u8; 32] {
+        return Err(LocksmithError::InvalidClaimCode.into());
     }
 
-    let (lock_token_pda, _) =
-        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
-    if *lock_token_info.key != lock_token_pda {
+    let template_id_bytes = template_id.to_le_bytes();
+    let (template_pda, template_bump) =
+        Pubkey::find_program_address(&[TEMPLATE_SEED, &template_id_bytes], program_id);
+    if *template_info.key != template_pda {
         return Err(LocksmithError::InvalidPDA.into());
     }
 
-    let clock = Clock::get()?;
-    if clock.unix_timestamp < lock.unlock_timestamp {
-        return Err(LocksmithError::UnlockTooEarly.into());
+    if template_info.data_is_empty() {
+        let rent = Rent::get()?;
+        invoke_signed(
+            &system_instruction::create_account(
+                admin_info.key,
+                template_info.key,
+                rent.minimum_balance(TemplateAccount::SIZE),
+                TemplateAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                admin_info.clone(),
+                template_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[TEMPLATE_SEED, &template_id_bytes, &[template_bump]]],
+        )?;
     }
 
-    let lock_token = TokenAccount::unpack(&lock_token_info.data.borrow())?;
-    if lock_token.amount != lock.amount {
-        return Err(LocksmithError::InconsistentState.into());
+    let template = TemplateAccount {
+        discriminator: TemplateAccount::DISCRIMINATOR,
+        template_id,
+        duration_seconds,
+        fee_usdc,
+        credential_issuer: options.credential_issuer,
+        non_transferable: options.non_transferable,
+        bump: template_bump,
+    };
+    template.pack(&mut template_info.data.borrow_mut());
+
+    msg!(
+        "Lock template {} set: {} seconds, {} USDC fee",
+        template_id,
+        duration_seconds,
+        fee_usdc
+    );
+    Ok(())
+}
+
+/// A donation needs both a share of at most 100% and a destination other
+/// than the owner; neither may be set alone.
+fn validate_donation(options: &LockOptions, owner: &Pubkey) -> ProgramResult {
+    let has_address = options.donation_address != Pubkey::default();
+    if has_address != (options.donation_bps > 0)
+        || options.donation_bps > BPS_DENOMINATOR
+        || options.donation_address == *owner
+    {
+        return Err(LocksmithError::InvalidDonation.into());
     }
+    Ok(())
+}
 
-    // Validate destination token account belongs to the owner and has correct mint
-    let owner_token = TokenAccount::unpack(&owner_token_info.data.borrow())?;
-    if owner_token.owner != *owner_info.key {
-        return Err(LocksmithError::Unauthorized.into());
+/// A gift lock belongs to its beneficiary alone, so its funder cannot give
+/// anyone else a claim on the tokens: a fallback owner, recovery or
+/// donation address, claim code or unlock destination.
+fn validate_gift(options: &LockOptions) -> ProgramResult {
+    if options.fallback_owner != Pubkey::default()
+        || options.recovery_address != Pubkey::default()
+        || options.donation_address != Pubkey::default()
+        || options.claim_key != [0u8; 32]
+        || options.unlock_destination != Pubkey::default()
+    {
+        return Err(LocksmithError::InvalidGiftOptions.into());
     }
-    if owner_token.mint != lock.mint {
+    Ok(())
+}
+
+/// A fallback owner must be someone other than the owner, and the delay
+/// after unlock before they may claim cannot be negative or set alone.
+fn validate_fallback(options: &LockOptions, owner: &Pubkey) -> ProgramResult {
+    let invalid = if options.fallback_owner == Pubkey::default() {
+        options.fallback_after != 0
+    } else {
+        options.fallback_owner == *owner || options.fallback_after < 0
+    };
+    if invalid {
+        return Err(LocksmithError::InvalidFallback.into());
+    }
+    Ok(())
+}
+
+/// A lock committed to an unlock destination may only ever pay out there,
+/// so it cannot also have an option that pays someone else, or be paid out
+/// as lamports.
+fn validate_unlock_destination(options: &LockOptions) -> ProgramResult {
+    if options.unlock_destination != Pubkey::default()
+        && (options.recovery_address != Pubkey::default()
+            || options.fallback_owner != Pubkey::default()
+            || options.donation_address != Pubkey::default()
+            || options.claim_key != [0u8; 32]
+            || options.early_unlock_allowed
+            || options.wrap_lamports)
+    {
+        return Err(LocksmithError::InvalidUnlockDestination.into());
+    }
+    Ok(())
+}
+
+/// A price condition needs a positive threshold within
+/// ±`MAX_PRICE_EXPONENT` decimal places, and can only hold back a lock that
+/// is released by unlocking: permanent locks never are, and claim codes,
+/// recovery sweeps and emergency unlocks would skip it.
+fn validate_price_condition(options: &LockOptions) -> ProgramResult {
+    let invalid = if options.price_feed_id == [0u8; 32] {
+        options.price_threshold != 0 || options.price_exponent != 0
+    } else {
+        options.price_threshold <= 0
+            || !(-MAX_PRICE_EXPONENT..=MAX_PRICE_EXPONENT).contains(&options.price_exponent)
+            || options.permanent
+            || options.claim_key != [0u8; 32]
+            || options.recovery_address != Pubkey::default()
+            || options.early_unlock_allowed
+    };
+    if invalid {
+        return Err(LocksmithError::InvalidPriceCondition.into());
+    }
+    Ok(())
+}
+
+/// An arbiter must be someone other than the owner, and can only hold back
+/// a lock that is released by unlocking: permanent locks never are, and
+/// claim codes, recovery sweeps and emergency unlocks would skip it.
+fn validate_arbiter(options: &LockOptions, owner: &Pubkey) -> ProgramResult {
+    if options.arbiter != Pubkey::default()
+        && (options.arbiter == *owner
+            || options.permanent
+            || options.claim_key != [0u8; 32]
+            || options.recovery_address != Pubkey::default()
+            || options.early_unlock_allowed)
+    {
+        return Err(LocksmithError::InvalidArbiter.into());
+    }
+    Ok(())
+}
+
+/// A counterparty must be someone other than the owner. An escrow pays out
+/// in full to one party or the other, so it cannot have recovery or
+/// donation addresses, an unlock destination, claim codes, emergency
+/// unlocks or permanence, nor conditions a release would bypass.
+fn validate_counterparty(options: &LockOptions, owner: &Pubkey) -> ProgramResult {
+    if options.counterparty != Pubkey::default()
+        && (options.counterparty == *owner
+            || options.permanent
+            || options.recovery_address != Pubkey::default()
+            || options.donation_address != Pubkey::default()
+            || options.unlock_destination != Pubkey::default()
+            || options.claim_key != [0u8; 32]
+            || options.early_unlock_allowed
+            || options.price_feed_id != [0u8; 32]
+            || options.arbiter != Pubkey::default())
+    {
+        return Err(LocksmithError::InvalidCounterparty.into());
+    }
+    Ok(())
+}
+
+/// Checks a price-conditional lock's price update: a fully verified Pyth
+/// update for the lock's feed, published at most `MAX_PRICE_AGE_SECONDS`
+/// ago with a confidence interval within `MAX_PRICE_CONFIDENCE_BPS` of the
+/// price, which must be at least the lock's threshold.
+fn check_price_condition(
+    lock: &LockAccount,
+    update_owner: &Pubkey,
+    update_data: &[u8],
+    now: i64,
+) -> ProgramResult {
+    if *update_owner != PYTH_RECEIVER_PROGRAM_ID {
+        return Err(LocksmithError::InvalidPriceFeed.into());
+    }
+    let update = PythPriceUpdate::unpack(update_data)?;
+    if update.feed_id != lock.price_feed_id {
+        return Err(LocksmithError::InvalidPriceFeed.into());
+    }
+    if now.saturating_sub(update.publish_time) > MAX_PRICE_AGE_SECONDS || !update.confident() {
+        return Err(LocksmithError::PriceUnreliable.into());
+    }
+    if !update
+        .at_least(lock.price_threshold, lock.price_exponent)
+        .ok_or(ProgramError::ArithmeticOverflow)?
+    {
+        return Err(LocksmithError::PriceConditionNotMet.into());
+    }
+    Ok(())
+}
+
+/// Checks a payout goes to the lock's committed unlock destination, if it
+/// has one.
+fn check_unlock_destination(lock: &LockAccount, destination: &Pubkey) -> ProgramResult {
+    if lock.unlock_destination != Pubkey::default() && *destination != lock.unlock_destination {
+        return Err(LocksmithError::InvalidUnlockDestination.into());
+    }
+    Ok(())
+}
+
+/// A claim code must expire after the lock unlocks, within the maximum lock
+/// duration. Claims skip credential checks, hooks, donations, sweeps and
+/// fallback claims, and hand the tokens to whoever holds the code, so
+/// claim-code locks can have none of those options and cannot be
+/// non-transferable.
+fn validate_claim_code(
+    options: &LockOptions,
+    unlock_timestamp: i64,
+    max_unlock_timestamp: i64,
+) -> ProgramResult {
+    if options.claim_expires_at <= unlock_timestamp
+        || options.claim_expires_at > max_unlock_timestamp
+        || options.credential_issuer != Pubkey::default()
+        || options.hook != Pubkey::default()
+        || options.recovery_address != Pubkey::default()
+        || options.fallback_owner != Pubkey::default()
+        || options.donation_address != Pubkey::default()
+        || options.non_transferable
+    {
+        return Err(LocksmithError::InvalidClaimCode.into());
+    }
+    Ok(())
+}
+
+/// A permanent lock releases nothing, so it cannot have the options that
+/// only matter when tokens leave escrow: a slot or epoch to unlock at, a
+/// claim code, a recovery, fallback or donation address, or emergency
+/// unlocks.
+fn validate_permanent(options: &LockOptions) -> ProgramResult {
+    if options.permanent
+        && (options.unlock_slot != 0
+            || options.unlock_epoch != 0
+            || options.claim_key != [0u8; 32]
+            || options.recovery_address != Pubkey::default()
+            || options.fallback_owner != Pubkey::default()
+            || options.donation_address != Pubkey::default()
+            || options.early_unlock_allowed)
+    {
+        return Err(LocksmithError::LockIsPermanent.into());
+    }
+    Ok(())
+}
+
+/// The amount to lock: `amount`, or for `LOCK_FULL_BALANCE` the source
+/// account's `balance` less any fee paid from that same account.
+fn resolve_lock_amount(amount: u64, balance: u64, fee_from_same_account: u64) -> u64 {
+    if amount == LOCK_FULL_BALANCE {
+        balance.saturating_sub(fee_from_same_account)
+    } else {
+        amount
+    }
+}
+
+/// Tokens carved off `amount` for a donation or penalty of `bps` basis
+/// points, rounded down.
+fn bps_share(amount: u64, bps: u16) -> u64 {
+    // At most `amount`, since `bps` is at most 10,000
+    (u128::from(amount) * u128::from(bps) / u128::from(BPS_DENOMINATOR)) as u64
+}
+
+/// Whether `mint` is an NFT: a single token that cannot be divided.
+fn is_nft_mint(mint: &Mint) -> bool {
+    mint.decimals == 0 && mint.supply == 1
+}
+
+/// Whether a new lock of `mint` locks lamports rather than wSOL, as
+/// `LockOptions::wrap_lamports` asks, which only the native mint can.
+fn wraps_lamports(wrap_lamports: bool, mint: &Pubkey) -> Result<bool, ProgramError> {
+    if wrap_lamports && *mint != spl_token::native_mint::id() {
         return Err(LocksmithError::InvalidMint.into());
     }
+    Ok(wrap_lamports)
+}
 
-    let amount = lock.amount;
-    let lock_bump = lock.bump;
+/// Creates a lock for each owner, lock and escrow triple following the
+/// fixed accounts, all with the same mint, amount, unlock timestamp and lock
+/// id, as gift locks from the signing funder. The fee is charged once for
+/// the batch, or for every lock if the config's `batch_fee_per_lock` is set
+/// or the mint pays a percentage fee. The accounts the locks find by key,
+/// like the mint's policy PDA under a mint policy or its fee cap PDA and
+/// token fee vault under a percentage fee, follow the triples.
+fn process_initialize_lock_batch<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    amount: u64,
+    unlock_timestamp: i64,
+    lock_id: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
 
-    invoke_signed(
-        &spl_token::instruction::transfer(
-            token_program_info.key,
-            lock_token_info.key,
-            owner_token_info.key,
-            lock_account_info.key,
-            &[],
+    let funder_info = next_account_info(account_info_iter)?;
+    let funder_token_info = next_account_info(account_info_iter)?;
+    let funder_usdc_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let fee_vault_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let batch_infos = account_info_iter.as_slice();
+    let batch_keys: Vec<Pubkey> = batch_infos.iter().map(|info| *info.key).collect();
+    let (lock_infos, trailing_infos) =
+        batch_infos.split_at(3 * lock_batch_len(program_id, &batch_keys, mint_info.key, lock_id));
+    let lock_accounts = lock_infos.chunks_exact(3);
+
+    if lock_accounts.len() == 0 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    // Each lock would drain a different remainder of the funder's balance
+    if amount == LOCK_FULL_BALANCE {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+    let bps_fee =
+        config.fee_bps > 0 && load_fee_cap(program_id, trailing_infos, mint_info.key)?.is_some();
+
+    let count = lock_accounts.len();
+    for (i, lock) in lock_accounts.enumerate() {
+        let fee_usdc = if i == 0 || config.batch_fee_per_lock || bps_fee {
+            None
+        } else {
+            Some(0)
+        };
+        let mut lock_accounts = vec![
+            lock[0].clone(),
+            funder_token_info.clone(),
+            funder_usdc_info.clone(),
+            mint_info.clone(),
+            lock[1].clone(),
+            lock[2].clone(),
+            fee_vault_info.clone(),
+            token_program_info.clone(),
+            system_program_info.clone(),
+            config_info.clone(),
+        ];
+        lock_accounts.extend(trailing_infos.iter().cloned());
+        process_initialize_lock(
+            program_id,
+            &lock_accounts,
             amount,
-        )?,
-        &[
-            lock_token_info.clone(),
-            owner_token_info.clone(),
-            lock_account_info.clone(),
-        ],
-        &[&[
-            LOCK_SEED,
-            owner_info.key.as_ref(),
-            lock.mint.as_ref(),
-            &lock_id_bytes,
-            &[lock_bump],
-        ]],
-    )?;
+            unlock_timestamp,
+            lock_id,
+            LockOptions::default(),
+            fee_usdc,
+            LockFunding::Funder(funder_info),
+        )?;
+    }
 
-    invoke_signed(
-        &spl_token::instruction::close_account(
-            token_program_info.key,
-            lock_token_info.key,
-            owner_info.key,
-            lock_account_info.key,
-            &[],
-        )?,
-        &[
-            lock_token_info.clone(),
-            owner_info.clone(),
-            lock_account_info.clone(),
-        ],
-        &[&[
-            LOCK_SEED,
-            owner_info.key.as_ref(),
-            lock.mint.as_ref(),
-            &lock_id_bytes,
-            &[lock_bump],
-        ]],
-    )?;
+    msg!("Created a batch of {} locks", count);
+    Ok(())
+}
+
+/// How many owner, lock and escrow triples start `keys`, the accounts
+/// following `InitializeLockBatch`'s fixed ones: they run for as long as
+/// the second key of each is the lock PDA of the first.
+fn lock_batch_len(program_id: &Pubkey, keys: &[Pubkey], mint: &Pubkey, lock_id: u64) -> usize {
+    let lock_id_bytes = lock_id.to_le_bytes();
+    keys.chunks_exact(3)
+        .take_while(|lock| {
+            let (lock_pda, _) = Pubkey::find_program_address(
+                &[LOCK_SEED, lock[0].as_ref(), mint.as_ref(), &lock_id_bytes],
+                program_id,
+            );
+            lock[1] == lock_pda
+        })
+        .count()
+}
+
+/// Chooses whether `InitializeLockBatch` charges the fee per lock or once
+/// per batch (admin only).
+fn process_set_batch_fee_mode(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    per_lock: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    config.batch_fee_per_lock = per_lock;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    msg!("Batch fee charged {}", if per_lock { "per lock" } else { "per batch" });
+    Ok(())
+}
+
+/// Relative lock durations (templates, standing orders,
+/// `InitializeLockForDuration`) must produce locks that `InitializeLock`
+/// would accept: in the future and within `MAX_LOCK_DURATION_SECONDS`.
+fn validate_lock_duration(duration_seconds: i64) -> ProgramResult {
+    if duration_seconds <= 0 {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+    if duration_seconds > MAX_LOCK_DURATION_SECONDS {
+        return Err(LocksmithError::LockDurationExceeded.into());
+    }
+    Ok(())
+}
+
+/// Rejects an instruction signed with a `valid_until` deadline that has
+/// passed. 0 means no deadline.
+fn check_deadline(valid_until: i64, now: i64) -> ProgramResult {
+    if valid_until != 0 && now > valid_until {
+        return Err(LocksmithError::TransactionExpired.into());
+    }
+    Ok(())
+}
+
+/// A slot- or epoch-based lock must unlock at a future slot or epoch at
+/// most `max_duration` ahead.
+fn validate_unlock_target(target: u64, current: u64, max_duration: u64) -> ProgramResult {
+    if target <= current {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+    if target - current > max_duration {
+        return Err(LocksmithError::LockDurationExceeded.into());
+    }
+    Ok(())
+}
+
+/// Creates a lock that unlocks `duration_seconds` after the on-chain clock,
+/// sparing clients the wall-clock arithmetic `InitializeLock` needs.
+fn process_initialize_lock_for_duration(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    duration_seconds: i64,
+    lock_id: u64,
+    valid_until: i64,
+) -> ProgramResult {
+    validate_lock_duration(duration_seconds)?;
+    let unlock_timestamp = Clock::get()?
+        .unix_timestamp
+        .checked_add(duration_seconds)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    process_initialize_lock(
+        program_id,
+        accounts,
+        amount,
+        unlock_timestamp,
+        lock_id,
+        LockOptions {
+            valid_until,
+            ..LockOptions::default()
+        },
+        None,
+        LockFunding::Owner,
+    )
+}
+
+/// Creates a lock on the terms of an admin-defined template: the unlock time
+/// is the template duration from now, and the fee and options come from the
+/// template. The owner only chooses the mint, amount and lock id.
+fn process_initialize_lock_from_template(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    lock_id: u64,
+    template_id: u64,
+    valid_until: i64,
+) -> ProgramResult {
+    let template_info = accounts.get(9).ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let (template_pda, _) =
+        Pubkey::find_program_address(&[TEMPLATE_SEED, &template_id.to_le_bytes()], program_id);
+    if *template_info.key != template_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let template = TemplateAccount::unpack(&template_info.data.borrow())?;
+    let unlock_timestamp = Clock::get()?
+        .unix_timestamp
+        .checked_add(template.duration_seconds)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    msg!("Creating lock from template {}", template_id);
+    process_initialize_lock(
+        program_id,
+        accounts,
+        amount,
+        unlock_timestamp,
+        lock_id,
+        LockOptions {
+            credential_issuer: template.credential_issuer,
+            non_transferable: template.non_transferable,
+            hook: Pubkey::default(),
+            recovery_address: Pubkey::default(),
+            donation_address: Pubkey::default(),
+            donation_bps: 0,
+            valid_until,
+            unlock_slot: 0,
+            unlock_epoch: 0,
+            claim_key: [0u8; 32],
+            claim_expires_at: 0,
+            early_unlock_allowed: false,
+            permanent: false,
+            label: [0u8; 32],
+            metadata_uri: [0u8; METADATA_URI_LEN],
+            fallback_owner: Pubkey::default(),
+            fallback_after: 0,
+            unlock_destination: Pubkey::default(),
+            price_feed_id: [0u8; 32],
+            price_threshold: 0,
+            price_exponent: 0,
+            arbiter: Pubkey::default(),
+            counterparty: Pubkey::default(),
+            fee_in_lamports: false,
+            wrap_lamports: false,
+        },
+        Some(template.fee_usdc),
+        LockFunding::Owner,
+    )
+}
+
+/// Creates a lock for an owner who signed `relayed_lock_message` rather
+/// than the transaction. The relayer pays the rent; the tokens and fee move
+/// via the owner's relay delegate PDA.
+fn process_initialize_relayed_lock(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    unlock_timestamp: i64,
+    lock_id: u64,
+    valid_until: i64,
+) -> ProgramResult {
+    let (relayer_info, accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let mint_info = accounts.get(3).ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let delegate_info = accounts.get(9).ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let instructions_sysvar_info = accounts.get(10).ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    // Once a lock unlocks its deadline has passed, so the message cannot
+    // recreate the lock under the same ID
+    if valid_until == 0 || valid_until > unlock_timestamp {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+
+    let (delegate_pda, delegate_bump) =
+        Pubkey::find_program_address(&[RELAY_SEED, owner_info.key.as_ref()], program_id);
+    if *delegate_info.key != delegate_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let message = relayed_lock_message(
+        program_id,
+        owner_info.key,
+        mint_info.key,
+        amount,
+        unlock_timestamp,
+        lock_id,
+        valid_until,
+    );
+    verify_preceding_ed25519_signature(instructions_sysvar_info, owner_info.key, &message)?;
+
+    process_initialize_lock(
+        program_id,
+        accounts,
+        amount,
+        unlock_timestamp,
+        lock_id,
+        LockOptions {
+            valid_until,
+            ..LockOptions::default()
+        },
+        None,
+        LockFunding::Relayed {
+            relayer_info,
+            delegate_info,
+            delegate_bump,
+        },
+    )
+}
+
+/// Checks that the instruction before the current one is an ed25519
+/// program instruction verifying `signer`'s signature over `message`. The
+/// ed25519 program fails the transaction if a signature it checks is
+/// invalid, so finding the check is enough.
+fn verify_preceding_ed25519_signature(
+    instructions_sysvar_info: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> ProgramResult {
+    let current_index = load_current_index_checked(instructions_sysvar_info)?;
+    let index = current_index
+        .checked_sub(1)
+        .ok_or(LocksmithError::InvalidSignature)?;
+    let instruction = load_instruction_at_checked(usize::from(index), instructions_sysvar_info)?;
+    if instruction.program_id != ed25519_program::ID
+        || !ed25519_instruction_verifies(&instruction.data, signer, message)
+    {
+        return Err(LocksmithError::InvalidSignature.into());
+    }
+    Ok(())
+}
+
+/// Whether ed25519 program instruction data checks exactly one signature,
+/// by `signer` over `message`, with the key, signature and message all in
+/// the instruction itself.
+fn ed25519_instruction_verifies(data: &[u8], signer: &Pubkey, message: &[u8]) -> bool {
+    // [count: u8][padding: u8] then one `Ed25519SignatureOffsets` of seven
+    // u16s: signature offset and instruction index, public key offset and
+    // instruction index, message offset, size and instruction index
+    if data.len() < 16 || data[0] != 1 {
+        return false;
+    }
+    let field = |i: usize| usize::from(u16::from_le_bytes([data[2 + 2 * i], data[3 + 2 * i]]));
+    // u16::MAX points an offset at the ed25519 instruction's own data
+    let this_instruction = usize::from(u16::MAX);
+    if field(1) != this_instruction || field(3) != this_instruction || field(6) != this_instruction
+    {
+        return false;
+    }
+    let public_key = data.get(field(2)..field(2) + 32);
+    let signed_message = data.get(field(4)..field(4) + field(5));
+    public_key == Some(signer.as_ref()) && signed_message == Some(message)
+}
+
+/// Creates a standing order. The owner approves the order PDA as delegate
+/// for every execution's tokens and fee and pre-pays every lock's rent, so
+/// the crank needs nothing further from the owner.
+fn process_create_standing_order(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    order_id: u64,
+    params: StandingOrderParams,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_token_info = next_account_info(account_info_iter)?;
+    let owner_usdc_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let order_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if params.amount_per_lock == 0 || params.executions == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+    if params.interval_seconds <= 0 {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+    validate_lock_duration(params.lock_duration_seconds)?;
+
+    let owner_token = TokenAccount::unpack(&owner_token_info.data.borrow())?;
+    if owner_token.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if owner_token.mint != *mint_info.key {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    // The fee account's mint is checked at each execution instead, since
+    // the fee mint may be migrated while the order is live
+    let owner_usdc = TokenAccount::unpack(&owner_usdc_info.data.borrow())?;
+    if owner_usdc.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let order_id_bytes = order_id.to_le_bytes();
+    let (order_pda, order_bump) = Pubkey::find_program_address(
+        &[
+            STANDING_ORDER_SEED,
+            owner_info.key.as_ref(),
+            mint_info.key.as_ref(),
+            &order_id_bytes,
+        ],
+        program_id,
+    );
+    if *order_info.key != order_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let executions = u64::from(params.executions);
+    let total_tokens = params
+        .amount_per_lock
+        .checked_mul(executions)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Executions charge the fee in force at the time, but never more than
+    // the fee approved here, so raising the fee cannot outrun the approval.
+    // The flat fee is approved even where a tier, percentage fee or
+    // exemption costs less now, since those may change too.
+    let config = find_config(program_id, accounts)?;
+    let is_nft = is_nft_mint(&Mint::unpack(&mint_info.data.borrow())?);
+    let fee_usdc = lock_fee(
+        program_id,
+        accounts,
+        &config,
+        mint_info.key,
+        is_nft,
+        owner_info.key,
+        None,
+        params.lock_duration_seconds.unsigned_abs(),
+        params.amount_per_lock,
+        false,
+    )?
+    .usdc
+    .max(config.fee_usdc);
+    let total_fees = fee_usdc
+        .checked_mul(executions)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let rent = Rent::get()?;
+    let prepaid_rent = lock_rent(&rent)
+        .checked_mul(executions)
+        .and_then(|r| r.checked_add(rent.minimum_balance(StandingOrderAccount::SIZE)))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    invoke_signed(
+        &system_instruction::create_account(
+            owner_info.key,
+            order_info.key,
+            prepaid_rent,
+            StandingOrderAccount::SIZE as u64,
+            program_id,
+        ),
+        &[
+            owner_info.clone(),
+            order_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[
+            STANDING_ORDER_SEED,
+            owner_info.key.as_ref(),
+            mint_info.key.as_ref(),
+            &order_id_bytes,
+            &[order_bump],
+        ]],
+    )?;
+
+    // A USDC order draws tokens and fees from the same account, which can
+    // only have one delegated amount
+    let approvals = if owner_token_info.key == owner_usdc_info.key {
+        let total = total_tokens
+            .checked_add(total_fees)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        vec![(owner_token_info, total)]
+    } else {
+        vec![(owner_token_info, total_tokens), (owner_usdc_info, total_fees)]
+    };
+    for (source_info, amount) in approvals {
+        invoke(
+            &spl_token::instruction::approve(
+                token_program_info.key,
+                source_info.key,
+                order_info.key,
+                owner_info.key,
+                &[],
+                amount,
+            )?,
+            &[source_info.clone(), order_info.clone(), owner_info.clone()],
+        )?;
+    }
+
+    let clock = Clock::get()?;
+    let order = StandingOrderAccount {
+        discriminator: StandingOrderAccount::DISCRIMINATOR,
+        owner: *owner_info.key,
+        mint: *mint_info.key,
+        owner_token_account: *owner_token_info.key,
+        owner_usdc_account: *owner_usdc_info.key,
+        order_id,
+        amount_per_lock: params.amount_per_lock,
+        interval_seconds: params.interval_seconds,
+        lock_duration_seconds: params.lock_duration_seconds,
+        next_execution_at: params.start_at.max(clock.unix_timestamp),
+        next_lock_id: params.first_lock_id,
+        remaining_executions: params.executions,
+        bump: order_bump,
+        fee_usdc,
+    };
+    order.pack(&mut order_info.data.borrow_mut());
+
+    msg!(
+        "Standing order {} created: {} x {} tokens every {} seconds",
+        order_id,
+        params.executions,
+        params.amount_per_lock,
+        params.interval_seconds
+    );
+    Ok(())
+}
+
+/// Runs one execution of a due standing order. Anyone may call this; the
+/// lock is created exactly as the order specifies, and the cranker is made
+/// whole for the rent it fronts.
+fn process_execute_standing_order(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let cranker_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let order_info = next_account_info(account_info_iter)?;
+    let owner_token_info = next_account_info(account_info_iter)?;
+    let owner_usdc_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let fee_vault_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !cranker_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config, fee_mint) =
+        load_config_for_new_lock(program_id, fee_vault_info, mint_info, accounts)?;
+
+    if order_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut order = StandingOrderAccount::unpack(&order_info.data.borrow())?;
+
+    if *owner_info.key != order.owner
+        || *owner_token_info.key != order.owner_token_account
+        || *owner_usdc_info.key != order.owner_usdc_account
+    {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if *mint_info.key != order.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    let clock = Clock::get()?;
+    if order.remaining_executions == 0 || clock.unix_timestamp < order.next_execution_at {
+        return Err(LocksmithError::StandingOrderNotDue.into());
+    }
+
+    // The owner does not sign executions, so cannot pay in lamports
+    let is_nft = is_nft_mint(&Mint::unpack(&mint_info.data.borrow())?);
+    if is_nft && order.amount_per_lock != 1 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+    let LockFee {
+        usdc: fee_usdc,
+        bps_cap: fee_cap,
+        mode: fee_mode,
+        ..
+    } = lock_fee(
+        program_id,
+        accounts,
+        &config,
+        mint_info.key,
+        is_nft,
+        &order.owner,
+        None,
+        order.lock_duration_seconds.unsigned_abs(),
+        order.amount_per_lock,
+        false,
+    )?;
+    // Capped at the fee approved when the order was created
+    let fee_usdc = fee_usdc.min(order.fee_usdc);
+    if fee_usdc > 0 && TokenAccount::unpack(&owner_usdc_info.data.borrow())?.mint != fee_mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    // The percentage fee comes out of the tokens, so the lock holds the rest
+    let token_fee = if let Some(cap) = fee_cap {
+        Some(load_bps_fee(
+            program_id,
+            accounts,
+            &config,
+            cap,
+            mint_info.key,
+            order.amount_per_lock,
+        )?)
+    } else {
+        None
+    };
+    let locked_amount = order.amount_per_lock - token_fee.map_or(0, |(fee, _, _)| fee);
+    if locked_amount == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    let unlock_timestamp = clock
+        .unix_timestamp
+        .checked_add(order.lock_duration_seconds)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let owner_counted = count_owner_lock(
+        program_id,
+        accounts,
+        &config,
+        &order.owner,
+        cranker_info,
+        system_program_info,
+    )?;
+
+    let lock_bump = create_lock_accounts(
+        program_id,
+        cranker_info,
+        &order.owner,
+        mint_info,
+        lock_account_info,
+        lock_token_info,
+        system_program_info,
+        order.next_lock_id,
+    )?;
+
+    let lock = LockAccount {
+        discriminator: LockAccount::DISCRIMINATOR,
+        owner: order.owner,
+        mint: order.mint,
+        amount: locked_amount,
+        unlock_timestamp,
+        created_at: clock.unix_timestamp,
+        lock_id: order.next_lock_id,
+        bump: lock_bump,
+        frozen: false,
+        credential_issuer: Pubkey::default(),
+        non_transferable: false,
+        forward_destination: Pubkey::default(),
+        hook: Pubkey::default(),
+        recovery_address: Pubkey::default(),
+        donation_address: Pubkey::default(),
+        donation_bps: 0,
+        collateral_holder: Pubkey::default(),
+        layout_version: LockAccount::LAYOUT_VERSION,
+        unlock_slot: 0,
+        unlock_epoch: 0,
+        claim_key: [0u8; 32],
+        claim_expires_at: 0,
+        index_seq: 0,
+        early_unlock_allowed: false,
+        permanent: false,
+        is_nft,
+        receipt_bound: false,
+        label: [0u8; 32],
+        metadata_uri: [0u8; METADATA_URI_LEN],
+        fallback_owner: Pubkey::default(),
+        fallback_after: 0,
+        unlock_destination: Pubkey::default(),
+        shared: false,
+        approvals_required: false,
+        price_feed_id: [0u8; 32],
+        price_threshold: 0,
+        price_exponent: 0,
+        arbiter: Pubkey::default(),
+        milestone_approved: false,
+        counterparty: Pubkey::default(),
+        owner_counted,
+        wrapped_lamports: false,
+        receipt_mint: Pubkey::default(),
+        fee_mode,
+        reserved: [0u8; 29],
+    };
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    let order_id_bytes = order.order_id.to_le_bytes();
+    let order_seeds: &[&[u8]] = &[
+        STANDING_ORDER_SEED,
+        order.owner.as_ref(),
+        order.mint.as_ref(),
+        &order_id_bytes,
+        &[order.bump],
+    ];
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            owner_token_info.key,
+            lock_token_info.key,
+            order_info.key,
+            &[],
+            locked_amount,
+        )?,
+        &[
+            owner_token_info.clone(),
+            lock_token_info.clone(),
+            order_info.clone(),
+        ],
+        &[order_seeds],
+    )?;
+
+    if fee_usdc > 0 {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                owner_usdc_info.key,
+                fee_vault_info.key,
+                order_info.key,
+                &[],
+                fee_usdc,
+            )?,
+            &[
+                owner_usdc_info.clone(),
+                fee_vault_info.clone(),
+                order_info.clone(),
+            ],
+            &[order_seeds],
+        )?;
+    }
+
+    if let Some((token_fee, token_fee_vault_info, token_fee_vault_bump)) = token_fee {
+        pay_token_fee(
+            token_fee,
+            owner_token_info,
+            order_info,
+            &[order_seeds],
+            token_fee_vault_info,
+            token_fee_vault_bump,
+            cranker_info,
+            mint_info,
+            token_program_info,
+            system_program_info,
+        )?;
+    }
+
+    let rent = Rent::get()?;
+    let reimbursement = lock_rent(&rent);
+    let spare = order_info
+        .lamports()
+        .saturating_sub(rent.minimum_balance(order_info.data_len()));
+    if spare < reimbursement {
+        return Err(LocksmithError::InsufficientFunds.into());
+    }
+    **order_info.lamports.borrow_mut() = order_info
+        .lamports()
+        .checked_sub(reimbursement)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **cranker_info.lamports.borrow_mut() = cranker_info
+        .lamports()
+        .checked_add(reimbursement)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    msg!(
+        "Standing order {} executed: lock {} of {} tokens until {}",
+        order.order_id,
+        order.next_lock_id,
+        locked_amount,
+        unlock_timestamp
+    );
+
+    order.remaining_executions -= 1;
+    if order.remaining_executions == 0 {
+        close_program_account(order_info, owner_info)?;
+        msg!("Standing order {} completed", order.order_id);
+        return Ok(());
+    }
+
+    // Advance on the original schedule, so a late crank can catch up
+    order.next_execution_at = order
+        .next_execution_at
+        .checked_add(order.interval_seconds)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    order.next_lock_id = order
+        .next_lock_id
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    order.pack(&mut order_info.data.borrow_mut());
+    Ok(())
+}
+
+/// Cancels a standing order: revokes the order's delegations (if still in
+/// place) and closes it, refunding the unused rent. Locks already created
+/// are unaffected.
+fn process_cancel_standing_order(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let order_info = next_account_info(account_info_iter)?;
+    let owner_token_info = next_account_info(account_info_iter)?;
+    let owner_usdc_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if order_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let order = StandingOrderAccount::unpack(&order_info.data.borrow())?;
+
+    if *owner_info.key != order.owner
+        || *owner_token_info.key != order.owner_token_account
+        || *owner_usdc_info.key != order.owner_usdc_account
+    {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let sources: &[&AccountInfo] = if owner_token_info.key == owner_usdc_info.key {
+        &[owner_token_info]
+    } else {
+        &[owner_token_info, owner_usdc_info]
+    };
+    for source_info in sources {
+        // Leave delegations the owner has since granted to someone else
+        let source = TokenAccount::unpack(&source_info.data.borrow())?;
+        if source.delegate != Some(*order_info.key).into() {
+            continue;
+        }
+        invoke(
+            &spl_token::instruction::revoke(
+                token_program_info.key,
+                source_info.key,
+                owner_info.key,
+                &[],
+            )?,
+            &[(*source_info).clone(), owner_info.clone()],
+        )?;
+    }
+
+    close_program_account(order_info, owner_info)?;
+
+    msg!("Standing order {} cancelled", order.order_id);
+    Ok(())
+}
+
+/// Rent for one lock and its escrow, which a standing order prepays per
+/// execution.
+fn lock_rent(rent: &Rent) -> u64 {
+    rent.minimum_balance(LockAccount::SIZE)
+        .saturating_add(rent.minimum_balance(TokenAccount::LEN))
+}
+
+/// Closes a program-owned account, moving its lamports to `destination`.
+fn close_program_account(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+    **destination.lamports.borrow_mut() = destination
+        .lamports()
+        .checked_add(account.lamports())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **account.lamports.borrow_mut() = 0;
+    account.data.borrow_mut().fill(0);
+    Ok(())
+}
+
+/// Who has signed an unlock
+#[derive(Clone, Copy)]
+enum UnlockAuthority<'b, 'a> {
+    /// The owner, who receives the tokens and rent
+    Owner,
+    /// The owner's session key; the unlock must be within its scope
+    Session(&'b SessionAccount),
+    /// The holder of a receipt-bound lock's receipt, who receives the
+    /// tokens and rent instead of the owner
+    ReceiptHolder(&'b AccountInfo<'a>),
+    /// The lock's fallback owner, once the owner has left it unlocked for
+    /// `fallback_after` seconds; receives the tokens and rent instead of the
+    /// owner
+    Fallback(&'b AccountInfo<'a>),
+    /// The owner, of a lock whose approvers have approved unlocking it
+    Approved,
+    /// An escrow lock's counterparty, signing along with the owner at any
+    /// time; receives the tokens and rent instead of the owner
+    Counterparty(&'b AccountInfo<'a>),
+    /// The owner, leaving the emptied lock and its escrow open for
+    /// `CloseLock` rather than reclaiming their rent
+    OwnerKeepingAccounts,
+}
+
+/// Unlocks tokens after the unlock timestamp has passed.
+///
+/// Credential-gated locks additionally need the owner's credential PDA from
+/// the lock's issuer as the sixth account. Locks with a forwarding
+/// destination need it and its program as the seventh and eighth accounts,
+/// and locks with a hook need the hook, its program and its state as the
+/// ninth to eleventh. Any further accounts are passed to the deposit
+/// instruction, which is invoked with the unlocked amount once the tokens
+/// reach the owner.
+///
+/// # Destination Token Account
+///
+/// The owner may specify any token account they own (with the correct mint) as the
+/// destination for unlocked tokens. This provides flexibility for the lock owner to
+/// receive tokens in whichever of their accounts they prefer.
+///
+/// Unless `authority` is the owner, someone else has signed instead; see
+/// `UnlockAuthority`.
+fn process_unlock<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    lock_id: u64,
+    authority: UnlockAuthority<'_, 'a>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_token_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if matches!(
+        authority,
+        UnlockAuthority::Owner
+            | UnlockAuthority::Approved
+            | UnlockAuthority::Counterparty(_)
+            | UnlockAuthority::OwnerKeepingAccounts
+    ) && !owner_info.is_signer
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let keep_accounts = matches!(authority, UnlockAuthority::OwnerKeepingAccounts);
+
+    // Validate token program is the official SPL Token program
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    // A price-conditional lock takes its price update last, after the
+    // forwarding deposit accounts, which start at index 12 either way
+    let (price_update_info, accounts) = if lock.price_feed_id != [0u8; 32] {
+        let (update_info, accounts) = accounts
+            .split_last()
+            .ok_or(LocksmithError::InvalidPriceFeed)?;
+        (Some(update_info), accounts)
+    } else {
+        (None, accounts)
+    };
+
+    let recipient_info = match authority {
+        UnlockAuthority::ReceiptHolder(holder_info) => holder_info,
+        UnlockAuthority::Fallback(fallback_info) => fallback_info,
+        UnlockAuthority::Counterparty(counterparty_info) => counterparty_info,
+        _ => owner_info,
+    };
+
+    // Only the receipt's holder may unlock a lock bound to its receipt, and
+    // only such a lock; likewise for approvals
+    let clock = Clock::get()?;
+    lock.can_pay_out(
+        clock.unix_timestamp,
+        matches!(authority, UnlockAuthority::ReceiptHolder(_)),
+        matches!(authority, UnlockAuthority::Approved),
+    )?;
+
+    if let UnlockAuthority::Session(session) = authority {
+        check_session(session, &lock, owner_token_info.key, clock.unix_timestamp)?;
+    }
+
+    if let UnlockAuthority::Fallback(fallback_info) = authority {
+        if lock.fallback_owner == Pubkey::default() || *fallback_info.key != lock.fallback_owner {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        let fallback_at = lock
+            .unlock_timestamp
+            .checked_add(lock.fallback_after)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        if clock.unix_timestamp < fallback_at {
+            return Err(LocksmithError::LockNotAbandoned.into());
+        }
+    }
+
+    if let UnlockAuthority::Counterparty(counterparty_info) = authority {
+        if lock.counterparty == Pubkey::default() || *counterparty_info.key != lock.counterparty {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+    }
+
+    if lock.credential_issuer != Pubkey::default() {
+        let credential_info = accounts.get(5).ok_or(LocksmithError::InvalidCredential)?;
+        verify_credential(
+            credential_info.key,
+            credential_info.owner,
+            credential_info.data_is_empty(),
+            &lock.credential_issuer,
+            recipient_info.key,
+        )?;
+    }
+
+    // The deposit is into the owner's account, so fallback claims and escrow
+    // releases pay their recipient directly
+    let forwarding = if lock.forward_destination != Pubkey::default()
+        && !matches!(
+            authority,
+            UnlockAuthority::Fallback(_) | UnlockAuthority::Counterparty(_)
+        )
+    {
+        let destination_info = accounts
+            .get(6)
+            .ok_or(LocksmithError::InvalidForwardingDestination)?;
+        let destination_program_info = accounts
+            .get(7)
+            .ok_or(LocksmithError::InvalidForwardingDestination)?;
+        if *destination_info.key != lock.forward_destination || destination_info.owner != program_id
+        {
+            return Err(LocksmithError::InvalidForwardingDestination.into());
+        }
+        let destination = ForwardingDestinationAccount::unpack(&destination_info.data.borrow())?;
+        if *destination_program_info.key != destination.program {
+            return Err(LocksmithError::InvalidForwardingDestination.into());
+        }
+        Some((
+            destination,
+            destination_program_info,
+            accounts.get(12..).unwrap_or(&[]),
+        ))
+    } else {
+        None
+    };
+
+    // A deregistered hook no longer blocks unlocks; it is simply not called
+    let hook = if lock.hook != Pubkey::default() {
+        let hook_info = accounts.get(8).ok_or(LocksmithError::InvalidHook)?;
+        load_hook(program_id, hook_info, &lock.hook)?
+    } else {
+        None
+    };
+
+    let lock_id_bytes = lock_id.to_le_bytes();
+    let (lock_pda, _) = Pubkey::find_program_address(
+        &[
+            LOCK_SEED,
+            owner_info.key.as_ref(),
+            lock.mint.as_ref(),
+            &lock_id_bytes,
+        ],
+        program_id,
+    );
+    if *lock_account_info.key != lock_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let (lock_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if lock.permanent {
+        return Err(LocksmithError::LockIsPermanent.into());
+    }
+    // Both parties agreeing releases an escrow before its timeout
+    if !lock.unlock_reached(&clock) && !matches!(authority, UnlockAuthority::Counterparty(_)) {
+        return Err(LocksmithError::UnlockTooEarly.into());
+    }
+    if let Some(update_info) = price_update_info {
+        check_price_condition(
+            &lock,
+            update_info.owner,
+            &update_info.data.borrow(),
+            clock.unix_timestamp,
+        )?;
+    }
+    if lock.arbiter != Pubkey::default() && !lock.milestone_approved {
+        return Err(LocksmithError::MilestoneNotApproved.into());
+    }
+
+    let lock_token = TokenAccount::unpack(&lock_token_info.data.borrow())?;
+    if lock_token.amount != lock.amount {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    check_unlock_destination(&lock, owner_token_info.key)?;
+
+    // A lock funded with lamports pays them back out to the recipient's
+    // wallet: the escrow is closed with the payout still in it, leaving
+    // nothing to forward
+    let unwrap = lock.wrapped_lamports;
+    if unwrap {
+        if owner_token_info.key != recipient_info.key {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        if forwarding.is_some() {
+            return Err(LocksmithError::InvalidForwardingDestination.into());
+        }
+        // Unwrapping closes the escrow, so it cannot be kept
+        if keep_accounts {
+            return Err(ProgramError::InvalidArgument);
+        }
+    } else {
+        // Validate destination token account belongs to the recipient and has correct mint
+        let owner_token = TokenAccount::unpack(&owner_token_info.data.borrow())?;
+        if owner_token.owner != *recipient_info.key {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        if owner_token.mint != lock.mint {
+            return Err(LocksmithError::InvalidMint.into());
+        }
+    }
+
+    let amount = lock.amount;
+    let lock_bump = lock.bump;
+
+    let donation = bps_share(amount, lock.donation_bps);
+    let donation_token_info = if donation > 0 {
+        let info = accounts.get(11).ok_or(LocksmithError::InvalidDonation)?;
+        let donation_token = TokenAccount::unpack(&info.data.borrow())?;
+        if donation_token.owner != lock.donation_address || donation_token.mint != lock.mint {
+            return Err(LocksmithError::InvalidDonation.into());
+        }
+        Some(info)
+    } else {
+        None
+    };
+    let payout = amount - donation;
+
+    #[cfg(feature = "strict-invariants")]
+    let owner_token_before = if unwrap {
+        None
+    } else {
+        Some(invariants::token_amount(owner_token_info)?)
+    };
+    #[cfg(feature = "strict-invariants")]
+    let lamports_before = recipient_info
+        .lamports()
+        .checked_add(lock_account_info.lamports())
+        .and_then(|l| l.checked_add(lock_token_info.lamports()));
+
+    if let Some(donation_token_info) = donation_token_info {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                lock_token_info.key,
+                donation_token_info.key,
+                lock_account_info.key,
+                &[],
+                donation,
+            )?,
+            &[
+                lock_token_info.clone(),
+                donation_token_info.clone(),
+                lock_account_info.clone(),
+            ],
+            &[&[
+                LOCK_SEED,
+                owner_info.key.as_ref(),
+                lock.mint.as_ref(),
+                &lock_id_bytes,
+                &[lock_bump],
+            ]],
+        )?;
+        msg!("Donated {} tokens to {}", donation, lock.donation_address);
+    }
+
+    if !unwrap {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                lock_token_info.key,
+                owner_token_info.key,
+                lock_account_info.key,
+                &[],
+                payout,
+            )?,
+            &[
+                lock_token_info.clone(),
+                owner_token_info.clone(),
+                lock_account_info.clone(),
+            ],
+            &[&[
+                LOCK_SEED,
+                owner_info.key.as_ref(),
+                lock.mint.as_ref(),
+                &lock_id_bytes,
+                &[lock_bump],
+            ]],
+        )?;
+    }
+
+    if keep_accounts {
+        // Left for `CloseLock`, which anyone can call on an emptied lock
+        lock.amount = 0;
+        lock.pack(&mut lock_account_info.data.borrow_mut());
+    } else {
+        // A native escrow closes with its balance, paying it out as lamports
+        invoke_signed(
+            &spl_token::instruction::close_account(
+                token_program_info.key,
+                lock_token_info.key,
+                recipient_info.key,
+                lock_account_info.key,
+                &[],
+            )?,
+            &[
+                lock_token_info.clone(),
+                recipient_info.clone(),
+                lock_account_info.clone(),
+            ],
+            &[&[
+                LOCK_SEED,
+                owner_info.key.as_ref(),
+                lock.mint.as_ref(),
+                &lock_id_bytes,
+                &[lock_bump],
+            ]],
+        )?;
+
+        uncount_owner_lock(program_id, accounts, &lock)?;
+        close_program_account(lock_account_info, recipient_info)?;
+    }
+
+    #[cfg(feature = "strict-invariants")]
+    {
+        if keep_accounts {
+            invariants::expect_eq("escrow balance", invariants::token_amount(lock_token_info)?, 0)?;
+        } else {
+            // Everything the lock and escrow held must end up with the
+            // recipient
+            invariants::expect_eq(
+                "owner lamports",
+                lamports_before,
+                Some(recipient_info.lamports()),
+            )?;
+            invariants::expect_closed(
+                "lock account",
+                lock_account_info.lamports(),
+                &lock_account_info.data.borrow(),
+            )?;
+            invariants::expect_eq("escrow lamports", lock_token_info.lamports(), 0)?;
+        }
+        if let Some(owner_token_before) = owner_token_before {
+            invariants::expect_eq(
+                "owner token balance",
+                owner_token_before.checked_add(payout),
+                Some(invariants::token_amount(owner_token_info)?),
+            )?;
+        }
+    }
+
+    msg!("Unlocked {} tokens", payout);
+    if unwrap {
+        msg!("Unwrapped to lamports");
+    }
+
+    if let Some((destination, destination_program_info, deposit_accounts)) = forwarding {
+        // Plain invoke: the deposit gets the owner's signature from this
+        // transaction, never one of Locksmith's PDAs
+        let mut account_infos = deposit_accounts.to_vec();
+        account_infos.push(destination_program_info.clone());
+        invoke(
+            &Instruction {
+                program_id: destination.program,
+                accounts: deposit_accounts
+                    .iter()
+                    .map(|a| AccountMeta {
+                        pubkey: *a.key,
+                        is_signer: a.is_signer,
+                        is_writable: a.is_writable,
+                    })
+                    .collect(),
+                data: forwarding_instruction_data(&destination, payout),
+            },
+            &account_infos,
+        )?;
+        msg!("Forwarded {} tokens to {}", payout, destination.program);
+    }
+
+    if let Some(hook) = hook {
+        invoke_hook(
+            &hook,
+            HOOK_EVENT_UNLOCKED,
+            lock_account_info,
+            owner_info,
+            accounts.get(9..11).unwrap_or(&[]),
+            lock_id,
+            amount,
+        )?;
+    }
+    Ok(())
+}
+
+/// Unlocks into the owner's associated token account for the lock's mint,
+/// creating it first if needed. The mint, system program and Associated
+/// Token Account program come before the lock's optional accounts, which
+/// are passed on to `process_unlock` in their usual positions.
+fn process_unlock_to_associated_account<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    lock_id: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_token_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let _lock_token_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id()
+        || !solana_system_interface::program::check_id(system_program_info.key)
+        || *associated_token_program_info.key != ASSOCIATED_TOKEN_PROGRAM_ID
+    {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if *mint_info.key != lock.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+    if *owner_token_info.key != associated_token_address(owner_info.key, &lock.mint) {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    // `CreateIdempotent`, so an existing account is simply reused
+    invoke(
+        &Instruction {
+            program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*owner_info.key, true),
+                AccountMeta::new(*owner_token_info.key, false),
+                AccountMeta::new_readonly(*owner_info.key, false),
+                AccountMeta::new_readonly(*mint_info.key, false),
+                AccountMeta::new_readonly(*system_program_info.key, false),
+                AccountMeta::new_readonly(*token_program_info.key, false),
+            ],
+            data: vec![1],
+        },
+        &[
+            owner_info.clone(),
+            owner_token_info.clone(),
+            mint_info.clone(),
+            system_program_info.clone(),
+            token_program_info.clone(),
+            associated_token_program_info.clone(),
+        ],
+    )?;
+
+    let mut unlock_accounts = accounts[..5].to_vec();
+    unlock_accounts.extend_from_slice(accounts.get(8..).unwrap_or(&[]));
+    process_unlock(program_id, &unlock_accounts, lock_id, UnlockAuthority::Owner)
+}
+
+/// Closes an emptied lock and its escrow, returning their rent to the
+/// owner. Anyone may call it, so the owner need not sign again.
+fn process_close_lock(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let lock_id_bytes = lock.lock_id.to_le_bytes();
+    let (lock_pda, _) = Pubkey::find_program_address(
+        &[
+            LOCK_SEED,
+            owner_info.key.as_ref(),
+            lock.mint.as_ref(),
+            &lock_id_bytes,
+        ],
+        program_id,
+    );
+    let (lock_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *lock_account_info.key != lock_pda || *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if lock.amount != 0 {
+        return Err(LocksmithError::LockNotEmpty.into());
+    }
+    let lock_seeds: &[&[u8]] = &[
+        LOCK_SEED,
+        owner_info.key.as_ref(),
+        lock.mint.as_ref(),
+        &lock_id_bytes,
+        &[lock.bump],
+    ];
+
+    // Anyone can transfer tokens into the escrow, so rather than block the
+    // close they go where the lock would have paid out. A native escrow
+    // closes with its balance, paying any stray lamports to the owner.
+    let lock_token = TokenAccount::unpack(&lock_token_info.data.borrow())?;
+    if lock_token.amount != 0 && !lock_token.is_native() {
+        let destination = stray_token_destination(&lock);
+        let destination_info = accounts
+            .iter()
+            .skip(4)
+            .find(|info| *info.key == destination)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let destination_token = TokenAccount::unpack(&destination_info.data.borrow())?;
+        // The owner may have reassigned their own account with `SetAuthority`
+        if lock.unlock_destination == Pubkey::default() && destination_token.owner != lock.owner {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        if destination_token.mint != lock.mint {
+            return Err(LocksmithError::InvalidMint.into());
+        }
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                lock_token_info.key,
+                destination_info.key,
+                lock_account_info.key,
+                &[],
+                lock_token.amount,
+            )?,
+            &[
+                lock_token_info.clone(),
+                destination_info.clone(),
+                lock_account_info.clone(),
+            ],
+            &[lock_seeds],
+        )?;
+        msg!("Swept {} stray tokens to {}", lock_token.amount, destination);
+    }
+
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program_info.key,
+            lock_token_info.key,
+            owner_info.key,
+            lock_account_info.key,
+            &[],
+        )?,
+        &[
+            lock_token_info.clone(),
+            owner_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[lock_seeds],
+    )?;
+    uncount_owner_lock(program_id, accounts, &lock)?;
+    close_program_account(lock_account_info, owner_info)?;
+
+    msg!("Closed lock {}", lock_account_info.key);
+    Ok(())
+}
+
+/// Where `CloseLock` sweeps tokens left in a lock's escrow: the lock's
+/// committed unlock destination, or else the owner's associated token
+/// account for its mint.
+fn stray_token_destination(lock: &LockAccount) -> Pubkey {
+    if lock.unlock_destination != Pubkey::default() {
+        lock.unlock_destination
+    } else {
+        associated_token_address(&lock.owner, &lock.mint)
+    }
+}
+
+/// Moves a lock's unlock timestamp later, keeping its creation time so the
+/// lock's history stays continuous for anyone who has verified it.
+fn process_extend_lock(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lock_id: u64,
+    new_unlock_timestamp: i64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let (lock_pda, _) = Pubkey::find_program_address(
+        &[
+            LOCK_SEED,
+            owner_info.key.as_ref(),
+            lock.mint.as_ref(),
+            &lock_id.to_le_bytes(),
+        ],
+        program_id,
+    );
+    if *lock_account_info.key != lock_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if new_unlock_timestamp <= Clock::get()?.unix_timestamp {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+    validate_extension(&lock, new_unlock_timestamp)?;
+
+    let previous = lock.unlock_timestamp;
+    lock.unlock_timestamp = new_unlock_timestamp;
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    msg!(
+        "Lock {} extended from {} to {}",
+        lock_account_info.key,
+        previous,
+        new_unlock_timestamp
+    );
+    Ok(())
+}
+
+/// Starts a new term for a matured lock in place. Unlike `ExtendLock`, the
+/// creation time moves to now, so the new term may run the full
+/// `MAX_LOCK_DURATION_SECONDS` and audits measure it on its own.
+fn process_relock(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lock_id: u64,
+    new_unlock_timestamp: i64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let (lock_pda, _) = Pubkey::find_program_address(
+        &[
+            LOCK_SEED,
+            owner_info.key.as_ref(),
+            lock.mint.as_ref(),
+            &lock_id.to_le_bytes(),
+        ],
+        program_id,
+    );
+    if *lock_account_info.key != lock_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let clock = Clock::get()?;
+    validate_relock(&lock, &clock, new_unlock_timestamp)?;
+
+    lock.unlock_timestamp = new_unlock_timestamp;
+    lock.created_at = clock.unix_timestamp;
+    lock.unlock_slot = 0;
+    lock.unlock_epoch = 0;
+    // The arbiter approves each lock period's milestone afresh
+    lock.milestone_approved = false;
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    msg!("Lock {} relocked until {}", lock_account_info.key, new_unlock_timestamp);
+    Ok(())
+}
+
+/// Checks that a lock has matured and may be relocked until
+/// `new_unlock_timestamp`. A claim code still in force keeps its holder's
+/// claim, so such locks must wait for it to expire.
+fn validate_relock(lock: &LockAccount, clock: &Clock, new_unlock_timestamp: i64) -> ProgramResult {
+    if lock.permanent {
+        return Err(LocksmithError::LockIsPermanent.into());
+    }
+    lock.can_change_terms()?;
+    if !lock.unlock_reached(clock) {
+        return Err(LocksmithError::UnlockTooEarly.into());
+    }
+    if lock.claim_active(clock.unix_timestamp) {
+        return Err(LocksmithError::ClaimCodeActive.into());
+    }
+    // A tiered fee was charged on the duration at creation
+    if lock.fee_mode == LOCK_FEE_TIERED {
+        return Err(LocksmithError::FeeDependsOnTerms.into());
+    }
+    validate_lock_duration(
+        new_unlock_timestamp
+            .checked_sub(clock.unix_timestamp)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+    )
+}
+
+/// Checks that a lock's amount can grow: it is not an NFT lock, which holds
+/// exactly the one token, not split among beneficiaries, whose shares are of
+/// the amount when it was split, and was not charged a tier on its amount
+/// and duration, which a top-up could not be priced against.
+fn validate_top_up(lock: &LockAccount) -> ProgramResult {
+    if lock.is_nft {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+    if lock.shared {
+        return Err(LocksmithError::LockShared.into());
+    }
+    if lock.fee_mode == LOCK_FEE_TIERED {
+        return Err(LocksmithError::FeeDependsOnTerms.into());
+    }
+    Ok(())
+}
+
+/// Tops up a lock's escrow from the owner's token account. The lock keeps
+/// its terms; only its amount grows, less the percentage fee if it was
+/// charged one.
+fn process_increase_lock_amount(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lock_id: u64,
+    additional_amount: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_token_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    validate_top_up(&lock)?;
+
+    let (lock_pda, _) = Pubkey::find_program_address(
+        &[
+            LOCK_SEED,
+            owner_info.key.as_ref(),
+            lock.mint.as_ref(),
+            &lock_id.to_le_bytes(),
+        ],
+        program_id,
+    );
+    if *lock_account_info.key != lock_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let (lock_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let lock_token = TokenAccount::unpack(&lock_token_info.data.borrow())?;
+    if lock_token.amount != lock.amount {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    let owner_token = TokenAccount::unpack(&owner_token_info.data.borrow())?;
+    if owner_token.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if owner_token.mint != lock.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+    let additional_amount = resolve_lock_amount(additional_amount, owner_token.amount, 0);
+    if additional_amount == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+    if owner_token.amount < additional_amount {
+        return Err(LocksmithError::InsufficientFunds.into());
+    }
+
+    // A percentage fee was charged on the amount at creation, so the added
+    // tokens pay it too, out of the top-up. Once the mint's cap is gone the
+    // top-up is free, as for a flat-fee lock.
+    let token_fee = if lock.fee_mode == LOCK_FEE_PERCENTAGE {
+        let config = find_config(program_id, accounts)?;
+        let fee = lock_fee(
+            program_id,
+            accounts,
+            &config,
+            &lock.mint,
+            false,
+            owner_info.key,
+            None,
+            0,
+            additional_amount,
+            false,
+        )?;
+        match fee.bps_cap {
+            Some(cap) => Some(load_bps_fee(
+                program_id,
+                accounts,
+                &config,
+                cap,
+                &lock.mint,
+                additional_amount,
+            )?),
+            None => None,
+        }
+    } else {
+        None
+    };
+    let locked_amount = additional_amount - token_fee.map_or(0, |(fee, _, _)| fee);
+    if locked_amount == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    lock.amount = lock
+        .amount
+        .checked_add(locked_amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            owner_token_info.key,
+            lock_token_info.key,
+            owner_info.key,
+            &[],
+            locked_amount,
+        )?,
+        &[
+            owner_token_info.clone(),
+            lock_token_info.clone(),
+            owner_info.clone(),
+        ],
+    )?;
+
+    if let Some((token_fee, token_fee_vault_info, token_fee_vault_bump)) = token_fee {
+        let mint_info = accounts
+            .iter()
+            .find(|info| *info.key == lock.mint)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let system_program_info = accounts
+            .iter()
+            .find(|info| solana_system_interface::program::check_id(info.key))
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        pay_token_fee(
+            token_fee,
+            owner_token_info,
+            owner_info,
+            &[],
+            token_fee_vault_info,
+            token_fee_vault_bump,
+            owner_info,
+            mint_info,
+            token_program_info,
+            system_program_info,
+        )?;
+    }
+
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    #[cfg(feature = "strict-invariants")]
+    invariants::expect_eq(
+        "escrow balance",
+        invariants::token_amount(lock_token_info)?,
+        lock.amount,
+    )?;
+
+    msg!(
+        "Added {} tokens to lock {}, now {}",
+        locked_amount,
+        lock_account_info.key,
+        lock.amount
+    );
+    Ok(())
+}
+
+/// Checks that `new_unlock_timestamp` extends a timestamp-based lock and
+/// stays within `MAX_LOCK_DURATION_SECONDS` of its creation.
+fn validate_extension(lock: &LockAccount, new_unlock_timestamp: i64) -> ProgramResult {
+    if lock.permanent {
+        return Err(LocksmithError::LockIsPermanent.into());
+    }
+    lock.can_change_terms()?;
+    if lock.unlock_slot != 0 || lock.unlock_epoch != 0 {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+    // A tiered fee was charged on the duration at creation
+    if lock.fee_mode == LOCK_FEE_TIERED {
+        return Err(LocksmithError::FeeDependsOnTerms.into());
+    }
+    if new_unlock_timestamp <= lock.unlock_timestamp {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+    match new_unlock_timestamp.checked_sub(lock.created_at) {
+        Some(duration) if duration <= MAX_LOCK_DURATION_SECONDS => Ok(()),
+        _ => Err(LocksmithError::LockDurationExceeded.into()),
+    }
+}
+
+/// Creates a vesting lock for `owner`, funded by the signing funder who also
+/// pays the fee and rent. The owner may be the funder.
+#[allow(clippy::too_many_arguments)]
+fn process_initialize_vesting(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    total_amount: u64,
+    start_timestamp: i64,
+    end_timestamp: i64,
+    vesting_id: u64,
+    cliff_timestamp: i64,
+    revocable: bool,
+    fee_in_lamports: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let funder_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let funder_token_info = next_account_info(account_info_iter)?;
+    let funder_usdc_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let vesting_info = next_account_info(account_info_iter)?;
+    let vesting_token_info = next_account_info(account_info_iter)?;
+    let fee_vault_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !funder_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if total_amount == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config, fee_mint) =
+        load_config_for_new_lock(program_id, fee_vault_info, mint_info, accounts)?;
+
+    let clock = Clock::get()?;
+    if start_timestamp >= end_timestamp || end_timestamp <= clock.unix_timestamp {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+    let cliff_timestamp = if cliff_timestamp == 0 {
+        start_timestamp
+    } else {
+        cliff_timestamp
+    };
+    if cliff_timestamp < start_timestamp || cliff_timestamp > end_timestamp {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+    let max_end_timestamp = clock
+        .unix_timestamp
+        .checked_add(MAX_LOCK_DURATION_SECONDS)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if end_timestamp > max_end_timestamp {
+        return Err(LocksmithError::LockDurationExceeded.into());
+    }
+
+    let funder_token = TokenAccount::unpack(&funder_token_info.data.borrow())?;
+    if funder_token.owner != *funder_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if funder_token.mint != *mint_info.key {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    // Priced like a lock until the end of the vesting
+    let is_nft = is_nft_mint(&Mint::unpack(&mint_info.data.borrow())?);
+    let tier_amount = if total_amount == LOCK_FULL_BALANCE {
+        funder_token.amount
+    } else {
+        total_amount
+    };
+    let LockFee {
+        usdc: fee_usdc,
+        sol: sol_fee,
+        bps_cap: fee_cap,
+        ..
+    } = lock_fee(
+        program_id,
+        accounts,
+        &config,
+        mint_info.key,
+        is_nft,
+        funder_info.key,
+        None,
+        end_timestamp.abs_diff(clock.unix_timestamp),
+        tier_amount,
+        fee_in_lamports,
+    )?;
+
+    let fee_from_same_account = if funder_token_info.key == funder_usdc_info.key {
+        fee_usdc
+    } else {
+        0
+    };
+    let total_amount =
+        resolve_lock_amount(total_amount, funder_token.amount, fee_from_same_account);
+    if total_amount == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+    if funder_token.amount < total_amount {
+        return Err(LocksmithError::InsufficientFunds.into());
+    }
+
+    if fee_usdc > 0 {
+        let funder_usdc = TokenAccount::unpack(&funder_usdc_info.data.borrow())?;
+        if funder_usdc.owner != *funder_info.key {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        if funder_usdc.mint != fee_mint {
+            return Err(LocksmithError::InvalidMint.into());
+        }
+        if funder_usdc.amount < fee_usdc {
+            return Err(LocksmithError::InsufficientFunds.into());
+        }
+    }
+
+    // The percentage fee comes out of the deposit, so the vesting lock
+    // holds the rest
+    let token_fee = if let Some(cap) = fee_cap {
+        Some(load_bps_fee(
+            program_id,
+            accounts,
+            &config,
+            cap,
+            mint_info.key,
+            total_amount,
+        )?)
+    } else {
+        None
+    };
+    let vested_amount = total_amount - token_fee.map_or(0, |(fee, _, _)| fee);
+    if vested_amount == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    let vesting_id_bytes = vesting_id.to_le_bytes();
+    let (vesting_pda, vesting_bump) = Pubkey::find_program_address(
+        &[
+            VESTING_SEED,
+            owner_info.key.as_ref(),
+            mint_info.key.as_ref(),
+            &vesting_id_bytes,
+        ],
+        program_id,
+    );
+    if *vesting_info.key != vesting_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let (vesting_token_pda, vesting_token_bump) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, vesting_info.key.as_ref()], program_id);
+    if *vesting_token_info.key != vesting_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let rent = Rent::get()?;
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_info.key,
+            vesting_info.key,
+            rent.minimum_balance(VestingLockAccount::SIZE),
+            VestingLockAccount::SIZE as u64,
+            program_id,
+        ),
+        &[
+            funder_info.clone(),
+            vesting_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[
+            VESTING_SEED,
+            owner_info.key.as_ref(),
+            mint_info.key.as_ref(),
+            &vesting_id_bytes,
+            &[vesting_bump],
+        ]],
+    )?;
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_info.key,
+            vesting_token_info.key,
+            rent.minimum_balance(TokenAccount::LEN),
+            TokenAccount::LEN as u64,
+            &spl_token::id(),
+        ),
+        &[
+            funder_info.clone(),
+            vesting_token_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[
+            LOCK_TOKEN_SEED,
+            vesting_info.key.as_ref(),
+            &[vesting_token_bump],
+        ]],
+    )?;
+
+    invoke(
+        &spl_token::instruction::initialize_account3(
+            &spl_token::id(),
+            vesting_token_info.key,
+            mint_info.key,
+            vesting_info.key,
+        )?,
+        &[vesting_token_info.clone(), mint_info.clone()],
+    )?;
+
+    let vesting = VestingLockAccount {
+        discriminator: VestingLockAccount::DISCRIMINATOR,
+        owner: *owner_info.key,
+        mint: *mint_info.key,
+        total: vested_amount,
+        claimed: 0,
+        start: start_timestamp,
+        cliff: cliff_timestamp,
+        end: end_timestamp,
+        created_at: clock.unix_timestamp,
+        vesting_id,
+        bump: vesting_bump,
+        grantor: *funder_info.key,
+        revocable,
+    };
+    vesting.pack(&mut vesting_info.data.borrow_mut());
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            funder_token_info.key,
+            vesting_token_info.key,
+            funder_info.key,
+            &[],
+            vested_amount,
+        )?,
+        &[
+            funder_token_info.clone(),
+            vesting_token_info.clone(),
+            funder_info.clone(),
+        ],
+    )?;
+
+    if let Some((fee_lamports, sol_fee_vault_info)) = sol_fee {
+        invoke(
+            &system_instruction::transfer(funder_info.key, sol_fee_vault_info.key, fee_lamports),
+            &[
+                funder_info.clone(),
+                sol_fee_vault_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+        msg!("Fee paid in {} lamports", fee_lamports);
+    } else if fee_usdc > 0 {
+        invoke(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                funder_usdc_info.key,
+                fee_vault_info.key,
+                funder_info.key,
+                &[],
+                fee_usdc,
+            )?,
+            &[
+                funder_usdc_info.clone(),
+                fee_vault_info.clone(),
+                funder_info.clone(),
+            ],
+        )?;
+    }
+
+    if let Some((token_fee, token_fee_vault_info, token_fee_vault_bump)) = token_fee {
+        pay_token_fee(
+            token_fee,
+            funder_token_info,
+            funder_info,
+            &[],
+            token_fee_vault_info,
+            token_fee_vault_bump,
+            funder_info,
+            mint_info,
+            token_program_info,
+            system_program_info,
+        )?;
+    }
+
+    #[cfg(feature = "strict-invariants")]
+    invariants::expect_eq(
+        "escrow balance",
+        invariants::token_amount(vesting_token_info)?,
+        vested_amount,
+    )?;
+
+    msg!(
+        "Vesting created: {} tokens vesting to {} from {} to {}",
+        vested_amount,
+        owner_info.key,
+        start_timestamp,
+        end_timestamp
+    );
+    if cliff_timestamp != start_timestamp {
+        msg!("Nothing claimable before {}", cliff_timestamp);
+    }
+    if revocable {
+        msg!("Revocable by {}", funder_info.key);
+    }
+    Ok(())
+}
+
+/// Pays out everything vested and not yet claimed. The final claim closes
+/// the vesting lock and its escrow, returning their rent to the owner.
+fn process_claim_vested(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    vesting_id: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_token_info = next_account_info(account_info_iter)?;
+    let vesting_info = next_account_info(account_info_iter)?;
+    let vesting_token_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if vesting_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut vesting = VestingLockAccount::unpack(&vesting_info.data.borrow())?;
+    if vesting.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let vesting_id_bytes = vesting_id.to_le_bytes();
+    let (vesting_pda, _) = Pubkey::find_program_address(
+        &[
+            VESTING_SEED,
+            owner_info.key.as_ref(),
+            vesting.mint.as_ref(),
+            &vesting_id_bytes,
+        ],
+        program_id,
+    );
+    if *vesting_info.key != vesting_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let (vesting_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, vesting_info.key.as_ref()], program_id);
+    if *vesting_token_info.key != vesting_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let vesting_token = TokenAccount::unpack(&vesting_token_info.data.borrow())?;
+    if Some(vesting_token.amount) != vesting.total.checked_sub(vesting.claimed) {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    let owner_token = TokenAccount::unpack(&owner_token_info.data.borrow())?;
+    if owner_token.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if owner_token.mint != vesting.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    let clock = Clock::get()?;
+    let claimable = vesting
+        .vested_at(clock.unix_timestamp)
+        .saturating_sub(vesting.claimed);
+    if claimable == 0 {
+        return Err(LocksmithError::NothingVested.into());
+    }
+    vesting.claimed += claimable;
+
+    let signer_seeds: &[&[u8]] = &[
+        VESTING_SEED,
+        owner_info.key.as_ref(),
+        vesting.mint.as_ref(),
+        &vesting_id_bytes,
+        &[vesting.bump],
+    ];
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            vesting_token_info.key,
+            owner_token_info.key,
+            vesting_info.key,
+            &[],
+            claimable,
+        )?,
+        &[
+            vesting_token_info.clone(),
+            owner_token_info.clone(),
+            vesting_info.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    if vesting.claimed == vesting.total {
+        invoke_signed(
+            &spl_token::instruction::close_account(
+                token_program_info.key,
+                vesting_token_info.key,
+                owner_info.key,
+                vesting_info.key,
+                &[],
+            )?,
+            &[
+                vesting_token_info.clone(),
+                owner_info.clone(),
+                vesting_info.clone(),
+            ],
+            &[signer_seeds],
+        )?;
+        close_program_account(vesting_info, owner_info)?;
+        msg!("Claimed {} tokens, vesting complete", claimable);
+        return Ok(());
+    }
+
+    vesting.pack(&mut vesting_info.data.borrow_mut());
+
+    #[cfg(feature = "strict-invariants")]
+    invariants::expect_eq(
+        "escrow balance",
+        Some(invariants::token_amount(vesting_token_info)?),
+        vesting.total.checked_sub(vesting.claimed),
+    )?;
+
+    msg!(
+        "Claimed {} tokens, {} of {} vested so far",
+        claimable,
+        vesting.claimed,
+        vesting.total
+    );
+    Ok(())
+}
+
+/// Claws the unvested remainder of a revocable vesting lock back to its
+/// grantor. What has vested stays claimable by the owner; when nothing is
+/// left to claim the vesting lock and escrow are closed, refunding the
+/// grantor's rent.
+fn process_revoke_vesting(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    vesting_id: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let grantor_info = next_account_info(account_info_iter)?;
+    let grantor_token_info = next_account_info(account_info_iter)?;
+    let vesting_info = next_account_info(account_info_iter)?;
+    let vesting_token_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !grantor_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if vesting_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut vesting = VestingLockAccount::unpack(&vesting_info.data.borrow())?;
+    if vesting.grantor != *grantor_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if !vesting.revocable {
+        return Err(LocksmithError::VestingNotRevocable.into());
+    }
+
+    let vesting_id_bytes = vesting_id.to_le_bytes();
+    let (vesting_pda, _) = Pubkey::find_program_address(
+        &[
+            VESTING_SEED,
+            vesting.owner.as_ref(),
+            vesting.mint.as_ref(),
+            &vesting_id_bytes,
+        ],
+        program_id,
+    );
+    if *vesting_info.key != vesting_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let (vesting_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, vesting_info.key.as_ref()], program_id);
+    if *vesting_token_info.key != vesting_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let vesting_token = TokenAccount::unpack(&vesting_token_info.data.borrow())?;
+    if Some(vesting_token.amount) != vesting.total.checked_sub(vesting.claimed) {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    let grantor_token = TokenAccount::unpack(&grantor_token_info.data.borrow())?;
+    if grantor_token.owner != *grantor_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if grantor_token.mint != vesting.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    let clock = Clock::get()?;
+    let unvested = vesting.revoke(clock.unix_timestamp);
+    if unvested == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    let signer_seeds: &[&[u8]] = &[
+        VESTING_SEED,
+        vesting.owner.as_ref(),
+        vesting.mint.as_ref(),
+        &vesting_id_bytes,
+        &[vesting.bump],
+    ];
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            vesting_token_info.key,
+            grantor_token_info.key,
+            vesting_info.key,
+            &[],
+            unvested,
+        )?,
+        &[
+            vesting_token_info.clone(),
+            grantor_token_info.clone(),
+            vesting_info.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    if vesting.claimed == vesting.total {
+        invoke_signed(
+            &spl_token::instruction::close_account(
+                token_program_info.key,
+                vesting_token_info.key,
+                grantor_info.key,
+                vesting_info.key,
+                &[],
+            )?,
+            &[
+                vesting_token_info.clone(),
+                grantor_info.clone(),
+                vesting_info.clone(),
+            ],
+            &[signer_seeds],
+        )?;
+        close_program_account(vesting_info, grantor_info)?;
+        msg!("Revoked vesting, {} tokens returned to the grantor", unvested);
+        return Ok(());
+    }
+
+    vesting.pack(&mut vesting_info.data.borrow_mut());
+
+    #[cfg(feature = "strict-invariants")]
+    invariants::expect_eq(
+        "escrow balance",
+        Some(invariants::token_amount(vesting_token_info)?),
+        vesting.total.checked_sub(vesting.claimed),
+    )?;
+
+    msg!(
+        "Revoked vesting, {} tokens returned to the grantor and {} left to claim",
+        unvested,
+        vesting.total - vesting.claimed
+    );
+    Ok(())
+}
+
+/// Sets the share of a lock's tokens `EmergencyUnlock` forfeits to the
+/// penalty vault. Growing an older config is paid for by the admin.
+fn process_set_early_unlock_penalty(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    penalty_bps: u16,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if penalty_bps > MAX_EARLY_UNLOCK_PENALTY_BPS {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    config.early_unlock_penalty_bps = penalty_bps;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    msg!("Early unlock penalty set to {} bps", penalty_bps);
+    Ok(())
+}
+
+/// Releases a lock created with `early_unlock_allowed` before it unlocks.
+/// The configured penalty goes to the mint's penalty vault, which the owner
+/// creates on first use, and the rest to the owner; the lock and escrow
+/// are closed as by `Unlock`. Donations, forwarding and hooks do not apply.
+fn process_emergency_unlock(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lock_id: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_token_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let penalty_vault_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if !lock.early_unlock_allowed {
+        return Err(LocksmithError::EarlyUnlockNotAllowed.into());
+    }
+
+    let clock = Clock::get()?;
+    lock.can_pay_out_to_owner(clock.unix_timestamp)?;
+    // Matured locks are unlocked without a penalty
+    if lock.unlock_reached(&clock) {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+
+    let lock_id_bytes = lock_id.to_le_bytes();
+    let (lock_pda, lock_bump) = Pubkey::find_program_address(
+        &[
+            LOCK_SEED,
+            owner_info.key.as_ref(),
+            lock.mint.as_ref(),
+            &lock_id_bytes,
+        ],
+        program_id,
+    );
+    if *lock_account_info.key != lock_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let (lock_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let lock_token = TokenAccount::unpack(&lock_token_info.data.borrow())?;
+    if lock_token.amount != lock.amount {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    let owner_token = TokenAccount::unpack(&owner_token_info.data.borrow())?;
+    if owner_token.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if owner_token.mint != lock.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+    if *mint_info.key != lock.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    let (penalty_vault_pda, penalty_vault_bump) = Pubkey::find_program_address(
+        &[PENALTY_VAULT_SEED, lock.mint.as_ref()],
+        program_id,
+    );
+    if *penalty_vault_info.key != penalty_vault_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let penalty = bps_share(lock.amount, config.early_unlock_penalty_bps);
+    let payout = lock.amount - penalty;
+    let lock_seeds: &[&[u8]] = &[
+        LOCK_SEED,
+        owner_info.key.as_ref(),
+        lock.mint.as_ref(),
+        &lock_id_bytes,
+        &[lock_bump],
+    ];
+
+    if penalty > 0 {
+        if penalty_vault_info.data_is_empty() {
+            create_mint_vault(
+                PENALTY_VAULT_SEED,
+                owner_info,
+                penalty_vault_info,
+                mint_info,
+                penalty_vault_bump,
+                system_program_info,
+            )?;
+        }
+
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                lock_token_info.key,
+                penalty_vault_info.key,
+                lock_account_info.key,
+                &[],
+                penalty,
+            )?,
+            &[
+                lock_token_info.clone(),
+                penalty_vault_info.clone(),
+                lock_account_info.clone(),
+            ],
+            &[lock_seeds],
+        )?;
+    }
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            lock_token_info.key,
+            owner_token_info.key,
+            lock_account_info.key,
+            &[],
+            payout,
+        )?,
+        &[
+            lock_token_info.clone(),
+            owner_token_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[lock_seeds],
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program_info.key,
+            lock_token_info.key,
+            owner_info.key,
+            lock_account_info.key,
+            &[],
+        )?,
+        &[
+            lock_token_info.clone(),
+            owner_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[lock_seeds],
+    )?;
+
+    uncount_owner_lock(program_id, accounts, &lock)?;
+    close_program_account(lock_account_info, owner_info)?;
+
+    #[cfg(feature = "strict-invariants")]
+    invariants::expect_eq(
+        "owner token balance",
+        owner_token.amount.checked_add(payout),
+        Some(invariants::token_amount(owner_token_info)?),
+    )?;
+
+    msg!(
+        "Emergency unlocked {} tokens, {} forfeited as penalty",
+        payout,
+        penalty
+    );
+    Ok(())
+}
+
+/// Withdraws a penalty vault's whole balance to a token account of the
+/// admin's choosing.
+fn process_withdraw_penalties(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let (amount, mint) = withdraw_mint_vault(program_id, accounts, PENALTY_VAULT_SEED)?;
+    if amount > 0 {
+        msg!("Withdrawn {} penalty tokens of mint {}", amount, mint);
+    }
+    Ok(())
+}
+
+/// Withdraws a token fee vault's whole balance to a token account of the
+/// admin's choosing.
+fn process_withdraw_token_fees(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let (amount, mint) = withdraw_mint_vault(program_id, accounts, TOKEN_FEE_VAULT_SEED)?;
+    if amount > 0 {
+        msg!("Withdrawn {} fee tokens of mint {}", amount, mint);
+    }
+    Ok(())
+}
+
+/// Empties the admin's vault [`seed`, mint] into the admin's token account,
+/// returning the amount withdrawn and the vault's mint.
+fn withdraw_mint_vault(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    seed: &[u8],
+) -> Result<(u64, Pubkey), ProgramError> {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let vault_info = next_account_info(account_info_iter)?;
+    let admin_token_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if *vault_info.owner != spl_token::id() {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    let vault = TokenAccount::unpack(&vault_info.data.borrow())?;
+    let (vault_pda, vault_bump) =
+        Pubkey::find_program_address(&[seed, vault.mint.as_ref()], program_id);
+    if *vault_info.key != vault_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let amount = vault.amount;
+    if amount == 0 {
+        return Ok((0, vault.mint));
+    }
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            vault_info.key,
+            admin_token_info.key,
+            vault_info.key,
+            &[],
+            amount,
+        )?,
+        &[vault_info.clone(), admin_token_info.clone(), vault_info.clone()],
+        &[&[seed, vault.mint.as_ref(), &[vault_bump]]],
+    )?;
+
+    #[cfg(feature = "strict-invariants")]
+    invariants::expect_eq("vault balance", invariants::token_amount(vault_info)?, 0)?;
+
+    Ok((amount, vault.mint))
+}
+
+/// Claims an unlocked claim-code lock before its code expires for whoever
+/// holds the code, proven by the claim key's signature over the claimant's
+/// token account. The tokens go to the claimant and the lock and escrow
+/// rent to the owner. The code never appears on chain and the signature
+/// names both this lock and the destination, so a pending claim cannot be
+/// redirected by anyone watching it.
+fn process_claim_lock(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let claimant_info = next_account_info(account_info_iter)?;
+    let claimant_token_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let instructions_sysvar_info = next_account_info(account_info_iter)?;
+
+    if !claimant_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let lock_id_bytes = lock.lock_id.to_le_bytes();
+    let lock_seeds: &[&[u8]] = &[
+        LOCK_SEED,
+        owner_info.key.as_ref(),
+        lock.mint.as_ref(),
+        &lock_id_bytes,
+        &[lock.bump],
+    ];
+    if *lock_account_info.key != Pubkey::create_program_address(lock_seeds, program_id)? {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let (lock_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let clock = Clock::get()?;
+    check_claim(&lock, &clock)?;
+    verify_preceding_ed25519_signature(
+        instructions_sysvar_info,
+        &Pubkey::new_from_array(lock.claim_key),
+        &claim_lock_message(program_id, lock_account_info.key, claimant_token_info.key),
+    )
+    .map_err(|_| LocksmithError::InvalidClaimCode)?;
+
+    let lock_token = TokenAccount::unpack(&lock_token_info.data.borrow())?;
+    if lock_token.amount != lock.amount {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    let claimant_token = TokenAccount::unpack(&claimant_token_info.data.borrow())?;
+    if claimant_token.mint != lock.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            lock_token_info.key,
+            claimant_token_info.key,
+            lock_account_info.key,
+            &[],
+            lock.amount,
+        )?,
+        &[
+            lock_token_info.clone(),
+            claimant_token_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[lock_seeds],
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program_info.key,
+            lock_token_info.key,
+            owner_info.key,
+            lock_account_info.key,
+            &[],
+        )?,
+        &[
+            lock_token_info.clone(),
+            owner_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[lock_seeds],
+    )?;
+
+    uncount_owner_lock(program_id, accounts, &lock)?;
+    close_program_account(lock_account_info, owner_info)?;
+
+    msg!("Claimed {} tokens for {}", lock.amount, claimant_info.key);
+    Ok(())
+}
+
+/// Checks that `lock` is a claim-code lock that can be claimed now:
+/// unlocked, its code not yet expired, and neither frozen nor
+/// collateralized.
+fn check_claim(lock: &LockAccount, clock: &Clock) -> ProgramResult {
+    if lock.claim_key == [0u8; 32] || clock.unix_timestamp >= lock.claim_expires_at {
+        return Err(LocksmithError::InvalidClaimCode.into());
+    }
+    if lock.frozen {
+        return Err(LocksmithError::LockFrozen.into());
+    }
+    if lock.collateral_holder != Pubkey::default() {
+        return Err(LocksmithError::LockCollateralized.into());
+    }
+    if !lock.unlock_reached(clock) {
+        return Err(LocksmithError::UnlockTooEarly.into());
+    }
+    Ok(())
+}
+
+/// Authorizes a session key to unlock the owner's locks within a scope, or
+/// revokes it when `expires_at` is 0. Updating an existing session replaces
+/// its scope and expiry.
+fn process_set_session(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint: &Pubkey,
+    expires_at: i64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let session_info = next_account_info(account_info_iter)?;
+    let session_key_info = next_account_info(account_info_iter)?;
+    let destination_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (session_pda, session_bump) = Pubkey::find_program_address(
+        &[SESSION_SEED, owner_info.key.as_ref(), session_key_info.key.as_ref()],
+        program_id,
+    );
+    if *session_info.key != session_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if expires_at == 0 {
+        if session_info.owner == program_id {
+            close_program_account(session_info, owner_info)?;
+        }
+        msg!("Session key {} revoked", session_key_info.key);
+        return Ok(());
+    }
+
+    if expires_at <= Clock::get()?.unix_timestamp {
+        return Err(LocksmithError::InvalidTimestamp.into());
+    }
+
+    if *destination_info.owner != spl_token::id() {
+        return Err(LocksmithError::InvalidSession.into());
+    }
+    let destination = TokenAccount::unpack(&destination_info.data.borrow())?;
+    if destination.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if *mint != Pubkey::default() && destination.mint != *mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    if session_info.data_is_empty() {
+        let rent = Rent::get()?;
+        invoke_signed(
+            &system_instruction::create_account(
+                owner_info.key,
+                session_info.key,
+                rent.minimum_balance(SessionAccount::SIZE),
+                SessionAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                owner_info.clone(),
+                session_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[
+                SESSION_SEED,
+                owner_info.key.as_ref(),
+                session_key_info.key.as_ref(),
+                &[session_bump],
+            ]],
+        )?;
+    }
+
+    SessionAccount {
+        discriminator: SessionAccount::DISCRIMINATOR,
+        owner: *owner_info.key,
+        session_key: *session_key_info.key,
+        mint: *mint,
+        destination: *destination_info.key,
+        expires_at,
+        bump: session_bump,
+    }
+    .pack(&mut session_info.data.borrow_mut());
+
+    msg!(
+        "Session key {} may unlock into {} until {}",
+        session_key_info.key,
+        destination_info.key,
+        expires_at
+    );
+    Ok(())
+}
+
+/// Unlocks a lock on a session key's signature: checks the session, then
+/// unlocks as `Unlock` would with the remaining accounts.
+fn process_unlock_with_session(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lock_id: u64,
+) -> ProgramResult {
+    let (session_key_info, accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let (session_info, accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if !session_key_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if session_info.owner != program_id {
+        return Err(LocksmithError::InvalidSession.into());
+    }
+    let session = SessionAccount::unpack(&session_info.data.borrow())?;
+    if session.owner != *owner_info.key || session.session_key != *session_key_info.key {
+        return Err(LocksmithError::InvalidSession.into());
+    }
+
+    process_unlock(program_id, accounts, lock_id, UnlockAuthority::Session(&session))
+}
+
+/// Checks that a session may unlock `lock` into `destination` at `now`:
+/// unexpired, within its mint scope, into its destination, and without
+/// unlock forwarding, whose deposit needs the owner's own signature.
+fn check_session(
+    session: &SessionAccount,
+    lock: &LockAccount,
+    destination: &Pubkey,
+    now: i64,
+) -> ProgramResult {
+    if now >= session.expires_at
+        || session.owner != lock.owner
+        || (session.mint != Pubkey::default() && session.mint != lock.mint)
+        || session.destination != *destination
+        || lock.forward_destination != Pubkey::default()
+    {
+        return Err(LocksmithError::InvalidSession.into());
+    }
+    Ok(())
+}
+
+/// Attaches an encrypted note to a lock, or deletes it when `note_len` is 0.
+/// The program never interprets the note. Notes of closed locks can only be
+/// deleted, by the owner who last wrote them.
+fn process_set_lock_note(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    note: &[u8; MAX_NOTE_LEN],
+    note_len: u16,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let note_account_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let lock_open = lock_account_info.owner == program_id;
+    if lock_open {
+        let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+        if lock.owner != *owner_info.key {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+    }
+
+    let (note_pda, note_bump) =
+        Pubkey::find_program_address(&[NOTE_SEED, lock_account_info.key.as_ref()], program_id);
+    if *note_account_info.key != note_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if note_len == 0 {
+        if note_account_info.owner == program_id {
+            if !lock_open {
+                let existing = NoteAccount::unpack(&note_account_info.data.borrow())?;
+                if existing.owner != *owner_info.key {
+                    return Err(LocksmithError::Unauthorized.into());
+                }
+            }
+            close_program_account(note_account_info, owner_info)?;
+        }
+        msg!("Lock {} note deleted", lock_account_info.key);
+        return Ok(());
+    }
+
+    if !lock_open {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if note_account_info.data_is_empty() {
+        let rent = Rent::get()?;
+        invoke_signed(
+            &system_instruction::create_account(
+                owner_info.key,
+                note_account_info.key,
+                rent.minimum_balance(NoteAccount::SIZE),
+                NoteAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                owner_info.clone(),
+                note_account_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[NOTE_SEED, lock_account_info.key.as_ref(), &[note_bump]]],
+        )?;
+    }
+
+    let len = note_len as usize;
+    let mut padded = [0u8; MAX_NOTE_LEN];
+    padded[..len].copy_from_slice(&note[..len]);
+    NoteAccount {
+        discriminator: NoteAccount::DISCRIMINATOR,
+        lock: *lock_account_info.key,
+        owner: *owner_info.key,
+        note_len,
+        note: padded,
+        bump: note_bump,
+    }
+    .pack(&mut note_account_info.data.borrow_mut());
+
+    msg!("Lock {} note set ({} bytes)", lock_account_info.key, note_len);
+    Ok(())
+}
+
+/// Records a lock in its mint's index under the index's next sequence
+/// number. Concurrent indexing of the same mint derives the same entry
+/// address, so all but the first such transaction fail and must be retried.
+fn process_index_lock(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let payer_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let mint_index_info = next_account_info(account_info_iter)?;
+    let index_entry_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.index_seq != 0 {
+        return Err(LocksmithError::LockAlreadyIndexed.into());
+    }
+
+    let (index_pda, index_bump) =
+        Pubkey::find_program_address(&[MINT_INDEX_SEED, lock.mint.as_ref()], program_id);
+    if *mint_index_info.key != index_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let rent = Rent::get()?;
+    let mut index = if mint_index_info.data_is_empty() {
+        invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                mint_index_info.key,
+                rent.minimum_balance(MintIndexAccount::SIZE),
+                MintIndexAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                payer_info.clone(),
+                mint_index_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[MINT_INDEX_SEED, lock.mint.as_ref(), &[index_bump]]],
+        )?;
+        MintIndexAccount {
+            discriminator: MintIndexAccount::DISCRIMINATOR,
+            mint: lock.mint,
+            total_locks: 0,
+            active_locks: 0,
+            bump: index_bump,
+        }
+    } else {
+        if mint_index_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        MintIndexAccount::unpack(&mint_index_info.data.borrow())?
+    };
+
+    let seq = index
+        .total_locks
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let seq_bytes = seq.to_le_bytes();
+    let (entry_pda, entry_bump) = Pubkey::find_program_address(
+        &[MINT_INDEX_SEED, lock.mint.as_ref(), &seq_bytes],
+        program_id,
+    );
+    if *index_entry_info.key != entry_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            payer_info.key,
+            index_entry_info.key,
+            rent.minimum_balance(IndexEntryAccount::SIZE),
+            IndexEntryAccount::SIZE as u64,
+            program_id,
+        ),
+        &[
+            payer_info.clone(),
+            index_entry_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[MINT_INDEX_SEED, lock.mint.as_ref(), &seq_bytes, &[entry_bump]]],
+    )?;
+    IndexEntryAccount {
+        discriminator: IndexEntryAccount::DISCRIMINATOR,
+        mint: lock.mint,
+        lock: *lock_account_info.key,
+        payer: *payer_info.key,
+        seq,
+        bump: entry_bump,
+    }
+    .pack(&mut index_entry_info.data.borrow_mut());
+
+    index.total_locks = seq;
+    index.active_locks = index
+        .active_locks
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    index.pack(&mut mint_index_info.data.borrow_mut());
+
+    grow_lock(lock_account_info, &mut lock, payer_info, system_program_info)?;
+    lock.index_seq = seq;
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    msg!("Lock {} indexed as {} of mint {}", lock_account_info.key, seq, lock.mint);
+    Ok(())
+}
+
+/// Removes a closed lock's entry from its mint's index, returning the
+/// entry's rent to its payer.
+fn process_unindex_lock(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let mint_index_info = next_account_info(account_info_iter)?;
+    let index_entry_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+
+    if index_entry_info.owner != program_id || mint_index_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let entry = IndexEntryAccount::unpack(&index_entry_info.data.borrow())?;
+
+    let (index_pda, _) =
+        Pubkey::find_program_address(&[MINT_INDEX_SEED, entry.mint.as_ref()], program_id);
+    if *mint_index_info.key != index_pda || *lock_account_info.key != entry.lock {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    if *payer_info.key != entry.payer {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    // A lock closed earlier in this transaction is program-owned but zeroed
+    let lock_open = lock_account_info.owner == program_id
+        && LockAccount::unpack(&lock_account_info.data.borrow())
+            .is_ok_and(|lock| lock.index_seq == entry.seq);
+    if lock_open {
+        return Err(LocksmithError::IndexEntryActive.into());
+    }
+
+    let mut index = MintIndexAccount::unpack(&mint_index_info.data.borrow())?;
+    index.active_locks = index
+        .active_locks
+        .checked_sub(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    index.pack(&mut mint_index_info.data.borrow_mut());
+
+    close_program_account(index_entry_info, payer_info)?;
+
+    msg!("Lock {} removed from the index of mint {}", entry.lock, entry.mint);
+    Ok(())
+}
+
+/// Moves a lock account's lamports above its rent-exempt minimum to the
+/// owner. The lock's fields and escrow are untouched.
+fn process_withdraw_surplus_rent(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let minimum_balance = Rent::get()?.minimum_balance(lock_account_info.data_len());
+    let surplus = lock_account_info.lamports().saturating_sub(minimum_balance);
+    if surplus > 0 {
+        **lock_account_info.lamports.borrow_mut() = minimum_balance;
+        **owner_info.lamports.borrow_mut() = owner_info
+            .lamports()
+            .checked_add(surplus)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
+    msg!("Withdrew {} surplus lamports from lock {}", surplus, lock_account_info.key);
+    Ok(())
+}
+
+/// Sends the owner whatever the escrow holds above the locked amount, so
+/// stray transfers neither strand tokens nor make `Unlock` fail with
+/// `InconsistentState`. A lock with a committed unlock destination only
+/// sweeps to it.
+fn process_sweep_excess(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lock_id: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_token_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if lock.frozen {
+        return Err(LocksmithError::LockFrozen.into());
+    }
+
+    let lock_id_bytes = lock_id.to_le_bytes();
+    let (lock_pda, _) = Pubkey::find_program_address(
+        &[
+            LOCK_SEED,
+            owner_info.key.as_ref(),
+            lock.mint.as_ref(),
+            &lock_id_bytes,
+        ],
+        program_id,
+    );
+    let (lock_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *lock_account_info.key != lock_pda || *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let lock_token = TokenAccount::unpack(&lock_token_info.data.borrow())?;
+    let excess = lock_token
+        .amount
+        .checked_sub(lock.amount)
+        .ok_or(LocksmithError::InconsistentState)?;
+    if excess == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    check_unlock_destination(&lock, owner_token_info.key)?;
+    let owner_token = TokenAccount::unpack(&owner_token_info.data.borrow())?;
+    if owner_token.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if owner_token.mint != lock.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            lock_token_info.key,
+            owner_token_info.key,
+            lock_account_info.key,
+            &[],
+            excess,
+        )?,
+        &[
+            lock_token_info.clone(),
+            owner_token_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[&[
+            LOCK_SEED,
+            owner_info.key.as_ref(),
+            lock.mint.as_ref(),
+            &lock_id_bytes,
+            &[lock.bump],
+        ]],
+    )?;
+
+    #[cfg(feature = "strict-invariants")]
+    invariants::expect_eq(
+        "escrow balance",
+        invariants::token_amount(lock_token_info)?,
+        lock.amount,
+    )?;
+
+    msg!("Swept {} excess tokens from lock {}", excess, lock_account_info.key);
+    Ok(())
+}
+
+/// Sweeps an abandoned lock to its recovery address: the tokens go to the
+/// recovery address's token account and the lock and escrow rent to the
+/// recovery address itself. Anyone may crank it once the lock has gone
+/// unclaimed for `ABANDONMENT_PERIOD_SECONDS` after its unlock timestamp.
+///
+/// A credential-gated lock requires the recovery address's credential, so
+/// the gate cannot be bypassed by abandoning a lock. Frozen locks cannot be
+/// swept.
+fn process_sweep_abandoned_lock(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let recovery_token_info = next_account_info(account_info_iter)?;
+    let recovery_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+
+    if lock.recovery_address == Pubkey::default() || *recovery_info.key != lock.recovery_address {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if *owner_info.key != lock.owner {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let clock = Clock::get()?;
+    lock.can_pay_out_to_owner(clock.unix_timestamp)?;
+
+    let abandoned_at = lock
+        .unlock_timestamp
+        .checked_add(ABANDONMENT_PERIOD_SECONDS)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if clock.unix_timestamp < abandoned_at || !lock.unlock_reached(&clock) {
+        return Err(LocksmithError::LockNotAbandoned.into());
+    }
+
+    if lock.credential_issuer != Pubkey::default() {
+        let credential_info = accounts.get(6).ok_or(LocksmithError::InvalidCredential)?;
+        verify_credential(
+            credential_info.key,
+            credential_info.owner,
+            credential_info.data_is_empty(),
+            &lock.credential_issuer,
+            recovery_info.key,
+        )?;
+    }
+
+    let hook = if lock.hook != Pubkey::default() {
+        let hook_info = accounts.get(7).ok_or(LocksmithError::InvalidHook)?;
+        load_hook(program_id, hook_info, &lock.hook)?
+    } else {
+        None
+    };
+
+    let lock_id_bytes = lock.lock_id.to_le_bytes();
+    let lock_seeds: &[&[u8]] = &[
+        LOCK_SEED,
+        lock.owner.as_ref(),
+        lock.mint.as_ref(),
+        &lock_id_bytes,
+        &[lock.bump],
+    ];
+    let lock_pda = Pubkey::create_program_address(lock_seeds, program_id)
+        .map_err(|_| LocksmithError::InvalidPDA)?;
+    if *lock_account_info.key != lock_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let (lock_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let lock_token = TokenAccount::unpack(&lock_token_info.data.borrow())?;
+    if lock_token.amount != lock.amount {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    let recovery_token = TokenAccount::unpack(&recovery_token_info.data.borrow())?;
+    if recovery_token.owner != lock.recovery_address {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if recovery_token.mint != lock.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            lock_token_info.key,
+            recovery_token_info.key,
+            lock_account_info.key,
+            &[],
+            lock.amount,
+        )?,
+        &[
+            lock_token_info.clone(),
+            recovery_token_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[lock_seeds],
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program_info.key,
+            lock_token_info.key,
+            recovery_info.key,
+            lock_account_info.key,
+            &[],
+        )?,
+        &[
+            lock_token_info.clone(),
+            recovery_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[lock_seeds],
+    )?;
+
+    uncount_owner_lock(program_id, accounts, &lock)?;
+    close_program_account(lock_account_info, recovery_info)?;
+
+    #[cfg(feature = "strict-invariants")]
+    invariants::expect_eq(
+        "recovery token balance",
+        recovery_token.amount.checked_add(lock.amount),
+        Some(invariants::token_amount(recovery_token_info)?),
+    )?;
+
+    msg!(
+        "Swept {} abandoned tokens to {}",
+        lock.amount,
+        lock.recovery_address
+    );
+
+    if let Some(hook) = hook {
+        invoke_hook(
+            &hook,
+            HOOK_EVENT_UNLOCKED,
+            lock_account_info,
+            owner_info,
+            accounts.get(8..10).unwrap_or(&[]),
+            lock.lock_id,
+            lock.amount,
+        )?;
+    }
+    Ok(())
+}
+
+/// `Unlock` by a lock's fallback owner, who signs ahead of `Unlock`'s
+/// accounts, once the owner has left the lock unlocked for its
+/// `fallback_after` seconds.
+fn process_claim_as_fallback<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    lock_id: u64,
+) -> ProgramResult {
+    let (fallback_info, accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if !fallback_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    msg!("Claiming as fallback owner {}", fallback_info.key);
+    process_unlock(
+        program_id,
+        accounts,
+        lock_id,
+        UnlockAuthority::Fallback(fallback_info),
+    )
+}
+
+/// Unlocks a matured lock on its owner's behalf: the tokens go to the
+/// owner's associated token account, so no owner signature is needed, and
+/// `CRANK_TIP_LAMPORTS` of the lock account's rent reward the cranker.
+/// Credential checks, donations and hooks apply as in `Unlock`.
+fn process_crank_unlock(program_id: &Pubkey, accounts: &[AccountInfo], lock_id: u64) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let cranker_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_token_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !cranker_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    // As in `Unlock`, a price-conditional lock takes its price update last
+    let (price_update_info, accounts) = if lock.price_feed_id != [0u8; 32] {
+        let (update_info, accounts) = accounts
+            .split_last()
+            .ok_or(LocksmithError::InvalidPriceFeed)?;
+        (Some(update_info), accounts)
+    } else {
+        (None, accounts)
+    };
+
+    let clock = Clock::get()?;
+    lock.can_pay_out_to_owner(clock.unix_timestamp)?;
+    // The forwarding deposit is signed by the owner
+    if lock.forward_destination != Pubkey::default() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if lock.permanent {
+        return Err(LocksmithError::LockIsPermanent.into());
+    }
+    if !lock.unlock_reached(&clock) {
+        return Err(LocksmithError::UnlockTooEarly.into());
+    }
+    if let Some(update_info) = price_update_info {
+        check_price_condition(
+            &lock,
+            update_info.owner,
+            &update_info.data.borrow(),
+            clock.unix_timestamp,
+        )?;
+    }
+    if lock.arbiter != Pubkey::default() && !lock.milestone_approved {
+        return Err(LocksmithError::MilestoneNotApproved.into());
+    }
+
+    if lock.credential_issuer != Pubkey::default() {
+        let credential_info = accounts.get(6).ok_or(LocksmithError::InvalidCredential)?;
+        verify_credential(
+            credential_info.key,
+            credential_info.owner,
+            credential_info.data_is_empty(),
+            &lock.credential_issuer,
+            owner_info.key,
+        )?;
+    }
+
+    let hook = if lock.hook != Pubkey::default() {
+        let hook_info = accounts.get(7).ok_or(LocksmithError::InvalidHook)?;
+        load_hook(program_id, hook_info, &lock.hook)?
+    } else {
+        None
+    };
+
+    let lock_id_bytes = lock_id.to_le_bytes();
+    let lock_seeds: &[&[u8]] = &[
+        LOCK_SEED,
+        lock.owner.as_ref(),
+        lock.mint.as_ref(),
+        &lock_id_bytes,
+        &[lock.bump],
+    ];
+    let lock_pda = Pubkey::create_program_address(lock_seeds, program_id)
+        .map_err(|_| LocksmithError::InvalidPDA)?;
+    if *lock_account_info.key != lock_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let (lock_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let lock_token = TokenAccount::unpack(&lock_token_info.data.borrow())?;
+    if lock_token.amount != lock.amount {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    // A lock funded with lamports pays them back out to the owner's wallet,
+    // as in `Unlock`, by closing the escrow with the payout still in it.
+    // Otherwise only the owner may choose another destination than their
+    // associated token account.
+    let unwrap = lock.wrapped_lamports;
+    #[cfg(feature = "strict-invariants")]
+    let mut owner_token_before = None;
+    if unwrap {
+        if owner_token_info.key != owner_info.key {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+    } else {
+        if *owner_token_info.key != associated_token_address(owner_info.key, &lock.mint) {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        check_unlock_destination(&lock, owner_token_info.key)?;
+        // The owner may have reassigned it with `SetAuthority`
+        let owner_token = TokenAccount::unpack(&owner_token_info.data.borrow())?;
+        if owner_token.owner != lock.owner {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        if owner_token.mint != lock.mint {
+            return Err(LocksmithError::InvalidMint.into());
+        }
+        #[cfg(feature = "strict-invariants")]
+        {
+            owner_token_before = Some(owner_token.amount);
+        }
+    }
+
+    let donation = bps_share(lock.amount, lock.donation_bps);
+    let payout = lock.amount - donation;
+    if donation > 0 {
+        let donation_token_info = accounts.get(10).ok_or(LocksmithError::InvalidDonation)?;
+        let donation_token = TokenAccount::unpack(&donation_token_info.data.borrow())?;
+        if donation_token.owner != lock.donation_address || donation_token.mint != lock.mint {
+            return Err(LocksmithError::InvalidDonation.into());
+        }
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                lock_token_info.key,
+                donation_token_info.key,
+                lock_account_info.key,
+                &[],
+                donation,
+            )?,
+            &[
+                lock_token_info.clone(),
+                donation_token_info.clone(),
+                lock_account_info.clone(),
+            ],
+            &[lock_seeds],
+        )?;
+        msg!("Donated {} tokens to {}", donation, lock.donation_address);
+    }
+
+    if !unwrap {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                lock_token_info.key,
+                owner_token_info.key,
+                lock_account_info.key,
+                &[],
+                payout,
+            )?,
+            &[
+                lock_token_info.clone(),
+                owner_token_info.clone(),
+                lock_account_info.clone(),
+            ],
+            &[lock_seeds],
+        )?;
+    }
+
+    // A native escrow closes with its balance, paying it out as lamports
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program_info.key,
+            lock_token_info.key,
+            owner_info.key,
+            lock_account_info.key,
+            &[],
+        )?,
+        &[
+            lock_token_info.clone(),
+            owner_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[lock_seeds],
+    )?;
+
+    let tip = CRANK_TIP_LAMPORTS.min(lock_account_info.lamports());
+    **lock_account_info.lamports.borrow_mut() = lock_account_info.lamports() - tip;
+    **cranker_info.lamports.borrow_mut() = cranker_info
+        .lamports()
+        .checked_add(tip)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    uncount_owner_lock(program_id, accounts, &lock)?;
+    close_program_account(lock_account_info, owner_info)?;
+
+    #[cfg(feature = "strict-invariants")]
+    if let Some(owner_token_before) = owner_token_before {
+        invariants::expect_eq(
+            "owner token balance",
+            owner_token_before.checked_add(payout),
+            Some(invariants::token_amount(owner_token_info)?),
+        )?;
+    }
+
+    msg!("Cranked unlock of {} tokens, tipping {} lamports", payout, tip);
+    if unwrap {
+        msg!("Unwrapped to lamports");
+    }
+
+    if let Some(hook) = hook {
+        invoke_hook(
+            &hook,
+            HOOK_EVENT_UNLOCKED,
+            lock_account_info,
+            owner_info,
+            accounts.get(8..10).unwrap_or(&[]),
+            lock_id,
+            lock.amount,
+        )?;
+    }
+    Ok(())
+}
+
+/// The associated token account of `wallet` for `mint` under the SPL Token
+/// program.
+fn associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), spl_token::id().as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Reads the hook a lock names from `hook_info`. Returns `None` if the hook
+/// has been deregistered.
+fn load_hook(
+    program_id: &Pubkey,
+    hook_info: &AccountInfo,
+    hook_key: &Pubkey,
+) -> Result<Option<HookAccount>, ProgramError> {
+    if hook_info.key != hook_key {
+        return Err(LocksmithError::InvalidHook.into());
+    }
+    if hook_info.owner != program_id {
+        return Ok(None);
+    }
+    Ok(HookAccount::unpack(&hook_info.data.borrow()).ok())
+}
+
+/// Calls a hook back about `event` if it subscribed to it.
+///
+/// The callback only gets the lock and owner (read-only, never as signers)
+/// and one writable state account owned by the hook program, so it can
+/// track locks but not move anything. It fails the transaction if it uses
+/// more than its compute allowance, CPI overhead included.
+fn invoke_hook<'a>(
+    hook: &HookAccount,
+    event: u8,
+    lock_account_info: &AccountInfo<'a>,
+    owner_info: &AccountInfo<'a>,
+    hook_accounts: &[AccountInfo<'a>],
+    lock_id: u64,
+    amount: u64,
+) -> ProgramResult {
+    if hook.events & event == 0 {
+        return Ok(());
+    }
+    let [hook_program_info, hook_state_info] = hook_accounts else {
+        return Err(LocksmithError::InvalidHook.into());
+    };
+    if *hook_program_info.key != hook.program || *hook_state_info.owner != hook.program {
+        return Err(LocksmithError::InvalidHook.into());
+    }
+
+    let remaining_before = sol_remaining_compute_units();
+    invoke(
+        &Instruction {
+            program_id: hook.program,
+            accounts: vec![
+                AccountMeta::new_readonly(*lock_account_info.key, false),
+                AccountMeta::new_readonly(*owner_info.key, false),
+                AccountMeta::new(*hook_state_info.key, false),
+            ],
+            data: hook_instruction_data(hook, event, lock_id, amount),
+        },
+        &[
+            lock_account_info.clone(),
+            owner_info.clone(),
+            hook_state_info.clone(),
+            hook_program_info.clone(),
+        ],
+    )?;
+    let used = remaining_before.saturating_sub(sol_remaining_compute_units());
+    if used > u64::from(hook.compute_allowance) {
+        msg!("Hook {} used {} compute units", hook.program, used);
+        return Err(LocksmithError::HookComputeExceeded.into());
+    }
+    Ok(())
+}
+
+/// Callback instruction data for a hook: its prefix, the `HOOK_EVENT_*`
+/// bit, then the lock ID and amount as little-endian u64s.
+fn hook_instruction_data(hook: &HookAccount, event: u8, lock_id: u64, amount: u64) -> Vec<u8> {
+    let mut data = hook.prefix().to_vec();
+    data.push(event);
+    data.extend_from_slice(&lock_id.to_le_bytes());
+    data.extend_from_slice(&amount.to_le_bytes());
+    data
+}
+
+/// Deposit instruction data for a forwarding destination: its prefix
+/// followed by the amount as a little-endian u64.
+fn forwarding_instruction_data(destination: &ForwardingDestinationAccount, amount: u64) -> Vec<u8> {
+    let mut data = destination.prefix().to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    data
+}
+
+/// Checks that `credential` is the issuer-owned, initialized credential PDA
+/// ["credential", owner] of `issuer`. The issuer program decides what a
+/// credential attests and revokes it by closing the account.
+fn verify_credential(
+    credential: &Pubkey,
+    credential_owner: &Pubkey,
+    credential_is_empty: bool,
+    issuer: &Pubkey,
+    owner: &Pubkey,
+) -> ProgramResult {
+    let (expected, _) = Pubkey::find_program_address(&[CREDENTIAL_SEED, owner.as_ref()], issuer);
+    if *credential != expected || credential_owner != issuer || credential_is_empty {
+        return Err(LocksmithError::InvalidCredential.into());
+    }
+    Ok(())
+}
+
+/// Re-checks a lock's invariants and emits a `LockAuditEvent`.
+///
+/// Anyone may call this. Invalid inputs (an account that is not a lock, or the
+/// wrong escrow address) fail the instruction; invariant violations on a real
+/// lock are reported through the event status so monitors can record them.
+fn process_audit_lock(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+
+    let (lock, escrow, status) = load_and_audit_lock(program_id, lock_account_info, lock_token_info)?;
+
+    let clock = Clock::get()?;
+    LockAuditEvent {
+        discriminator: LockAuditEvent::DISCRIMINATOR,
+        lock: *lock_account_info.key,
+        owner: lock.owner,
+        mint: lock.mint,
+        recorded_amount: lock.amount,
+        escrow_amount: escrow.map_or(0, |e| e.amount),
+        status,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    }
+    .emit();
+
+    msg!("Lock audited: {:?}", status);
+    Ok(())
+}
+
+/// Copies a lock's terms into its certificate PDA once `audit_lock` finds
+/// it consistent. Republishing refreshes the copy and keeps the payer.
+fn process_publish_lock_certificate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let payer_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let certificate_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (lock, _, status) = load_and_audit_lock(program_id, lock_account_info, lock_token_info)?;
+    if status != AuditStatus::Consistent {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    let (certificate_pda, certificate_bump) = Pubkey::find_program_address(
+        &[CERTIFICATE_SEED, lock_account_info.key.as_ref()],
+        program_id,
+    );
+    if *certificate_info.key != certificate_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let payer = if certificate_info.data_is_empty() {
+        invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                certificate_info.key,
+                Rent::get()?.minimum_balance(LockCertificateAccount::SIZE),
+                LockCertificateAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                payer_info.clone(),
+                certificate_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[CERTIFICATE_SEED, lock_account_info.key.as_ref(), &[certificate_bump]]],
+        )?;
+        *payer_info.key
+    } else {
+        if certificate_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        LockCertificateAccount::unpack(&certificate_info.data.borrow())?.payer
+    };
+
+    let published_at = Clock::get()?.unix_timestamp;
+    LockCertificateAccount {
+        discriminator: LockCertificateAccount::DISCRIMINATOR,
+        lock: *lock_account_info.key,
+        owner: lock.owner,
+        mint: lock.mint,
+        amount: lock.amount,
+        unlock_timestamp: lock.unlock_timestamp,
+        unlock_slot: lock.unlock_slot,
+        unlock_epoch: lock.unlock_epoch,
+        published_at,
+        payer,
+        bump: certificate_bump,
+    }
+    .pack(&mut certificate_info.data.borrow_mut());
+
+    msg!("Certificate published for lock {}", lock_account_info.key);
+    Ok(())
+}
+
+/// Closes the certificate of a closed lock, returning its rent to its
+/// payer.
+fn process_close_lock_certificate(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let certificate_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+
+    if certificate_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let certificate = LockCertificateAccount::unpack(&certificate_info.data.borrow())?;
+
+    if *lock_account_info.key != certificate.lock {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    if *payer_info.key != certificate.payer {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    // A lock closed earlier in this transaction is program-owned but zeroed
+    let lock_open = lock_account_info.owner == program_id
+        && LockAccount::unpack(&lock_account_info.data.borrow()).is_ok();
+    if lock_open {
+        return Err(LocksmithError::CertificateActive.into());
+    }
+
+    close_program_account(certificate_info, payer_info)?;
+
+    msg!("Certificate of lock {} closed", certificate.lock);
+    Ok(())
+}
+
+/// Writes a consistent lock's current voting weight to its vote weight PDA,
+/// creating it if needed.
+fn process_snapshot_weight(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let payer_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let snapshot_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // The weight is only as good as the amount it is computed from
+    let (lock, _, status) = load_and_audit_lock(program_id, lock_account_info, lock_token_info)?;
+    if status != AuditStatus::Consistent {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    let (snapshot_pda, snapshot_bump) = Pubkey::find_program_address(
+        &[VOTE_WEIGHT_SEED, lock_account_info.key.as_ref()],
+        program_id,
+    );
+    if *snapshot_info.key != snapshot_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let payer = if snapshot_info.data_is_empty() {
+        invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                snapshot_info.key,
+                Rent::get()?.minimum_balance(VoteWeightSnapshotAccount::SIZE),
+                VoteWeightSnapshotAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                payer_info.clone(),
+                snapshot_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[VOTE_WEIGHT_SEED, lock_account_info.key.as_ref(), &[snapshot_bump]]],
+        )?;
+        *payer_info.key
+    } else {
+        if snapshot_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        VoteWeightSnapshotAccount::unpack(&snapshot_info.data.borrow())?.payer
+    };
+
+    let clock = Clock::get()?;
+    let weight = lock.voting_weight(&clock);
+    VoteWeightSnapshotAccount {
+        discriminator: VoteWeightSnapshotAccount::DISCRIMINATOR,
+        lock: *lock_account_info.key,
+        owner: lock.owner,
+        mint: lock.mint,
+        weight,
+        slot: clock.slot,
+        snapshot_at: clock.unix_timestamp,
+        payer,
+        bump: snapshot_bump,
+    }
+    .pack(&mut snapshot_info.data.borrow_mut());
+
+    msg!("Lock {} has voting weight {} at slot {}", lock_account_info.key, weight, clock.slot);
+    Ok(())
+}
+
+/// Closes the vote weight snapshot of a closed lock, returning its rent to
+/// its payer.
+fn process_close_weight_snapshot(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let snapshot_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+
+    if snapshot_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let snapshot = VoteWeightSnapshotAccount::unpack(&snapshot_info.data.borrow())?;
+
+    if *lock_account_info.key != snapshot.lock {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    if *payer_info.key != snapshot.payer {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    // A lock closed earlier in this transaction is program-owned but zeroed
+    let lock_open = lock_account_info.owner == program_id
+        && LockAccount::unpack(&lock_account_info.data.borrow()).is_ok();
+    if lock_open {
+        return Err(LocksmithError::WeightSnapshotActive.into());
+    }
+
+    close_program_account(snapshot_info, payer_info)?;
+
+    msg!("Vote weight snapshot of lock {} closed", snapshot.lock);
+    Ok(())
+}
+
+/// Writes what share of its mint's supply a consistent lock holds, and
+/// whether the mint's authorities are revoked, to its lock proof PDA,
+/// creating it if needed.
+fn process_record_lock_proof(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let payer_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let proof_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // The proof is only as good as the amount it is computed from
+    let (lock, _, status) = load_and_audit_lock(program_id, lock_account_info, lock_token_info)?;
+    if status != AuditStatus::Consistent {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    // The supply and authorities must come from the real mint
+    if *mint_info.key != lock.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+    if *mint_info.owner != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mint = Mint::unpack(&mint_info.data.borrow())?;
+
+    let (proof_pda, proof_bump) = Pubkey::find_program_address(
+        &[LOCK_PROOF_SEED, lock_account_info.key.as_ref()],
+        program_id,
+    );
+    if *proof_info.key != proof_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let payer = if proof_info.data_is_empty() {
+        invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                proof_info.key,
+                Rent::get()?.minimum_balance(LockProofAccount::SIZE),
+                LockProofAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                payer_info.clone(),
+                proof_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[LOCK_PROOF_SEED, lock_account_info.key.as_ref(), &[proof_bump]]],
+        )?;
+        *payer_info.key
+    } else {
+        if proof_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        LockProofAccount::unpack(&proof_info.data.borrow())?.payer
+    };
+
+    let clock = Clock::get()?;
+    let locked_bps = LockProofAccount::locked_bps_of(lock.amount, mint.supply);
+    LockProofAccount {
+        discriminator: LockProofAccount::DISCRIMINATOR,
+        lock: *lock_account_info.key,
+        mint: lock.mint,
+        amount: lock.amount,
+        supply: mint.supply,
+        locked_bps,
+        mint_authority_revoked: mint.mint_authority.is_none(),
+        freeze_authority_revoked: mint.freeze_authority.is_none(),
+        unlock_timestamp: lock.unlock_timestamp,
+        permanent: lock.permanent,
+        slot: clock.slot,
+        recorded_at: clock.unix_timestamp,
+        payer,
+        bump: proof_bump,
+    }
+    .pack(&mut proof_info.data.borrow_mut());
+
+    msg!("Lock {} holds {} bps of the supply of {}", lock_account_info.key, locked_bps, lock.mint);
+    Ok(())
+}
+
+/// Closes the lock proof of a closed lock, returning its rent to its payer.
+fn process_close_lock_proof(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let proof_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+
+    if proof_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let proof = LockProofAccount::unpack(&proof_info.data.borrow())?;
+
+    if *lock_account_info.key != proof.lock {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    if *payer_info.key != proof.payer {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    // A lock closed earlier in this transaction is program-owned but zeroed
+    let lock_open = lock_account_info.owner == program_id
+        && LockAccount::unpack(&lock_account_info.data.borrow()).is_ok();
+    if lock_open {
+        return Err(LocksmithError::LockProofActive.into());
+    }
+
+    close_program_account(proof_info, payer_info)?;
+
+    msg!("Lock proof of lock {} closed", proof.lock);
+    Ok(())
+}
+
+/// Token Metadata's `CreateMetadataAccountV3` instruction tag
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+
+/// Mints the owner a non-fungible receipt for their lock. The receipt PDA
+/// is its mint, freeze and metadata update authority, so anyone can tell a
+/// genuine receipt by its update authority. It is frozen in the owner's
+/// associated token account until `BurnLockReceipt` burns it, unless it is
+/// `transferable`: then the lock is bound to it, and whoever holds it
+/// unlocks with `UnlockWithReceipt`.
+///
+/// SPL Token mints cannot be closed, so a burned receipt's mint stays at
+/// its address; seeding it with `nonce` as well as the lock lets a new lock
+/// at a reused lock ID have a receipt of its own.
+fn process_mint_lock_receipt(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    transferable: bool,
+    nonce: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let receipt_mint_info = next_account_info(account_info_iter)?;
+    let owner_receipt_info = next_account_info(account_info_iter)?;
+    let metadata_info = next_account_info(account_info_iter)?;
+    let receipt_authority_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+    let token_metadata_program_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id()
+        || *associated_token_program_info.key != ASSOCIATED_TOKEN_PROGRAM_ID
+        || *token_metadata_program_info.key != TOKEN_METADATA_PROGRAM_ID
+        || !solana_system_interface::program::check_id(system_program_info.key)
+    {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if lock.receipt_mint != Pubkey::default() {
+        return Err(LocksmithError::ReceiptActive.into());
+    }
+    if transferable {
+        validate_receipt_binding(&lock)?;
+    }
+
+    let nonce_bytes = nonce.to_le_bytes();
+    let (receipt_mint_pda, receipt_mint_bump) = Pubkey::find_program_address(
+        &[RECEIPT_SEED, lock_account_info.key.as_ref(), &nonce_bytes],
+        program_id,
+    );
+    let (receipt_authority_pda, receipt_authority_bump) =
+        Pubkey::find_program_address(&[RECEIPT_SEED], program_id);
+    let (metadata_pda, _) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            receipt_mint_info.key.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+    if *receipt_mint_info.key != receipt_mint_pda
+        || *receipt_authority_info.key != receipt_authority_pda
+        || *metadata_info.key != metadata_pda
+        || *owner_receipt_info.key != associated_token_address(owner_info.key, &receipt_mint_pda)
+    {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    let authority_seeds: &[&[u8]] = &[RECEIPT_SEED, &[receipt_authority_bump]];
+
+    invoke_signed(
+        &system_instruction::create_account(
+            owner_info.key,
+            receipt_mint_info.key,
+            Rent::get()?.minimum_balance(Mint::LEN),
+            Mint::LEN as u64,
+            &spl_token::id(),
+        ),
+        &[
+            owner_info.clone(),
+            receipt_mint_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[
+            RECEIPT_SEED,
+            lock_account_info.key.as_ref(),
+            &nonce_bytes,
+            &[receipt_mint_bump],
+        ]],
+    )?;
+    invoke(
+        &spl_token::instruction::initialize_mint2(
+            &spl_token::id(),
+            receipt_mint_info.key,
+            receipt_authority_info.key,
+            Some(receipt_authority_info.key),
+            0,
+        )?,
+        &[receipt_mint_info.clone(), token_program_info.clone()],
+    )?;
+
+    // `CreateIdempotent`, in case someone created the account already
+    invoke(
+        &Instruction {
+            program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*owner_info.key, true),
+                AccountMeta::new(*owner_receipt_info.key, false),
+                AccountMeta::new_readonly(*owner_info.key, false),
+                AccountMeta::new_readonly(*receipt_mint_info.key, false),
+                AccountMeta::new_readonly(*system_program_info.key, false),
+                AccountMeta::new_readonly(*token_program_info.key, false),
+            ],
+            data: vec![1],
+        },
+        &[
+            owner_info.clone(),
+            owner_receipt_info.clone(),
+            receipt_mint_info.clone(),
+            system_program_info.clone(),
+            token_program_info.clone(),
+            associated_token_program_info.clone(),
+        ],
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::mint_to(
+            &spl_token::id(),
+            receipt_mint_info.key,
+            owner_receipt_info.key,
+            receipt_authority_info.key,
+            &[],
+            1,
+        )?,
+        &[
+            receipt_mint_info.clone(),
+            owner_receipt_info.clone(),
+            receipt_authority_info.clone(),
+        ],
+        &[authority_seeds],
+    )?;
+    grow_lock(lock_account_info, &mut lock, owner_info, system_program_info)?;
+    lock.receipt_mint = receipt_mint_pda;
+    lock.receipt_bound = transferable;
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+    if !transferable {
+        invoke_signed(
+            &spl_token::instruction::freeze_account(
+                &spl_token::id(),
+                owner_receipt_info.key,
+                receipt_mint_info.key,
+                receipt_authority_info.key,
+                &[],
+            )?,
+            &[
+                owner_receipt_info.clone(),
+                receipt_mint_info.clone(),
+                receipt_authority_info.clone(),
+            ],
+            &[authority_seeds],
+        )?;
+    }
+
+    invoke_signed(
+        &Instruction {
+            program_id: TOKEN_METADATA_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*metadata_info.key, false),
+                AccountMeta::new_readonly(*receipt_mint_info.key, false),
+                AccountMeta::new_readonly(*receipt_authority_info.key, true),
+                AccountMeta::new(*owner_info.key, true),
+                AccountMeta::new_readonly(*receipt_authority_info.key, true),
+                AccountMeta::new_readonly(*system_program_info.key, false),
+            ],
+            data: receipt_metadata_instruction_data(&lock),
+        },
+        &[
+            metadata_info.clone(),
+            receipt_mint_info.clone(),
+            receipt_authority_info.clone(),
+            owner_info.clone(),
+            system_program_info.clone(),
+            token_metadata_program_info.clone(),
+        ],
+        &[authority_seeds],
+    )?;
+
+    msg!(
+        "Receipt {} minted for lock {}",
+        receipt_mint_info.key,
+        lock_account_info.key
+    );
+    if transferable {
+        msg!("Lock is bound to its receipt");
+    }
+    Ok(())
+}
+
+/// Checks a lock can be bound to a transferable receipt: nothing about it
+/// may depend on, or pay out to, its owner in particular.
+fn validate_receipt_binding(lock: &LockAccount) -> ProgramResult {
+    if lock.shared {
+        return Err(LocksmithError::LockShared.into());
+    }
+    if lock.approvals_required {
+        return Err(LocksmithError::ApprovalsRequired.into());
+    }
+    if lock.collateral_holder != Pubkey::default() {
+        return Err(LocksmithError::LockCollateralized.into());
+    }
+    if lock.non_transferable
+        || lock.credential_issuer != Pubkey::default()
+        || lock.forward_destination != Pubkey::default()
+        || lock.recovery_address != Pubkey::default()
+        || lock.fallback_owner != Pubkey::default()
+        || lock.unlock_destination != Pubkey::default()
+        || lock.counterparty != Pubkey::default()
+        || lock.claim_key != [0u8; 32]
+        || lock.early_unlock_allowed
+    {
+        return Err(LocksmithError::LockBoundToReceipt.into());
+    }
+    Ok(())
+}
+
+/// `Unlock` by the holder of a receipt-bound lock's receipt, who signs and
+/// presents the token account holding it ahead of `Unlock`'s accounts.
+fn process_unlock_with_receipt<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    lock_id: u64,
+) -> ProgramResult {
+    let (holder_info, accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let (holder_receipt_info, accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let lock_account_info = accounts.get(2).ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if !holder_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if lock_account_info.owner != program_id || *holder_receipt_info.owner != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    let receipt = TokenAccount::unpack(&holder_receipt_info.data.borrow())?;
+    if receipt.owner != *holder_info.key
+        || receipt.mint != lock.receipt_mint
+        || lock.receipt_mint == Pubkey::default()
+        || receipt.amount != 1
+    {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    process_unlock(
+        program_id,
+        accounts,
+        lock_id,
+        UnlockAuthority::ReceiptHolder(holder_info),
+    )
+}
+
+/// The metadata URI of a lock's receipt: a JSON data URI with the lock's
+/// amount and unlock date as attributes. Token Metadata caps URIs at 200
+/// bytes, too few to also hold the mint, which the lock itself records.
+fn receipt_uri(lock: &LockAccount) -> String {
+    format!(
+        concat!(
+            r#"data:application/json,{{"attributes":["#,
+            r#"{{"trait_type":"amount","value":{}}},"#,
+            r#"{{"trait_type":"unlock","display_type":"date","value":{}}}]}}"#,
+        ),
+        lock.amount, lock.unlock_timestamp
+    )
+}
+
+/// Borsh-encoded `CreateMetadataAccountV3` data for a lock's receipt, named
+/// after its lock ID, without creators, royalties or a collection, and
+/// immutable.
+fn receipt_metadata_instruction_data(lock: &LockAccount) -> Vec<u8> {
+    let mut data = vec![CREATE_METADATA_ACCOUNT_V3];
+    for field in [format!("Lock #{}", lock.lock_id), "LOCK".to_string(), receipt_uri(lock)] {
+        data.extend_from_slice(&(field.len() as u32).to_le_bytes());
+        data.extend_from_slice(field.as_bytes());
+    }
+    // Seller fee basis points, then no creators, collection or uses
+    data.extend_from_slice(&[0, 0, 0, 0, 0]);
+    // Immutable, then no collection details
+    data.extend_from_slice(&[0, 0]);
+    data
+}
+
+/// Burns the receipt of a closed lock and closes the token account that
+/// held it. The receipt mint and its metadata remain. A new lock at the
+/// same address has a receipt of its own, if any, so only the receipt the
+/// open lock records is refused.
+fn process_burn_lock_receipt(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    nonce: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let holder_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let receipt_mint_info = next_account_info(account_info_iter)?;
+    let holder_receipt_info = next_account_info(account_info_iter)?;
+    let receipt_authority_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !holder_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (receipt_mint_pda, _) = Pubkey::find_program_address(
+        &[RECEIPT_SEED, lock_account_info.key.as_ref(), &nonce.to_le_bytes()],
+        program_id,
+    );
+    let (receipt_authority_pda, receipt_authority_bump) =
+        Pubkey::find_program_address(&[RECEIPT_SEED], program_id);
+    if *receipt_mint_info.key != receipt_mint_pda
+        || *receipt_authority_info.key != receipt_authority_pda
+    {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    // A lock closed earlier in this transaction is program-owned but zeroed
+    let receipt_active = lock_account_info.owner == program_id
+        && LockAccount::unpack(&lock_account_info.data.borrow())
+            .is_ok_and(|lock| lock.receipt_mint == receipt_mint_pda);
+    if receipt_active {
+        return Err(LocksmithError::ReceiptActive.into());
+    }
+
+    // Only receipts of locks not bound to them are frozen
+    if TokenAccount::unpack(&holder_receipt_info.data.borrow())?.is_frozen() {
+        invoke_signed(
+            &spl_token::instruction::thaw_account(
+                &spl_token::id(),
+                holder_receipt_info.key,
+                receipt_mint_info.key,
+                receipt_authority_info.key,
+                &[],
+            )?,
+            &[
+                holder_receipt_info.clone(),
+                receipt_mint_info.clone(),
+                receipt_authority_info.clone(),
+            ],
+            &[&[RECEIPT_SEED, &[receipt_authority_bump]]],
+        )?;
+    }
+    invoke(
+        &spl_token::instruction::burn(
+            &spl_token::id(),
+            holder_receipt_info.key,
+            receipt_mint_info.key,
+            holder_info.key,
+            &[],
+            1,
+        )?,
+        &[
+            holder_receipt_info.clone(),
+            receipt_mint_info.clone(),
+            holder_info.clone(),
+        ],
+    )?;
+    invoke(
+        &spl_token::instruction::close_account(
+            &spl_token::id(),
+            holder_receipt_info.key,
+            holder_info.key,
+            holder_info.key,
+            &[],
+        )?,
+        &[
+            holder_receipt_info.clone(),
+            holder_info.clone(),
+            holder_info.clone(),
+        ],
+    )?;
+
+    msg!("Receipt of lock {} burned", lock_account_info.key);
+    Ok(())
+}
+
+/// Sets a lock's label, growing locks created before it had one.
+fn process_update_lock_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    label: [u8; 32],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    grow_lock(lock_account_info, &mut lock, owner_info, system_program_info)?;
+    lock.label = label;
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    msg!("Lock {} label updated", lock_account_info.key);
+    Ok(())
+}
+
+/// Splits a lock among beneficiaries: records their shares in the lock's
+/// shares account and marks the lock shared, so that only `ClaimShare` can
+/// pay it out.
+fn process_set_lock_shares(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    beneficiaries: &[Pubkey; MAX_BENEFICIARIES],
+    share_bps: &[u16; MAX_BENEFICIARIES],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let shares_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    validate_share_split(&lock)?;
+    validate_shares(beneficiaries, share_bps)?;
+
+    let (shares_pda, shares_bump) =
+        Pubkey::find_program_address(&[SHARES_SEED, lock_account_info.key.as_ref()], program_id);
+    if *shares_info.key != shares_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            owner_info.key,
+            shares_info.key,
+            Rent::get()?.minimum_balance(LockSharesAccount::SIZE),
+            LockSharesAccount::SIZE as u64,
+            program_id,
+        ),
+        &[
+            owner_info.clone(),
+            shares_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[SHARES_SEED, lock_account_info.key.as_ref(), &[shares_bump]]],
+    )?;
+    LockSharesAccount {
+        discriminator: LockSharesAccount::DISCRIMINATOR,
+        lock: *lock_account_info.key,
+        total: lock.amount,
+        beneficiaries: *beneficiaries,
+        share_bps: *share_bps,
+        claimed: 0,
+        bump: shares_bump,
+    }
+    .pack(&mut shares_info.data.borrow_mut());
+
+    grow_lock(lock_account_info, &mut lock, owner_info, system_program_info)?;
+    lock.shared = true;
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    msg!(
+        "Lock {} split among {} beneficiaries",
+        lock_account_info.key,
+        share_bps.iter().filter(|&&bps| bps > 0).count()
+    );
+    Ok(())
+}
+
+/// Checks a lock can be split among beneficiaries: nothing about it may
+/// depend on, or pay out to, its owner in particular, and it must not be
+/// a single indivisible token.
+fn validate_share_split(lock: &LockAccount) -> ProgramResult {
+    if lock.shared {
+        return Err(LocksmithError::LockShared.into());
+    }
+    if lock.approvals_required {
+        return Err(LocksmithError::ApprovalsRequired.into());
+    }
+    if lock.permanent {
+        return Err(LocksmithError::LockIsPermanent.into());
+    }
+    if lock.collateral_holder != Pubkey::default() {
+        return Err(LocksmithError::LockCollateralized.into());
+    }
+    if lock.receipt_bound {
+        return Err(LocksmithError::LockBoundToReceipt.into());
+    }
+    if lock.is_nft
+        || lock.non_transferable
+        || lock.credential_issuer != Pubkey::default()
+        || lock.forward_destination != Pubkey::default()
+        || lock.hook != Pubkey::default()
+        || lock.recovery_address != Pubkey::default()
+        || lock.fallback_owner != Pubkey::default()
+        || lock.donation_bps > 0
+        || lock.unlock_destination != Pubkey::default()
+        || lock.price_feed_id != [0u8; 32]
+        || lock.arbiter != Pubkey::default()
+        || lock.counterparty != Pubkey::default()
+        || lock.claim_key != [0u8; 32]
+        || lock.early_unlock_allowed
+    {
+        return Err(LocksmithError::InvalidShares.into());
+    }
+    Ok(())
+}
+
+/// Shares must be non-zero for exactly the used slots, whose beneficiaries
+/// must be distinct, and sum to 10,000 basis points.
+fn validate_shares(
+    beneficiaries: &[Pubkey; MAX_BENEFICIARIES],
+    share_bps: &[u16; MAX_BENEFICIARIES],
+) -> ProgramResult {
+    let mut total = 0u32;
+    for (i, (beneficiary, &bps)) in beneficiaries.iter().zip(share_bps).enumerate() {
+        let unused = *beneficiary == Pubkey::default();
+        if unused != (bps == 0) || (!unused && beneficiaries[..i].contains(beneficiary)) {
+            return Err(LocksmithError::InvalidShares.into());
+        }
+        total += u32::from(bps);
+    }
+    if total != u32::from(BPS_DENOMINATOR) {
+        return Err(LocksmithError::InvalidShares.into());
+    }
+    Ok(())
+}
+
+/// Pays a split lock's beneficiary their share once the lock unlocks. The
+/// last claim takes what is left and closes the lock, its escrow and its
+/// shares account, returning their rent to the owner.
+fn process_claim_share(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let beneficiary_info = next_account_info(account_info_iter)?;
+    let beneficiary_token_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let shares_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !beneficiary_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id || shares_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if !lock.shared {
+        return Err(LocksmithError::InvalidShares.into());
+    }
+    if lock.frozen {
+        return Err(LocksmithError::LockFrozen.into());
+    }
+
+    let (shares_pda, _) =
+        Pubkey::find_program_address(&[SHARES_SEED, lock_account_info.key.as_ref()], program_id);
+    let (lock_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *shares_info.key != shares_pda || *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    let mut shares = LockSharesAccount::unpack(&shares_info.data.borrow())?;
+
+    if !lock.unlock_reached(&Clock::get()?) {
+        return Err(LocksmithError::UnlockTooEarly.into());
+    }
+
+    let index = shares
+        .beneficiary_index(beneficiary_info.key)
+        .ok_or(LocksmithError::Unauthorized)?;
+    if shares.claimed & (1 << index) != 0 {
+        return Err(LocksmithError::ShareClaimed.into());
+    }
+
+    let lock_token = TokenAccount::unpack(&lock_token_info.data.borrow())?;
+    if lock_token.amount != lock.amount {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    let beneficiary_token = TokenAccount::unpack(&beneficiary_token_info.data.borrow())?;
+    if beneficiary_token.owner != *beneficiary_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if beneficiary_token.mint != lock.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    let payout = shares.payout(index, lock.amount);
+    shares.claimed |= 1 << index;
+    lock.amount -= payout;
+
+    let lock_id_bytes = lock.lock_id.to_le_bytes();
+    let signer_seeds: &[&[u8]] = &[
+        LOCK_SEED,
+        owner_info.key.as_ref(),
+        lock.mint.as_ref(),
+        &lock_id_bytes,
+        &[lock.bump],
+    ];
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            lock_token_info.key,
+            beneficiary_token_info.key,
+            lock_account_info.key,
+            &[],
+            payout,
+        )?,
+        &[
+            lock_token_info.clone(),
+            beneficiary_token_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    if shares.all_claimed() {
+        invoke_signed(
+            &spl_token::instruction::close_account(
+                token_program_info.key,
+                lock_token_info.key,
+                owner_info.key,
+                lock_account_info.key,
+                &[],
+            )?,
+            &[
+                lock_token_info.clone(),
+                owner_info.clone(),
+                lock_account_info.clone(),
+            ],
+            &[signer_seeds],
+        )?;
+        uncount_owner_lock(program_id, accounts, &lock)?;
+        close_program_account(lock_account_info, owner_info)?;
+        close_program_account(shares_info, owner_info)?;
+        msg!("Claimed {} tokens, all shares claimed", payout);
+        return Ok(());
+    }
+
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+    shares.pack(&mut shares_info.data.borrow_mut());
+
+    #[cfg(feature = "strict-invariants")]
+    invariants::expect_eq(
+        "escrow balance",
+        invariants::token_amount(lock_token_info)?,
+        lock.amount,
+    )?;
+
+    msg!("Claimed {} tokens, {} left to claim", payout, lock.amount);
+    Ok(())
+}
+
+/// Makes a lock require `threshold` of `approvers` to approve before it can
+/// be unlocked, with `ExecuteUnlock` only. Irreversible, so a compromised
+/// owner key alone can never release it.
+fn process_set_unlock_approvers(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    approvers: &[Pubkey; MAX_APPROVERS],
+    threshold: u8,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let approvals_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    validate_approval_requirement(&lock)?;
+    validate_approvers(approvers, threshold)?;
+
+    let (approvals_pda, approvals_bump) =
+        Pubkey::find_program_address(&[APPROVALS_SEED, lock_account_info.key.as_ref()], program_id);
+    if *approvals_info.key != approvals_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            owner_info.key,
+            approvals_info.key,
+            Rent::get()?.minimum_balance(UnlockApprovalsAccount::SIZE),
+            UnlockApprovalsAccount::SIZE as u64,
+            program_id,
+        ),
+        &[
+            owner_info.clone(),
+            approvals_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[APPROVALS_SEED, lock_account_info.key.as_ref(), &[approvals_bump]]],
+    )?;
+    UnlockApprovalsAccount {
+        discriminator: UnlockApprovalsAccount::DISCRIMINATOR,
+        lock: *lock_account_info.key,
+        approvers: *approvers,
+        threshold,
+        approvals: 0,
+        bump: approvals_bump,
+    }
+    .pack(&mut approvals_info.data.borrow_mut());
+
+    grow_lock(lock_account_info, &mut lock, owner_info, system_program_info)?;
+    lock.approvals_required = true;
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    msg!(
+        "Lock {} requires {} of {} approvals to unlock",
+        lock_account_info.key,
+        threshold,
+        approvers.iter().filter(|a| **a != Pubkey::default()).count()
+    );
+    Ok(())
+}
+
+/// Checks a lock can be made to require approvals: nothing may release its
+/// tokens other than an unlock by its owner.
+fn validate_approval_requirement(lock: &LockAccount) -> ProgramResult {
+    if lock.approvals_required {
+        return Err(LocksmithError::ApprovalsRequired.into());
+    }
+    if lock.permanent {
+        return Err(LocksmithError::LockIsPermanent.into());
+    }
+    if lock.receipt_bound {
+        return Err(LocksmithError::LockBoundToReceipt.into());
+    }
+    if lock.shared {
+        return Err(LocksmithError::LockShared.into());
+    }
+    if lock.recovery_address != Pubkey::default()
+        || lock.fallback_owner != Pubkey::default()
+        || lock.claim_key != [0u8; 32]
+        || lock.early_unlock_allowed
+    {
+        return Err(LocksmithError::InvalidApprovers.into());
+    }
+    Ok(())
+}
+
+/// Approvers must be distinct, and the threshold between one and their
+/// number.
+fn validate_approvers(approvers: &[Pubkey; MAX_APPROVERS], threshold: u8) -> ProgramResult {
+    let mut count = 0;
+    for (i, approver) in approvers.iter().enumerate() {
+        if *approver == Pubkey::default() {
+            continue;
+        }
+        if approvers[..i].contains(approver) {
+            return Err(LocksmithError::InvalidApprovers.into());
+        }
+        count += 1;
+    }
+    if threshold == 0 || usize::from(threshold) > count {
+        return Err(LocksmithError::InvalidApprovers.into());
+    }
+    Ok(())
+}
+
+/// Records an approver's approval of unlocking a lock. Approving twice is
+/// harmless.
+fn process_approve_unlock(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let approver_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let approvals_info = next_account_info(account_info_iter)?;
+
+    if !approver_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if lock_account_info.owner != program_id || approvals_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let (approvals_pda, _) =
+        Pubkey::find_program_address(&[APPROVALS_SEED, lock_account_info.key.as_ref()], program_id);
+    if *approvals_info.key != approvals_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    let mut approvals = UnlockApprovalsAccount::unpack(&approvals_info.data.borrow())?;
+
+    let index = approvals
+        .approver_index(approver_info.key)
+        .ok_or(LocksmithError::Unauthorized)?;
+    approvals.approvals |= 1 << index;
+    approvals.pack(&mut approvals_info.data.borrow_mut());
+
+    msg!(
+        "Unlock of lock {} approved by {} ({} of {} needed)",
+        lock_account_info.key,
+        approver_info.key,
+        approvals.approvals.count_ones(),
+        approvals.threshold
+    );
+    Ok(())
+}
+
+/// `Unlock` by the owner of a lock requiring approvals, who presents its
+/// approvals account ahead of `Unlock`'s accounts. The approvals account is
+/// closed along with the lock.
+fn process_execute_unlock<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    lock_id: u64,
+) -> ProgramResult {
+    let (approvals_info, accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let lock_account_info = accounts.get(2).ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if approvals_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let (approvals_pda, _) =
+        Pubkey::find_program_address(&[APPROVALS_SEED, lock_account_info.key.as_ref()], program_id);
+    if *approvals_info.key != approvals_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    let approvals = UnlockApprovalsAccount::unpack(&approvals_info.data.borrow())?;
+    if !approvals.approved() {
+        return Err(LocksmithError::ApprovalsRequired.into());
+    }
+
+    process_unlock(program_id, accounts, lock_id, UnlockAuthority::Approved)?;
+    close_program_account(approvals_info, owner_info)
+}
+
+/// `Unlock` of an escrow lock by its counterparty, who signs ahead of
+/// `Unlock`'s accounts with the owner also signing, and receives the tokens.
+fn process_release_escrow<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    lock_id: u64,
+) -> ProgramResult {
+    let (counterparty_info, accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if !counterparty_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    msg!("Releasing escrow to {}", counterparty_info.key);
+    process_unlock(
+        program_id,
+        accounts,
+        lock_id,
+        UnlockAuthority::Counterparty(counterparty_info),
+    )
+}
+
+/// Records whether a lock's arbiter approves its milestone. Approval can be
+/// withdrawn until the owner unlocks.
+fn process_approve_milestone(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    approved: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let arbiter_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+
+    if !arbiter_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.arbiter == Pubkey::default() || lock.arbiter != *arbiter_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    lock.milestone_approved = approved;
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    if approved {
+        msg!("Milestone of lock {} approved", lock_account_info.key);
+    } else {
+        msg!("Milestone approval of lock {} withdrawn", lock_account_info.key);
+    }
+    Ok(())
+}
+
+/// Returns a lock's `CollateralAttestation` as return data. Lenders must
+/// check `status` and `collateral_holder` themselves; the call succeeds for
+/// inconsistent locks so the failure is visible to them.
+fn process_verify_collateral(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+
+    let (lock, _, status) = load_and_audit_lock(program_id, lock_account_info, lock_token_info)?;
+
+    CollateralAttestation {
+        discriminator: CollateralAttestation::DISCRIMINATOR,
+        lock: *lock_account_info.key,
+        owner: lock.owner,
+        mint: lock.mint,
+        amount: lock.amount,
+        unlock_timestamp: lock.unlock_timestamp,
+        collateral_holder: lock.collateral_holder,
+        frozen: lock.frozen,
+        status,
+    }
+    .set_return_data();
+
+    msg!(
+        "Lock {} collateral verified: {:?}, held by {}",
+        lock_account_info.key,
+        status,
+        lock.collateral_holder
+    );
+    Ok(())
+}
+
+/// Loads a lock and its escrow and runs `audit_lock` on them. A missing or
+/// foreign escrow is reported through the status rather than an error.
+fn load_and_audit_lock(
+    program_id: &Pubkey,
+    lock_account_info: &AccountInfo,
+    lock_token_info: &AccountInfo,
+) -> Result<(LockAccount, Option<TokenAccount>, AuditStatus), ProgramError> {
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+
+    let (lock_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let escrow = if *lock_token_info.owner == spl_token::id() {
+        TokenAccount::unpack(&lock_token_info.data.borrow()).ok()
+    } else {
+        None
+    };
+
+    let status = audit_lock(program_id, lock_account_info.key, &lock, escrow.as_ref());
+    Ok((lock, escrow, status))
+}
+
+/// Checks the invariants of a lock against its escrow.
+fn audit_lock(
+    program_id: &Pubkey,
+    lock_key: &Pubkey,
+    lock: &LockAccount,
+    escrow: Option<&TokenAccount>,
+) -> AuditStatus {
+    let derived = Pubkey::create_program_address(
+        &[
+            LOCK_SEED,
+            lock.owner.as_ref(),
+            lock.mint.as_ref(),
+            &lock.lock_id.to_le_bytes(),
+            &[lock.bump],
+        ],
+        program_id,
+    );
+    if derived.as_ref() != Ok(lock_key) {
+        return AuditStatus::LockPdaMismatch;
+    }
+
+    match lock.unlock_timestamp.checked_sub(lock.created_at) {
+        Some(duration)
+            if duration > 0 && (duration <= MAX_LOCK_DURATION_SECONDS || lock.permanent) => {}
+        _ => return AuditStatus::InvalidTimestamps,
+    }
+
+    let Some(escrow) = escrow else {
+        return AuditStatus::EscrowMissing;
+    };
+    if escrow.owner != *lock_key || escrow.mint != lock.mint {
+        return AuditStatus::EscrowMismatch;
+    }
+    if escrow.amount != lock.amount {
+        return AuditStatus::BalanceMismatch;
+    }
+
+    AuditStatus::Consistent
+}
+
+/// Appoints or removes the compliance authority.
+///
+/// Appointments are timelocked: the current authority, if any, keeps acting
+/// until `COMPLIANCE_TIMELOCK_SECONDS` have passed. Removal takes effect
+/// immediately and cancels any pending appointment.
+fn process_set_compliance_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let new_authority_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Validate system program is the official System program
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let clock = Clock::get()?;
+
+    // Settle a matured appointment before replacing the pending slot
+    config.compliance_authority = config
+        .compliance_authority_at(clock.unix_timestamp)
+        .unwrap_or_default();
+
+    let (action, effective_at) = if *new_authority_info.key == Pubkey::default() {
+        config.compliance_authority = Pubkey::default();
+        config.pending_compliance_authority = Pubkey::default();
+        config.compliance_authority_effective_at = 0;
+        (ComplianceAction::AuthorityRemoved, clock.unix_timestamp)
+    } else {
+        let effective_at = clock
+            .unix_timestamp
+            .checked_add(COMPLIANCE_TIMELOCK_SECONDS)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        config.pending_compliance_authority = *new_authority_info.key;
+        config.compliance_authority_effective_at = effective_at;
+        (ComplianceAction::AuthorityAppointed, effective_at)
+    };
+
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    ComplianceEvent {
+        discriminator: ComplianceEvent::DISCRIMINATOR,
+        action,
+        actor: *admin_info.key,
+        target: *new_authority_info.key,
+        effective_at,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    }
+    .emit();
+
+    msg!(
+        "Compliance authority set to {}, effective at {}",
+        new_authority_info.key,
+        effective_at
+    );
+    Ok(())
+}
+
+/// Freezes or unfreezes a lock. A frozen lock cannot be unlocked; nothing
+/// else about it changes, and the compliance authority never gains access
+/// to the escrowed tokens.
+fn process_set_lock_frozen(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    frozen: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+    let clock = Clock::get()?;
+    authorize_freeze(&config, authority_info.key, clock.unix_timestamp)?;
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+
+    if frozen {
+        let system_program_info = next_account_info(account_info_iter)?;
+        if !solana_system_interface::program::check_id(system_program_info.key) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        grow_lock(lock_account_info, &mut lock, authority_info, system_program_info)?;
+    }
+
+    lock.frozen = frozen;
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    ComplianceEvent {
+        discriminator: ComplianceEvent::DISCRIMINATOR,
+        action: if frozen {
+            ComplianceAction::LockFrozen
+        } else {
+            ComplianceAction::LockUnfrozen
+        },
+        actor: *authority_info.key,
+        target: *lock_account_info.key,
+        effective_at: clock.unix_timestamp,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    }
+    .emit();
+
+    msg!("Lock {} frozen: {}", lock_account_info.key, frozen);
+    Ok(())
+}
+
+/// The admin or the active compliance authority may freeze and unfreeze.
+/// The admin needs no appointment, so it can pause a single lock at once,
+/// and unfreezing does not depend on an authority that may be removed.
+fn authorize_freeze(config: &ConfigAccount, signer: &Pubkey, now: i64) -> ProgramResult {
+    let is_compliance_authority = config.compliance_authority_at(now) == Some(*signer);
+    if is_compliance_authority || config.admin == *signer {
+        Ok(())
+    } else {
+        Err(LocksmithError::Unauthorized.into())
+    }
+}
+
+/// Approves a program instruction as a forwarding destination, or revokes
+/// it by closing the destination account. Locks pointing at a revoked
+/// destination cannot unlock until their owner clears or replaces it.
+fn process_set_forwarding_destination(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_prefix: [u8; 8],
+    prefix_len: u8,
+    approved: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let destination_program_info = next_account_info(account_info_iter)?;
+    let destination_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let prefix = instruction_prefix
+        .get(..usize::from(prefix_len))
+        .ok_or(LocksmithError::InvalidInstruction)?;
+    let (destination_pda, destination_bump) = Pubkey::find_program_address(
+        &[FORWARDING_SEED, destination_program_info.key.as_ref(), prefix],
+        program_id,
+    );
+    if *destination_info.key != destination_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if !approved {
+        if destination_info.owner == program_id {
+            close_program_account(destination_info, admin_info)?;
+        }
+        msg!("Forwarding destination {} revoked", destination_info.key);
+        return Ok(());
+    }
+
+    if destination_info.data_is_empty() {
+        let rent = Rent::get()?;
+        invoke_signed(
+            &system_instruction::create_account(
+                admin_info.key,
+                destination_info.key,
+                rent.minimum_balance(ForwardingDestinationAccount::SIZE),
+                ForwardingDestinationAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                admin_info.clone(),
+                destination_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[
+                FORWARDING_SEED,
+                destination_program_info.key.as_ref(),
+                prefix,
+                &[destination_bump],
+            ]],
+        )?;
+    }
+
+    ForwardingDestinationAccount {
+        discriminator: ForwardingDestinationAccount::DISCRIMINATOR,
+        program: *destination_program_info.key,
+        instruction_prefix,
+        prefix_len,
+        bump: destination_bump,
+    }
+    .pack(&mut destination_info.data.borrow_mut());
+
+    msg!(
+        "Forwarding destination {} approved for program {}",
+        destination_info.key,
+        destination_program_info.key
+    );
+    Ok(())
+}
+
+/// Points a lock at an approved forwarding destination, or clears it when
+/// given the default pubkey.
+fn process_set_lock_forwarding(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let destination_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    lock.can_change_terms()?;
+    // The deposit would move the tokens on from the committed destination
+    if lock.unlock_destination != Pubkey::default() && *destination_info.key != Pubkey::default() {
+        return Err(LocksmithError::InvalidUnlockDestination.into());
+    }
+
+    if *destination_info.key != Pubkey::default() {
+        if destination_info.owner != program_id {
+            return Err(LocksmithError::InvalidForwardingDestination.into());
+        }
+        ForwardingDestinationAccount::unpack(&destination_info.data.borrow())
+            .map_err(|_| LocksmithError::InvalidForwardingDestination)?;
+    }
+
+    grow_lock(lock_account_info, &mut lock, owner_info, system_program_info)?;
+    lock.forward_destination = *destination_info.key;
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    msg!(
+        "Lock {} forwarding destination set to {}",
+        lock_account_info.key,
+        destination_info.key
+    );
+    Ok(())
+}
+
+/// Registers a program to be called back on lock events, or deregisters it
+/// by closing the hook account. Locks that opted into a deregistered hook
+/// unlock without calling it.
+#[allow(clippy::too_many_arguments)]
+fn process_set_hook(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_prefix: [u8; 8],
+    prefix_len: u8,
+    events: u8,
+    compute_allowance: u32,
+    approved: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let hook_program_info = next_account_info(account_info_iter)?;
+    let hook_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let (hook_pda, hook_bump) =
+        Pubkey::find_program_address(&[HOOK_SEED, hook_program_info.key.as_ref()], program_id);
+    if *hook_info.key != hook_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if !approved {
+        if hook_info.owner == program_id {
+            close_program_account(hook_info, admin_info)?;
+        }
+        msg!("Hook {} deregistered", hook_info.key);
+        return Ok(());
+    }
+
+    if events == 0
+        || events & !(HOOK_EVENT_CREATED | HOOK_EVENT_UNLOCKED) != 0
+        || compute_allowance == 0
+        || compute_allowance > MAX_HOOK_COMPUTE_UNITS
+    {
+        return Err(LocksmithError::InvalidInstruction.into());
+    }
+
+    if hook_info.data_is_empty() {
+        let rent = Rent::get()?;
+        invoke_signed(
+            &system_instruction::create_account(
+                admin_info.key,
+                hook_info.key,
+                rent.minimum_balance(HookAccount::SIZE),
+                HookAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                admin_info.clone(),
+                hook_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[HOOK_SEED, hook_program_info.key.as_ref(), &[hook_bump]]],
+        )?;
+    }
+
+    HookAccount {
+        discriminator: HookAccount::DISCRIMINATOR,
+        program: *hook_program_info.key,
+        instruction_prefix,
+        prefix_len,
+        events,
+        compute_allowance,
+        bump: hook_bump,
+    }
+    .pack(&mut hook_info.data.borrow_mut());
+
+    msg!(
+        "Hook {} registered for program {} with {} compute units",
+        hook_info.key,
+        hook_program_info.key,
+        compute_allowance
+    );
+    Ok(())
+}
+
+/// Approves a lender authority to mark locks as collateral, or revokes it by
+/// closing the lender account. Locks it already holds stay collateralized
+/// until the authority releases them.
+fn process_set_lender(program_id: &Pubkey, accounts: &[AccountInfo], approved: bool) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let lender_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let (lender_pda, lender_bump) =
+        Pubkey::find_program_address(&[LENDER_SEED, authority_info.key.as_ref()], program_id);
+    if *lender_info.key != lender_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if !approved {
+        if lender_info.owner == program_id {
+            close_program_account(lender_info, admin_info)?;
+        }
+        msg!("Lender {} revoked", authority_info.key);
+        return Ok(());
+    }
+
+    if lender_info.data_is_empty() {
+        let rent = Rent::get()?;
+        invoke_signed(
+            &system_instruction::create_account(
+                admin_info.key,
+                lender_info.key,
+                rent.minimum_balance(LenderAccount::SIZE),
+                LenderAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                admin_info.clone(),
+                lender_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[LENDER_SEED, authority_info.key.as_ref(), &[lender_bump]]],
+        )?;
+    }
+
+    LenderAccount {
+        discriminator: LenderAccount::DISCRIMINATOR,
+        authority: *authority_info.key,
+        bump: lender_bump,
+    }
+    .pack(&mut lender_info.data.borrow_mut());
+
+    msg!("Lender {} approved", authority_info.key);
+    Ok(())
+}
+
+/// Marks a lock as backing a loan from `lender_authority`, or releases it.
+fn process_set_collateralized(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    collateralized: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(account_info_iter)?;
+    let lender_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+
+    if collateralized {
+        if !owner_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        let (lender_pda, _) =
+            Pubkey::find_program_address(&[LENDER_SEED, authority_info.key.as_ref()], program_id);
+        if *lender_info.key != lender_pda || lender_info.owner != program_id {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        LenderAccount::unpack(&lender_info.data.borrow())
+            .map_err(|_| LocksmithError::Unauthorized)?;
+    }
+
+    authorize_collateral_change(&lock, owner_info.key, authority_info.key, collateralized)?;
+
+    if collateralized {
+        grow_lock(lock_account_info, &mut lock, owner_info, system_program_info)?;
+        lock.collateral_holder = *authority_info.key;
+    } else {
+        lock.collateral_holder = Pubkey::default();
+    }
+    lock.pack(&mut lock_account_info.data.borrow_mut());
+
+    msg!(
+        "Lock {} collateralized: {} (lender {})",
+        lock_account_info.key,
+        collateralized,
+        authority_info.key
+    );
+    Ok(())
+}
+
+/// Checks a collateral transition: marking needs the owner and an unfrozen
+/// lock with no claim code and no current holder; releasing needs the
+/// current holder.
+fn authorize_collateral_change(
+    lock: &LockAccount,
+    owner: &Pubkey,
+    authority: &Pubkey,
+    collateralized: bool,
+) -> ProgramResult {
+    if collateralized {
+        if lock.owner != *owner {
+            return Err(LocksmithError::Unauthorized.into());
+        }
+        if lock.frozen {
+            return Err(LocksmithError::LockFrozen.into());
+        }
+        if lock.collateral_holder != Pubkey::default() {
+            return Err(LocksmithError::LockCollateralized.into());
+        }
+        // Its tokens may be claimed by anyone holding the code
+        if lock.claim_key != [0u8; 32] {
+            return Err(LocksmithError::InvalidClaimCode.into());
+        }
+        // Its receipt's holder can hand the receipt to a lender instead
+        lock.can_change_terms()?;
+    } else if lock.collateral_holder == Pubkey::default() || lock.collateral_holder != *authority {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    Ok(())
+}
+
+/// Extra seed of the fee vault for `mint`. The original USDC vault has
+/// none, keeping its address; vaults of later fee mints add the mint.
+fn fee_vault_mint_seed(mint: &Pubkey) -> &[u8] {
+    if *mint == USDC_MINT {
+        &[]
+    } else {
+        mint.as_ref()
+    }
+}
+
+/// Checks that `fee_vault_info` is an open fee vault and returns its mint
+/// and bump. Only the current fee mint's vault is open, since
+/// `MigrateFeeMint` closes the old one, so its mint is the one fees are
+/// charged in.
+fn load_fee_vault(
+    program_id: &Pubkey,
+    fee_vault_info: &AccountInfo,
+) -> Result<(Pubkey, u8), ProgramError> {
+    if *fee_vault_info.owner != spl_token::id() {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    let mint = TokenAccount::unpack(&fee_vault_info.data.borrow())?.mint;
+    let (fee_vault_pda, bump) =
+        Pubkey::find_program_address(&[FEE_VAULT_SEED, fee_vault_mint_seed(&mint)], program_id);
+    if *fee_vault_info.key != fee_vault_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    Ok((mint, bump))
+}
+
+/// `load_fee_vault` for paths creating a lock of `mint_info`, returning
+/// the config, found among `accounts`, and the fee mint. Fails once the
+/// program is sunset, while creation is paused and for mints the config
+/// rejects, by their freeze authority or the mint policy mode; under a
+/// mode, the mint's policy PDA must be among `accounts` too.
+fn load_config_for_new_lock(
+    program_id: &Pubkey,
+    fee_vault_info: &AccountInfo,
+    mint_info: &AccountInfo,
+    accounts: &[AccountInfo],
+) -> Result<(ConfigAccount, Pubkey), ProgramError> {
+    let config = find_config(program_id, accounts)?;
+    if config.sunset_at != 0 {
+        return Err(LocksmithError::ProgramSunset.into());
+    }
+    if config.paused {
+        return Err(LocksmithError::ProgramPaused.into());
+    }
+
+    let (fee_mint, _) = load_fee_vault(program_id, fee_vault_info)?;
+    if config.reject_freezable_mints
+        && Mint::unpack(&mint_info.data.borrow())?.freeze_authority.is_some()
+    {
+        return Err(LocksmithError::MintHasFreezeAuthority.into());
+    }
+
+    let mode = config.mint_policy_mode;
+    if mode != MINT_POLICY_NONE {
+        let (policy_pda, _) =
+            Pubkey::find_program_address(&[MINT_POLICY_SEED, mint_info.key.as_ref()], program_id);
+        let policy_info = accounts
+            .iter()
+            .find(|info| *info.key == policy_pda)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        // An uncreated PDA means the mint has no policy
+        let policy = if policy_info.owner == program_id {
+            Some(MintPolicyAccount::unpack(&policy_info.data.borrow())?)
+        } else {
+            None
+        };
+        if !mint_allowed(mode, policy.as_ref()) {
+            return Err(LocksmithError::MintNotAllowed.into());
+        }
+    }
+    Ok((config, fee_mint))
+}
+
+/// The fee cap of `mint`, whose PDA must be among `accounts`, or `None` if
+/// the admin set none and its locks pay the flat fee.
+fn load_fee_cap(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint: &Pubkey,
+) -> Result<Option<u64>, ProgramError> {
+    let (fee_cap_pda, _) = Pubkey::find_program_address(&[FEE_CAP_SEED, mint.as_ref()], program_id);
+    // Leaving the PDA out must not dodge the percentage fee; only an
+    // uncreated one means the mint has no cap
+    let fee_cap_info = accounts
+        .iter()
+        .find(|info| *info.key == fee_cap_pda)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if fee_cap_info.owner != program_id || fee_cap_info.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(FeeCapAccount::unpack(&fee_cap_info.data.borrow())?.cap))
+}
+
+/// `config`'s percentage fee, at most `cap`, on a lock of `amount` tokens
+/// of `mint`, and the mint's token fee vault collecting it with its bump.
+/// The vault must be among `accounts`.
+fn load_bps_fee<'b, 'a>(
+    program_id: &Pubkey,
+    accounts: &'b [AccountInfo<'a>],
+    config: &ConfigAccount,
+    cap: u64,
+    mint: &Pubkey,
+    amount: u64,
+) -> Result<(u64, &'b AccountInfo<'a>, u8), ProgramError> {
+    let (token_fee_vault_pda, token_fee_vault_bump) =
+        Pubkey::find_program_address(&[TOKEN_FEE_VAULT_SEED, mint.as_ref()], program_id);
+    let token_fee_vault_info = accounts
+        .iter()
+        .find(|info| *info.key == token_fee_vault_pda)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let fee = capped_bps_fee(amount, config.fee_bps, cap);
+    Ok((fee, token_fee_vault_info, token_fee_vault_bump))
+}
+
+/// `config`'s fee in lamports and the SOL fee vault collecting it, found
+/// among `accounts`. Fails unless the config accepts SOL.
+fn load_sol_fee<'b, 'a>(
+    program_id: &Pubkey,
+    accounts: &'b [AccountInfo<'a>],
+    config: &ConfigAccount,
+) -> Result<(u64, &'b AccountInfo<'a>), ProgramError> {
+    if config.fee_lamports == 0 {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    let (sol_fee_vault_pda, _) = Pubkey::find_program_address(&[SOL_FEE_VAULT_SEED], program_id);
+    let sol_fee_vault_info = accounts
+        .iter()
+        .find(|info| *info.key == sol_fee_vault_pda)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    Ok((config.fee_lamports, sol_fee_vault_info))
+}
+
+/// The fee schedule's fee for a lock of `amount` tokens for `duration`
+/// seconds, if it meets one of its tiers. The schedule PDA is found among
+/// `accounts`.
+fn load_tiered_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    duration: u64,
+    amount: u64,
+) -> Result<Option<u64>, ProgramError> {
+    let (schedule_pda, _) = Pubkey::find_program_address(&[FEE_SCHEDULE_SEED], program_id);
+    let schedule_info = accounts
+        .iter()
+        .find(|info| *info.key == schedule_pda)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if schedule_info.owner != program_id {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    let schedule = FeeScheduleAccount::unpack(&schedule_info.data.borrow())?;
+    Ok(schedule.fee_for(duration, amount))
+}
+
+/// Whether `wallet`'s fee exemption PDA is among `accounts`.
+fn has_fee_exemption(program_id: &Pubkey, accounts: &[AccountInfo], wallet: &Pubkey) -> bool {
+    accounts.iter().any(|info| {
+        info.owner == program_id
+            && FeeExemptionAccount::unpack(&info.data.borrow()).is_ok_and(|exemption| {
+                exemption.wallet == *wallet
+                    && Pubkey::create_program_address(
+                        &[FEE_EXEMPTION_SEED, wallet.as_ref(), &[exemption.bump]],
+                        program_id,
+                    )
+                    .is_ok_and(|pda| pda == *info.key)
+            })
+    })
+}
+
+/// The config, found among `accounts`. Lock creation paths read it without
+/// it having a fixed position, like the mint's policy PDA.
+fn find_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<ConfigAccount, ProgramError> {
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    let config_info = accounts
+        .iter()
+        .find(|info| *info.key == config_pda)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if config_info.owner != program_id {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    ConfigAccount::unpack(&config_info.data.borrow())
+}
+
+/// The fee for a new lock, chosen by `lock_fee`.
+struct LockFee<'b, 'a> {
+    /// Paid in the fee mint from the fee account
+    usdc: u64,
+    /// Paid in lamports into the SOL fee vault instead
+    sol: Option<(u64, &'b AccountInfo<'a>)>,
+    /// The mint's fee cap, when a percentage fee is taken from the deposit
+    /// instead, at most that cap
+    bps_cap: Option<u64>,
+    /// How the fee was priced, recorded in the lock's `fee_mode`
+    mode: u8,
+}
+
+/// The fee every lock creation path charges for a lock of `amount` tokens
+/// of `mint` held for `duration` seconds, the accounts it reads found among
+/// `accounts`. NFT locks and locks paid for by an exempt `payer` are free.
+/// Otherwise a percentage fee, for mints with a fee cap, or the fee
+/// schedule's tier replaces the configured flat fee, but not `fee_override`,
+/// a template's fee. With `pays_in_sol`, the configured flat fee is paid in
+/// lamports instead.
+#[allow(clippy::too_many_arguments)]
+fn lock_fee<'b, 'a>(
+    program_id: &Pubkey,
+    accounts: &'b [AccountInfo<'a>],
+    config: &ConfigAccount,
+    mint: &Pubkey,
+    is_nft: bool,
+    payer: &Pubkey,
+    fee_override: Option<u64>,
+    duration: u64,
+    amount: u64,
+    pays_in_sol: bool,
+) -> Result<LockFee<'b, 'a>, ProgramError> {
+    let mut fee = LockFee {
+        usdc: 0,
+        sol: None,
+        bps_cap: None,
+        mode: LOCK_FEE_FLAT,
+    };
+    if is_nft || has_fee_exemption(program_id, accounts, payer) {
+        return Ok(fee);
+    }
+
+    if fee_override.is_none() && config.fee_bps > 0 {
+        fee.bps_cap = load_fee_cap(program_id, accounts, mint)?;
+        if fee.bps_cap.is_some() {
+            fee.mode = LOCK_FEE_PERCENTAGE;
+            return Ok(fee);
+        }
+    }
+    fee.usdc = match fee_override {
+        Some(fee_usdc) => fee_usdc,
+        None if config.fee_tiered => {
+            match load_tiered_fee(program_id, accounts, duration, amount)? {
+                Some(tier_fee) => {
+                    fee.mode = LOCK_FEE_TIERED;
+                    tier_fee
+                }
+                None => config.fee_usdc,
+            }
+        }
+        None => config.fee_usdc,
+    };
+
+    if pays_in_sol && fee.usdc > 0 {
+        if fee_override.is_some() {
+            return Err(LocksmithError::InvalidMint.into());
+        }
+        fee.sol = Some(load_sol_fee(program_id, accounts, config)?);
+        fee.usdc = 0;
+        fee.mode = LOCK_FEE_FLAT;
+    }
+    Ok(fee)
+}
+
+/// Moves a percentage fee of `fee` tokens from `source_info`, signed by
+/// `authority_info` with `signer_seeds`, into the mint's token fee vault,
+/// which `payer_info` creates on first use.
+#[allow(clippy::too_many_arguments)]
+fn pay_token_fee<'a>(
+    fee: u64,
+    source_info: &AccountInfo<'a>,
+    authority_info: &AccountInfo<'a>,
+    signer_seeds: &[&[&[u8]]],
+    token_fee_vault_info: &AccountInfo<'a>,
+    token_fee_vault_bump: u8,
+    payer_info: &AccountInfo<'a>,
+    mint_info: &AccountInfo<'a>,
+    token_program_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    if fee > 0 {
+        if token_fee_vault_info.data_is_empty() {
+            create_mint_vault(
+                TOKEN_FEE_VAULT_SEED,
+                payer_info,
+                token_fee_vault_info,
+                mint_info,
+                token_fee_vault_bump,
+                system_program_info,
+            )?;
+        }
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                source_info.key,
+                token_fee_vault_info.key,
+                authority_info.key,
+                &[],
+                fee,
+            )?,
+            &[
+                source_info.clone(),
+                token_fee_vault_info.clone(),
+                authority_info.clone(),
+            ],
+            signer_seeds,
+        )?;
+    }
+    msg!("Charged a fee of {} tokens", fee);
+    Ok(())
+}
+
+/// `bps` of `amount`, but no more than `cap`.
+fn capped_bps_fee(amount: u64, bps: u16, cap: u64) -> u64 {
+    bps_share(amount, bps).min(cap)
+}
+
+/// Creates the vault [`seed`, mint] of `mint_info`, a penalty or token fee
+/// vault, rent paid by `payer_info`. Its address is shared by every lock of
+/// the mint, so it is created even if someone has pre-funded it.
+fn create_mint_vault<'a>(
+    seed: &[u8],
+    payer_info: &AccountInfo<'a>,
+    vault_info: &AccountInfo<'a>,
+    mint_info: &AccountInfo<'a>,
+    vault_bump: u8,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    create_pda_account(
+        payer_info,
+        vault_info,
+        TokenAccount::LEN,
+        &spl_token::id(),
+        system_program_info,
+        &[seed, mint_info.key.as_ref(), &[vault_bump]],
+    )?;
+
+    invoke(
+        &spl_token::instruction::initialize_account3(
+            &spl_token::id(),
+            vault_info.key,
+            mint_info.key,
+            vault_info.key,
+        )?,
+        &[vault_info.clone(), mint_info.clone()],
+    )
+}
+
+/// Whether a mint with `policy`, if any, can be locked under mint policy
+/// `mode`.
+fn mint_allowed(mode: u8, policy: Option<&MintPolicyAccount>) -> bool {
+    match mode {
+        MINT_POLICY_ALLOWLIST => policy.is_some_and(|policy| policy.allowed),
+        MINT_POLICY_DENYLIST => policy.is_none_or(|policy| policy.allowed),
+        _ => true,
+    }
+}
+
+/// Proposes a new fee mint, or cancels a pending proposal when given the
+/// current one. Fee mints need `FEE_MINT_DECIMALS` decimals so that
+/// the configured fee keeps its value.
+fn process_propose_fee_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let new_fee_mint_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if config.sunset_at != 0 {
+        return Err(LocksmithError::ProgramSunset.into());
+    }
+
+    if *new_fee_mint_info.owner != spl_token::id()
+        || Mint::unpack(&new_fee_mint_info.data.borrow())?.decimals != FEE_MINT_DECIMALS
+    {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    if *new_fee_mint_info.key == config.current_fee_mint() {
+        config.pending_fee_mint = Pubkey::default();
+        config.fee_mint_effective_at = 0;
+        msg!("Fee mint proposal cancelled");
+    } else {
+        let effective_at = Clock::get()?
+            .unix_timestamp
+            .checked_add(FEE_MINT_TIMELOCK_SECONDS)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        config.pending_fee_mint = *new_fee_mint_info.key;
+        config.fee_mint_effective_at = effective_at;
+        msg!(
+            "Fee mint {} proposed, migratable from {}",
+            new_fee_mint_info.key,
+            effective_at
+        );
+    }
+
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+    Ok(())
+}
+
+/// Switches fees to the proposed mint once its timelock has elapsed. The
+/// old vault's balance is paid out to the admin and the vault closed, so
+/// locks can no longer pay fees in the old mint; the new mint's vault is
+/// created in its place.
+fn process_migrate_fee_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let old_fee_vault_info = next_account_info(account_info_iter)?;
+    let admin_token_info = next_account_info(account_info_iter)?;
+    let new_fee_mint_info = next_account_info(account_info_iter)?;
+    let new_fee_vault_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if config.sunset_at != 0 {
+        return Err(LocksmithError::ProgramSunset.into());
+    }
+
+    let clock = Clock::get()?;
+    if config.pending_fee_mint == Pubkey::default()
+        || *new_fee_mint_info.key != config.pending_fee_mint
+        || clock.unix_timestamp < config.fee_mint_effective_at
+    {
+        return Err(LocksmithError::FeeMintNotReady.into());
+    }
+
+    let old_fee_mint = config.current_fee_mint();
+    let (vault_mint, old_fee_vault_bump) = load_fee_vault(program_id, old_fee_vault_info)?;
+    if vault_mint != old_fee_mint {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let new_fee_mint = config.pending_fee_mint;
+    let (new_fee_vault_pda, new_fee_vault_bump) = Pubkey::find_program_address(
+        &[FEE_VAULT_SEED, fee_vault_mint_seed(&new_fee_mint)],
+        program_id,
+    );
+    if *new_fee_vault_info.key != new_fee_vault_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let old_vault_seeds: &[&[u8]] = &[
+        FEE_VAULT_SEED,
+        fee_vault_mint_seed(&old_fee_mint),
+        &[old_fee_vault_bump],
+    ];
+    let remaining = TokenAccount::unpack(&old_fee_vault_info.data.borrow())?.amount;
+    if remaining > 0 {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                old_fee_vault_info.key,
+                admin_token_info.key,
+                old_fee_vault_info.key,
+                &[],
+                remaining,
+            )?,
+            &[
+                old_fee_vault_info.clone(),
+                admin_token_info.clone(),
+                old_fee_vault_info.clone(),
+            ],
+            &[old_vault_seeds],
+        )?;
+    }
+
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program_info.key,
+            old_fee_vault_info.key,
+            admin_info.key,
+            old_fee_vault_info.key,
+            &[],
+        )?,
+        &[
+            old_fee_vault_info.clone(),
+            admin_info.clone(),
+            old_fee_vault_info.clone(),
+        ],
+        &[old_vault_seeds],
+    )?;
+
+    invoke_signed(
+        &system_instruction::create_account(
+            admin_info.key,
+            new_fee_vault_info.key,
+            Rent::get()?.minimum_balance(TokenAccount::LEN),
+            TokenAccount::LEN as u64,
+            &spl_token::id(),
+        ),
+        &[
+            admin_info.clone(),
+            new_fee_vault_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[
+            FEE_VAULT_SEED,
+            fee_vault_mint_seed(&new_fee_mint),
+            &[new_fee_vault_bump],
+        ]],
+    )?;
+
+    invoke(
+        &spl_token::instruction::initialize_account3(
+            &spl_token::id(),
+            new_fee_vault_info.key,
+            new_fee_mint_info.key,
+            new_fee_vault_info.key,
+        )?,
+        &[new_fee_vault_info.clone(), new_fee_mint_info.clone()],
+    )?;
+
+    config.fee_mint = new_fee_mint;
+    config.pending_fee_mint = Pubkey::default();
+    config.fee_mint_effective_at = 0;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    msg!(
+        "Fee mint migrated from {} to {}; {} withdrawn from the old vault",
+        old_fee_mint,
+        new_fee_mint,
+        remaining
+    );
+    Ok(())
+}
+
+/// Allows or denies locking a mint, creating its policy PDA if needed.
+fn process_set_mint_policy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    allowed: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let policy_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if *mint_info.owner != spl_token::id() {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    let (policy_pda, policy_bump) =
+        Pubkey::find_program_address(&[MINT_POLICY_SEED, mint_info.key.as_ref()], program_id);
+    if *policy_info.key != policy_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if policy_info.data_is_empty() {
+        invoke_signed(
+            &system_instruction::create_account(
+                admin_info.key,
+                policy_info.key,
+                Rent::get()?.minimum_balance(MintPolicyAccount::SIZE),
+                MintPolicyAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                admin_info.clone(),
+                policy_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[MINT_POLICY_SEED, mint_info.key.as_ref(), &[policy_bump]]],
+        )?;
+    } else if policy_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    MintPolicyAccount {
+        discriminator: MintPolicyAccount::DISCRIMINATOR,
+        mint: *mint_info.key,
+        allowed,
+        bump: policy_bump,
+    }
+    .pack(&mut policy_info.data.borrow_mut());
+
+    msg!("Mint {} {}", mint_info.key, if allowed { "allowed" } else { "denied" });
+    Ok(())
+}
+
+/// Removes a mint's policy, returning its rent to the admin.
+fn process_close_mint_policy(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let policy_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if policy_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let policy = MintPolicyAccount::unpack(&policy_info.data.borrow())?;
+
+    close_program_account(policy_info, admin_info)?;
+
+    msg!("Policy of mint {} removed", policy.mint);
+    Ok(())
+}
+
+/// Exempts a wallet from fees, creating its fee exemption PDA.
+fn process_set_fee_exemption(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let wallet_info = next_account_info(account_info_iter)?;
+    let exemption_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let (exemption_pda, exemption_bump) =
+        Pubkey::find_program_address(&[FEE_EXEMPTION_SEED, wallet_info.key.as_ref()], program_id);
+    if *exemption_info.key != exemption_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if !exemption_info.data_is_empty() {
+        return Err(LocksmithError::AlreadyInitialized.into());
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            admin_info.key,
+            exemption_info.key,
+            Rent::get()?.minimum_balance(FeeExemptionAccount::SIZE),
+            FeeExemptionAccount::SIZE as u64,
+            program_id,
+        ),
+        &[
+            admin_info.clone(),
+            exemption_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[FEE_EXEMPTION_SEED, wallet_info.key.as_ref(), &[exemption_bump]]],
+    )?;
+
+    FeeExemptionAccount {
+        discriminator: FeeExemptionAccount::DISCRIMINATOR,
+        wallet: *wallet_info.key,
+        bump: exemption_bump,
+    }
+    .pack(&mut exemption_info.data.borrow_mut());
+
+    msg!("Wallet {} exempted from fees", wallet_info.key);
+    Ok(())
+}
+
+/// Revokes a wallet's fee exemption, returning its rent to the admin.
+fn process_close_fee_exemption(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let exemption_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if exemption_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let exemption = FeeExemptionAccount::unpack(&exemption_info.data.borrow())?;
+
+    close_program_account(exemption_info, admin_info)?;
+
+    msg!("Fee exemption of wallet {} revoked", exemption.wallet);
+    Ok(())
+}
+
+/// Caps the unclosed locks each owner may hold, or lifts the cap if 0.
+fn process_set_max_locks_per_owner(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_locks: u32,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    config.max_locks_per_owner = max_locks;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    if max_locks > 0 {
+        msg!("Owners may hold up to {} locks", max_locks);
+    } else {
+        msg!("Locks per owner no longer capped");
+    }
+    Ok(())
+}
+
+/// Sets an owner's own lock cap, creating their counter PDA if needed.
+fn process_set_owner_lock_cap(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lock_cap: u32,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let counter_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let (counter_pda, counter_bump) =
+        Pubkey::find_program_address(&[OWNER_COUNTER_SEED, owner_info.key.as_ref()], program_id);
+    if *counter_info.key != counter_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut counter = load_owner_counter(
+        program_id,
+        counter_info,
+        counter_bump,
+        owner_info.key,
+        admin_info,
+        system_program_info,
+    )?;
+    counter.lock_cap = lock_cap;
+    counter.pack(&mut counter_info.data.borrow_mut());
+
+    if lock_cap > 0 {
+        msg!("Owner {} may hold up to {} locks", owner_info.key, lock_cap);
+    } else {
+        msg!("Owner {} returned to the configured lock cap", owner_info.key);
+    }
+    Ok(())
+}
+
+/// Loads `owner`'s counter PDA, creating it at `payer`'s expense if it does
+/// not exist yet.
+fn load_owner_counter<'a>(
+    program_id: &Pubkey,
+    counter_info: &AccountInfo<'a>,
+    counter_bump: u8,
+    owner: &Pubkey,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<OwnerCounterAccount, ProgramError> {
+    if !counter_info.data_is_empty() {
+        if counter_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        return OwnerCounterAccount::unpack(&counter_info.data.borrow());
+    }
+
+    create_pda_account(
+        payer,
+        counter_info,
+        OwnerCounterAccount::SIZE,
+        program_id,
+        system_program,
+        &[OWNER_COUNTER_SEED, owner.as_ref(), &[counter_bump]],
+    )?;
+
+    Ok(OwnerCounterAccount {
+        discriminator: OwnerCounterAccount::DISCRIMINATOR,
+        owner: *owner,
+        active_locks: 0,
+        lock_cap: 0,
+        bump: counter_bump,
+    })
+}
+
+/// Counts a new lock of `owner` on their counter PDA, found among
+/// `accounts`, while the config caps locks per owner. Returns whether the
+/// lock was counted.
+fn count_owner_lock<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    config: &ConfigAccount,
+    owner: &Pubkey,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<bool, ProgramError> {
+    if config.max_locks_per_owner == 0 {
+        return Ok(false);
+    }
+
+    let (counter_info, counter_bump) = find_owner_counter(program_id, accounts, owner)?;
+    let mut counter = load_owner_counter(
+        program_id,
+        counter_info,
+        counter_bump,
+        owner,
+        payer,
+        system_program,
+    )?;
+    if counter.active_locks >= counter.cap(config) {
+        return Err(LocksmithError::LockCapReached.into());
+    }
+    counter.active_locks += 1;
+    counter.pack(&mut counter_info.data.borrow_mut());
+    Ok(true)
+}
+
+/// Takes a closing lock off its owner's counter PDA, found among
+/// `accounts`, if the lock was counted when created.
+fn uncount_owner_lock(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lock: &LockAccount,
+) -> ProgramResult {
+    if !lock.owner_counted {
+        return Ok(());
+    }
+
+    let (counter_info, _) = find_owner_counter(program_id, accounts, &lock.owner)?;
+    if counter_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut counter = OwnerCounterAccount::unpack(&counter_info.data.borrow())?;
+    counter.active_locks = counter.active_locks.saturating_sub(1);
+    counter.pack(&mut counter_info.data.borrow_mut());
+    Ok(())
+}
+
+/// `owner`'s counter PDA and its bump, found among `accounts`.
+fn find_owner_counter<'b, 'a>(
+    program_id: &Pubkey,
+    accounts: &'b [AccountInfo<'a>],
+    owner: &Pubkey,
+) -> Result<(&'b AccountInfo<'a>, u8), ProgramError> {
+    let (counter_pda, counter_bump) =
+        Pubkey::find_program_address(&[OWNER_COUNTER_SEED, owner.as_ref()], program_id);
+    let counter_info = accounts
+        .iter()
+        .find(|info| *info.key == counter_pda)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    Ok((counter_info, counter_bump))
+}
+
+/// Sets the mint policy mode.
+fn process_set_mint_policy_mode(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mode: u8,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if config.sunset_at != 0 {
+        return Err(LocksmithError::ProgramSunset.into());
+    }
+
+    config.mint_policy_mode = mode;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    msg!("Mint policy mode set to {}", mode);
+    Ok(())
+}
+
+/// Rejects or accepts mints with a freeze authority.
+fn process_set_reject_freezable_mints(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reject: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if config.sunset_at != 0 {
+        return Err(LocksmithError::ProgramSunset.into());
+    }
+
+    config.reject_freezable_mints = reject;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    if reject {
+        msg!("Mints with a freeze authority can no longer be locked");
+    } else {
+        msg!("Mints with a freeze authority can be locked");
+    }
+    Ok(())
+}
+
+/// Sets the fee charged per lock.
+fn process_update_fee_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_fee: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if config.sunset_at != 0 {
+        return Err(LocksmithError::ProgramSunset.into());
+    }
+
+    if new_fee > MAX_FEE_USDC {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    config.fee_usdc = new_fee;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    msg!("Fee set to {}", new_fee);
+    Ok(())
+}
+
+/// Switches locks of mints with a fee cap to paying `fee_bps` of their
+/// tokens, or back to the flat fee when `fee_bps` is 0.
+fn process_set_percentage_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_bps: u16,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if config.sunset_at != 0 {
+        return Err(LocksmithError::ProgramSunset.into());
+    }
+
+    if fee_bps > MAX_FEE_BPS {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    config.fee_bps = fee_bps;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    if fee_bps > 0 {
+        msg!("Fee set to {} bps of each lock of a capped mint", fee_bps);
+    } else {
+        msg!("Fee set back to the flat fee");
+    }
+    Ok(())
+}
+
+/// Sets the most tokens of a mint the percentage fee takes from one lock,
+/// creating the mint's fee cap PDA if needed.
+fn process_set_fee_cap(program_id: &Pubkey, accounts: &[AccountInfo], cap: u64) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let fee_cap_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if *mint_info.owner != spl_token::id() {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    // A zero cap would charge nothing; closing the cap returns the mint to
+    // the flat fee
+    if cap == 0 {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    let (fee_cap_pda, fee_cap_bump) =
+        Pubkey::find_program_address(&[FEE_CAP_SEED, mint_info.key.as_ref()], program_id);
+    if *fee_cap_info.key != fee_cap_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if fee_cap_info.data_is_empty() {
+        invoke_signed(
+            &system_instruction::create_account(
+                admin_info.key,
+                fee_cap_info.key,
+                Rent::get()?.minimum_balance(FeeCapAccount::SIZE),
+                FeeCapAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                admin_info.clone(),
+                fee_cap_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[FEE_CAP_SEED, mint_info.key.as_ref(), &[fee_cap_bump]]],
+        )?;
+    } else if fee_cap_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    FeeCapAccount {
+        discriminator: FeeCapAccount::DISCRIMINATOR,
+        mint: *mint_info.key,
+        cap,
+        bump: fee_cap_bump,
+    }
+    .pack(&mut fee_cap_info.data.borrow_mut());
+
+    msg!("Fee on locks of mint {} capped at {} tokens", mint_info.key, cap);
+    Ok(())
+}
+
+/// Removes a mint's fee cap, returning its rent to the admin.
+fn process_close_fee_cap(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let fee_cap_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if fee_cap_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let fee_cap = FeeCapAccount::unpack(&fee_cap_info.data.borrow())?;
+
+    close_program_account(fee_cap_info, admin_info)?;
+
+    msg!("Fee cap of mint {} removed", fee_cap.mint);
+    Ok(())
+}
+
+/// Replaces the fee schedule's tiers, creating its PDA if needed. No tiers
+/// returns to the configured flat fee.
+fn process_set_fee_schedule(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    tier_count: u8,
+    min_durations: [u64; MAX_FEE_TIERS],
+    min_amounts: [u64; MAX_FEE_TIERS],
+    fees_usdc: [u64; MAX_FEE_TIERS],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let schedule_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if config.sunset_at != 0 {
+        return Err(LocksmithError::ProgramSunset.into());
+    }
+
+    let tiers = usize::from(tier_count);
+    if tiers > MAX_FEE_TIERS || fees_usdc[..tiers].iter().any(|&fee| fee > MAX_FEE_USDC) {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    let (schedule_pda, schedule_bump) =
+        Pubkey::find_program_address(&[FEE_SCHEDULE_SEED], program_id);
+    if *schedule_info.key != schedule_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    if schedule_info.data_is_empty() {
+        invoke_signed(
+            &system_instruction::create_account(
+                admin_info.key,
+                schedule_info.key,
+                Rent::get()?.minimum_balance(FeeScheduleAccount::SIZE),
+                FeeScheduleAccount::SIZE as u64,
+                program_id,
+            ),
+            &[
+                admin_info.clone(),
+                schedule_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[FEE_SCHEDULE_SEED, &[schedule_bump]]],
+        )?;
+    } else if schedule_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    FeeScheduleAccount {
+        discriminator: FeeScheduleAccount::DISCRIMINATOR,
+        tier_count,
+        min_durations,
+        min_amounts,
+        fees_usdc,
+        bump: schedule_bump,
+    }
+    .pack(&mut schedule_info.data.borrow_mut());
+
+    config.fee_tiered = tier_count > 0;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    if tier_count > 0 {
+        msg!("Fee schedule set with {} tiers", tier_count);
+    } else {
+        msg!("Fee schedule cleared");
+    }
+    Ok(())
+}
+
+/// Sets the fee in lamports lockers may pay instead of the flat fee, or
+/// stops accepting SOL with 0. The SOL fee vault is topped up to rent
+/// exemption from the admin, so fees below the rent minimum still land.
+fn process_set_sol_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_lamports: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let sol_fee_vault_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if config.sunset_at != 0 {
+        return Err(LocksmithError::ProgramSunset.into());
+    }
+
+    if fee_lamports > MAX_FEE_LAMPORTS {
+        return Err(LocksmithError::InvalidAmount.into());
+    }
+
+    let (sol_fee_vault_pda, _) = Pubkey::find_program_address(&[SOL_FEE_VAULT_SEED], program_id);
+    if *sol_fee_vault_info.key != sol_fee_vault_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let shortfall = rent_exempt_minimum.saturating_sub(sol_fee_vault_info.lamports());
+    if fee_lamports > 0 && shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(admin_info.key, sol_fee_vault_info.key, shortfall),
+            &[
+                admin_info.clone(),
+                sol_fee_vault_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+    }
+
+    config.fee_lamports = fee_lamports;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    if fee_lamports > 0 {
+        msg!("Fees can be paid as {} lamports", fee_lamports);
+    } else {
+        msg!("Fees can no longer be paid in SOL");
+    }
+    Ok(())
+}
+
+/// Withdraws `amount` lamports of SOL fees, or all of them if 0, to any
+/// account. The vault's rent exemption stays behind.
+fn process_withdraw_sol_fees(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let sol_fee_vault_info = next_account_info(account_info_iter)?;
+    let destination_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    let (sol_fee_vault_pda, sol_fee_vault_bump) =
+        Pubkey::find_program_address(&[SOL_FEE_VAULT_SEED], program_id);
+    if *sol_fee_vault_info.key != sol_fee_vault_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let available = sol_fee_vault_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    let amount = if amount == 0 { available } else { amount };
+
+    if amount == 0 {
+        return Ok(());
+    }
+    if amount > available {
+        return Err(LocksmithError::InsufficientFunds.into());
+    }
+
+    invoke_signed(
+        &system_instruction::transfer(sol_fee_vault_info.key, destination_info.key, amount),
+        &[
+            sol_fee_vault_info.clone(),
+            destination_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[SOL_FEE_VAULT_SEED, &[sol_fee_vault_bump]]],
+    )?;
+
+    msg!("Withdrawn {} lamports of fees to {}", amount, destination_info.key);
+    Ok(())
+}
+
+/// Declares the program locks may be migrated to, or withdraws the
+/// declaration. Each migration still needs its lock owner's signature.
+fn process_set_successor_program(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    successor_program: Pubkey,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if successor_program == *program_id {
+        return Err(LocksmithError::InvalidSuccessorProgram.into());
+    }
+
+    config.successor_program = successor_program;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    if successor_program == Pubkey::default() {
+        msg!("Successor program cleared");
+    } else {
+        msg!("Successor program set to {}", successor_program);
+    }
+    Ok(())
+}
+
+/// Moves a lock's escrowed tokens to a token account owned by the successor
+/// program's PDA at the lock's seeds, then closes the lock and escrow. The
+/// successor adopts the lock from there; it can read this lock's terms
+/// earlier in the same transaction, before the lock is closed.
+fn process_migrate_lock(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let owner_info = next_account_info(account_info_iter)?;
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let lock_account_info = next_account_info(account_info_iter)?;
+    let lock_token_info = next_account_info(account_info_iter)?;
+    let successor_info = next_account_info(account_info_iter)?;
+    let successor_token_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer || !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+    let config = ConfigAccount::unpack(&config_info.data.borrow())?;
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+    if config.successor_program == Pubkey::default()
+        || *successor_info.key != config.successor_program
+        || !successor_info.executable
+    {
+        return Err(LocksmithError::InvalidSuccessorProgram.into());
+    }
+
+    if lock_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let lock = LockAccount::unpack(&lock_account_info.data.borrow())?;
+    if lock.owner != *owner_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    // Migration hands the tokens to the owner's successor lock
+    lock.can_pay_out_to_owner(Clock::get()?.unix_timestamp)?;
+    // ...under the successor's rules, so without its permanence, price
+    // condition, arbiter's approval or counterparty
+    if lock.permanent {
+        return Err(LocksmithError::LockIsPermanent.into());
+    }
+    if lock.price_feed_id != [0u8; 32] {
+        return Err(LocksmithError::InvalidPriceCondition.into());
+    }
+    if lock.arbiter != Pubkey::default() {
+        return Err(LocksmithError::InvalidArbiter.into());
+    }
+    if lock.counterparty != Pubkey::default() {
+        return Err(LocksmithError::InvalidCounterparty.into());
+    }
+
+    let lock_id_bytes = lock.lock_id.to_le_bytes();
+    let lock_seeds: &[&[u8]] = &[
+        LOCK_SEED,
+        owner_info.key.as_ref(),
+        lock.mint.as_ref(),
+        &lock_id_bytes,
+    ];
+    let (lock_pda, _) = Pubkey::find_program_address(lock_seeds, program_id);
+    if *lock_account_info.key != lock_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let (lock_token_pda, _) =
+        Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_account_info.key.as_ref()], program_id);
+    if *lock_token_info.key != lock_token_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let lock_token = TokenAccount::unpack(&lock_token_info.data.borrow())?;
+    if lock_token.amount != lock.amount {
+        return Err(LocksmithError::InconsistentState.into());
+    }
+
+    let (successor_lock, _) = Pubkey::find_program_address(lock_seeds, successor_info.key);
+    let successor_token = TokenAccount::unpack(&successor_token_info.data.borrow())?;
+    if successor_token.owner != successor_lock {
+        return Err(LocksmithError::InvalidSuccessorProgram.into());
+    }
+    if successor_token.mint != lock.mint {
+        return Err(LocksmithError::InvalidMint.into());
+    }
+
+    let signer_seeds: &[&[u8]] = &[
+        LOCK_SEED,
+        owner_info.key.as_ref(),
+        lock.mint.as_ref(),
+        &lock_id_bytes,
+        &[lock.bump],
+    ];
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            lock_token_info.key,
+            successor_token_info.key,
+            lock_account_info.key,
+            &[],
+            lock.amount,
+        )?,
+        &[
+            lock_token_info.clone(),
+            successor_token_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program_info.key,
+            lock_token_info.key,
+            owner_info.key,
+            lock_account_info.key,
+            &[],
+        )?,
+        &[
+            lock_token_info.clone(),
+            owner_info.clone(),
+            lock_account_info.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    uncount_owner_lock(program_id, accounts, &lock)?;
+    close_program_account(lock_account_info, owner_info)?;
+
+    msg!(
+        "Migrated lock {} ({} tokens) to {}",
+        lock_account_info.key,
+        lock.amount,
+        successor_info.key
+    );
+    Ok(())
+}
+
+/// Permanently stops lock creation. The config records the time, which
+/// every lock creation path checks, and fee mint migrations are refused
+/// from here on.
+fn process_sunset(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if config.sunset_at != 0 {
+        return Err(LocksmithError::ProgramSunset.into());
+    }
+
+    config.sunset_at = Clock::get()?.unix_timestamp;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    msg!("Program sunset at {}; no new locks can be created", config.sunset_at);
+    Ok(())
+}
+
+/// Pauses or resumes lock creation.
+fn process_set_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !solana_system_interface::program::check_id(system_program_info.key) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if *config_info.key != config_pda {
+        return Err(LocksmithError::InvalidPDA.into());
+    }
+
+    let mut config = ConfigAccount::unpack(&config_info.data.borrow())?;
+
+    if config.admin != *admin_info.key {
+        return Err(LocksmithError::Unauthorized.into());
+    }
+
+    if config.sunset_at != 0 {
+        return Err(LocksmithError::ProgramSunset.into());
+    }
+
+    config.paused = paused;
+    grow_account(config_info, ConfigAccount::SIZE, admin_info, system_program_info)?;
+    config.pack(&mut config_info.data.borrow_mut());
+
+    msg!("Lock creation {}", if paused { "paused" } else { "resumed" });
+    Ok(())
+}
+
+/// Creates the PDA `account` at `seeds` with `space` bytes, owned by
+/// `owner`, rent paid by `payer`. `create_account` fails on an address that
+/// already holds lamports, so for PDAs at addresses anyone can derive, a
+/// pre-funded account is topped up, allocated and assigned instead.
+fn create_pda_account<'a>(
+    payer: &AccountInfo<'a>,
+    account: &AccountInfo<'a>,
+    space: usize,
+    owner: &Pubkey,
+    system_program: &AccountInfo<'a>,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+    if account.lamports() == 0 {
+        return invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                account.key,
+                rent_exempt,
+                space as u64,
+                owner,
+            ),
+            &[payer.clone(), account.clone(), system_program.clone()],
+            &[seeds],
+        );
+    }
+
+    let shortfall = rent_exempt.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, shortfall),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+    invoke_signed(
+        &system_instruction::allocate(account.key, space as u64),
+        &[account.clone(), system_program.clone()],
+        &[seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(account.key, owner),
+        &[account.clone(), system_program.clone()],
+        &[seeds],
+    )
+}
+
+/// Grows a program-owned account created at an older, smaller layout,
+/// topping up its rent from `payer` so it stays rent-exempt.
+fn grow_account<'a>(
+    account: &AccountInfo<'a>,
+    new_len: usize,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, shortfall),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+
+    account.resize(new_len)
+}
+
+/// Grows a lock to `LockAccount::SIZE` and moves it to the current layout
+/// version, which `lock` is packed with from then on.
+fn grow_lock<'a>(
+    lock_account_info: &AccountInfo<'a>,
+    lock: &mut LockAccount,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    grow_account(lock_account_info, LockAccount::SIZE, payer, system_program)?;
+    lock.layout_version = LockAccount::LAYOUT_VERSION;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::program_error::ProgramError;
+
+    #[test]
+    fn test_process_instruction_empty_data() {
+        let program_id = Pubkey::new_unique();
+        let accounts: Vec<AccountInfo> = vec![];
+        let instruction_data: [u8; 0] = [];
+
+        let result = process_instruction(&program_id, &accounts, &instruction_data);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::Custom(LocksmithError::InvalidInstruction as u32)
+        );
+    }
+
+    #[test]
+    fn test_process_instruction_invalid_tag() {
+        let program_id = Pubkey::new_unique();
+        let accounts: Vec<AccountInfo> = vec![];
+        let instruction_data = [255u8];
+
+        let result = process_instruction(&program_id, &accounts, &instruction_data);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::Custom(LocksmithError::InvalidInstruction as u32)
+        );
+    }
+
+    #[test]
+    fn test_lock_pda_isolation_by_lock_id() {
+        let program_id = crate::id();
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        let (pda_0, _) = Pubkey::find_program_address(
+            &[LOCK_SEED, owner.as_ref(), mint.as_ref(), &0u64.to_le_bytes()],
+            &program_id,
+        );
+        let (pda_1, _) = Pubkey::find_program_address(
+            &[LOCK_SEED, owner.as_ref(), mint.as_ref(), &1u64.to_le_bytes()],
+            &program_id,
+        );
+
+        assert_ne!(pda_0, pda_1);
+    }
+
+    #[test]
+    fn test_lock_pda_isolation_by_owner() {
+        let program_id = crate::id();
+        let owner_1 = Pubkey::new_unique();
+        let owner_2 = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let lock_id = 1u64.to_le_bytes();
+
+        let (pda_1, _) = Pubkey::find_program_address(
+            &[LOCK_SEED, owner_1.as_ref(), mint.as_ref(), &lock_id],
+            &program_id,
+        );
+        let (pda_2, _) = Pubkey::find_program_address(
+            &[LOCK_SEED, owner_2.as_ref(), mint.as_ref(), &lock_id],
+            &program_id,
+        );
+
+        assert_ne!(pda_1, pda_2);
+    }
+
+    #[test]
+    fn test_lock_pda_isolation_by_mint() {
+        let program_id = crate::id();
+        let owner = Pubkey::new_unique();
+        let mint_1 = Pubkey::new_unique();
+        let mint_2 = Pubkey::new_unique();
+        let lock_id = 1u64.to_le_bytes();
+
+        let (pda_1, _) = Pubkey::find_program_address(
+            &[LOCK_SEED, owner.as_ref(), mint_1.as_ref(), &lock_id],
+            &program_id,
+        );
+        let (pda_2, _) = Pubkey::find_program_address(
+            &[LOCK_SEED, owner.as_ref(), mint_2.as_ref(), &lock_id],
+            &program_id,
+        );
+
+        assert_ne!(pda_1, pda_2);
+    }
+
+    #[test]
+    fn test_lock_token_pda_isolation() {
+        let program_id = crate::id();
+        let lock_1 = Pubkey::new_unique();
+        let lock_2 = Pubkey::new_unique();
+
+        let (token_pda_1, _) =
+            Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_1.as_ref()], &program_id);
+        let (token_pda_2, _) =
+            Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_2.as_ref()], &program_id);
+
+        assert_ne!(token_pda_1, token_pda_2);
+    }
+
+    #[test]
+    fn test_usdc_mint_matches_mainnet() {
+        assert_eq!(
+            USDC_MINT.to_string(),
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+        );
+    }
+
+    #[test]
+    fn test_program_id_matches_declared() {
+        assert_eq!(
+            crate::id().to_string(),
+            "A5vz72a5ipKUJZxmGUjGtS7uhWfzr6jhDgV2q73YhD8A"
+        );
+    }
+
+    #[test]
+    fn test_fee_usdc_is_015_usdc() {
+        // 0.15 USDC with 6 decimals = 150,000
+        assert_eq!(FEE_USDC, 150_000);
+    }
+
+    #[test]
+    fn test_usdc_fee_vault_keeps_its_address() {
+        let program_id = crate::id();
+        let (legacy, _) = Pubkey::find_program_address(&[FEE_VAULT_SEED], &program_id);
+        let (usdc, _) = Pubkey::find_program_address(
+            &[FEE_VAULT_SEED, fee_vault_mint_seed(&USDC_MINT)],
+            &program_id,
+        );
+        let (other, _) = Pubkey::find_program_address(
+            &[FEE_VAULT_SEED, fee_vault_mint_seed(&Pubkey::new_unique())],
+            &program_id,
+        );
+
+        assert_eq!(usdc, legacy);
+        assert_ne!(other, legacy);
+    }
+
+    #[test]
+    fn test_config_account_size() {
+        // discriminator(8) + admin(32) + bump(1) = 41
+        assert_eq!(ConfigAccount::LEGACY_SIZE, 41);
+        // + compliance_authority(32) + pending_compliance_authority(32)
+        // + compliance_authority_effective_at(8) = 113
+        assert_eq!(ConfigAccount::COMPLIANCE_SIZE, 113);
+        // + fee_mint(32) + pending_fee_mint(32) + fee_mint_effective_at(8) = 185
+        assert_eq!(ConfigAccount::FEE_MINT_SIZE, 185);
+        // + successor_program(32) = 217
+        assert_eq!(ConfigAccount::SUCCESSOR_SIZE, 217);
+        // + sunset_at(8) = 225
+        assert_eq!(ConfigAccount::SUNSET_SIZE, 225);
+        // + early_unlock_penalty_bps(2) = 227
+        assert_eq!(ConfigAccount::PENALTY_SIZE, 227);
+        // + batch_fee_per_lock(1) = 228
+        assert_eq!(ConfigAccount::BATCH_FEE_SIZE, 228);
+        // + paused(1) = 229
+        assert_eq!(ConfigAccount::PAUSE_SIZE, 229);
+        // + mint_policy_mode(1) = 230
+        assert_eq!(ConfigAccount::MINT_POLICY_SIZE, 230);
+        // + reject_freezable_mints(1) = 231
+        assert_eq!(ConfigAccount::FREEZABLE_SIZE, 231);
+        // + fee_usdc(8) = 239
+        assert_eq!(ConfigAccount::FEE_SIZE, 239);
+        // + fee_bps(2) = 241
+        assert_eq!(ConfigAccount::BPS_FEE_SIZE, 241);
+        // + fee_lamports(8) = 249
+        assert_eq!(ConfigAccount::SOL_FEE_SIZE, 249);
+        // + fee_tiered(1) = 250
+        assert_eq!(ConfigAccount::FEE_SCHEDULE_SIZE, 250);
+        // + max_locks_per_owner(4) = 254
+        assert_eq!(ConfigAccount::SIZE, 254);
+    }
+
+    #[test]
+    fn test_lock_account_size() {
+        // discriminator(8) + owner(32) + mint(32) + amount(8) + unlock_timestamp(8)
+        // + created_at(8) + lock_id(8) + bump(1) = 105
+        assert_eq!(LockAccount::LEGACY_SIZE, 105);
+        // + frozen(1) + credential_issuer(32) + non_transferable(1)
+        // + forward_destination(32) + hook(32) + recovery_address(32)
+        // + donation_address(32) + donation_bps(2) + collateral_holder(32)
+        // + layout_version(1) + unlock_slot(8) + unlock_epoch(8) + claim_key(32)
+        // + claim_expires_at(8) + index_seq(4) + early_unlock_allowed(1)
+        // + permanent(1) + is_nft(1) + receipt_bound(1) + label(32)
+        // + metadata_uri(128) + fallback_owner(32) + fallback_after(8)
+        // + unlock_destination(32) + shared(1) + approvals_required(1)
+        // + price_feed_id(32) + price_threshold(8) + price_exponent(4)
+        // + arbiter(32) + milestone_approved(1) + counterparty(32)
+        // + owner_counted(1) + reserved(63) = 773
+        assert_eq!(LockAccount::SIZE, 773);
+    }
+
+    #[test]
+    fn test_max_lock_duration_is_10_years() {
+        // 10 years in seconds = 10 * 365 * 24 * 60 * 60
+        let expected_seconds: i64 = 10 * 365 * 24 * 60 * 60;
+        assert_eq!(MAX_LOCK_DURATION_SECONDS, expected_seconds);
+        // Verify it's approximately 315,360,000 seconds (10 years without leap years)
+        assert_eq!(MAX_LOCK_DURATION_SECONDS, 315_360_000);
+    }
+
+    #[test]
+    fn test_max_lock_duration_arithmetic_safety() {
+        // Ensure adding MAX_LOCK_DURATION_SECONDS to a reasonable timestamp doesn't overflow
+        let current_timestamp: i64 = 1_700_000_000; // ~2023
+        let result = current_timestamp.checked_add(MAX_LOCK_DURATION_SECONDS);
+        assert!(result.is_some());
+        // Result should be around 2033
+        assert!(result.unwrap() > current_timestamp);
+    }
+
+    #[test]
+    fn test_system_program_check_id_validates_correctly() {
+        // Test that the system program ID is recognized
+        let system_program_id = solana_system_interface::program::id();
+        assert!(solana_system_interface::program::check_id(&system_program_id));
+
+        // Test that a random key is not recognized as system program
+        let random_key = Pubkey::new_unique();
+        assert!(!solana_system_interface::program::check_id(&random_key));
+    }
+
+    #[test]
+    fn test_lock_duration_exceeded_error_code() {
+        // Ensure the new error code is correct
+        assert_eq!(LocksmithError::LockDurationExceeded as u32, 11);
+    }
+
+    #[test]
+    fn test_validate_lock_duration() {
+        assert_eq!(
+            validate_lock_duration(0).unwrap_err(),
+            LocksmithError::InvalidTimestamp.into()
+        );
+        assert_eq!(
+            validate_lock_duration(-1).unwrap_err(),
+            LocksmithError::InvalidTimestamp.into()
+        );
+        assert!(validate_lock_duration(1).is_ok());
+        assert!(validate_lock_duration(MAX_LOCK_DURATION_SECONDS).is_ok());
+        assert_eq!(
+            validate_lock_duration(MAX_LOCK_DURATION_SECONDS + 1).unwrap_err(),
+            LocksmithError::LockDurationExceeded.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_unlock_target() {
+        let max = MAX_LOCK_DURATION_SLOTS;
+        assert_eq!(
+            validate_unlock_target(100, 100, max).unwrap_err(),
+            LocksmithError::InvalidTimestamp.into()
+        );
+        assert!(validate_unlock_target(101, 100, max).is_ok());
+        assert!(validate_unlock_target(100 + max, 100, max).is_ok());
+        assert_eq!(
+            validate_unlock_target(101 + max, 100, max).unwrap_err(),
+            LocksmithError::LockDurationExceeded.into()
+        );
+        assert_eq!(
+            validate_unlock_target(600 + MAX_LOCK_DURATION_EPOCHS, 599, MAX_LOCK_DURATION_EPOCHS)
+                .unwrap_err(),
+            LocksmithError::LockDurationExceeded.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_extension() {
+        let (_, mut lock, _) = audited_lock(&crate::id());
+        lock.unlock_timestamp = 1_800_000_000;
+
+        assert!(validate_extension(&lock, 1_800_000_001).is_ok());
+        assert!(validate_extension(&lock, lock.created_at + MAX_LOCK_DURATION_SECONDS).is_ok());
+        assert_eq!(
+            validate_extension(&lock, 1_800_000_000).unwrap_err(),
+            LocksmithError::InvalidTimestamp.into()
+        );
+        assert_eq!(
+            validate_extension(&lock, lock.created_at + MAX_LOCK_DURATION_SECONDS + 1)
+                .unwrap_err(),
+            LocksmithError::LockDurationExceeded.into()
+        );
+
+        // Slot- and epoch-based locks ignore their timestamp
+        lock.unlock_slot = 1;
+        assert_eq!(
+            validate_extension(&lock, 1_800_000_001).unwrap_err(),
+            LocksmithError::InvalidTimestamp.into()
+        );
+
+        lock.unlock_slot = 0;
+        lock.permanent = true;
+        assert_eq!(
+            validate_extension(&lock, 1_800_000_001).unwrap_err(),
+            LocksmithError::LockIsPermanent.into()
+        );
+
+        lock.permanent = false;
+        lock.receipt_bound = true;
+        assert_eq!(
+            validate_extension(&lock, 1_800_000_001).unwrap_err(),
+            LocksmithError::LockBoundToReceipt.into()
+        );
+
+        lock.receipt_bound = false;
+        lock.shared = true;
+        assert_eq!(
+            validate_extension(&lock, 1_800_000_001).unwrap_err(),
+            LocksmithError::LockShared.into()
+        );
+
+        // The lender verified the lock backing its loan on its current terms
+        lock.shared = false;
+        lock.collateral_holder = Pubkey::new_unique();
+        assert_eq!(
+            validate_extension(&lock, 1_800_000_001).unwrap_err(),
+            LocksmithError::LockCollateralized.into()
+        );
+
+        // A percentage fee does not depend on the duration; a tier does
+        lock.collateral_holder = Pubkey::default();
+        lock.fee_mode = LOCK_FEE_PERCENTAGE;
+        assert!(validate_extension(&lock, 1_800_000_001).is_ok());
+        lock.fee_mode = LOCK_FEE_TIERED;
+        assert_eq!(
+            validate_extension(&lock, 1_800_000_001).unwrap_err(),
+            LocksmithError::FeeDependsOnTerms.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_top_up() {
+        let (_, mut lock, _) = audited_lock(&crate::id());
+        assert!(validate_top_up(&lock).is_ok());
+
+        // A percentage fee is charged again on the tokens added
+        lock.fee_mode = LOCK_FEE_PERCENTAGE;
+        assert!(validate_top_up(&lock).is_ok());
+        lock.fee_mode = LOCK_FEE_TIERED;
+        assert_eq!(
+            validate_top_up(&lock).unwrap_err(),
+            LocksmithError::FeeDependsOnTerms.into()
+        );
+
+        lock.fee_mode = LOCK_FEE_FLAT;
+        lock.shared = true;
+        assert_eq!(validate_top_up(&lock).unwrap_err(), LocksmithError::LockShared.into());
+        lock.shared = false;
+        lock.is_nft = true;
+        assert_eq!(validate_top_up(&lock).unwrap_err(), LocksmithError::InvalidAmount.into());
+    }
+
+    #[test]
+    fn test_validate_relock() {
+        let (_, mut lock, _) = audited_lock(&crate::id());
+        lock.unlock_timestamp = 1_800_000_000;
+        let clock = |unix_timestamp| Clock {
+            unix_timestamp,
+            ..Clock::default()
+        };
+
+        assert!(validate_relock(&lock, &clock(1_800_000_000), 1_900_000_000).is_ok());
+        assert!(validate_relock(
+            &lock,
+            &clock(1_800_000_000),
+            1_800_000_000 + MAX_LOCK_DURATION_SECONDS
+        )
+        .is_ok());
+        assert_eq!(
+            validate_relock(&lock, &clock(1_799_999_999), 1_900_000_000).unwrap_err(),
+            LocksmithError::UnlockTooEarly.into()
+        );
+        assert_eq!(
+            validate_relock(&lock, &clock(1_800_000_000), 1_800_000_000).unwrap_err(),
+            LocksmithError::InvalidTimestamp.into()
+        );
+        assert_eq!(
+            validate_relock(
+                &lock,
+                &clock(1_800_000_000),
+                1_800_000_001 + MAX_LOCK_DURATION_SECONDS
+            )
+            .unwrap_err(),
+            LocksmithError::LockDurationExceeded.into()
+        );
+
+        lock.claim_key = [1u8; 32];
+        lock.claim_expires_at = 1_850_000_000;
+        assert_eq!(
+            validate_relock(&lock, &clock(1_800_000_000), 1_900_000_000).unwrap_err(),
+            LocksmithError::ClaimCodeActive.into()
+        );
+        assert!(validate_relock(&lock, &clock(1_850_000_000), 1_900_000_000).is_ok());
+
+        lock.permanent = true;
+        assert_eq!(
+            validate_relock(&lock, &clock(1_850_000_000), 1_900_000_000).unwrap_err(),
+            LocksmithError::LockIsPermanent.into()
+        );
+
+        lock.permanent = false;
+        lock.receipt_bound = true;
+        assert_eq!(
+            validate_relock(&lock, &clock(1_850_000_000), 1_900_000_000).unwrap_err(),
+            LocksmithError::LockBoundToReceipt.into()
+        );
+
+        lock.receipt_bound = false;
+        lock.shared = true;
+        assert_eq!(
+            validate_relock(&lock, &clock(1_850_000_000), 1_900_000_000).unwrap_err(),
+            LocksmithError::LockShared.into()
+        );
+
+        // Still backing a loan, whose lender verified the current terms
+        lock.shared = false;
+        lock.collateral_holder = Pubkey::new_unique();
+        assert_eq!(
+            validate_relock(&lock, &clock(1_850_000_000), 1_900_000_000).unwrap_err(),
+            LocksmithError::LockCollateralized.into()
+        );
+
+        lock.collateral_holder = Pubkey::default();
+        lock.fee_mode = LOCK_FEE_TIERED;
+        assert_eq!(
+            validate_relock(&lock, &clock(1_850_000_000), 1_900_000_000).unwrap_err(),
+            LocksmithError::FeeDependsOnTerms.into()
+        );
+    }
+
+    #[test]
+    fn test_check_deadline() {
+        assert!(check_deadline(0, 1_700_000_000).is_ok());
+        assert!(check_deadline(1_700_000_000, 1_700_000_000).is_ok());
+        assert!(check_deadline(1_700_000_001, 1_700_000_000).is_ok());
+        assert_eq!(
+            check_deadline(1_699_999_999, 1_700_000_000).unwrap_err(),
+            LocksmithError::TransactionExpired.into()
+        );
+    }
+
+    #[test]
+    fn test_template_account_size() {
+        // discriminator(8) + template_id(8) + duration_seconds(8) + fee_usdc(8)
+        // + credential_issuer(32) + non_transferable(1) + bump(1) = 66
+        assert_eq!(TemplateAccount::SIZE, 66);
+    }
+
+    #[test]
+    fn test_forwarding_instruction_data() {
+        let destination = ForwardingDestinationAccount {
+            discriminator: ForwardingDestinationAccount::DISCRIMINATOR,
+            program: Pubkey::new_unique(),
+            instruction_prefix: [0xf2, 0x23, 0xc6, 0x89, 0x52, 0xe1, 0xf2, 0xb6],
+            prefix_len: 8,
+            bump: 255,
+        };
+        let data = forwarding_instruction_data(&destination, 1_000);
+        assert_eq!(&data[..8], &destination.instruction_prefix);
+        assert_eq!(&data[8..], &1_000u64.to_le_bytes());
+
+        // One-byte tags of native programs
+        let destination = ForwardingDestinationAccount {
+            instruction_prefix: [3, 0, 0, 0, 0, 0, 0, 0],
+            prefix_len: 1,
+            ..destination
+        };
+        assert_eq!(forwarding_instruction_data(&destination, 7), [3, 7, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_hook_instruction_data() {
+        let hook = HookAccount {
+            discriminator: HookAccount::DISCRIMINATOR,
+            program: Pubkey::new_unique(),
+            instruction_prefix: [7, 0, 0, 0, 0, 0, 0, 0],
+            prefix_len: 1,
+            events: HOOK_EVENT_CREATED | HOOK_EVENT_UNLOCKED,
+            compute_allowance: 10_000,
+            bump: 255,
+        };
+        let data = hook_instruction_data(&hook, HOOK_EVENT_UNLOCKED, 42, 1_000);
+        assert_eq!(data[..2], [7, HOOK_EVENT_UNLOCKED]);
+        assert_eq!(data[2..10], 42u64.to_le_bytes());
+        assert_eq!(data[10..], 1_000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_validate_receipt_binding() {
+        let (_, lock, _) = audited_lock(&crate::id());
+        assert!(validate_receipt_binding(&lock).is_ok());
+
+        assert_eq!(
+            validate_receipt_binding(&LockAccount {
+                collateral_holder: Pubkey::new_unique(),
+                ..lock
+            })
+            .unwrap_err(),
+            LocksmithError::LockCollateralized.into()
+        );
+        assert_eq!(
+            validate_receipt_binding(&LockAccount { shared: true, ..lock }).unwrap_err(),
+            LocksmithError::LockShared.into()
+        );
+        assert_eq!(
+            validate_receipt_binding(&LockAccount { approvals_required: true, ..lock })
+                .unwrap_err(),
+            LocksmithError::ApprovalsRequired.into()
+        );
+        // Options tying the lock to its owner
+        for bound_to_owner in [
+            LockAccount {
+                non_transferable: true,
+                ..lock
+            },
+            LockAccount {
+                credential_issuer: Pubkey::new_unique(),
+                ..lock
+            },
+            LockAccount {
+                forward_destination: Pubkey::new_unique(),
+                ..lock
+            },
+            LockAccount {
+                recovery_address: Pubkey::new_unique(),
+                ..lock
+            },
+            LockAccount {
+                fallback_owner: Pubkey::new_unique(),
+                ..lock
+            },
+            LockAccount {
+                unlock_destination: Pubkey::new_unique(),
+                ..lock
+            },
+            LockAccount {
+                counterparty: Pubkey::new_unique(),
+                ..lock
+            },
+            LockAccount {
+                claim_key: [1u8; 32],
+                ..lock
+            },
+            LockAccount {
+                early_unlock_allowed: true,
+                ..lock
+            },
+        ] {
+            assert_eq!(
+                validate_receipt_binding(&bound_to_owner).unwrap_err(),
+                LocksmithError::LockBoundToReceipt.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_receipt_metadata_instruction_data() {
+        let (_, mut lock, _) = audited_lock(&crate::id());
+        assert_eq!(
+            receipt_uri(&lock),
+            concat!(
+                r#"data:application/json,{"attributes":[{"trait_type":"amount","value":1000},"#,
+                r#"{"trait_type":"unlock","display_type":"date","value":1700086400}]}"#,
+            )
+        );
+
+        let data = receipt_metadata_instruction_data(&lock);
+        assert_eq!(data[0], CREATE_METADATA_ACCOUNT_V3);
+        assert_eq!(data[1..5], 7u32.to_le_bytes());
+        assert_eq!(&data[5..12], b"Lock #7");
+        assert_eq!(data[12..16], 4u32.to_le_bytes());
+        assert_eq!(&data[16..20], b"LOCK");
+        assert_eq!(data[20..24], (receipt_uri(&lock).len() as u32).to_le_bytes());
+        assert_eq!(data[data.len() - 7..], [0u8; 7]);
+
+        // Within Token Metadata's 32-byte name and 200-byte URI limits
+        lock.lock_id = u64::MAX;
+        lock.amount = u64::MAX;
+        lock.unlock_timestamp = i64::MIN;
+        assert!(format!("Lock #{}", lock.lock_id).len() <= 32);
+        assert!(receipt_uri(&lock).len() <= 200);
+    }
+
+    #[test]
+    fn test_resolve_lock_amount() {
+        assert_eq!(resolve_lock_amount(1_000, 5_000, 0), 1_000);
+        assert_eq!(resolve_lock_amount(LOCK_FULL_BALANCE, 5_000, 0), 5_000);
+        // Locking the fee mint from the fee account leaves the fee behind
+        assert_eq!(resolve_lock_amount(LOCK_FULL_BALANCE, 5_000, FEE_USDC), 0);
+        assert_eq!(
+            resolve_lock_amount(LOCK_FULL_BALANCE, 1_000_000, FEE_USDC),
+            1_000_000 - FEE_USDC
+        );
+        assert_eq!(resolve_lock_amount(LOCK_FULL_BALANCE, 0, 0), 0);
+    }
+
+    #[test]
+    fn test_bps_share() {
+        assert_eq!(bps_share(1_000, 250), 25);
+        assert_eq!(bps_share(1_000, 0), 0);
+        assert_eq!(bps_share(1_000, BPS_DENOMINATOR), 1_000);
+        // Rounds down
+        assert_eq!(bps_share(39, 250), 0);
+        assert_eq!(bps_share(u64::MAX, BPS_DENOMINATOR), u64::MAX);
+    }
+
+    #[test]
+    fn test_capped_bps_fee() {
+        // 0.5% of a dust position
+        assert_eq!(capped_bps_fee(1_000, 50, 1_000_000), 5);
+        // 0.5% of a large position stops at the cap
+        assert_eq!(capped_bps_fee(10_000_000_000, 50, 1_000_000), 1_000_000);
+        assert_eq!(capped_bps_fee(199, 50, 1_000_000), 0);
+        assert_eq!(capped_bps_fee(u64::MAX, MAX_FEE_BPS, u64::MAX), u64::MAX / 100);
+    }
+
+    #[test]
+    fn test_lock_fee_without_fee_accounts() {
+        let program_id = crate::id();
+        let mint = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let mut config = compliance_config();
+        config.fee_usdc = 250_000;
+        let fee = |config: &ConfigAccount, is_nft, fee_override| {
+            lock_fee(
+                &program_id,
+                &[],
+                config,
+                &mint,
+                is_nft,
+                &payer,
+                fee_override,
+                86_400,
+                1_000,
+                false,
+            )
+            .map(|fee| (fee.usdc, fee.sol.is_some(), fee.bps_cap, fee.mode))
+        };
+
+        assert_eq!(fee(&config, false, None).unwrap(), (250_000, false, None, LOCK_FEE_FLAT));
+        assert_eq!(fee(&config, false, Some(50_000)).unwrap(), (50_000, false, None, LOCK_FEE_FLAT));
+        assert_eq!(fee(&config, true, None).unwrap(), (0, false, None, LOCK_FEE_FLAT));
+
+        // Tiers need the fee schedule PDA
+        config.fee_tiered = true;
+        assert_eq!(fee(&config, false, None).unwrap_err(), ProgramError::NotEnoughAccountKeys);
+        assert_eq!(fee(&config, false, Some(50_000)).unwrap(), (50_000, false, None, LOCK_FEE_FLAT));
+        config.fee_tiered = false;
+
+        // A percentage fee needs the mint's fee cap PDA, even to find none
+        config.fee_bps = 50;
+        assert_eq!(fee(&config, false, None).unwrap_err(), ProgramError::NotEnoughAccountKeys);
+        assert_eq!(fee(&config, false, Some(50_000)).unwrap(), (50_000, false, None, LOCK_FEE_FLAT));
+        assert_eq!(fee(&config, true, None).unwrap(), (0, false, None, LOCK_FEE_FLAT));
+    }
+
+    #[test]
+    fn test_lock_fee_with_fee_cap_pda() {
+        let program_id = crate::id();
+        let mint = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let mut config = compliance_config();
+        config.fee_usdc = 250_000;
+        config.fee_bps = 50;
+        let (fee_cap_pda, bump) =
+            Pubkey::find_program_address(&[FEE_CAP_SEED, mint.as_ref()], &program_id);
+        let fee = |fee_cap_info: &AccountInfo| {
+            lock_fee(
+                &program_id,
+                std::slice::from_ref(fee_cap_info),
+                &config,
+                &mint,
+                false,
+                &payer,
+                None,
+                86_400,
+                1_000,
+                false,
+            )
+            .map(|fee| (fee.usdc, fee.bps_cap, fee.mode))
+        };
+
+        // An uncreated PDA means the mint is uncapped and pays the flat fee
+        let system_program = solana_system_interface::program::id();
+        let (mut lamports, mut data) = (0, []);
+        let uncreated = AccountInfo::new(
+            &fee_cap_pda,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &system_program,
+            false,
+        );
+        assert_eq!(fee(&uncreated).unwrap(), (250_000, None, LOCK_FEE_FLAT));
+
+        let mut data = [0u8; FeeCapAccount::SIZE];
+        FeeCapAccount {
+            discriminator: FeeCapAccount::DISCRIMINATOR,
+            mint,
+            cap: 1_000_000,
+            bump,
+        }
+        .pack(&mut data);
+        let mut lamports = 1;
+        let capped = AccountInfo::new(
+            &fee_cap_pda,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+        );
+        assert_eq!(fee(&capped).unwrap(), (0, Some(1_000_000), LOCK_FEE_PERCENTAGE));
+    }
+
+    #[test]
+    fn test_lock_batch_len() {
+        let program_id = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let lock_id = 7u64;
+        let lock_of = |owner: &Pubkey| {
+            Pubkey::find_program_address(
+                &[LOCK_SEED, owner.as_ref(), mint.as_ref(), &lock_id.to_le_bytes()],
+                &program_id,
+            )
+            .0
+        };
+        let owners = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut keys = Vec::new();
+        for owner in &owners {
+            keys.extend([*owner, lock_of(owner), Pubkey::new_unique()]);
+        }
+        assert_eq!(lock_batch_len(&program_id, &keys, &mint, lock_id), 2);
+        assert_eq!(lock_batch_len(&program_id, &keys, &mint, lock_id + 1), 0);
+
+        // Three accounts after the last lock are not mistaken for a lock
+        keys.extend([Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()]);
+        assert_eq!(lock_batch_len(&program_id, &keys, &mint, lock_id), 2);
+        assert_eq!(lock_batch_len(&program_id, &keys[..5], &mint, lock_id), 1);
+    }
+
+    #[test]
+    fn test_is_nft_mint() {
+        let nft = Mint {
+            supply: 1,
+            decimals: 0,
+            is_initialized: true,
+            ..Mint::default()
+        };
+        assert!(is_nft_mint(&nft));
+        // Fungible tokens, an edition print run or a burned NFT are not
+        assert!(!is_nft_mint(&Mint { decimals: 6, ..nft }));
+        assert!(!is_nft_mint(&Mint { supply: 10, ..nft }));
+        assert!(!is_nft_mint(&Mint { supply: 0, ..nft }));
+    }
+
+    #[test]
+    fn test_wraps_lamports() {
+        let native = spl_token::native_mint::id();
+        assert_eq!(wraps_lamports(true, &native), Ok(true));
+        assert_eq!(wraps_lamports(false, &native), Ok(false));
+        assert_eq!(wraps_lamports(false, &USDC_MINT), Ok(false));
+        // Only wSOL can be wrapped from lamports
+        assert_eq!(
+            wraps_lamports(true, &USDC_MINT),
+            Err(LocksmithError::InvalidMint.into())
+        );
+    }
+
+    #[test]
+    fn test_validate_donation() {
+        let owner = Pubkey::new_unique();
+        let donation = LockOptions {
+            donation_address: Pubkey::new_unique(),
+            donation_bps: 250,
+            ..LockOptions::default()
+        };
+        assert!(validate_donation(&donation, &owner).is_ok());
+        assert!(validate_donation(&LockOptions::default(), &owner).is_ok());
+
+        for invalid in [
+            LockOptions { donation_bps: 0, ..donation },
+            LockOptions { donation_address: Pubkey::default(), ..donation },
+            LockOptions { donation_address: owner, ..donation },
+            LockOptions { donation_bps: BPS_DENOMINATOR + 1, ..donation },
+        ] {
+            assert_eq!(
+                validate_donation(&invalid, &owner).unwrap_err(),
+                ProgramError::Custom(LocksmithError::InvalidDonation as u32)
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_fallback() {
+        let owner = Pubkey::new_unique();
+        let fallback = LockOptions {
+            fallback_owner: Pubkey::new_unique(),
+            fallback_after: 365 * 24 * 60 * 60,
+            ..LockOptions::default()
+        };
+        assert!(validate_fallback(&fallback, &owner).is_ok());
+        assert!(validate_fallback(&LockOptions { fallback_after: 0, ..fallback }, &owner).is_ok());
+        assert!(validate_fallback(&LockOptions::default(), &owner).is_ok());
+
+        for invalid in [
+            LockOptions { fallback_owner: Pubkey::default(), ..fallback },
+            LockOptions { fallback_owner: owner, ..fallback },
+            LockOptions { fallback_after: -1, ..fallback },
+        ] {
+            assert_eq!(
+                validate_fallback(&invalid, &owner).unwrap_err(),
+                ProgramError::Custom(LocksmithError::InvalidFallback as u32)
+            );
+        }
+    }
 
-    let lock_lamports = lock_account_info.lamports();
-    **lock_account_info.lamports.borrow_mut() = 0;
-    **owner_info.lamports.borrow_mut() = owner_info
-        .lamports()
-        .checked_add(lock_lamports)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    #[test]
+    fn test_validate_gift() {
+        let anyone = Pubkey::new_unique();
+        assert!(validate_gift(&LockOptions::default()).is_ok());
+        // Conditions on the owner's own unlock are the funder's to choose
+        assert!(validate_gift(&LockOptions { arbiter: anyone, ..LockOptions::default() }).is_ok());
 
-    lock_account_info.data.borrow_mut().fill(0);
+        for invalid in [
+            LockOptions { fallback_owner: anyone, ..LockOptions::default() },
+            LockOptions { recovery_address: anyone, ..LockOptions::default() },
+            LockOptions {
+                donation_address: anyone,
+                donation_bps: 250,
+                ..LockOptions::default()
+            },
+            LockOptions { claim_key: [1u8; 32], ..LockOptions::default() },
+            LockOptions { unlock_destination: anyone, ..LockOptions::default() },
+        ] {
+            assert_eq!(
+                validate_gift(&invalid).unwrap_err(),
+                ProgramError::Custom(LocksmithError::InvalidGiftOptions as u32)
+            );
+        }
+    }
 
-    msg!("Unlocked {} tokens", amount);
-    Ok(())
-}
+    #[test]
+    fn test_validate_share_split() {
+        let (_, lock, _) = audited_lock(&crate::id());
+        assert!(validate_share_split(&lock).is_ok());
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use solana_program::program_error::ProgramError;
+        for (invalid, error) in [
+            (LockAccount { shared: true, ..lock }, LocksmithError::LockShared),
+            (
+                LockAccount { approvals_required: true, ..lock },
+                LocksmithError::ApprovalsRequired,
+            ),
+            (LockAccount { permanent: true, ..lock }, LocksmithError::LockIsPermanent),
+            (
+                LockAccount { collateral_holder: Pubkey::new_unique(), ..lock },
+                LocksmithError::LockCollateralized,
+            ),
+            (LockAccount { receipt_bound: true, ..lock }, LocksmithError::LockBoundToReceipt),
+            (LockAccount { is_nft: true, ..lock }, LocksmithError::InvalidShares),
+            (LockAccount { hook: Pubkey::new_unique(), ..lock }, LocksmithError::InvalidShares),
+            (
+                LockAccount { credential_issuer: Pubkey::new_unique(), ..lock },
+                LocksmithError::InvalidShares,
+            ),
+            (LockAccount { donation_bps: 100, ..lock }, LocksmithError::InvalidShares),
+            (
+                LockAccount { unlock_destination: Pubkey::new_unique(), ..lock },
+                LocksmithError::InvalidShares,
+            ),
+            (LockAccount { price_feed_id: [1u8; 32], ..lock }, LocksmithError::InvalidShares),
+            (LockAccount { arbiter: Pubkey::new_unique(), ..lock }, LocksmithError::InvalidShares),
+            (
+                LockAccount { counterparty: Pubkey::new_unique(), ..lock },
+                LocksmithError::InvalidShares,
+            ),
+            (LockAccount { claim_key: [1u8; 32], ..lock }, LocksmithError::InvalidShares),
+        ] {
+            assert_eq!(validate_share_split(&invalid).unwrap_err(), error.into());
+        }
+    }
 
     #[test]
-    fn test_process_instruction_empty_data() {
-        let program_id = Pubkey::new_unique();
-        let accounts: Vec<AccountInfo> = vec![];
-        let instruction_data: [u8; 0] = [];
+    fn test_validate_shares() {
+        let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut beneficiaries = [Pubkey::default(); MAX_BENEFICIARIES];
+        beneficiaries[0] = alice;
+        beneficiaries[1] = bob;
+        let mut share_bps = [0u16; MAX_BENEFICIARIES];
+        share_bps[0] = 6_000;
+        share_bps[1] = 4_000;
+        assert!(validate_shares(&beneficiaries, &share_bps).is_ok());
 
-        let result = process_instruction(&program_id, &accounts, &instruction_data);
+        let invalid = |beneficiaries: [Pubkey; MAX_BENEFICIARIES], share_bps| {
+            assert_eq!(
+                validate_shares(&beneficiaries, &share_bps).unwrap_err(),
+                ProgramError::Custom(LocksmithError::InvalidShares as u32)
+            );
+        };
+        // Shares not summing to 10,000 bps
+        let mut short = share_bps;
+        short[1] = 3_999;
+        invalid(beneficiaries, short);
+        invalid([Pubkey::default(); MAX_BENEFICIARIES], [0; MAX_BENEFICIARIES]);
+        // A share without a beneficiary, and a beneficiary without a share
+        let mut unassigned = share_bps;
+        unassigned[1] = 3_000;
+        unassigned[2] = 1_000;
+        invalid(beneficiaries, unassigned);
+        let mut unpaid = beneficiaries;
+        unpaid[2] = Pubkey::new_unique();
+        invalid(unpaid, share_bps);
+        // The same beneficiary twice
+        let mut duplicated = beneficiaries;
+        duplicated[1] = alice;
+        invalid(duplicated, share_bps);
+    }
+
+    #[test]
+    fn test_validate_approval_requirement() {
+        let (_, lock, _) = audited_lock(&crate::id());
+        assert!(validate_approval_requirement(&lock).is_ok());
+
+        for (invalid, error) in [
+            (
+                LockAccount { approvals_required: true, ..lock },
+                LocksmithError::ApprovalsRequired,
+            ),
+            (LockAccount { permanent: true, ..lock }, LocksmithError::LockIsPermanent),
+            (LockAccount { receipt_bound: true, ..lock }, LocksmithError::LockBoundToReceipt),
+            (LockAccount { shared: true, ..lock }, LocksmithError::LockShared),
+            // Ways around the owner's unlock
+            (
+                LockAccount { recovery_address: Pubkey::new_unique(), ..lock },
+                LocksmithError::InvalidApprovers,
+            ),
+            (
+                LockAccount { fallback_owner: Pubkey::new_unique(), ..lock },
+                LocksmithError::InvalidApprovers,
+            ),
+            (LockAccount { claim_key: [1u8; 32], ..lock }, LocksmithError::InvalidApprovers),
+            (
+                LockAccount { early_unlock_allowed: true, ..lock },
+                LocksmithError::InvalidApprovers,
+            ),
+        ] {
+            assert_eq!(validate_approval_requirement(&invalid).unwrap_err(), error.into());
+        }
+    }
+
+    #[test]
+    fn test_validate_approvers() {
+        let (alice, bob, carol) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut approvers = [Pubkey::default(); MAX_APPROVERS];
+        approvers[0] = alice;
+        approvers[1] = bob;
+        approvers[3] = carol;
+        assert!(validate_approvers(&approvers, 1).is_ok());
+        assert!(validate_approvers(&approvers, 3).is_ok());
+
+        let invalid = |approvers: [Pubkey; MAX_APPROVERS], threshold| {
+            assert_eq!(
+                validate_approvers(&approvers, threshold).unwrap_err(),
+                ProgramError::Custom(LocksmithError::InvalidApprovers as u32)
+            );
+        };
+        // A threshold of zero, or beyond the number of approvers
+        invalid(approvers, 0);
+        invalid(approvers, 4);
+        invalid([Pubkey::default(); MAX_APPROVERS], 1);
+        // The same approver twice
+        let mut duplicated = approvers;
+        duplicated[1] = alice;
+        invalid(duplicated, 2);
+    }
+
+    #[test]
+    fn test_validate_unlock_destination() {
+        let committed = LockOptions {
+            unlock_destination: Pubkey::new_unique(),
+            ..LockOptions::default()
+        };
+        assert!(validate_unlock_destination(&committed).is_ok());
+        assert!(validate_unlock_destination(&LockOptions::default()).is_ok());
+        assert!(validate_unlock_destination(&LockOptions {
+            recovery_address: Pubkey::new_unique(),
+            ..LockOptions::default()
+        })
+        .is_ok());
+
+        for invalid in [
+            LockOptions { recovery_address: Pubkey::new_unique(), ..committed },
+            LockOptions { fallback_owner: Pubkey::new_unique(), ..committed },
+            LockOptions { donation_address: Pubkey::new_unique(), ..committed },
+            LockOptions { claim_key: [1u8; 32], ..committed },
+            LockOptions { early_unlock_allowed: true, ..committed },
+            LockOptions { wrap_lamports: true, ..committed },
+        ] {
+            assert_eq!(
+                validate_unlock_destination(&invalid).unwrap_err(),
+                ProgramError::Custom(LocksmithError::InvalidUnlockDestination as u32)
+            );
+        }
+
+        let (_, lock, _) = audited_lock(&crate::id());
+        let destination = Pubkey::new_unique();
+        assert!(check_unlock_destination(&lock, &destination).is_ok());
+        let lock = LockAccount { unlock_destination: destination, ..lock };
+        assert!(check_unlock_destination(&lock, &destination).is_ok());
         assert_eq!(
-            result.unwrap_err(),
-            ProgramError::Custom(LocksmithError::InvalidInstruction as u32)
+            check_unlock_destination(&lock, &Pubkey::new_unique()).unwrap_err(),
+            ProgramError::Custom(LocksmithError::InvalidUnlockDestination as u32)
         );
     }
 
     #[test]
-    fn test_process_instruction_invalid_tag() {
-        let program_id = Pubkey::new_unique();
-        let accounts: Vec<AccountInfo> = vec![];
-        let instruction_data = [255u8];
-
-        let result = process_instruction(&program_id, &accounts, &instruction_data);
+    fn test_stray_token_destination() {
+        let (_, lock, _) = audited_lock(&crate::id());
         assert_eq!(
-            result.unwrap_err(),
-            ProgramError::Custom(LocksmithError::InvalidInstruction as u32)
+            stray_token_destination(&lock),
+            associated_token_address(&lock.owner, &lock.mint)
         );
+        let destination = Pubkey::new_unique();
+        let lock = LockAccount { unlock_destination: destination, ..lock };
+        assert_eq!(stray_token_destination(&lock), destination);
     }
 
     #[test]
-    fn test_lock_pda_isolation_by_lock_id() {
-        let program_id = crate::id();
+    fn test_validate_price_condition() {
+        let conditional = LockOptions {
+            price_feed_id: [7u8; 32],
+            price_threshold: 150,
+            price_exponent: -2,
+            ..LockOptions::default()
+        };
+        assert!(validate_price_condition(&conditional).is_ok());
+        assert!(validate_price_condition(&LockOptions::default()).is_ok());
+
+        for invalid in [
+            // A threshold without a feed
+            LockOptions { price_feed_id: [0u8; 32], ..conditional },
+            LockOptions { price_threshold: 0, ..conditional },
+            LockOptions { price_exponent: MAX_PRICE_EXPONENT + 1, ..conditional },
+            LockOptions { price_exponent: -MAX_PRICE_EXPONENT - 1, ..conditional },
+            // Releases that would skip the condition
+            LockOptions { permanent: true, ..conditional },
+            LockOptions { claim_key: [1u8; 32], ..conditional },
+            LockOptions { recovery_address: Pubkey::new_unique(), ..conditional },
+            LockOptions { early_unlock_allowed: true, ..conditional },
+        ] {
+            assert_eq!(
+                validate_price_condition(&invalid).unwrap_err(),
+                ProgramError::Custom(LocksmithError::InvalidPriceCondition as u32)
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_arbiter() {
         let owner = Pubkey::new_unique();
-        let mint = Pubkey::new_unique();
+        let arbitrated = LockOptions {
+            arbiter: Pubkey::new_unique(),
+            ..LockOptions::default()
+        };
+        assert!(validate_arbiter(&arbitrated, &owner).is_ok());
+        assert!(validate_arbiter(&LockOptions::default(), &owner).is_ok());
 
-        let (pda_0, _) = Pubkey::find_program_address(
-            &[LOCK_SEED, owner.as_ref(), mint.as_ref(), &0u64.to_le_bytes()],
-            &program_id,
-        );
-        let (pda_1, _) = Pubkey::find_program_address(
-            &[LOCK_SEED, owner.as_ref(), mint.as_ref(), &1u64.to_le_bytes()],
-            &program_id,
-        );
+        for invalid in [
+            LockOptions { arbiter: owner, ..arbitrated },
+            // Releases that would skip the arbiter
+            LockOptions { permanent: true, ..arbitrated },
+            LockOptions { claim_key: [1u8; 32], ..arbitrated },
+            LockOptions { recovery_address: Pubkey::new_unique(), ..arbitrated },
+            LockOptions { early_unlock_allowed: true, ..arbitrated },
+        ] {
+            assert_eq!(
+                validate_arbiter(&invalid, &owner).unwrap_err(),
+                ProgramError::Custom(LocksmithError::InvalidArbiter as u32)
+            );
+        }
+    }
 
-        assert_ne!(pda_0, pda_1);
+    #[test]
+    fn test_mint_allowed() {
+        let policy = |allowed| MintPolicyAccount {
+            discriminator: MintPolicyAccount::DISCRIMINATOR,
+            mint: Pubkey::new_unique(),
+            allowed,
+            bump: 255,
+        };
+
+        assert!(mint_allowed(MINT_POLICY_NONE, None));
+        assert!(mint_allowed(MINT_POLICY_NONE, Some(&policy(false))));
+
+        assert!(!mint_allowed(MINT_POLICY_ALLOWLIST, None));
+        assert!(!mint_allowed(MINT_POLICY_ALLOWLIST, Some(&policy(false))));
+        assert!(mint_allowed(MINT_POLICY_ALLOWLIST, Some(&policy(true))));
+
+        assert!(mint_allowed(MINT_POLICY_DENYLIST, None));
+        assert!(mint_allowed(MINT_POLICY_DENYLIST, Some(&policy(true))));
+        assert!(!mint_allowed(MINT_POLICY_DENYLIST, Some(&policy(false))));
     }
 
     #[test]
-    fn test_lock_pda_isolation_by_owner() {
+    fn test_validate_counterparty() {
+        let owner = Pubkey::new_unique();
+        let escrow = LockOptions {
+            counterparty: Pubkey::new_unique(),
+            credential_issuer: Pubkey::new_unique(),
+            fallback_owner: Pubkey::new_unique(),
+            ..LockOptions::default()
+        };
+        assert!(validate_counterparty(&escrow, &owner).is_ok());
+        assert!(validate_counterparty(&LockOptions::default(), &owner).is_ok());
+
+        for invalid in [
+            LockOptions { counterparty: owner, ..escrow },
+            LockOptions { permanent: true, ..escrow },
+            LockOptions { recovery_address: Pubkey::new_unique(), ..escrow },
+            LockOptions { donation_address: Pubkey::new_unique(), ..escrow },
+            LockOptions { unlock_destination: Pubkey::new_unique(), ..escrow },
+            LockOptions { claim_key: [1u8; 32], ..escrow },
+            LockOptions { early_unlock_allowed: true, ..escrow },
+            LockOptions { price_feed_id: [1u8; 32], ..escrow },
+            LockOptions { arbiter: Pubkey::new_unique(), ..escrow },
+        ] {
+            assert_eq!(
+                validate_counterparty(&invalid, &owner).unwrap_err(),
+                ProgramError::Custom(LocksmithError::InvalidCounterparty as u32)
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_price_condition() {
+        let (_, lock, _) = audited_lock(&crate::id());
+        // At least $1.50 on feed [7; 32]
+        let lock = LockAccount {
+            price_feed_id: [7u8; 32],
+            price_threshold: 150,
+            price_exponent: -2,
+            ..lock
+        };
+        let now = 1_700_000_000;
+        let update = |feed_id: [u8; 32], price: i64, conf: u64, publish_time: i64| {
+            let mut data = vec![0u8; 134];
+            data[0..8].copy_from_slice(&PythPriceUpdate::DISCRIMINATOR);
+            data[40] = 1;
+            data[41..73].copy_from_slice(&feed_id);
+            data[73..81].copy_from_slice(&price.to_le_bytes());
+            data[81..89].copy_from_slice(&conf.to_le_bytes());
+            data[89..93].copy_from_slice(&(-8i32).to_le_bytes());
+            data[93..101].copy_from_slice(&publish_time.to_le_bytes());
+            data
+        };
+        let check = |owner: &Pubkey, data: Vec<u8>| check_price_condition(&lock, owner, &data, now);
+        let pyth = PYTH_RECEIVER_PROGRAM_ID;
+
+        assert!(check(&pyth, update([7u8; 32], 150_000_000, 100_000, now)).is_ok());
+        assert!(check(&pyth, update([7u8; 32], 200_000_000, 100_000, now - MAX_PRICE_AGE_SECONDS))
+            .is_ok());
+
+        for (owner, data, error) in [
+            (
+                Pubkey::new_unique(),
+                update([7u8; 32], 150_000_000, 100_000, now),
+                LocksmithError::InvalidPriceFeed,
+            ),
+            (pyth, update([8u8; 32], 150_000_000, 100_000, now), LocksmithError::InvalidPriceFeed),
+            (
+                pyth,
+                update([7u8; 32], 150_000_000, 100_000, now - MAX_PRICE_AGE_SECONDS - 1),
+                LocksmithError::PriceUnreliable,
+            ),
+            // A confidence interval of over 2%
+            (pyth, update([7u8; 32], 150_000_000, 3_100_000, now), LocksmithError::PriceUnreliable),
+            (
+                pyth,
+                update([7u8; 32], 149_999_999, 100_000, now),
+                LocksmithError::PriceConditionNotMet,
+            ),
+        ] {
+            assert_eq!(check(&owner, data).unwrap_err(), error.into());
+        }
+    }
+
+    #[test]
+    fn test_validate_claim_code() {
+        let claim = LockOptions {
+            claim_key: [1u8; 32],
+            claim_expires_at: 2_000,
+            ..LockOptions::default()
+        };
+        assert!(validate_claim_code(&claim, 1_000, 3_000).is_ok());
+
+        for (options, unlock_timestamp) in [
+            (claim, 2_000),
+            (LockOptions { claim_expires_at: 4_000, ..claim }, 1_000),
+            (LockOptions { credential_issuer: Pubkey::new_unique(), ..claim }, 1_000),
+            (LockOptions { hook: Pubkey::new_unique(), ..claim }, 1_000),
+            (LockOptions { recovery_address: Pubkey::new_unique(), ..claim }, 1_000),
+            (LockOptions { fallback_owner: Pubkey::new_unique(), ..claim }, 1_000),
+            (LockOptions { donation_address: Pubkey::new_unique(), ..claim }, 1_000),
+            (LockOptions { non_transferable: true, ..claim }, 1_000),
+        ] {
+            assert_eq!(
+                validate_claim_code(&options, unlock_timestamp, 3_000).unwrap_err(),
+                ProgramError::Custom(LocksmithError::InvalidClaimCode as u32)
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_permanent() {
+        let permanent = LockOptions {
+            permanent: true,
+            ..LockOptions::default()
+        };
+        assert!(validate_permanent(&permanent).is_ok());
+        assert!(validate_permanent(&LockOptions {
+            permanent: false,
+            early_unlock_allowed: true,
+            ..permanent
+        })
+        .is_ok());
+
+        for options in [
+            LockOptions { unlock_slot: 1, ..permanent },
+            LockOptions { unlock_epoch: 1, ..permanent },
+            LockOptions { claim_key: [1u8; 32], ..permanent },
+            LockOptions { recovery_address: Pubkey::new_unique(), ..permanent },
+            LockOptions { fallback_owner: Pubkey::new_unique(), ..permanent },
+            LockOptions { donation_address: Pubkey::new_unique(), ..permanent },
+            LockOptions { early_unlock_allowed: true, ..permanent },
+        ] {
+            assert_eq!(
+                validate_permanent(&options).unwrap_err(),
+                ProgramError::Custom(LocksmithError::LockIsPermanent as u32)
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_claim() {
         let program_id = crate::id();
-        let owner_1 = Pubkey::new_unique();
-        let owner_2 = Pubkey::new_unique();
-        let mint = Pubkey::new_unique();
-        let lock_id = 1u64.to_le_bytes();
+        let (lock_key, mut lock, _) = audited_lock(&program_id);
+        let clock = |unix_timestamp| Clock {
+            unix_timestamp,
+            ..Clock::default()
+        };
+        let invalid = ProgramError::Custom(LocksmithError::InvalidClaimCode as u32);
 
-        let (pda_1, _) = Pubkey::find_program_address(
-            &[LOCK_SEED, owner_1.as_ref(), mint.as_ref(), &lock_id],
-            &program_id,
-        );
-        let (pda_2, _) = Pubkey::find_program_address(
-            &[LOCK_SEED, owner_2.as_ref(), mint.as_ref(), &lock_id],
-            &program_id,
+        // Locks without a claim code cannot be claimed
+        assert_eq!(check_claim(&lock, &clock(1_800_000_000)).unwrap_err(), invalid);
+
+        lock.claim_key = [3u8; 32];
+        lock.claim_expires_at = 1_800_000_000;
+        assert!(check_claim(&lock, &clock(1_700_086_400)).is_ok());
+        assert_eq!(
+            check_claim(&lock, &clock(1_700_086_399)).unwrap_err(),
+            ProgramError::Custom(LocksmithError::UnlockTooEarly as u32)
         );
+        assert_eq!(check_claim(&lock, &clock(1_800_000_000)).unwrap_err(), invalid);
 
-        assert_ne!(pda_1, pda_2);
+        // The claim key's signature names the lock and the destination
+        let claim_key = Pubkey::new_from_array(lock.claim_key);
+        let destination = Pubkey::new_unique();
+        let message = claim_lock_message(&program_id, &lock_key, &destination);
+        let data = ed25519_instruction_data(&claim_key, &message);
+        assert!(ed25519_instruction_verifies(&data, &claim_key, &message));
+        for (lock_key, destination) in [
+            (lock_key, Pubkey::new_unique()),
+            (Pubkey::new_unique(), destination),
+        ] {
+            let message = claim_lock_message(&program_id, &lock_key, &destination);
+            assert!(!ed25519_instruction_verifies(&data, &claim_key, &message));
+        }
+
+        lock.frozen = true;
+        assert_eq!(
+            check_claim(&lock, &clock(1_700_086_400)).unwrap_err(),
+            ProgramError::Custom(LocksmithError::LockFrozen as u32)
+        );
     }
 
     #[test]
-    fn test_lock_pda_isolation_by_mint() {
+    fn test_check_session() {
         let program_id = crate::id();
-        let owner = Pubkey::new_unique();
-        let mint_1 = Pubkey::new_unique();
-        let mint_2 = Pubkey::new_unique();
-        let lock_id = 1u64.to_le_bytes();
+        let (_, mut lock, _) = audited_lock(&program_id);
+        let destination = Pubkey::new_unique();
+        let mut session = SessionAccount {
+            discriminator: SessionAccount::DISCRIMINATOR,
+            owner: lock.owner,
+            session_key: Pubkey::new_unique(),
+            mint: Pubkey::default(),
+            destination,
+            expires_at: 1_800_000_000,
+            bump: 255,
+        };
+        let invalid = ProgramError::Custom(LocksmithError::InvalidSession as u32);
 
-        let (pda_1, _) = Pubkey::find_program_address(
-            &[LOCK_SEED, owner.as_ref(), mint_1.as_ref(), &lock_id],
-            &program_id,
+        assert!(check_session(&session, &lock, &destination, 1_799_999_999).is_ok());
+        assert_eq!(
+            check_session(&session, &lock, &destination, 1_800_000_000).unwrap_err(),
+            invalid
         );
-        let (pda_2, _) = Pubkey::find_program_address(
-            &[LOCK_SEED, owner.as_ref(), mint_2.as_ref(), &lock_id],
-            &program_id,
+        assert_eq!(
+            check_session(&session, &lock, &Pubkey::new_unique(), 1_700_000_000).unwrap_err(),
+            invalid
         );
 
-        assert_ne!(pda_1, pda_2);
+        // A mint-scoped session only unlocks that mint
+        session.mint = lock.mint;
+        assert!(check_session(&session, &lock, &destination, 1_700_000_000).is_ok());
+        session.mint = Pubkey::new_unique();
+        assert_eq!(
+            check_session(&session, &lock, &destination, 1_700_000_000).unwrap_err(),
+            invalid
+        );
+        session.mint = Pubkey::default();
+
+        session.owner = Pubkey::new_unique();
+        assert_eq!(
+            check_session(&session, &lock, &destination, 1_700_000_000).unwrap_err(),
+            invalid
+        );
+        session.owner = lock.owner;
+
+        lock.forward_destination = Pubkey::new_unique();
+        assert_eq!(
+            check_session(&session, &lock, &destination, 1_700_000_000).unwrap_err(),
+            invalid
+        );
+    }
+
+    /// ed25519 program instruction data in the layout clients produce:
+    /// offsets, then public key, signature and message.
+    fn ed25519_instruction_data(signer: &Pubkey, message: &[u8]) -> Vec<u8> {
+        let offsets: [u16; 7] = [48, u16::MAX, 16, u16::MAX, 112, message.len() as u16, u16::MAX];
+        let mut data = vec![1u8, 0];
+        for offset in offsets {
+            data.extend_from_slice(&offset.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0u8; 64]);
+        data.extend_from_slice(message);
+        data
     }
 
     #[test]
-    fn test_lock_token_pda_isolation() {
-        let program_id = crate::id();
-        let lock_1 = Pubkey::new_unique();
-        let lock_2 = Pubkey::new_unique();
+    fn test_ed25519_instruction_verifies() {
+        let owner = Pubkey::new_unique();
+        let message = b"locksmith-relayed-lock-v1 test".to_vec();
+        let data = ed25519_instruction_data(&owner, &message);
 
-        let (token_pda_1, _) =
-            Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_1.as_ref()], &program_id);
-        let (token_pda_2, _) =
-            Pubkey::find_program_address(&[LOCK_TOKEN_SEED, lock_2.as_ref()], &program_id);
+        assert!(ed25519_instruction_verifies(&data, &owner, &message));
+        assert!(!ed25519_instruction_verifies(&data, &Pubkey::new_unique(), &message));
+        assert!(!ed25519_instruction_verifies(&data, &owner, b"other message"));
+        assert!(!ed25519_instruction_verifies(&data[..15], &owner, &message));
 
-        assert_ne!(token_pda_1, token_pda_2);
+        // Key, signature or message taken from another instruction
+        for field in [1, 3, 6] {
+            let mut elsewhere = data.clone();
+            elsewhere[2 + 2 * field..4 + 2 * field].copy_from_slice(&0u16.to_le_bytes());
+            assert!(!ed25519_instruction_verifies(&elsewhere, &owner, &message));
+        }
+
+        let mut two_signatures = data.clone();
+        two_signatures[0] = 2;
+        assert!(!ed25519_instruction_verifies(&two_signatures, &owner, &message));
+
+        // Offsets past the end of the data
+        let mut truncated = data;
+        truncated.pop();
+        assert!(!ed25519_instruction_verifies(&truncated, &owner, &message));
     }
 
     #[test]
-    fn test_usdc_mint_matches_mainnet() {
+    fn test_standing_order_account_size() {
+        // discriminator(8) + owner(32) + mint(32) + owner_token_account(32)
+        // + owner_usdc_account(32) + order_id(8) + amount_per_lock(8)
+        // + interval_seconds(8) + lock_duration_seconds(8) + next_execution_at(8)
+        // + next_lock_id(8) + remaining_executions(4) + bump(1)
+        // + fee_usdc(8) = 197
+        assert_eq!(StandingOrderAccount::SIZE, 197);
+        assert_eq!(StandingOrderAccount::LEGACY_SIZE, 189);
+    }
+
+    #[test]
+    fn test_lock_rent_covers_lock_and_escrow() {
+        let rent = Rent::default();
         assert_eq!(
-            USDC_MINT.to_string(),
-            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+            lock_rent(&rent),
+            rent.minimum_balance(LockAccount::SIZE) + rent.minimum_balance(TokenAccount::LEN)
+        );
+    }
+
+    fn audited_lock(program_id: &Pubkey) -> (Pubkey, LockAccount, TokenAccount) {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let lock_id = 7u64;
+        let (lock_key, bump) = Pubkey::find_program_address(
+            &[LOCK_SEED, owner.as_ref(), mint.as_ref(), &lock_id.to_le_bytes()],
+            program_id,
         );
+        let lock = LockAccount {
+            discriminator: LockAccount::DISCRIMINATOR,
+            owner,
+            mint,
+            amount: 1_000,
+            unlock_timestamp: 1_700_086_400,
+            created_at: 1_700_000_000,
+            lock_id,
+            bump,
+            frozen: false,
+            credential_issuer: Pubkey::default(),
+            non_transferable: false,
+            forward_destination: Pubkey::default(),
+            hook: Pubkey::default(),
+            recovery_address: Pubkey::default(),
+            donation_address: Pubkey::default(),
+            donation_bps: 0,
+            collateral_holder: Pubkey::default(),
+            layout_version: 0,
+            unlock_slot: 0,
+            unlock_epoch: 0,
+            claim_key: [0u8; 32],
+            claim_expires_at: 0,
+            index_seq: 0,
+            early_unlock_allowed: false,
+            permanent: false,
+            is_nft: false,
+            receipt_bound: false,
+            label: [0u8; 32],
+            metadata_uri: [0u8; METADATA_URI_LEN],
+            fallback_owner: Pubkey::default(),
+            fallback_after: 0,
+            unlock_destination: Pubkey::default(),
+            shared: false,
+            approvals_required: false,
+            price_feed_id: [0u8; 32],
+            price_threshold: 0,
+            price_exponent: 0,
+            arbiter: Pubkey::default(),
+            milestone_approved: false,
+            counterparty: Pubkey::default(),
+            owner_counted: false,
+            wrapped_lamports: false,
+            receipt_mint: Pubkey::default(),
+            fee_mode: LOCK_FEE_FLAT,
+            reserved: [0u8; 29],
+        };
+        let escrow = TokenAccount {
+            mint,
+            owner: lock_key,
+            amount: 1_000,
+            state: spl_token::state::AccountState::Initialized,
+            ..TokenAccount::default()
+        };
+        (lock_key, lock, escrow)
     }
 
     #[test]
-    fn test_program_id_matches_declared() {
+    fn test_audit_lock_consistent() {
+        let program_id = crate::id();
+        let (lock_key, lock, escrow) = audited_lock(&program_id);
         assert_eq!(
-            crate::id().to_string(),
-            "A5vz72a5ipKUJZxmGUjGtS7uhWfzr6jhDgV2q73YhD8A"
+            audit_lock(&program_id, &lock_key, &lock, Some(&escrow)),
+            AuditStatus::Consistent
         );
     }
 
     #[test]
-    fn test_fee_usdc_is_015_usdc() {
-        // 0.15 USDC with 6 decimals = 150,000
-        assert_eq!(FEE_USDC, 150_000);
+    fn test_audit_lock_detects_pda_mismatch() {
+        let program_id = crate::id();
+        let (_, lock, escrow) = audited_lock(&program_id);
+        let other_key = Pubkey::new_unique();
+        assert_eq!(
+            audit_lock(&program_id, &other_key, &lock, Some(&escrow)),
+            AuditStatus::LockPdaMismatch
+        );
     }
 
     #[test]
-    fn test_config_account_size() {
-        // discriminator(8) + admin(32) + bump(1) = 41
-        assert_eq!(ConfigAccount::SIZE, 41);
+    fn test_audit_lock_detects_invalid_timestamps() {
+        let program_id = crate::id();
+        let (lock_key, mut lock, escrow) = audited_lock(&program_id);
+
+        lock.unlock_timestamp = lock.created_at;
+        assert_eq!(
+            audit_lock(&program_id, &lock_key, &lock, Some(&escrow)),
+            AuditStatus::InvalidTimestamps
+        );
+
+        lock.unlock_timestamp = lock.created_at + MAX_LOCK_DURATION_SECONDS + 1;
+        assert_eq!(
+            audit_lock(&program_id, &lock_key, &lock, Some(&escrow)),
+            AuditStatus::InvalidTimestamps
+        );
+
+        // Permanent locks are exempt from the maximum duration
+        lock.permanent = true;
+        assert_eq!(
+            audit_lock(&program_id, &lock_key, &lock, Some(&escrow)),
+            AuditStatus::Consistent
+        );
     }
 
     #[test]
-    fn test_lock_account_size() {
-        // discriminator(8) + owner(32) + mint(32) + amount(8) + unlock_timestamp(8)
-        // + created_at(8) + lock_id(8) + bump(1) = 105
-        assert_eq!(LockAccount::SIZE, 105);
+    fn test_audit_lock_detects_escrow_problems() {
+        let program_id = crate::id();
+        let (lock_key, lock, escrow) = audited_lock(&program_id);
+
+        assert_eq!(
+            audit_lock(&program_id, &lock_key, &lock, None),
+            AuditStatus::EscrowMissing
+        );
+
+        let wrong_owner = TokenAccount {
+            owner: Pubkey::new_unique(),
+            ..escrow
+        };
+        assert_eq!(
+            audit_lock(&program_id, &lock_key, &lock, Some(&wrong_owner)),
+            AuditStatus::EscrowMismatch
+        );
+
+        let wrong_mint = TokenAccount {
+            mint: Pubkey::new_unique(),
+            ..escrow
+        };
+        assert_eq!(
+            audit_lock(&program_id, &lock_key, &lock, Some(&wrong_mint)),
+            AuditStatus::EscrowMismatch
+        );
+
+        let short = TokenAccount { amount: 999, ..escrow };
+        assert_eq!(
+            audit_lock(&program_id, &lock_key, &lock, Some(&short)),
+            AuditStatus::BalanceMismatch
+        );
+    }
+
+    fn compliance_config() -> ConfigAccount {
+        ConfigAccount {
+            discriminator: ConfigAccount::DISCRIMINATOR,
+            admin: Pubkey::new_unique(),
+            bump: 255,
+            compliance_authority: Pubkey::new_unique(),
+            pending_compliance_authority: Pubkey::new_unique(),
+            compliance_authority_effective_at: 1_000,
+            fee_mint: Pubkey::default(),
+            pending_fee_mint: Pubkey::default(),
+            fee_mint_effective_at: 0,
+            successor_program: Pubkey::default(),
+            sunset_at: 0,
+            early_unlock_penalty_bps: 0,
+            batch_fee_per_lock: false,
+            paused: false,
+            mint_policy_mode: MINT_POLICY_NONE,
+            reject_freezable_mints: false,
+            fee_usdc: FEE_USDC,
+            fee_bps: 0,
+            fee_lamports: 0,
+            fee_tiered: false,
+            max_locks_per_owner: 0,
+        }
     }
 
     #[test]
-    fn test_max_lock_duration_is_10_years() {
-        // 10 years in seconds = 10 * 365 * 24 * 60 * 60
-        let expected_seconds: i64 = 10 * 365 * 24 * 60 * 60;
-        assert_eq!(MAX_LOCK_DURATION_SECONDS, expected_seconds);
-        // Verify it's approximately 315,360,000 seconds (10 years without leap years)
-        assert_eq!(MAX_LOCK_DURATION_SECONDS, 315_360_000);
+    fn test_authorize_freeze_tracks_compliance_authority() {
+        let config = compliance_config();
+        let unauthorized = ProgramError::Custom(LocksmithError::Unauthorized as u32);
+
+        assert!(authorize_freeze(&config, &config.compliance_authority, 999).is_ok());
+        assert_eq!(
+            authorize_freeze(&config, &config.pending_compliance_authority, 999).unwrap_err(),
+            unauthorized
+        );
+
+        // Once the timelock elapses the appointee replaces the old authority
+        let appointee = config.pending_compliance_authority;
+        assert!(authorize_freeze(&config, &appointee, 1_000).is_ok());
+        assert_eq!(
+            authorize_freeze(&config, &config.compliance_authority, 1_000).unwrap_err(),
+            unauthorized
+        );
+
+        assert_eq!(
+            authorize_freeze(&config, &Pubkey::new_unique(), 999).unwrap_err(),
+            unauthorized
+        );
     }
 
     #[test]
-    fn test_max_lock_duration_arithmetic_safety() {
-        // Ensure adding MAX_LOCK_DURATION_SECONDS to a reasonable timestamp doesn't overflow
-        let current_timestamp: i64 = 1_700_000_000; // ~2023
-        let result = current_timestamp.checked_add(MAX_LOCK_DURATION_SECONDS);
-        assert!(result.is_some());
-        // Result should be around 2033
-        assert!(result.unwrap() > current_timestamp);
+    fn test_authorize_freeze_allows_admin() {
+        let mut config = compliance_config();
+        assert!(authorize_freeze(&config, &config.admin, 999).is_ok());
+
+        // The admin needs no compliance authority, and locks stay
+        // recoverable after it is removed
+        config.compliance_authority = Pubkey::default();
+        config.pending_compliance_authority = Pubkey::default();
+        assert!(authorize_freeze(&config, &config.admin, 999).is_ok());
     }
 
     #[test]
-    fn test_system_program_check_id_validates_correctly() {
-        // Test that the system program ID is recognized
-        let system_program_id = solana_system_interface::program::id();
-        assert!(solana_system_interface::program::check_id(&system_program_id));
+    fn test_authorize_collateral_change() {
+        let program_id = crate::id();
+        let (_, mut lock, _) = audited_lock(&program_id);
+        let owner = lock.owner;
+        let lender = Pubkey::new_unique();
 
-        // Test that a random key is not recognized as system program
-        let random_key = Pubkey::new_unique();
-        assert!(!solana_system_interface::program::check_id(&random_key));
+        assert!(authorize_collateral_change(&lock, &owner, &lender, true).is_ok());
+        assert_eq!(
+            authorize_collateral_change(&lock, &Pubkey::new_unique(), &lender, true).unwrap_err(),
+            ProgramError::Custom(LocksmithError::Unauthorized as u32)
+        );
+        // Nothing to release yet
+        assert_eq!(
+            authorize_collateral_change(&lock, &owner, &lender, false).unwrap_err(),
+            ProgramError::Custom(LocksmithError::Unauthorized as u32)
+        );
+
+        lock.frozen = true;
+        assert_eq!(
+            authorize_collateral_change(&lock, &owner, &lender, true).unwrap_err(),
+            ProgramError::Custom(LocksmithError::LockFrozen as u32)
+        );
+        lock.frozen = false;
+
+        lock.claim_key = [1u8; 32];
+        assert_eq!(
+            authorize_collateral_change(&lock, &owner, &lender, true).unwrap_err(),
+            ProgramError::Custom(LocksmithError::InvalidClaimCode as u32)
+        );
+        lock.claim_key = [0u8; 32];
+
+        lock.receipt_bound = true;
+        assert_eq!(
+            authorize_collateral_change(&lock, &owner, &lender, true).unwrap_err(),
+            ProgramError::Custom(LocksmithError::LockBoundToReceipt as u32)
+        );
+        lock.receipt_bound = false;
+
+        lock.shared = true;
+        assert_eq!(
+            authorize_collateral_change(&lock, &owner, &lender, true).unwrap_err(),
+            ProgramError::Custom(LocksmithError::LockShared as u32)
+        );
+        lock.shared = false;
+
+        lock.collateral_holder = lender;
+        assert_eq!(
+            authorize_collateral_change(&lock, &owner, &Pubkey::new_unique(), true).unwrap_err(),
+            ProgramError::Custom(LocksmithError::LockCollateralized as u32)
+        );
+        // Only the holder releases, not the owner or another lender
+        assert!(authorize_collateral_change(&lock, &owner, &lender, false).is_ok());
+        assert_eq!(
+            authorize_collateral_change(&lock, &owner, &owner, false).unwrap_err(),
+            ProgramError::Custom(LocksmithError::Unauthorized as u32)
+        );
     }
 
     #[test]
-    fn test_lock_duration_exceeded_error_code() {
-        // Ensure the new error code is correct
-        assert_eq!(LocksmithError::LockDurationExceeded as u32, 11);
+    fn test_lock_frozen_error_code() {
+        assert_eq!(LocksmithError::LockFrozen as u32, 12);
+    }
+
+    #[test]
+    fn test_verify_credential() {
+        let issuer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (credential, _) =
+            Pubkey::find_program_address(&[CREDENTIAL_SEED, owner.as_ref()], &issuer);
+        let invalid = ProgramError::Custom(LocksmithError::InvalidCredential as u32);
+
+        assert!(verify_credential(&credential, &issuer, false, &issuer, &owner).is_ok());
+
+        // Another wallet's credential
+        let (other, _) = Pubkey::find_program_address(
+            &[CREDENTIAL_SEED, Pubkey::new_unique().as_ref()],
+            &issuer,
+        );
+        assert_eq!(
+            verify_credential(&other, &issuer, false, &issuer, &owner).unwrap_err(),
+            invalid
+        );
+
+        // Right address but not (or no longer) created by the issuer
+        let system = solana_system_interface::program::id();
+        assert_eq!(
+            verify_credential(&credential, &system, true, &issuer, &owner).unwrap_err(),
+            invalid
+        );
+        assert_eq!(
+            verify_credential(&credential, &issuer, true, &issuer, &owner).unwrap_err(),
+            invalid
+        );
     }
 }
@@ -1,5 +1,5 @@
 use shank::ShankAccount;
-use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::error::LocksmithError;
 
@@ -8,18 +8,177 @@ pub const CONFIG_SEED: &[u8] = b"config";
 pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
 pub const LOCK_SEED: &[u8] = b"lock";
 pub const LOCK_TOKEN_SEED: &[u8] = b"lock_token";
+/// Seed of credential PDAs under a credential issuer program:
+/// ["credential", owner]
+pub const CREDENTIAL_SEED: &[u8] = b"credential";
+pub const TEMPLATE_SEED: &[u8] = b"template";
+pub const STANDING_ORDER_SEED: &[u8] = b"standing_order";
+pub const FORWARDING_SEED: &[u8] = b"forwarding";
+pub const HOOK_SEED: &[u8] = b"hook";
+pub const LENDER_SEED: &[u8] = b"lender";
+pub const SESSION_SEED: &[u8] = b"session";
+pub const NOTE_SEED: &[u8] = b"note";
+pub const MINT_INDEX_SEED: &[u8] = b"mint_index";
+pub const CERTIFICATE_SEED: &[u8] = b"certificate";
+pub const VESTING_SEED: &[u8] = b"vesting";
+pub const PENALTY_VAULT_SEED: &[u8] = b"penalty_vault";
+pub const SHARES_SEED: &[u8] = b"shares";
+pub const APPROVALS_SEED: &[u8] = b"approvals";
+pub const VOTE_WEIGHT_SEED: &[u8] = b"vote_weight";
+pub const MINT_POLICY_SEED: &[u8] = b"mint_policy";
+pub const LOCK_PROOF_SEED: &[u8] = b"lock_proof";
+pub const FEE_EXEMPTION_SEED: &[u8] = b"fee_exemption";
+pub const FEE_SCHEDULE_SEED: &[u8] = b"fee_schedule";
+pub const FEE_CAP_SEED: &[u8] = b"fee_cap";
+pub const OWNER_COUNTER_SEED: &[u8] = b"owner_counter";
+/// Seed of a mint's vault ["token_fee_vault", mint] collecting percentage
+/// fees in that mint
+pub const TOKEN_FEE_VAULT_SEED: &[u8] = b"token_fee_vault";
+/// Seed of the system-owned PDA ["sol_fee_vault"] collecting fees paid in
+/// lamports
+pub const SOL_FEE_VAULT_SEED: &[u8] = b"sol_fee_vault";
+/// Seed of a lock's receipt mint ["receipt", lock] and, alone, of the PDA
+/// that is every receipt's mint, freeze and metadata update authority
+pub const RECEIPT_SEED: &[u8] = b"receipt";
+/// Seed of the delegate PDA ["relay", owner] owners approve on their token
+/// accounts to have locks created from their signed messages
+pub const RELAY_SEED: &[u8] = b"relay";
+
+/// Domain prefix of the message owners sign for `InitializeRelayedLock`
+pub const RELAYED_LOCK_DOMAIN: &[u8] = b"locksmith-relayed-lock-v1";
+
+/// Domain prefix of the message a claim key signs for `ClaimLock`
+pub const CLAIM_LOCK_DOMAIN: &[u8] = b"locksmith-claim-lock-v1";
 
 /// USDC mint address (mainnet)
 pub const USDC_MINT: Pubkey =
     solana_program::pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
 
-/// Fee amount: 0.15 USDC (USDC has 6 decimals)
+/// SPL Associated Token Account program, whose PDAs are each wallet's
+/// canonical token account for a mint
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// Metaplex Token Metadata program, which holds lock receipts' metadata
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Default fee amount: 0.15 USDC (USDC has 6 decimals), charged until
+/// `UpdateFeeConfig` sets another
 pub const FEE_USDC: u64 = 150_000;
 
+/// Largest fee `UpdateFeeConfig` accepts: 10 USDC
+pub const MAX_FEE_USDC: u64 = 10_000_000;
+
+/// Largest fee in lamports `SetSolFee` accepts: 1 SOL
+pub const MAX_FEE_LAMPORTS: u64 = 1_000_000_000;
+
+/// Lock amount meaning "the source token account's whole balance", resolved
+/// when the lock is created
+pub const LOCK_FULL_BALANCE: u64 = u64::MAX;
+
+/// Decimals a fee mint must have for `FEE_USDC` to keep its value
+pub const FEE_MINT_DECIMALS: u8 = 6;
+
 /// Maximum lock duration: 10 years in seconds
-/// This prevents accidental permanent locks while supporting all legitimate use cases
+/// This prevents accidental permanent locks while supporting all legitimate use cases;
+/// deliberate ones set `LockOptions::permanent`
 pub const MAX_LOCK_DURATION_SECONDS: i64 = 10 * 365 * 24 * 60 * 60;
 
+/// Target slot time in milliseconds, converting slots into time
+pub const TARGET_MS_PER_SLOT: u64 = 400;
+
+/// Slots per epoch, converting epochs into time
+pub const SLOTS_PER_EPOCH: u64 = 432_000;
+
+/// Maximum lock duration of slot-based locks: 10 years at the 400ms target
+/// slot time
+pub const MAX_LOCK_DURATION_SLOTS: u64 =
+    MAX_LOCK_DURATION_SECONDS as u64 * 1000 / TARGET_MS_PER_SLOT;
+
+/// Maximum lock duration of epoch-based locks: 10 years of 432,000-slot
+/// epochs
+pub const MAX_LOCK_DURATION_EPOCHS: u64 = MAX_LOCK_DURATION_SLOTS / SLOTS_PER_EPOCH;
+
+/// Delay before a newly appointed compliance authority can act: 48 hours.
+/// Gives lock owners time to see the appointment on-chain and react.
+pub const COMPLIANCE_TIMELOCK_SECONDS: i64 = 48 * 60 * 60;
+
+/// Delay before a proposed fee mint can be migrated to: 7 days. Gives
+/// integrators time to start holding the new mint before the old one is
+/// refused.
+pub const FEE_MINT_TIMELOCK_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Time after a lock's unlock timestamp before its tokens count as
+/// abandoned and may be swept to its recovery address: 5 years
+pub const ABANDONMENT_PERIOD_SECONDS: i64 = 5 * 365 * 24 * 60 * 60;
+
+/// Lamports of a lock account's rent that `CrankUnlock` pays whoever
+/// cranks it (0.0001 SOL); the owner gets the rest
+pub const CRANK_TIP_LAMPORTS: u64 = 100_000;
+
+/// Basis points in 100%, the scale of `LockAccount::donation_bps`
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+/// Upper bound on `ConfigAccount::early_unlock_penalty_bps`: 50%. Locks
+/// opting into `EmergencyUnlock` know at worst half is forfeited.
+pub const MAX_EARLY_UNLOCK_PENALTY_BPS: u16 = 5_000;
+
+/// Upper bound on `ConfigAccount::fee_bps`: 1%
+pub const MAX_FEE_BPS: u16 = 100;
+
+/// Upper bound on the size of a lock's encrypted note
+pub const MAX_NOTE_LEN: usize = 256;
+
+/// Size of a lock's metadata URI, zero-padded
+pub const METADATA_URI_LEN: usize = 128;
+
+/// Most beneficiaries a lock can be split among
+pub const MAX_BENEFICIARIES: usize = 8;
+
+/// Most approvers `SetUnlockApprovers` can designate for a lock
+pub const MAX_APPROVERS: usize = 8;
+
+/// Maximum tiers in the fee schedule
+pub const MAX_FEE_TIERS: usize = 8;
+
+/// Pyth receiver program, which owns the verified price updates
+/// price-conditional locks are unlocked against
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LyC6");
+
+/// Oldest price update a price-conditional unlock accepts: 60 seconds
+pub const MAX_PRICE_AGE_SECONDS: i64 = 60;
+
+/// Widest confidence interval a price-conditional unlock accepts, relative
+/// to the price: 2%
+pub const MAX_PRICE_CONFIDENCE_BPS: u16 = 200;
+
+/// Largest decimal exponent, either way, of a lock's price threshold
+pub const MAX_PRICE_EXPONENT: i32 = 18;
+
+/// Upper bound on a hook's compute allowance. Keeps a misbehaving hook from
+/// eating the compute budget of the lock or unlock that triggered it.
+pub const MAX_HOOK_COMPUTE_UNITS: u32 = 50_000;
+
+/// `HookAccount::events` bits
+pub const HOOK_EVENT_CREATED: u8 = 1 << 0;
+pub const HOOK_EVENT_UNLOCKED: u8 = 1 << 1;
+
+/// `ConfigAccount::mint_policy_mode` values
+pub const MINT_POLICY_NONE: u8 = 0;
+/// Only mints whose `MintPolicyAccount` allows them can be locked
+pub const MINT_POLICY_ALLOWLIST: u8 = 1;
+/// Any mint can be locked unless its `MintPolicyAccount` denies it
+pub const MINT_POLICY_DENYLIST: u8 = 2;
+
+/// `LockAccount::fee_mode` values
+pub const LOCK_FEE_FLAT: u8 = 0;
+/// A percentage of the deposit was taken, so the fee grows with the amount
+pub const LOCK_FEE_PERCENTAGE: u8 = 1;
+/// A fee schedule tier was charged, priced on the amount and duration
+pub const LOCK_FEE_TIERED: u8 = 2;
+
 /// Config account - stores admin and program state.
 /// PDA seeds: ["config"]
 #[derive(Debug, PartialEq, ShankAccount)]
@@ -30,14 +189,101 @@ pub struct ConfigAccount {
     pub admin: Pubkey,
     /// PDA bump seed
     pub bump: u8,
+    /// Wallet allowed to freeze and unfreeze locks (default pubkey = none)
+    pub compliance_authority: Pubkey,
+    /// Appointed compliance authority waiting out the timelock (default pubkey = none)
+    pub pending_compliance_authority: Pubkey,
+    /// Unix timestamp from which `pending_compliance_authority` may act
+    pub compliance_authority_effective_at: i64,
+    /// Mint fees are charged in (default pubkey = `USDC_MINT`, for configs
+    /// created before fee mint migration)
+    pub fee_mint: Pubkey,
+    /// Proposed fee mint waiting out the timelock (default pubkey = none)
+    pub pending_fee_mint: Pubkey,
+    /// Unix timestamp from which the admin may migrate to `pending_fee_mint`
+    pub fee_mint_effective_at: i64,
+    /// Program locks may be migrated to with `MigrateLock` (default pubkey =
+    /// none)
+    pub successor_program: Pubkey,
+    /// Unix timestamp the program was sunset at (0 = still creating locks)
+    pub sunset_at: i64,
+    /// Share of a lock's tokens `EmergencyUnlock` sends to the mint's
+    /// penalty vault, in basis points
+    pub early_unlock_penalty_bps: u16,
+    /// Charge `InitializeLockBatch` the fee for every lock rather than once
+    /// per batch
+    pub batch_fee_per_lock: bool,
+    /// Whether `SetPaused` has stopped lock creation until further notice
+    pub paused: bool,
+    /// Which mints can be locked (`MINT_POLICY_NONE`, `MINT_POLICY_ALLOWLIST`
+    /// or `MINT_POLICY_DENYLIST`)
+    pub mint_policy_mode: u8,
+    /// Refuse to lock mints that still have a freeze authority
+    pub reject_freezable_mints: bool,
+    /// Fee charged per lock, in the fee mint's smallest units
+    pub fee_usdc: u64,
+    /// Share of each lock's tokens charged instead of `fee_usdc`, in basis
+    /// points, for mints with a `FeeCapAccount` (0 = flat fee)
+    pub fee_bps: u16,
+    /// Lamports charged in place of the flat fee to lockers paying in SOL
+    /// (0 = fees cannot be paid in SOL)
+    pub fee_lamports: u64,
+    /// Whether the fee schedule's tiers set the flat fee
+    pub fee_tiered: bool,
+    /// Most unclosed locks an owner may hold, unless their counter sets
+    /// their own cap (0 = no cap)
+    pub max_locks_per_owner: u32,
 }
 
 impl ConfigAccount {
     pub const DISCRIMINATOR: [u8; 8] = *b"CONFIG\0\0";
-    pub const SIZE: usize = 8 + 32 + 1;
+    pub const SIZE: usize = Self::FEE_SCHEDULE_SIZE + 4;
+    /// Size of configs created before the compliance fields were added.
+    /// They read as having no compliance authority and are grown on first
+    /// write of those fields.
+    pub const LEGACY_SIZE: usize = 8 + 32 + 1;
+    /// Size of configs created before the fee mint fields were added. They
+    /// read as charging USDC with no migration pending.
+    pub const COMPLIANCE_SIZE: usize = Self::LEGACY_SIZE + 32 + 32 + 8;
+    /// Size of configs created before the successor program was added. They
+    /// read as declaring no successor.
+    pub const FEE_MINT_SIZE: usize = Self::COMPLIANCE_SIZE + 32 + 32 + 8;
+    /// Size of configs created before sunset was added. They read as not
+    /// sunset.
+    pub const SUCCESSOR_SIZE: usize = Self::FEE_MINT_SIZE + 32;
+    /// Size of configs created before the early unlock penalty was added.
+    /// They read as charging no penalty.
+    pub const SUNSET_SIZE: usize = Self::SUCCESSOR_SIZE + 8;
+    /// Size of configs created before the batch fee mode was added. They
+    /// read as charging one fee per batch.
+    pub const PENALTY_SIZE: usize = Self::SUNSET_SIZE + 2;
+    /// Size of configs created before the pause flag was added. They read
+    /// as not paused.
+    pub const BATCH_FEE_SIZE: usize = Self::PENALTY_SIZE + 1;
+    /// Size of configs created before the mint policy mode was added. They
+    /// read as accepting any mint.
+    pub const PAUSE_SIZE: usize = Self::BATCH_FEE_SIZE + 1;
+    /// Size of configs created before freezable mints could be rejected.
+    /// They read as accepting them.
+    pub const MINT_POLICY_SIZE: usize = Self::PAUSE_SIZE + 1;
+    /// Size of configs created before the fee was configurable. They read
+    /// as charging `FEE_USDC`.
+    pub const FREEZABLE_SIZE: usize = Self::MINT_POLICY_SIZE + 1;
+    /// Size of configs created before the percentage fee was added. They
+    /// read as charging the flat fee.
+    pub const FEE_SIZE: usize = Self::FREEZABLE_SIZE + 8;
+    /// Size of configs created before fees could be paid in SOL. They read
+    /// as not accepting SOL.
+    pub const BPS_FEE_SIZE: usize = Self::FEE_SIZE + 2;
+    /// Size of configs created before the fee schedule was added. They read
+    /// as charging one flat fee.
+    pub const SOL_FEE_SIZE: usize = Self::BPS_FEE_SIZE + 8;
+    /// Size of configs created before the per-owner lock cap was added.
+    /// They read as not capping locks.
+    pub const FEE_SCHEDULE_SIZE: usize = Self::SOL_FEE_SIZE + 1;
 
     pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
-        if data.len() < Self::SIZE {
+        if data.len() < Self::LEGACY_SIZE {
             return Err(LocksmithError::UninitializedAccount.into());
         }
         let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
@@ -46,17 +292,154 @@ impl ConfigAccount {
         }
         let admin = Pubkey::try_from(&data[8..40]).unwrap();
         let bump = data[40];
-        Ok(Self {
+        let mut config = Self {
             discriminator,
             admin,
             bump,
-        })
+            compliance_authority: Pubkey::default(),
+            pending_compliance_authority: Pubkey::default(),
+            compliance_authority_effective_at: 0,
+            fee_mint: Pubkey::default(),
+            pending_fee_mint: Pubkey::default(),
+            fee_mint_effective_at: 0,
+            successor_program: Pubkey::default(),
+            sunset_at: 0,
+            early_unlock_penalty_bps: 0,
+            batch_fee_per_lock: false,
+            paused: false,
+            mint_policy_mode: MINT_POLICY_NONE,
+            reject_freezable_mints: false,
+            fee_usdc: FEE_USDC,
+            fee_bps: 0,
+            fee_lamports: 0,
+            fee_tiered: false,
+            max_locks_per_owner: 0,
+        };
+        if data.len() >= Self::COMPLIANCE_SIZE {
+            config.compliance_authority = Pubkey::try_from(&data[41..73]).unwrap();
+            config.pending_compliance_authority = Pubkey::try_from(&data[73..105]).unwrap();
+            config.compliance_authority_effective_at =
+                i64::from_le_bytes(data[105..113].try_into().unwrap());
+        }
+        if data.len() >= Self::FEE_MINT_SIZE {
+            config.fee_mint = Pubkey::try_from(&data[113..145]).unwrap();
+            config.pending_fee_mint = Pubkey::try_from(&data[145..177]).unwrap();
+            config.fee_mint_effective_at = i64::from_le_bytes(data[177..185].try_into().unwrap());
+        }
+        if data.len() >= Self::SUCCESSOR_SIZE {
+            config.successor_program = Pubkey::try_from(&data[185..217]).unwrap();
+        }
+        if data.len() >= Self::SUNSET_SIZE {
+            config.sunset_at = i64::from_le_bytes(data[217..225].try_into().unwrap());
+        }
+        if data.len() >= Self::PENALTY_SIZE {
+            config.early_unlock_penalty_bps =
+                u16::from_le_bytes(data[225..227].try_into().unwrap());
+        }
+        if data.len() >= Self::BATCH_FEE_SIZE {
+            config.batch_fee_per_lock = data[227] != 0;
+        }
+        if data.len() >= Self::PAUSE_SIZE {
+            config.paused = data[228] != 0;
+        }
+        if data.len() >= Self::MINT_POLICY_SIZE {
+            config.mint_policy_mode = data[229];
+        }
+        if data.len() >= Self::FREEZABLE_SIZE {
+            config.reject_freezable_mints = data[230] != 0;
+        }
+        if data.len() >= Self::FEE_SIZE {
+            config.fee_usdc = u64::from_le_bytes(data[231..239].try_into().unwrap());
+        }
+        if data.len() >= Self::BPS_FEE_SIZE {
+            config.fee_bps = u16::from_le_bytes(data[239..241].try_into().unwrap());
+        }
+        if data.len() >= Self::SOL_FEE_SIZE {
+            config.fee_lamports = u64::from_le_bytes(data[241..249].try_into().unwrap());
+        }
+        if data.len() >= Self::FEE_SCHEDULE_SIZE {
+            config.fee_tiered = data[249] != 0;
+        }
+        if data.len() >= Self::SIZE {
+            config.max_locks_per_owner = u32::from_le_bytes(data[250..254].try_into().unwrap());
+        }
+        Ok(config)
     }
 
+    /// Packs into `dst`. Appended fields are only written if `dst` has room
+    /// for them, so older configs must be grown before setting them.
     pub fn pack(&self, dst: &mut [u8]) {
         dst[0..8].copy_from_slice(&self.discriminator);
         dst[8..40].copy_from_slice(self.admin.as_ref());
         dst[40] = self.bump;
+        if dst.len() >= Self::COMPLIANCE_SIZE {
+            dst[41..73].copy_from_slice(self.compliance_authority.as_ref());
+            dst[73..105].copy_from_slice(self.pending_compliance_authority.as_ref());
+            dst[105..113].copy_from_slice(&self.compliance_authority_effective_at.to_le_bytes());
+        }
+        if dst.len() >= Self::FEE_MINT_SIZE {
+            dst[113..145].copy_from_slice(self.fee_mint.as_ref());
+            dst[145..177].copy_from_slice(self.pending_fee_mint.as_ref());
+            dst[177..185].copy_from_slice(&self.fee_mint_effective_at.to_le_bytes());
+        }
+        if dst.len() >= Self::SUCCESSOR_SIZE {
+            dst[185..217].copy_from_slice(self.successor_program.as_ref());
+        }
+        if dst.len() >= Self::SUNSET_SIZE {
+            dst[217..225].copy_from_slice(&self.sunset_at.to_le_bytes());
+        }
+        if dst.len() >= Self::PENALTY_SIZE {
+            dst[225..227].copy_from_slice(&self.early_unlock_penalty_bps.to_le_bytes());
+        }
+        if dst.len() >= Self::BATCH_FEE_SIZE {
+            dst[227] = self.batch_fee_per_lock as u8;
+        }
+        if dst.len() >= Self::PAUSE_SIZE {
+            dst[228] = self.paused as u8;
+        }
+        if dst.len() >= Self::MINT_POLICY_SIZE {
+            dst[229] = self.mint_policy_mode;
+        }
+        if dst.len() >= Self::FREEZABLE_SIZE {
+            dst[230] = self.reject_freezable_mints as u8;
+        }
+        if dst.len() >= Self::FEE_SIZE {
+            dst[231..239].copy_from_slice(&self.fee_usdc.to_le_bytes());
+        }
+        if dst.len() >= Self::BPS_FEE_SIZE {
+            dst[239..241].copy_from_slice(&self.fee_bps.to_le_bytes());
+        }
+        if dst.len() >= Self::SOL_FEE_SIZE {
+            dst[241..249].copy_from_slice(&self.fee_lamports.to_le_bytes());
+        }
+        if dst.len() >= Self::FEE_SCHEDULE_SIZE {
+            dst[249] = self.fee_tiered as u8;
+        }
+        if dst.len() >= Self::SIZE {
+            dst[250..254].copy_from_slice(&self.max_locks_per_owner.to_le_bytes());
+        }
+    }
+
+    /// Mint fees are currently charged in
+    pub fn current_fee_mint(&self) -> Pubkey {
+        if self.fee_mint == Pubkey::default() {
+            USDC_MINT
+        } else {
+            self.fee_mint
+        }
+    }
+
+    /// Compliance authority able to act at `now`, if any. A pending
+    /// appointment takes over once its timelock has elapsed.
+    pub fn compliance_authority_at(&self, now: i64) -> Option<Pubkey> {
+        let authority = if self.pending_compliance_authority != Pubkey::default()
+            && now >= self.compliance_authority_effective_at
+        {
+            self.pending_compliance_authority
+        } else {
+            self.compliance_authority
+        };
+        (authority != Pubkey::default()).then_some(authority)
     }
 }
 
@@ -80,20 +463,155 @@ pub struct LockAccount {
     pub lock_id: u64,
     /// PDA bump seed
     pub bump: u8,
+    /// Set by the admin or compliance authority; a frozen lock cannot be
+    /// unlocked
+    pub frozen: bool,
+    /// Program whose credential PDA the owner must present to unlock
+    /// (default pubkey = not gated)
+    pub credential_issuer: Pubkey,
+    /// Set at creation and never cleared; any instruction that moves the
+    /// lock to a new owner must reject it
+    pub non_transferable: bool,
+    /// Admin-approved `ForwardingDestinationAccount` that unlocked tokens
+    /// are deposited through (default pubkey = paid out to the owner)
+    pub forward_destination: Pubkey,
+    /// Admin-registered `HookAccount` notified of this lock's creation and
+    /// unlock (default pubkey = no hook)
+    pub hook: Pubkey,
+    /// Wallet that may receive the tokens once they have gone unclaimed for
+    /// `ABANDONMENT_PERIOD_SECONDS` after unlock (default pubkey = never)
+    pub recovery_address: Pubkey,
+    /// Wallet receiving `donation_bps` of the tokens at unlock (default
+    /// pubkey = no donation)
+    pub donation_address: Pubkey,
+    /// Share of the tokens donated at unlock, in basis points
+    pub donation_bps: u16,
+    /// Approved lender authority the lock currently backs a loan for; while
+    /// set the lock cannot be unlocked, swept or redirected (default pubkey
+    /// = not collateralized)
+    pub collateral_holder: Pubkey,
+    /// Layout the lock was created with: `LAYOUT_VERSION` for new locks, 0
+    /// for locks created before the version byte (including grown ones)
+    pub layout_version: u8,
+    /// Slot at which the lock unlocks instead of `unlock_timestamp`, which
+    /// is then only an estimate (0 = timestamp-based)
+    pub unlock_slot: u64,
+    /// Epoch at whose start the lock unlocks instead of `unlock_timestamp`,
+    /// which is then only an estimate (0 = timestamp-based)
+    pub unlock_epoch: u64,
+    /// Public key of the ed25519 key pair whose seed is a secret claim
+    /// code; whoever holds the code may claim the tokens with `ClaimLock`
+    /// (all zero = no claim code)
+    pub claim_key: [u8; 32],
+    /// Unix timestamp from which a claim-code lock can no longer be claimed
+    /// and its owner may unlock it instead
+    pub claim_expires_at: i64,
+    /// Sequence number of the lock's `IndexEntryAccount` in its mint's
+    /// index (0 = not indexed)
+    pub index_seq: u32,
+    /// Set at creation; the owner may release the lock before it unlocks
+    /// with `EmergencyUnlock`, forfeiting the configured penalty
+    pub early_unlock_allowed: bool,
+    /// Set at creation; the lock never unlocks and its tokens can never
+    /// leave escrow
+    pub permanent: bool,
+    /// Set at creation when the mint had no decimals and a supply of one;
+    /// the lock then holds that single token
+    pub is_nft: bool,
+    /// Set by `MintLockReceipt` for a transferable receipt; from then on
+    /// whoever holds the receipt may unlock, and the owner may not. The
+    /// last byte of the block new fields were carved out of.
+    pub receipt_bound: bool,
+    /// Owner-chosen name telling the lock apart from the owner's others,
+    /// UTF-8 padded with zeroes (all zero = unlabelled)
+    pub label: [u8; 32],
+    /// Set at creation and never changed; URI of a JSON document describing
+    /// the lock's terms, UTF-8 padded with zeroes (all zero = none)
+    pub metadata_uri: [u8; 128],
+    /// Wallet that may claim the tokens with `ClaimAsFallback` once the
+    /// owner has left them unlocked for `fallback_after` seconds, e.g. in
+    /// case the owner's key is lost (default pubkey = none)
+    pub fallback_owner: Pubkey,
+    /// Seconds after `unlock_timestamp` from which `fallback_owner` may
+    /// claim
+    pub fallback_after: i64,
+    /// Set at creation; the only token account the lock may ever pay out to
+    /// (default pubkey = any account of the owner)
+    pub unlock_destination: Pubkey,
+    /// Set by `SetLockShares`; from then on the lock's beneficiaries claim
+    /// their shares with `ClaimShare`, and the owner may not unlock
+    pub shared: bool,
+    /// Set by `SetUnlockApprovers`; from then on the lock unlocks only with
+    /// `ExecuteUnlock`, once enough of its approvers have approved
+    pub approvals_required: bool,
+    /// Set at creation; Pyth feed whose price must be at least
+    /// `price_threshold` × 10^`price_exponent` for the lock to unlock
+    /// (zeroes = no price condition)
+    pub price_feed_id: [u8; 32],
+    /// Minimum price, scaled by 10^`price_exponent`
+    pub price_threshold: i64,
+    /// Decimal exponent of `price_threshold`
+    pub price_exponent: i32,
+    /// Set at creation; reviewer whose `ApproveMilestone` the lock needs to
+    /// unlock (default pubkey = none)
+    pub arbiter: Pubkey,
+    /// Whether the arbiter has approved the lock's milestone; cleared by
+    /// `Relock`
+    pub milestone_approved: bool,
+    /// Set at creation; party an escrow lock is released to with
+    /// `ReleaseEscrow` (default pubkey = not an escrow)
+    pub counterparty: Pubkey,
+    /// Set at creation if the lock counts towards its owner's lock cap, in
+    /// which case closing it takes it off the owner's counter
+    pub owner_counted: bool,
+    /// Set at creation if the lock was funded with lamports wrapped into
+    /// its escrow, which unlocking pays back out as lamports
+    pub wrapped_lamports: bool,
+    /// Mint of the receipt `MintLockReceipt` issued for the lock (default
+    /// pubkey = none)
+    pub receipt_mint: Pubkey,
+    /// Set at creation; how the lock's fee was priced (`LOCK_FEE_*`), which
+    /// decides whether the lock can be topped up or extended
+    pub fee_mode: u8,
+    /// Always zero; new fields are carved out of it so that locks of
+    /// `SIZE` need not be grown to hold them
+    pub reserved: [u8; 29],
 }
 
 impl LockAccount {
     pub const DISCRIMINATOR: [u8; 8] = *b"LOCK\0\0\0\0";
-    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 1;
+    pub const SIZE: usize =
+        8 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1 + 32 + 1 + 32 + 32 + 32 + 32 + 2 + 32 + 1 + 8 + 8 + 32 + 8 + 4 + 1 + 1 + 1 + 1 + 32 + METADATA_URI_LEN + 32 + 8 + 32 + 1 + 1 + 32 + 8 + 4 + 32 + 1 + 32 + 1 + 1 + 32 + 1 + 29;
+    /// Written to `layout_version` by lock creation, and by growing a lock
+    /// to `SIZE`
+    pub const LAYOUT_VERSION: u8 = 2;
+    /// Size of locks at layout version 1, whose reserved block ended with
+    /// `receipt_bound`
+    pub const V1_SIZE: usize = 366;
+    /// Size of locks created before fields were appended. Appended fields
+    /// missing from a shorter lock read as zero (not frozen, not gated,
+    /// transferable);
+    /// the lock is grown when one of them is first set.
+    pub const LEGACY_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 1;
 
     pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
-        if data.len() < Self::SIZE {
+        if data.len() < Self::LEGACY_SIZE {
             return Err(LocksmithError::UninitializedAccount.into());
         }
         let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
         if discriminator != Self::DISCRIMINATOR {
             return Err(LocksmithError::UninitializedAccount.into());
         }
+        // The layout version says which fields the lock holds: version 1
+        // ends at `V1_SIZE`, the current one at `SIZE`, and version 0 at
+        // whichever appended field the lock was last grown to hold
+        let layout_version = data.get(301).copied().unwrap_or(0);
+        let data = match layout_version {
+            0 => data,
+            1 => &data[..data.len().min(Self::V1_SIZE)],
+            Self::LAYOUT_VERSION if data.len() >= Self::SIZE => data,
+            _ => return Err(LocksmithError::InconsistentState.into()),
+        };
         let owner = Pubkey::try_from(&data[8..40]).unwrap();
         let mint = Pubkey::try_from(&data[40..72]).unwrap();
         let amount = u64::from_le_bytes(data[72..80].try_into().unwrap());
@@ -101,6 +619,90 @@ impl LockAccount {
         let created_at = i64::from_le_bytes(data[88..96].try_into().unwrap());
         let lock_id = u64::from_le_bytes(data[96..104].try_into().unwrap());
         let bump = data[104];
+        let frozen = data.get(105).is_some_and(|&b| b != 0);
+        let credential_issuer = data
+            .get(106..138)
+            .map_or(Pubkey::default(), |b| Pubkey::try_from(b).unwrap());
+        let non_transferable = data.get(138).is_some_and(|&b| b != 0);
+        let forward_destination = data
+            .get(139..171)
+            .map_or(Pubkey::default(), |b| Pubkey::try_from(b).unwrap());
+        let hook = data
+            .get(171..203)
+            .map_or(Pubkey::default(), |b| Pubkey::try_from(b).unwrap());
+        let recovery_address = data
+            .get(203..235)
+            .map_or(Pubkey::default(), |b| Pubkey::try_from(b).unwrap());
+        let donation_address = data
+            .get(235..267)
+            .map_or(Pubkey::default(), |b| Pubkey::try_from(b).unwrap());
+        let donation_bps = data
+            .get(267..269)
+            .map_or(0, |b| u16::from_le_bytes(b.try_into().unwrap()));
+        let collateral_holder = data
+            .get(269..301)
+            .map_or(Pubkey::default(), |b| Pubkey::try_from(b).unwrap());
+        let unlock_slot = data
+            .get(302..310)
+            .map_or(0, |b| u64::from_le_bytes(b.try_into().unwrap()));
+        let unlock_epoch = data
+            .get(310..318)
+            .map_or(0, |b| u64::from_le_bytes(b.try_into().unwrap()));
+        let claim_key = data
+            .get(318..350)
+            .map_or([0u8; 32], |b| b.try_into().unwrap());
+        let claim_expires_at = data
+            .get(350..358)
+            .map_or(0, |b| i64::from_le_bytes(b.try_into().unwrap()));
+        let index_seq = data
+            .get(358..362)
+            .map_or(0, |b| u32::from_le_bytes(b.try_into().unwrap()));
+        let early_unlock_allowed = data.get(362).is_some_and(|&b| b != 0);
+        let permanent = data.get(363).is_some_and(|&b| b != 0);
+        let is_nft = data.get(364).is_some_and(|&b| b != 0);
+        let receipt_bound = data.get(365).is_some_and(|&b| b != 0);
+        let label = data
+            .get(366..398)
+            .map_or([0u8; 32], |b| b.try_into().unwrap());
+        let metadata_uri = data
+            .get(398..526)
+            .map_or([0u8; METADATA_URI_LEN], |b| b.try_into().unwrap());
+        let fallback_owner = data
+            .get(526..558)
+            .map_or(Pubkey::default(), |b| Pubkey::try_from(b).unwrap());
+        let fallback_after = data
+            .get(558..566)
+            .map_or(0, |b| i64::from_le_bytes(b.try_into().unwrap()));
+        let unlock_destination = data
+            .get(566..598)
+            .map_or(Pubkey::default(), |b| Pubkey::try_from(b).unwrap());
+        let shared = data.get(598).is_some_and(|&b| b != 0);
+        let approvals_required = data.get(599).is_some_and(|&b| b != 0);
+        let price_feed_id = data
+            .get(600..632)
+            .map_or([0u8; 32], |b| b.try_into().unwrap());
+        let price_threshold = data
+            .get(632..640)
+            .map_or(0, |b| i64::from_le_bytes(b.try_into().unwrap()));
+        let price_exponent = data
+            .get(640..644)
+            .map_or(0, |b| i32::from_le_bytes(b.try_into().unwrap()));
+        let arbiter = data
+            .get(644..676)
+            .map_or(Pubkey::default(), |b| Pubkey::try_from(b).unwrap());
+        let milestone_approved = data.get(676).is_some_and(|&b| b != 0);
+        let counterparty = data
+            .get(677..709)
+            .map_or(Pubkey::default(), |b| Pubkey::try_from(b).unwrap());
+        let owner_counted = data.get(709).is_some_and(|&b| b != 0);
+        let wrapped_lamports = data.get(710).is_some_and(|&b| b != 0);
+        let receipt_mint = data
+            .get(711..743)
+            .map_or(Pubkey::default(), |b| Pubkey::try_from(b).unwrap());
+        let fee_mode = data.get(743).copied().unwrap_or(LOCK_FEE_FLAT);
+        let reserved = data
+            .get(744..773)
+            .map_or([0u8; 29], |b| b.try_into().unwrap());
         Ok(Self {
             discriminator,
             owner,
@@ -110,9 +712,48 @@ impl LockAccount {
             created_at,
             lock_id,
             bump,
+            frozen,
+            credential_issuer,
+            non_transferable,
+            forward_destination,
+            hook,
+            recovery_address,
+            donation_address,
+            donation_bps,
+            collateral_holder,
+            layout_version,
+            unlock_slot,
+            unlock_epoch,
+            claim_key,
+            claim_expires_at,
+            index_seq,
+            early_unlock_allowed,
+            permanent,
+            is_nft,
+            receipt_bound,
+            label,
+            metadata_uri,
+            fallback_owner,
+            fallback_after,
+            unlock_destination,
+            shared,
+            approvals_required,
+            price_feed_id,
+            price_threshold,
+            price_exponent,
+            arbiter,
+            milestone_approved,
+            counterparty,
+            owner_counted,
+            wrapped_lamports,
+            receipt_mint,
+            fee_mode,
+            reserved,
         })
     }
 
+    /// Packs into `dst`. Appended fields are only written if `dst` has room
+    /// for them, so legacy locks must be grown before setting them.
     pub fn pack(&self, dst: &mut [u8]) {
         dst[0..8].copy_from_slice(&self.discriminator);
         dst[8..40].copy_from_slice(self.owner.as_ref());
@@ -122,116 +763,2333 @@ impl LockAccount {
         dst[88..96].copy_from_slice(&self.created_at.to_le_bytes());
         dst[96..104].copy_from_slice(&self.lock_id.to_le_bytes());
         dst[104] = self.bump;
+        if let Some(frozen) = dst.get_mut(105) {
+            *frozen = self.frozen as u8;
+        }
+        if let Some(issuer) = dst.get_mut(106..138) {
+            issuer.copy_from_slice(self.credential_issuer.as_ref());
+        }
+        if let Some(non_transferable) = dst.get_mut(138) {
+            *non_transferable = self.non_transferable as u8;
+        }
+        if let Some(destination) = dst.get_mut(139..171) {
+            destination.copy_from_slice(self.forward_destination.as_ref());
+        }
+        if let Some(hook) = dst.get_mut(171..203) {
+            hook.copy_from_slice(self.hook.as_ref());
+        }
+        if let Some(recovery) = dst.get_mut(203..235) {
+            recovery.copy_from_slice(self.recovery_address.as_ref());
+        }
+        if let Some(donation) = dst.get_mut(235..267) {
+            donation.copy_from_slice(self.donation_address.as_ref());
+        }
+        if let Some(bps) = dst.get_mut(267..269) {
+            bps.copy_from_slice(&self.donation_bps.to_le_bytes());
+        }
+        if let Some(holder) = dst.get_mut(269..301) {
+            holder.copy_from_slice(self.collateral_holder.as_ref());
+        }
+        if let Some(version) = dst.get_mut(301) {
+            *version = self.layout_version;
+        }
+        if let Some(slot) = dst.get_mut(302..310) {
+            slot.copy_from_slice(&self.unlock_slot.to_le_bytes());
+        }
+        if let Some(epoch) = dst.get_mut(310..318) {
+            epoch.copy_from_slice(&self.unlock_epoch.to_le_bytes());
+        }
+        if let Some(key) = dst.get_mut(318..350) {
+            key.copy_from_slice(&self.claim_key);
+        }
+        if let Some(expires_at) = dst.get_mut(350..358) {
+            expires_at.copy_from_slice(&self.claim_expires_at.to_le_bytes());
+        }
+        if let Some(seq) = dst.get_mut(358..362) {
+            seq.copy_from_slice(&self.index_seq.to_le_bytes());
+        }
+        if let Some(early_unlock_allowed) = dst.get_mut(362) {
+            *early_unlock_allowed = self.early_unlock_allowed as u8;
+        }
+        if let Some(permanent) = dst.get_mut(363) {
+            *permanent = self.permanent as u8;
+        }
+        if let Some(is_nft) = dst.get_mut(364) {
+            *is_nft = self.is_nft as u8;
+        }
+        if let Some(receipt_bound) = dst.get_mut(365) {
+            *receipt_bound = self.receipt_bound as u8;
+        }
+        if let Some(label) = dst.get_mut(366..398) {
+            label.copy_from_slice(&self.label);
+        }
+        if let Some(uri) = dst.get_mut(398..526) {
+            uri.copy_from_slice(&self.metadata_uri);
+        }
+        if let Some(fallback_owner) = dst.get_mut(526..558) {
+            fallback_owner.copy_from_slice(self.fallback_owner.as_ref());
+        }
+        if let Some(fallback_after) = dst.get_mut(558..566) {
+            fallback_after.copy_from_slice(&self.fallback_after.to_le_bytes());
+        }
+        if let Some(destination) = dst.get_mut(566..598) {
+            destination.copy_from_slice(self.unlock_destination.as_ref());
+        }
+        if let Some(shared) = dst.get_mut(598) {
+            *shared = self.shared as u8;
+        }
+        if let Some(approvals_required) = dst.get_mut(599) {
+            *approvals_required = self.approvals_required as u8;
+        }
+        if let Some(feed_id) = dst.get_mut(600..632) {
+            feed_id.copy_from_slice(&self.price_feed_id);
+        }
+        if let Some(threshold) = dst.get_mut(632..640) {
+            threshold.copy_from_slice(&self.price_threshold.to_le_bytes());
+        }
+        if let Some(exponent) = dst.get_mut(640..644) {
+            exponent.copy_from_slice(&self.price_exponent.to_le_bytes());
+        }
+        if let Some(arbiter) = dst.get_mut(644..676) {
+            arbiter.copy_from_slice(self.arbiter.as_ref());
+        }
+        if let Some(milestone_approved) = dst.get_mut(676) {
+            *milestone_approved = self.milestone_approved as u8;
+        }
+        if let Some(counterparty) = dst.get_mut(677..709) {
+            counterparty.copy_from_slice(self.counterparty.as_ref());
+        }
+        if let Some(owner_counted) = dst.get_mut(709) {
+            *owner_counted = self.owner_counted as u8;
+        }
+        if let Some(wrapped_lamports) = dst.get_mut(710) {
+            *wrapped_lamports = self.wrapped_lamports as u8;
+        }
+        if let Some(receipt_mint) = dst.get_mut(711..743) {
+            receipt_mint.copy_from_slice(self.receipt_mint.as_ref());
+        }
+        if let Some(fee_mode) = dst.get_mut(743) {
+            *fee_mode = self.fee_mode;
+        }
+        if let Some(reserved) = dst.get_mut(744..773) {
+            reserved.copy_from_slice(&self.reserved);
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use solana_program::pubkey::Pubkey;
+    /// Whether the lock's unlock condition holds: its unlock slot or epoch
+    /// if it has one, otherwise its unlock timestamp. Never for permanent
+    /// locks.
+    pub fn unlock_reached(&self, clock: &Clock) -> bool {
+        if self.permanent {
+            false
+        } else if self.unlock_slot != 0 {
+            clock.slot >= self.unlock_slot
+        } else if self.unlock_epoch != 0 {
+            clock.epoch >= self.unlock_epoch
+        } else {
+            clock.unix_timestamp >= self.unlock_timestamp
+        }
+    }
 
-    #[test]
-    fn test_config_account_pack_unpack_roundtrip() {
-        let config = ConfigAccount {
-            discriminator: ConfigAccount::DISCRIMINATOR,
-            admin: Pubkey::new_unique(),
-            bump: 255,
-        };
+    /// Whether a claim code still reserves the lock's tokens for whoever
+    /// presents it at `now`.
+    pub fn claim_active(&self, now: i64) -> bool {
+        self.claim_key != [0u8; 32] && now < self.claim_expires_at
+    }
 
-        let mut buffer = vec![0u8; ConfigAccount::SIZE];
-        config.pack(&mut buffer);
+    /// Checks that nothing holds the lock's tokens back from its owner at
+    /// `now`: it is not frozen, collateralized, bound to a receipt, split
+    /// among beneficiaries, awaiting approvals or reserved by a claim code.
+    /// Every path paying a lock out on its owner's say checks this before
+    /// its own conditions.
+    pub fn can_pay_out_to_owner(&self, now: i64) -> Result<(), ProgramError> {
+        self.can_pay_out(now, false, false)
+    }
 
-        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
-        assert_eq!(config, unpacked);
+    /// `can_pay_out_to_owner` for an unlock on behalf of the receipt's
+    /// holder if `by_receipt_holder`, or once approved if `approved`,
+    /// which the lock must then require.
+    pub fn can_pay_out(
+        &self,
+        now: i64,
+        by_receipt_holder: bool,
+        approved: bool,
+    ) -> Result<(), ProgramError> {
+        if self.frozen {
+            return Err(LocksmithError::LockFrozen.into());
+        }
+        if self.collateral_holder != Pubkey::default() {
+            return Err(LocksmithError::LockCollateralized.into());
+        }
+        if self.receipt_bound != by_receipt_holder {
+            return Err(LocksmithError::LockBoundToReceipt.into());
+        }
+        if self.shared {
+            return Err(LocksmithError::LockShared.into());
+        }
+        if self.approvals_required != approved {
+            return Err(LocksmithError::ApprovalsRequired.into());
+        }
+        if self.claim_active(now) {
+            return Err(LocksmithError::ClaimCodeActive.into());
+        }
+        Ok(())
     }
 
-    #[test]
-    fn test_config_account_unpack_insufficient_size() {
-        let data = vec![0u8; ConfigAccount::SIZE - 1];
-        let result = ConfigAccount::unpack(&data);
-        assert_eq!(
-            result.unwrap_err(),
-            ProgramError::Custom(LocksmithError::UninitializedAccount as u32)
-        );
+    /// Checks that the lock's terms may change: its lender verified it, the
+    /// receipt's holder bought it, and its beneficiaries were given their
+    /// shares, on its current terms.
+    pub fn can_change_terms(&self) -> Result<(), ProgramError> {
+        if self.collateral_holder != Pubkey::default() {
+            return Err(LocksmithError::LockCollateralized.into());
+        }
+        if self.receipt_bound {
+            return Err(LocksmithError::LockBoundToReceipt.into());
+        }
+        if self.shared {
+            return Err(LocksmithError::LockShared.into());
+        }
+        Ok(())
     }
 
-    #[test]
-    fn test_config_account_unpack_wrong_discriminator() {
-        let mut data = vec![0u8; ConfigAccount::SIZE];
-        data[0..8].copy_from_slice(b"WRONGDIS");
+    /// Vote-escrow weight of the lock at `clock`: its amount scaled by the
+    /// time left until it unlocks over `MAX_LOCK_DURATION_SECONDS`, so it
+    /// decays linearly to 0 at the unlock time. Permanent locks keep the
+    /// full amount.
+    pub fn voting_weight(&self, clock: &Clock) -> u64 {
+        if self.permanent {
+            return self.amount;
+        }
+        if self.unlock_reached(clock) {
+            return 0;
+        }
+        let remaining = self.seconds_until_unlock(clock).min(MAX_LOCK_DURATION_SECONDS as u64);
+        (self.amount as u128 * remaining as u128 / MAX_LOCK_DURATION_SECONDS as u128) as u64
+    }
 
-        let result = ConfigAccount::unpack(&data);
-        assert_eq!(
-            result.unwrap_err(),
-            ProgramError::Custom(LocksmithError::UninitializedAccount as u32)
-        );
+    /// Seconds left until the lock unlocks at `clock`. Slot- and epoch-based
+    /// locks convert their slot or epoch at `TARGET_MS_PER_SLOT` rather than
+    /// trust `unlock_timestamp`, which is only the creator's estimate.
+    fn seconds_until_unlock(&self, clock: &Clock) -> u64 {
+        if self.unlock_slot != 0 {
+            let slots = self.unlock_slot.saturating_sub(clock.slot);
+            slots.saturating_mul(TARGET_MS_PER_SLOT) / 1000
+        } else if self.unlock_epoch != 0 {
+            let epochs = self.unlock_epoch.saturating_sub(clock.epoch);
+            let into_epoch = clock.unix_timestamp.saturating_sub(clock.epoch_start_timestamp);
+            (epochs.saturating_mul(SLOTS_PER_EPOCH).saturating_mul(TARGET_MS_PER_SLOT) / 1000)
+                .saturating_sub(into_epoch.max(0) as u64)
+        } else {
+            self.unlock_timestamp.saturating_sub(clock.unix_timestamp).max(0) as u64
+        }
     }
+}
 
-    #[test]
-    fn test_lock_account_pack_unpack_roundtrip() {
-        let lock = LockAccount {
-            discriminator: LockAccount::DISCRIMINATOR,
-            owner: Pubkey::new_unique(),
-            mint: Pubkey::new_unique(),
-            amount: 1_000_000_000,
-            unlock_timestamp: 1700000000,
-            created_at: 1699000000,
-            lock_id: 42,
-            bump: 254,
-        };
+/// Vesting lock - tokens released linearly between `start` and `end` and
+/// claimed incrementally by the owner. Its escrow is the token account
+/// ["lock_token", vesting_account], as for locks.
+/// PDA seeds: ["vesting", owner, mint, vesting_id.to_le_bytes()]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct VestingLockAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Beneficiary who claims the vested tokens
+    pub owner: Pubkey,
+    /// Mint of the vesting tokens
+    pub mint: Pubkey,
+    /// Amount vesting in total
+    pub total: u64,
+    /// Amount claimed so far
+    pub claimed: u64,
+    /// Unix timestamp vesting starts at
+    pub start: i64,
+    /// Unix timestamp before which nothing can be claimed
+    pub cliff: i64,
+    /// Unix timestamp by which everything has vested
+    pub end: i64,
+    /// Unix timestamp the vesting lock was created
+    pub created_at: i64,
+    /// User-provided vesting identifier
+    pub vesting_id: u64,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Funder of the vesting lock, who may revoke it if `revocable`
+    pub grantor: Pubkey,
+    /// Whether the grantor can still claw back unvested tokens
+    pub revocable: bool,
+}
 
-        let mut buffer = vec![0u8; LockAccount::SIZE];
-        lock.pack(&mut buffer);
+impl VestingLockAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"VESTING\0";
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 32 + 1;
 
-        let unpacked = LockAccount::unpack(&buffer).unwrap();
-        assert_eq!(lock, unpacked);
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            owner: Pubkey::try_from(&data[8..40]).unwrap(),
+            mint: Pubkey::try_from(&data[40..72]).unwrap(),
+            total: u64::from_le_bytes(data[72..80].try_into().unwrap()),
+            claimed: u64::from_le_bytes(data[80..88].try_into().unwrap()),
+            start: i64::from_le_bytes(data[88..96].try_into().unwrap()),
+            cliff: i64::from_le_bytes(data[96..104].try_into().unwrap()),
+            end: i64::from_le_bytes(data[104..112].try_into().unwrap()),
+            created_at: i64::from_le_bytes(data[112..120].try_into().unwrap()),
+            vesting_id: u64::from_le_bytes(data[120..128].try_into().unwrap()),
+            bump: data[128],
+            grantor: Pubkey::try_from(&data[129..161]).unwrap(),
+            revocable: data[161] != 0,
+        })
     }
 
-    #[test]
-    fn test_lock_account_unpack_insufficient_size() {
-        let data = vec![0u8; LockAccount::SIZE - 1];
-        let result = LockAccount::unpack(&data);
-        assert_eq!(
-            result.unwrap_err(),
-            ProgramError::Custom(LocksmithError::UninitializedAccount as u32)
-        );
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.owner.as_ref());
+        dst[40..72].copy_from_slice(self.mint.as_ref());
+        dst[72..80].copy_from_slice(&self.total.to_le_bytes());
+        dst[80..88].copy_from_slice(&self.claimed.to_le_bytes());
+        dst[88..96].copy_from_slice(&self.start.to_le_bytes());
+        dst[96..104].copy_from_slice(&self.cliff.to_le_bytes());
+        dst[104..112].copy_from_slice(&self.end.to_le_bytes());
+        dst[112..120].copy_from_slice(&self.created_at.to_le_bytes());
+        dst[120..128].copy_from_slice(&self.vesting_id.to_le_bytes());
+        dst[128] = self.bump;
+        dst[129..161].copy_from_slice(self.grantor.as_ref());
+        dst[161] = self.revocable as u8;
     }
 
-    #[test]
-    fn test_lock_account_unpack_wrong_discriminator() {
-        let mut data = vec![0u8; LockAccount::SIZE];
-        data[0..8].copy_from_slice(b"WRONGDIS");
+    /// Amount vested by `now`, linear in time between `start` and `end`.
+    /// Nothing vests before `cliff`, at which the portion accrued since
+    /// `start` vests at once. Rounds down, so the last unit vests at `end`.
+    pub fn vested_at(&self, now: i64) -> u64 {
+        if now < self.cliff || now <= self.start {
+            return 0;
+        }
+        if now >= self.end {
+            return self.total;
+        }
+        let elapsed = now.abs_diff(self.start) as u128;
+        let duration = self.end.abs_diff(self.start) as u128;
+        (self.total as u128 * elapsed / duration) as u64
+    }
 
-        let result = LockAccount::unpack(&data);
-        assert_eq!(
-            result.unwrap_err(),
-            ProgramError::Custom(LocksmithError::UninitializedAccount as u32)
-        );
+    /// Stops vesting at `now`: the amount vested so far becomes the total,
+    /// fully vested, and the lock is no longer revocable. Returns the
+    /// unvested remainder clawed back.
+    pub fn revoke(&mut self, now: i64) -> u64 {
+        let vested = self.vested_at(now);
+        let unvested = self.total - vested;
+        self.total = vested;
+        self.end = self.end.min(now);
+        self.cliff = self.cliff.min(now);
+        self.revocable = false;
+        unvested
     }
+}
 
-    #[test]
-    fn test_lock_account_rejects_config_discriminator() {
-        let mut data = vec![0u8; LockAccount::SIZE];
-        data[0..8].copy_from_slice(&ConfigAccount::DISCRIMINATOR);
+/// Lock template - admin-published lock parameters for
+/// `InitializeLockFromTemplate`.
+/// PDA seeds: ["template", template_id.to_le_bytes()]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct TemplateAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Admin-chosen template identifier
+    pub template_id: u64,
+    /// Seconds from creation until locks made from this template unlock
+    pub duration_seconds: i64,
+    /// USDC fee charged instead of `FEE_USDC`
+    pub fee_usdc: u64,
+    /// `LockOptions::credential_issuer` applied to every lock
+    pub credential_issuer: Pubkey,
+    /// `LockOptions::non_transferable` applied to every lock
+    pub non_transferable: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
 
-        assert!(LockAccount::unpack(&data).is_err());
+impl TemplateAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"TEMPLATE";
+    pub const SIZE: usize = 8 + 8 + 8 + 8 + 32 + 1 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            template_id: u64::from_le_bytes(data[8..16].try_into().unwrap()),
+            duration_seconds: i64::from_le_bytes(data[16..24].try_into().unwrap()),
+            fee_usdc: u64::from_le_bytes(data[24..32].try_into().unwrap()),
+            credential_issuer: Pubkey::try_from(&data[32..64]).unwrap(),
+            non_transferable: data[64] != 0,
+            bump: data[65],
+        })
     }
 
-    #[test]
-    fn test_discriminators_are_unique() {
-        assert_ne!(ConfigAccount::DISCRIMINATOR, LockAccount::DISCRIMINATOR);
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..16].copy_from_slice(&self.template_id.to_le_bytes());
+        dst[16..24].copy_from_slice(&self.duration_seconds.to_le_bytes());
+        dst[24..32].copy_from_slice(&self.fee_usdc.to_le_bytes());
+        dst[32..64].copy_from_slice(self.credential_issuer.as_ref());
+        dst[64] = self.non_transferable as u8;
+        dst[65] = self.bump;
     }
+}
 
-    #[test]
-    fn test_config_account_byte_layout() {
-        let admin_bytes: [u8; 32] = [
-            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
-            25, 26, 27, 28, 29, 30, 31, 32,
-        ];
-        let config = ConfigAccount {
+/// Standing order - a pre-authorized schedule of lock creations executed by
+/// a permissionless crank. The order PDA is the delegate on the owner's token
+/// and USDC accounts and holds the rent for the locks it will create.
+/// PDA seeds: ["standing_order", owner, mint, order_id.to_le_bytes()]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct StandingOrderAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Owner of the locks created by this order
+    pub owner: Pubkey,
+    /// Mint of the locked tokens
+    pub mint: Pubkey,
+    /// Owner's token account the locked tokens are drawn from
+    pub owner_token_account: Pubkey,
+    /// Owner's USDC account the fees are drawn from
+    pub owner_usdc_account: Pubkey,
+    /// User-provided order identifier
+    pub order_id: u64,
+    /// Tokens locked by each execution
+    pub amount_per_lock: u64,
+    /// Seconds between executions
+    pub interval_seconds: i64,
+    /// Seconds from execution until each lock unlocks
+    pub lock_duration_seconds: i64,
+    /// Unix timestamp from which the next execution may run
+    pub next_execution_at: i64,
+    /// Lock ID the next execution will use
+    pub next_lock_id: u64,
+    /// Executions left; the order is closed when this reaches zero
+    pub remaining_executions: u32,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Most fee an execution charges, approved for each execution at
+    /// creation
+    pub fee_usdc: u64,
+}
+
+impl StandingOrderAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"STORDER\0";
+    pub const SIZE: usize = Self::LEGACY_SIZE + 8;
+    /// Size of orders created before `fee_usdc` was appended, which approved
+    /// `FEE_USDC` per execution and read as charging at most that.
+    pub const LEGACY_SIZE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 4 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::LEGACY_SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            owner: Pubkey::try_from(&data[8..40]).unwrap(),
+            mint: Pubkey::try_from(&data[40..72]).unwrap(),
+            owner_token_account: Pubkey::try_from(&data[72..104]).unwrap(),
+            owner_usdc_account: Pubkey::try_from(&data[104..136]).unwrap(),
+            order_id: u64::from_le_bytes(data[136..144].try_into().unwrap()),
+            amount_per_lock: u64::from_le_bytes(data[144..152].try_into().unwrap()),
+            interval_seconds: i64::from_le_bytes(data[152..160].try_into().unwrap()),
+            lock_duration_seconds: i64::from_le_bytes(data[160..168].try_into().unwrap()),
+            next_execution_at: i64::from_le_bytes(data[168..176].try_into().unwrap()),
+            next_lock_id: u64::from_le_bytes(data[176..184].try_into().unwrap()),
+            remaining_executions: u32::from_le_bytes(data[184..188].try_into().unwrap()),
+            bump: data[188],
+            fee_usdc: data
+                .get(189..197)
+                .map_or(FEE_USDC, |b| u64::from_le_bytes(b.try_into().unwrap())),
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.owner.as_ref());
+        dst[40..72].copy_from_slice(self.mint.as_ref());
+        dst[72..104].copy_from_slice(self.owner_token_account.as_ref());
+        dst[104..136].copy_from_slice(self.owner_usdc_account.as_ref());
+        dst[136..144].copy_from_slice(&self.order_id.to_le_bytes());
+        dst[144..152].copy_from_slice(&self.amount_per_lock.to_le_bytes());
+        dst[152..160].copy_from_slice(&self.interval_seconds.to_le_bytes());
+        dst[160..168].copy_from_slice(&self.lock_duration_seconds.to_le_bytes());
+        dst[168..176].copy_from_slice(&self.next_execution_at.to_le_bytes());
+        dst[176..184].copy_from_slice(&self.next_lock_id.to_le_bytes());
+        dst[184..188].copy_from_slice(&self.remaining_executions.to_le_bytes());
+        dst[188] = self.bump;
+        if let Some(fee_usdc) = dst.get_mut(189..197) {
+            fee_usdc.copy_from_slice(&self.fee_usdc.to_le_bytes());
+        }
+    }
+}
+
+/// Forwarding destination - an admin-approved program instruction that
+/// unlocked tokens may be deposited through (e.g. a lending vault deposit).
+/// PDA seeds: ["forwarding", program, instruction_prefix[..prefix_len]]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct ForwardingDestinationAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Program invoked with the unlocked tokens
+    pub program: Pubkey,
+    /// Instruction data prefix (e.g. an Anchor sighash); the unlocked
+    /// amount is appended as a little-endian u64
+    pub instruction_prefix: [u8; 8],
+    /// Number of bytes of `instruction_prefix` in use
+    pub prefix_len: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ForwardingDestinationAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"FORWARD\0";
+    pub const SIZE: usize = 8 + 32 + 8 + 1 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            program: Pubkey::try_from(&data[8..40]).unwrap(),
+            instruction_prefix: data[40..48].try_into().unwrap(),
+            prefix_len: data[48],
+            bump: data[49],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.program.as_ref());
+        dst[40..48].copy_from_slice(&self.instruction_prefix);
+        dst[48] = self.prefix_len;
+        dst[49] = self.bump;
+    }
+
+    /// The instruction data prefix in use.
+    pub fn prefix(&self) -> &[u8] {
+        &self.instruction_prefix[..usize::from(self.prefix_len).min(8)]
+    }
+}
+
+/// Hook - an integrator program registered by the admin to be notified
+/// when locks that opted in are created or unlocked.
+/// PDA seeds: ["hook", program]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct HookAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Program invoked on lock events
+    pub program: Pubkey,
+    /// Instruction data prefix of the callback (e.g. an Anchor sighash)
+    pub instruction_prefix: [u8; 8],
+    /// Number of bytes of `instruction_prefix` in use
+    pub prefix_len: u8,
+    /// `HOOK_EVENT_*` bits the program subscribed to
+    pub events: u8,
+    /// Compute units the callback may consume, at most
+    /// `MAX_HOOK_COMPUTE_UNITS`
+    pub compute_allowance: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl HookAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"HOOK\0\0\0\0";
+    pub const SIZE: usize = 8 + 32 + 8 + 1 + 1 + 4 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            program: Pubkey::try_from(&data[8..40]).unwrap(),
+            instruction_prefix: data[40..48].try_into().unwrap(),
+            prefix_len: data[48],
+            events: data[49],
+            compute_allowance: u32::from_le_bytes(data[50..54].try_into().unwrap()),
+            bump: data[54],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.program.as_ref());
+        dst[40..48].copy_from_slice(&self.instruction_prefix);
+        dst[48] = self.prefix_len;
+        dst[49] = self.events;
+        dst[50..54].copy_from_slice(&self.compute_allowance.to_le_bytes());
+        dst[54] = self.bump;
+    }
+
+    /// The instruction data prefix in use.
+    pub fn prefix(&self) -> &[u8] {
+        &self.instruction_prefix[..usize::from(self.prefix_len).min(8)]
+    }
+}
+
+/// Lender - an admin-approved authority (typically a PDA of a lending
+/// program) that may mark locks as collateral.
+/// PDA seeds: ["lender", authority]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct LenderAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Signer allowed to call `SetCollateralized`
+    pub authority: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LenderAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"LENDER\0\0";
+    pub const SIZE: usize = 8 + 32 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            authority: Pubkey::try_from(&data[8..40]).unwrap(),
+            bump: data[40],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.authority.as_ref());
+        dst[40] = self.bump;
+    }
+}
+
+/// Session - a key the owner lets unlock their locks on their behalf, e.g.
+/// a bot scheduling claims, within a scope and until an expiry.
+/// PDA seeds: ["session", owner, session_key]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct SessionAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Owner whose locks the session key may unlock
+    pub owner: Pubkey,
+    /// Signer allowed to call `UnlockWithSession`
+    pub session_key: Pubkey,
+    /// Only locks of this mint may be unlocked (default pubkey = any mint)
+    pub mint: Pubkey,
+    /// Owner's token account the unlocked tokens must go to
+    pub destination: Pubkey,
+    /// Unix timestamp from which the session key is no longer honored
+    pub expires_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SessionAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"SESSION\0";
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            owner: Pubkey::try_from(&data[8..40]).unwrap(),
+            session_key: Pubkey::try_from(&data[40..72]).unwrap(),
+            mint: Pubkey::try_from(&data[72..104]).unwrap(),
+            destination: Pubkey::try_from(&data[104..136]).unwrap(),
+            expires_at: i64::from_le_bytes(data[136..144].try_into().unwrap()),
+            bump: data[144],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.owner.as_ref());
+        dst[40..72].copy_from_slice(self.session_key.as_ref());
+        dst[72..104].copy_from_slice(self.mint.as_ref());
+        dst[104..136].copy_from_slice(self.destination.as_ref());
+        dst[136..144].copy_from_slice(&self.expires_at.to_le_bytes());
+        dst[144] = self.bump;
+    }
+}
+
+/// Note - an opaque blob the lock owner attaches to a lock, encrypted
+/// client-side, e.g. an invoice reference.
+/// PDA seeds: ["note", lock_account]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct NoteAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Lock the note is attached to
+    pub lock: Pubkey,
+    /// Lock owner who wrote the note, and may close it after the lock is closed
+    pub owner: Pubkey,
+    /// Number of bytes of `note` in use
+    pub note_len: u16,
+    /// Encrypted note, zero-padded past `note_len`
+    pub note: [u8; 256],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl NoteAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"NOTE\0\0\0\0";
+    pub const SIZE: usize = 8 + 32 + 32 + 2 + MAX_NOTE_LEN + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            lock: Pubkey::try_from(&data[8..40]).unwrap(),
+            owner: Pubkey::try_from(&data[40..72]).unwrap(),
+            note_len: u16::from_le_bytes(data[72..74].try_into().unwrap()),
+            note: data[74..330].try_into().unwrap(),
+            bump: data[330],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.lock.as_ref());
+        dst[40..72].copy_from_slice(self.owner.as_ref());
+        dst[72..74].copy_from_slice(&self.note_len.to_le_bytes());
+        dst[74..330].copy_from_slice(&self.note);
+        dst[330] = self.bump;
+    }
+}
+
+/// Mint index - counts the indexed locks of a mint and numbers them, so
+/// that the `IndexEntryAccount` of every indexed lock can be derived.
+/// PDA seeds: ["mint_index", mint]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct MintIndexAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Mint whose locks are indexed
+    pub mint: Pubkey,
+    /// Locks ever indexed; entries are numbered 1 to `total_locks`
+    pub total_locks: u32,
+    /// Indexed locks not yet removed from the index
+    pub active_locks: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MintIndexAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"MINTIDX\0";
+    pub const SIZE: usize = 8 + 32 + 4 + 4 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            mint: Pubkey::try_from(&data[8..40]).unwrap(),
+            total_locks: u32::from_le_bytes(data[40..44].try_into().unwrap()),
+            active_locks: u32::from_le_bytes(data[44..48].try_into().unwrap()),
+            bump: data[48],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.mint.as_ref());
+        dst[40..44].copy_from_slice(&self.total_locks.to_le_bytes());
+        dst[44..48].copy_from_slice(&self.active_locks.to_le_bytes());
+        dst[48] = self.bump;
+    }
+}
+
+/// Index entry - records one indexed lock of a mint until the lock is
+/// closed and the entry removed.
+/// PDA seeds: ["mint_index", mint, seq.to_le_bytes()]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct IndexEntryAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Mint of the indexed lock
+    pub mint: Pubkey,
+    /// Indexed lock
+    pub lock: Pubkey,
+    /// Paid the entry's rent, and receives it back when the entry is removed
+    pub payer: Pubkey,
+    /// Position in the mint's index, starting at 1
+    pub seq: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl IndexEntryAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"IDXENTRY";
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 4 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            mint: Pubkey::try_from(&data[8..40]).unwrap(),
+            lock: Pubkey::try_from(&data[40..72]).unwrap(),
+            payer: Pubkey::try_from(&data[72..104]).unwrap(),
+            seq: u32::from_le_bytes(data[104..108].try_into().unwrap()),
+            bump: data[108],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.mint.as_ref());
+        dst[40..72].copy_from_slice(self.lock.as_ref());
+        dst[72..104].copy_from_slice(self.payer.as_ref());
+        dst[104..108].copy_from_slice(&self.seq.to_le_bytes());
+        dst[108] = self.bump;
+    }
+}
+
+/// Lock certificate - an on-chain copy of a consistent lock's terms that
+/// explorers and launchpads can render without reading the lock layout.
+/// PDA seeds: ["certificate", lock_account]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct LockCertificateAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Certified lock
+    pub lock: Pubkey,
+    /// Owner of the lock
+    pub owner: Pubkey,
+    /// Mint of the locked tokens
+    pub mint: Pubkey,
+    /// Amount locked, matching the escrow at `published_at`
+    pub amount: u64,
+    /// Unix timestamp the lock unlocks at
+    pub unlock_timestamp: i64,
+    /// Slot the lock unlocks at (0 = not slot-based)
+    pub unlock_slot: u64,
+    /// Epoch the lock unlocks at (0 = not epoch-based)
+    pub unlock_epoch: u64,
+    /// Unix timestamp the certificate was last published at
+    pub published_at: i64,
+    /// Paid the certificate's rent, and receives it back when it is closed
+    pub payer: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LockCertificateAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"LOCKCERT";
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            lock: Pubkey::try_from(&data[8..40]).unwrap(),
+            owner: Pubkey::try_from(&data[40..72]).unwrap(),
+            mint: Pubkey::try_from(&data[72..104]).unwrap(),
+            amount: u64::from_le_bytes(data[104..112].try_into().unwrap()),
+            unlock_timestamp: i64::from_le_bytes(data[112..120].try_into().unwrap()),
+            unlock_slot: u64::from_le_bytes(data[120..128].try_into().unwrap()),
+            unlock_epoch: u64::from_le_bytes(data[128..136].try_into().unwrap()),
+            published_at: i64::from_le_bytes(data[136..144].try_into().unwrap()),
+            payer: Pubkey::try_from(&data[144..176]).unwrap(),
+            bump: data[176],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.lock.as_ref());
+        dst[40..72].copy_from_slice(self.owner.as_ref());
+        dst[72..104].copy_from_slice(self.mint.as_ref());
+        dst[104..112].copy_from_slice(&self.amount.to_le_bytes());
+        dst[112..120].copy_from_slice(&self.unlock_timestamp.to_le_bytes());
+        dst[120..128].copy_from_slice(&self.unlock_slot.to_le_bytes());
+        dst[128..136].copy_from_slice(&self.unlock_epoch.to_le_bytes());
+        dst[136..144].copy_from_slice(&self.published_at.to_le_bytes());
+        dst[144..176].copy_from_slice(self.payer.as_ref());
+        dst[176] = self.bump;
+    }
+}
+
+/// Lock shares - the beneficiaries a lock was split among by
+/// `SetLockShares`, each of whom claims their share with `ClaimShare` once
+/// the lock unlocks.
+/// PDA seeds: ["shares", lock_account]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct LockSharesAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Lock that was split
+    pub lock: Pubkey,
+    /// Lock's amount when it was split, which the shares are of
+    pub total: u64,
+    /// Beneficiaries (default pubkey = unused slot)
+    pub beneficiaries: [Pubkey; 8],
+    /// Each beneficiary's share of `total` in basis points
+    pub share_bps: [u16; 8],
+    /// Bit `i` is set once `beneficiaries[i]` has claimed
+    pub claimed: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LockSharesAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"SHARES\0\0";
+    pub const SIZE: usize = 8 + 32 + 8 + 32 * MAX_BENEFICIARIES + 2 * MAX_BENEFICIARIES + 1 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let mut beneficiaries = [Pubkey::default(); MAX_BENEFICIARIES];
+        let mut share_bps = [0u16; MAX_BENEFICIARIES];
+        for i in 0..MAX_BENEFICIARIES {
+            beneficiaries[i] = Pubkey::try_from(&data[48 + 32 * i..80 + 32 * i]).unwrap();
+            share_bps[i] = u16::from_le_bytes(data[304 + 2 * i..306 + 2 * i].try_into().unwrap());
+        }
+        Ok(Self {
+            discriminator,
+            lock: Pubkey::try_from(&data[8..40]).unwrap(),
+            total: u64::from_le_bytes(data[40..48].try_into().unwrap()),
+            beneficiaries,
+            share_bps,
+            claimed: data[320],
+            bump: data[321],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.lock.as_ref());
+        dst[40..48].copy_from_slice(&self.total.to_le_bytes());
+        for i in 0..MAX_BENEFICIARIES {
+            dst[48 + 32 * i..80 + 32 * i].copy_from_slice(self.beneficiaries[i].as_ref());
+            dst[304 + 2 * i..306 + 2 * i].copy_from_slice(&self.share_bps[i].to_le_bytes());
+        }
+        dst[320] = self.claimed;
+        dst[321] = self.bump;
+    }
+
+    /// Slot of `beneficiary`, if it is one.
+    pub fn beneficiary_index(&self, beneficiary: &Pubkey) -> Option<usize> {
+        if *beneficiary == Pubkey::default() {
+            return None;
+        }
+        self.beneficiaries.iter().position(|b| b == beneficiary)
+    }
+
+    /// Whether every beneficiary has claimed.
+    pub fn all_claimed(&self) -> bool {
+        (0..MAX_BENEFICIARIES)
+            .all(|i| self.beneficiaries[i] == Pubkey::default() || self.claimed & (1 << i) != 0)
+    }
+
+    /// What beneficiary `index` is paid when `remaining` is left in escrow:
+    /// their share of `total`, rounded down, or all of `remaining` if they
+    /// are the last to claim, so no rounding dust is left behind.
+    pub fn payout(&self, index: usize, remaining: u64) -> u64 {
+        let others_claimed = (0..MAX_BENEFICIARIES).all(|i| {
+            i == index || self.beneficiaries[i] == Pubkey::default() || self.claimed & (1 << i) != 0
+        });
+        if others_claimed {
+            return remaining;
+        }
+        let share = u128::from(self.total) * u128::from(self.share_bps[index])
+            / u128::from(BPS_DENOMINATOR);
+        (share as u64).min(remaining)
+    }
+}
+
+/// Unlock approvals - the approvers `SetUnlockApprovers` designated for a
+/// lock, `threshold` of whom must approve with `ApproveUnlock` before
+/// `ExecuteUnlock` can unlock it.
+/// PDA seeds: ["approvals", lock_account]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct UnlockApprovalsAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Lock the approvals are for
+    pub lock: Pubkey,
+    /// Approvers (default pubkey = unused slot)
+    pub approvers: [Pubkey; 8],
+    /// Approvals needed to unlock
+    pub threshold: u8,
+    /// Bit `i` is set once `approvers[i]` has approved
+    pub approvals: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UnlockApprovalsAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"APPROVAL";
+    pub const SIZE: usize = 8 + 32 + 32 * MAX_APPROVERS + 1 + 1 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let mut approvers = [Pubkey::default(); MAX_APPROVERS];
+        for (i, approver) in approvers.iter_mut().enumerate() {
+            *approver = Pubkey::try_from(&data[40 + 32 * i..72 + 32 * i]).unwrap();
+        }
+        Ok(Self {
+            discriminator,
+            lock: Pubkey::try_from(&data[8..40]).unwrap(),
+            approvers,
+            threshold: data[296],
+            approvals: data[297],
+            bump: data[298],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.lock.as_ref());
+        for (i, approver) in self.approvers.iter().enumerate() {
+            dst[40 + 32 * i..72 + 32 * i].copy_from_slice(approver.as_ref());
+        }
+        dst[296] = self.threshold;
+        dst[297] = self.approvals;
+        dst[298] = self.bump;
+    }
+
+    /// Slot of `approver`, if it is one.
+    pub fn approver_index(&self, approver: &Pubkey) -> Option<usize> {
+        if *approver == Pubkey::default() {
+            return None;
+        }
+        self.approvers.iter().position(|a| a == approver)
+    }
+
+    /// Whether at least `threshold` approvers have approved.
+    pub fn approved(&self) -> bool {
+        self.approvals.count_ones() >= u32::from(self.threshold)
+    }
+}
+
+/// Vote weight snapshot - a lock's vote-escrow weight as of one slot,
+/// written by `SnapshotWeight` for governance programs to count.
+/// PDA seeds: ["vote_weight", lock_account]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct VoteWeightSnapshotAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Lock the weight is of
+    pub lock: Pubkey,
+    /// Owner of the lock
+    pub owner: Pubkey,
+    /// Mint of the locked tokens
+    pub mint: Pubkey,
+    /// Lock's voting weight at `slot`
+    pub weight: u64,
+    /// Slot the snapshot was taken at
+    pub slot: u64,
+    /// Unix timestamp the snapshot was taken at
+    pub snapshot_at: i64,
+    /// Paid the snapshot's rent, and receives it back when it is closed
+    pub payer: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl VoteWeightSnapshotAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"VOTEWGHT";
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 8 + 32 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            lock: Pubkey::try_from(&data[8..40]).unwrap(),
+            owner: Pubkey::try_from(&data[40..72]).unwrap(),
+            mint: Pubkey::try_from(&data[72..104]).unwrap(),
+            weight: u64::from_le_bytes(data[104..112].try_into().unwrap()),
+            slot: u64::from_le_bytes(data[112..120].try_into().unwrap()),
+            snapshot_at: i64::from_le_bytes(data[120..128].try_into().unwrap()),
+            payer: Pubkey::try_from(&data[128..160]).unwrap(),
+            bump: data[160],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.lock.as_ref());
+        dst[40..72].copy_from_slice(self.owner.as_ref());
+        dst[72..104].copy_from_slice(self.mint.as_ref());
+        dst[104..112].copy_from_slice(&self.weight.to_le_bytes());
+        dst[112..120].copy_from_slice(&self.slot.to_le_bytes());
+        dst[120..128].copy_from_slice(&self.snapshot_at.to_le_bytes());
+        dst[128..160].copy_from_slice(self.payer.as_ref());
+        dst[160] = self.bump;
+    }
+}
+
+/// Mint policy - whether a mint can be locked, under the config's mint
+/// policy mode. Set by the admin with `SetMintPolicy`.
+/// PDA seeds: ["mint_policy", mint]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct MintPolicyAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Mint the policy is for
+    pub mint: Pubkey,
+    /// Whether the mint is allowed (allowlist mode) or denied (denylist
+    /// mode, when false)
+    pub allowed: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MintPolicyAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"MINTPLCY";
+    pub const SIZE: usize = 8 + 32 + 1 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            mint: Pubkey::try_from(&data[8..40]).unwrap(),
+            allowed: data[40] != 0,
+            bump: data[41],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.mint.as_ref());
+        dst[40] = self.allowed as u8;
+        dst[41] = self.bump;
+    }
+}
+
+/// Fee cap - most tokens of a mint the percentage fee takes from one lock,
+/// in the mint's smallest units. Locks of mints without one pay the flat
+/// fee. Set by the admin with `SetFeeCap`.
+/// PDA seeds: ["fee_cap", mint]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct FeeCapAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Mint the cap is for
+    pub mint: Pubkey,
+    /// Most tokens charged per lock
+    pub cap: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FeeCapAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"FEECAP\0\0";
+    pub const SIZE: usize = 8 + 32 + 8 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            mint: Pubkey::try_from(&data[8..40]).unwrap(),
+            cap: u64::from_le_bytes(data[40..48].try_into().unwrap()),
+            bump: data[48],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.mint.as_ref());
+        dst[40..48].copy_from_slice(&self.cap.to_le_bytes());
+        dst[48] = self.bump;
+    }
+}
+
+/// Fee exemption - a wallet that pays no fee on the locks it pays for. Set
+/// by the admin with `SetFeeExemption`.
+/// PDA seeds: ["fee_exemption", wallet]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct FeeExemptionAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Exempt wallet
+    pub wallet: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FeeExemptionAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"FEEEXMPT";
+    pub const SIZE: usize = 8 + 32 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            wallet: Pubkey::try_from(&data[8..40]).unwrap(),
+            bump: data[40],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.wallet.as_ref());
+        dst[40] = self.bump;
+    }
+}
+
+/// Owner counter - counts an owner's unclosed locks while the config caps
+/// them, and holds any cap the admin has set for the owner with
+/// `SetOwnerLockCap`.
+/// PDA seeds: ["owner_counter", owner]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct OwnerCounterAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Owner whose locks are counted
+    pub owner: Pubkey,
+    /// Counted locks not yet closed
+    pub active_locks: u32,
+    /// Replaces `ConfigAccount::max_locks_per_owner` for this owner (0 =
+    /// the config's cap applies)
+    pub lock_cap: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl OwnerCounterAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"OWNRCNTR";
+    pub const SIZE: usize = 8 + 32 + 4 + 4 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            owner: Pubkey::try_from(&data[8..40]).unwrap(),
+            active_locks: u32::from_le_bytes(data[40..44].try_into().unwrap()),
+            lock_cap: u32::from_le_bytes(data[44..48].try_into().unwrap()),
+            bump: data[48],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.owner.as_ref());
+        dst[40..44].copy_from_slice(&self.active_locks.to_le_bytes());
+        dst[44..48].copy_from_slice(&self.lock_cap.to_le_bytes());
+        dst[48] = self.bump;
+    }
+
+    /// Most unclosed locks the owner may hold under `config`
+    pub fn cap(&self, config: &ConfigAccount) -> u32 {
+        if self.lock_cap != 0 {
+            self.lock_cap
+        } else {
+            config.max_locks_per_owner
+        }
+    }
+}
+
+/// Fee schedule - tiers setting the flat fee by lock duration and amount.
+/// Set by the admin with `SetFeeSchedule`.
+/// PDA seeds: ["fee_schedule"]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct FeeScheduleAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Tiers in use, from the first
+    pub tier_count: u8,
+    /// Shortest lock, in seconds, each tier applies to
+    pub min_durations: [u64; 8],
+    /// Smallest lock, in the locked mint's smallest units, each tier
+    /// applies to
+    pub min_amounts: [u64; 8],
+    /// Fee each tier charges, in the fee mint's smallest units
+    pub fees_usdc: [u64; 8],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FeeScheduleAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"FEESCHED";
+    pub const SIZE: usize = 8 + 1 + 8 * MAX_FEE_TIERS * 3 + 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let u64_at = |i: usize| u64::from_le_bytes(data[i..i + 8].try_into().unwrap());
+        let mut min_durations = [0u64; MAX_FEE_TIERS];
+        let mut min_amounts = [0u64; MAX_FEE_TIERS];
+        let mut fees_usdc = [0u64; MAX_FEE_TIERS];
+        for i in 0..MAX_FEE_TIERS {
+            min_durations[i] = u64_at(9 + 8 * i);
+            min_amounts[i] = u64_at(73 + 8 * i);
+            fees_usdc[i] = u64_at(137 + 8 * i);
+        }
+        Ok(Self {
+            discriminator,
+            tier_count: data[8],
+            min_durations,
+            min_amounts,
+            fees_usdc,
+            bump: data[201],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8] = self.tier_count;
+        for i in 0..MAX_FEE_TIERS {
+            dst[9 + 8 * i..17 + 8 * i].copy_from_slice(&self.min_durations[i].to_le_bytes());
+            dst[73 + 8 * i..81 + 8 * i].copy_from_slice(&self.min_amounts[i].to_le_bytes());
+            dst[137 + 8 * i..145 + 8 * i].copy_from_slice(&self.fees_usdc[i].to_le_bytes());
+        }
+        dst[201] = self.bump;
+    }
+
+    /// Fee of the last tier a lock of `amount` tokens for `duration` seconds
+    /// meets both minimums of, if any.
+    pub fn fee_for(&self, duration: u64, amount: u64) -> Option<u64> {
+        (0..usize::from(self.tier_count).min(MAX_FEE_TIERS))
+            .rev()
+            .find(|&i| duration >= self.min_durations[i] && amount >= self.min_amounts[i])
+            .map(|i| self.fees_usdc[i])
+    }
+}
+
+/// Lock proof - an attestation, written by `RecordLockProof`, of how much
+/// of a mint's supply a lock holds and until when, alongside whether the
+/// mint can still be inflated or frozen.
+/// PDA seeds: ["lock_proof", lock_account]
+#[derive(Debug, PartialEq, ShankAccount)]
+pub struct LockProofAccount {
+    /// Account discriminator
+    pub discriminator: [u8; 8],
+    /// Attested lock
+    pub lock: Pubkey,
+    /// Mint of the locked tokens
+    pub mint: Pubkey,
+    /// Amount locked, matching the escrow at `recorded_at`
+    pub amount: u64,
+    /// Mint's supply at `recorded_at`
+    pub supply: u64,
+    /// `amount` as a share of `supply`, in basis points, rounded down
+    pub locked_bps: u16,
+    /// Whether the mint's mint authority was revoked, fixing its supply
+    pub mint_authority_revoked: bool,
+    /// Whether the mint's freeze authority was revoked, so the escrow cannot
+    /// be frozen
+    pub freeze_authority_revoked: bool,
+    /// Unix timestamp the lock unlocks at
+    pub unlock_timestamp: i64,
+    /// Whether the lock never unlocks
+    pub permanent: bool,
+    /// Slot the proof was recorded at
+    pub slot: u64,
+    /// Unix timestamp the proof was recorded at
+    pub recorded_at: i64,
+    /// Paid the proof's rent, and receives it back when it is closed
+    pub payer: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LockProofAccount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"LOCKPROF";
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 2 + 1 + 1 + 8 + 1 + 8 + 8 + 32 + 1;
+
+    /// `amount` as a share of `supply` in basis points, rounded down. A
+    /// supply of zero has no share locked.
+    pub fn locked_bps_of(amount: u64, supply: u64) -> u16 {
+        if supply == 0 {
+            return 0;
+        }
+        (u128::from(amount) * 10_000 / u128::from(supply)).min(10_000) as u16
+    }
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::SIZE {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        let discriminator: [u8; 8] = data[0..8].try_into().unwrap();
+        if discriminator != Self::DISCRIMINATOR {
+            return Err(LocksmithError::UninitializedAccount.into());
+        }
+        Ok(Self {
+            discriminator,
+            lock: Pubkey::try_from(&data[8..40]).unwrap(),
+            mint: Pubkey::try_from(&data[40..72]).unwrap(),
+            amount: u64::from_le_bytes(data[72..80].try_into().unwrap()),
+            supply: u64::from_le_bytes(data[80..88].try_into().unwrap()),
+            locked_bps: u16::from_le_bytes(data[88..90].try_into().unwrap()),
+            mint_authority_revoked: data[90] != 0,
+            freeze_authority_revoked: data[91] != 0,
+            unlock_timestamp: i64::from_le_bytes(data[92..100].try_into().unwrap()),
+            permanent: data[100] != 0,
+            slot: u64::from_le_bytes(data[101..109].try_into().unwrap()),
+            recorded_at: i64::from_le_bytes(data[109..117].try_into().unwrap()),
+            payer: Pubkey::try_from(&data[117..149]).unwrap(),
+            bump: data[149],
+        })
+    }
+
+    pub fn pack(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.discriminator);
+        dst[8..40].copy_from_slice(self.lock.as_ref());
+        dst[40..72].copy_from_slice(self.mint.as_ref());
+        dst[72..80].copy_from_slice(&self.amount.to_le_bytes());
+        dst[80..88].copy_from_slice(&self.supply.to_le_bytes());
+        dst[88..90].copy_from_slice(&self.locked_bps.to_le_bytes());
+        dst[90] = self.mint_authority_revoked as u8;
+        dst[91] = self.freeze_authority_revoked as u8;
+        dst[92..100].copy_from_slice(&self.unlock_timestamp.to_le_bytes());
+        dst[100] = self.permanent as u8;
+        dst[101..109].copy_from_slice(&self.slot.to_le_bytes());
+        dst[109..117].copy_from_slice(&self.recorded_at.to_le_bytes());
+        dst[117..149].copy_from_slice(self.payer.as_ref());
+        dst[149] = self.bump;
+    }
+}
+
+/// A fully verified Pyth price update (the receiver program's
+/// `PriceUpdateV2` account), as read by price-conditional unlocks. Partially
+/// verified updates are rejected.
+#[derive(Debug, PartialEq)]
+pub struct PythPriceUpdate {
+    /// Pyth feed the price is for
+    pub feed_id: [u8; 32],
+    /// Price, scaled by 10^`exponent`
+    pub price: i64,
+    /// Confidence interval around the price, scaled by 10^`exponent`
+    pub conf: u64,
+    /// Decimal exponent of `price` and `conf`
+    pub exponent: i32,
+    /// Unix timestamp the price was published at
+    pub publish_time: i64,
+}
+
+impl PythPriceUpdate {
+    /// Anchor discriminator of `PriceUpdateV2`
+    pub const DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+    /// `VerificationLevel::Full`
+    const FULLY_VERIFIED: u8 = 1;
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        // discriminator(8) + write_authority(32) + verification_level(1),
+        // then the price message
+        let message = data.get(41..101).ok_or(LocksmithError::InvalidPriceFeed)?;
+        if data[0..8] != Self::DISCRIMINATOR || data[40] != Self::FULLY_VERIFIED {
+            return Err(LocksmithError::InvalidPriceFeed.into());
+        }
+        Ok(Self {
+            feed_id: message[0..32].try_into().unwrap(),
+            price: i64::from_le_bytes(message[32..40].try_into().unwrap()),
+            conf: u64::from_le_bytes(message[40..48].try_into().unwrap()),
+            exponent: i32::from_le_bytes(message[48..52].try_into().unwrap()),
+            publish_time: i64::from_le_bytes(message[52..60].try_into().unwrap()),
+        })
+    }
+
+    /// Whether the price is at least `threshold` × 10^`exponent`, or `None`
+    /// if comparing them overflows.
+    pub fn at_least(&self, threshold: i64, exponent: i32) -> Option<bool> {
+        let (price, threshold) = (i128::from(self.price), i128::from(threshold));
+        let shift = self.exponent.checked_sub(exponent)?;
+        let scale = 10i128.checked_pow(shift.unsigned_abs())?;
+        Some(if shift >= 0 {
+            price.checked_mul(scale)? >= threshold
+        } else {
+            price >= threshold.checked_mul(scale)?
+        })
+    }
+
+    /// Whether the confidence interval is within `MAX_PRICE_CONFIDENCE_BPS`
+    /// of a positive price.
+    pub fn confident(&self) -> bool {
+        self.price > 0
+            && u128::from(self.conf) * u128::from(BPS_DENOMINATOR)
+                <= self.price as u128 * u128::from(MAX_PRICE_CONFIDENCE_BPS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::pubkey::Pubkey;
+
+    #[test]
+    fn test_config_account_pack_unpack_roundtrip() {
+        let config = ConfigAccount {
+            discriminator: ConfigAccount::DISCRIMINATOR,
+            admin: Pubkey::new_unique(),
+            bump: 255,
+            compliance_authority: Pubkey::new_unique(),
+            pending_compliance_authority: Pubkey::new_unique(),
+            compliance_authority_effective_at: 1_700_172_800,
+            fee_mint: Pubkey::new_unique(),
+            pending_fee_mint: Pubkey::new_unique(),
+            fee_mint_effective_at: 1_700_777_600,
+            successor_program: Pubkey::new_unique(),
+            sunset_at: 1_700_000_000,
+            early_unlock_penalty_bps: 0,
+            batch_fee_per_lock: false,
+            paused: false,
+            mint_policy_mode: MINT_POLICY_NONE,
+            reject_freezable_mints: false,
+            fee_usdc: FEE_USDC,
+            fee_bps: 0,
+            fee_lamports: 0,
+            fee_tiered: false,
+            max_locks_per_owner: 0,
+        };
+
+        let mut buffer = vec![0u8; ConfigAccount::SIZE];
+        config.pack(&mut buffer);
+
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert_eq!(config, unpacked);
+    }
+
+    #[test]
+    fn test_config_account_unpack_insufficient_size() {
+        let data = vec![0u8; ConfigAccount::LEGACY_SIZE - 1];
+        let result = ConfigAccount::unpack(&data);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::Custom(LocksmithError::UninitializedAccount as u32)
+        );
+    }
+
+    #[test]
+    fn test_config_account_unpack_wrong_discriminator() {
+        let mut data = vec![0u8; ConfigAccount::SIZE];
+        data[0..8].copy_from_slice(b"WRONGDIS");
+
+        let result = ConfigAccount::unpack(&data);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::Custom(LocksmithError::UninitializedAccount as u32)
+        );
+    }
+
+    #[test]
+    fn test_lock_account_pack_unpack_roundtrip() {
+        let lock = LockAccount {
+            discriminator: LockAccount::DISCRIMINATOR,
+            owner: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 1_000_000_000,
+            unlock_timestamp: 1700000000,
+            created_at: 1699000000,
+            lock_id: 42,
+            bump: 254,
+            frozen: true,
+            credential_issuer: Pubkey::new_unique(),
+            non_transferable: true,
+            forward_destination: Pubkey::new_unique(),
+            hook: Pubkey::new_unique(),
+            recovery_address: Pubkey::new_unique(),
+            donation_address: Pubkey::new_unique(),
+            donation_bps: 250,
+            collateral_holder: Pubkey::new_unique(),
+            layout_version: 0,
+            unlock_slot: 0,
+            unlock_epoch: 0,
+            claim_key: [0u8; 32],
+            claim_expires_at: 0,
+            index_seq: 0,
+            early_unlock_allowed: false,
+            permanent: false,
+            is_nft: false,
+            receipt_bound: false,
+            label: [0u8; 32],
+            metadata_uri: [0u8; METADATA_URI_LEN],
+            fallback_owner: Pubkey::default(),
+            fallback_after: 0,
+            unlock_destination: Pubkey::default(),
+            shared: false,
+            approvals_required: false,
+            price_feed_id: [0u8; 32],
+            price_threshold: 0,
+            price_exponent: 0,
+            arbiter: Pubkey::default(),
+            milestone_approved: false,
+            counterparty: Pubkey::default(),
+            owner_counted: false,
+            wrapped_lamports: false,
+            receipt_mint: Pubkey::default(),
+            fee_mode: LOCK_FEE_FLAT,
+            reserved: [0u8; 29],
+        };
+
+        let mut buffer = vec![0u8; LockAccount::SIZE];
+        lock.pack(&mut buffer);
+
+        let unpacked = LockAccount::unpack(&buffer).unwrap();
+        assert_eq!(lock, unpacked);
+    }
+
+    #[test]
+    fn test_lock_account_unpack_insufficient_size() {
+        let data = vec![0u8; LockAccount::LEGACY_SIZE - 1];
+        let result = LockAccount::unpack(&data);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::Custom(LocksmithError::UninitializedAccount as u32)
+        );
+    }
+
+    #[test]
+    fn test_lock_account_unpack_wrong_discriminator() {
+        let mut data = vec![0u8; LockAccount::SIZE];
+        data[0..8].copy_from_slice(b"WRONGDIS");
+
+        let result = LockAccount::unpack(&data);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::Custom(LocksmithError::UninitializedAccount as u32)
+        );
+    }
+
+    #[test]
+    fn test_lock_account_rejects_config_discriminator() {
+        let mut data = vec![0u8; LockAccount::SIZE];
+        data[0..8].copy_from_slice(&ConfigAccount::DISCRIMINATOR);
+
+        assert!(LockAccount::unpack(&data).is_err());
+    }
+
+    #[test]
+    fn test_discriminators_are_unique() {
+        assert_ne!(ConfigAccount::DISCRIMINATOR, LockAccount::DISCRIMINATOR);
+        assert_ne!(TemplateAccount::DISCRIMINATOR, ConfigAccount::DISCRIMINATOR);
+        assert_ne!(TemplateAccount::DISCRIMINATOR, LockAccount::DISCRIMINATOR);
+        assert_ne!(StandingOrderAccount::DISCRIMINATOR, ConfigAccount::DISCRIMINATOR);
+        assert_ne!(StandingOrderAccount::DISCRIMINATOR, LockAccount::DISCRIMINATOR);
+        assert_ne!(StandingOrderAccount::DISCRIMINATOR, TemplateAccount::DISCRIMINATOR);
+        let discriminators = [
+            ConfigAccount::DISCRIMINATOR,
+            LockAccount::DISCRIMINATOR,
+            TemplateAccount::DISCRIMINATOR,
+            StandingOrderAccount::DISCRIMINATOR,
+        ];
+        assert!(!discriminators.contains(&ForwardingDestinationAccount::DISCRIMINATOR));
+        assert!(!discriminators.contains(&HookAccount::DISCRIMINATOR));
+        assert!(!discriminators.contains(&LenderAccount::DISCRIMINATOR));
+        assert!(!discriminators.contains(&SessionAccount::DISCRIMINATOR));
+        assert!(!discriminators.contains(&NoteAccount::DISCRIMINATOR));
+        assert!(!discriminators.contains(&MintIndexAccount::DISCRIMINATOR));
+        assert!(!discriminators.contains(&IndexEntryAccount::DISCRIMINATOR));
+        assert_ne!(HookAccount::DISCRIMINATOR, ForwardingDestinationAccount::DISCRIMINATOR);
+    }
+
+    #[test]
+    fn test_forwarding_destination_account_pack_unpack_roundtrip() {
+        let destination = ForwardingDestinationAccount {
+            discriminator: ForwardingDestinationAccount::DISCRIMINATOR,
+            program: Pubkey::new_unique(),
+            instruction_prefix: [9, 0, 0, 0, 0, 0, 0, 0],
+            prefix_len: 1,
+            bump: 251,
+        };
+
+        let mut buffer = vec![0u8; ForwardingDestinationAccount::SIZE];
+        destination.pack(&mut buffer);
+
+        let unpacked = ForwardingDestinationAccount::unpack(&buffer).unwrap();
+        assert_eq!(unpacked, destination);
+        assert_eq!(unpacked.prefix(), &[9]);
+    }
+
+    #[test]
+    fn test_hook_account_pack_unpack_roundtrip() {
+        let hook = HookAccount {
+            discriminator: HookAccount::DISCRIMINATOR,
+            program: Pubkey::new_unique(),
+            instruction_prefix: [1, 2, 3, 4, 5, 6, 7, 8],
+            prefix_len: 8,
+            events: HOOK_EVENT_CREATED | HOOK_EVENT_UNLOCKED,
+            compute_allowance: MAX_HOOK_COMPUTE_UNITS,
+            bump: 250,
+        };
+
+        let mut buffer = vec![0u8; HookAccount::SIZE];
+        hook.pack(&mut buffer);
+
+        assert_eq!(HookAccount::unpack(&buffer).unwrap(), hook);
+        assert_eq!(u32::from_le_bytes(buffer[50..54].try_into().unwrap()), 50_000);
+        assert!(HookAccount::unpack(&buffer[..HookAccount::SIZE - 1]).is_err());
+    }
+
+    #[test]
+    fn test_lender_account_pack_unpack_roundtrip() {
+        let lender = LenderAccount {
+            discriminator: LenderAccount::DISCRIMINATOR,
+            authority: Pubkey::new_unique(),
+            bump: 249,
+        };
+
+        let mut buffer = vec![0u8; LenderAccount::SIZE];
+        lender.pack(&mut buffer);
+
+        assert_eq!(LenderAccount::unpack(&buffer).unwrap(), lender);
+    }
+
+    #[test]
+    fn test_note_account_pack_unpack_roundtrip() {
+        let mut note = [0u8; MAX_NOTE_LEN];
+        note[..5].copy_from_slice(b"\x01\x02\x03\x04\x05");
+        note[MAX_NOTE_LEN - 1] = 9;
+        let note = NoteAccount {
+            discriminator: NoteAccount::DISCRIMINATOR,
+            lock: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            note_len: MAX_NOTE_LEN as u16,
+            note,
+            bump: 247,
+        };
+
+        let mut buffer = vec![0u8; NoteAccount::SIZE];
+        note.pack(&mut buffer);
+
+        assert_eq!(NoteAccount::SIZE, 331);
+        assert_eq!(NoteAccount::unpack(&buffer).unwrap(), note);
+    }
+
+    #[test]
+    fn test_mint_index_accounts_pack_unpack_roundtrip() {
+        let index = MintIndexAccount {
+            discriminator: MintIndexAccount::DISCRIMINATOR,
+            mint: Pubkey::new_unique(),
+            total_locks: 1_000_000,
+            active_locks: 999_000,
+            bump: 253,
+        };
+        let mut buffer = vec![0u8; MintIndexAccount::SIZE];
+        index.pack(&mut buffer);
+        assert_eq!(MintIndexAccount::unpack(&buffer).unwrap(), index);
+
+        let entry = IndexEntryAccount {
+            discriminator: IndexEntryAccount::DISCRIMINATOR,
+            mint: index.mint,
+            lock: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+            seq: 1_000_000,
+            bump: 252,
+        };
+        let mut buffer = vec![0u8; IndexEntryAccount::SIZE];
+        entry.pack(&mut buffer);
+        assert_eq!(IndexEntryAccount::unpack(&buffer).unwrap(), entry);
+    }
+
+    #[test]
+    fn test_lock_certificate_account_pack_unpack_roundtrip() {
+        let certificate = LockCertificateAccount {
+            discriminator: LockCertificateAccount::DISCRIMINATOR,
+            lock: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 1_000_000_000,
+            unlock_timestamp: 1_800_000_000,
+            unlock_slot: 400_000_000,
+            unlock_epoch: 900,
+            published_at: 1_700_000_000,
+            payer: Pubkey::new_unique(),
+            bump: 251,
+        };
+
+        let mut buffer = vec![0u8; LockCertificateAccount::SIZE];
+        certificate.pack(&mut buffer);
+
+        assert_eq!(LockCertificateAccount::SIZE, 177);
+        assert_eq!(LockCertificateAccount::unpack(&buffer).unwrap(), certificate);
+    }
+
+    fn lock_shares(share_bps: [u16; 3]) -> LockSharesAccount {
+        let mut shares = LockSharesAccount {
+            discriminator: LockSharesAccount::DISCRIMINATOR,
+            lock: Pubkey::new_unique(),
+            total: 1_000,
+            beneficiaries: [Pubkey::default(); MAX_BENEFICIARIES],
+            share_bps: [0; MAX_BENEFICIARIES],
+            claimed: 0,
+            bump: 249,
+        };
+        for (i, bps) in share_bps.into_iter().enumerate() {
+            shares.beneficiaries[i] = Pubkey::new_unique();
+            shares.share_bps[i] = bps;
+        }
+        shares
+    }
+
+    #[test]
+    fn test_lock_shares_account_pack_unpack_roundtrip() {
+        let mut shares = lock_shares([5_000, 3_000, 2_000]);
+        shares.claimed = 0b101;
+
+        let mut buffer = vec![0u8; LockSharesAccount::SIZE];
+        shares.pack(&mut buffer);
+
+        assert_eq!(LockSharesAccount::SIZE, 322);
+        assert_eq!(&buffer[48..80], shares.beneficiaries[0].as_ref());
+        assert_eq!(&buffer[304..306], &5_000u16.to_le_bytes());
+        assert_eq!(LockSharesAccount::unpack(&buffer).unwrap(), shares);
+    }
+
+    #[test]
+    fn test_unlock_approvals_account() {
+        let mut approvals = UnlockApprovalsAccount {
+            discriminator: UnlockApprovalsAccount::DISCRIMINATOR,
+            lock: Pubkey::new_unique(),
+            approvers: [Pubkey::default(); MAX_APPROVERS],
+            threshold: 2,
+            approvals: 0,
+            bump: 248,
+        };
+        for approver in &mut approvals.approvers[..3] {
+            *approver = Pubkey::new_unique();
+        }
+        assert_eq!(approvals.approver_index(&approvals.approvers[2]), Some(2));
+        assert_eq!(approvals.approver_index(&Pubkey::new_unique()), None);
+        assert_eq!(approvals.approver_index(&Pubkey::default()), None);
+
+        assert!(!approvals.approved());
+        approvals.approvals = 0b100;
+        assert!(!approvals.approved());
+        approvals.approvals = 0b101;
+        assert!(approvals.approved());
+
+        let mut buffer = vec![0u8; UnlockApprovalsAccount::SIZE];
+        approvals.pack(&mut buffer);
+
+        assert_eq!(UnlockApprovalsAccount::SIZE, 299);
+        assert_eq!(&buffer[72..104], approvals.approvers[1].as_ref());
+        assert_eq!(UnlockApprovalsAccount::unpack(&buffer).unwrap(), approvals);
+    }
+
+    #[test]
+    fn test_vote_weight_snapshot_account_pack_unpack_roundtrip() {
+        let snapshot = VoteWeightSnapshotAccount {
+            discriminator: VoteWeightSnapshotAccount::DISCRIMINATOR,
+            lock: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            weight: 250_000_000,
+            slot: 400_000_000,
+            snapshot_at: 1_700_000_000,
+            payer: Pubkey::new_unique(),
+            bump: 247,
+        };
+
+        let mut buffer = vec![0u8; VoteWeightSnapshotAccount::SIZE];
+        snapshot.pack(&mut buffer);
+
+        assert_eq!(VoteWeightSnapshotAccount::SIZE, 161);
+        assert_eq!(VoteWeightSnapshotAccount::unpack(&buffer).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn test_mint_policy_account_pack_unpack_roundtrip() {
+        let policy = MintPolicyAccount {
+            discriminator: MintPolicyAccount::DISCRIMINATOR,
+            mint: Pubkey::new_unique(),
+            allowed: true,
+            bump: 246,
+        };
+
+        let mut buffer = vec![0u8; MintPolicyAccount::SIZE];
+        policy.pack(&mut buffer);
+
+        assert_eq!(MintPolicyAccount::SIZE, 42);
+        assert_eq!(MintPolicyAccount::unpack(&buffer).unwrap(), policy);
+    }
+
+    #[test]
+    fn test_fee_cap_account_pack_unpack_roundtrip() {
+        let fee_cap = FeeCapAccount {
+            discriminator: FeeCapAccount::DISCRIMINATOR,
+            mint: Pubkey::new_unique(),
+            cap: 0x0102030405060708,
+            bump: 249,
+        };
+
+        let mut buffer = vec![0u8; FeeCapAccount::SIZE];
+        fee_cap.pack(&mut buffer);
+
+        assert_eq!(FeeCapAccount::SIZE, 49);
+        assert_eq!(FeeCapAccount::unpack(&buffer).unwrap(), fee_cap);
+        assert!(MintPolicyAccount::unpack(&buffer).is_err());
+    }
+
+    #[test]
+    fn test_fee_exemption_account_pack_unpack_roundtrip() {
+        let exemption = FeeExemptionAccount {
+            discriminator: FeeExemptionAccount::DISCRIMINATOR,
+            wallet: Pubkey::new_unique(),
+            bump: 251,
+        };
+
+        let mut buffer = vec![0u8; FeeExemptionAccount::SIZE];
+        exemption.pack(&mut buffer);
+
+        assert_eq!(FeeExemptionAccount::SIZE, 41);
+        assert_eq!(FeeExemptionAccount::unpack(&buffer).unwrap(), exemption);
+        assert!(MintPolicyAccount::unpack(&buffer).is_err());
+    }
+
+    #[test]
+    fn test_owner_counter_account_pack_unpack_roundtrip() {
+        let mut counter = OwnerCounterAccount {
+            discriminator: OwnerCounterAccount::DISCRIMINATOR,
+            owner: Pubkey::new_unique(),
+            active_locks: 0x01020304,
+            lock_cap: 0,
+            bump: 252,
+        };
+
+        let mut buffer = vec![0u8; OwnerCounterAccount::SIZE];
+        counter.pack(&mut buffer);
+
+        assert_eq!(OwnerCounterAccount::SIZE, 49);
+        assert_eq!(OwnerCounterAccount::unpack(&buffer).unwrap(), counter);
+        assert!(MintIndexAccount::unpack(&buffer).is_err());
+
+        let mut config = ConfigAccount::unpack(&{
+            let mut data = vec![0u8; ConfigAccount::SIZE];
+            data[0..8].copy_from_slice(&ConfigAccount::DISCRIMINATOR);
+            data
+        })
+        .unwrap();
+        config.max_locks_per_owner = 10;
+        assert_eq!(counter.cap(&config), 10);
+        counter.lock_cap = 500;
+        assert_eq!(counter.cap(&config), 500);
+    }
+
+    #[test]
+    fn test_fee_schedule_account_pack_unpack_roundtrip() {
+        let schedule = FeeScheduleAccount {
+            discriminator: FeeScheduleAccount::DISCRIMINATOR,
+            tier_count: 3,
+            min_durations: [0, 0, 63_072_000, 0, 0, 0, 0, 7],
+            min_amounts: [0, 1_000_000, 1_000_000, 0, 0, 0, 0, 8],
+            fees_usdc: [150_000, 500_000, 250_000, 0, 0, 0, 0, 9],
+            bump: 250,
+        };
+
+        let mut buffer = vec![0u8; FeeScheduleAccount::SIZE];
+        schedule.pack(&mut buffer);
+
+        assert_eq!(FeeScheduleAccount::SIZE, 202);
+        assert_eq!(FeeScheduleAccount::unpack(&buffer).unwrap(), schedule);
+
+        // The last tier met wins; unused tiers are ignored
+        assert_eq!(schedule.fee_for(86_400, 10), Some(150_000));
+        assert_eq!(schedule.fee_for(86_400, 1_000_000), Some(500_000));
+        assert_eq!(schedule.fee_for(u64::MAX, 1_000_000), Some(250_000));
+        assert_eq!(schedule.fee_for(u64::MAX, 999_999), Some(150_000));
+
+        let empty = FeeScheduleAccount {
+            tier_count: 0,
+            ..schedule
+        };
+        assert_eq!(empty.fee_for(u64::MAX, u64::MAX), None);
+    }
+
+    #[test]
+    fn test_lock_proof_account_pack_unpack_roundtrip() {
+        let proof = LockProofAccount {
+            discriminator: LockProofAccount::DISCRIMINATOR,
+            lock: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 950_000,
+            supply: 1_000_000,
+            locked_bps: 9_500,
+            mint_authority_revoked: true,
+            freeze_authority_revoked: false,
+            unlock_timestamp: 1_800_000_000,
+            permanent: true,
+            slot: 400_000_000,
+            recorded_at: 1_700_000_000,
+            payer: Pubkey::new_unique(),
+            bump: 245,
+        };
+
+        let mut buffer = vec![0u8; LockProofAccount::SIZE];
+        proof.pack(&mut buffer);
+
+        assert_eq!(LockProofAccount::SIZE, 150);
+        assert_eq!(LockProofAccount::unpack(&buffer).unwrap(), proof);
+    }
+
+    #[test]
+    fn test_lock_proof_locked_bps_of() {
+        assert_eq!(LockProofAccount::locked_bps_of(950_000, 1_000_000), 9_500);
+        assert_eq!(LockProofAccount::locked_bps_of(1, 3), 3_333);
+        assert_eq!(LockProofAccount::locked_bps_of(u64::MAX, u64::MAX), 10_000);
+        assert_eq!(LockProofAccount::locked_bps_of(5, 0), 0);
+    }
+
+    #[test]
+    fn test_pyth_price_update_unpack() {
+        let mut data = vec![0u8; 134];
+        data[0..8].copy_from_slice(&PythPriceUpdate::DISCRIMINATOR);
+        data[40] = 1;
+        data[41..73].copy_from_slice(&[7u8; 32]);
+        data[73..81].copy_from_slice(&150_000_000i64.to_le_bytes());
+        data[81..89].copy_from_slice(&1_000_000u64.to_le_bytes());
+        data[89..93].copy_from_slice(&(-8i32).to_le_bytes());
+        data[93..101].copy_from_slice(&1_700_000_000i64.to_le_bytes());
+        let update = PythPriceUpdate::unpack(&data).unwrap();
+        assert_eq!(
+            update,
+            PythPriceUpdate {
+                feed_id: [7u8; 32],
+                price: 150_000_000,
+                conf: 1_000_000,
+                exponent: -8,
+                publish_time: 1_700_000_000,
+            }
+        );
+
+        // $1.50 against thresholds at other exponents
+        assert_eq!(update.at_least(150, -2), Some(true));
+        assert_eq!(update.at_least(151, -2), Some(false));
+        assert_eq!(update.at_least(1, 0), Some(true));
+        assert_eq!(update.at_least(2, 0), Some(false));
+        assert_eq!(update.at_least(1_500_000_000, -9), Some(true));
+        assert_eq!(update.at_least(1_500_000_001, -9), Some(false));
+        assert_eq!(update.at_least(1, i32::MIN), None);
+
+        // 1,000,000 of 150,000,000 is within 2%; 4,000,000 is not
+        assert!(update.confident());
+        assert!(!PythPriceUpdate { conf: 4_000_000, ..update }.confident());
+        assert!(!PythPriceUpdate { price: 0, conf: 0, ..update }.confident());
+
+        // Partially verified updates and other accounts are rejected
+        let invalid = ProgramError::Custom(LocksmithError::InvalidPriceFeed as u32);
+        data[40] = 0;
+        assert_eq!(PythPriceUpdate::unpack(&data).unwrap_err(), invalid);
+        data[40] = 1;
+        data[0] = 0;
+        assert_eq!(PythPriceUpdate::unpack(&data).unwrap_err(), invalid);
+        assert_eq!(PythPriceUpdate::unpack(&data[..100]).unwrap_err(), invalid);
+    }
+
+    #[test]
+    fn test_lock_shares_payout() {
+        let mut shares = lock_shares([3_333, 3_333, 3_334]);
+        let beneficiary = shares.beneficiaries[1];
+        assert_eq!(shares.beneficiary_index(&beneficiary), Some(1));
+        assert_eq!(shares.beneficiary_index(&Pubkey::new_unique()), None);
+        assert_eq!(shares.beneficiary_index(&Pubkey::default()), None);
+
+        // Shares round down until the last claim, which takes the dust
+        assert_eq!(shares.payout(0, 1_000), 333);
+        shares.claimed |= 1;
+        assert_eq!(shares.payout(2, 667), 333);
+        shares.claimed |= 1 << 2;
+        assert!(!shares.all_claimed());
+        assert_eq!(shares.payout(1, 334), 334);
+        shares.claimed |= 1 << 1;
+        assert!(shares.all_claimed());
+    }
+
+    fn vesting_lock(total: u64, start: i64, end: i64) -> VestingLockAccount {
+        VestingLockAccount {
+            discriminator: VestingLockAccount::DISCRIMINATOR,
+            owner: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            total,
+            claimed: 0,
+            start,
+            cliff: start,
+            end,
+            created_at: start,
+            vesting_id: 3,
+            bump: 250,
+            grantor: Pubkey::new_unique(),
+            revocable: true,
+        }
+    }
+
+    #[test]
+    fn test_vesting_lock_account_pack_unpack_roundtrip() {
+        let mut vesting = vesting_lock(1_000_000, 1_700_000_000, 1_800_000_000);
+        vesting.claimed = 250_000;
+        vesting.cliff = 1_725_000_000;
+
+        let mut buffer = vec![0u8; VestingLockAccount::SIZE];
+        vesting.pack(&mut buffer);
+
+        assert_eq!(VestingLockAccount::SIZE, 162);
+        assert_eq!(VestingLockAccount::unpack(&buffer).unwrap(), vesting);
+    }
+
+    #[test]
+    fn test_vesting_lock_vests_linearly() {
+        let vesting = vesting_lock(1_000, 1_000, 2_000);
+
+        assert_eq!(vesting.vested_at(0), 0);
+        assert_eq!(vesting.vested_at(1_000), 0);
+        assert_eq!(vesting.vested_at(1_001), 1);
+        assert_eq!(vesting.vested_at(1_500), 500);
+        assert_eq!(vesting.vested_at(1_999), 999);
+        assert_eq!(vesting.vested_at(2_000), 1_000);
+        assert_eq!(vesting.vested_at(i64::MAX), 1_000);
+
+        // No overflow for large totals
+        let vesting = vesting_lock(u64::MAX, 0, 4);
+        assert_eq!(vesting.vested_at(1), u64::MAX / 4);
+    }
+
+    #[test]
+    fn test_vesting_lock_revoke() {
+        let mut vesting = vesting_lock(1_000, 1_000, 2_000);
+        vesting.claimed = 100;
+        assert_eq!(vesting.revoke(1_400), 600);
+        assert_eq!(vesting.total, 400);
+        assert!(!vesting.revocable);
+        // The vested portion stays claimable, and nothing more vests
+        assert_eq!(vesting.vested_at(1_400), 400);
+        assert_eq!(vesting.vested_at(3_000), 400);
+
+        // Before the cliff everything is clawed back
+        let mut vesting = vesting_lock(1_000, 1_000, 2_000);
+        vesting.cliff = 1_500;
+        assert_eq!(vesting.revoke(1_400), 1_000);
+        assert_eq!(vesting.total, 0);
+        assert_eq!(vesting.vested_at(3_000), 0);
+
+        // Before the start too
+        let mut vesting = vesting_lock(1_000, 1_000, 2_000);
+        assert_eq!(vesting.revoke(500), 1_000);
+        assert_eq!(vesting.vested_at(3_000), 0);
+    }
+
+    #[test]
+    fn test_vesting_lock_cliff() {
+        let mut vesting = vesting_lock(1_000, 1_000, 2_000);
+        vesting.cliff = 1_250;
+        assert_eq!(vesting.vested_at(1_249), 0);
+        assert_eq!(vesting.vested_at(1_250), 250);
+        assert_eq!(vesting.vested_at(1_500), 500);
+        assert_eq!(vesting.vested_at(2_000), 1_000);
+
+        // A cliff at the end is a hard lock
+        vesting.cliff = 2_000;
+        assert_eq!(vesting.vested_at(1_999), 0);
+        assert_eq!(vesting.vested_at(2_000), 1_000);
+
+        // A cliff at the start is purely linear
+        vesting.cliff = 1_000;
+        assert_eq!(vesting.vested_at(1_000), 0);
+        assert_eq!(vesting.vested_at(1_001), 1);
+        assert_eq!(vesting.vested_at(1_500), 500);
+    }
+
+    #[test]
+    fn test_session_account_pack_unpack_roundtrip() {
+        let session = SessionAccount {
+            discriminator: SessionAccount::DISCRIMINATOR,
+            owner: Pubkey::new_unique(),
+            session_key: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            destination: Pubkey::new_unique(),
+            expires_at: 1_700_000_000,
+            bump: 248,
+        };
+
+        let mut buffer = vec![0u8; SessionAccount::SIZE];
+        session.pack(&mut buffer);
+
+        assert_eq!(SessionAccount::unpack(&buffer).unwrap(), session);
+    }
+
+    #[test]
+    fn test_standing_order_account_pack_unpack_roundtrip() {
+        let order = StandingOrderAccount {
+            discriminator: StandingOrderAccount::DISCRIMINATOR,
+            owner: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            owner_token_account: Pubkey::new_unique(),
+            owner_usdc_account: Pubkey::new_unique(),
+            order_id: 1,
+            amount_per_lock: 100_000_000,
+            interval_seconds: 30 * 24 * 60 * 60,
+            lock_duration_seconds: 365 * 24 * 60 * 60,
+            next_execution_at: 1_700_000_000,
+            next_lock_id: 1_000,
+            remaining_executions: 12,
+            bump: 252,
+            fee_usdc: 400_000,
+        };
+
+        let mut buffer = vec![0u8; StandingOrderAccount::SIZE];
+        order.pack(&mut buffer);
+
+        assert_eq!(&buffer[0..8], b"STORDER\0");
+        assert_eq!(u32::from_le_bytes(buffer[184..188].try_into().unwrap()), 12);
+        assert_eq!(buffer[188], 252);
+        assert_eq!(u64::from_le_bytes(buffer[189..197].try_into().unwrap()), 400_000);
+        assert_eq!(StandingOrderAccount::unpack(&buffer).unwrap(), order);
+
+        // Orders from before `fee_usdc` approved the default fee
+        let mut legacy = vec![0u8; StandingOrderAccount::LEGACY_SIZE];
+        order.pack(&mut legacy);
+        assert_eq!(
+            StandingOrderAccount::unpack(&legacy).unwrap(),
+            StandingOrderAccount {
+                fee_usdc: FEE_USDC,
+                ..order
+            }
+        );
+        assert!(StandingOrderAccount::unpack(&legacy[..188]).is_err());
+    }
+
+    #[test]
+    fn test_template_account_pack_unpack_roundtrip() {
+        let template = TemplateAccount {
+            discriminator: TemplateAccount::DISCRIMINATOR,
+            template_id: 3,
+            duration_seconds: 365 * 24 * 60 * 60,
+            fee_usdc: 0,
+            credential_issuer: Pubkey::new_unique(),
+            non_transferable: true,
+            bump: 253,
+        };
+
+        let mut buffer = vec![0u8; TemplateAccount::SIZE];
+        template.pack(&mut buffer);
+
+        assert_eq!(&buffer[0..8], b"TEMPLATE");
+        assert_eq!(TemplateAccount::unpack(&buffer).unwrap(), template);
+        assert!(TemplateAccount::unpack(&buffer[..TemplateAccount::SIZE - 1]).is_err());
+    }
+
+    #[test]
+    fn test_config_account_byte_layout() {
+        let admin_bytes: [u8; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let config = ConfigAccount {
             discriminator: ConfigAccount::DISCRIMINATOR,
             admin: Pubkey::from(admin_bytes),
             bump: 200,
+            compliance_authority: Pubkey::from([3u8; 32]),
+            pending_compliance_authority: Pubkey::from([4u8; 32]),
+            compliance_authority_effective_at: 0x0102030405060708,
+            fee_mint: Pubkey::from([5u8; 32]),
+            pending_fee_mint: Pubkey::from([6u8; 32]),
+            fee_mint_effective_at: 0x0807060504030201,
+            successor_program: Pubkey::from([7u8; 32]),
+            sunset_at: 0x0102030405060708,
+            early_unlock_penalty_bps: 0x0102,
+            batch_fee_per_lock: true,
+            paused: false,
+            mint_policy_mode: MINT_POLICY_NONE,
+            reject_freezable_mints: false,
+            fee_usdc: 0x1112131415161718,
+            fee_bps: 0x0304,
+            fee_lamports: 0x3132333435363738,
+            fee_tiered: true,
+            max_locks_per_owner: 0,
         };
 
         let mut buffer = vec![0u8; ConfigAccount::SIZE];
@@ -240,6 +3098,206 @@ mod tests {
         assert_eq!(&buffer[0..8], b"CONFIG\0\0");
         assert_eq!(&buffer[8..40], &admin_bytes);
         assert_eq!(buffer[40], 200);
+        assert_eq!(&buffer[41..73], &[3u8; 32]);
+        assert_eq!(&buffer[73..105], &[4u8; 32]);
+        assert_eq!(i64::from_le_bytes(buffer[105..113].try_into().unwrap()), 0x0102030405060708);
+        assert_eq!(&buffer[113..145], &[5u8; 32]);
+        assert_eq!(&buffer[145..177], &[6u8; 32]);
+        assert_eq!(i64::from_le_bytes(buffer[177..185].try_into().unwrap()), 0x0807060504030201);
+        assert_eq!(&buffer[185..217], &[7u8; 32]);
+        assert_eq!(i64::from_le_bytes(buffer[217..225].try_into().unwrap()), 0x0102030405060708);
+        assert_eq!(u16::from_le_bytes(buffer[225..227].try_into().unwrap()), 0x0102);
+        assert_eq!(buffer[227], 1);
+        assert_eq!(u64::from_le_bytes(buffer[231..239].try_into().unwrap()), 0x1112131415161718);
+        assert_eq!(u16::from_le_bytes(buffer[239..241].try_into().unwrap()), 0x0304);
+        assert_eq!(u64::from_le_bytes(buffer[241..249].try_into().unwrap()), 0x3132333435363738);
+        assert_eq!(buffer[249], 1);
+    }
+
+    #[test]
+    fn test_legacy_config_account_unpacks_without_compliance_authority() {
+        let config = ConfigAccount {
+            discriminator: ConfigAccount::DISCRIMINATOR,
+            admin: Pubkey::new_unique(),
+            bump: 255,
+            compliance_authority: Pubkey::new_unique(),
+            pending_compliance_authority: Pubkey::new_unique(),
+            compliance_authority_effective_at: 1,
+            fee_mint: Pubkey::default(),
+            pending_fee_mint: Pubkey::default(),
+            fee_mint_effective_at: 0,
+            successor_program: Pubkey::default(),
+            sunset_at: 0,
+            early_unlock_penalty_bps: 0,
+            batch_fee_per_lock: false,
+            paused: false,
+            mint_policy_mode: MINT_POLICY_NONE,
+            reject_freezable_mints: false,
+            fee_usdc: FEE_USDC,
+            fee_bps: 0,
+            fee_lamports: 0,
+            fee_tiered: false,
+            max_locks_per_owner: 0,
+        };
+
+        // Packing into a legacy-sized buffer drops the compliance fields
+        let mut buffer = vec![0u8; ConfigAccount::LEGACY_SIZE];
+        config.pack(&mut buffer);
+
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert_eq!(unpacked.admin, config.admin);
+        assert_eq!(unpacked.bump, 255);
+        assert_eq!(unpacked.compliance_authority, Pubkey::default());
+        assert_eq!(unpacked.compliance_authority_at(i64::MAX), None);
+    }
+
+    #[test]
+    fn test_pre_fee_mint_config_account_charges_usdc() {
+        let config = ConfigAccount {
+            discriminator: ConfigAccount::DISCRIMINATOR,
+            admin: Pubkey::new_unique(),
+            bump: 255,
+            compliance_authority: Pubkey::new_unique(),
+            pending_compliance_authority: Pubkey::default(),
+            compliance_authority_effective_at: 1,
+            fee_mint: Pubkey::new_unique(),
+            pending_fee_mint: Pubkey::new_unique(),
+            fee_mint_effective_at: 1,
+            successor_program: Pubkey::new_unique(),
+            sunset_at: 1_700_000_000,
+            early_unlock_penalty_bps: 250,
+            batch_fee_per_lock: true,
+            paused: true,
+            mint_policy_mode: MINT_POLICY_DENYLIST,
+            reject_freezable_mints: true,
+            fee_usdc: 250_000,
+            fee_bps: 50,
+            fee_lamports: 5_000_000,
+            fee_tiered: true,
+            max_locks_per_owner: 20,
+        };
+
+        let mut buffer = vec![0u8; ConfigAccount::COMPLIANCE_SIZE];
+        config.pack(&mut buffer);
+
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert_eq!(unpacked.compliance_authority, config.compliance_authority);
+        assert_eq!(unpacked.pending_fee_mint, Pubkey::default());
+        assert_eq!(unpacked.current_fee_mint(), USDC_MINT);
+
+        let mut buffer = vec![0u8; ConfigAccount::FEE_MINT_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert_eq!(unpacked.current_fee_mint(), config.fee_mint);
+        assert_eq!(unpacked.successor_program, Pubkey::default());
+
+        let mut buffer = vec![0u8; ConfigAccount::SUCCESSOR_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert_eq!(unpacked.successor_program, config.successor_program);
+        assert_eq!(unpacked.sunset_at, 0);
+
+        let mut buffer = vec![0u8; ConfigAccount::SUNSET_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert_eq!(unpacked.sunset_at, config.sunset_at);
+        assert_eq!(unpacked.early_unlock_penalty_bps, 0);
+
+        let mut buffer = vec![0u8; ConfigAccount::PENALTY_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert_eq!(unpacked.early_unlock_penalty_bps, config.early_unlock_penalty_bps);
+        assert!(!unpacked.batch_fee_per_lock);
+
+        let mut buffer = vec![0u8; ConfigAccount::BATCH_FEE_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert!(unpacked.batch_fee_per_lock);
+        assert!(!unpacked.paused);
+
+        let mut buffer = vec![0u8; ConfigAccount::PAUSE_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert!(unpacked.paused);
+        assert_eq!(unpacked.mint_policy_mode, MINT_POLICY_NONE);
+
+        let mut buffer = vec![0u8; ConfigAccount::MINT_POLICY_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert_eq!(unpacked.mint_policy_mode, config.mint_policy_mode);
+        assert!(!unpacked.reject_freezable_mints);
+
+        let mut buffer = vec![0u8; ConfigAccount::FREEZABLE_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert!(unpacked.reject_freezable_mints);
+        assert_eq!(unpacked.fee_usdc, FEE_USDC);
+
+        let mut buffer = vec![0u8; ConfigAccount::FEE_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert_eq!(unpacked.fee_usdc, config.fee_usdc);
+        assert_eq!(unpacked.fee_bps, 0);
+
+        let mut buffer = vec![0u8; ConfigAccount::BPS_FEE_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert_eq!(unpacked.fee_bps, config.fee_bps);
+        assert_eq!(unpacked.fee_lamports, 0);
+
+        let mut buffer = vec![0u8; ConfigAccount::SOL_FEE_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert_eq!(unpacked.fee_lamports, config.fee_lamports);
+        assert!(!unpacked.fee_tiered);
+
+        let mut buffer = vec![0u8; ConfigAccount::FEE_SCHEDULE_SIZE];
+        config.pack(&mut buffer);
+        let unpacked = ConfigAccount::unpack(&buffer).unwrap();
+        assert!(unpacked.fee_tiered);
+        assert_eq!(unpacked.max_locks_per_owner, 0);
+
+        let mut buffer = vec![0u8; ConfigAccount::SIZE];
+        config.pack(&mut buffer);
+        assert_eq!(ConfigAccount::unpack(&buffer).unwrap(), config);
+    }
+
+    #[test]
+    fn test_compliance_authority_timelock() {
+        let current = Pubkey::new_unique();
+        let pending = Pubkey::new_unique();
+        let mut config = ConfigAccount {
+            discriminator: ConfigAccount::DISCRIMINATOR,
+            admin: Pubkey::new_unique(),
+            bump: 255,
+            compliance_authority: current,
+            pending_compliance_authority: pending,
+            compliance_authority_effective_at: 1_000,
+            fee_mint: Pubkey::default(),
+            pending_fee_mint: Pubkey::default(),
+            fee_mint_effective_at: 0,
+            successor_program: Pubkey::default(),
+            sunset_at: 0,
+            early_unlock_penalty_bps: 0,
+            batch_fee_per_lock: false,
+            paused: false,
+            mint_policy_mode: MINT_POLICY_NONE,
+            reject_freezable_mints: false,
+            fee_usdc: FEE_USDC,
+            fee_bps: 0,
+            fee_lamports: 0,
+            fee_tiered: false,
+            max_locks_per_owner: 0,
+        };
+
+        assert_eq!(config.compliance_authority_at(999), Some(current));
+        assert_eq!(config.compliance_authority_at(1_000), Some(pending));
+
+        config.compliance_authority = Pubkey::default();
+        assert_eq!(config.compliance_authority_at(999), None);
+
+        config.pending_compliance_authority = Pubkey::default();
+        assert_eq!(config.compliance_authority_at(1_000), None);
     }
 
     #[test]
@@ -256,6 +3314,43 @@ mod tests {
             created_at: 0x1112131415161718_u64 as i64,
             lock_id: 0x191A1B1C1D1E1F20,
             bump: 250,
+            frozen: true,
+            credential_issuer: Pubkey::from([3u8; 32]),
+            non_transferable: true,
+            forward_destination: Pubkey::from([4u8; 32]),
+            hook: Pubkey::from([5u8; 32]),
+            recovery_address: Pubkey::from([6u8; 32]),
+            donation_address: Pubkey::from([7u8; 32]),
+            donation_bps: 0x0102,
+            collateral_holder: Pubkey::from([8u8; 32]),
+            layout_version: LockAccount::LAYOUT_VERSION,
+            unlock_slot: 0x2122232425262728,
+            unlock_epoch: 0x292A2B2C2D2E2F30,
+            claim_key: [0x31u8; 32],
+            claim_expires_at: 0x3233343536373839,
+            index_seq: 0x3A3B3C3D,
+            early_unlock_allowed: true,
+            permanent: true,
+            is_nft: true,
+            receipt_bound: true,
+            label: [0x3Eu8; 32],
+            metadata_uri: [0x3Fu8; METADATA_URI_LEN],
+            fallback_owner: Pubkey::from([0x40u8; 32]),
+            fallback_after: 0x4142434445464748,
+            unlock_destination: Pubkey::from([0x49u8; 32]),
+            shared: true,
+            approvals_required: true,
+            price_feed_id: [0x4Au8; 32],
+            price_threshold: 0x4B4C4D4E4F505152,
+            price_exponent: 0x53545556,
+            arbiter: Pubkey::from([0x57u8; 32]),
+            milestone_approved: true,
+            counterparty: Pubkey::from([0x58u8; 32]),
+            owner_counted: true,
+            wrapped_lamports: true,
+            receipt_mint: Pubkey::from([0x5Au8; 32]),
+            fee_mode: LOCK_FEE_TIERED,
+            reserved: [0x59u8; 29],
         };
 
         let mut buffer = vec![0u8; LockAccount::SIZE];
@@ -269,6 +3364,396 @@ mod tests {
         assert_eq!(i64::from_le_bytes(buffer[88..96].try_into().unwrap()), 0x1112131415161718_u64 as i64);
         assert_eq!(u64::from_le_bytes(buffer[96..104].try_into().unwrap()), 0x191A1B1C1D1E1F20);
         assert_eq!(buffer[104], 250);
+        assert_eq!(buffer[105], 1);
+        assert_eq!(&buffer[106..138], &[3u8; 32]);
+        assert_eq!(buffer[138], 1);
+        assert_eq!(&buffer[139..171], &[4u8; 32]);
+        assert_eq!(&buffer[171..203], &[5u8; 32]);
+        assert_eq!(&buffer[203..235], &[6u8; 32]);
+        assert_eq!(&buffer[235..267], &[7u8; 32]);
+        assert_eq!(&buffer[267..269], &[0x02, 0x01]);
+        assert_eq!(&buffer[269..301], &[8u8; 32]);
+        assert_eq!(buffer[301], 2);
+        assert_eq!(u64::from_le_bytes(buffer[302..310].try_into().unwrap()), 0x2122232425262728);
+        assert_eq!(u64::from_le_bytes(buffer[310..318].try_into().unwrap()), 0x292A2B2C2D2E2F30);
+        assert_eq!(&buffer[318..350], &[0x31u8; 32]);
+        assert_eq!(i64::from_le_bytes(buffer[350..358].try_into().unwrap()), 0x3233343536373839);
+        assert_eq!(u32::from_le_bytes(buffer[358..362].try_into().unwrap()), 0x3A3B3C3D);
+        assert_eq!(buffer[362], 1);
+        assert_eq!(buffer[363], 1);
+        assert_eq!(buffer[364], 1);
+        assert_eq!(buffer[365], 1);
+        assert_eq!(&buffer[366..398], &[0x3Eu8; 32]);
+        assert_eq!(&buffer[398..526], &[0x3Fu8; METADATA_URI_LEN]);
+        assert_eq!(&buffer[526..558], &[0x40u8; 32]);
+        assert_eq!(i64::from_le_bytes(buffer[558..566].try_into().unwrap()), 0x4142434445464748);
+        assert_eq!(&buffer[566..598], &[0x49u8; 32]);
+        assert_eq!(buffer[598], 1);
+        assert_eq!(buffer[599], 1);
+        assert_eq!(&buffer[600..632], &[0x4Au8; 32]);
+        assert_eq!(i64::from_le_bytes(buffer[632..640].try_into().unwrap()), 0x4B4C4D4E4F505152);
+        assert_eq!(i32::from_le_bytes(buffer[640..644].try_into().unwrap()), 0x53545556);
+        assert_eq!(&buffer[644..676], &[0x57u8; 32]);
+        assert_eq!(buffer[676], 1);
+        assert_eq!(&buffer[677..709], &[0x58u8; 32]);
+        assert_eq!(buffer[709], 1);
+        assert_eq!(buffer[710], 1);
+        assert_eq!(&buffer[711..743], &[0x5Au8; 32]);
+        assert_eq!(buffer[743], LOCK_FEE_TIERED);
+        assert_eq!(&buffer[744..773], &[0x59u8; 29]);
+    }
+
+    #[test]
+    fn test_legacy_lock_account_unpacks_as_not_frozen() {
+        let lock = LockAccount {
+            discriminator: LockAccount::DISCRIMINATOR,
+            owner: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 1_000,
+            unlock_timestamp: 1700000000,
+            created_at: 1699000000,
+            lock_id: 1,
+            bump: 254,
+            frozen: false,
+            credential_issuer: Pubkey::default(),
+            non_transferable: false,
+            forward_destination: Pubkey::default(),
+            hook: Pubkey::default(),
+            recovery_address: Pubkey::default(),
+            donation_address: Pubkey::default(),
+            donation_bps: 0,
+            collateral_holder: Pubkey::default(),
+            layout_version: 0,
+            unlock_slot: 0,
+            unlock_epoch: 0,
+            claim_key: [0u8; 32],
+            claim_expires_at: 0,
+            index_seq: 0,
+            early_unlock_allowed: false,
+            permanent: false,
+            is_nft: false,
+            receipt_bound: false,
+            label: [0u8; 32],
+            metadata_uri: [0u8; METADATA_URI_LEN],
+            fallback_owner: Pubkey::default(),
+            fallback_after: 0,
+            unlock_destination: Pubkey::default(),
+            shared: false,
+            approvals_required: false,
+            price_feed_id: [0u8; 32],
+            price_threshold: 0,
+            price_exponent: 0,
+            arbiter: Pubkey::default(),
+            milestone_approved: false,
+            counterparty: Pubkey::default(),
+            owner_counted: false,
+            wrapped_lamports: false,
+            receipt_mint: Pubkey::default(),
+            fee_mode: LOCK_FEE_FLAT,
+            reserved: [0u8; 29],
+        };
+
+        let mut buffer = vec![0u8; LockAccount::LEGACY_SIZE];
+        lock.pack(&mut buffer);
+
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+    }
+
+    #[test]
+    fn test_lock_account_unpacks_every_appended_size() {
+        let mut lock = LockAccount {
+            discriminator: LockAccount::DISCRIMINATOR,
+            owner: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 1_000,
+            unlock_timestamp: 1700000000,
+            created_at: 1699000000,
+            lock_id: 1,
+            bump: 254,
+            frozen: true,
+            credential_issuer: Pubkey::default(),
+            non_transferable: false,
+            forward_destination: Pubkey::default(),
+            hook: Pubkey::default(),
+            recovery_address: Pubkey::default(),
+            donation_address: Pubkey::default(),
+            donation_bps: 0,
+            collateral_holder: Pubkey::default(),
+            layout_version: 0,
+            unlock_slot: 0,
+            unlock_epoch: 0,
+            claim_key: [0u8; 32],
+            claim_expires_at: 0,
+            index_seq: 0,
+            early_unlock_allowed: false,
+            permanent: false,
+            is_nft: false,
+            receipt_bound: false,
+            label: [0u8; 32],
+            metadata_uri: [0u8; METADATA_URI_LEN],
+            fallback_owner: Pubkey::default(),
+            fallback_after: 0,
+            unlock_destination: Pubkey::default(),
+            shared: false,
+            approvals_required: false,
+            price_feed_id: [0u8; 32],
+            price_threshold: 0,
+            price_exponent: 0,
+            arbiter: Pubkey::default(),
+            milestone_approved: false,
+            counterparty: Pubkey::default(),
+            owner_counted: false,
+            wrapped_lamports: false,
+            receipt_mint: Pubkey::default(),
+            fee_mode: LOCK_FEE_FLAT,
+            reserved: [0u8; 29],
+        };
+
+        // Lock grown to hold `frozen` but not `credential_issuer`
+        let mut buffer = vec![0u8; LockAccount::LEGACY_SIZE + 1];
+        lock.pack(&mut buffer);
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+
+        // Lock grown to hold `credential_issuer` but not `non_transferable`
+        lock.credential_issuer = Pubkey::new_unique();
+        let mut buffer = vec![0u8; 138];
+        lock.pack(&mut buffer);
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+
+        // Lock grown to hold `non_transferable` but not `forward_destination`
+        lock.non_transferable = true;
+        let mut buffer = vec![0u8; 139];
+        lock.pack(&mut buffer);
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+
+        // Lock grown to hold `forward_destination` but not `hook`
+        lock.forward_destination = Pubkey::new_unique();
+        let mut buffer = vec![0u8; 171];
+        lock.pack(&mut buffer);
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+
+        // Lock grown to hold `hook` but not `recovery_address`
+        lock.hook = Pubkey::new_unique();
+        let mut buffer = vec![0u8; 203];
+        lock.pack(&mut buffer);
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+
+        // Lock grown to hold `recovery_address` but not the donation
+        lock.recovery_address = Pubkey::new_unique();
+        let mut buffer = vec![0u8; 235];
+        lock.pack(&mut buffer);
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+
+        // Lock grown to hold the donation but not `collateral_holder`
+        lock.donation_address = Pubkey::new_unique();
+        lock.donation_bps = 500;
+        let mut buffer = vec![0u8; 269];
+        lock.pack(&mut buffer);
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+
+        // Lock grown to hold `collateral_holder` but not the version byte
+        lock.collateral_holder = Pubkey::new_unique();
+        let mut buffer = vec![0u8; 301];
+        lock.pack(&mut buffer);
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+
+        // Fields carved out of version 1's reserved block need no growth
+        lock.layout_version = 1;
+        lock.unlock_slot = 1_000;
+        lock.unlock_epoch = 900;
+        let mut buffer = vec![0u8; LockAccount::V1_SIZE];
+        lock.pack(&mut buffer);
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+
+        // Past it, a version 1 lock reads no fields whatever its length
+        let mut buffer = vec![0u8; LockAccount::SIZE];
+        LockAccount {
+            label: [1u8; 32],
+            ..lock
+        }
+        .pack(&mut buffer);
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+
+        lock.layout_version = LockAccount::LAYOUT_VERSION;
+        lock.label = [1u8; 32];
+        lock.pack(&mut buffer);
+        assert_eq!(LockAccount::unpack(&buffer).unwrap(), lock);
+
+        // A current lock must hold the whole layout
+        assert_eq!(
+            LockAccount::unpack(&buffer[..LockAccount::V1_SIZE]).unwrap_err(),
+            LocksmithError::InconsistentState.into()
+        );
+    }
+
+    #[test]
+    fn test_abandonment_period_constant() {
+        // 5 years, half the maximum lock duration
+        assert_eq!(ABANDONMENT_PERIOD_SECONDS, 157_680_000);
+        assert_eq!(ABANDONMENT_PERIOD_SECONDS * 2, MAX_LOCK_DURATION_SECONDS);
+    }
+
+    #[test]
+    fn test_unlock_reached() {
+        let mut lock = LockAccount::unpack(&{
+            let mut data = vec![0u8; LockAccount::LEGACY_SIZE];
+            data[0..8].copy_from_slice(&LockAccount::DISCRIMINATOR);
+            data
+        })
+        .unwrap();
+        lock.unlock_timestamp = 1_700_000_000;
+        let clock = |slot, unix_timestamp| Clock {
+            slot,
+            unix_timestamp,
+            ..Clock::default()
+        };
+
+        assert!(!lock.unlock_reached(&clock(500, 1_699_999_999)));
+        assert!(lock.unlock_reached(&clock(0, 1_700_000_000)));
+
+        // A slot-based lock ignores the timestamp
+        lock.unlock_slot = 500;
+        assert!(!lock.unlock_reached(&clock(499, 1_800_000_000)));
+        assert!(lock.unlock_reached(&clock(500, 0)));
+
+        // So does an epoch-based one
+        lock.unlock_slot = 0;
+        lock.unlock_epoch = 800;
+        let epoch_clock = |epoch| Clock {
+            epoch,
+            unix_timestamp: 1_800_000_000,
+            ..Clock::default()
+        };
+        assert!(!lock.unlock_reached(&epoch_clock(799)));
+        assert!(lock.unlock_reached(&epoch_clock(800)));
+
+        // A permanent lock never unlocks
+        lock.unlock_epoch = 0;
+        lock.permanent = true;
+        assert!(!lock.unlock_reached(&clock(u64::MAX, i64::MAX)));
+    }
+
+    #[test]
+    fn test_voting_weight() {
+        let mut lock = LockAccount::unpack(&{
+            let mut data = vec![0u8; LockAccount::LEGACY_SIZE];
+            data[0..8].copy_from_slice(&LockAccount::DISCRIMINATOR);
+            data
+        })
+        .unwrap();
+        lock.amount = 1_000_000;
+        lock.unlock_timestamp = 1_700_000_000 + MAX_LOCK_DURATION_SECONDS;
+        let clock = |slot, unix_timestamp| Clock {
+            slot,
+            unix_timestamp,
+            ..Clock::default()
+        };
+
+        // Full weight at the maximum duration, decaying linearly to 0
+        assert_eq!(lock.voting_weight(&clock(0, 1_700_000_000)), 1_000_000);
+        let halfway = 1_700_000_000 + MAX_LOCK_DURATION_SECONDS / 2;
+        assert_eq!(lock.voting_weight(&clock(0, halfway)), 500_000);
+        assert_eq!(lock.voting_weight(&clock(0, lock.unlock_timestamp - 1)), 0);
+        assert_eq!(lock.voting_weight(&clock(0, lock.unlock_timestamp)), 0);
+
+        // Never more than the amount, even before the lock was created
+        assert_eq!(lock.voting_weight(&clock(0, 0)), 1_000_000);
+
+        // A slot-based lock's weight follows its slot, not the estimated
+        // timestamp, and is 0 once its slot is reached
+        lock.unlock_slot = 1_000 + MAX_LOCK_DURATION_SLOTS / 2;
+        assert_eq!(lock.voting_weight(&clock(1_000, 1_700_000_000)), 500_000);
+        assert_eq!(lock.voting_weight(&clock(lock.unlock_slot, 1_700_000_000)), 0);
+        lock.unlock_slot = 1_001;
+        assert_eq!(lock.voting_weight(&clock(1_000, 1_700_000_000)), 0);
+
+        // Likewise an epoch-based lock, less the time into the current epoch
+        lock.unlock_slot = 0;
+        lock.unlock_epoch = 10 + MAX_LOCK_DURATION_EPOCHS;
+        let epoch_clock = |epoch, unix_timestamp| Clock {
+            epoch,
+            epoch_start_timestamp: 1_700_000_000,
+            unix_timestamp,
+            ..Clock::default()
+        };
+        assert_eq!(lock.voting_weight(&epoch_clock(10, 1_700_000_000)), 1_000_000);
+        let seconds_per_epoch = (SLOTS_PER_EPOCH * TARGET_MS_PER_SLOT / 1000) as i64;
+        let weight = lock.voting_weight(&epoch_clock(10, 1_700_000_000 + seconds_per_epoch / 2));
+        assert!(weight < 1_000_000);
+        lock.unlock_epoch = 11;
+        assert!(lock.voting_weight(&epoch_clock(10, 1_700_000_000)) < 1_000);
+        assert_eq!(lock.voting_weight(&epoch_clock(11, 1_700_000_000)), 0);
+
+        // A permanent lock keeps the full amount
+        lock.unlock_epoch = 0;
+        lock.permanent = true;
+        assert_eq!(lock.voting_weight(&clock(u64::MAX, i64::MAX)), 1_000_000);
+    }
+
+    #[test]
+    fn test_can_pay_out_to_owner() {
+        let mut lock = LockAccount::unpack(&{
+            let mut data = vec![0u8; LockAccount::LEGACY_SIZE];
+            data[0..8].copy_from_slice(&LockAccount::DISCRIMINATOR);
+            data
+        })
+        .unwrap();
+        let error = |result: Result<(), ProgramError>| result.unwrap_err();
+        assert!(lock.can_pay_out_to_owner(1_700_000_000).is_ok());
+
+        // A claim code holds the tokens until it expires
+        lock.claim_key = [1u8; 32];
+        lock.claim_expires_at = 1_700_000_000;
+        assert_eq!(
+            error(lock.can_pay_out_to_owner(1_699_999_999)),
+            LocksmithError::ClaimCodeActive.into()
+        );
+        assert!(lock.can_pay_out_to_owner(1_700_000_000).is_ok());
+
+        // A receipt-bound lock pays out only on its holder's say
+        lock.receipt_bound = true;
+        assert_eq!(
+            error(lock.can_pay_out_to_owner(1_700_000_000)),
+            LocksmithError::LockBoundToReceipt.into()
+        );
+        assert!(lock.can_pay_out(1_700_000_000, true, false).is_ok());
+        assert_eq!(error(lock.can_change_terms()), LocksmithError::LockBoundToReceipt.into());
+
+        // ...and one requiring approvals only once approved
+        lock.receipt_bound = false;
+        lock.approvals_required = true;
+        assert_eq!(
+            error(lock.can_pay_out_to_owner(1_700_000_000)),
+            LocksmithError::ApprovalsRequired.into()
+        );
+        assert!(lock.can_pay_out(1_700_000_000, false, true).is_ok());
+        assert_eq!(
+            error(lock.can_pay_out(1_700_000_000, true, true)),
+            LocksmithError::LockBoundToReceipt.into()
+        );
+
+        lock.approvals_required = false;
+        lock.shared = true;
+        assert_eq!(error(lock.can_pay_out_to_owner(0)), LocksmithError::LockShared.into());
+        assert_eq!(error(lock.can_change_terms()), LocksmithError::LockShared.into());
+
+        // Frozen and collateralized locks are held whatever else is set
+        lock.collateral_holder = Pubkey::new_unique();
+        assert_eq!(error(lock.can_change_terms()), LocksmithError::LockCollateralized.into());
+        assert_eq!(
+            error(lock.can_pay_out(0, false, false)),
+            LocksmithError::LockCollateralized.into()
+        );
+        lock.frozen = true;
+        assert_eq!(error(lock.can_pay_out(0, false, false)), LocksmithError::LockFrozen.into());
+    }
+
+    #[test]
+    fn test_max_lock_duration_slots_constant() {
+        // 315_360_000 seconds / 0.4 seconds per slot
+        assert_eq!(MAX_LOCK_DURATION_SLOTS, 788_400_000);
+        // Two-day epochs
+        assert_eq!(MAX_LOCK_DURATION_EPOCHS, 1_825);
     }
 
     #[test]
@@ -0,0 +1,22 @@
+import { createFromRoot } from "codama";
+import { rootNodeFromAnchor } from "@codama/nodes-from-anchor";
+import { writeFileSync } from "fs";
+import path from "path";
+import { fileURLToPath } from "url";
+
+// ESM equivalent of __dirname
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+// Load the Shank IDL
+const idlPath = path.join(__dirname, "..", "idl", "locksmith.json");
+const idl = await import(idlPath, { with: { type: "json" } });
+
+// Lift the Shank IDL into a Codama root node. The resulting JSON is the
+// renderer-agnostic input for Codama's JS, Rust and community renderers.
+const codama = createFromRoot(rootNodeFromAnchor(idl.default));
+
+const outPath = path.join(__dirname, "..", "idl", "codama.json");
+writeFileSync(outPath, codama.getJson());
+
+console.log("Codama IDL written to:", outPath);
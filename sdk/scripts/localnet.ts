@@ -0,0 +1,76 @@
+// Bootstraps a freshly started local validator and writes a manifest of
+// addresses. Invoked by `cargo xtask localnet`:
+//
+//   tsx sdk/scripts/localnet.ts <rpc-url> <localnet-dir>
+//
+// Expects <localnet-dir> to hold usdc-mint-authority.json, admin.json and
+// owner.json keypairs, with the validator's USDC mint controlled by the
+// first one.
+
+import { readFileSync, writeFileSync } from "fs";
+import path from "path";
+import { createKeyPairSignerFromBytes, createSolanaRpc } from "@solana/kit";
+import {
+  LOCKSMITH_PROGRAM_ADDRESS,
+  USDC_MINT,
+  bootstrapTestEnvironment,
+} from "../src/index";
+
+const [rpcUrl = "http://127.0.0.1:8899", dir = ".localnet"] =
+  process.argv.slice(2);
+const rpc = createSolanaRpc(rpcUrl);
+
+function keypairPath(name: string) {
+  return path.join(dir, `${name}.json`);
+}
+
+async function loadKeypair(name: string) {
+  const bytes = JSON.parse(readFileSync(keypairPath(name), "utf8"));
+  return createKeyPairSignerFromBytes(new Uint8Array(bytes));
+}
+
+// Wait up to 60s for the validator to accept requests
+for (let attempt = 1; ; attempt++) {
+  try {
+    await rpc.getHealth().send();
+    break;
+  } catch (error) {
+    if (attempt >= 120) {
+      throw error;
+    }
+    await new Promise((resolve) => setTimeout(resolve, 500));
+  }
+}
+
+const [usdcMintAuthority, admin, owner] = await Promise.all(
+  ["usdc-mint-authority", "admin", "owner"].map(loadKeypair)
+);
+const env = await bootstrapTestEnvironment(rpc, {
+  usdcMintAuthority,
+  admin,
+  owner,
+});
+
+const manifest = {
+  rpcUrl,
+  programAddress: LOCKSMITH_PROGRAM_ADDRESS,
+  config: env.config,
+  feeVault: env.feeVault,
+  usdcMint: USDC_MINT,
+  usdcMintAuthority: {
+    address: usdcMintAuthority.address,
+    keypair: keypairPath("usdc-mint-authority"),
+  },
+  admin: { address: admin.address, keypair: keypairPath("admin") },
+  owner: {
+    address: owner.address,
+    keypair: keypairPath("owner"),
+    tokenAccount: env.ownerTokenAccount,
+    usdcAccount: env.ownerUsdcAccount,
+  },
+  mint: env.mint,
+};
+
+const manifestPath = path.join(dir, "manifest.json");
+writeFileSync(manifestPath, JSON.stringify(manifest, null, 2) + "\n");
+console.log("Localnet manifest written to:", manifestPath);
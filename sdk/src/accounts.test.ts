@@ -12,7 +12,40 @@ import {
   type ConfigAccount,
   type LockAccount,
 } from "./generated";
-import { CONFIG_DISCRIMINATOR, LOCK_DISCRIMINATOR } from "./constants";
+import {
+  COMPLIANCE_CONFIG_ACCOUNT_SIZE,
+  FEE_MINT_CONFIG_ACCOUNT_SIZE,
+  SUCCESSOR_CONFIG_ACCOUNT_SIZE,
+  SUNSET_CONFIG_ACCOUNT_SIZE,
+  PENALTY_CONFIG_ACCOUNT_SIZE,
+  BATCH_FEE_CONFIG_ACCOUNT_SIZE,
+  PAUSE_CONFIG_ACCOUNT_SIZE,
+  MINT_POLICY_CONFIG_ACCOUNT_SIZE,
+  FREEZABLE_CONFIG_ACCOUNT_SIZE,
+  FEE_CONFIG_ACCOUNT_SIZE,
+  BPS_FEE_CONFIG_ACCOUNT_SIZE,
+  SOL_FEE_CONFIG_ACCOUNT_SIZE,
+  FEE_SCHEDULE_CONFIG_ACCOUNT_SIZE,
+  CONFIG_DISCRIMINATOR,
+  LEGACY_CONFIG_ACCOUNT_SIZE,
+  LEGACY_LOCK_ACCOUNT_SIZE,
+  LOCK_DISCRIMINATOR,
+  LOCK_LAYOUT_VERSION,
+  MINT_POLICY_NONE,
+  MINT_POLICY_DENYLIST,
+  USDC_MINT,
+  FEE_USDC,
+  V1_LOCK_ACCOUNT_SIZE,
+} from "./constants";
+import {
+  decodeConfigAccountData,
+  decodeLockAccountData,
+  encodeLockLabel,
+  encodeLockMetadataUri,
+  getFeeMint,
+  getLockLabel,
+  getLockMetadataUri,
+} from "./accounts";
 
 // Valid base58 Solana addresses for testing
 const TEST_ADDRESSES = {
@@ -29,12 +62,18 @@ const TEST_ADDRESSES = {
  *
  * Account layouts from Rust:
  *
- * ConfigAccount (41 bytes):
+ * ConfigAccount (185 bytes):
  *   [0-7]:   discriminator "CONFIG\0\0" (8 bytes)
  *   [8-39]:  admin pubkey (32 bytes)
  *   [40]:    bump (1 byte)
+ *   [41-72]: compliance_authority pubkey (32 bytes)
+ *   [73-104]: pending_compliance_authority pubkey (32 bytes)
+ *   [105-112]: compliance_authority_effective_at (i64 little-endian, 8 bytes)
+ *   [113-144]: fee_mint pubkey (32 bytes)
+ *   [145-176]: pending_fee_mint pubkey (32 bytes)
+ *   [177-184]: fee_mint_effective_at (i64 little-endian, 8 bytes)
  *
- * LockAccount (105 bytes):
+ * LockAccount (773 bytes):
  *   [0-7]:   discriminator "LOCK\0\0\0\0" (8 bytes)
  *   [8-39]:  owner pubkey (32 bytes)
  *   [40-71]: mint pubkey (32 bytes)
@@ -43,17 +82,61 @@ const TEST_ADDRESSES = {
  *   [88-95]: created_at (i64 little-endian, 8 bytes)
  *   [96-103]: lock_id (u64 little-endian, 8 bytes)
  *   [104]:   bump (1 byte)
+ *   [105]:   frozen (bool, 1 byte)
+ *   [106-137]: credential_issuer pubkey (32 bytes)
+ *   [138]:   non_transferable (bool, 1 byte)
+ *   [139-170]: forward_destination pubkey (32 bytes)
+ *   [171-202]: hook pubkey (32 bytes)
+ *   [203-234]: recovery_address pubkey (32 bytes)
+ *   [235-266]: donation_address pubkey (32 bytes)
+ *   [267-268]: donation_bps (u16 little-endian, 2 bytes)
+ *   [269-300]: collateral_holder pubkey (32 bytes)
+ *   [301]:   layout_version (1 byte)
+ *   [302-309]: unlock_slot (u64 little-endian, 8 bytes)
+ *   [310-317]: unlock_epoch (u64 little-endian, 8 bytes)
+ *   [318-349]: claim_hash (32 bytes)
+ *   [350-357]: claim_expires_at (i64 little-endian, 8 bytes)
+ *   [358-361]: index_seq (u32 little-endian, 4 bytes)
+ *   [362]:   early_unlock_allowed (bool, 1 byte)
+ *   [363]:   permanent (bool, 1 byte)
+ *   [364]:   is_nft (bool, 1 byte)
+ *   [365]:   receipt_bound (bool, 1 byte)
+ *   [366-397]: label (32 bytes)
+ *   [398-525]: metadata_uri (128 bytes)
+ *   [526-557]: fallback_owner pubkey (32 bytes)
+ *   [558-565]: fallback_after (i64 little-endian, 8 bytes)
+ *   [566-597]: unlock_destination pubkey (32 bytes)
+ *   [598]:   shared (bool, 1 byte)
+ *   [599]:   approvals_required (bool, 1 byte)
+ *   [600-631]: price_feed_id (32 bytes)
+ *   [632-639]: price_threshold (i64 little-endian, 8 bytes)
+ *   [640-643]: price_exponent (i32 little-endian, 4 bytes)
+ *   [644-675]: arbiter pubkey (32 bytes)
+ *   [676]:   milestone_approved (bool, 1 byte)
+ *   [677-708]: counterparty pubkey (32 bytes)
+ *   [709]:   owner_counted (bool, 1 byte)
+ *   [710]:   wrapped_lamports (bool, 1 byte)
+ *   [711-742]: receipt_mint (32 bytes)
+ *   [743]:   fee_mode (u8, 1 byte)
+ *   [744-772]: reserved (29 bytes)
+ *
+ * Accounts created before the compliance fields were added are 41 and 105
+ * bytes, and locks created before credential gating are 106 bytes; the
+ * program reads the missing fields as zero.
  */
 
 describe("Account sizes", () => {
   it("ConfigAccount size matches Rust constant", () => {
-    // Rust: pub const SIZE: usize = 8 + 32 + 1 = 41
-    expect(getConfigAccountSize()).toBe(41);
+    // Rust: pub const SIZE: usize = Self::FEE_SCHEDULE_SIZE + 4 = 254
+    expect(getConfigAccountSize()).toBe(254);
   });
 
   it("LockAccount size matches Rust constant", () => {
-    // Rust: pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 1 = 105
-    expect(getLockAccountSize()).toBe(105);
+    // Rust: pub const SIZE: usize =
+    //   8 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1 + 32 + 1 + 32 + 32 + 32
+    //   + 32 + 2 + 32 + 1 + 64 + 32 + 128 + 32 + 8 + 32 + 1 + 1 + 32 + 8 + 4
+    //   + 32 + 1 + 32 + 1 + 1 + 32 + 30 = 773
+    expect(getLockAccountSize()).toBe(773);
   });
 });
 
@@ -63,7 +146,7 @@ describe("ConfigAccount decoding", () => {
     const adminBytes = new Uint8Array(32);
     adminBytes.fill(0x42); // Fill with recognizable pattern
 
-    const data = new Uint8Array(41);
+    const data = new Uint8Array(113);
     // Discriminator: "CONFIG\0\0"
     data.set(new TextEncoder().encode("CONFIG\0\0"), 0);
     // Admin pubkey
@@ -81,7 +164,7 @@ describe("ConfigAccount decoding", () => {
   });
 
   it("decodes min bump value", () => {
-    const data = new Uint8Array(41);
+    const data = new Uint8Array(113);
     data.set(new TextEncoder().encode("CONFIG\0\0"), 0);
     data.set(new Uint8Array(32), 8); // zero admin
     data[40] = 0;
@@ -100,6 +183,12 @@ describe("ConfigAccount decoding", () => {
       discriminator: CONFIG_DISCRIMINATOR,
       admin: TEST_ADDRESSES.admin,
       bump: 200,
+      complianceAuthority: TEST_ADDRESSES.owner,
+      pendingComplianceAuthority: TEST_ADDRESSES.account,
+      complianceAuthorityEffectiveAt: 1_700_172_800n,
+      feeMint: TEST_ADDRESSES.mint,
+      pendingFeeMint: TEST_ADDRESSES.account,
+      feeMintEffectiveAt: 1_700_777_600n,
     };
 
     const encoded = encoder.encode(original);
@@ -107,6 +196,12 @@ describe("ConfigAccount decoding", () => {
 
     expect(decoded.bump).toBe(original.bump);
     expect(decoded.admin).toBe(original.admin);
+    expect(decoded.complianceAuthority).toBe(original.complianceAuthority);
+    expect(decoded.complianceAuthorityEffectiveAt).toBe(
+      original.complianceAuthorityEffectiveAt
+    );
+    expect(decoded.feeMint).toBe(original.feeMint);
+    expect(decoded.feeMintEffectiveAt).toBe(original.feeMintEffectiveAt);
   });
 
   it("matches Rust byte layout exactly", () => {
@@ -117,7 +212,7 @@ describe("ConfigAccount decoding", () => {
     ]);
 
     // Build expected data
-    const expectedData = new Uint8Array(41);
+    const expectedData = new Uint8Array(185);
     expectedData.set(new TextEncoder().encode("CONFIG\0\0"), 0);
     expectedData.set(adminBytes, 8);
     expectedData[40] = 200;
@@ -142,7 +237,7 @@ describe("LockAccount decoding", () => {
     lockId?: bigint;
     bump?: number;
   }): Uint8Array => {
-    const data = new Uint8Array(105);
+    const data = new Uint8Array(773);
 
     // Discriminator: "LOCK\0\0\0\0"
     data.set(new TextEncoder().encode("LOCK\0\0\0\0"), 0);
@@ -268,6 +363,43 @@ describe("LockAccount decoding", () => {
       createdAt: 1699000000n,
       lockId: 42n,
       bump: 254,
+      frozen: true,
+      credentialIssuer: TEST_ADDRESSES.program,
+      nonTransferable: true,
+      forwardDestination: TEST_ADDRESSES.owner,
+      hook: TEST_ADDRESSES.mint,
+      recoveryAddress: TEST_ADDRESSES.owner,
+      donationAddress: TEST_ADDRESSES.mint,
+      donationBps: 250,
+      collateralHolder: TEST_ADDRESSES.program,
+      layoutVersion: LOCK_LAYOUT_VERSION,
+      unlockSlot: 300_000_000n,
+      unlockEpoch: 700n,
+      claimKey: new Uint8Array(32).fill(7),
+      claimExpiresAt: 1800000000n,
+      indexSeq: 12,
+      earlyUnlockAllowed: false,
+      permanent: false,
+      isNft: false,
+      receiptBound: false,
+      label: encodeLockLabel("team cliff"),
+      metadataUri: encodeLockMetadataUri("https://example.com/terms.json"),
+      fallbackOwner: TEST_ADDRESSES.admin,
+      fallbackAfter: 31_536_000n,
+      unlockDestination: "11111111111111111111111111111111" as Address,
+      shared: false,
+      approvalsRequired: false,
+      priceFeedId: new Uint8Array(32).fill(7),
+      priceThreshold: 150n,
+      priceExponent: -2,
+      arbiter: "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde" as Address,
+      milestoneApproved: true,
+      counterparty: "11111111111111111111111111111111" as Address,
+      ownerCounted: false,
+      wrappedLamports: false,
+      receiptMint: "11111111111111111111111111111111" as Address,
+      feeMode: 0,
+      reserved: new Uint8Array(29),
     };
 
     const encoded = encoder.encode(original);
@@ -280,6 +412,34 @@ describe("LockAccount decoding", () => {
     expect(decoded.createdAt).toBe(original.createdAt);
     expect(decoded.lockId).toBe(original.lockId);
     expect(decoded.bump).toBe(original.bump);
+    expect(getLockLabel(decoded)).toBe("team cliff");
+    expect(getLockMetadataUri(decoded)).toBe(
+      "https://example.com/terms.json"
+    );
+    expect(decoded.fallbackOwner).toBe(TEST_ADDRESSES.admin);
+    expect(decoded.fallbackAfter).toBe(31_536_000n);
+    expect(decoded.unlockDestination).toBe(original.unlockDestination);
+    expect(decoded.priceFeedId).toEqual(original.priceFeedId);
+    expect(decoded.priceThreshold).toBe(original.priceThreshold);
+    expect(decoded.priceExponent).toBe(original.priceExponent);
+    expect(decoded.arbiter).toBe(original.arbiter);
+    expect(decoded.milestoneApproved).toBe(true);
+    expect(decoded.counterparty).toBe(original.counterparty);
+    expect(decoded.frozen).toBe(original.frozen);
+    expect(decoded.credentialIssuer).toBe(original.credentialIssuer);
+    expect(decoded.nonTransferable).toBe(original.nonTransferable);
+    expect(decoded.forwardDestination).toBe(original.forwardDestination);
+    expect(decoded.hook).toBe(original.hook);
+    expect(decoded.recoveryAddress).toBe(original.recoveryAddress);
+    expect(decoded.donationAddress).toBe(original.donationAddress);
+    expect(decoded.donationBps).toBe(original.donationBps);
+    expect(decoded.collateralHolder).toBe(original.collateralHolder);
+    expect(decoded.layoutVersion).toBe(LOCK_LAYOUT_VERSION);
+    expect(decoded.unlockSlot).toBe(original.unlockSlot);
+    expect(decoded.unlockEpoch).toBe(original.unlockEpoch);
+    expect(decoded.claimKey).toEqual(original.claimKey);
+    expect(decoded.claimExpiresAt).toBe(original.claimExpiresAt);
+    expect(decoded.indexSeq).toBe(12);
   });
 
   it("matches Rust byte layout from test_lock_account_byte_layout", () => {
@@ -287,7 +447,7 @@ describe("LockAccount decoding", () => {
     const ownerBytes = new Uint8Array(32).fill(1);
     const mintBytes = new Uint8Array(32).fill(2);
 
-    const data = new Uint8Array(105);
+    const data = new Uint8Array(773);
     // Discriminator
     data.set(new TextEncoder().encode("LOCK\0\0\0\0"), 0);
     // Owner
@@ -304,6 +464,10 @@ describe("LockAccount decoding", () => {
     data.set([0x20, 0x1f, 0x1e, 0x1d, 0x1c, 0x1b, 0x1a, 0x19], 96);
     // Bump
     data[104] = 250;
+    // Frozen
+    data[105] = 1;
+    // Non-transferable
+    data[138] = 1;
 
     const decoder = getLockAccountDecoder();
     const decoded = decoder.decode(data);
@@ -313,6 +477,8 @@ describe("LockAccount decoding", () => {
     expect(decoded.createdAt).toBe(BigInt("0x1112131415161718"));
     expect(decoded.lockId).toBe(0x191a1b1c1d1e1f20n);
     expect(decoded.bump).toBe(250);
+    expect(decoded.frozen).toBe(true);
+    expect(decoded.nonTransferable).toBe(true);
   });
 });
 
@@ -341,7 +507,7 @@ describe("Discriminator validation", () => {
 
 describe("decodeAccount helpers", () => {
   it("decodeConfigAccount handles MaybeEncodedAccount format", () => {
-    const data = new Uint8Array(41);
+    const data = new Uint8Array(113);
     data.set(new TextEncoder().encode("CONFIG\0\0"), 0);
     data.set(new Uint8Array(32), 8);
     data[40] = 123;
@@ -362,7 +528,7 @@ describe("decodeAccount helpers", () => {
   });
 
   it("decodeLockAccount handles MaybeEncodedAccount format", () => {
-    const data = new Uint8Array(105);
+    const data = new Uint8Array(773);
     data.set(new TextEncoder().encode("LOCK\0\0\0\0"), 0);
     data.set(new Uint8Array(32), 8); // owner
     data.set(new Uint8Array(32), 40); // mint
@@ -396,3 +562,357 @@ describe("decodeAccount helpers", () => {
     expect(decoded.exists).toBe(false);
   });
 });
+
+describe("Legacy account decoding", () => {
+  it("decodes 41-byte configs without a compliance authority", () => {
+    const data = new Uint8Array(LEGACY_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data.fill(0x42, 8, 40);
+    data[40] = 255;
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.bump).toBe(255);
+    expect(decoded.complianceAuthority).toBe(
+      "11111111111111111111111111111111"
+    );
+    expect(decoded.complianceAuthorityEffectiveAt).toBe(0n);
+  });
+
+  it("decodes 113-byte configs as charging USDC", () => {
+    const data = new Uint8Array(COMPLIANCE_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data.fill(0x42, 41, 73);
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.complianceAuthority).not.toBe(
+      "11111111111111111111111111111111"
+    );
+    expect(decoded.feeMintEffectiveAt).toBe(0n);
+    expect(getFeeMint(decoded)).toBe(USDC_MINT);
+    expect(getFeeMint({ ...decoded, feeMint: TEST_ADDRESSES.account })).toBe(
+      TEST_ADDRESSES.account
+    );
+  });
+
+  it("decodes 185-byte configs without a successor program", () => {
+    const data = new Uint8Array(FEE_MINT_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data.fill(0x42, 113, 145);
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(getFeeMint(decoded)).not.toBe(USDC_MINT);
+    expect(decoded.successorProgram).toBe("11111111111111111111111111111111");
+  });
+
+  it("decodes 227-byte configs as charging one fee per batch", () => {
+    const data = new Uint8Array(PENALTY_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data[225] = 250;
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.earlyUnlockPenaltyBps).toBe(250);
+    expect(decoded.batchFeePerLock).toBe(false);
+  });
+
+  it("decodes 228-byte configs as not paused", () => {
+    const data = new Uint8Array(BATCH_FEE_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data[227] = 1;
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.batchFeePerLock).toBe(true);
+    expect(decoded.paused).toBe(false);
+  });
+
+  it("decodes 229-byte configs as accepting any mint", () => {
+    const data = new Uint8Array(PAUSE_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data[228] = 1;
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.paused).toBe(true);
+    expect(decoded.mintPolicyMode).toBe(MINT_POLICY_NONE);
+  });
+
+  it("decodes 230-byte configs as accepting freezable mints", () => {
+    const data = new Uint8Array(MINT_POLICY_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data[229] = MINT_POLICY_DENYLIST;
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.mintPolicyMode).toBe(MINT_POLICY_DENYLIST);
+    expect(decoded.rejectFreezableMints).toBe(false);
+  });
+
+  it("decodes 231-byte configs as charging FEE_USDC", () => {
+    const data = new Uint8Array(FREEZABLE_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data[230] = 1;
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.rejectFreezableMints).toBe(true);
+    expect(decoded.feeUsdc).toBe(FEE_USDC);
+  });
+
+  it("decodes 239-byte configs as charging the flat fee", () => {
+    const data = new Uint8Array(FEE_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data[231] = 0x40;
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.feeUsdc).toBe(0x40n);
+    expect(decoded.feeBps).toBe(0);
+  });
+
+  it("decodes 241-byte configs as not accepting SOL fees", () => {
+    const data = new Uint8Array(BPS_FEE_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data[239] = 50;
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.feeBps).toBe(50);
+    expect(decoded.feeLamports).toBe(0n);
+  });
+
+  it("decodes 249-byte configs as not tiered", () => {
+    const data = new Uint8Array(SOL_FEE_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data[241] = 0x40;
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.feeLamports).toBe(0x40n);
+    expect(decoded.feeTiered).toBe(false);
+  });
+
+  it("decodes 250-byte configs as not capping locks per owner", () => {
+    const data = new Uint8Array(FEE_SCHEDULE_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data[249] = 1;
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.feeTiered).toBe(true);
+    expect(decoded.maxLocksPerOwner).toBe(0);
+  });
+
+  it("decodes a configured fee of zero", () => {
+    const data = new Uint8Array(getConfigAccountSize());
+    data.set(CONFIG_DISCRIMINATOR, 0);
+
+    expect(decodeConfigAccountData(data).feeUsdc).toBe(0n);
+  });
+
+  it("decodes 225-byte configs as charging no early unlock penalty", () => {
+    const data = new Uint8Array(SUNSET_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data[217] = 1;
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.sunsetAt).toBe(1n);
+    expect(decoded.earlyUnlockPenaltyBps).toBe(0);
+  });
+
+  it("decodes 217-byte configs as not sunset", () => {
+    const data = new Uint8Array(SUCCESSOR_CONFIG_ACCOUNT_SIZE);
+    data.set(CONFIG_DISCRIMINATOR, 0);
+    data.fill(0x42, 185, 217);
+
+    const decoded = decodeConfigAccountData(data);
+
+    expect(decoded.successorProgram).not.toBe(
+      "11111111111111111111111111111111"
+    );
+    expect(decoded.sunsetAt).toBe(0n);
+  });
+
+  it("decodes 105-byte locks as not frozen", () => {
+    const full = getLockAccountEncoder().encode({
+      discriminator: LOCK_DISCRIMINATOR,
+      owner: TEST_ADDRESSES.owner,
+      mint: TEST_ADDRESSES.mint,
+      amount: 1_000n,
+      unlockTimestamp: 1700000000n,
+      createdAt: 1699000000n,
+      lockId: 42n,
+      bump: 254,
+      frozen: true,
+      credentialIssuer: TEST_ADDRESSES.program,
+      nonTransferable: true,
+      forwardDestination: TEST_ADDRESSES.owner,
+      hook: TEST_ADDRESSES.mint,
+      recoveryAddress: TEST_ADDRESSES.owner,
+      donationAddress: TEST_ADDRESSES.mint,
+      donationBps: 250,
+      collateralHolder: TEST_ADDRESSES.program,
+      layoutVersion: LOCK_LAYOUT_VERSION,
+      unlockSlot: 0n,
+      unlockEpoch: 0n,
+      claimKey: new Uint8Array(32),
+      claimExpiresAt: 0n,
+      indexSeq: 0,
+      earlyUnlockAllowed: false,
+      permanent: false,
+      isNft: false,
+      receiptBound: false,
+      label: new Uint8Array(32),
+      metadataUri: new Uint8Array(128),
+      fallbackOwner: "11111111111111111111111111111111" as Address,
+      fallbackAfter: 0n,
+      unlockDestination: "11111111111111111111111111111111" as Address,
+      shared: false,
+      approvalsRequired: false,
+      priceFeedId: new Uint8Array(32),
+      priceThreshold: 0n,
+      priceExponent: 0,
+      arbiter: "11111111111111111111111111111111" as Address,
+      milestoneApproved: false,
+      counterparty: "11111111111111111111111111111111" as Address,
+      ownerCounted: false,
+      wrappedLamports: false,
+      receiptMint: "11111111111111111111111111111111" as Address,
+      feeMode: 0,
+      reserved: new Uint8Array(29),
+    });
+
+    const decoded = decodeLockAccountData(
+      full.slice(0, LEGACY_LOCK_ACCOUNT_SIZE)
+    );
+
+    expect(decoded.amount).toBe(1_000n);
+    expect(decoded.bump).toBe(254);
+    expect(decoded.frozen).toBe(false);
+    expect(decoded.credentialIssuer).toBe("11111111111111111111111111111111");
+    expect(decoded.nonTransferable).toBe(false);
+    expect(decoded.forwardDestination).toBe(
+      "11111111111111111111111111111111"
+    );
+    expect(decoded.hook).toBe("11111111111111111111111111111111");
+    expect(decoded.recoveryAddress).toBe("11111111111111111111111111111111");
+    expect(decoded.donationBps).toBe(0);
+    expect(decoded.collateralHolder).toBe("11111111111111111111111111111111");
+    expect(decoded.layoutVersion).toBe(0);
+  });
+
+  it("decodes 106-byte locks as not credential-gated", () => {
+    const data = new Uint8Array(LEGACY_LOCK_ACCOUNT_SIZE + 1);
+    data.set(LOCK_DISCRIMINATOR, 0);
+    data[105] = 1;
+
+    const decoded = decodeLockAccountData(data);
+
+    expect(decoded.frozen).toBe(true);
+    expect(decoded.credentialIssuer).toBe("11111111111111111111111111111111");
+  });
+
+  it("decodes current-size accounts unchanged", () => {
+    const data = new Uint8Array(getLockAccountSize());
+    data.set(LOCK_DISCRIMINATOR, 0);
+    data[105] = 1;
+
+    expect(decodeLockAccountData(data).frozen).toBe(true);
+  });
+
+  it("decodes the layout version and ignores trailing bytes", () => {
+    const data = new Uint8Array(getLockAccountSize() + 8);
+    data.set(LOCK_DISCRIMINATOR, 0);
+    data[301] = LOCK_LAYOUT_VERSION;
+
+    const decoded = decodeLockAccountData(data);
+
+    expect(decoded.layoutVersion).toBe(LOCK_LAYOUT_VERSION);
+    expect(decoded.unlockSlot).toBe(0n);
+    expect(decoded.unlockEpoch).toBe(0n);
+    expect(decoded.claimKey).toEqual(new Uint8Array(32));
+    expect(decoded.claimExpiresAt).toBe(0n);
+    expect(decoded.indexSeq).toBe(0);
+    expect(decoded.earlyUnlockAllowed).toBe(false);
+    expect(decoded.permanent).toBe(false);
+    expect(decoded.isNft).toBe(false);
+    expect(decoded.receiptBound).toBe(false);
+    expect(decoded.label).toEqual(new Uint8Array(32));
+    expect(decoded.metadataUri).toEqual(new Uint8Array(128));
+    expect(decoded.fallbackOwner).toBe("11111111111111111111111111111111");
+    expect(decoded.fallbackAfter).toBe(0n);
+    expect(decoded.unlockDestination).toBe(
+      "11111111111111111111111111111111"
+    );
+    expect(decoded.shared).toBe(false);
+    expect(decoded.approvalsRequired).toBe(false);
+    expect(decoded.priceFeedId).toEqual(new Uint8Array(32));
+    expect(decoded.priceThreshold).toBe(0n);
+    expect(decoded.arbiter).toBe("11111111111111111111111111111111");
+    expect(decoded.milestoneApproved).toBe(false);
+    expect(decoded.counterparty).toBe("11111111111111111111111111111111");
+    expect(decoded.ownerCounted).toBe(false);
+    expect(decoded.wrappedLamports).toBe(false);
+    expect(decoded.receiptMint).toBe("11111111111111111111111111111111");
+    expect(decoded.feeMode).toBe(0);
+    expect(decoded.reserved).toEqual(new Uint8Array(29));
+  });
+
+  it("reads version 1 locks only up to their own layout", () => {
+    const data = new Uint8Array(getLockAccountSize());
+    data.set(LOCK_DISCRIMINATOR, 0);
+    data[301] = 1;
+    data[365] = 1;
+    data.fill(7, V1_LOCK_ACCOUNT_SIZE);
+
+    const decoded = decodeLockAccountData(data);
+
+    expect(decoded.layoutVersion).toBe(1);
+    expect(decoded.receiptBound).toBe(true);
+    expect(decoded.label).toEqual(new Uint8Array(32));
+    expect(decoded.reserved).toEqual(new Uint8Array(29));
+  });
+});
+
+describe("Lock labels", () => {
+  it("zero-pads labels to 32 bytes", () => {
+    const label = encodeLockLabel("LP lock #3");
+
+    expect(label.length).toBe(32);
+    expect(label.slice(10)).toEqual(new Uint8Array(22));
+  });
+
+  it("reads an unlabelled lock as an empty label", () => {
+    const lock = decodeLockAccountData(
+      new Uint8Array(LEGACY_LOCK_ACCOUNT_SIZE)
+    );
+
+    expect(getLockLabel(lock)).toBe("");
+  });
+
+  it("rejects labels longer than 32 bytes", () => {
+    expect(() => encodeLockLabel("x".repeat(33))).toThrow(
+      "Label exceeds 32 bytes"
+    );
+  });
+});
+
+describe("Lock metadata URIs", () => {
+  it("reads a lock created without a URI as an empty URI", () => {
+    const lock = decodeLockAccountData(
+      new Uint8Array(LEGACY_LOCK_ACCOUNT_SIZE)
+    );
+
+    expect(getLockMetadataUri(lock)).toBe("");
+  });
+
+  it("rejects URIs longer than 128 bytes", () => {
+    expect(() => encodeLockMetadataUri("x".repeat(129))).toThrow(
+      "Metadata URI exceeds 128 bytes"
+    );
+  });
+});
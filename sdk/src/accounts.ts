@@ -0,0 +1,216 @@
+import {
+  assertAccountExists,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  type Account,
+  type Address,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+} from "@solana/kit";
+import {
+  decodeConfigAccount,
+  decodeLockAccount,
+  getConfigAccountDecoder,
+  getConfigAccountSize,
+  getLockAccountDecoder,
+  getLockAccountSize,
+  getStandingOrderAccountDecoder,
+  getStandingOrderAccountSize,
+  type ConfigAccount,
+  type LockAccount,
+  type StandingOrderAccount,
+} from "./generated";
+import {
+  FEE_USDC,
+  METADATA_URI_LEN,
+  USDC_MINT,
+  V1_LOCK_ACCOUNT_SIZE,
+} from "./constants";
+
+/*
+ * Accounts only ever grow by appending fields, and the program reads
+ * missing trailing fields as zero. Accounts created before a field was
+ * added are therefore shorter than the generated decoders expect; these
+ * helpers zero-pad them first, so e.g. a legacy 105-byte lock decodes with
+ * `frozen: false`. The exceptions are the fees of configs and standing
+ * orders, which read as `FEE_USDC` when missing, and version 1 locks, which hold no fields past
+ * `V1_LOCK_ACCOUNT_SIZE` whatever their length.
+ */
+
+function padTo(data: ReadonlyUint8Array, size: number): Uint8Array {
+  const padded = new Uint8Array(Math.max(size, data.length));
+  padded.set(data);
+  return padded;
+}
+
+function withDefaultFee(config: ConfigAccount, size: number): ConfigAccount {
+  return size < getConfigAccountSize()
+    ? { ...config, feeUsdc: FEE_USDC }
+    : config;
+}
+
+function padAccount(
+  account: MaybeEncodedAccount,
+  size: number
+): MaybeEncodedAccount {
+  return account.exists
+    ? { ...account, data: padTo(account.data, size) }
+    : account;
+}
+
+/** Offset of `LockAccount.layoutVersion` */
+const LOCK_LAYOUT_VERSION_OFFSET = 301;
+
+function padLock(data: ReadonlyUint8Array): Uint8Array {
+  const end =
+    data[LOCK_LAYOUT_VERSION_OFFSET] === 1 ? V1_LOCK_ACCOUNT_SIZE : undefined;
+  return padTo(data.slice(0, end), getLockAccountSize());
+}
+
+function padLockAccount(account: MaybeEncodedAccount): MaybeEncodedAccount {
+  return account.exists ? { ...account, data: padLock(account.data) } : account;
+}
+
+/**
+ * Decode LockAccount data of any layout version.
+ */
+export function decodeLockAccountData(data: ReadonlyUint8Array): LockAccount {
+  return getLockAccountDecoder().decode(padLock(data));
+}
+
+/**
+ * Decode ConfigAccount data of any layout version.
+ */
+export function decodeConfigAccountData(
+  data: ReadonlyUint8Array
+): ConfigAccount {
+  return withDefaultFee(
+    getConfigAccountDecoder().decode(padTo(data, getConfigAccountSize())),
+    data.length
+  );
+}
+
+/**
+ * Decode StandingOrderAccount data of any layout version.
+ */
+export function decodeStandingOrderAccountData(
+  data: ReadonlyUint8Array
+): StandingOrderAccount {
+  const order = getStandingOrderAccountDecoder().decode(
+    padTo(data, getStandingOrderAccountSize())
+  );
+  return data.length < getStandingOrderAccountSize()
+    ? { ...order, feeUsdc: FEE_USDC }
+    : order;
+}
+
+/**
+ * Mint the config currently charges fees in. Configs created before fee
+ * mint migration store the default address, meaning USDC.
+ */
+export function getFeeMint(config: ConfigAccount): Address {
+  return config.feeMint === "11111111111111111111111111111111"
+    ? USDC_MINT
+    : config.feeMint;
+}
+
+/** Bytes of `LockAccount.label` */
+const LOCK_LABEL_LENGTH = 32;
+
+function encodePaddedText(text: string, size: number, what: string) {
+  const bytes = new TextEncoder().encode(text);
+  if (bytes.length > size) {
+    throw new Error(`${what} exceeds ${size} bytes`);
+  }
+  const padded = new Uint8Array(size);
+  padded.set(bytes);
+  return padded;
+}
+
+function decodePaddedText(bytes: ReadonlyUint8Array): string {
+  const end = bytes.indexOf(0);
+  return new TextDecoder().decode(
+    bytes.slice(0, end === -1 ? bytes.length : end)
+  );
+}
+
+/**
+ * Encode a label for `getUpdateLockMetadataInstruction` or
+ * `LockOptions.label` as zero-padded UTF-8. An empty label clears it.
+ */
+export function encodeLockLabel(label: string): Uint8Array {
+  return encodePaddedText(label, LOCK_LABEL_LENGTH, "Label");
+}
+
+/**
+ * A lock's label as text, or "" if it has none.
+ */
+export function getLockLabel(lock: LockAccount): string {
+  return decodePaddedText(lock.label);
+}
+
+/**
+ * Encode `LockOptions.metadataUri` as zero-padded UTF-8. It is fixed when
+ * the lock is created.
+ */
+export function encodeLockMetadataUri(uri: string): Uint8Array {
+  return encodePaddedText(uri, METADATA_URI_LEN, "Metadata URI");
+}
+
+/**
+ * The URI of a lock's terms document, or "" if it has none.
+ */
+export function getLockMetadataUri(lock: LockAccount): string {
+  return decodePaddedText(lock.metadataUri);
+}
+
+/**
+ * `fetchLockAccount` that also accepts locks created at an older layout.
+ */
+export async function fetchLockAccountCompat(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address,
+  config?: FetchAccountConfig
+): Promise<Account<LockAccount>> {
+  const account = decodeLockAccount(
+    padLockAccount(await fetchEncodedAccount(rpc, address, config))
+  );
+  assertAccountExists(account);
+  return account;
+}
+
+/**
+ * `fetchAllMaybeLockAccount` that also accepts locks created at an older
+ * layout.
+ */
+export async function fetchAllMaybeLockAccountCompat(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Address[],
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<LockAccount>[]> {
+  const accounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return accounts.map((account) =>
+    decodeLockAccount(padLockAccount(account))
+  );
+}
+
+/**
+ * `fetchConfigAccount` that also accepts a config created at an older
+ * layout.
+ */
+export async function fetchConfigAccountCompat(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address,
+  config?: FetchAccountConfig
+): Promise<Account<ConfigAccount>> {
+  const encoded = await fetchEncodedAccount(rpc, address, config);
+  const account = decodeConfigAccount(
+    padAccount(encoded, getConfigAccountSize())
+  );
+  assertAccountExists(account);
+  const size = encoded.exists ? encoded.data.length : 0;
+  return { ...account, data: withDefaultFee(account.data, size) };
+}
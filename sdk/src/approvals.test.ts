@@ -0,0 +1,56 @@
+import { describe, it, expect } from "vitest";
+import type { Address } from "@solana/kit";
+import {
+  getUnlockApproversArgs,
+  getUnlockApprovers,
+  isUnlockApproved,
+} from "./approvals";
+import { MAX_APPROVERS, UNLOCK_APPROVALS_DISCRIMINATOR } from "./constants";
+import type { UnlockApprovalsAccount } from "./generated";
+
+const ALICE = "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address;
+const BOB = "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde" as Address;
+const CAROL = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address;
+
+function unlockApprovals(approvals = 0): UnlockApprovalsAccount {
+  return {
+    discriminator: UNLOCK_APPROVALS_DISCRIMINATOR,
+    lock: "11111111111111111111111111111112" as Address,
+    ...getUnlockApproversArgs([ALICE, BOB, CAROL], 2),
+    approvals,
+    bump: 255,
+  };
+}
+
+describe("getUnlockApproversArgs", () => {
+  it("pads approvers to MAX_APPROVERS slots", () => {
+    const { approvers, threshold } = getUnlockApproversArgs([ALICE, BOB], 2);
+
+    expect(approvers).toHaveLength(MAX_APPROVERS);
+    expect(approvers.slice(0, 2)).toEqual([ALICE, BOB]);
+    expect(approvers[2]).toBe("11111111111111111111111111111111");
+    expect(threshold).toBe(2);
+  });
+
+  it("rejects thresholds the approvers cannot meet", () => {
+    expect(() => getUnlockApproversArgs([ALICE, BOB], 0)).toThrow();
+    expect(() => getUnlockApproversArgs([ALICE, BOB], 3)).toThrow();
+    expect(() => getUnlockApproversArgs([], 1)).toThrow();
+  });
+
+  it("rejects duplicate and excess approvers", () => {
+    expect(() => getUnlockApproversArgs([ALICE, ALICE], 1)).toThrow();
+    expect(() =>
+      getUnlockApproversArgs(Array(MAX_APPROVERS + 1).fill(ALICE), 1)
+    ).toThrow();
+  });
+});
+
+describe("isUnlockApproved", () => {
+  it("counts approvals against the threshold", () => {
+    expect(isUnlockApproved(unlockApprovals())).toBe(false);
+    expect(isUnlockApproved(unlockApprovals(0b001))).toBe(false);
+    expect(isUnlockApproved(unlockApprovals(0b101))).toBe(true);
+    expect(getUnlockApprovers(unlockApprovals(0b101))).toEqual([ALICE, CAROL]);
+  });
+});
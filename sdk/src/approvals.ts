@@ -0,0 +1,52 @@
+import type { Address } from "@solana/kit";
+import { MAX_APPROVERS } from "./constants";
+import type {
+  SetUnlockApproversInstructionDataArgs,
+  UnlockApprovalsAccount,
+} from "./generated";
+
+/** Approver of an unused slot */
+const UNUSED_SLOT = "11111111111111111111111111111111" as Address;
+
+/**
+ * The `approvers` and `threshold` arguments of
+ * `getSetUnlockApproversInstruction`, padded to `MAX_APPROVERS` slots.
+ * Approvers must be distinct, and `threshold` between one and their number.
+ */
+export function getUnlockApproversArgs(
+  approvers: Address[],
+  threshold: number
+): SetUnlockApproversInstructionDataArgs {
+  if (approvers.length === 0 || approvers.length > MAX_APPROVERS) {
+    throw new Error(`Expected 1 to ${MAX_APPROVERS} approvers`);
+  }
+  if (new Set(approvers).size < approvers.length) {
+    throw new Error("Approvers must be distinct");
+  }
+  if (!Number.isInteger(threshold) || threshold < 1) {
+    throw new Error("Threshold must be at least 1");
+  }
+  if (threshold > approvers.length) {
+    throw new Error(`Threshold exceeds the ${approvers.length} approvers`);
+  }
+  const padded = Array<Address>(MAX_APPROVERS).fill(UNUSED_SLOT);
+  approvers.forEach((approver, i) => {
+    padded[i] = approver;
+  });
+  return { approvers: padded, threshold };
+}
+
+/** Approvers who have approved unlocking the lock */
+export function getUnlockApprovers(
+  approvals: UnlockApprovalsAccount
+): Address[] {
+  return approvals.approvers.filter(
+    (approver, i) =>
+      approver !== UNUSED_SLOT && (approvals.approvals & (1 << i)) !== 0
+  );
+}
+
+/** Whether enough approvers have approved for `ExecuteUnlock` */
+export function isUnlockApproved(approvals: UnlockApprovalsAccount): boolean {
+  return getUnlockApprovers(approvals).length >= approvals.threshold;
+}
@@ -0,0 +1,123 @@
+import { describe, it, expect } from "vitest";
+import { AccountRole, createNoopSigner, type Address } from "@solana/kit";
+import { getInitializeLockBatchInstructionForOwners } from "./batch";
+import {
+  findConfigPda,
+  findFeeCapPda,
+  findFeeExemptionPda,
+  findFeeSchedulePda,
+  findFeeVaultPda,
+  findLockAccountPda,
+  findLockTokenPda,
+  findMintPolicyPda,
+  findTokenFeeVaultPda,
+} from "./pdas";
+
+const TEST_ADDRESSES = {
+  funder: "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde" as Address,
+  funderToken: "Vote111111111111111111111111111111111111111" as Address,
+  funderUsdc: "Stake11111111111111111111111111111111111111" as Address,
+  mint: "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address,
+  owners: [
+    "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV",
+    "Config1111111111111111111111111111111111111",
+  ] as Address[],
+};
+
+function params(owners: Address[]) {
+  return {
+    funder: createNoopSigner(TEST_ADDRESSES.funder),
+    funderTokenAccount: TEST_ADDRESSES.funderToken,
+    funderUsdcAccount: TEST_ADDRESSES.funderUsdc,
+    mint: TEST_ADDRESSES.mint,
+    owners,
+    amount: 1_000n,
+    unlockTimestamp: 1_800_000_000n,
+    lockId: 7n,
+  };
+}
+
+describe("getInitializeLockBatchInstructionForOwners", () => {
+  it("appends each owner's lock and escrow", async () => {
+    const ix = await getInitializeLockBatchInstructionForOwners(
+      params(TEST_ADDRESSES.owners)
+    );
+
+    const [config] = await findConfigPda();
+    const [feeVault] = await findFeeVaultPda();
+    const expected: Address[] = [
+      TEST_ADDRESSES.funder,
+      TEST_ADDRESSES.funderToken,
+      TEST_ADDRESSES.funderUsdc,
+      TEST_ADDRESSES.mint,
+      config,
+      feeVault,
+      "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address,
+      "11111111111111111111111111111111" as Address,
+    ];
+    for (const owner of TEST_ADDRESSES.owners) {
+      const [lock] = await findLockAccountPda(owner, TEST_ADDRESSES.mint, 7n);
+      const [escrow] = await findLockTokenPda(lock);
+      expected.push(owner, lock, escrow);
+    }
+    expect(ix.accounts?.map((a) => a.address)).toEqual(expected);
+    expect(ix.accounts?.[12].role).toBe(AccountRole.WRITABLE);
+  });
+
+  it("appends the mint policy PDA when asked", async () => {
+    const ix = await getInitializeLockBatchInstructionForOwners({
+      ...params(TEST_ADDRESSES.owners),
+      mintPolicy: true,
+    });
+
+    const [mintPolicy] = await findMintPolicyPda(TEST_ADDRESSES.mint);
+    expect(ix.accounts?.length).toBe(8 + 3 * TEST_ADDRESSES.owners.length + 1);
+    expect(ix.accounts?.at(-1)?.address).toBe(mintPolicy);
+  });
+
+  it("appends the funder's fee exemption PDA when asked", async () => {
+    const ix = await getInitializeLockBatchInstructionForOwners({
+      ...params(TEST_ADDRESSES.owners),
+      feeExemption: true,
+    });
+
+    const [feeExemption] = await findFeeExemptionPda(TEST_ADDRESSES.funder);
+    expect(ix.accounts?.length).toBe(8 + 3 * TEST_ADDRESSES.owners.length + 1);
+    expect(ix.accounts?.at(-1)?.address).toBe(feeExemption);
+  });
+
+  it("appends the fee schedule PDA when asked", async () => {
+    const ix = await getInitializeLockBatchInstructionForOwners({
+      ...params(TEST_ADDRESSES.owners),
+      feeSchedule: true,
+    });
+
+    const [feeSchedule] = await findFeeSchedulePda();
+    expect(ix.accounts?.length).toBe(8 + 3 * TEST_ADDRESSES.owners.length + 1);
+    expect(ix.accounts?.at(-1)?.address).toBe(feeSchedule);
+  });
+
+  it("appends the fee cap PDA and token fee vault when asked", async () => {
+    const ix = await getInitializeLockBatchInstructionForOwners({
+      ...params(TEST_ADDRESSES.owners),
+      mintPolicy: true,
+      percentageFee: true,
+    });
+
+    const [mintPolicy] = await findMintPolicyPda(TEST_ADDRESSES.mint);
+    const [feeCap] = await findFeeCapPda(TEST_ADDRESSES.mint);
+    const [tokenFeeVault] = await findTokenFeeVaultPda(TEST_ADDRESSES.mint);
+    expect(ix.accounts?.slice(-3).map((a) => a.address)).toEqual([
+      mintPolicy,
+      feeCap,
+      tokenFeeVault,
+    ]);
+    expect(ix.accounts?.at(-1)?.role).toBe(AccountRole.WRITABLE);
+  });
+
+  it("rejects an empty batch", async () => {
+    await expect(
+      getInitializeLockBatchInstructionForOwners(params([]))
+    ).rejects.toThrow("at least one owner");
+  });
+});
@@ -0,0 +1,137 @@
+import {
+  AccountRole,
+  type AccountMeta,
+  type Address,
+  type Instruction,
+  type TransactionSigner,
+} from "@solana/kit";
+import {
+  LOCKSMITH_PROGRAM_ADDRESS,
+  getInitializeLockBatchInstruction,
+} from "./generated";
+import { USDC_MINT } from "./constants";
+import {
+  findConfigPda,
+  findFeeCapPda,
+  findFeeExemptionPda,
+  findFeeSchedulePda,
+  findFeeVaultPda,
+  findLockAccountPda,
+  findLockTokenPda,
+  findMintPolicyPda,
+  findTokenFeeVaultPda,
+} from "./pdas";
+
+export type LockBatchParams = {
+  /** Pays the tokens, fees and rent */
+  funder: TransactionSigner;
+  /** Funder's token account for the locked mint */
+  funderTokenAccount: Address;
+  /**
+   * Funder's fee mint account; under a percentage fee, which charges no
+   * USDC, any account such as `funderTokenAccount`
+   */
+  funderUsdcAccount: Address;
+  mint: Address;
+  /** Owners of the new locks, one lock each */
+  owners: Address[];
+  /** Amount locked for every owner */
+  amount: bigint;
+  unlockTimestamp: bigint;
+  /** Lock ID shared by every lock; unique per owner and mint */
+  lockId: bigint;
+  /** Append the mint's policy PDA, needed under a mint policy mode */
+  mintPolicy?: boolean;
+  /** Append the funder's fee exemption PDA, making the batch free */
+  feeExemption?: boolean;
+  /** Append the fee schedule PDA, needed while the fee is tiered */
+  feeSchedule?: boolean;
+  /**
+   * Append the mint's fee cap PDA and token fee vault, needed under a
+   * percentage fee even for an uncapped mint
+   */
+  percentageFee?: boolean;
+};
+
+/**
+ * Build the InitializeLockBatch instruction creating a lock for each of
+ * `params.owners`, deriving their lock and escrow PDAs. Pass `feeMint` once
+ * fees have been migrated off USDC (see `getFeeMint`). The batch must fit
+ * in one transaction; use an address lookup table for larger ones.
+ */
+export async function getInitializeLockBatchInstructionForOwners(
+  params: LockBatchParams,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS,
+  feeMint: Address = USDC_MINT
+): Promise<Instruction> {
+  const { owners, mint, lockId } = params;
+  if (owners.length === 0) {
+    throw new Error("A lock batch needs at least one owner");
+  }
+
+  const lockAccounts: AccountMeta[] = [];
+  for (const owner of owners) {
+    const [lockAccount] = await findLockAccountPda(
+      owner,
+      mint,
+      lockId,
+      programAddress
+    );
+    const [lockTokenAccount] = await findLockTokenPda(
+      lockAccount,
+      programAddress
+    );
+    lockAccounts.push(
+      { address: owner, role: AccountRole.READONLY },
+      { address: lockAccount, role: AccountRole.WRITABLE },
+      { address: lockTokenAccount, role: AccountRole.WRITABLE }
+    );
+  }
+  const [config] = await findConfigPda(programAddress);
+  const [feeVault] = await findFeeVaultPda(programAddress, feeMint);
+
+  const instruction = getInitializeLockBatchInstruction(
+    {
+      funder: params.funder,
+      funderTokenAccount: params.funderTokenAccount,
+      funderUsdcAccount: params.funderUsdcAccount,
+      mint,
+      config,
+      feeVault,
+      owner: owners[0],
+      lockAccount: lockAccounts[1].address,
+      lockTokenAccount: lockAccounts[2].address,
+      amount: params.amount,
+      unlockTimestamp: params.unlockTimestamp,
+      lockId,
+    },
+    { programAddress }
+  );
+  if (params.mintPolicy) {
+    const [mintPolicy] = await findMintPolicyPda(mint, programAddress);
+    lockAccounts.push({ address: mintPolicy, role: AccountRole.READONLY });
+  }
+  if (params.feeExemption) {
+    const [feeExemption] = await findFeeExemptionPda(
+      params.funder.address,
+      programAddress
+    );
+    lockAccounts.push({ address: feeExemption, role: AccountRole.READONLY });
+  }
+  if (params.feeSchedule) {
+    const [feeSchedule] = await findFeeSchedulePda(programAddress);
+    lockAccounts.push({ address: feeSchedule, role: AccountRole.READONLY });
+  }
+  if (params.percentageFee) {
+    const [feeCap] = await findFeeCapPda(mint, programAddress);
+    const [tokenFeeVault] = await findTokenFeeVaultPda(mint, programAddress);
+    lockAccounts.push(
+      { address: feeCap, role: AccountRole.READONLY },
+      { address: tokenFeeVault, role: AccountRole.WRITABLE }
+    );
+  }
+  return {
+    ...instruction,
+    accounts: [...instruction.accounts, ...lockAccounts.slice(3)],
+  };
+}
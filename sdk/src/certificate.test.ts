@@ -0,0 +1,266 @@
+import { describe, it, expect } from "vitest";
+import {
+  generateKeyPairSigner,
+  getAddressEncoder,
+  getBase64Decoder,
+  getBase64Encoder,
+  getU64Encoder,
+  type Address,
+  type GetMultipleAccountsApi,
+  type Rpc,
+} from "@solana/kit";
+import {
+  createLockCertificate,
+  getLockCertificateMessage,
+  verifyLockCertificate,
+} from "./certificate";
+import { LOCKSMITH_PROGRAM_ADDRESS, getLockAccountEncoder } from "./generated";
+import { LEGACY_LOCK_ACCOUNT_SIZE, LOCK_DISCRIMINATOR } from "./constants";
+import { findLockAccountPda, findLockTokenPda } from "./pdas";
+
+const TEST_ADDRESSES = {
+  owner: "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address,
+  mint: "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address,
+};
+const TOKEN_PROGRAM = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const SLOT = 250_000_000n;
+
+function encodeLock(amount: bigint, permanent = false) {
+  return getBase64Decoder().decode(
+    getLockAccountEncoder().encode({
+      discriminator: LOCK_DISCRIMINATOR,
+      owner: TEST_ADDRESSES.owner,
+      mint: TEST_ADDRESSES.mint,
+      amount,
+      unlockTimestamp: 1_800_000_000n,
+      createdAt: 1_700_000_000n,
+      lockId: 1n,
+      bump: 255,
+      frozen: false,
+      credentialIssuer: "11111111111111111111111111111111" as Address,
+      nonTransferable: false,
+      forwardDestination: "11111111111111111111111111111111" as Address,
+      hook: "11111111111111111111111111111111" as Address,
+      recoveryAddress: "11111111111111111111111111111111" as Address,
+      donationAddress: "11111111111111111111111111111111" as Address,
+      donationBps: 0,
+      collateralHolder: "11111111111111111111111111111111" as Address,
+      layoutVersion: 1,
+      unlockSlot: 0n,
+      unlockEpoch: 0n,
+      claimKey: new Uint8Array(32),
+      claimExpiresAt: 0n,
+      indexSeq: 0,
+      earlyUnlockAllowed: false,
+      permanent,
+      isNft: false,
+      receiptBound: false,
+      label: new Uint8Array(32),
+      metadataUri: new Uint8Array(128),
+      fallbackOwner: "11111111111111111111111111111111" as Address,
+      fallbackAfter: 0n,
+      unlockDestination: "11111111111111111111111111111111" as Address,
+      shared: false,
+      approvalsRequired: false,
+      priceFeedId: new Uint8Array(32),
+      priceThreshold: 0n,
+      priceExponent: 0,
+      arbiter: "11111111111111111111111111111111" as Address,
+      milestoneApproved: false,
+      counterparty: "11111111111111111111111111111111" as Address,
+      ownerCounted: false,
+      wrappedLamports: false,
+      receiptMint: "11111111111111111111111111111111" as Address,
+      feeMode: 0,
+      reserved: new Uint8Array(29),
+    })
+  );
+}
+
+/** SPL token account: [mint:32][owner:32][amount:u64], padded to 165 */
+function encodeEscrow(lock: Address, amount: bigint) {
+  const data = new Uint8Array(165);
+  data.set(getAddressEncoder().encode(TEST_ADDRESSES.mint), 0);
+  data.set(getAddressEncoder().encode(lock), 32);
+  data.set(getU64Encoder().encode(amount), 64);
+  return getBase64Decoder().decode(data);
+}
+
+async function fixture(escrowAmount = 1_000n, permanent = false) {
+  const [lock] = await findLockAccountPda(
+    TEST_ADDRESSES.owner,
+    TEST_ADDRESSES.mint,
+    1n
+  );
+  const [escrow] = await findLockTokenPda(lock);
+  const rpc = {
+    getMultipleAccounts: (addresses: Address[]) => ({
+      send: async () => {
+        expect(addresses).toEqual([lock, escrow]);
+        return {
+          context: { slot: SLOT },
+          value: [
+            {
+              owner: LOCKSMITH_PROGRAM_ADDRESS,
+              data: [encodeLock(1_000n, permanent), "base64"],
+            },
+            {
+              owner: TOKEN_PROGRAM,
+              data: [encodeEscrow(lock, escrowAmount), "base64"],
+            },
+          ],
+        };
+      },
+    }),
+  } as unknown as Rpc<GetMultipleAccountsApi>;
+  return { lock, escrow, rpc };
+}
+
+describe("createLockCertificate", () => {
+  it("captures both accounts at the same slot", async () => {
+    const { lock, escrow, rpc } = await fixture();
+    const certificate = await createLockCertificate(rpc, lock);
+
+    expect(certificate).toMatchObject({
+      version: 1,
+      programAddress: LOCKSMITH_PROGRAM_ADDRESS,
+      lockAddress: lock,
+      escrowAddress: escrow,
+      slot: SLOT.toString(),
+    });
+    expect(certificate.attestation).toBeUndefined();
+  });
+
+  it("rejects missing locks", async () => {
+    const { lock } = await fixture();
+    const rpc = {
+      getMultipleAccounts: () => ({
+        send: async () => ({ context: { slot: SLOT }, value: [null, null] }),
+      }),
+    } as unknown as Rpc<GetMultipleAccountsApi>;
+    await expect(createLockCertificate(rpc, lock)).rejects.toThrow(
+      "not found"
+    );
+  });
+});
+
+describe("verifyLockCertificate", () => {
+  it("accepts a consistent certificate and checks expectations", async () => {
+    const { lock, rpc } = await fixture();
+    const certificate = await createLockCertificate(rpc, lock);
+
+    const result = await verifyLockCertificate(certificate, {
+      owner: TEST_ADDRESSES.owner,
+      mint: TEST_ADDRESSES.mint,
+      minAmount: 1_000n,
+      lockedUntil: 1_800_000_000n,
+    });
+    expect(result.errors).toEqual([]);
+    expect(result.valid).toBe(true);
+    expect(result.escrowAmount).toBe(1_000n);
+
+    const tooStrict = await verifyLockCertificate(certificate, {
+      minAmount: 1_001n,
+      lockedUntil: 1_800_000_001n,
+    });
+    expect(tooStrict.valid).toBe(false);
+    expect(tooStrict.errors).toHaveLength(2);
+  });
+
+  it("treats permanent locks as locked until any date", async () => {
+    const { rpc, lock } = await fixture(1_000n, true);
+    const certificate = await createLockCertificate(rpc, lock);
+
+    const result = await verifyLockCertificate(certificate, {
+      lockedUntil: 1n << 62n,
+    });
+    expect(result.errors).toEqual([]);
+  });
+
+  it("accepts locks created before the frozen flag", async () => {
+    const { lock, rpc } = await fixture();
+    const certificate = await createLockCertificate(rpc, lock);
+    const legacyLockData = getBase64Decoder().decode(
+      getBase64Encoder()
+        .encode(certificate.lockData)
+        .slice(0, LEGACY_LOCK_ACCOUNT_SIZE)
+    );
+
+    const result = await verifyLockCertificate({
+      ...certificate,
+      lockData: legacyLockData,
+    });
+    expect(result.errors).toEqual([]);
+    expect(result.lock?.frozen).toBe(false);
+  });
+
+  it("flags an underfunded escrow", async () => {
+    const { lock, rpc } = await fixture(999n);
+    const certificate = await createLockCertificate(rpc, lock);
+
+    const result = await verifyLockCertificate(certificate);
+    expect(result.valid).toBe(false);
+    expect(result.errors).toContain(
+      "Escrow balance is below the locked amount"
+    );
+  });
+
+  it("flags a lock address that is not the lock's PDA", async () => {
+    const { lock, rpc } = await fixture();
+    const certificate = await createLockCertificate(rpc, lock);
+
+    const result = await verifyLockCertificate({
+      ...certificate,
+      lockAddress: TEST_ADDRESSES.owner,
+    });
+    expect(result.valid).toBe(false);
+    expect(result.errors).toContain("Lock address does not match its PDA");
+  });
+
+  it("verifies attestations and trusted attesters", async () => {
+    const { lock, rpc } = await fixture();
+    const attester = await generateKeyPairSigner();
+    const certificate = await createLockCertificate(rpc, lock, { attester });
+
+    expect(certificate.attestation?.signer).toBe(attester.address);
+    expect(
+      (
+        await verifyLockCertificate(certificate, undefined, {
+          trustedAttesters: [attester.address],
+        })
+      ).valid
+    ).toBe(true);
+
+    const untrusted = await verifyLockCertificate(certificate, undefined, {
+      trustedAttesters: [TEST_ADDRESSES.owner],
+    });
+    expect(untrusted.errors).toEqual(["Attestation signer is not trusted"]);
+
+    const tampered = await verifyLockCertificate({
+      ...certificate,
+      slot: (SLOT + 1n).toString(),
+    });
+    expect(tampered.errors).toEqual(["Attestation signature is invalid"]);
+  });
+
+  it("requires an attestation when attesters are trusted", async () => {
+    const { lock, rpc } = await fixture();
+    const certificate = await createLockCertificate(rpc, lock);
+
+    const result = await verifyLockCertificate(certificate, undefined, {
+      trustedAttesters: [TEST_ADDRESSES.owner],
+    });
+    expect(result.errors).toEqual(["Certificate is not attested"]);
+  });
+});
+
+describe("getLockCertificateMessage", () => {
+  it("binds the slot and both accounts", async () => {
+    const { lock, rpc } = await fixture();
+    const certificate = await createLockCertificate(rpc, lock);
+    const message = getLockCertificateMessage(certificate);
+
+    // domain (29) + 3 addresses + slot + lock data (773) + escrow data (165)
+    expect(message.length).toBe(29 + 3 * 32 + 8 + 773 + 165);
+  });
+});
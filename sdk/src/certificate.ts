@@ -0,0 +1,272 @@
+import {
+  getAddressDecoder,
+  getAddressEncoder,
+  getBase58Decoder,
+  getBase58Encoder,
+  getBase64Encoder,
+  getPublicKeyFromAddress,
+  getU64Encoder,
+  signBytes,
+  verifySignature,
+  type Address,
+  type GetMultipleAccountsApi,
+  type KeyPairSigner,
+  type Rpc,
+  type SignatureBytes,
+} from "@solana/kit";
+import { LOCKSMITH_PROGRAM_ADDRESS, type LockAccount } from "./generated";
+import { decodeLockAccountData } from "./accounts";
+import { LEGACY_LOCK_ACCOUNT_SIZE, LOCK_DISCRIMINATOR } from "./constants";
+import { findLockAccountPda, findLockTokenPda } from "./pdas";
+
+const CERTIFICATE_DOMAIN = new TextEncoder().encode(
+  "locksmith-lock-certificate-v1"
+);
+
+/**
+ * Self-contained proof that a lock existed at a given slot.
+ *
+ * Contains the raw lock and escrow account data as returned by RPC at
+ * `slot`, so a verifier can re-derive every claim without trusting the
+ * issuer's summary. An optional attestation signs the same data with a key
+ * the verifier trusts (e.g. the issuer's RPC operator or a validator
+ * identity).
+ */
+export type LockCertificate = {
+  version: 1;
+  programAddress: Address;
+  lockAddress: Address;
+  escrowAddress: Address;
+  /** Slot at which both accounts were read (decimal string) */
+  slot: string;
+  /** Base64 lock account data */
+  lockData: string;
+  /** Base64 escrow token account data */
+  escrowData: string;
+  attestation?: {
+    signer: Address;
+    /** Base58 Ed25519 signature over `getLockCertificateMessage` */
+    signature: string;
+  };
+};
+
+export type LockCertificateExpectations = {
+  owner?: Address;
+  mint?: Address;
+  /** Minimum locked amount */
+  minAmount?: bigint;
+  /**
+   * Lock must not be unlockable before this Unix timestamp; permanent locks
+   * always satisfy it
+   */
+  lockedUntil?: bigint;
+};
+
+export type LockCertificateVerification = {
+  valid: boolean;
+  errors: string[];
+  lock?: LockAccount;
+  escrowAmount?: bigint;
+};
+
+/**
+ * Bytes covered by a certificate attestation:
+ * domain || program || lock || escrow || slot:u64_le || lockData || escrowData
+ */
+export function getLockCertificateMessage(
+  certificate: Omit<LockCertificate, "attestation">
+): Uint8Array {
+  const addressEncoder = getAddressEncoder();
+  const base64 = getBase64Encoder();
+  const parts = [
+    CERTIFICATE_DOMAIN,
+    addressEncoder.encode(certificate.programAddress),
+    addressEncoder.encode(certificate.lockAddress),
+    addressEncoder.encode(certificate.escrowAddress),
+    getU64Encoder().encode(BigInt(certificate.slot)),
+    base64.encode(certificate.lockData),
+    base64.encode(certificate.escrowData),
+  ];
+  const message = new Uint8Array(parts.reduce((n, p) => n + p.length, 0));
+  let offset = 0;
+  for (const part of parts) {
+    message.set(part, offset);
+    offset += part.length;
+  }
+  return message;
+}
+
+/**
+ * Read a lock and its escrow in one RPC call and package them as a
+ * certificate, optionally signed by `attester`.
+ */
+export async function createLockCertificate(
+  rpc: Rpc<GetMultipleAccountsApi>,
+  lockAddress: Address,
+  options: {
+    attester?: KeyPairSigner;
+    programAddress?: Address;
+  } = {}
+): Promise<LockCertificate> {
+  const programAddress = options.programAddress ?? LOCKSMITH_PROGRAM_ADDRESS;
+  const [escrowAddress] = await findLockTokenPda(lockAddress, programAddress);
+
+  const { context, value } = await rpc
+    .getMultipleAccounts([lockAddress, escrowAddress], {
+      encoding: "base64",
+      commitment: "finalized",
+    })
+    .send();
+  const [lockAccount, escrowAccount] = value;
+  if (!lockAccount || lockAccount.owner !== programAddress) {
+    throw new Error(`Lock account ${lockAddress} not found`);
+  }
+  if (!escrowAccount) {
+    throw new Error(`Escrow account ${escrowAddress} not found`);
+  }
+
+  const certificate: LockCertificate = {
+    version: 1,
+    programAddress,
+    lockAddress,
+    escrowAddress,
+    slot: context.slot.toString(),
+    lockData: lockAccount.data[0],
+    escrowData: escrowAccount.data[0],
+  };
+
+  if (options.attester) {
+    const signature = await signBytes(
+      options.attester.keyPair.privateKey,
+      getLockCertificateMessage(certificate)
+    );
+    certificate.attestation = {
+      signer: options.attester.address,
+      signature: getBase58Decoder().decode(signature),
+    };
+  }
+
+  return certificate;
+}
+
+function bytesEqual(a: Uint8Array, b: Uint8Array) {
+  return a.length === b.length && a.every((byte, i) => byte === b[i]);
+}
+
+/**
+ * Verify a lock certificate offline.
+ *
+ * Checks that the lock data is a Locksmith lock at its own PDA, that the
+ * escrow is the lock's token PDA holding at least the recorded amount of
+ * the lock's mint, that any `expected` parameters hold, and that the
+ * attestation (if present) is valid and, when `trustedAttesters` is given,
+ * signed by one of them.
+ *
+ * Without an attestation, the certificate only proves internal
+ * consistency; confirm liveness with `fetchLockAccountCompat` if needed.
+ */
+export async function verifyLockCertificate(
+  certificate: LockCertificate,
+  expected: LockCertificateExpectations = {},
+  options: { trustedAttesters?: readonly Address[] } = {}
+): Promise<LockCertificateVerification> {
+  const errors: string[] = [];
+  const base64 = getBase64Encoder();
+  const addressDecoder = getAddressDecoder();
+
+  if (certificate.version !== 1) {
+    return { valid: false, errors: ["Unsupported certificate version"] };
+  }
+
+  const lockData = new Uint8Array(base64.encode(certificate.lockData));
+  if (
+    lockData.length < LEGACY_LOCK_ACCOUNT_SIZE ||
+    !bytesEqual(lockData.slice(0, 8), LOCK_DISCRIMINATOR)
+  ) {
+    return { valid: false, errors: ["Lock data is not a LockAccount"] };
+  }
+  const lock = decodeLockAccountData(lockData);
+
+  const [lockPda] = await findLockAccountPda(
+    lock.owner,
+    lock.mint,
+    lock.lockId,
+    certificate.programAddress
+  );
+  if (lockPda !== certificate.lockAddress) {
+    errors.push("Lock address does not match its PDA");
+  }
+  const [escrowPda] = await findLockTokenPda(
+    certificate.lockAddress,
+    certificate.programAddress
+  );
+  if (escrowPda !== certificate.escrowAddress) {
+    errors.push("Escrow address does not match the lock token PDA");
+  }
+
+  // SPL token account: [mint:32][owner:32][amount:u64] ...
+  const escrowData = new Uint8Array(base64.encode(certificate.escrowData));
+  let escrowAmount: bigint | undefined;
+  if (escrowData.length < 72) {
+    errors.push("Escrow data is not a token account");
+  } else {
+    const escrowMint = addressDecoder.decode(escrowData.slice(0, 32));
+    const escrowOwner = addressDecoder.decode(escrowData.slice(32, 64));
+    escrowAmount = new DataView(
+      escrowData.buffer,
+      escrowData.byteOffset
+    ).getBigUint64(64, true);
+    if (escrowMint !== lock.mint) {
+      errors.push("Escrow mint does not match the lock mint");
+    }
+    if (escrowOwner !== certificate.lockAddress) {
+      errors.push("Escrow is not owned by the lock");
+    }
+    if (escrowAmount < lock.amount) {
+      errors.push("Escrow balance is below the locked amount");
+    }
+  }
+
+  if (expected.owner && lock.owner !== expected.owner) {
+    errors.push("Owner does not match");
+  }
+  if (expected.mint && lock.mint !== expected.mint) {
+    errors.push("Mint does not match");
+  }
+  if (expected.minAmount !== undefined && lock.amount < expected.minAmount) {
+    errors.push("Locked amount is below the expected minimum");
+  }
+  if (
+    expected.lockedUntil !== undefined &&
+    !lock.permanent &&
+    lock.unlockTimestamp < expected.lockedUntil
+  ) {
+    errors.push("Lock expires before the expected date");
+  }
+
+  const { attestation } = certificate;
+  if (attestation) {
+    const publicKey = await getPublicKeyFromAddress(attestation.signer);
+    const signature = getBase58Encoder().encode(
+      attestation.signature
+    ) as SignatureBytes;
+    const ok = await verifySignature(
+      publicKey,
+      signature,
+      getLockCertificateMessage(certificate)
+    );
+    if (!ok) {
+      errors.push("Attestation signature is invalid");
+    }
+    if (
+      options.trustedAttesters &&
+      !options.trustedAttesters.includes(attestation.signer)
+    ) {
+      errors.push("Attestation signer is not trusted");
+    }
+  } else if (options.trustedAttesters) {
+    errors.push("Certificate is not attested");
+  }
+
+  return { valid: errors.length === 0, errors, lock, escrowAmount };
+}
@@ -0,0 +1,85 @@
+import { describe, it, expect } from "vitest";
+import { getAddressEncoder, type Address } from "@solana/kit";
+import {
+  generateClaimCode,
+  getClaimKey,
+  getClaimLockMessage,
+  getClaimLockVerifyInstruction,
+  isClaimCodeValid,
+} from "./claims";
+import { LOCKSMITH_PROGRAM_ADDRESS, type LockAccount } from "./generated";
+import { ED25519_PROGRAM_ADDRESS } from "./relay";
+
+const LOCK = "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address;
+const DESTINATION = "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde" as Address;
+
+describe("getClaimKey", () => {
+  it("derives the same key from the same code", async () => {
+    const code = generateClaimCode();
+
+    expect(await getClaimKey(code)).toEqual(await getClaimKey(code));
+    expect(await getClaimKey(code)).not.toEqual(
+      await getClaimKey(generateClaimCode())
+    );
+  });
+
+  it("rejects codes that are not 32 bytes", async () => {
+    await expect(getClaimKey(new Uint8Array(31))).rejects.toThrow();
+  });
+});
+
+describe("getClaimLockMessage", () => {
+  it("matches the program's claim_lock_message layout", () => {
+    const message = getClaimLockMessage(LOCK, DESTINATION);
+    const encoder = getAddressEncoder();
+
+    expect(message.length).toBe(119);
+    expect(new TextDecoder().decode(message.slice(0, 23))).toBe(
+      "locksmith-claim-lock-v1"
+    );
+    expect(message.slice(23, 55)).toEqual(
+      encoder.encode(LOCKSMITH_PROGRAM_ADDRESS)
+    );
+    expect(message.slice(55, 87)).toEqual(encoder.encode(LOCK));
+    expect(message.slice(87, 119)).toEqual(encoder.encode(DESTINATION));
+  });
+});
+
+describe("getClaimLockVerifyInstruction", () => {
+  it("has the claim key sign the claim message", async () => {
+    const code = generateClaimCode();
+    const instruction = await getClaimLockVerifyInstruction(
+      code,
+      LOCK,
+      DESTINATION
+    );
+    const data = instruction.data!;
+
+    expect(instruction.programAddress).toBe(ED25519_PROGRAM_ADDRESS);
+    expect(data.slice(16, 48)).toEqual(await getClaimKey(code));
+    expect(data.slice(112)).toEqual(getClaimLockMessage(LOCK, DESTINATION));
+  });
+});
+
+describe("isClaimCodeValid", () => {
+  it("accepts the committed code until it expires", async () => {
+    const code = generateClaimCode();
+    const lock = {
+      claimKey: await getClaimKey(code),
+      claimExpiresAt: 2_000n,
+    } as LockAccount;
+
+    expect(await isClaimCodeValid(lock, code, 1_999n)).toBe(true);
+    expect(await isClaimCodeValid(lock, code, 2_000n)).toBe(false);
+    expect(await isClaimCodeValid(lock, generateClaimCode(), 0n)).toBe(false);
+  });
+
+  it("rejects locks without a claim code", async () => {
+    const lock = {
+      claimKey: new Uint8Array(32),
+      claimExpiresAt: 2_000n,
+    } as LockAccount;
+
+    expect(await isClaimCodeValid(lock, new Uint8Array(32), 0n)).toBe(false);
+  });
+});
@@ -0,0 +1,98 @@
+import {
+  createKeyPairFromPrivateKeyBytes,
+  getAddressEncoder,
+  getAddressFromPublicKey,
+  signBytes,
+  type Address,
+  type Instruction,
+} from "@solana/kit";
+import { LOCKSMITH_PROGRAM_ADDRESS, type LockAccount } from "./generated";
+import { getEd25519VerifyInstruction } from "./relay";
+
+const CLAIM_LOCK_DOMAIN = new TextEncoder().encode("locksmith-claim-lock-v1");
+
+/**
+ * Generate a random 32-byte claim code for a claim-code lock. Share it with
+ * the recipient out of band; anyone holding it can claim the lock until it
+ * expires.
+ */
+export function generateClaimCode(): Uint8Array {
+  return crypto.getRandomValues(new Uint8Array(32));
+}
+
+async function getClaimKeyPair(claimCode: Uint8Array): Promise<CryptoKeyPair> {
+  if (claimCode.length !== 32) {
+    throw new Error("Claim code must be 32 bytes");
+  }
+  return createKeyPairFromPrivateKeyBytes(claimCode);
+}
+
+/**
+ * The `claimKey` lock option for `claimCode`: the public key of the
+ * ed25519 key pair whose seed is the code
+ */
+export async function getClaimKey(claimCode: Uint8Array): Promise<Uint8Array> {
+  const { publicKey } = await getClaimKeyPair(claimCode);
+  return getAddressEncoder().encode(await getAddressFromPublicKey(publicKey));
+}
+
+/**
+ * The message a claim key signs for `ClaimLock`:
+ * domain || program || lock || claimant token account
+ */
+export function getClaimLockMessage(
+  lockAddress: Address,
+  claimantTokenAccount: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Uint8Array {
+  const addressEncoder = getAddressEncoder();
+  const message = new Uint8Array(CLAIM_LOCK_DOMAIN.length + 96);
+  message.set(CLAIM_LOCK_DOMAIN, 0);
+  message.set(addressEncoder.encode(programAddress), 23);
+  message.set(addressEncoder.encode(lockAddress), 55);
+  message.set(addressEncoder.encode(claimantTokenAccount), 87);
+  return message;
+}
+
+/**
+ * Sign a claim of `lockAddress` to `claimantTokenAccount` with the claim
+ * key derived from `claimCode`, and return the ed25519 program instruction
+ * to place directly before `ClaimLock`. The code stays off chain, and the
+ * signature cannot be reused for any other token account.
+ */
+export async function getClaimLockVerifyInstruction(
+  claimCode: Uint8Array,
+  lockAddress: Address,
+  claimantTokenAccount: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<Instruction> {
+  const { privateKey, publicKey } = await getClaimKeyPair(claimCode);
+  const message = getClaimLockMessage(
+    lockAddress,
+    claimantTokenAccount,
+    programAddress
+  );
+  return getEd25519VerifyInstruction(
+    await getAddressFromPublicKey(publicKey),
+    await signBytes(privateKey, message),
+    message
+  );
+}
+
+/**
+ * Whether `claimCode` opens the claim-code `lock` and can still be
+ * redeemed at `now` (Unix seconds). Does not check that the lock has
+ * unlocked.
+ */
+export async function isClaimCodeValid(
+  lock: LockAccount,
+  claimCode: Uint8Array,
+  now: bigint
+): Promise<boolean> {
+  const hasClaimCode = lock.claimKey.some((byte) => byte !== 0);
+  if (!hasClaimCode || now >= lock.claimExpiresAt) {
+    return false;
+  }
+  const key = await getClaimKey(claimCode);
+  return key.every((byte, i) => byte === lock.claimKey[i]);
+}
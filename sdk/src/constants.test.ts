@@ -1,13 +1,66 @@
 import { describe, it, expect } from "vitest";
 import {
   USDC_MINT,
+  NATIVE_MINT,
   FEE_USDC,
   MAX_LOCK_DURATION_SECONDS,
+  MAX_LOCK_DURATION_SLOTS,
+  MAX_LOCK_DURATION_EPOCHS,
+  COMPLIANCE_TIMELOCK_SECONDS,
+  FEE_MINT_DECIMALS,
+  LOCK_FULL_BALANCE,
+  FEE_MINT_TIMELOCK_SECONDS,
+  ABANDONMENT_PERIOD_SECONDS,
+  CRANK_TIP_LAMPORTS,
+  BPS_DENOMINATOR,
+  MAX_HOOK_COMPUTE_UNITS,
+  HOOK_EVENT_CREATED,
+  HOOK_EVENT_UNLOCKED,
   CONFIG_DISCRIMINATOR,
   LOCK_DISCRIMINATOR,
+  TEMPLATE_DISCRIMINATOR,
+  STANDING_ORDER_DISCRIMINATOR,
+  FORWARDING_DESTINATION_DISCRIMINATOR,
+  HOOK_DISCRIMINATOR,
+  LENDER_DISCRIMINATOR,
+  SESSION_DISCRIMINATOR,
+  NOTE_DISCRIMINATOR,
+  MINT_INDEX_DISCRIMINATOR,
+  INDEX_ENTRY_DISCRIMINATOR,
+  LOCK_CERTIFICATE_DISCRIMINATOR,
+  VOTE_WEIGHT_SNAPSHOT_DISCRIMINATOR,
+  LOCK_PROOF_DISCRIMINATOR,
+  FEE_EXEMPTION_DISCRIMINATOR,
+  OWNER_COUNTER_DISCRIMINATOR,
+  FEE_SCHEDULE_DISCRIMINATOR,
+  FEE_CAP_DISCRIMINATOR,
+  COLLATERAL_ATTESTATION_DISCRIMINATOR,
+  LEGACY_CONFIG_ACCOUNT_SIZE,
+  COMPLIANCE_CONFIG_ACCOUNT_SIZE,
+  FEE_MINT_CONFIG_ACCOUNT_SIZE,
+  SUCCESSOR_CONFIG_ACCOUNT_SIZE,
+  SUNSET_CONFIG_ACCOUNT_SIZE,
+  PENALTY_CONFIG_ACCOUNT_SIZE,
+  BATCH_FEE_CONFIG_ACCOUNT_SIZE,
+  PAUSE_CONFIG_ACCOUNT_SIZE,
+  MINT_POLICY_CONFIG_ACCOUNT_SIZE,
+  FREEZABLE_CONFIG_ACCOUNT_SIZE,
+  FEE_CONFIG_ACCOUNT_SIZE,
+  BPS_FEE_CONFIG_ACCOUNT_SIZE,
+  SOL_FEE_CONFIG_ACCOUNT_SIZE,
+  FEE_SCHEDULE_CONFIG_ACCOUNT_SIZE,
+  MINT_POLICY_DISCRIMINATOR,
+  LEGACY_LOCK_ACCOUNT_SIZE,
+  LOCK_LAYOUT_VERSION,
+  V1_LOCK_ACCOUNT_SIZE,
+  LEGACY_STANDING_ORDER_ACCOUNT_SIZE,
 } from "./constants";
 import { LOCKSMITH_PROGRAM_ADDRESS } from "./generated";
-import { getConfigAccountSize, getLockAccountSize } from "./generated";
+import {
+  getConfigAccountSize,
+  getLockAccountSize,
+  getStandingOrderAccountSize,
+} from "./generated";
 
 /**
  * These tests validate that SDK constants match the Rust program constants.
@@ -18,8 +71,19 @@ import { getConfigAccountSize, getLockAccountSize } from "./generated";
  *   - MAX_LOCK_DURATION_SECONDS: 315_360_000 (10 years)
  *   - ConfigAccount::DISCRIMINATOR: "CONFIG\0\0"
  *   - LockAccount::DISCRIMINATOR: "LOCK\0\0\0\0"
- *   - ConfigAccount::SIZE: 41
- *   - LockAccount::SIZE: 105
+ *   - TemplateAccount::DISCRIMINATOR: "TEMPLATE"
+ *   - StandingOrderAccount::DISCRIMINATOR: "STORDER\0"
+ *   - ForwardingDestinationAccount::DISCRIMINATOR: "FORWARD\0"
+ *   - HookAccount::DISCRIMINATOR: "HOOK\0\0\0\0"
+ *   - ConfigAccount::SIZE: 225 (LEGACY_SIZE: 41)
+ *   - LenderAccount::DISCRIMINATOR: "LENDER\0\0"
+ *   - SessionAccount::DISCRIMINATOR: "SESSION\0"
+ *   - NoteAccount::DISCRIMINATOR: "NOTE\0\0\0\0"
+ *   - MintIndexAccount::DISCRIMINATOR: "MINTIDX\0"
+ *   - IndexEntryAccount::DISCRIMINATOR: "IDXENTRY"
+ *   - LockCertificateAccount::DISCRIMINATOR: "LOCKCERT"
+ *   - LockAccount::LAYOUT_VERSION: 2
+ *   - LockAccount::SIZE: 773 (LEGACY_SIZE: 105, V1_SIZE: 366)
  */
 
 describe("USDC Mint constant", () => {
@@ -38,6 +102,13 @@ describe("USDC Mint constant", () => {
   });
 });
 
+describe("Native mint constant", () => {
+  it("matches the SPL Token native mint", () => {
+    // spl_token::native_mint::ID
+    expect(NATIVE_MINT).toBe("So11111111111111111111111111111111111111112");
+  });
+});
+
 describe("Fee constant", () => {
   it("FEE_USDC matches Rust constant (0.15 USDC)", () => {
     // USDC has 6 decimals, so 0.15 USDC = 150,000 base units
@@ -99,6 +170,70 @@ describe("Max lock duration constant", () => {
   });
 });
 
+describe("Max lock duration in slots", () => {
+  it("matches Rust constant (10 years at 400ms slots)", () => {
+    expect(MAX_LOCK_DURATION_SLOTS).toBe(788_400_000n);
+    expect(MAX_LOCK_DURATION_SLOTS).toBe(
+      (MAX_LOCK_DURATION_SECONDS * 1000n) / 400n
+    );
+  });
+
+  it("epoch bound matches Rust constant (432,000-slot epochs)", () => {
+    expect(MAX_LOCK_DURATION_EPOCHS).toBe(1_825n);
+  });
+});
+
+describe("Compliance timelock constant", () => {
+  it("matches Rust constant (48 hours in seconds)", () => {
+    expect(COMPLIANCE_TIMELOCK_SECONDS).toBe(172_800n);
+  });
+});
+
+describe("Fee mint constants", () => {
+  it("match Rust constants (6 decimals, 7 days in seconds)", () => {
+    expect(FEE_MINT_DECIMALS).toBe(6);
+    expect(FEE_MINT_TIMELOCK_SECONDS).toBe(604_800n);
+  });
+});
+
+describe("Full-balance lock sentinel", () => {
+  it("matches Rust constant (u64::MAX)", () => {
+    expect(LOCK_FULL_BALANCE).toBe(18_446_744_073_709_551_615n);
+  });
+});
+
+describe("Abandonment period constant", () => {
+  it("matches Rust constant (5 years in seconds)", () => {
+    expect(ABANDONMENT_PERIOD_SECONDS).toBe(157_680_000n);
+  });
+});
+
+describe("Crank tip constant", () => {
+  it("matches Rust constant (0.0001 SOL)", () => {
+    expect(CRANK_TIP_LAMPORTS).toBe(100_000n);
+  });
+});
+
+describe("Basis point denominator", () => {
+  it("matches Rust constant (100%)", () => {
+    expect(BPS_DENOMINATOR).toBe(10_000);
+  });
+});
+
+describe("Hook constants", () => {
+  it("match Rust constants", () => {
+    expect(MAX_HOOK_COMPUTE_UNITS).toBe(50_000);
+    expect(HOOK_EVENT_CREATED).toBe(1);
+    expect(HOOK_EVENT_UNLOCKED).toBe(2);
+  });
+});
+
+describe("Lock layout version", () => {
+  it("matches Rust constant", () => {
+    expect(LOCK_LAYOUT_VERSION).toBe(2);
+  });
+});
+
 describe("Program address", () => {
   it("is a valid Solana address", () => {
     const base58Regex = /^[1-9A-HJ-NP-Za-km-z]+$/;
@@ -126,29 +261,246 @@ describe("Account discriminators", () => {
     expect(Array.from(LOCK_DISCRIMINATOR)).toEqual(Array.from(expected));
   });
 
+  it("TEMPLATE_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("TEMPLATE");
+    expect(Array.from(TEMPLATE_DISCRIMINATOR)).toEqual(Array.from(expected));
+  });
+
+  it("STANDING_ORDER_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("STORDER\0");
+    expect(Array.from(STANDING_ORDER_DISCRIMINATOR)).toEqual(
+      Array.from(expected)
+    );
+  });
+
+  it("FORWARDING_DESTINATION_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("FORWARD\0");
+    expect(Array.from(FORWARDING_DESTINATION_DISCRIMINATOR)).toEqual(
+      Array.from(expected)
+    );
+  });
+
+  it("HOOK_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("HOOK\0\0\0\0");
+    expect(Array.from(HOOK_DISCRIMINATOR)).toEqual(Array.from(expected));
+  });
+
+  it("LENDER_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("LENDER\0\0");
+    expect(Array.from(LENDER_DISCRIMINATOR)).toEqual(Array.from(expected));
+  });
+
+  it("SESSION_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("SESSION\0");
+    expect(Array.from(SESSION_DISCRIMINATOR)).toEqual(Array.from(expected));
+  });
+
+  it("NOTE_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("NOTE\0\0\0\0");
+    expect(Array.from(NOTE_DISCRIMINATOR)).toEqual(Array.from(expected));
+  });
+
+  it("mint index discriminators are correct ASCII bytes", () => {
+    expect(Array.from(MINT_INDEX_DISCRIMINATOR)).toEqual(
+      Array.from(new TextEncoder().encode("MINTIDX\0"))
+    );
+    expect(Array.from(INDEX_ENTRY_DISCRIMINATOR)).toEqual(
+      Array.from(new TextEncoder().encode("IDXENTRY"))
+    );
+  });
+
+  it("LOCK_CERTIFICATE_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("LOCKCERT");
+    expect(Array.from(LOCK_CERTIFICATE_DISCRIMINATOR)).toEqual(
+      Array.from(expected)
+    );
+  });
+
+  it("MINT_POLICY_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("MINTPLCY");
+    expect(Array.from(MINT_POLICY_DISCRIMINATOR)).toEqual(Array.from(expected));
+  });
+
+  it("VOTE_WEIGHT_SNAPSHOT_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("VOTEWGHT");
+    expect(Array.from(VOTE_WEIGHT_SNAPSHOT_DISCRIMINATOR)).toEqual(
+      Array.from(expected)
+    );
+  });
+
+  it("LOCK_PROOF_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("LOCKPROF");
+    expect(Array.from(LOCK_PROOF_DISCRIMINATOR)).toEqual(Array.from(expected));
+  });
+
+  it("FEE_EXEMPTION_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("FEEEXMPT");
+    expect(Array.from(FEE_EXEMPTION_DISCRIMINATOR)).toEqual(
+      Array.from(expected)
+    );
+  });
+
+  it("FEE_SCHEDULE_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("FEESCHED");
+    expect(Array.from(FEE_SCHEDULE_DISCRIMINATOR)).toEqual(
+      Array.from(expected)
+    );
+  });
+
+  it("FEE_CAP_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("FEECAP\0\0");
+    expect(Array.from(FEE_CAP_DISCRIMINATOR)).toEqual(Array.from(expected));
+  });
+
+  it("OWNER_COUNTER_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("OWNRCNTR");
+    expect(Array.from(OWNER_COUNTER_DISCRIMINATOR)).toEqual(
+      Array.from(expected)
+    );
+  });
+
+  it("COLLATERAL_ATTESTATION_DISCRIMINATOR is correct ASCII bytes", () => {
+    const expected = new TextEncoder().encode("COLLATRL");
+    expect(Array.from(COLLATERAL_ATTESTATION_DISCRIMINATOR)).toEqual(
+      Array.from(expected)
+    );
+  });
+
   it("discriminators are 8 bytes each", () => {
     expect(CONFIG_DISCRIMINATOR.length).toBe(8);
     expect(LOCK_DISCRIMINATOR.length).toBe(8);
+    expect(TEMPLATE_DISCRIMINATOR.length).toBe(8);
+    expect(STANDING_ORDER_DISCRIMINATOR.length).toBe(8);
+    expect(FORWARDING_DESTINATION_DISCRIMINATOR.length).toBe(8);
+    expect(HOOK_DISCRIMINATOR.length).toBe(8);
+    expect(LENDER_DISCRIMINATOR.length).toBe(8);
+    expect(SESSION_DISCRIMINATOR.length).toBe(8);
+    expect(NOTE_DISCRIMINATOR.length).toBe(8);
+    expect(MINT_INDEX_DISCRIMINATOR.length).toBe(8);
+    expect(INDEX_ENTRY_DISCRIMINATOR.length).toBe(8);
+    expect(LOCK_CERTIFICATE_DISCRIMINATOR.length).toBe(8);
+    expect(VOTE_WEIGHT_SNAPSHOT_DISCRIMINATOR.length).toBe(8);
+    expect(MINT_POLICY_DISCRIMINATOR.length).toBe(8);
+    expect(LOCK_PROOF_DISCRIMINATOR.length).toBe(8);
+    expect(FEE_EXEMPTION_DISCRIMINATOR.length).toBe(8);
+    expect(OWNER_COUNTER_DISCRIMINATOR.length).toBe(8);
+    expect(FEE_SCHEDULE_DISCRIMINATOR.length).toBe(8);
+    expect(FEE_CAP_DISCRIMINATOR.length).toBe(8);
   });
 });
 
 describe("Account sizes", () => {
-  it("ConfigAccount size matches Rust (41 bytes)", () => {
+  it("ConfigAccount size matches Rust (249 bytes)", () => {
     // 8 (discriminator) + 32 (admin) + 1 (bump) = 41
-    expect(getConfigAccountSize()).toBe(41);
+    expect(LEGACY_CONFIG_ACCOUNT_SIZE).toBe(41);
+    // + 32 (compliance_authority) + 32 (pending_compliance_authority)
+    // + 8 (compliance_authority_effective_at) = 113
+    expect(COMPLIANCE_CONFIG_ACCOUNT_SIZE).toBe(113);
+    // + 32 (fee_mint) + 32 (pending_fee_mint) + 8 (fee_mint_effective_at)
+    // = 185
+    expect(FEE_MINT_CONFIG_ACCOUNT_SIZE).toBe(185);
+    // + 32 (successor_program) = 217
+    expect(SUCCESSOR_CONFIG_ACCOUNT_SIZE).toBe(217);
+    // + 8 (sunset_at) = 225
+    expect(SUNSET_CONFIG_ACCOUNT_SIZE).toBe(225);
+    // + 2 (early_unlock_penalty_bps) = 227
+    expect(PENALTY_CONFIG_ACCOUNT_SIZE).toBe(227);
+    // + 1 (batch_fee_per_lock) = 228
+    expect(BATCH_FEE_CONFIG_ACCOUNT_SIZE).toBe(228);
+    // + 1 (paused) = 229
+    expect(PAUSE_CONFIG_ACCOUNT_SIZE).toBe(229);
+    // + 1 (mint_policy_mode) = 230
+    expect(MINT_POLICY_CONFIG_ACCOUNT_SIZE).toBe(230);
+    // + 1 (reject_freezable_mints) = 231
+    expect(FREEZABLE_CONFIG_ACCOUNT_SIZE).toBe(231);
+    // + 8 (fee_usdc) = 239
+    expect(FEE_CONFIG_ACCOUNT_SIZE).toBe(239);
+    // + 2 (fee_bps) = 241
+    expect(BPS_FEE_CONFIG_ACCOUNT_SIZE).toBe(241);
+    // + 8 (fee_lamports) = 249
+    expect(SOL_FEE_CONFIG_ACCOUNT_SIZE).toBe(249);
+    // + 1 (fee_tiered) = 250
+    expect(FEE_SCHEDULE_CONFIG_ACCOUNT_SIZE).toBe(250);
+    // + 4 (max_locks_per_owner) = 254
+    expect(getConfigAccountSize()).toBe(254);
   });
 
-  it("LockAccount size matches Rust (105 bytes)", () => {
+  it("LockAccount size matches Rust (773 bytes)", () => {
     // 8 (discriminator) + 32 (owner) + 32 (mint) + 8 (amount)
     // + 8 (unlock_timestamp) + 8 (created_at) + 8 (lock_id) + 1 (bump) = 105
-    expect(getLockAccountSize()).toBe(105);
+    expect(LEGACY_LOCK_ACCOUNT_SIZE).toBe(105);
+    // + 1 (frozen) + 32 (credential_issuer) + 1 (non_transferable)
+    // + 32 (forward_destination) + 32 (hook) + 32 (recovery_address)
+    // + 32 (donation_address) + 2 (donation_bps)
+    // + 32 (collateral_holder) + 1 (layout_version) + 8 (unlock_slot)
+    // + 8 (unlock_epoch) + 32 (claim_key) + 8 (claim_expires_at)
+    // + 4 (index_seq) + 1 (early_unlock_allowed) + 1 (permanent)
+    // + 1 (is_nft) + 1 (receipt_bound) + 32 (label)
+    // + 128 (metadata_uri) + 32 (fallback_owner) + 8 (fallback_after)
+    // + 32 (unlock_destination) + 1 (shared) + 1 (approvals_required)
+    // + 32 (price_feed_id) + 8 (price_threshold) + 4 (price_exponent)
+    // + 32 (arbiter) + 1 (milestone_approved) + 32 (counterparty)
+    // + 1 (owner_counted) + 1 (wrapped_lamports) + 32 (receipt_mint)
+    // + 1 (fee_mode) + 29 (reserved) = 773
+    expect(getLockAccountSize()).toBe(773);
+    // Layout version 1 ended with receipt_bound
+    expect(V1_LOCK_ACCOUNT_SIZE).toBe(366);
+  });
+
+  it("StandingOrderAccount size matches Rust (197 bytes)", () => {
+    // 8 (discriminator) + 4 * 32 (owner, mint, token accounts) + 8 (order_id)
+    // + 8 (amount_per_lock) + 8 (interval_seconds)
+    // + 8 (lock_duration_seconds) + 8 (next_execution_at) + 8 (next_lock_id)
+    // + 4 (remaining_executions) + 1 (bump) + 8 (fee_usdc) = 197
+    expect(getStandingOrderAccountSize()).toBe(197);
+    // Orders before fee_usdc ended with bump
+    expect(LEGACY_STANDING_ORDER_ACCOUNT_SIZE).toBe(189);
   });
 
   it("ConfigAccount size breakdown is correct", () => {
     const discriminator = 8;
     const admin = 32;
     const bump = 1;
-    const expected = discriminator + admin + bump;
+    const complianceAuthority = 32;
+    const pendingComplianceAuthority = 32;
+    const complianceAuthorityEffectiveAt = 8;
+    const feeMint = 32;
+    const pendingFeeMint = 32;
+    const feeMintEffectiveAt = 8;
+    const successorProgram = 32;
+    const sunsetAt = 8;
+    const earlyUnlockPenaltyBps = 2;
+    const batchFeePerLock = 1;
+    const paused = 1;
+    const mintPolicyMode = 1;
+    const rejectFreezableMints = 1;
+    const feeUsdc = 8;
+    const feeBps = 2;
+    const feeLamports = 8;
+    const feeTiered = 1;
+    const maxLocksPerOwner = 4;
+    const expected =
+      discriminator +
+      admin +
+      bump +
+      complianceAuthority +
+      pendingComplianceAuthority +
+      complianceAuthorityEffectiveAt +
+      feeMint +
+      pendingFeeMint +
+      feeMintEffectiveAt +
+      successorProgram +
+      sunsetAt +
+      earlyUnlockPenaltyBps +
+      batchFeePerLock +
+      paused +
+      mintPolicyMode +
+      rejectFreezableMints +
+      feeUsdc +
+      feeBps +
+      feeLamports +
+      feeTiered +
+      maxLocksPerOwner;
 
     expect(getConfigAccountSize()).toBe(expected);
   });
@@ -162,6 +514,43 @@ describe("Account sizes", () => {
     const createdAt = 8;
     const lockId = 8;
     const bump = 1;
+    const frozen = 1;
+    const credentialIssuer = 32;
+    const nonTransferable = 1;
+    const forwardDestination = 32;
+    const hook = 32;
+    const recoveryAddress = 32;
+    const donationAddress = 32;
+    const donationBps = 2;
+    const collateralHolder = 32;
+    const layoutVersion = 1;
+    const unlockSlot = 8;
+    const unlockEpoch = 8;
+    const claimKey = 32;
+    const claimExpiresAt = 8;
+    const indexSeq = 4;
+    const earlyUnlockAllowed = 1;
+    const permanent = 1;
+    const isNft = 1;
+    const receiptBound = 1;
+    const label = 32;
+    const metadataUri = 128;
+    const fallbackOwner = 32;
+    const fallbackAfter = 8;
+    const unlockDestination = 32;
+    const shared = 1;
+    const approvalsRequired = 1;
+    const priceFeedId = 32;
+    const priceThreshold = 8;
+    const priceExponent = 4;
+    const arbiter = 32;
+    const milestoneApproved = 1;
+    const counterparty = 32;
+    const ownerCounted = 1;
+    const wrappedLamports = 1;
+    const receiptMint = 32;
+    const feeMode = 1;
+    const reserved = 29;
     const expected =
       discriminator +
       owner +
@@ -170,7 +559,44 @@ describe("Account sizes", () => {
       unlockTimestamp +
       createdAt +
       lockId +
-      bump;
+      bump +
+      frozen +
+      credentialIssuer +
+      nonTransferable +
+      forwardDestination +
+      hook +
+      recoveryAddress +
+      donationAddress +
+      donationBps +
+      collateralHolder +
+      layoutVersion +
+      unlockSlot +
+      unlockEpoch +
+      claimKey +
+      claimExpiresAt +
+      indexSeq +
+      earlyUnlockAllowed +
+      permanent +
+      isNft +
+      receiptBound +
+      label +
+      metadataUri +
+      fallbackOwner +
+      fallbackAfter +
+      unlockDestination +
+      shared +
+      approvalsRequired +
+      priceFeedId +
+      priceThreshold +
+      priceExponent +
+      arbiter +
+      milestoneApproved +
+      counterparty +
+      ownerCounted +
+      wrappedLamports +
+      receiptMint +
+      feeMode +
+      reserved;
 
     expect(getLockAccountSize()).toBe(expected);
   });
@@ -188,12 +614,12 @@ describe("Cross-validation with Rust tests", () => {
   });
 
   it("ConfigAccount size matches test_config_account_size", () => {
-    // From Rust: assert_eq!(ConfigAccount::SIZE, 41);
-    expect(getConfigAccountSize()).toBe(41);
+    // From Rust: assert_eq!(ConfigAccount::SIZE, 254);
+    expect(getConfigAccountSize()).toBe(254);
   });
 
   it("LockAccount size matches test_lock_account_size", () => {
-    // From Rust: assert_eq!(LockAccount::SIZE, 105);
-    expect(getLockAccountSize()).toBe(105);
+    // From Rust: assert_eq!(LockAccount::SIZE, 773);
+    expect(getLockAccountSize()).toBe(773);
   });
 });
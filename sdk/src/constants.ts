@@ -7,15 +7,274 @@ export const USDC_MINT =
   "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" as Address<"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v">;
 
 /**
- * Fee amount: 0.15 USDC (USDC has 6 decimals)
+ * Wrapped SOL mint. Locks of this mint may pass the owner's wallet as the
+ * token account to lock, or unlock to, lamports directly.
+ */
+export const NATIVE_MINT =
+  "So11111111111111111111111111111111111111112" as Address<"So11111111111111111111111111111111111111112">;
+
+/**
+ * SPL Associated Token Account program address
+ */
+export const ASSOCIATED_TOKEN_PROGRAM_ADDRESS =
+  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">;
+
+/**
+ * Metaplex Token Metadata program address, which holds lock receipts'
+ * metadata
+ */
+export const TOKEN_METADATA_PROGRAM_ADDRESS =
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s" as Address<"metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s">;
+
+/**
+ * Pyth receiver program address, which owns the verified price updates
+ * price-conditional locks unlock against
+ */
+export const PYTH_RECEIVER_PROGRAM_ADDRESS =
+  "rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LyC6" as Address<"rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LyC6">;
+
+/**
+ * Default fee amount: 0.15 USDC (USDC has 6 decimals), charged until
+ * `UpdateFeeConfig` sets another (see `ConfigAccount.feeUsdc`)
  */
 export const FEE_USDC = 150_000n;
 
+/**
+ * Largest fee `UpdateFeeConfig` accepts: 10 USDC
+ */
+export const MAX_FEE_USDC = 10_000_000n;
+
+/**
+ * Lock amount meaning "the source token account's whole balance", resolved
+ * on-chain when the lock is created (u64::MAX)
+ */
+export const LOCK_FULL_BALANCE = 0xffff_ffff_ffff_ffffn;
+
+/**
+ * Decimals a fee mint must have for `FEE_USDC` to keep its value
+ */
+export const FEE_MINT_DECIMALS = 6;
+
+/**
+ * Delay before a proposed fee mint can be migrated to: 7 days
+ */
+export const FEE_MINT_TIMELOCK_SECONDS = 7n * 24n * 60n * 60n;
+
 /**
  * Maximum lock duration: 10 years in seconds
  */
 export const MAX_LOCK_DURATION_SECONDS = 10n * 365n * 24n * 60n * 60n;
 
+/**
+ * Target slot time in milliseconds, converting slots into time
+ */
+export const TARGET_MS_PER_SLOT = 400n;
+
+/**
+ * Slots per epoch, converting epochs into time
+ */
+export const SLOTS_PER_EPOCH = 432_000n;
+
+/**
+ * Maximum lock duration of slot-based locks: 10 years at the 400ms target
+ * slot time
+ */
+export const MAX_LOCK_DURATION_SLOTS =
+  (MAX_LOCK_DURATION_SECONDS * 1000n) / TARGET_MS_PER_SLOT;
+
+/**
+ * Maximum lock duration of epoch-based locks: 10 years of 432,000-slot
+ * epochs
+ */
+export const MAX_LOCK_DURATION_EPOCHS =
+  MAX_LOCK_DURATION_SLOTS / SLOTS_PER_EPOCH;
+
+/**
+ * Delay before a newly appointed compliance authority can act: 48 hours
+ */
+export const COMPLIANCE_TIMELOCK_SECONDS = 48n * 60n * 60n;
+
+/**
+ * Time after unlock before an unclaimed lock with a recovery address may be
+ * swept to it: 5 years
+ */
+export const ABANDONMENT_PERIOD_SECONDS = 5n * 365n * 24n * 60n * 60n;
+
+/**
+ * Lamports of a lock account's rent that `CrankUnlock` pays the cranker
+ * (0.0001 SOL); the owner gets the rest
+ */
+export const CRANK_TIP_LAMPORTS = 100_000n;
+
+/**
+ * Basis points in 100%, the scale of a lock's `donationBps`
+ */
+export const BPS_DENOMINATOR = 10_000;
+
+/**
+ * Upper bound on a hook's compute allowance
+ */
+export const MAX_HOOK_COMPUTE_UNITS = 50_000;
+
+/**
+ * Upper bound on the size of a lock's encrypted note
+ */
+export const MAX_NOTE_LEN = 256;
+
+/**
+ * Size of a lock's `metadataUri`, zero-padded
+ */
+export const METADATA_URI_LEN = 128;
+
+/**
+ * Most beneficiaries `SetLockShares` can split a lock among
+ */
+export const MAX_BENEFICIARIES = 8;
+
+/**
+ * Most approvers `SetUnlockApprovers` can give a lock
+ */
+export const MAX_APPROVERS = 8;
+
+/**
+ * Oldest price update a price-conditional unlock accepts, in seconds
+ */
+export const MAX_PRICE_AGE_SECONDS = 60n;
+
+/**
+ * Widest confidence interval a price-conditional unlock accepts, in basis
+ * points of the price (2%)
+ */
+export const MAX_PRICE_CONFIDENCE_BPS = 200;
+
+/**
+ * Largest decimal exponent, either way, of a lock's price threshold
+ */
+export const MAX_PRICE_EXPONENT = 18;
+
+/**
+ * Hook event bits: `SetHook` subscribes to them and callbacks carry one
+ */
+export const HOOK_EVENT_CREATED = 1;
+export const HOOK_EVENT_UNLOCKED = 2;
+
+/**
+ * `layoutVersion` of locks created, or grown, by this program version;
+ * locks created before the version byte read 0
+ */
+export const LOCK_LAYOUT_VERSION = 2;
+
+/**
+ * Account sizes before the compliance fields were appended. Accounts
+ * created earlier keep these sizes until the program grows them; decode
+ * them with the helpers in `accounts.ts`.
+ */
+export const LEGACY_CONFIG_ACCOUNT_SIZE = 41;
+/**
+ * ConfigAccount size before the fee mint fields were appended. Such
+ * configs charge fees in USDC.
+ */
+export const COMPLIANCE_CONFIG_ACCOUNT_SIZE = 113;
+/**
+ * ConfigAccount size before the successor program was appended. Such
+ * configs declare no successor.
+ */
+export const FEE_MINT_CONFIG_ACCOUNT_SIZE = 185;
+/**
+ * ConfigAccount size before `sunsetAt` was appended. Such configs have not
+ * been sunset.
+ */
+export const SUCCESSOR_CONFIG_ACCOUNT_SIZE = 217;
+/**
+ * ConfigAccount size before `earlyUnlockPenaltyBps` was appended. Such
+ * configs charge no early unlock penalty.
+ */
+export const SUNSET_CONFIG_ACCOUNT_SIZE = 225;
+/**
+ * ConfigAccount size before `batchFeePerLock` was appended. Such configs
+ * charge one fee per lock batch.
+ */
+export const PENALTY_CONFIG_ACCOUNT_SIZE = 227;
+/**
+ * ConfigAccount size before `paused` was appended. Such configs are not
+ * paused.
+ */
+export const BATCH_FEE_CONFIG_ACCOUNT_SIZE = 228;
+/**
+ * ConfigAccount size before `mintPolicyMode` was appended. Such configs
+ * accept any mint.
+ */
+export const PAUSE_CONFIG_ACCOUNT_SIZE = 229;
+/**
+ * ConfigAccount size before `rejectFreezableMints` was appended. Such
+ * configs accept mints with a freeze authority.
+ */
+export const MINT_POLICY_CONFIG_ACCOUNT_SIZE = 230;
+/**
+ * ConfigAccount size before `feeUsdc` was appended. Such configs charge
+ * `FEE_USDC`.
+ */
+export const FREEZABLE_CONFIG_ACCOUNT_SIZE = 231;
+/**
+ * ConfigAccount size before `feeBps` was appended. Such configs charge the
+ * flat fee.
+ */
+export const FEE_CONFIG_ACCOUNT_SIZE = 239;
+/**
+ * ConfigAccount size before `feeLamports` was appended. Such configs do not
+ * accept fees in SOL.
+ */
+export const BPS_FEE_CONFIG_ACCOUNT_SIZE = 241;
+/**
+ * ConfigAccount size before `feeTiered` was appended. Such configs charge
+ * one flat fee.
+ */
+export const SOL_FEE_CONFIG_ACCOUNT_SIZE = 249;
+/**
+ * ConfigAccount size before `maxLocksPerOwner` was appended. Such configs
+ * do not cap locks per owner.
+ */
+export const FEE_SCHEDULE_CONFIG_ACCOUNT_SIZE = 250;
+export const LEGACY_LOCK_ACCOUNT_SIZE = 105;
+/**
+ * LockAccount size at layout version 1. Such locks end with `receiptBound`
+ * whatever their length.
+ */
+export const V1_LOCK_ACCOUNT_SIZE = 366;
+/**
+ * StandingOrderAccount size before `feeUsdc` was appended. Such orders
+ * approved `FEE_USDC` per execution and charge at most that.
+ */
+export const LEGACY_STANDING_ORDER_ACCOUNT_SIZE = 189;
+
+/**
+ * `ConfigAccount.mintPolicyMode` values: any mint can be locked, only mints
+ * whose policy allows them, or all but mints whose policy denies them
+ */
+export const MINT_POLICY_NONE = 0;
+export const MINT_POLICY_ALLOWLIST = 1;
+export const MINT_POLICY_DENYLIST = 2;
+
+/**
+ * Upper bound on `ConfigAccount.earlyUnlockPenaltyBps`: 50%
+ */
+export const MAX_EARLY_UNLOCK_PENALTY_BPS = 5_000;
+
+/**
+ * Upper bound on `ConfigAccount.feeBps`: 1%
+ */
+export const MAX_FEE_BPS = 100;
+
+/**
+ * Upper bound on `ConfigAccount.feeLamports`: 1 SOL
+ */
+export const MAX_FEE_LAMPORTS = 1_000_000_000n;
+
+/**
+ * Maximum tiers in the fee schedule
+ */
+export const MAX_FEE_TIERS = 8;
+
 /**
  * ConfigAccount discriminator bytes
  */
@@ -27,3 +286,164 @@ export const CONFIG_DISCRIMINATOR = new Uint8Array([
  * LockAccount discriminator bytes
  */
 export const LOCK_DISCRIMINATOR = new Uint8Array([76, 79, 67, 75, 0, 0, 0, 0]); // "LOCK\0\0\0\0"
+
+/**
+ * TemplateAccount discriminator bytes
+ */
+export const TEMPLATE_DISCRIMINATOR = new Uint8Array([
+  84, 69, 77, 80, 76, 65, 84, 69,
+]); // "TEMPLATE"
+
+/**
+ * StandingOrderAccount discriminator bytes
+ */
+export const STANDING_ORDER_DISCRIMINATOR = new Uint8Array([
+  83, 84, 79, 82, 68, 69, 82, 0,
+]); // "STORDER\0"
+
+/**
+ * ForwardingDestinationAccount discriminator bytes
+ */
+export const FORWARDING_DESTINATION_DISCRIMINATOR = new Uint8Array([
+  70, 79, 82, 87, 65, 82, 68, 0,
+]); // "FORWARD\0"
+
+/**
+ * HookAccount discriminator bytes
+ */
+export const HOOK_DISCRIMINATOR = new Uint8Array([
+  72, 79, 79, 75, 0, 0, 0, 0,
+]); // "HOOK\0\0\0\0"
+
+/**
+ * LenderAccount discriminator bytes
+ */
+export const LENDER_DISCRIMINATOR = new Uint8Array([
+  76, 69, 78, 68, 69, 82, 0, 0,
+]); // "LENDER\0\0"
+
+/**
+ * SessionAccount discriminator bytes
+ */
+export const SESSION_DISCRIMINATOR = new Uint8Array([
+  83, 69, 83, 83, 73, 79, 78, 0,
+]); // "SESSION\0"
+
+/**
+ * NoteAccount discriminator bytes
+ */
+export const NOTE_DISCRIMINATOR = new Uint8Array([
+  78, 79, 84, 69, 0, 0, 0, 0,
+]); // "NOTE\0\0\0\0"
+
+/**
+ * MintIndexAccount discriminator bytes
+ */
+export const MINT_INDEX_DISCRIMINATOR = new Uint8Array([
+  77, 73, 78, 84, 73, 68, 88, 0,
+]); // "MINTIDX\0"
+
+/**
+ * IndexEntryAccount discriminator bytes
+ */
+export const INDEX_ENTRY_DISCRIMINATOR = new Uint8Array([
+  73, 68, 88, 69, 78, 84, 82, 89,
+]); // "IDXENTRY"
+
+/**
+ * LockCertificateAccount discriminator bytes
+ */
+export const LOCK_CERTIFICATE_DISCRIMINATOR = new Uint8Array([
+  76, 79, 67, 75, 67, 69, 82, 84,
+]); // "LOCKCERT"
+
+/**
+ * VestingLockAccount discriminator bytes
+ */
+export const VESTING_DISCRIMINATOR = new Uint8Array([
+  86, 69, 83, 84, 73, 78, 71, 0,
+]); // "VESTING\0"
+
+/**
+ * LockSharesAccount discriminator bytes
+ */
+export const LOCK_SHARES_DISCRIMINATOR = new Uint8Array([
+  83, 72, 65, 82, 69, 83, 0, 0,
+]); // "SHARES\0\0"
+
+/**
+ * UnlockApprovalsAccount discriminator bytes
+ */
+export const UNLOCK_APPROVALS_DISCRIMINATOR = new Uint8Array([
+  65, 80, 80, 82, 79, 86, 65, 76,
+]); // "APPROVAL"
+
+/**
+ * MintPolicyAccount discriminator bytes
+ */
+export const MINT_POLICY_DISCRIMINATOR = new Uint8Array([
+  77, 73, 78, 84, 80, 76, 67, 89,
+]); // "MINTPLCY"
+
+/**
+ * VoteWeightSnapshotAccount discriminator bytes
+ */
+export const VOTE_WEIGHT_SNAPSHOT_DISCRIMINATOR = new Uint8Array([
+  86, 79, 84, 69, 87, 71, 72, 84,
+]); // "VOTEWGHT"
+
+/**
+ * LockProofAccount discriminator bytes
+ */
+export const LOCK_PROOF_DISCRIMINATOR = new Uint8Array([
+  76, 79, 67, 75, 80, 82, 79, 70,
+]); // "LOCKPROF"
+
+/**
+ * FeeExemptionAccount discriminator bytes
+ */
+export const FEE_EXEMPTION_DISCRIMINATOR = new Uint8Array([
+  70, 69, 69, 69, 88, 77, 80, 84,
+]); // "FEEEXMPT"
+
+/**
+ * OwnerCounterAccount discriminator bytes
+ */
+export const OWNER_COUNTER_DISCRIMINATOR = new Uint8Array([
+  79, 87, 78, 82, 67, 78, 84, 82,
+]); // "OWNRCNTR"
+
+/**
+ * FeeCapAccount discriminator bytes
+ */
+export const FEE_CAP_DISCRIMINATOR = new Uint8Array([
+  70, 69, 69, 67, 65, 80, 0, 0,
+]); // "FEECAP\0\0"
+
+/**
+ * FeeScheduleAccount discriminator bytes
+ */
+export const FEE_SCHEDULE_DISCRIMINATOR = new Uint8Array([
+  70, 69, 69, 83, 67, 72, 69, 68,
+]); // "FEESCHED"
+
+/**
+ * LockAuditEvent discriminator bytes
+ */
+export const LOCK_AUDIT_EVENT_DISCRIMINATOR = new Uint8Array([
+  65, 85, 68, 73, 84, 0, 0, 0,
+]); // "AUDIT\0\0\0"
+
+/**
+ * CollateralAttestation discriminator bytes (VerifyCollateral return data)
+ */
+export const COLLATERAL_ATTESTATION_DISCRIMINATOR = new Uint8Array([
+  67, 79, 76, 76, 65, 84, 82, 76,
+]); // "COLLATRL"
+
+/**
+ * ComplianceEvent discriminator bytes
+ */
+export const COMPLIANCE_EVENT_DISCRIMINATOR = new Uint8Array([
+  67, 79, 77, 80, 76, 89, 0, 0,
+]); // "COMPLY\0\0"
@@ -0,0 +1,103 @@
+import { describe, it, expect } from "vitest";
+import type {
+  Address,
+  GetMinimumBalanceForRentExemptionApi,
+  GetRecentPrioritizationFeesApi,
+  Rpc,
+} from "@solana/kit";
+import {
+  TOKEN_ACCOUNT_SIZE,
+  estimateLockCost,
+  getPriorityFeeLamports,
+  getPriorityFeePercentile,
+} from "./costs";
+import { FEE_USDC } from "./constants";
+
+const MINT = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address;
+
+// Rent-exempt minimum with the default rent parameters:
+// (size + 128) * 3480 * 2
+const rentFor = (size: bigint) => (size + 128n) * 3480n * 2n;
+
+function fakeRpc(fees: bigint[]) {
+  const feeQueries: unknown[] = [];
+  const rpc = {
+    getMinimumBalanceForRentExemption: (size: bigint) => ({
+      send: async () => rentFor(size),
+    }),
+    getRecentPrioritizationFees: (addresses?: Address[]) => {
+      feeQueries.push(addresses);
+      return {
+        send: async () =>
+          fees.map((prioritizationFee, i) => ({
+            slot: BigInt(i),
+            prioritizationFee,
+          })),
+      };
+    },
+  } as unknown as Rpc<
+    GetMinimumBalanceForRentExemptionApi & GetRecentPrioritizationFeesApi
+  >;
+  return { rpc, feeQueries };
+}
+
+describe("Priority fee helpers", () => {
+  it("picks the requested percentile", () => {
+    const fees = [5n, 1n, 4n, 2n, 3n];
+    expect(getPriorityFeePercentile(fees, 0)).toBe(1n);
+    expect(getPriorityFeePercentile(fees, 50)).toBe(3n);
+    expect(getPriorityFeePercentile(fees, 100)).toBe(5n);
+  });
+
+  it("returns zero without samples", () => {
+    expect(getPriorityFeePercentile([])).toBe(0n);
+  });
+
+  it("converts micro-lamports per CU to lamports, rounding up", () => {
+    expect(getPriorityFeeLamports(1_000_000n, 200_000)).toBe(200_000n);
+    expect(getPriorityFeeLamports(1n, 1)).toBe(1n);
+    expect(getPriorityFeeLamports(0n, 200_000)).toBe(0n);
+  });
+});
+
+describe("estimateLockCost", () => {
+  it("sums rent for the lock account and escrow", async () => {
+    const { rpc } = fakeRpc([]);
+    const cost = await estimateLockCost(rpc);
+
+    expect(cost.lockAccountRent).toBe(rentFor(773n));
+    expect(cost.lockTokenAccountRent).toBe(rentFor(BigInt(TOKEN_ACCOUNT_SIZE)));
+    expect(cost.feeUsdc).toBe(FEE_USDC);
+    expect(cost.priorityFee).toBe(0n);
+    expect(cost.totalLamports).toBe(
+      cost.lockAccountRent + cost.lockTokenAccountRent + cost.baseFee
+    );
+  });
+
+  it("prices the priority fee from recent fees for the mint", async () => {
+    const { rpc, feeQueries } = fakeRpc([100n, 300n, 200n]);
+    const cost = await estimateLockCost(rpc, {
+      mint: MINT,
+      computeUnits: 100_000,
+    });
+
+    expect(feeQueries).toEqual([[MINT]]);
+    expect(cost.computeUnitPrice).toBe(200n);
+    expect(cost.priorityFee).toBe(20n);
+    expect(cost.totalLamports).toBe(
+      cost.lockAccountRent + cost.lockTokenAccountRent + cost.baseFee + 20n
+    );
+  });
+
+  it("charges the base fee per signature", async () => {
+    const { rpc } = fakeRpc([]);
+    const cost = await estimateLockCost(rpc, { signatures: 2 });
+    expect(cost.baseFee).toBe(10_000n);
+  });
+
+  it("reports a template's fee override", async () => {
+    const { rpc } = fakeRpc([]);
+    const cost = await estimateLockCost(rpc, { feeUsdc: 0n });
+    expect(cost.feeUsdc).toBe(0n);
+  });
+});
@@ -0,0 +1,135 @@
+import type {
+  Address,
+  GetMinimumBalanceForRentExemptionApi,
+  GetRecentPrioritizationFeesApi,
+  Rpc,
+} from "@solana/kit";
+import { getLockAccountSize } from "./generated";
+import { FEE_USDC } from "./constants";
+
+/**
+ * SPL Token account size (escrow account created per lock)
+ */
+export const TOKEN_ACCOUNT_SIZE = 165;
+
+/**
+ * Fee charged per transaction signature, in lamports
+ */
+export const LAMPORTS_PER_SIGNATURE = 5_000n;
+
+/**
+ * Compute units budgeted for an InitializeLock transaction when the caller
+ * does not supply a simulated value
+ */
+export const DEFAULT_LOCK_COMPUTE_UNITS = 60_000;
+
+/**
+ * Default percentile of recent prioritization fees used for the estimate
+ */
+export const DEFAULT_PRIORITY_FEE_PERCENTILE = 50;
+
+export type LockCostEstimate = {
+  /** Rent-exempt minimum for the lock account (refunded on unlock) */
+  lockAccountRent: bigint;
+  /** Rent-exempt minimum for the escrow token account (refunded on unlock) */
+  lockTokenAccountRent: bigint;
+  /** Protocol fee in USDC base units (not refunded) */
+  feeUsdc: bigint;
+  /** Base signature fee in lamports */
+  baseFee: bigint;
+  /** Suggested compute unit price in micro-lamports */
+  computeUnitPrice: bigint;
+  /** Priority fee in lamports for `computeUnits` at `computeUnitPrice` */
+  priorityFee: bigint;
+  /** Compute units the estimate assumes */
+  computeUnits: number;
+  /** Total SOL outlay in lamports: rent + base fee + priority fee */
+  totalLamports: bigint;
+};
+
+/**
+ * Pick the given percentile (0-100) from a list of prioritization fees.
+ * Returns 0 for an empty list.
+ */
+export function getPriorityFeePercentile(
+  fees: readonly bigint[],
+  percentile: number = DEFAULT_PRIORITY_FEE_PERCENTILE
+): bigint {
+  if (fees.length === 0) {
+    return 0n;
+  }
+  const sorted = [...fees].sort((a, b) => (a < b ? -1 : a > b ? 1 : 0));
+  const clamped = Math.min(Math.max(percentile, 0), 100);
+  const index = Math.min(
+    sorted.length - 1,
+    Math.floor((clamped / 100) * sorted.length)
+  );
+  return sorted[index];
+}
+
+/**
+ * Priority fee in lamports: ceil(price_micro_lamports * units / 1_000_000)
+ */
+export function getPriorityFeeLamports(
+  computeUnitPrice: bigint,
+  computeUnits: number
+): bigint {
+  return (computeUnitPrice * BigInt(computeUnits) + 999_999n) / 1_000_000n;
+}
+
+/**
+ * Estimate the full cost of creating a lock before the user signs.
+ *
+ * Rent comes from the cluster's current rent parameters, and the priority
+ * fee from recent prioritization fees paid for transactions that wrote to
+ * `mint` (pass the lock mint to reflect contention on that market).
+ */
+export async function estimateLockCost(
+  rpc: Rpc<
+    GetMinimumBalanceForRentExemptionApi & GetRecentPrioritizationFeesApi
+  >,
+  options: {
+    mint?: Address;
+    computeUnits?: number;
+    percentile?: number;
+    signatures?: number;
+    /** USDC fee to report, e.g. the config's or a template's `feeUsdc` */
+    feeUsdc?: bigint;
+  } = {}
+): Promise<LockCostEstimate> {
+  const computeUnits = options.computeUnits ?? DEFAULT_LOCK_COMPUTE_UNITS;
+  const signatures = BigInt(options.signatures ?? 1);
+
+  const [lockAccountRent, lockTokenAccountRent, recentFees] =
+    await Promise.all([
+      rpc
+        .getMinimumBalanceForRentExemption(BigInt(getLockAccountSize()))
+        .send(),
+      rpc.getMinimumBalanceForRentExemption(BigInt(TOKEN_ACCOUNT_SIZE)).send(),
+      rpc
+        .getRecentPrioritizationFees(options.mint ? [options.mint] : undefined)
+        .send(),
+    ]);
+
+  const computeUnitPrice = getPriorityFeePercentile(
+    recentFees.map((f) => BigInt(f.prioritizationFee)),
+    options.percentile
+  );
+  const priorityFee = getPriorityFeeLamports(computeUnitPrice, computeUnits);
+  const baseFee = LAMPORTS_PER_SIGNATURE * signatures;
+
+  return {
+    lockAccountRent: BigInt(lockAccountRent),
+    lockTokenAccountRent: BigInt(lockTokenAccountRent),
+    feeUsdc: options.feeUsdc ?? FEE_USDC,
+    baseFee,
+    computeUnitPrice,
+    priorityFee,
+    computeUnits,
+    totalLamports:
+      BigInt(lockAccountRent) +
+      BigInt(lockTokenAccountRent) +
+      baseFee +
+      priorityFee,
+  };
+}
@@ -0,0 +1,175 @@
+import { describe, it, expect } from "vitest";
+import { getBase64Decoder, type Address } from "@solana/kit";
+import {
+  getProgramDataFromLogs,
+  parseCollateralAttestation,
+  parseComplianceEvents,
+  parseLockAuditEvents,
+} from "./events";
+import {
+  AuditStatus,
+  ComplianceAction,
+  LOCKSMITH_PROGRAM_ADDRESS,
+  getCollateralAttestationEncoder,
+  getComplianceEventEncoder,
+  getLockAuditEventEncoder,
+} from "./generated";
+import {
+  COLLATERAL_ATTESTATION_DISCRIMINATOR,
+  COMPLIANCE_EVENT_DISCRIMINATOR,
+  LOCK_AUDIT_EVENT_DISCRIMINATOR,
+} from "./constants";
+
+const TEST_ADDRESSES = {
+  lock: "Vote111111111111111111111111111111111111111" as Address,
+  owner: "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address,
+  mint: "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address,
+};
+const OTHER_PROGRAM = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+/**
+ * LockAuditEvent layout from Rust (137 bytes):
+ *   [0-7]:     discriminator "AUDIT\0\0\0"
+ *   [8-39]:    lock
+ *   [40-71]:   owner
+ *   [72-103]:  mint
+ *   [104-111]: recorded_amount (u64)
+ *   [112-119]: escrow_amount (u64)
+ *   [120]:     status (u8)
+ *   [121-128]: slot (u64)
+ *   [129-136]: timestamp (i64)
+ */
+function auditEventLog(status: AuditStatus) {
+  const bytes = getLockAuditEventEncoder().encode({
+    discriminator: LOCK_AUDIT_EVENT_DISCRIMINATOR,
+    lock: TEST_ADDRESSES.lock,
+    owner: TEST_ADDRESSES.owner,
+    mint: TEST_ADDRESSES.mint,
+    recordedAmount: 1_000n,
+    escrowAmount: 900n,
+    status,
+    slot: 42n,
+    timestamp: 1_700_000_000n,
+  });
+  return `Program data: ${getBase64Decoder().decode(bytes)}`;
+}
+
+describe("LockAuditEvent", () => {
+  it("encodes 137 bytes with status at byte 120", () => {
+    const bytes = getLockAuditEventEncoder().encode({
+      discriminator: LOCK_AUDIT_EVENT_DISCRIMINATOR,
+      lock: TEST_ADDRESSES.lock,
+      owner: TEST_ADDRESSES.owner,
+      mint: TEST_ADDRESSES.mint,
+      recordedAmount: 0n,
+      escrowAmount: 0n,
+      status: AuditStatus.BalanceMismatch,
+      slot: 0n,
+      timestamp: 0n,
+    });
+    expect(bytes.length).toBe(137);
+    expect(bytes[120]).toBe(4);
+  });
+});
+
+describe("parseLockAuditEvents", () => {
+  it("decodes events emitted by the program", () => {
+    const events = parseLockAuditEvents([
+      `Program ${LOCKSMITH_PROGRAM_ADDRESS} invoke [1]`,
+      auditEventLog(AuditStatus.BalanceMismatch),
+      "Program log: Lock audited: BalanceMismatch",
+      `Program ${LOCKSMITH_PROGRAM_ADDRESS} success`,
+    ]);
+
+    expect(events).toHaveLength(1);
+    expect(events[0]).toMatchObject({
+      lock: TEST_ADDRESSES.lock,
+      recordedAmount: 1_000n,
+      escrowAmount: 900n,
+      status: AuditStatus.BalanceMismatch,
+      slot: 42n,
+    });
+  });
+
+  it("ignores data logged by other programs", () => {
+    const logs = [
+      `Program ${OTHER_PROGRAM} invoke [1]`,
+      auditEventLog(AuditStatus.Consistent),
+      `Program ${OTHER_PROGRAM} success`,
+    ];
+    expect(getProgramDataFromLogs(logs)).toEqual([]);
+    expect(parseLockAuditEvents(logs)).toEqual([]);
+  });
+});
+
+describe("parseComplianceEvents", () => {
+  it("decodes freezes and ignores audit events", () => {
+    const bytes = getComplianceEventEncoder().encode({
+      discriminator: COMPLIANCE_EVENT_DISCRIMINATOR,
+      action: ComplianceAction.LockFrozen,
+      actor: TEST_ADDRESSES.owner,
+      target: TEST_ADDRESSES.lock,
+      effectiveAt: 1_700_000_000n,
+      slot: 42n,
+      timestamp: 1_700_000_000n,
+    });
+    // Rust: assert_eq!(ComplianceEvent::SIZE, 97)
+    expect(bytes.length).toBe(97);
+
+    const events = parseComplianceEvents([
+      `Program ${LOCKSMITH_PROGRAM_ADDRESS} invoke [1]`,
+      `Program data: ${getBase64Decoder().decode(bytes)}`,
+      auditEventLog(AuditStatus.Consistent),
+      `Program ${LOCKSMITH_PROGRAM_ADDRESS} success`,
+    ]);
+
+    expect(events).toHaveLength(1);
+    expect(events[0]).toMatchObject({
+      action: ComplianceAction.LockFrozen,
+      actor: TEST_ADDRESSES.owner,
+      target: TEST_ADDRESSES.lock,
+      slot: 42n,
+    });
+  });
+});
+
+describe("parseCollateralAttestation", () => {
+  const bytes = getCollateralAttestationEncoder().encode({
+    discriminator: COLLATERAL_ATTESTATION_DISCRIMINATOR,
+    lock: TEST_ADDRESSES.lock,
+    owner: TEST_ADDRESSES.owner,
+    mint: TEST_ADDRESSES.mint,
+    amount: 1_000n,
+    unlockTimestamp: 1_700_000_000n,
+    collateralHolder: TEST_ADDRESSES.owner,
+    frozen: false,
+    status: AuditStatus.Consistent,
+  });
+  const data = [getBase64Decoder().decode(bytes), "base64"] as const;
+
+  it("decodes return data set by the program", () => {
+    // Rust: assert_eq!(CollateralAttestation::SIZE, 154)
+    expect(bytes.length).toBe(154);
+
+    const attestation = parseCollateralAttestation({
+      programId: LOCKSMITH_PROGRAM_ADDRESS,
+      data,
+    });
+    expect(attestation).toMatchObject({
+      lock: TEST_ADDRESSES.lock,
+      amount: 1_000n,
+      collateralHolder: TEST_ADDRESSES.owner,
+      status: AuditStatus.Consistent,
+    });
+  });
+
+  it("ignores missing data and data from other programs", () => {
+    expect(parseCollateralAttestation(null)).toBeNull();
+    expect(
+      parseCollateralAttestation({
+        programId: OTHER_PROGRAM as Address,
+        data,
+      })
+    ).toBeNull();
+  });
+});
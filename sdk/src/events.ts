@@ -0,0 +1,109 @@
+import { getBase64Encoder, type Address } from "@solana/kit";
+import {
+  LOCKSMITH_PROGRAM_ADDRESS,
+  getCollateralAttestationDecoder,
+  getComplianceEventDecoder,
+  getLockAuditEventDecoder,
+  type CollateralAttestation,
+  type ComplianceEvent,
+  type LockAuditEvent,
+} from "./generated";
+import {
+  COLLATERAL_ATTESTATION_DISCRIMINATOR,
+  COMPLIANCE_EVENT_DISCRIMINATOR,
+  LOCK_AUDIT_EVENT_DISCRIMINATOR,
+} from "./constants";
+
+const PROGRAM_DATA_PREFIX = "Program data: ";
+
+function startsWith(data: Uint8Array, prefix: Uint8Array): boolean {
+  return (
+    data.length >= prefix.length && prefix.every((byte, i) => data[i] === byte)
+  );
+}
+
+/**
+ * Collect `sol_log_data` payloads emitted directly by the Locksmith program.
+ * Each `Program data:` line holds one base64 field per logged slice.
+ */
+export function getProgramDataFromLogs(
+  logs: readonly string[],
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Uint8Array[][] {
+  const base64 = getBase64Encoder();
+  const stack: string[] = [];
+  const payloads: Uint8Array[][] = [];
+
+  for (const line of logs) {
+    const invoke = /^Program (\w+) invoke \[\d+\]$/.exec(line);
+    if (invoke) {
+      stack.push(invoke[1]);
+      continue;
+    }
+    if (/^Program \w+ (success|failed)/.test(line)) {
+      stack.pop();
+      continue;
+    }
+    if (
+      stack[stack.length - 1] === programAddress &&
+      line.startsWith(PROGRAM_DATA_PREFIX)
+    ) {
+      payloads.push(
+        line
+          .slice(PROGRAM_DATA_PREFIX.length)
+          .split(" ")
+          .map((field) => new Uint8Array(base64.encode(field)))
+      );
+    }
+  }
+
+  return payloads;
+}
+
+/**
+ * Decode every LockAuditEvent in a transaction's logs (from AuditLock).
+ */
+export function parseLockAuditEvents(
+  logs: readonly string[],
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): LockAuditEvent[] {
+  const decoder = getLockAuditEventDecoder();
+  return getProgramDataFromLogs(logs, programAddress)
+    .map(([data]) => data)
+    .filter((data) => data && startsWith(data, LOCK_AUDIT_EVENT_DISCRIMINATOR))
+    .map((data) => decoder.decode(data));
+}
+
+/**
+ * Decode every ComplianceEvent in a transaction's logs (from
+ * SetComplianceAuthority, FreezeLock and UnfreezeLock).
+ */
+export function parseComplianceEvents(
+  logs: readonly string[],
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): ComplianceEvent[] {
+  const decoder = getComplianceEventDecoder();
+  return getProgramDataFromLogs(logs, programAddress)
+    .map(([data]) => data)
+    .filter((data) => data && startsWith(data, COMPLIANCE_EVENT_DISCRIMINATOR))
+    .map((data) => decoder.decode(data));
+}
+
+/**
+ * Decode the CollateralAttestation that VerifyCollateral sets as return
+ * data, e.g. from `simulateTransaction`'s `returnData`. Returns null when
+ * the data is missing or was not set by the Locksmith program.
+ */
+export function parseCollateralAttestation(
+  returnData: { programId: Address; data: readonly [string, string] } | null,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): CollateralAttestation | null {
+  if (!returnData || returnData.programId !== programAddress) {
+    return null;
+  }
+  const data = new Uint8Array(getBase64Encoder().encode(returnData.data[0]));
+  if (!startsWith(data, COLLATERAL_ATTESTATION_DISCRIMINATOR)) {
+    return null;
+  }
+  return getCollateralAttestationDecoder().decode(data);
+}
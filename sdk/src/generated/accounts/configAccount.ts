@@ -17,10 +17,20 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Account,
@@ -40,9 +50,49 @@ export type ConfigAccount = {
   discriminator: ReadonlyUint8Array;
   admin: Address;
   bump: number;
+  complianceAuthority: Address;
+  pendingComplianceAuthority: Address;
+  complianceAuthorityEffectiveAt: bigint;
+  feeMint: Address;
+  pendingFeeMint: Address;
+  feeMintEffectiveAt: bigint;
+  successorProgram: Address;
+  sunsetAt: bigint;
+  earlyUnlockPenaltyBps: number;
+  batchFeePerLock: boolean;
+  paused: boolean;
+  mintPolicyMode: number;
+  rejectFreezableMints: boolean;
+  feeUsdc: bigint;
+  feeBps: number;
+  feeLamports: bigint;
+  feeTiered: boolean;
+  maxLocksPerOwner: number;
 };
 
-export type ConfigAccountArgs = ConfigAccount;
+export type ConfigAccountArgs = {
+  discriminator: ReadonlyUint8Array;
+  admin: Address;
+  bump: number;
+  complianceAuthority: Address;
+  pendingComplianceAuthority: Address;
+  complianceAuthorityEffectiveAt: number | bigint;
+  feeMint: Address;
+  pendingFeeMint: Address;
+  feeMintEffectiveAt: number | bigint;
+  successorProgram: Address;
+  sunsetAt: number | bigint;
+  earlyUnlockPenaltyBps: number;
+  batchFeePerLock: boolean;
+  paused: boolean;
+  mintPolicyMode: number;
+  rejectFreezableMints: boolean;
+  feeUsdc: number | bigint;
+  feeBps: number;
+  feeLamports: number | bigint;
+  feeTiered: boolean;
+  maxLocksPerOwner: number;
+};
 
 /** Gets the encoder for {@link ConfigAccountArgs} account data. */
 export function getConfigAccountEncoder(): FixedSizeEncoder<ConfigAccountArgs> {
@@ -50,6 +100,24 @@ export function getConfigAccountEncoder(): FixedSizeEncoder<ConfigAccountArgs> {
     ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
     ['admin', getAddressEncoder()],
     ['bump', getU8Encoder()],
+    ['complianceAuthority', getAddressEncoder()],
+    ['pendingComplianceAuthority', getAddressEncoder()],
+    ['complianceAuthorityEffectiveAt', getI64Encoder()],
+    ['feeMint', getAddressEncoder()],
+    ['pendingFeeMint', getAddressEncoder()],
+    ['feeMintEffectiveAt', getI64Encoder()],
+    ['successorProgram', getAddressEncoder()],
+    ['sunsetAt', getI64Encoder()],
+    ['earlyUnlockPenaltyBps', getU16Encoder()],
+    ['batchFeePerLock', getBooleanEncoder()],
+    ['paused', getBooleanEncoder()],
+    ['mintPolicyMode', getU8Encoder()],
+    ['rejectFreezableMints', getBooleanEncoder()],
+    ['feeUsdc', getU64Encoder()],
+    ['feeBps', getU16Encoder()],
+    ['feeLamports', getU64Encoder()],
+    ['feeTiered', getBooleanEncoder()],
+    ['maxLocksPerOwner', getU32Encoder()],
   ]);
 }
 
@@ -59,6 +127,24 @@ export function getConfigAccountDecoder(): FixedSizeDecoder<ConfigAccount> {
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['admin', getAddressDecoder()],
     ['bump', getU8Decoder()],
+    ['complianceAuthority', getAddressDecoder()],
+    ['pendingComplianceAuthority', getAddressDecoder()],
+    ['complianceAuthorityEffectiveAt', getI64Decoder()],
+    ['feeMint', getAddressDecoder()],
+    ['pendingFeeMint', getAddressDecoder()],
+    ['feeMintEffectiveAt', getI64Decoder()],
+    ['successorProgram', getAddressDecoder()],
+    ['sunsetAt', getI64Decoder()],
+    ['earlyUnlockPenaltyBps', getU16Decoder()],
+    ['batchFeePerLock', getBooleanDecoder()],
+    ['paused', getBooleanDecoder()],
+    ['mintPolicyMode', getU8Decoder()],
+    ['rejectFreezableMints', getBooleanDecoder()],
+    ['feeUsdc', getU64Decoder()],
+    ['feeBps', getU16Decoder()],
+    ['feeLamports', getU64Decoder()],
+    ['feeTiered', getBooleanDecoder()],
+    ['maxLocksPerOwner', getU32Decoder()],
   ]);
 }
 
@@ -128,5 +214,5 @@ export async function fetchAllMaybeConfigAccount(
 }
 
 export function getConfigAccountSize(): number {
-  return 41;
+  return 254;
 }
@@ -7,4 +7,24 @@
  */
 
 export * from './configAccount';
+export * from './feeCapAccount';
+export * from './feeExemptionAccount';
+export * from './feeScheduleAccount';
+export * from './forwardingDestinationAccount';
+export * from './hookAccount';
+export * from './indexEntryAccount';
+export * from './lenderAccount';
 export * from './lockAccount';
+export * from './lockCertificateAccount';
+export * from './lockProofAccount';
+export * from './lockSharesAccount';
+export * from './mintIndexAccount';
+export * from './mintPolicyAccount';
+export * from './noteAccount';
+export * from './ownerCounterAccount';
+export * from './sessionAccount';
+export * from './standingOrderAccount';
+export * from './templateAccount';
+export * from './unlockApprovalsAccount';
+export * from './vestingLockAccount';
+export * from './voteWeightSnapshotAccount';
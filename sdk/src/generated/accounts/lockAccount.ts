@@ -17,12 +17,20 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI32Decoder,
+  getI32Encoder,
   getI64Decoder,
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -49,6 +57,43 @@ export type LockAccount = {
   createdAt: bigint;
   lockId: bigint;
   bump: number;
+  frozen: boolean;
+  credentialIssuer: Address;
+  nonTransferable: boolean;
+  forwardDestination: Address;
+  hook: Address;
+  recoveryAddress: Address;
+  donationAddress: Address;
+  donationBps: number;
+  collateralHolder: Address;
+  layoutVersion: number;
+  unlockSlot: bigint;
+  unlockEpoch: bigint;
+  claimKey: ReadonlyUint8Array;
+  claimExpiresAt: bigint;
+  indexSeq: number;
+  earlyUnlockAllowed: boolean;
+  permanent: boolean;
+  isNft: boolean;
+  receiptBound: boolean;
+  label: ReadonlyUint8Array;
+  metadataUri: ReadonlyUint8Array;
+  fallbackOwner: Address;
+  fallbackAfter: bigint;
+  unlockDestination: Address;
+  shared: boolean;
+  approvalsRequired: boolean;
+  priceFeedId: ReadonlyUint8Array;
+  priceThreshold: bigint;
+  priceExponent: number;
+  arbiter: Address;
+  milestoneApproved: boolean;
+  counterparty: Address;
+  ownerCounted: boolean;
+  wrappedLamports: boolean;
+  receiptMint: Address;
+  feeMode: number;
+  reserved: ReadonlyUint8Array;
 };
 
 export type LockAccountArgs = {
@@ -60,6 +105,43 @@ export type LockAccountArgs = {
   createdAt: number | bigint;
   lockId: number | bigint;
   bump: number;
+  frozen: boolean;
+  credentialIssuer: Address;
+  nonTransferable: boolean;
+  forwardDestination: Address;
+  hook: Address;
+  recoveryAddress: Address;
+  donationAddress: Address;
+  donationBps: number;
+  collateralHolder: Address;
+  layoutVersion: number;
+  unlockSlot: number | bigint;
+  unlockEpoch: number | bigint;
+  claimKey: ReadonlyUint8Array;
+  claimExpiresAt: number | bigint;
+  indexSeq: number;
+  earlyUnlockAllowed: boolean;
+  permanent: boolean;
+  isNft: boolean;
+  receiptBound: boolean;
+  label: ReadonlyUint8Array;
+  metadataUri: ReadonlyUint8Array;
+  fallbackOwner: Address;
+  fallbackAfter: number | bigint;
+  unlockDestination: Address;
+  shared: boolean;
+  approvalsRequired: boolean;
+  priceFeedId: ReadonlyUint8Array;
+  priceThreshold: number | bigint;
+  priceExponent: number;
+  arbiter: Address;
+  milestoneApproved: boolean;
+  counterparty: Address;
+  ownerCounted: boolean;
+  wrappedLamports: boolean;
+  receiptMint: Address;
+  feeMode: number;
+  reserved: ReadonlyUint8Array;
 };
 
 /** Gets the encoder for {@link LockAccountArgs} account data. */
@@ -73,6 +155,43 @@ export function getLockAccountEncoder(): FixedSizeEncoder<LockAccountArgs> {
     ['createdAt', getI64Encoder()],
     ['lockId', getU64Encoder()],
     ['bump', getU8Encoder()],
+    ['frozen', getBooleanEncoder()],
+    ['credentialIssuer', getAddressEncoder()],
+    ['nonTransferable', getBooleanEncoder()],
+    ['forwardDestination', getAddressEncoder()],
+    ['hook', getAddressEncoder()],
+    ['recoveryAddress', getAddressEncoder()],
+    ['donationAddress', getAddressEncoder()],
+    ['donationBps', getU16Encoder()],
+    ['collateralHolder', getAddressEncoder()],
+    ['layoutVersion', getU8Encoder()],
+    ['unlockSlot', getU64Encoder()],
+    ['unlockEpoch', getU64Encoder()],
+    ['claimKey', fixEncoderSize(getBytesEncoder(), 32)],
+    ['claimExpiresAt', getI64Encoder()],
+    ['indexSeq', getU32Encoder()],
+    ['earlyUnlockAllowed', getBooleanEncoder()],
+    ['permanent', getBooleanEncoder()],
+    ['isNft', getBooleanEncoder()],
+    ['receiptBound', getBooleanEncoder()],
+    ['label', fixEncoderSize(getBytesEncoder(), 32)],
+    ['metadataUri', fixEncoderSize(getBytesEncoder(), 128)],
+    ['fallbackOwner', getAddressEncoder()],
+    ['fallbackAfter', getI64Encoder()],
+    ['unlockDestination', getAddressEncoder()],
+    ['shared', getBooleanEncoder()],
+    ['approvalsRequired', getBooleanEncoder()],
+    ['priceFeedId', fixEncoderSize(getBytesEncoder(), 32)],
+    ['priceThreshold', getI64Encoder()],
+    ['priceExponent', getI32Encoder()],
+    ['arbiter', getAddressEncoder()],
+    ['milestoneApproved', getBooleanEncoder()],
+    ['counterparty', getAddressEncoder()],
+    ['ownerCounted', getBooleanEncoder()],
+    ['wrappedLamports', getBooleanEncoder()],
+    ['receiptMint', getAddressEncoder()],
+    ['feeMode', getU8Encoder()],
+    ['reserved', fixEncoderSize(getBytesEncoder(), 29)],
   ]);
 }
 
@@ -87,6 +206,43 @@ export function getLockAccountDecoder(): FixedSizeDecoder<LockAccount> {
     ['createdAt', getI64Decoder()],
     ['lockId', getU64Decoder()],
     ['bump', getU8Decoder()],
+    ['frozen', getBooleanDecoder()],
+    ['credentialIssuer', getAddressDecoder()],
+    ['nonTransferable', getBooleanDecoder()],
+    ['forwardDestination', getAddressDecoder()],
+    ['hook', getAddressDecoder()],
+    ['recoveryAddress', getAddressDecoder()],
+    ['donationAddress', getAddressDecoder()],
+    ['donationBps', getU16Decoder()],
+    ['collateralHolder', getAddressDecoder()],
+    ['layoutVersion', getU8Decoder()],
+    ['unlockSlot', getU64Decoder()],
+    ['unlockEpoch', getU64Decoder()],
+    ['claimKey', fixDecoderSize(getBytesDecoder(), 32)],
+    ['claimExpiresAt', getI64Decoder()],
+    ['indexSeq', getU32Decoder()],
+    ['earlyUnlockAllowed', getBooleanDecoder()],
+    ['permanent', getBooleanDecoder()],
+    ['isNft', getBooleanDecoder()],
+    ['receiptBound', getBooleanDecoder()],
+    ['label', fixDecoderSize(getBytesDecoder(), 32)],
+    ['metadataUri', fixDecoderSize(getBytesDecoder(), 128)],
+    ['fallbackOwner', getAddressDecoder()],
+    ['fallbackAfter', getI64Decoder()],
+    ['unlockDestination', getAddressDecoder()],
+    ['shared', getBooleanDecoder()],
+    ['approvalsRequired', getBooleanDecoder()],
+    ['priceFeedId', fixDecoderSize(getBytesDecoder(), 32)],
+    ['priceThreshold', getI64Decoder()],
+    ['priceExponent', getI32Decoder()],
+    ['arbiter', getAddressDecoder()],
+    ['milestoneApproved', getBooleanDecoder()],
+    ['counterparty', getAddressDecoder()],
+    ['ownerCounted', getBooleanDecoder()],
+    ['wrappedLamports', getBooleanDecoder()],
+    ['receiptMint', getAddressDecoder()],
+    ['feeMode', getU8Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 29)],
   ]);
 }
 
@@ -152,5 +308,5 @@ export async function fetchAllMaybeLockAccount(
 }
 
 export function getLockAccountSize(): number {
-  return 105;
+  return 773;
 }
@@ -6,8 +6,93 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './approveMilestone';
+export * from './approveUnlock';
+export * from './auditLock';
+export * from './burnLockReceipt';
+export * from './cancelStandingOrder';
+export * from './claimAsFallback';
+export * from './claimLock';
+export * from './claimShare';
+export * from './claimVested';
+export * from './closeFeeCap';
+export * from './closeFeeExemption';
+export * from './closeLock';
+export * from './closeLockCertificate';
+export * from './closeLockProof';
+export * from './closeMintPolicy';
+export * from './closeWeightSnapshot';
+export * from './crankUnlock';
+export * from './createStandingOrder';
+export * from './emergencyUnlock';
+export * from './executeStandingOrder';
+export * from './executeUnlock';
+export * from './extendLock';
+export * from './freezeLock';
+export * from './increaseLockAmount';
+export * from './indexLock';
 export * from './initializeConfig';
+export * from './initializeGiftLock';
 export * from './initializeLock';
+export * from './initializeLockBatch';
+export * from './initializeLockForDuration';
+export * from './initializeLockFromTemplate';
+export * from './initializeLockWithOptions';
+export * from './initializeLockWithPayer';
+export * from './initializeRelayedLock';
+export * from './initializeVesting';
+export * from './migrateFeeMint';
+export * from './migrateLock';
+export * from './mintLockReceipt';
+export * from './proposeFeeMint';
+export * from './publishLockCertificate';
+export * from './recordLockProof';
+export * from './recoverStrandedTokens';
+export * from './releaseEscrow';
+export * from './relock';
+export * from './revokeVesting';
+export * from './setBatchFeeMode';
+export * from './setCollateralized';
+export * from './setComplianceAuthority';
+export * from './setEarlyUnlockPenalty';
+export * from './setFeeCap';
+export * from './setFeeExemption';
+export * from './setFeeSchedule';
+export * from './setForwardingDestination';
+export * from './setHook';
+export * from './setLender';
+export * from './setLockForwarding';
+export * from './setLockNote';
+export * from './setLockShares';
+export * from './setLockTemplate';
+export * from './setMaxLocksPerOwner';
+export * from './setMintPolicy';
+export * from './setMintPolicyMode';
+export * from './setOwnerLockCap';
+export * from './setPaused';
+export * from './setPercentageFee';
+export * from './setRejectFreezableMints';
+export * from './setSession';
+export * from './setSolFee';
+export * from './setSuccessorProgram';
+export * from './setUnlockApprovers';
+export * from './snapshotWeight';
+export * from './sunset';
+export * from './sweepAbandonedLock';
+export * from './sweepExcess';
 export * from './transferAdmin';
+export * from './unfreezeLock';
+export * from './unindexLock';
 export * from './unlock';
+export * from './unlockToAssociatedAccount';
+export * from './unlockWithReceipt';
+export * from './unlockWithSession';
+export * from './unlockWithoutClosing';
+export * from './updateFeeConfig';
+export * from './updateLockMetadata';
+export * from './verifyCollateral';
 export * from './withdrawFees';
+export * from './withdrawPenalties';
+export * from './withdrawSolFees';
+export * from './withdrawSurplusRent';
+export * from './withdrawTokenFees';
@@ -96,12 +96,14 @@ export type InitializeLockInstructionData = {
   amount: bigint;
   unlockTimestamp: bigint;
   lockId: bigint;
+  validUntil: bigint;
 };
 
 export type InitializeLockInstructionDataArgs = {
   amount: number | bigint;
   unlockTimestamp: number | bigint;
   lockId: number | bigint;
+  validUntil: number | bigint;
 };
 
 export function getInitializeLockInstructionDataEncoder(): FixedSizeEncoder<InitializeLockInstructionDataArgs> {
@@ -111,6 +113,7 @@ export function getInitializeLockInstructionDataEncoder(): FixedSizeEncoder<Init
       ['amount', getU64Encoder()],
       ['unlockTimestamp', getI64Encoder()],
       ['lockId', getU64Encoder()],
+      ['validUntil', getI64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: INITIALIZE_LOCK_DISCRIMINATOR })
   );
@@ -122,6 +125,7 @@ export function getInitializeLockInstructionDataDecoder(): FixedSizeDecoder<Init
     ['amount', getU64Decoder()],
     ['unlockTimestamp', getI64Decoder()],
     ['lockId', getU64Decoder()],
+    ['validUntil', getI64Decoder()],
   ]);
 }
 
@@ -167,6 +171,7 @@ export type InitializeLockInput<
   amount: InitializeLockInstructionDataArgs['amount'];
   unlockTimestamp: InitializeLockInstructionDataArgs['unlockTimestamp'];
   lockId: InitializeLockInstructionDataArgs['lockId'];
+  validUntil: InitializeLockInstructionDataArgs['validUntil'];
 };
 
 export function getInitializeLockInstruction<
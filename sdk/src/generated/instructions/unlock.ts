@@ -47,6 +47,13 @@ export type UnlockInstruction<
   TAccountLockTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountCredential extends string | AccountMeta<string> = string,
+  TAccountForwardingDestination extends string | AccountMeta<string> = string,
+  TAccountDestinationProgram extends string | AccountMeta<string> = string,
+  TAccountHook extends string | AccountMeta<string> = string,
+  TAccountHookProgram extends string | AccountMeta<string> = string,
+  TAccountHookState extends string | AccountMeta<string> = string,
+  TAccountDonationTokenAccount extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -68,6 +75,27 @@ export type UnlockInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountCredential extends string
+        ? ReadonlyAccount<TAccountCredential>
+        : TAccountCredential,
+      TAccountForwardingDestination extends string
+        ? ReadonlyAccount<TAccountForwardingDestination>
+        : TAccountForwardingDestination,
+      TAccountDestinationProgram extends string
+        ? ReadonlyAccount<TAccountDestinationProgram>
+        : TAccountDestinationProgram,
+      TAccountHook extends string
+        ? ReadonlyAccount<TAccountHook>
+        : TAccountHook,
+      TAccountHookProgram extends string
+        ? ReadonlyAccount<TAccountHookProgram>
+        : TAccountHookProgram,
+      TAccountHookState extends string
+        ? WritableAccount<TAccountHookState>
+        : TAccountHookState,
+      TAccountDonationTokenAccount extends string
+        ? WritableAccount<TAccountDonationTokenAccount>
+        : TAccountDonationTokenAccount,
       ...TRemainingAccounts,
     ]
   >;
@@ -109,6 +137,13 @@ export type UnlockInput<
   TAccountLockAccount extends string = string,
   TAccountLockTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountCredential extends string = string,
+  TAccountForwardingDestination extends string = string,
+  TAccountDestinationProgram extends string = string,
+  TAccountHook extends string = string,
+  TAccountHookProgram extends string = string,
+  TAccountHookState extends string = string,
+  TAccountDonationTokenAccount extends string = string,
 > = {
   /** Lock owner receiving tokens */
   owner: TransactionSigner<TAccountOwner>;
@@ -120,6 +155,20 @@ export type UnlockInput<
   lockTokenAccount: Address<TAccountLockTokenAccount>;
   /** SPL Token program */
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Owner's credential PDA, required if the lock is credential-gated */
+  credential?: Address<TAccountCredential>;
+  /** Lock's forwarding destination, required if set */
+  forwardingDestination?: Address<TAccountForwardingDestination>;
+  /** Program of the forwarding destination */
+  destinationProgram?: Address<TAccountDestinationProgram>;
+  /** Lock's hook, required if set */
+  hook?: Address<TAccountHook>;
+  /** Program of the hook */
+  hookProgram?: Address<TAccountHookProgram>;
+  /** Hook program's account updated by the callback */
+  hookState?: Address<TAccountHookState>;
+  /** Donation address's token account, required if the lock donates */
+  donationTokenAccount?: Address<TAccountDonationTokenAccount>;
   lockId: UnlockInstructionDataArgs['lockId'];
 };
 
@@ -129,6 +178,13 @@ export function getUnlockInstruction<
   TAccountLockAccount extends string,
   TAccountLockTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountCredential extends string,
+  TAccountForwardingDestination extends string,
+  TAccountDestinationProgram extends string,
+  TAccountHook extends string,
+  TAccountHookProgram extends string,
+  TAccountHookState extends string,
+  TAccountDonationTokenAccount extends string,
   TProgramAddress extends Address = typeof LOCKSMITH_PROGRAM_ADDRESS,
 >(
   input: UnlockInput<
@@ -136,7 +192,14 @@ export function getUnlockInstruction<
     TAccountOwnerTokenAccount,
     TAccountLockAccount,
     TAccountLockTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountCredential,
+    TAccountForwardingDestination,
+    TAccountDestinationProgram,
+    TAccountHook,
+    TAccountHookProgram,
+    TAccountHookState,
+    TAccountDonationTokenAccount
   >,
   config?: { programAddress?: TProgramAddress }
 ): UnlockInstruction<
@@ -145,7 +208,14 @@ export function getUnlockInstruction<
   TAccountOwnerTokenAccount,
   TAccountLockAccount,
   TAccountLockTokenAccount,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountCredential,
+  TAccountForwardingDestination,
+  TAccountDestinationProgram,
+  TAccountHook,
+  TAccountHookProgram,
+  TAccountHookState,
+  TAccountDonationTokenAccount
 > {
   // Program address.
   const programAddress = config?.programAddress ?? LOCKSMITH_PROGRAM_ADDRESS;
@@ -163,6 +233,22 @@ export function getUnlockInstruction<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    credential: { value: input.credential ?? null, isWritable: false },
+    forwardingDestination: {
+      value: input.forwardingDestination ?? null,
+      isWritable: false,
+    },
+    destinationProgram: {
+      value: input.destinationProgram ?? null,
+      isWritable: false,
+    },
+    hook: { value: input.hook ?? null, isWritable: false },
+    hookProgram: { value: input.hookProgram ?? null, isWritable: false },
+    hookState: { value: input.hookState ?? null, isWritable: true },
+    donationTokenAccount: {
+      value: input.donationTokenAccount ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -186,6 +272,13 @@ export function getUnlockInstruction<
       getAccountMeta(accounts.lockAccount),
       getAccountMeta(accounts.lockTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.credential),
+      getAccountMeta(accounts.forwardingDestination),
+      getAccountMeta(accounts.destinationProgram),
+      getAccountMeta(accounts.hook),
+      getAccountMeta(accounts.hookProgram),
+      getAccountMeta(accounts.hookState),
+      getAccountMeta(accounts.donationTokenAccount),
     ],
     data: getUnlockInstructionDataEncoder().encode(
       args as UnlockInstructionDataArgs
@@ -197,7 +290,14 @@ export function getUnlockInstruction<
     TAccountOwnerTokenAccount,
     TAccountLockAccount,
     TAccountLockTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountCredential,
+    TAccountForwardingDestination,
+    TAccountDestinationProgram,
+    TAccountHook,
+    TAccountHookProgram,
+    TAccountHookState,
+    TAccountDonationTokenAccount
   >);
 }
 
@@ -217,6 +317,20 @@ export type ParsedUnlockInstruction<
     lockTokenAccount: TAccountMetas[3];
     /** SPL Token program */
     tokenProgram: TAccountMetas[4];
+    /** Owner's credential PDA, required if the lock is credential-gated */
+    credential: TAccountMetas[5] | undefined;
+    /** Lock's forwarding destination, required if set */
+    forwardingDestination: TAccountMetas[6] | undefined;
+    /** Program of the forwarding destination */
+    destinationProgram: TAccountMetas[7] | undefined;
+    /** Lock's hook, required if set */
+    hook: TAccountMetas[8] | undefined;
+    /** Program of the hook */
+    hookProgram: TAccountMetas[9] | undefined;
+    /** Hook program's account updated by the callback */
+    hookState: TAccountMetas[10] | undefined;
+    /** Donation address's token account, required if the lock donates */
+    donationTokenAccount: TAccountMetas[11] | undefined;
   };
   data: UnlockInstructionData;
 };
@@ -229,7 +343,7 @@ export function parseUnlockInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUnlockInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -239,6 +353,12 @@ export function parseUnlockInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === LOCKSMITH_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -247,6 +367,13 @@ export function parseUnlockInstruction<
       lockAccount: getNextAccount(),
       lockTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
+      credential: getNextOptionalAccount(),
+      forwardingDestination: getNextOptionalAccount(),
+      destinationProgram: getNextOptionalAccount(),
+      hook: getNextOptionalAccount(),
+      hookProgram: getNextOptionalAccount(),
+      hookState: getNextOptionalAccount(),
+      donationTokenAccount: getNextOptionalAccount(),
     },
     data: getUnlockInstructionDataDecoder().decode(instruction.data),
   };
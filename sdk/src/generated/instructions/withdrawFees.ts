@@ -10,6 +10,8 @@ import {
   combineCodec,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -70,19 +72,28 @@ export type WithdrawFeesInstruction<
     ]
   >;
 
-export type WithdrawFeesInstructionData = { discriminator: number };
+export type WithdrawFeesInstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
 
-export type WithdrawFeesInstructionDataArgs = {};
+export type WithdrawFeesInstructionDataArgs = { amount: number | bigint };
 
 export function getWithdrawFeesInstructionDataEncoder(): FixedSizeEncoder<WithdrawFeesInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
     (value) => ({ ...value, discriminator: WITHDRAW_FEES_DISCRIMINATOR })
   );
 }
 
 export function getWithdrawFeesInstructionDataDecoder(): FixedSizeDecoder<WithdrawFeesInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
 }
 
 export function getWithdrawFeesInstructionDataCodec(): FixedSizeCodec<
@@ -108,10 +119,11 @@ export type WithdrawFeesInput<
   config: Address<TAccountConfig>;
   /** Fee vault holding USDC fees */
   feeVault: Address<TAccountFeeVault>;
-  /** Admin's USDC token account */
+  /** Fee mint token account receiving the fees, not necessarily the admin's */
   adminTokenAccount: Address<TAccountAdminTokenAccount>;
   /** SPL Token program */
   tokenProgram?: Address<TAccountTokenProgram>;
+  amount: WithdrawFeesInstructionDataArgs['amount'];
 };
 
 export function getWithdrawFeesInstruction<
@@ -157,6 +169,9 @@ export function getWithdrawFeesInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
@@ -172,7 +187,9 @@ export function getWithdrawFeesInstruction<
       getAccountMeta(accounts.adminTokenAccount),
       getAccountMeta(accounts.tokenProgram),
     ],
-    data: getWithdrawFeesInstructionDataEncoder().encode({}),
+    data: getWithdrawFeesInstructionDataEncoder().encode(
+      args as WithdrawFeesInstructionDataArgs
+    ),
     programAddress,
   } as WithdrawFeesInstruction<
     TProgramAddress,
@@ -196,7 +213,7 @@ export type ParsedWithdrawFeesInstruction<
     config: TAccountMetas[1];
     /** Fee vault holding USDC fees */
     feeVault: TAccountMetas[2];
-    /** Admin's USDC token account */
+    /** Fee mint token account receiving the fees, not necessarily the admin's */
     adminTokenAccount: TAccountMetas[3];
     /** SPL Token program */
     tokenProgram: TAccountMetas[4];
@@ -13,11 +13,96 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedApproveMilestoneInstruction,
+  type ParsedApproveUnlockInstruction,
+  type ParsedAuditLockInstruction,
+  type ParsedBurnLockReceiptInstruction,
+  type ParsedCancelStandingOrderInstruction,
+  type ParsedClaimAsFallbackInstruction,
+  type ParsedClaimLockInstruction,
+  type ParsedClaimShareInstruction,
+  type ParsedClaimVestedInstruction,
+  type ParsedCloseFeeCapInstruction,
+  type ParsedCloseFeeExemptionInstruction,
+  type ParsedCloseLockCertificateInstruction,
+  type ParsedCloseLockInstruction,
+  type ParsedCloseLockProofInstruction,
+  type ParsedCloseMintPolicyInstruction,
+  type ParsedCloseWeightSnapshotInstruction,
+  type ParsedCrankUnlockInstruction,
+  type ParsedCreateStandingOrderInstruction,
+  type ParsedEmergencyUnlockInstruction,
+  type ParsedExecuteStandingOrderInstruction,
+  type ParsedExecuteUnlockInstruction,
+  type ParsedExtendLockInstruction,
+  type ParsedFreezeLockInstruction,
+  type ParsedIncreaseLockAmountInstruction,
+  type ParsedIndexLockInstruction,
   type ParsedInitializeConfigInstruction,
+  type ParsedInitializeGiftLockInstruction,
+  type ParsedInitializeLockBatchInstruction,
+  type ParsedInitializeLockForDurationInstruction,
+  type ParsedInitializeLockFromTemplateInstruction,
   type ParsedInitializeLockInstruction,
+  type ParsedInitializeLockWithOptionsInstruction,
+  type ParsedInitializeLockWithPayerInstruction,
+  type ParsedInitializeRelayedLockInstruction,
+  type ParsedInitializeVestingInstruction,
+  type ParsedMigrateFeeMintInstruction,
+  type ParsedMigrateLockInstruction,
+  type ParsedMintLockReceiptInstruction,
+  type ParsedProposeFeeMintInstruction,
+  type ParsedPublishLockCertificateInstruction,
+  type ParsedRecordLockProofInstruction,
+  type ParsedRecoverStrandedTokensInstruction,
+  type ParsedReleaseEscrowInstruction,
+  type ParsedRelockInstruction,
+  type ParsedRevokeVestingInstruction,
+  type ParsedSetBatchFeeModeInstruction,
+  type ParsedSetCollateralizedInstruction,
+  type ParsedSetComplianceAuthorityInstruction,
+  type ParsedSetEarlyUnlockPenaltyInstruction,
+  type ParsedSetFeeCapInstruction,
+  type ParsedSetFeeExemptionInstruction,
+  type ParsedSetFeeScheduleInstruction,
+  type ParsedSetForwardingDestinationInstruction,
+  type ParsedSetHookInstruction,
+  type ParsedSetLenderInstruction,
+  type ParsedSetLockForwardingInstruction,
+  type ParsedSetLockNoteInstruction,
+  type ParsedSetLockSharesInstruction,
+  type ParsedSetLockTemplateInstruction,
+  type ParsedSetMaxLocksPerOwnerInstruction,
+  type ParsedSetMintPolicyInstruction,
+  type ParsedSetMintPolicyModeInstruction,
+  type ParsedSetOwnerLockCapInstruction,
+  type ParsedSetPausedInstruction,
+  type ParsedSetPercentageFeeInstruction,
+  type ParsedSetRejectFreezableMintsInstruction,
+  type ParsedSetSessionInstruction,
+  type ParsedSetSolFeeInstruction,
+  type ParsedSetSuccessorProgramInstruction,
+  type ParsedSetUnlockApproversInstruction,
+  type ParsedSnapshotWeightInstruction,
+  type ParsedSunsetInstruction,
+  type ParsedSweepAbandonedLockInstruction,
+  type ParsedSweepExcessInstruction,
   type ParsedTransferAdminInstruction,
+  type ParsedUnfreezeLockInstruction,
+  type ParsedUnindexLockInstruction,
   type ParsedUnlockInstruction,
+  type ParsedUnlockToAssociatedAccountInstruction,
+  type ParsedUnlockWithReceiptInstruction,
+  type ParsedUnlockWithSessionInstruction,
+  type ParsedUnlockWithoutClosingInstruction,
+  type ParsedUpdateFeeConfigInstruction,
+  type ParsedUpdateLockMetadataInstruction,
+  type ParsedVerifyCollateralInstruction,
   type ParsedWithdrawFeesInstruction,
+  type ParsedWithdrawPenaltiesInstruction,
+  type ParsedWithdrawSolFeesInstruction,
+  type ParsedWithdrawSurplusRentInstruction,
+  type ParsedWithdrawTokenFeesInstruction,
 } from '../instructions';
 
 export const LOCKSMITH_PROGRAM_ADDRESS =
@@ -26,6 +111,26 @@ export const LOCKSMITH_PROGRAM_ADDRESS =
 export enum LocksmithAccount {
   ConfigAccount,
   LockAccount,
+  VestingLockAccount,
+  TemplateAccount,
+  StandingOrderAccount,
+  ForwardingDestinationAccount,
+  HookAccount,
+  LenderAccount,
+  SessionAccount,
+  NoteAccount,
+  MintIndexAccount,
+  IndexEntryAccount,
+  LockCertificateAccount,
+  LockSharesAccount,
+  UnlockApprovalsAccount,
+  VoteWeightSnapshotAccount,
+  MintPolicyAccount,
+  FeeCapAccount,
+  FeeExemptionAccount,
+  OwnerCounterAccount,
+  FeeScheduleAccount,
+  LockProofAccount,
 }
 
 export enum LocksmithInstruction {
@@ -34,6 +139,91 @@ export enum LocksmithInstruction {
   WithdrawFees,
   InitializeLock,
   Unlock,
+  AuditLock,
+  SetComplianceAuthority,
+  FreezeLock,
+  UnfreezeLock,
+  InitializeLockWithOptions,
+  SetLockTemplate,
+  InitializeLockFromTemplate,
+  CreateStandingOrder,
+  ExecuteStandingOrder,
+  CancelStandingOrder,
+  SetForwardingDestination,
+  SetLockForwarding,
+  SetHook,
+  SweepAbandonedLock,
+  SetLender,
+  SetCollateralized,
+  VerifyCollateral,
+  InitializeLockForDuration,
+  ProposeFeeMint,
+  MigrateFeeMint,
+  InitializeLockWithPayer,
+  InitializeGiftLock,
+  ClaimLock,
+  InitializeRelayedLock,
+  SetSession,
+  UnlockWithSession,
+  SetLockNote,
+  IndexLock,
+  UnindexLock,
+  WithdrawSurplusRent,
+  SetSuccessorProgram,
+  MigrateLock,
+  Sunset,
+  PublishLockCertificate,
+  CloseLockCertificate,
+  ExtendLock,
+  IncreaseLockAmount,
+  InitializeVesting,
+  ClaimVested,
+  RevokeVesting,
+  SetEarlyUnlockPenalty,
+  EmergencyUnlock,
+  WithdrawPenalties,
+  Relock,
+  CrankUnlock,
+  InitializeLockBatch,
+  SetBatchFeeMode,
+  MintLockReceipt,
+  BurnLockReceipt,
+  UnlockWithReceipt,
+  UpdateLockMetadata,
+  ClaimAsFallback,
+  SetLockShares,
+  ClaimShare,
+  SetUnlockApprovers,
+  ApproveUnlock,
+  ExecuteUnlock,
+  ApproveMilestone,
+  ReleaseEscrow,
+  SnapshotWeight,
+  CloseWeightSnapshot,
+  SetPaused,
+  SetMintPolicy,
+  CloseMintPolicy,
+  SetMintPolicyMode,
+  SetRejectFreezableMints,
+  RecordLockProof,
+  CloseLockProof,
+  UnlockWithoutClosing,
+  CloseLock,
+  UnlockToAssociatedAccount,
+  SweepExcess,
+  RecoverStrandedTokens,
+  UpdateFeeConfig,
+  SetPercentageFee,
+  SetSolFee,
+  WithdrawSolFees,
+  SetFeeExemption,
+  CloseFeeExemption,
+  SetFeeSchedule,
+  SetFeeCap,
+  CloseFeeCap,
+  WithdrawTokenFees,
+  SetMaxLocksPerOwner,
+  SetOwnerLockCap,
 }
 
 export function identifyLocksmithInstruction(
@@ -55,6 +245,261 @@ export function identifyLocksmithInstruction(
   if (containsBytes(data, getU8Encoder().encode(4), 0)) {
     return LocksmithInstruction.Unlock;
   }
+  if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+    return LocksmithInstruction.AuditLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+    return LocksmithInstruction.SetComplianceAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+    return LocksmithInstruction.FreezeLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+    return LocksmithInstruction.UnfreezeLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+    return LocksmithInstruction.InitializeLockWithOptions;
+  }
+  if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+    return LocksmithInstruction.SetLockTemplate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+    return LocksmithInstruction.InitializeLockFromTemplate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return LocksmithInstruction.CreateStandingOrder;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return LocksmithInstruction.ExecuteStandingOrder;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return LocksmithInstruction.CancelStandingOrder;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return LocksmithInstruction.SetForwardingDestination;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return LocksmithInstruction.SetLockForwarding;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return LocksmithInstruction.SetHook;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return LocksmithInstruction.SweepAbandonedLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return LocksmithInstruction.SetLender;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return LocksmithInstruction.SetCollateralized;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return LocksmithInstruction.VerifyCollateral;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return LocksmithInstruction.InitializeLockForDuration;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return LocksmithInstruction.ProposeFeeMint;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return LocksmithInstruction.MigrateFeeMint;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return LocksmithInstruction.InitializeLockWithPayer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return LocksmithInstruction.InitializeGiftLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return LocksmithInstruction.ClaimLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return LocksmithInstruction.InitializeRelayedLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return LocksmithInstruction.SetSession;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return LocksmithInstruction.UnlockWithSession;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return LocksmithInstruction.SetLockNote;
+  }
+  if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+    return LocksmithInstruction.IndexLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+    return LocksmithInstruction.UnindexLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return LocksmithInstruction.WithdrawSurplusRent;
+  }
+  if (containsBytes(data, getU8Encoder().encode(35), 0)) {
+    return LocksmithInstruction.SetSuccessorProgram;
+  }
+  if (containsBytes(data, getU8Encoder().encode(36), 0)) {
+    return LocksmithInstruction.MigrateLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(37), 0)) {
+    return LocksmithInstruction.Sunset;
+  }
+  if (containsBytes(data, getU8Encoder().encode(38), 0)) {
+    return LocksmithInstruction.PublishLockCertificate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(39), 0)) {
+    return LocksmithInstruction.CloseLockCertificate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(40), 0)) {
+    return LocksmithInstruction.ExtendLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(41), 0)) {
+    return LocksmithInstruction.IncreaseLockAmount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(42), 0)) {
+    return LocksmithInstruction.InitializeVesting;
+  }
+  if (containsBytes(data, getU8Encoder().encode(43), 0)) {
+    return LocksmithInstruction.ClaimVested;
+  }
+  if (containsBytes(data, getU8Encoder().encode(44), 0)) {
+    return LocksmithInstruction.RevokeVesting;
+  }
+  if (containsBytes(data, getU8Encoder().encode(45), 0)) {
+    return LocksmithInstruction.SetEarlyUnlockPenalty;
+  }
+  if (containsBytes(data, getU8Encoder().encode(46), 0)) {
+    return LocksmithInstruction.EmergencyUnlock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(47), 0)) {
+    return LocksmithInstruction.WithdrawPenalties;
+  }
+  if (containsBytes(data, getU8Encoder().encode(48), 0)) {
+    return LocksmithInstruction.Relock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(49), 0)) {
+    return LocksmithInstruction.CrankUnlock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(50), 0)) {
+    return LocksmithInstruction.InitializeLockBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(51), 0)) {
+    return LocksmithInstruction.SetBatchFeeMode;
+  }
+  if (containsBytes(data, getU8Encoder().encode(52), 0)) {
+    return LocksmithInstruction.MintLockReceipt;
+  }
+  if (containsBytes(data, getU8Encoder().encode(53), 0)) {
+    return LocksmithInstruction.BurnLockReceipt;
+  }
+  if (containsBytes(data, getU8Encoder().encode(54), 0)) {
+    return LocksmithInstruction.UnlockWithReceipt;
+  }
+  if (containsBytes(data, getU8Encoder().encode(55), 0)) {
+    return LocksmithInstruction.UpdateLockMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(56), 0)) {
+    return LocksmithInstruction.ClaimAsFallback;
+  }
+  if (containsBytes(data, getU8Encoder().encode(57), 0)) {
+    return LocksmithInstruction.SetLockShares;
+  }
+  if (containsBytes(data, getU8Encoder().encode(58), 0)) {
+    return LocksmithInstruction.ClaimShare;
+  }
+  if (containsBytes(data, getU8Encoder().encode(59), 0)) {
+    return LocksmithInstruction.SetUnlockApprovers;
+  }
+  if (containsBytes(data, getU8Encoder().encode(60), 0)) {
+    return LocksmithInstruction.ApproveUnlock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(61), 0)) {
+    return LocksmithInstruction.ExecuteUnlock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(62), 0)) {
+    return LocksmithInstruction.ApproveMilestone;
+  }
+  if (containsBytes(data, getU8Encoder().encode(63), 0)) {
+    return LocksmithInstruction.ReleaseEscrow;
+  }
+  if (containsBytes(data, getU8Encoder().encode(64), 0)) {
+    return LocksmithInstruction.SnapshotWeight;
+  }
+  if (containsBytes(data, getU8Encoder().encode(65), 0)) {
+    return LocksmithInstruction.CloseWeightSnapshot;
+  }
+  if (containsBytes(data, getU8Encoder().encode(66), 0)) {
+    return LocksmithInstruction.SetPaused;
+  }
+  if (containsBytes(data, getU8Encoder().encode(67), 0)) {
+    return LocksmithInstruction.SetMintPolicy;
+  }
+  if (containsBytes(data, getU8Encoder().encode(68), 0)) {
+    return LocksmithInstruction.CloseMintPolicy;
+  }
+  if (containsBytes(data, getU8Encoder().encode(69), 0)) {
+    return LocksmithInstruction.SetMintPolicyMode;
+  }
+  if (containsBytes(data, getU8Encoder().encode(70), 0)) {
+    return LocksmithInstruction.SetRejectFreezableMints;
+  }
+  if (containsBytes(data, getU8Encoder().encode(71), 0)) {
+    return LocksmithInstruction.RecordLockProof;
+  }
+  if (containsBytes(data, getU8Encoder().encode(72), 0)) {
+    return LocksmithInstruction.CloseLockProof;
+  }
+  if (containsBytes(data, getU8Encoder().encode(73), 0)) {
+    return LocksmithInstruction.UnlockWithoutClosing;
+  }
+  if (containsBytes(data, getU8Encoder().encode(74), 0)) {
+    return LocksmithInstruction.CloseLock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(75), 0)) {
+    return LocksmithInstruction.UnlockToAssociatedAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(76), 0)) {
+    return LocksmithInstruction.SweepExcess;
+  }
+  if (containsBytes(data, getU8Encoder().encode(77), 0)) {
+    return LocksmithInstruction.RecoverStrandedTokens;
+  }
+  if (containsBytes(data, getU8Encoder().encode(78), 0)) {
+    return LocksmithInstruction.UpdateFeeConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(79), 0)) {
+    return LocksmithInstruction.SetPercentageFee;
+  }
+  if (containsBytes(data, getU8Encoder().encode(80), 0)) {
+    return LocksmithInstruction.SetSolFee;
+  }
+  if (containsBytes(data, getU8Encoder().encode(81), 0)) {
+    return LocksmithInstruction.WithdrawSolFees;
+  }
+  if (containsBytes(data, getU8Encoder().encode(82), 0)) {
+    return LocksmithInstruction.SetFeeExemption;
+  }
+  if (containsBytes(data, getU8Encoder().encode(83), 0)) {
+    return LocksmithInstruction.CloseFeeExemption;
+  }
+  if (containsBytes(data, getU8Encoder().encode(84), 0)) {
+    return LocksmithInstruction.SetFeeSchedule;
+  }
+  if (containsBytes(data, getU8Encoder().encode(85), 0)) {
+    return LocksmithInstruction.SetFeeCap;
+  }
+  if (containsBytes(data, getU8Encoder().encode(86), 0)) {
+    return LocksmithInstruction.CloseFeeCap;
+  }
+  if (containsBytes(data, getU8Encoder().encode(87), 0)) {
+    return LocksmithInstruction.WithdrawTokenFees;
+  }
+  if (containsBytes(data, getU8Encoder().encode(88), 0)) {
+    return LocksmithInstruction.SetMaxLocksPerOwner;
+  }
+  if (containsBytes(data, getU8Encoder().encode(89), 0)) {
+    return LocksmithInstruction.SetOwnerLockCap;
+  }
   throw new Error(
     'The provided instruction could not be identified as a locksmith instruction.'
   );
@@ -77,4 +522,259 @@ export type ParsedLocksmithInstruction<
     } & ParsedInitializeLockInstruction<TProgram>)
   | ({
       instructionType: LocksmithInstruction.Unlock;
-    } & ParsedUnlockInstruction<TProgram>);
+    } & ParsedUnlockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.AuditLock;
+    } & ParsedAuditLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetComplianceAuthority;
+    } & ParsedSetComplianceAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.FreezeLock;
+    } & ParsedFreezeLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.UnfreezeLock;
+    } & ParsedUnfreezeLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.InitializeLockWithOptions;
+    } & ParsedInitializeLockWithOptionsInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetLockTemplate;
+    } & ParsedSetLockTemplateInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.InitializeLockFromTemplate;
+    } & ParsedInitializeLockFromTemplateInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.CreateStandingOrder;
+    } & ParsedCreateStandingOrderInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.ExecuteStandingOrder;
+    } & ParsedExecuteStandingOrderInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.CancelStandingOrder;
+    } & ParsedCancelStandingOrderInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetForwardingDestination;
+    } & ParsedSetForwardingDestinationInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetLockForwarding;
+    } & ParsedSetLockForwardingInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetHook;
+    } & ParsedSetHookInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SweepAbandonedLock;
+    } & ParsedSweepAbandonedLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetLender;
+    } & ParsedSetLenderInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetCollateralized;
+    } & ParsedSetCollateralizedInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.VerifyCollateral;
+    } & ParsedVerifyCollateralInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.InitializeLockForDuration;
+    } & ParsedInitializeLockForDurationInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.ProposeFeeMint;
+    } & ParsedProposeFeeMintInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.MigrateFeeMint;
+    } & ParsedMigrateFeeMintInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.InitializeLockWithPayer;
+    } & ParsedInitializeLockWithPayerInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.InitializeGiftLock;
+    } & ParsedInitializeGiftLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.ClaimLock;
+    } & ParsedClaimLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.InitializeRelayedLock;
+    } & ParsedInitializeRelayedLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetSession;
+    } & ParsedSetSessionInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.UnlockWithSession;
+    } & ParsedUnlockWithSessionInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetLockNote;
+    } & ParsedSetLockNoteInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.IndexLock;
+    } & ParsedIndexLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.UnindexLock;
+    } & ParsedUnindexLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.WithdrawSurplusRent;
+    } & ParsedWithdrawSurplusRentInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetSuccessorProgram;
+    } & ParsedSetSuccessorProgramInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.MigrateLock;
+    } & ParsedMigrateLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.Sunset;
+    } & ParsedSunsetInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.PublishLockCertificate;
+    } & ParsedPublishLockCertificateInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.CloseLockCertificate;
+    } & ParsedCloseLockCertificateInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.ExtendLock;
+    } & ParsedExtendLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.IncreaseLockAmount;
+    } & ParsedIncreaseLockAmountInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.InitializeVesting;
+    } & ParsedInitializeVestingInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.ClaimVested;
+    } & ParsedClaimVestedInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.RevokeVesting;
+    } & ParsedRevokeVestingInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetEarlyUnlockPenalty;
+    } & ParsedSetEarlyUnlockPenaltyInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.EmergencyUnlock;
+    } & ParsedEmergencyUnlockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.WithdrawPenalties;
+    } & ParsedWithdrawPenaltiesInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.Relock;
+    } & ParsedRelockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.CrankUnlock;
+    } & ParsedCrankUnlockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.InitializeLockBatch;
+    } & ParsedInitializeLockBatchInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetBatchFeeMode;
+    } & ParsedSetBatchFeeModeInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.MintLockReceipt;
+    } & ParsedMintLockReceiptInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.BurnLockReceipt;
+    } & ParsedBurnLockReceiptInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.UnlockWithReceipt;
+    } & ParsedUnlockWithReceiptInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.UpdateLockMetadata;
+    } & ParsedUpdateLockMetadataInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.ClaimAsFallback;
+    } & ParsedClaimAsFallbackInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetLockShares;
+    } & ParsedSetLockSharesInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.ClaimShare;
+    } & ParsedClaimShareInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetUnlockApprovers;
+    } & ParsedSetUnlockApproversInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.ApproveUnlock;
+    } & ParsedApproveUnlockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.ExecuteUnlock;
+    } & ParsedExecuteUnlockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.ApproveMilestone;
+    } & ParsedApproveMilestoneInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.ReleaseEscrow;
+    } & ParsedReleaseEscrowInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SnapshotWeight;
+    } & ParsedSnapshotWeightInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.CloseWeightSnapshot;
+    } & ParsedCloseWeightSnapshotInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetPaused;
+    } & ParsedSetPausedInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetMintPolicy;
+    } & ParsedSetMintPolicyInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.CloseMintPolicy;
+    } & ParsedCloseMintPolicyInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetMintPolicyMode;
+    } & ParsedSetMintPolicyModeInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetRejectFreezableMints;
+    } & ParsedSetRejectFreezableMintsInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.RecordLockProof;
+    } & ParsedRecordLockProofInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.CloseLockProof;
+    } & ParsedCloseLockProofInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.UnlockWithoutClosing;
+    } & ParsedUnlockWithoutClosingInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.CloseLock;
+    } & ParsedCloseLockInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.UnlockToAssociatedAccount;
+    } & ParsedUnlockToAssociatedAccountInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SweepExcess;
+    } & ParsedSweepExcessInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.RecoverStrandedTokens;
+    } & ParsedRecoverStrandedTokensInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.UpdateFeeConfig;
+    } & ParsedUpdateFeeConfigInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetPercentageFee;
+    } & ParsedSetPercentageFeeInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetSolFee;
+    } & ParsedSetSolFeeInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.WithdrawSolFees;
+    } & ParsedWithdrawSolFeesInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetFeeExemption;
+    } & ParsedSetFeeExemptionInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.CloseFeeExemption;
+    } & ParsedCloseFeeExemptionInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetFeeSchedule;
+    } & ParsedSetFeeScheduleInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetFeeCap;
+    } & ParsedSetFeeCapInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.CloseFeeCap;
+    } & ParsedCloseFeeCapInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.WithdrawTokenFees;
+    } & ParsedWithdrawTokenFeesInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetMaxLocksPerOwner;
+    } & ParsedSetMaxLocksPerOwnerInstruction<TProgram>)
+  | ({
+      instructionType: LocksmithInstruction.SetOwnerLockCap;
+    } & ParsedSetOwnerLockCapInstruction<TProgram>);
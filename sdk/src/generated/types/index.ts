@@ -6,4 +6,11 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './auditStatus';
+export * from './collateralAttestation';
+export * from './complianceAction';
+export * from './complianceEvent';
+export * from './lockAuditEvent';
+export * from './lockOptions';
 export * from './locksmithError';
+export * from './standingOrderParams';
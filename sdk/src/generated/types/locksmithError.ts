@@ -28,6 +28,55 @@ export enum LocksmithError {
   InvalidPDA,
   InvalidMint,
   LockDurationExceeded,
+  LockFrozen,
+  InvalidCredential,
+  StandingOrderNotDue,
+  InvalidForwardingDestination,
+  InvalidHook,
+  HookComputeExceeded,
+  LockNotAbandoned,
+  InvalidDonation,
+  LockCollateralized,
+  TransactionExpired,
+  FeeMintNotReady,
+  InvalidClaimCode,
+  ClaimCodeActive,
+  InvalidSignature,
+  InvalidSession,
+  LockAlreadyIndexed,
+  IndexEntryActive,
+  InvalidSuccessorProgram,
+  ProgramSunset,
+  CertificateActive,
+  NothingVested,
+  VestingNotRevocable,
+  EarlyUnlockNotAllowed,
+  LockIsPermanent,
+  ReceiptActive,
+  LockBoundToReceipt,
+  InvalidFallback,
+  InvalidUnlockDestination,
+  InvalidShares,
+  LockShared,
+  ShareClaimed,
+  InvalidApprovers,
+  ApprovalsRequired,
+  InvalidPriceCondition,
+  InvalidPriceFeed,
+  PriceUnreliable,
+  PriceConditionNotMet,
+  InvalidArbiter,
+  MilestoneNotApproved,
+  InvalidCounterparty,
+  WeightSnapshotActive,
+  ProgramPaused,
+  MintNotAllowed,
+  MintHasFreezeAuthority,
+  LockProofActive,
+  LockNotEmpty,
+  LockCapReached,
+  FeeDependsOnTerms,
+  InvalidGiftOptions,
 }
 
 export type LocksmithErrorArgs = LocksmithError;
@@ -21,3 +21,55 @@ export * from "./generated";
 // Export PDA helpers and constants
 export * from "./pdas";
 export * from "./constants";
+
+// Export transaction building helpers
+export * from "./transaction";
+export * from "./lookupTables";
+export * from "./costs";
+export * from "./nonce";
+export * from "./send";
+
+// Export account query helpers
+export * from "./accounts";
+export * from "./queries";
+export * from "./subscriptions";
+export * from "./events";
+
+// Export mint index helpers
+export * from "./mintIndex";
+
+// Export standing order crank helpers
+export * from "./standingOrders";
+
+// Export batch lock creation helpers
+export * from "./batch";
+
+// Export proof-of-lock certificates
+export * from "./certificate";
+
+// Export vesting lock helpers
+export * from "./vesting";
+
+// Export claim-code helpers
+export * from "./claims";
+
+// Export relayed lock creation helpers
+export * from "./relay";
+
+// Export encrypted lock note helpers
+export * from "./notes";
+
+// Export split lock helpers
+export * from "./shares";
+
+// Export multisig unlock helpers
+export * from "./approvals";
+
+// Export price-conditional lock helpers
+export * from "./priceConditions";
+
+// Export vote-escrow weight helpers
+export * from "./votingWeight";
+
+// Export local validator test helpers
+export * from "./testing";
@@ -14,6 +14,103 @@ import {
   parseUnlockInstruction,
   INITIALIZE_LOCK_DISCRIMINATOR,
   UNLOCK_DISCRIMINATOR,
+  AUDIT_LOCK_DISCRIMINATOR,
+  SET_COMPLIANCE_AUTHORITY_DISCRIMINATOR,
+  FREEZE_LOCK_DISCRIMINATOR,
+  UNFREEZE_LOCK_DISCRIMINATOR,
+  INITIALIZE_LOCK_WITH_OPTIONS_DISCRIMINATOR,
+  SET_LOCK_TEMPLATE_DISCRIMINATOR,
+  INITIALIZE_LOCK_FROM_TEMPLATE_DISCRIMINATOR,
+  CREATE_STANDING_ORDER_DISCRIMINATOR,
+  EXECUTE_STANDING_ORDER_DISCRIMINATOR,
+  CANCEL_STANDING_ORDER_DISCRIMINATOR,
+  SET_FORWARDING_DESTINATION_DISCRIMINATOR,
+  SET_LOCK_FORWARDING_DISCRIMINATOR,
+  SET_HOOK_DISCRIMINATOR,
+  SET_LENDER_DISCRIMINATOR,
+  SET_COLLATERALIZED_DISCRIMINATOR,
+  VERIFY_COLLATERAL_DISCRIMINATOR,
+  INITIALIZE_LOCK_FOR_DURATION_DISCRIMINATOR,
+  PROPOSE_FEE_MINT_DISCRIMINATOR,
+  MIGRATE_FEE_MINT_DISCRIMINATOR,
+  INITIALIZE_LOCK_WITH_PAYER_DISCRIMINATOR,
+  INITIALIZE_GIFT_LOCK_DISCRIMINATOR,
+  CLAIM_LOCK_DISCRIMINATOR,
+  INITIALIZE_RELAYED_LOCK_DISCRIMINATOR,
+  SET_SESSION_DISCRIMINATOR,
+  UNLOCK_WITH_SESSION_DISCRIMINATOR,
+  SET_LOCK_NOTE_DISCRIMINATOR,
+  INDEX_LOCK_DISCRIMINATOR,
+  UNINDEX_LOCK_DISCRIMINATOR,
+  WITHDRAW_SURPLUS_RENT_DISCRIMINATOR,
+  SET_SUCCESSOR_PROGRAM_DISCRIMINATOR,
+  MIGRATE_LOCK_DISCRIMINATOR,
+  SUNSET_DISCRIMINATOR,
+  PUBLISH_LOCK_CERTIFICATE_DISCRIMINATOR,
+  CLOSE_LOCK_CERTIFICATE_DISCRIMINATOR,
+  EXTEND_LOCK_DISCRIMINATOR,
+  INCREASE_LOCK_AMOUNT_DISCRIMINATOR,
+  INITIALIZE_VESTING_DISCRIMINATOR,
+  CLAIM_VESTED_DISCRIMINATOR,
+  REVOKE_VESTING_DISCRIMINATOR,
+  SET_EARLY_UNLOCK_PENALTY_DISCRIMINATOR,
+  EMERGENCY_UNLOCK_DISCRIMINATOR,
+  WITHDRAW_PENALTIES_DISCRIMINATOR,
+  RELOCK_DISCRIMINATOR,
+  CRANK_UNLOCK_DISCRIMINATOR,
+  INITIALIZE_LOCK_BATCH_DISCRIMINATOR,
+  SET_BATCH_FEE_MODE_DISCRIMINATOR,
+  MINT_LOCK_RECEIPT_DISCRIMINATOR,
+  BURN_LOCK_RECEIPT_DISCRIMINATOR,
+  UNLOCK_WITH_RECEIPT_DISCRIMINATOR,
+  UPDATE_LOCK_METADATA_DISCRIMINATOR,
+  CLAIM_AS_FALLBACK_DISCRIMINATOR,
+  SET_LOCK_SHARES_DISCRIMINATOR,
+  CLAIM_SHARE_DISCRIMINATOR,
+  SET_UNLOCK_APPROVERS_DISCRIMINATOR,
+  APPROVE_UNLOCK_DISCRIMINATOR,
+  EXECUTE_UNLOCK_DISCRIMINATOR,
+  APPROVE_MILESTONE_DISCRIMINATOR,
+  RELEASE_ESCROW_DISCRIMINATOR,
+  SNAPSHOT_WEIGHT_DISCRIMINATOR,
+  CLOSE_WEIGHT_SNAPSHOT_DISCRIMINATOR,
+  SET_PAUSED_DISCRIMINATOR,
+  SET_MINT_POLICY_DISCRIMINATOR,
+  CLOSE_MINT_POLICY_DISCRIMINATOR,
+  SET_MINT_POLICY_MODE_DISCRIMINATOR,
+  SET_REJECT_FREEZABLE_MINTS_DISCRIMINATOR,
+  RECORD_LOCK_PROOF_DISCRIMINATOR,
+  CLOSE_LOCK_PROOF_DISCRIMINATOR,
+  UNLOCK_WITHOUT_CLOSING_DISCRIMINATOR,
+  CLOSE_LOCK_DISCRIMINATOR,
+  UNLOCK_TO_ASSOCIATED_ACCOUNT_DISCRIMINATOR,
+  SWEEP_EXCESS_DISCRIMINATOR,
+  RECOVER_STRANDED_TOKENS_DISCRIMINATOR,
+  UPDATE_FEE_CONFIG_DISCRIMINATOR,
+  SET_PERCENTAGE_FEE_DISCRIMINATOR,
+  getSetPercentageFeeInstructionDataEncoder,
+  SET_SOL_FEE_DISCRIMINATOR,
+  WITHDRAW_SOL_FEES_DISCRIMINATOR,
+  SET_FEE_EXEMPTION_DISCRIMINATOR,
+  CLOSE_FEE_EXEMPTION_DISCRIMINATOR,
+  SET_FEE_SCHEDULE_DISCRIMINATOR,
+  getSetFeeScheduleInstructionDataEncoder,
+  SET_FEE_CAP_DISCRIMINATOR,
+  getSetFeeCapInstructionDataEncoder,
+  CLOSE_FEE_CAP_DISCRIMINATOR,
+  WITHDRAW_TOKEN_FEES_DISCRIMINATOR,
+  SET_MAX_LOCKS_PER_OWNER_DISCRIMINATOR,
+  SET_OWNER_LOCK_CAP_DISCRIMINATOR,
+  getSetLockNoteInstructionDataEncoder,
+  getSetSessionInstructionDataEncoder,
+  getClaimLockInstructionDataEncoder,
+  getInitializeRelayedLockInstructionDataEncoder,
+  getInitializeLockForDurationInstructionDataEncoder,
+  getExtendLockInstructionDataEncoder,
+  getIncreaseLockAmountInstructionDataEncoder,
+  getMintLockReceiptInstructionDataEncoder,
+  getInitializeVestingInstructionDataEncoder,
+  LOCKSMITH_PROGRAM_ADDRESS,
 } from "./generated";
 
 /**
@@ -49,6 +146,338 @@ describe("Instruction encoding", () => {
     it("Unlock discriminator is 4", () => {
       expect(UNLOCK_DISCRIMINATOR).toBe(4);
     });
+
+    it("AuditLock discriminator is 5", () => {
+      expect(AUDIT_LOCK_DISCRIMINATOR).toBe(5);
+    });
+
+    it("compliance instructions use discriminators 6-8", () => {
+      expect(SET_COMPLIANCE_AUTHORITY_DISCRIMINATOR).toBe(6);
+      expect(FREEZE_LOCK_DISCRIMINATOR).toBe(7);
+      expect(UNFREEZE_LOCK_DISCRIMINATOR).toBe(8);
+    });
+
+    it("InitializeLockWithOptions uses discriminator 9", () => {
+      expect(INITIALIZE_LOCK_WITH_OPTIONS_DISCRIMINATOR).toBe(9);
+    });
+
+    it("template instructions use discriminators 10-11", () => {
+      expect(SET_LOCK_TEMPLATE_DISCRIMINATOR).toBe(10);
+      expect(INITIALIZE_LOCK_FROM_TEMPLATE_DISCRIMINATOR).toBe(11);
+    });
+
+    it("standing order instructions use discriminators 12-14", () => {
+      expect(CREATE_STANDING_ORDER_DISCRIMINATOR).toBe(12);
+      expect(EXECUTE_STANDING_ORDER_DISCRIMINATOR).toBe(13);
+      expect(CANCEL_STANDING_ORDER_DISCRIMINATOR).toBe(14);
+    });
+
+    it("forwarding instructions use discriminators 15-16", () => {
+      expect(SET_FORWARDING_DESTINATION_DISCRIMINATOR).toBe(15);
+      expect(SET_LOCK_FORWARDING_DISCRIMINATOR).toBe(16);
+    });
+
+    it("SetHook uses discriminator 17", () => {
+      expect(SET_HOOK_DISCRIMINATOR).toBe(17);
+    });
+
+    it("collateral instructions use discriminators 19-21", () => {
+      expect(SET_LENDER_DISCRIMINATOR).toBe(19);
+      expect(SET_COLLATERALIZED_DISCRIMINATOR).toBe(20);
+      expect(VERIFY_COLLATERAL_DISCRIMINATOR).toBe(21);
+    });
+
+    it("InitializeLockForDuration uses discriminator 22", () => {
+      expect(INITIALIZE_LOCK_FOR_DURATION_DISCRIMINATOR).toBe(22);
+    });
+
+    it("fee mint instructions use discriminators 23-24", () => {
+      expect(PROPOSE_FEE_MINT_DISCRIMINATOR).toBe(23);
+      expect(MIGRATE_FEE_MINT_DISCRIMINATOR).toBe(24);
+    });
+
+    it("sponsored lock instructions use discriminators 25-26", () => {
+      expect(INITIALIZE_LOCK_WITH_PAYER_DISCRIMINATOR).toBe(25);
+      expect(INITIALIZE_GIFT_LOCK_DISCRIMINATOR).toBe(26);
+    });
+
+    it("ClaimLock uses discriminator 27 and carries no claim code", () => {
+      const data = getClaimLockInstructionDataEncoder().encode({});
+
+      expect(CLAIM_LOCK_DISCRIMINATOR).toBe(27);
+      expect(data).toEqual(new Uint8Array([27]));
+    });
+
+    it("InitializeRelayedLock uses discriminator 28", () => {
+      const data = getInitializeRelayedLockInstructionDataEncoder().encode({
+        amount: 1_000n,
+        unlockTimestamp: 2_000n,
+        lockId: 7n,
+        validUntil: 1_500n,
+      });
+
+      expect(INITIALIZE_RELAYED_LOCK_DISCRIMINATOR).toBe(28);
+      expect(data.length).toBe(33);
+      expect(data[0]).toBe(28);
+      const view = new DataView(data.buffer, data.byteOffset);
+      expect(view.getBigInt64(25, true)).toBe(1_500n);
+    });
+
+    it("session instructions use discriminators 29-30", () => {
+      const data = getSetSessionInstructionDataEncoder().encode({
+        mint: TEST_ADDRESSES.mint,
+        expiresAt: 1_800_000_000n,
+      });
+
+      expect(SET_SESSION_DISCRIMINATOR).toBe(29);
+      expect(UNLOCK_WITH_SESSION_DISCRIMINATOR).toBe(30);
+      expect(data.length).toBe(41);
+      expect(data[0]).toBe(29);
+      const view = new DataView(data.buffer, data.byteOffset);
+      expect(view.getBigInt64(33, true)).toBe(1_800_000_000n);
+    });
+
+    it("SetLockNote uses discriminator 31 and a fixed note buffer", () => {
+      const data = getSetLockNoteInstructionDataEncoder().encode({
+        note: new Uint8Array(256).fill(1),
+        noteLen: 40,
+      });
+
+      expect(SET_LOCK_NOTE_DISCRIMINATOR).toBe(31);
+      expect(data.length).toBe(259);
+      expect(data[0]).toBe(31);
+      expect(data[257] | (data[258] << 8)).toBe(40);
+    });
+
+    it("mint index instructions use discriminators 32-33", () => {
+      expect(INDEX_LOCK_DISCRIMINATOR).toBe(32);
+      expect(UNINDEX_LOCK_DISCRIMINATOR).toBe(33);
+    });
+
+    it("WithdrawSurplusRent uses discriminator 34", () => {
+      expect(WITHDRAW_SURPLUS_RENT_DISCRIMINATOR).toBe(34);
+    });
+
+    it("lock migration instructions use discriminators 35-36", () => {
+      expect(SET_SUCCESSOR_PROGRAM_DISCRIMINATOR).toBe(35);
+      expect(MIGRATE_LOCK_DISCRIMINATOR).toBe(36);
+    });
+
+    it("Sunset uses discriminator 37", () => {
+      expect(SUNSET_DISCRIMINATOR).toBe(37);
+    });
+
+    it("lock certificate instructions use discriminators 38-39", () => {
+      expect(PUBLISH_LOCK_CERTIFICATE_DISCRIMINATOR).toBe(38);
+      expect(CLOSE_LOCK_CERTIFICATE_DISCRIMINATOR).toBe(39);
+    });
+
+    it("ExtendLock uses discriminator 40", () => {
+      expect(EXTEND_LOCK_DISCRIMINATOR).toBe(40);
+    });
+
+    it("IncreaseLockAmount uses discriminator 41", () => {
+      expect(INCREASE_LOCK_AMOUNT_DISCRIMINATOR).toBe(41);
+    });
+
+    it("InitializeVesting uses discriminator 42", () => {
+      expect(INITIALIZE_VESTING_DISCRIMINATOR).toBe(42);
+    });
+
+    it("ClaimVested uses discriminator 43", () => {
+      expect(CLAIM_VESTED_DISCRIMINATOR).toBe(43);
+    });
+
+    it("RevokeVesting uses discriminator 44", () => {
+      expect(REVOKE_VESTING_DISCRIMINATOR).toBe(44);
+    });
+
+    it("SetEarlyUnlockPenalty uses discriminator 45", () => {
+      expect(SET_EARLY_UNLOCK_PENALTY_DISCRIMINATOR).toBe(45);
+    });
+
+    it("EmergencyUnlock uses discriminator 46", () => {
+      expect(EMERGENCY_UNLOCK_DISCRIMINATOR).toBe(46);
+    });
+
+    it("WithdrawPenalties uses discriminator 47", () => {
+      expect(WITHDRAW_PENALTIES_DISCRIMINATOR).toBe(47);
+    });
+
+    it("Relock uses discriminator 48", () => {
+      expect(RELOCK_DISCRIMINATOR).toBe(48);
+    });
+
+    it("CrankUnlock uses discriminator 49", () => {
+      expect(CRANK_UNLOCK_DISCRIMINATOR).toBe(49);
+    });
+
+    it("InitializeLockBatch uses discriminator 50", () => {
+      expect(INITIALIZE_LOCK_BATCH_DISCRIMINATOR).toBe(50);
+    });
+
+    it("SetBatchFeeMode uses discriminator 51", () => {
+      expect(SET_BATCH_FEE_MODE_DISCRIMINATOR).toBe(51);
+    });
+
+    it("MintLockReceipt uses discriminator 52", () => {
+      expect(MINT_LOCK_RECEIPT_DISCRIMINATOR).toBe(52);
+    });
+
+    it("BurnLockReceipt uses discriminator 53", () => {
+      expect(BURN_LOCK_RECEIPT_DISCRIMINATOR).toBe(53);
+    });
+
+    it("UnlockWithReceipt uses discriminator 54", () => {
+      expect(UNLOCK_WITH_RECEIPT_DISCRIMINATOR).toBe(54);
+    });
+
+    it("UpdateLockMetadata uses discriminator 55", () => {
+      expect(UPDATE_LOCK_METADATA_DISCRIMINATOR).toBe(55);
+    });
+
+    it("ClaimAsFallback uses discriminator 56", () => {
+      expect(CLAIM_AS_FALLBACK_DISCRIMINATOR).toBe(56);
+    });
+
+    it("SetLockShares uses discriminator 57", () => {
+      expect(SET_LOCK_SHARES_DISCRIMINATOR).toBe(57);
+    });
+
+    it("ClaimShare uses discriminator 58", () => {
+      expect(CLAIM_SHARE_DISCRIMINATOR).toBe(58);
+    });
+
+    it("SetUnlockApprovers uses discriminator 59", () => {
+      expect(SET_UNLOCK_APPROVERS_DISCRIMINATOR).toBe(59);
+    });
+
+    it("ApproveUnlock uses discriminator 60", () => {
+      expect(APPROVE_UNLOCK_DISCRIMINATOR).toBe(60);
+    });
+
+    it("ExecuteUnlock uses discriminator 61", () => {
+      expect(EXECUTE_UNLOCK_DISCRIMINATOR).toBe(61);
+    });
+
+    it("ApproveMilestone uses discriminator 62", () => {
+      expect(APPROVE_MILESTONE_DISCRIMINATOR).toBe(62);
+    });
+
+    it("ReleaseEscrow uses discriminator 63", () => {
+      expect(RELEASE_ESCROW_DISCRIMINATOR).toBe(63);
+    });
+
+    it("weight snapshot instructions use discriminators 64 and 65", () => {
+      expect(SNAPSHOT_WEIGHT_DISCRIMINATOR).toBe(64);
+      expect(CLOSE_WEIGHT_SNAPSHOT_DISCRIMINATOR).toBe(65);
+    });
+
+    it("SetPaused uses discriminator 66", () => {
+      expect(SET_PAUSED_DISCRIMINATOR).toBe(66);
+    });
+
+    it("mint policy instructions use discriminators 67 to 69", () => {
+      expect(SET_MINT_POLICY_DISCRIMINATOR).toBe(67);
+      expect(CLOSE_MINT_POLICY_DISCRIMINATOR).toBe(68);
+      expect(SET_MINT_POLICY_MODE_DISCRIMINATOR).toBe(69);
+    });
+
+    it("SetRejectFreezableMints uses discriminator 70", () => {
+      expect(SET_REJECT_FREEZABLE_MINTS_DISCRIMINATOR).toBe(70);
+    });
+
+    it("lock proof instructions use discriminators 71 and 72", () => {
+      expect(RECORD_LOCK_PROOF_DISCRIMINATOR).toBe(71);
+      expect(CLOSE_LOCK_PROOF_DISCRIMINATOR).toBe(72);
+    });
+
+    it("split unlock instructions use discriminators 73 and 74", () => {
+      expect(UNLOCK_WITHOUT_CLOSING_DISCRIMINATOR).toBe(73);
+      expect(CLOSE_LOCK_DISCRIMINATOR).toBe(74);
+    });
+
+    it("UnlockToAssociatedAccount uses discriminator 75", () => {
+      expect(UNLOCK_TO_ASSOCIATED_ACCOUNT_DISCRIMINATOR).toBe(75);
+    });
+
+    it("SweepExcess uses discriminator 76", () => {
+      expect(SWEEP_EXCESS_DISCRIMINATOR).toBe(76);
+    });
+
+    it("RecoverStrandedTokens uses discriminator 77", () => {
+      expect(RECOVER_STRANDED_TOKENS_DISCRIMINATOR).toBe(77);
+    });
+
+    it("UpdateFeeConfig uses discriminator 78", () => {
+      expect(UPDATE_FEE_CONFIG_DISCRIMINATOR).toBe(78);
+    });
+
+    it("SetPercentageFee uses discriminator 79", () => {
+      const data = getSetPercentageFeeInstructionDataEncoder().encode({
+        feeBps: 50,
+      });
+
+      expect(SET_PERCENTAGE_FEE_DISCRIMINATOR).toBe(79);
+      expect(data.length).toBe(3);
+      expect(data[0]).toBe(79);
+      const view = new DataView(data.buffer, data.byteOffset);
+      expect(view.getUint16(1, true)).toBe(50);
+    });
+
+    it("SetSolFee and WithdrawSolFees use discriminators 80 and 81", () => {
+      expect(SET_SOL_FEE_DISCRIMINATOR).toBe(80);
+      expect(WITHDRAW_SOL_FEES_DISCRIMINATOR).toBe(81);
+    });
+
+    it("fee exemption instructions use discriminators 82 and 83", () => {
+      expect(SET_FEE_EXEMPTION_DISCRIMINATOR).toBe(82);
+      expect(CLOSE_FEE_EXEMPTION_DISCRIMINATOR).toBe(83);
+    });
+
+    it("SetFeeSchedule uses discriminator 84", () => {
+      const tiers = (start: bigint) =>
+        Array.from({ length: 8 }, (_, i) => start + BigInt(i));
+      const data = getSetFeeScheduleInstructionDataEncoder().encode({
+        tierCount: 2,
+        minDurations: tiers(0n),
+        minAmounts: tiers(8n),
+        feesUsdc: tiers(16n),
+      });
+
+      expect(SET_FEE_SCHEDULE_DISCRIMINATOR).toBe(84);
+      expect(data.length).toBe(194);
+      expect(data[0]).toBe(84);
+      expect(data[1]).toBe(2);
+      const view = new DataView(data.buffer, data.byteOffset);
+      expect(view.getBigUint64(2, true)).toBe(0n);
+      expect(view.getBigUint64(66, true)).toBe(8n);
+      expect(view.getBigUint64(186, true)).toBe(23n);
+    });
+
+    it("SetFeeCap uses discriminator 85", () => {
+      const data = getSetFeeCapInstructionDataEncoder().encode({
+        cap: 1_000_000n,
+      });
+
+      expect(SET_FEE_CAP_DISCRIMINATOR).toBe(85);
+      expect(data.length).toBe(9);
+      const view = new DataView(data.buffer, data.byteOffset);
+      expect(view.getBigUint64(1, true)).toBe(1_000_000n);
+    });
+
+    it("CloseFeeCap uses discriminator 86", () => {
+      expect(CLOSE_FEE_CAP_DISCRIMINATOR).toBe(86);
+    });
+
+    it("WithdrawTokenFees uses discriminator 87", () => {
+      expect(WITHDRAW_TOKEN_FEES_DISCRIMINATOR).toBe(87);
+    });
+
+    it("lock cap instructions use discriminators 88 and 89", () => {
+      expect(SET_MAX_LOCKS_PER_OWNER_DISCRIMINATOR).toBe(88);
+      expect(SET_OWNER_LOCK_CAP_DISCRIMINATOR).toBe(89);
+    });
   });
 
   describe("InitializeLock instruction", () => {
@@ -58,6 +487,7 @@ describe("Instruction encoding", () => {
         amount: 1_000_000n,
         unlockTimestamp: 1700000000n,
         lockId: 42n,
+        validUntil: 1690000000n,
       });
 
       // Expected layout:
@@ -65,7 +495,8 @@ describe("Instruction encoding", () => {
       // [1-8]: amount (1_000_000 = 0x000F4240) in little-endian
       // [9-16]: unlock_timestamp (1700000000 = 0x6552DD80) in little-endian
       // [17-24]: lock_id (42 = 0x2A) in little-endian
-      expect(data.length).toBe(25);
+      // [25-32]: valid_until (1690000000) in little-endian
+      expect(data.length).toBe(33);
       expect(data[0]).toBe(3); // discriminator
 
       // Verify amount bytes (little-endian)
@@ -82,6 +513,14 @@ describe("Instruction encoding", () => {
       const lockIdBytes = data.slice(17, 25);
       const lockId = new DataView(lockIdBytes.buffer).getBigUint64(0, true);
       expect(lockId).toBe(42n);
+
+      // Verify valid_until bytes (little-endian, signed)
+      const validUntilBytes = data.slice(25, 33);
+      const validUntil = new DataView(validUntilBytes.buffer).getBigInt64(
+        0,
+        true
+      );
+      expect(validUntil).toBe(1690000000n);
     });
 
     it("encodes max u64 amount correctly", () => {
@@ -92,6 +531,7 @@ describe("Instruction encoding", () => {
         amount: maxU64,
         unlockTimestamp: 0n,
         lockId: 0n,
+        validUntil: 0n,
       });
 
       const amountBytes = data.slice(1, 9);
@@ -107,6 +547,7 @@ describe("Instruction encoding", () => {
         amount: 0n,
         unlockTimestamp: negativeTimestamp,
         lockId: 0n,
+        validUntil: 0n,
       });
 
       const timestampBytes = data.slice(9, 17);
@@ -122,6 +563,7 @@ describe("Instruction encoding", () => {
         amount: 1_000_000n,
         unlockTimestamp: 1700000000n,
         lockId: 42n,
+        validUntil: 0n,
       };
 
       const encoded = encoder.encode(original);
@@ -140,6 +582,7 @@ describe("Instruction encoding", () => {
         amount: 0x0102030405060708n,
         unlockTimestamp: BigInt("0x090A0B0C0D0E0F10"),
         lockId: 0x1112131415161718n,
+        validUntil: 0n,
       });
 
       // Rust expects these exact bytes (little-endian):
@@ -148,12 +591,101 @@ describe("Instruction encoding", () => {
         0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // amount (little-endian)
         0x10, 0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a, 0x09, // timestamp (little-endian)
         0x18, 0x17, 0x16, 0x15, 0x14, 0x13, 0x12, 0x11, // lock_id (little-endian)
+        0, 0, 0, 0, 0, 0, 0, 0, // valid_until (0 = no deadline)
       ]);
 
       expect(Array.from(data)).toEqual(Array.from(expectedBytes));
     });
   });
 
+  describe("InitializeLockForDuration instruction", () => {
+    it("encodes a signed duration after the amount", () => {
+      const data = getInitializeLockForDurationInstructionDataEncoder().encode({
+        amount: 1_000n,
+        durationSeconds: 86_400n,
+        lockId: 42n,
+        validUntil: 0n,
+      });
+
+      // Rust: test_unpack_initialize_lock_for_duration
+      expect(data.length).toBe(33);
+      expect(data[0]).toBe(22);
+      const duration = new DataView(data.slice(9, 17).buffer).getBigInt64(
+        0,
+        true
+      );
+      expect(duration).toBe(86_400n);
+    });
+  });
+
+  describe("ExtendLock instruction", () => {
+    it("encodes the new unlock timestamp after the lock ID", () => {
+      const data = getExtendLockInstructionDataEncoder().encode({
+        lockId: 42n,
+        newUnlockTimestamp: 1_900_000_000n,
+      });
+
+      // Rust: test_unpack_extend_lock
+      expect(data.length).toBe(17);
+      expect(data[0]).toBe(40);
+      const view = new DataView(data.buffer, data.byteOffset);
+      expect(view.getBigUint64(1, true)).toBe(42n);
+      expect(view.getBigInt64(9, true)).toBe(1_900_000_000n);
+    });
+  });
+
+  describe("IncreaseLockAmount instruction", () => {
+    it("encodes the additional amount after the lock ID", () => {
+      const data = getIncreaseLockAmountInstructionDataEncoder().encode({
+        lockId: 42n,
+        additionalAmount: 500_000n,
+      });
+
+      expect(data.length).toBe(17);
+      expect(data[0]).toBe(41);
+      const view = new DataView(data.buffer, data.byteOffset);
+      expect(view.getBigUint64(1, true)).toBe(42n);
+      expect(view.getBigUint64(9, true)).toBe(500_000n);
+    });
+  });
+
+  describe("MintLockReceipt instruction", () => {
+    it("encodes the transferable flag and nonce after the tag", () => {
+      const data = getMintLockReceiptInstructionDataEncoder().encode({
+        transferable: false,
+        nonce: 7n,
+      });
+
+      // Rust: test_unpack_receipt_instructions
+      expect(Array.from(data)).toEqual([52, 0, 7, 0, 0, 0, 0, 0, 0, 0]);
+    });
+  });
+
+  describe("InitializeVesting instruction", () => {
+    it("encodes the schedule around the vesting ID", () => {
+      const data = getInitializeVestingInstructionDataEncoder().encode({
+        totalAmount: 1_000_000n,
+        startTimestamp: 1_700_000_000n,
+        endTimestamp: 1_800_000_000n,
+        vestingId: 9n,
+        cliffTimestamp: 1_725_000_000n,
+        revocable: true,
+        feeInLamports: true,
+      });
+
+      expect(data.length).toBe(43);
+      expect(data[0]).toBe(42);
+      const view = new DataView(data.buffer, data.byteOffset);
+      expect(view.getBigUint64(1, true)).toBe(1_000_000n);
+      expect(view.getBigInt64(9, true)).toBe(1_700_000_000n);
+      expect(view.getBigInt64(17, true)).toBe(1_800_000_000n);
+      expect(view.getBigUint64(25, true)).toBe(9n);
+      expect(view.getBigInt64(33, true)).toBe(1_725_000_000n);
+      expect(data[41]).toBe(1);
+      expect(data[42]).toBe(1);
+    });
+  });
+
   describe("Unlock instruction", () => {
     it("encodes data in correct byte layout", () => {
       const encoder = getUnlockInstructionDataEncoder();
@@ -219,10 +751,11 @@ describe("Instruction encoding", () => {
         amount: 1_000_000n,
         unlockTimestamp: 1700000000n,
         lockId: 1n,
+        validUntil: 0n,
       });
 
       expect(instruction.accounts.length).toBe(9);
-      expect(instruction.data.length).toBe(25);
+      expect(instruction.data.length).toBe(33);
       expect(instruction.data[0]).toBe(3); // InitializeLock discriminator
     });
 
@@ -235,11 +768,28 @@ describe("Instruction encoding", () => {
         lockId: 42n,
       });
 
-      expect(instruction.accounts.length).toBe(5);
+      // Omitted optional accounts are filled with the program address
+      expect(instruction.accounts.length).toBe(13);
+      expect(instruction.accounts[5].address).toBe(LOCKSMITH_PROGRAM_ADDRESS);
       expect(instruction.data.length).toBe(9);
       expect(instruction.data[0]).toBe(4); // Unlock discriminator
     });
 
+    it("places forwarding accounts after the credential slot", () => {
+      const instruction = getUnlockInstruction({
+        owner: ownerSigner,
+        ownerTokenAccount: TEST_ADDRESSES.ownerToken,
+        lockAccount: TEST_ADDRESSES.lockAccount,
+        lockTokenAccount: TEST_ADDRESSES.lockToken,
+        forwardingDestination: TEST_ADDRESSES.config,
+        destinationProgram: TEST_ADDRESSES.newAdmin,
+        lockId: 42n,
+      });
+
+      expect(instruction.accounts[6].address).toBe(TEST_ADDRESSES.config);
+      expect(instruction.accounts[7].address).toBe(TEST_ADDRESSES.newAdmin);
+    });
+
     it("creates InitializeConfig instruction", () => {
       const instruction = getInitializeConfigInstruction({
         admin: ownerSigner,
@@ -269,12 +819,30 @@ describe("Instruction encoding", () => {
         config: TEST_ADDRESSES.config,
         feeVault: TEST_ADDRESSES.feeVault,
         adminTokenAccount: TEST_ADDRESSES.adminToken,
+        amount: 0,
       });
 
       expect(instruction.accounts.length).toBe(5);
       expect(instruction.data[0]).toBe(2); // WithdrawFees discriminator
     });
 
+    it("encodes a partial WithdrawFees amount", () => {
+      const instruction = getWithdrawFeesInstruction({
+        admin: ownerSigner,
+        config: TEST_ADDRESSES.config,
+        feeVault: TEST_ADDRESSES.feeVault,
+        adminTokenAccount: TEST_ADDRESSES.adminToken,
+        amount: 250_000n,
+      });
+
+      const view = new DataView(
+        instruction.data.buffer,
+        instruction.data.byteOffset
+      );
+      expect(instruction.data.length).toBe(9);
+      expect(view.getBigUint64(1, true)).toBe(250_000n);
+    });
+
     it("accepts number type for bigint fields", () => {
       // TypeScript allows number | bigint for these fields
       const instruction = getInitializeLockInstruction({
@@ -288,6 +856,7 @@ describe("Instruction encoding", () => {
         amount: 1000000, // number instead of bigint
         unlockTimestamp: 1700000000,
         lockId: 1,
+        validUntil: 0,
       });
 
       expect(instruction.data[0]).toBe(3);
@@ -309,6 +878,7 @@ describe("Instruction encoding", () => {
         amount: 1_000_000n,
         unlockTimestamp: 1700000000n,
         lockId: 42n,
+        validUntil: 0n,
       });
 
       const parsed = parseInitializeLockInstruction(instruction);
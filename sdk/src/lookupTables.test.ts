@@ -0,0 +1,153 @@
+import { describe, it, expect } from "vitest";
+import { AccountRole, getAddressDecoder, type Address } from "@solana/kit";
+import {
+  ADDRESS_LOOKUP_TABLE_PROGRAM_ADDRESS,
+  LOOKUP_TABLE_EXTEND_CHUNK_SIZE,
+  findLookupTablePda,
+  getCreateLookupTableInstruction,
+  getExtendLookupTableInstructions,
+  getLocksmithLookupTableAddresses,
+} from "./lookupTables";
+import {
+  findConfigPda,
+  findFeeVaultPda,
+  findLockAccountPda,
+  findLockTokenPda,
+} from "./pdas";
+import { USDC_MINT } from "./constants";
+
+const TEST_ADDRESSES = {
+  owner: "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address,
+  payer: "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde" as Address,
+  mint: "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address,
+  lookupTable: "Vote111111111111111111111111111111111111111" as Address,
+};
+
+describe("CreateLookupTable instruction", () => {
+  it("encodes [u32 tag][u64 slot][u8 bump]", async () => {
+    const { instruction, lookupTableAddress } =
+      await getCreateLookupTableInstruction({
+        authority: TEST_ADDRESSES.owner,
+        payer: TEST_ADDRESSES.payer,
+        recentSlot: 1234n,
+      });
+    const [expectedAddress, bump] = await findLookupTablePda(
+      TEST_ADDRESSES.owner,
+      1234n
+    );
+
+    expect(lookupTableAddress).toBe(expectedAddress);
+    expect(instruction.programAddress).toBe(
+      ADDRESS_LOOKUP_TABLE_PROGRAM_ADDRESS
+    );
+    const data = instruction.data!;
+    expect(data.length).toBe(13);
+    expect(Array.from(data.slice(0, 4))).toEqual([0, 0, 0, 0]);
+    expect(new DataView(data.buffer).getBigUint64(4, true)).toBe(1234n);
+    expect(data[12]).toBe(bump);
+  });
+
+  it("orders accounts as table, authority, payer, system program", async () => {
+    const { instruction, lookupTableAddress } =
+      await getCreateLookupTableInstruction({
+        authority: TEST_ADDRESSES.owner,
+        payer: TEST_ADDRESSES.payer,
+        recentSlot: 1n,
+      });
+
+    expect(instruction.accounts).toEqual([
+      { address: lookupTableAddress, role: AccountRole.WRITABLE },
+      { address: TEST_ADDRESSES.owner, role: AccountRole.READONLY },
+      { address: TEST_ADDRESSES.payer, role: AccountRole.WRITABLE_SIGNER },
+      {
+        address: "11111111111111111111111111111111",
+        role: AccountRole.READONLY,
+      },
+    ]);
+  });
+});
+
+describe("ExtendLookupTable instructions", () => {
+  it("encodes [u32 tag][u64 len][addresses]", () => {
+    const [ix] = getExtendLookupTableInstructions({
+      lookupTable: TEST_ADDRESSES.lookupTable,
+      authority: TEST_ADDRESSES.owner,
+      payer: TEST_ADDRESSES.payer,
+      addresses: [TEST_ADDRESSES.mint, USDC_MINT],
+    });
+
+    const data = ix.data!;
+    expect(data.length).toBe(12 + 2 * 32);
+    expect(Array.from(data.slice(0, 4))).toEqual([2, 0, 0, 0]);
+    expect(new DataView(data.buffer).getBigUint64(4, true)).toBe(2n);
+    const decoder = getAddressDecoder();
+    expect(decoder.decode(data.slice(12, 44))).toBe(TEST_ADDRESSES.mint);
+    expect(decoder.decode(data.slice(44, 76))).toBe(USDC_MINT);
+    expect(ix.accounts![1].role).toBe(AccountRole.READONLY_SIGNER);
+  });
+
+  it("splits addresses into chunks", () => {
+    const addresses = Array.from(
+      { length: LOOKUP_TABLE_EXTEND_CHUNK_SIZE + 1 },
+      () => TEST_ADDRESSES.mint
+    );
+    const ixs = getExtendLookupTableInstructions({
+      lookupTable: TEST_ADDRESSES.lookupTable,
+      authority: TEST_ADDRESSES.owner,
+      payer: TEST_ADDRESSES.payer,
+      addresses,
+    });
+
+    expect(ixs).toHaveLength(2);
+    expect(new DataView(ixs[1].data!.buffer).getBigUint64(4, true)).toBe(1n);
+  });
+
+  it("returns nothing for an empty address list", () => {
+    expect(
+      getExtendLookupTableInstructions({
+        lookupTable: TEST_ADDRESSES.lookupTable,
+        authority: TEST_ADDRESSES.owner,
+        payer: TEST_ADDRESSES.payer,
+        addresses: [],
+      })
+    ).toEqual([]);
+  });
+});
+
+describe("getLocksmithLookupTableAddresses", () => {
+  it("includes program-wide accounts", async () => {
+    const addresses = await getLocksmithLookupTableAddresses({
+      owner: TEST_ADDRESSES.owner,
+    });
+    const [config] = await findConfigPda();
+    const [feeVault] = await findFeeVaultPda();
+
+    expect(addresses).toContain(config);
+    expect(addresses).toContain(feeVault);
+    expect(addresses).toContain(USDC_MINT);
+    expect(addresses).toContain("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+  });
+
+  it("includes lock and escrow PDAs without duplicates", async () => {
+    const addresses = await getLocksmithLookupTableAddresses({
+      owner: TEST_ADDRESSES.owner,
+      locks: [
+        { mint: TEST_ADDRESSES.mint, lockId: 1n },
+        { mint: TEST_ADDRESSES.mint, lockId: 2n },
+      ],
+    });
+    const [lock1] = await findLockAccountPda(
+      TEST_ADDRESSES.owner,
+      TEST_ADDRESSES.mint,
+      1n
+    );
+    const [lockToken1] = await findLockTokenPda(lock1);
+
+    expect(addresses).toContain(lock1);
+    expect(addresses).toContain(lockToken1);
+    expect(new Set(addresses).size).toBe(addresses.length);
+    expect(addresses.filter((a) => a === TEST_ADDRESSES.mint)).toHaveLength(
+      1
+    );
+  });
+});
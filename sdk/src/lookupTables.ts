@@ -0,0 +1,187 @@
+import {
+  AccountRole,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  getU64Encoder,
+  type Address,
+  type Instruction,
+  type ProgramDerivedAddress,
+} from "@solana/kit";
+import { LOCKSMITH_PROGRAM_ADDRESS } from "./generated";
+import {
+  findConfigPda,
+  findFeeVaultPda,
+  findLockAccountPda,
+  findLockTokenPda,
+} from "./pdas";
+import { USDC_MINT } from "./constants";
+
+/**
+ * Address Lookup Table program address
+ */
+export const ADDRESS_LOOKUP_TABLE_PROGRAM_ADDRESS =
+  "AddressLookupTab1e1111111111111111111111111" as Address<"AddressLookupTab1e1111111111111111111111111">;
+
+const SYSTEM_PROGRAM_ADDRESS =
+  "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
+const TOKEN_PROGRAM_ADDRESS =
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
+
+/**
+ * Addresses appended per ExtendLookupTable instruction. Keeps each extend
+ * transaction comfortably under the packet size limit.
+ */
+export const LOOKUP_TABLE_EXTEND_CHUNK_SIZE = 20;
+
+/**
+ * Maximum number of addresses a lookup table can hold
+ */
+export const LOOKUP_TABLE_MAX_ADDRESSES = 256;
+
+// Address Lookup Table instruction tags (u32 little-endian enum index)
+const CREATE_LOOKUP_TABLE_TAG = 0;
+const EXTEND_LOOKUP_TABLE_TAG = 2;
+
+/**
+ * Find the address of a lookup table created by `authority`
+ * Seeds: [authority, recent_slot.to_le_bytes()]
+ */
+export async function findLookupTablePda(
+  authority: Address,
+  recentSlot: bigint | number
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: ADDRESS_LOOKUP_TABLE_PROGRAM_ADDRESS,
+    seeds: [
+      getAddressEncoder().encode(authority),
+      getU64Encoder().encode(BigInt(recentSlot)),
+    ],
+  });
+}
+
+/**
+ * Build a CreateLookupTable instruction.
+ * Layout: [tag:u32_le = 0][recent_slot:u64_le][bump:u8]
+ *
+ * `recentSlot` must be a recent finalized slot; the returned
+ * `lookupTableAddress` is derived from it.
+ */
+export async function getCreateLookupTableInstruction(input: {
+  authority: Address;
+  payer: Address;
+  recentSlot: bigint | number;
+}): Promise<{ instruction: Instruction; lookupTableAddress: Address }> {
+  const [lookupTableAddress, bump] = await findLookupTablePda(
+    input.authority,
+    input.recentSlot
+  );
+
+  const data = new Uint8Array(13);
+  const view = new DataView(data.buffer);
+  view.setUint32(0, CREATE_LOOKUP_TABLE_TAG, true);
+  view.setBigUint64(4, BigInt(input.recentSlot), true);
+  data[12] = bump;
+
+  return {
+    lookupTableAddress,
+    instruction: {
+      programAddress: ADDRESS_LOOKUP_TABLE_PROGRAM_ADDRESS,
+      accounts: [
+        { address: lookupTableAddress, role: AccountRole.WRITABLE },
+        { address: input.authority, role: AccountRole.READONLY },
+        { address: input.payer, role: AccountRole.WRITABLE_SIGNER },
+        { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+      ],
+      data,
+    },
+  };
+}
+
+/**
+ * Build ExtendLookupTable instructions, one per chunk of addresses.
+ * Layout: [tag:u32_le = 2][len:u64_le][addresses:[u8;32] * len]
+ */
+export function getExtendLookupTableInstructions(input: {
+  lookupTable: Address;
+  authority: Address;
+  payer: Address;
+  addresses: readonly Address[];
+  chunkSize?: number;
+}): Instruction[] {
+  const chunkSize = input.chunkSize ?? LOOKUP_TABLE_EXTEND_CHUNK_SIZE;
+  const addressEncoder = getAddressEncoder();
+  const instructions: Instruction[] = [];
+
+  for (let i = 0; i < input.addresses.length; i += chunkSize) {
+    const chunk = input.addresses.slice(i, i + chunkSize);
+    const data = new Uint8Array(12 + chunk.length * 32);
+    const view = new DataView(data.buffer);
+    view.setUint32(0, EXTEND_LOOKUP_TABLE_TAG, true);
+    view.setBigUint64(4, BigInt(chunk.length), true);
+    chunk.forEach((address, j) => {
+      data.set(addressEncoder.encode(address), 12 + j * 32);
+    });
+
+    instructions.push({
+      programAddress: ADDRESS_LOOKUP_TABLE_PROGRAM_ADDRESS,
+      accounts: [
+        { address: input.lookupTable, role: AccountRole.WRITABLE },
+        { address: input.authority, role: AccountRole.READONLY_SIGNER },
+        { address: input.payer, role: AccountRole.WRITABLE_SIGNER },
+        { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+      ],
+      data,
+    });
+  }
+
+  return instructions;
+}
+
+/**
+ * Collect the addresses worth putting in a lookup table for batch
+ * operations: program-wide accounts (config, fee vault, fee mint, programs)
+ * followed by the lock and escrow PDAs for each of the owner's locks.
+ * `feeMint` defaults to USDC.
+ *
+ * Duplicates are removed and insertion order is preserved.
+ */
+export async function getLocksmithLookupTableAddresses(input: {
+  owner: Address;
+  locks?: readonly { mint: Address; lockId: bigint | number }[];
+  programAddress?: Address;
+  feeMint?: Address;
+}): Promise<Address[]> {
+  const programAddress = input.programAddress ?? LOCKSMITH_PROGRAM_ADDRESS;
+  const feeMint = input.feeMint ?? USDC_MINT;
+  const [config] = await findConfigPda(programAddress);
+  const [feeVault] = await findFeeVaultPda(programAddress, feeMint);
+
+  const addresses: Address[] = [
+    programAddress,
+    config,
+    feeVault,
+    feeMint,
+    TOKEN_PROGRAM_ADDRESS,
+    SYSTEM_PROGRAM_ADDRESS,
+    input.owner,
+  ];
+
+  for (const lock of input.locks ?? []) {
+    const [lockAccount] = await findLockAccountPda(
+      input.owner,
+      lock.mint,
+      lock.lockId,
+      programAddress
+    );
+    const [lockToken] = await findLockTokenPda(lockAccount, programAddress);
+    addresses.push(lock.mint, lockAccount, lockToken);
+  }
+
+  const unique = [...new Set(addresses)];
+  if (unique.length > LOOKUP_TABLE_MAX_ADDRESSES) {
+    throw new Error(
+      `Lookup table holds at most ${LOOKUP_TABLE_MAX_ADDRESSES} addresses, got ${unique.length}`
+    );
+  }
+  return unique;
+}
@@ -0,0 +1,196 @@
+import { describe, it, expect } from "vitest";
+import {
+  createNoopSigner,
+  getBase64Decoder,
+  type Address,
+  type GetMultipleAccountsApi,
+  type Rpc,
+} from "@solana/kit";
+import {
+  fetchMintIndexLockAddresses,
+  fetchMintIndexTotal,
+  getIndexLockInstructionForLock,
+  getUnindexLockInstructionForEntry,
+} from "./mintIndex";
+import {
+  LOCKSMITH_PROGRAM_ADDRESS,
+  getIndexEntryAccountEncoder,
+  getMintIndexAccountEncoder,
+  type IndexEntryAccount,
+} from "./generated";
+import {
+  INDEX_ENTRY_DISCRIMINATOR,
+  MINT_INDEX_DISCRIMINATOR,
+} from "./constants";
+import { findIndexEntryPda, findMintIndexPda } from "./pdas";
+
+const TEST_ADDRESSES = {
+  mint: "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address,
+  payer: "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde" as Address,
+  locks: [
+    "Vote111111111111111111111111111111111111111",
+    "Stake11111111111111111111111111111111111111",
+    "Config1111111111111111111111111111111111111",
+  ] as Address[],
+};
+
+function entryData(seq: number): IndexEntryAccount {
+  return {
+    discriminator: INDEX_ENTRY_DISCRIMINATOR,
+    mint: TEST_ADDRESSES.mint,
+    lock: TEST_ADDRESSES.locks[seq - 1],
+    payer: TEST_ADDRESSES.payer,
+    seq,
+    bump: 255,
+  };
+}
+
+/**
+ * Fake RPC serving a mint index of `total` entries, of which those in
+ * `removed` have been closed.
+ */
+async function fakeRpc(total: number | null, removed: number[] = []) {
+  const accounts = new Map<string, Uint8Array>();
+  const [index] = await findMintIndexPda(TEST_ADDRESSES.mint);
+  if (total !== null) {
+    accounts.set(
+      index,
+      getMintIndexAccountEncoder().encode({
+        discriminator: MINT_INDEX_DISCRIMINATOR,
+        mint: TEST_ADDRESSES.mint,
+        totalLocks: total,
+        activeLocks: total - removed.length,
+        bump: 255,
+      }) as Uint8Array
+    );
+  }
+  for (let seq = 1; seq <= (total ?? 0); seq++) {
+    if (!removed.includes(seq)) {
+      const [entry] = await findIndexEntryPda(TEST_ADDRESSES.mint, seq);
+      accounts.set(
+        entry,
+        getIndexEntryAccountEncoder().encode(entryData(seq)) as Uint8Array
+      );
+    }
+  }
+
+  const calls: Address[][] = [];
+  const rpc = {
+    getMultipleAccounts: (addresses: Address[]) => {
+      calls.push(addresses);
+      return {
+        send: async () => ({
+          context: { slot: 1n },
+          value: addresses.map((address) => {
+            const data = accounts.get(address);
+            return data
+              ? {
+                  data: [getBase64Decoder().decode(data), "base64"],
+                  executable: false,
+                  lamports: 1_000_000n,
+                  owner: LOCKSMITH_PROGRAM_ADDRESS,
+                  rentEpoch: 0n,
+                  space: BigInt(data.length),
+                }
+              : null;
+          }),
+        }),
+      };
+    },
+  } as unknown as Rpc<GetMultipleAccountsApi>;
+  return { rpc, calls };
+}
+
+describe("fetchMintIndexLockAddresses", () => {
+  it("lists live entries in index order, skipping removed ones", async () => {
+    const { rpc } = await fakeRpc(3, [2]);
+
+    const locks = await fetchMintIndexLockAddresses(rpc, TEST_ADDRESSES.mint);
+
+    expect(locks).toEqual([TEST_ADDRESSES.locks[0], TEST_ADDRESSES.locks[2]]);
+  });
+
+  it("fetches entries in pages", async () => {
+    const { rpc, calls } = await fakeRpc(3);
+
+    await fetchMintIndexLockAddresses(rpc, TEST_ADDRESSES.mint, {
+      pageSize: 2,
+    });
+
+    // The index, then entries 1-2 and 3
+    expect(calls.map((c) => c.length)).toEqual([1, 2, 1]);
+  });
+
+  it("returns nothing for a mint without an index", async () => {
+    const { rpc } = await fakeRpc(null);
+
+    expect(await fetchMintIndexTotal(rpc, TEST_ADDRESSES.mint)).toBe(0);
+    expect(
+      await fetchMintIndexLockAddresses(rpc, TEST_ADDRESSES.mint)
+    ).toEqual([]);
+  });
+});
+
+describe("getIndexLockInstructionForLock", () => {
+  it("targets the entry after the index's last one", async () => {
+    const { rpc } = await fakeRpc(3);
+    const payer = createNoopSigner(TEST_ADDRESSES.payer);
+    const lock = TEST_ADDRESSES.locks[0];
+
+    const ix = await getIndexLockInstructionForLock(
+      rpc,
+      payer,
+      lock,
+      TEST_ADDRESSES.mint
+    );
+
+    const [index] = await findMintIndexPda(TEST_ADDRESSES.mint);
+    const [entry] = await findIndexEntryPda(TEST_ADDRESSES.mint, 4);
+    expect(ix.accounts.map((a) => a.address)).toEqual([
+      TEST_ADDRESSES.payer,
+      lock,
+      index,
+      entry,
+      "11111111111111111111111111111111",
+    ]);
+  });
+
+  it("starts a new index at entry 1", async () => {
+    const { rpc } = await fakeRpc(null);
+    const payer = createNoopSigner(TEST_ADDRESSES.payer);
+
+    const ix = await getIndexLockInstructionForLock(
+      rpc,
+      payer,
+      TEST_ADDRESSES.locks[0],
+      TEST_ADDRESSES.mint
+    );
+
+    const [entry] = await findIndexEntryPda(TEST_ADDRESSES.mint, 1);
+    expect(ix.accounts[3].address).toBe(entry);
+  });
+});
+
+describe("getUnindexLockInstructionForEntry", () => {
+  it("refunds the entry's payer", async () => {
+    const [address] = await findIndexEntryPda(TEST_ADDRESSES.mint, 1);
+    const entry = {
+      address,
+      data: entryData(1),
+      executable: false,
+      lamports: 1_000_000n,
+      programAddress: LOCKSMITH_PROGRAM_ADDRESS,
+      space: 109n,
+    };
+
+    const ix = await getUnindexLockInstructionForEntry(entry);
+
+    const [index] = await findMintIndexPda(TEST_ADDRESSES.mint);
+    expect(ix.accounts.map((a) => a.address)).toEqual([
+      TEST_ADDRESSES.locks[0],
+      index,
+      address,
+      TEST_ADDRESSES.payer,
+    ]);
+  });
+});
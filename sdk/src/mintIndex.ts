@@ -0,0 +1,126 @@
+import type {
+  Account,
+  Address,
+  GetMultipleAccountsApi,
+  Rpc,
+  TransactionSigner,
+} from "@solana/kit";
+import {
+  LOCKSMITH_PROGRAM_ADDRESS,
+  fetchAllMaybeIndexEntryAccount,
+  fetchAllMaybeMintIndexAccount,
+  getIndexLockInstruction,
+  getUnindexLockInstruction,
+  type IndexEntryAccount,
+  type IndexLockInstruction,
+  type UnindexLockInstruction,
+} from "./generated";
+import { findIndexEntryPda, findMintIndexPda } from "./pdas";
+import { DEFAULT_QUERY_PAGE_SIZE, type LockQueryOptions } from "./queries";
+
+type IndexRpc = Rpc<GetMultipleAccountsApi>;
+
+/**
+ * Number of locks ever recorded in `mint`'s index (0 if it has none yet).
+ * Their entries are numbered 1 to this count.
+ */
+export async function fetchMintIndexTotal(
+  rpc: IndexRpc,
+  mint: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<number> {
+  const [indexAddress] = await findMintIndexPda(mint, programAddress);
+  const [index] = await fetchAllMaybeMintIndexAccount(rpc, [indexAddress]);
+  return index.exists ? index.data.totalLocks : 0;
+}
+
+/**
+ * Iterate over the live entries of `mint`'s index one page at a time, in
+ * index order. Entry addresses are derived from their sequence numbers, so
+ * no getProgramAccounts scan is needed; removed entries are skipped.
+ */
+export async function* fetchMintIndexEntryPages(
+  rpc: IndexRpc,
+  mint: Address,
+  options: LockQueryOptions = {}
+): AsyncGenerator<Account<IndexEntryAccount>[]> {
+  const pageSize = options.pageSize ?? DEFAULT_QUERY_PAGE_SIZE;
+  const programAddress = options.programAddress ?? LOCKSMITH_PROGRAM_ADDRESS;
+  const total = await fetchMintIndexTotal(rpc, mint, programAddress);
+
+  for (let first = 1; first <= total; first += pageSize) {
+    const last = Math.min(first + pageSize - 1, total);
+    const addresses = await Promise.all(
+      Array.from({ length: last - first + 1 }, async (_, i) => {
+        const seq = first + i;
+        const [entry] = await findIndexEntryPda(mint, seq, programAddress);
+        return entry;
+      })
+    );
+    const page = await fetchAllMaybeIndexEntryAccount(rpc, addresses);
+    yield page.filter((a): a is Account<IndexEntryAccount> => a.exists);
+  }
+}
+
+/**
+ * Addresses of every lock in `mint`'s index that has not been removed from
+ * it. Locks closed since may still be listed until `UnindexLock` runs.
+ */
+export async function fetchMintIndexLockAddresses(
+  rpc: IndexRpc,
+  mint: Address,
+  options: LockQueryOptions = {}
+): Promise<Address[]> {
+  const locks: Address[] = [];
+  for await (const page of fetchMintIndexEntryPages(rpc, mint, options)) {
+    locks.push(...page.map((entry) => entry.data.lock));
+  }
+  return locks;
+}
+
+/**
+ * Build the IndexLock instruction recording `lockAccount` in `mint`'s
+ * index, deriving the entry of the index's next sequence number. Send it
+ * with or right after the lock's creation.
+ */
+export async function getIndexLockInstructionForLock(
+  rpc: IndexRpc,
+  payer: TransactionSigner,
+  lockAccount: Address,
+  mint: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<IndexLockInstruction<Address>> {
+  const total = await fetchMintIndexTotal(rpc, mint, programAddress);
+  const [mintIndex] = await findMintIndexPda(mint, programAddress);
+  const [indexEntry] = await findIndexEntryPda(
+    mint,
+    total + 1,
+    programAddress
+  );
+
+  return getIndexLockInstruction(
+    { payer, lockAccount, mintIndex, indexEntry },
+    { programAddress }
+  );
+}
+
+/**
+ * Build the UnindexLock instruction removing `entry` once its lock is
+ * closed, e.g. right after the lock's `Unlock`.
+ */
+export async function getUnindexLockInstructionForEntry(
+  entry: Account<IndexEntryAccount>,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<UnindexLockInstruction<Address>> {
+  const [mintIndex] = await findMintIndexPda(entry.data.mint, programAddress);
+
+  return getUnindexLockInstruction(
+    {
+      lockAccount: entry.data.lock,
+      mintIndex,
+      indexEntry: entry.address,
+      payer: entry.data.payer,
+    },
+    { programAddress }
+  );
+}
@@ -0,0 +1,85 @@
+import { describe, it, expect } from "vitest";
+import {
+  getAddressEncoder,
+  getBase58Encoder,
+  getBase64Decoder,
+  type Address,
+  type GetAccountInfoApi,
+  type Rpc,
+} from "@solana/kit";
+import {
+  NONCE_ACCOUNT_SIZE,
+  decodeNonceAccount,
+  fetchDurableNonceConfig,
+} from "./nonce";
+
+const AUTHORITY = "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address;
+const NONCE_ACCOUNT = "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde" as Address;
+const NONCE_VALUE = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+
+function nonceAccountData(state = 1) {
+  const data = new Uint8Array(NONCE_ACCOUNT_SIZE);
+  const view = new DataView(data.buffer);
+  view.setUint32(0, 1, true); // version: current
+  view.setUint32(4, state, true);
+  data.set(getAddressEncoder().encode(AUTHORITY), 8);
+  data.set(getBase58Encoder().encode(NONCE_VALUE), 40);
+  view.setBigUint64(72, 5_000n, true);
+  return data;
+}
+
+describe("decodeNonceAccount", () => {
+  it("reads the authority and nonce", () => {
+    expect(decodeNonceAccount(nonceAccountData())).toEqual({
+      nonceAuthorityAddress: AUTHORITY,
+      nonce: NONCE_VALUE,
+    });
+  });
+
+  it("rejects uninitialized or short accounts", () => {
+    expect(() => decodeNonceAccount(nonceAccountData(0))).toThrow(
+      "not initialized"
+    );
+    expect(() => decodeNonceAccount(new Uint8Array(10))).toThrow(
+      "not a nonce account"
+    );
+  });
+});
+
+describe("fetchDurableNonceConfig", () => {
+  it("returns a config usable as a transaction lifetime", async () => {
+    const rpc = {
+      getAccountInfo: () => ({
+        send: async () => ({
+          context: { slot: 1n },
+          value: {
+            data: [getBase64Decoder().decode(nonceAccountData()), "base64"],
+            executable: false,
+            lamports: 1_447_680n,
+            owner: "11111111111111111111111111111111",
+            rentEpoch: 0n,
+            space: 80n,
+          },
+        }),
+      }),
+    } as unknown as Rpc<GetAccountInfoApi>;
+
+    expect(await fetchDurableNonceConfig(rpc, NONCE_ACCOUNT)).toEqual({
+      nonceAccountAddress: NONCE_ACCOUNT,
+      nonceAuthorityAddress: AUTHORITY,
+      nonce: NONCE_VALUE,
+    });
+  });
+
+  it("throws when the account does not exist", async () => {
+    const rpc = {
+      getAccountInfo: () => ({
+        send: async () => ({ context: { slot: 1n }, value: null }),
+      }),
+    } as unknown as Rpc<GetAccountInfoApi>;
+
+    await expect(fetchDurableNonceConfig(rpc, NONCE_ACCOUNT)).rejects.toThrow(
+      "not found"
+    );
+  });
+});
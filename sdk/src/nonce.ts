@@ -0,0 +1,66 @@
+import {
+  fetchEncodedAccount,
+  getAddressDecoder,
+  getBase58Decoder,
+  type Address,
+  type GetAccountInfoApi,
+  type Nonce,
+  type Rpc,
+} from "@solana/kit";
+
+/**
+ * System program nonce account size
+ * Layout: [version:u32][state:u32][authority:32][nonce:32][lamports_per_signature:u64]
+ */
+export const NONCE_ACCOUNT_SIZE = 80;
+
+const NONCE_STATE_INITIALIZED = 1;
+
+/**
+ * Everything a transaction needs to use a durable nonce as its lifetime
+ */
+export type DurableNonceConfig = {
+  nonce: Nonce;
+  nonceAccountAddress: Address;
+  nonceAuthorityAddress: Address;
+};
+
+/**
+ * Decode the authority and current nonce from nonce account data.
+ * Throws if the account is not an initialized nonce account.
+ */
+export function decodeNonceAccount(
+  data: Uint8Array
+): Omit<DurableNonceConfig, "nonceAccountAddress"> {
+  if (data.length < NONCE_ACCOUNT_SIZE) {
+    throw new Error("Account is not a nonce account");
+  }
+  const state = new DataView(data.buffer, data.byteOffset).getUint32(4, true);
+  if (state !== NONCE_STATE_INITIALIZED) {
+    throw new Error("Nonce account is not initialized");
+  }
+  return {
+    nonceAuthorityAddress: getAddressDecoder().decode(data.slice(8, 40)),
+    nonce: getBase58Decoder().decode(data.slice(40, 72)) as Nonce,
+  };
+}
+
+/**
+ * Fetch a nonce account and return the config for
+ * `buildLocksmithTransactionMessage({ durableNonce })`.
+ *
+ * Re-fetch after every transaction that uses the nonce: each use advances it.
+ */
+export async function fetchDurableNonceConfig(
+  rpc: Rpc<GetAccountInfoApi>,
+  nonceAccountAddress: Address
+): Promise<DurableNonceConfig> {
+  const account = await fetchEncodedAccount(rpc, nonceAccountAddress);
+  if (!account.exists) {
+    throw new Error(`Nonce account ${nonceAccountAddress} not found`);
+  }
+  return {
+    nonceAccountAddress,
+    ...decodeNonceAccount(new Uint8Array(account.data)),
+  };
+}
@@ -0,0 +1,70 @@
+import { describe, it, expect } from "vitest";
+import { decryptLockNote, encryptLockNote, getLockNoteArgs } from "./notes";
+import { MAX_NOTE_LEN } from "./constants";
+import type { NoteAccount } from "./generated";
+
+function generateKey() {
+  return crypto.subtle.generateKey({ name: "AES-GCM", length: 256 }, false, [
+    "encrypt",
+    "decrypt",
+  ]);
+}
+
+function asNoteAccount(encrypted: Uint8Array) {
+  return getLockNoteArgs(encrypted) as NoteAccount;
+}
+
+describe("encryptLockNote", () => {
+  it("round-trips through the padded note account", async () => {
+    const key = await generateKey();
+    const invoice = new TextEncoder().encode("INV-2026-0042");
+
+    const encrypted = await encryptLockNote(key, invoice);
+    const decrypted = await decryptLockNote(key, asNoteAccount(encrypted));
+
+    expect(new TextDecoder().decode(decrypted)).toBe("INV-2026-0042");
+  });
+
+  it("does not reveal the plaintext", async () => {
+    const key = await generateKey();
+    const invoice = new TextEncoder().encode("INV-2026-0042");
+
+    const first = await encryptLockNote(key, invoice);
+    const second = await encryptLockNote(key, invoice);
+
+    expect(first).not.toEqual(second);
+  });
+
+  it("fails to decrypt with another key", async () => {
+    const encrypted = await encryptLockNote(
+      await generateKey(),
+      new Uint8Array([1, 2, 3])
+    );
+
+    await expect(
+      decryptLockNote(await generateKey(), asNoteAccount(encrypted))
+    ).rejects.toThrow();
+  });
+
+  it("rejects plaintexts that do not fit in a note", async () => {
+    const key = await generateKey();
+
+    const full = await encryptLockNote(key, new Uint8Array(228));
+    expect(full).toHaveLength(MAX_NOTE_LEN);
+    await expect(encryptLockNote(key, new Uint8Array(229))).rejects.toThrow();
+  });
+});
+
+describe("getLockNoteArgs", () => {
+  it("pads the note to the program's buffer size", () => {
+    const args = getLockNoteArgs(new Uint8Array([9, 9, 9]));
+
+    expect(args.note).toHaveLength(MAX_NOTE_LEN);
+    expect(args.noteLen).toBe(3);
+    expect(Array.from(args.note.slice(0, 4))).toEqual([9, 9, 9, 0]);
+  });
+
+  it("rejects notes longer than the buffer", () => {
+    expect(() => getLockNoteArgs(new Uint8Array(MAX_NOTE_LEN + 1))).toThrow();
+  });
+});
@@ -0,0 +1,61 @@
+import type { ReadonlyUint8Array } from "@solana/kit";
+import { MAX_NOTE_LEN } from "./constants";
+import type { NoteAccount, SetLockNoteInstructionDataArgs } from "./generated";
+
+/** Bytes of the random AES-GCM nonce prefixed to each encrypted note */
+const NOTE_IV_LENGTH = 12;
+
+/**
+ * Encrypt a private note for `SetLockNote` with an AES-GCM key the client
+ * keeps, e.g. one derived from the business's own secrets. The result is
+ * the random nonce followed by the ciphertext and must fit in
+ * `MAX_NOTE_LEN` bytes, leaving 228 bytes of plaintext.
+ */
+export async function encryptLockNote(
+  key: CryptoKey,
+  plaintext: Uint8Array
+): Promise<Uint8Array> {
+  const iv = crypto.getRandomValues(new Uint8Array(NOTE_IV_LENGTH));
+  const ciphertext = new Uint8Array(
+    await crypto.subtle.encrypt({ name: "AES-GCM", iv }, key, plaintext)
+  );
+  if (NOTE_IV_LENGTH + ciphertext.length > MAX_NOTE_LEN) {
+    throw new Error(`Encrypted note exceeds ${MAX_NOTE_LEN} bytes`);
+  }
+  const note = new Uint8Array(NOTE_IV_LENGTH + ciphertext.length);
+  note.set(iv, 0);
+  note.set(ciphertext, NOTE_IV_LENGTH);
+  return note;
+}
+
+/**
+ * Decrypt a note written with `encryptLockNote`. Throws if `key` is not the
+ * key it was encrypted with or the note was tampered with.
+ */
+export async function decryptLockNote(
+  key: CryptoKey,
+  note: NoteAccount
+): Promise<Uint8Array> {
+  const bytes = note.note.slice(0, note.noteLen);
+  const plaintext = await crypto.subtle.decrypt(
+    { name: "AES-GCM", iv: bytes.slice(0, NOTE_IV_LENGTH) },
+    key,
+    bytes.slice(NOTE_IV_LENGTH)
+  );
+  return new Uint8Array(plaintext);
+}
+
+/**
+ * The `note` and `noteLen` arguments of `getSetLockNoteInstruction` for an
+ * encrypted note, zero-padded to `MAX_NOTE_LEN`. An empty note deletes it.
+ */
+export function getLockNoteArgs(
+  encrypted: ReadonlyUint8Array
+): Pick<SetLockNoteInstructionDataArgs, "note" | "noteLen"> {
+  if (encrypted.length > MAX_NOTE_LEN) {
+    throw new Error(`Note exceeds ${MAX_NOTE_LEN} bytes`);
+  }
+  const note = new Uint8Array(MAX_NOTE_LEN);
+  note.set(encrypted, 0);
+  return { note, noteLen: encrypted.length };
+}
@@ -2,9 +2,38 @@ import { describe, it, expect } from "vitest";
 import { address, type Address } from "@solana/kit";
 import {
   findConfigPda,
+  findCredentialPda,
   findFeeVaultPda,
+  findForwardingDestinationPda,
+  findHookPda,
+  findLenderPda,
   findLockAccountPda,
   findLockTokenPda,
+  findRelayDelegatePda,
+  findSessionPda,
+  findNotePda,
+  findLockCertificatePda,
+  findVestingPda,
+  findPenaltyVaultPda,
+  findSolFeeVaultPda,
+  findFeeExemptionPda,
+  findOwnerCounterPda,
+  findFeeCapPda,
+  findTokenFeeVaultPda,
+  findFeeSchedulePda,
+  findReceiptMintPda,
+  findReceiptAuthorityPda,
+  findReceiptMetadataPda,
+  findLockSharesPda,
+  findUnlockApprovalsPda,
+  findVoteWeightSnapshotPda,
+  findMintPolicyPda,
+  findLockProofPda,
+  findAssociatedTokenPda,
+  findMintIndexPda,
+  findIndexEntryPda,
+  findStandingOrderPda,
+  findTemplatePda,
 } from "./pdas";
 import { LOCKSMITH_PROGRAM_ADDRESS } from "./generated";
 
@@ -59,6 +88,21 @@ describe("PDA derivations", () => {
 
       expect(configPda).not.toBe(feeVaultPda);
     });
+
+    it("seeds vaults of later fee mints by mint", async () => {
+      const [usdcVault] = await findFeeVaultPda();
+      const [explicitUsdcVault] = await findFeeVaultPda(
+        LOCKSMITH_PROGRAM_ADDRESS,
+        TEST_ADDRESSES.mint1
+      );
+      const [otherVault] = await findFeeVaultPda(
+        LOCKSMITH_PROGRAM_ADDRESS,
+        TEST_ADDRESSES.mint2
+      );
+
+      expect(explicitUsdcVault).toBe(usdcVault);
+      expect(otherVault).not.toBe(usdcVault);
+    });
   });
 
   describe("findLockAccountPda", () => {
@@ -162,6 +206,354 @@ describe("PDA derivations", () => {
     });
   });
 
+  describe("findTemplatePda", () => {
+    it("derives deterministically and per template id", async () => {
+      const [pda1] = await findTemplatePda(1n);
+      const [pda2] = await findTemplatePda(1);
+      const [pda3] = await findTemplatePda(2n);
+
+      expect(pda1).toBe(pda2);
+      expect(pda1).not.toBe(pda3);
+    });
+  });
+
+  describe("findStandingOrderPda", () => {
+    it("differs from the lock PDA for the same owner, mint, id", async () => {
+      const { owner1, mint1 } = TEST_ADDRESSES;
+      const [order] = await findStandingOrderPda(owner1, mint1, 1n);
+      const [lock] = await findLockAccountPda(owner1, mint1, 1n);
+
+      expect(order).not.toBe(lock);
+    });
+
+    it("derives different PDAs for different order IDs", async () => {
+      const { owner1, mint1 } = TEST_ADDRESSES;
+      const [pda1] = await findStandingOrderPda(owner1, mint1, 1);
+      const [pda2] = await findStandingOrderPda(owner1, mint1, 2);
+
+      expect(pda1).not.toBe(pda2);
+    });
+  });
+
+  describe("findForwardingDestinationPda", () => {
+    it("derives different PDAs for different prefixes", async () => {
+      const program = TEST_ADDRESSES.lock1;
+      const [pda1] = await findForwardingDestinationPda(
+        program,
+        new Uint8Array([1])
+      );
+      const [pda2] = await findForwardingDestinationPda(
+        program,
+        new Uint8Array([1, 0])
+      );
+
+      expect(pda1).not.toBe(pda2);
+    });
+
+    it("derives different PDAs for different programs", async () => {
+      const prefix = new Uint8Array([3]);
+      const [pda1] = await findForwardingDestinationPda(
+        TEST_ADDRESSES.lock1,
+        prefix
+      );
+      const [pda2] = await findForwardingDestinationPda(
+        TEST_ADDRESSES.lock2,
+        prefix
+      );
+
+      expect(pda1).not.toBe(pda2);
+    });
+  });
+
+  describe("findHookPda", () => {
+    it("derives different PDAs for different programs", async () => {
+      const [pda1] = await findHookPda(TEST_ADDRESSES.lock1);
+      const [pda2] = await findHookPda(TEST_ADDRESSES.lock2);
+
+      expect(pda1).not.toBe(pda2);
+    });
+  });
+
+  describe("findLenderPda", () => {
+    it("derives different PDAs for different authorities", async () => {
+      const [pda1] = await findLenderPda(TEST_ADDRESSES.owner1);
+      const [pda2] = await findLenderPda(TEST_ADDRESSES.owner2);
+
+      expect(pda1).not.toBe(pda2);
+    });
+
+    it("differs from the hook PDA of the same address", async () => {
+      const [lender] = await findLenderPda(TEST_ADDRESSES.lock1);
+      const [hook] = await findHookPda(TEST_ADDRESSES.lock1);
+
+      expect(lender).not.toBe(hook);
+    });
+  });
+
+  describe("findRelayDelegatePda", () => {
+    it("derives different PDAs for different owners", async () => {
+      const [pda1] = await findRelayDelegatePda(TEST_ADDRESSES.owner1);
+      const [pda2] = await findRelayDelegatePda(TEST_ADDRESSES.owner2);
+
+      expect(pda1).not.toBe(pda2);
+    });
+
+    it("differs from the lender PDA of the same address", async () => {
+      const [relay] = await findRelayDelegatePda(TEST_ADDRESSES.owner1);
+      const [lender] = await findLenderPda(TEST_ADDRESSES.owner1);
+
+      expect(relay).not.toBe(lender);
+    });
+  });
+
+  describe("findSessionPda", () => {
+    it("derives different PDAs for different session keys", async () => {
+      const { owner1, owner2, lock1 } = TEST_ADDRESSES;
+      const [pda1] = await findSessionPda(owner1, owner2);
+      const [pda2] = await findSessionPda(owner1, lock1);
+
+      expect(pda1).not.toBe(pda2);
+    });
+
+    it("is specific to the owner", async () => {
+      const { owner1, owner2 } = TEST_ADDRESSES;
+      const [pda1] = await findSessionPda(owner1, owner2);
+      const [pda2] = await findSessionPda(owner2, owner1);
+
+      expect(pda1).not.toBe(pda2);
+    });
+  });
+
+  describe("findNotePda", () => {
+    it("differs from the lock token PDA of the same lock", async () => {
+      const [note] = await findNotePda(TEST_ADDRESSES.lock1);
+      const [escrow] = await findLockTokenPda(TEST_ADDRESSES.lock1);
+
+      expect(note).not.toBe(escrow);
+    });
+  });
+
+  describe("findLockCertificatePda", () => {
+    it("derives one certificate per lock", async () => {
+      const [certificate1] = await findLockCertificatePda(TEST_ADDRESSES.lock1);
+      const [certificate2] = await findLockCertificatePda(TEST_ADDRESSES.lock2);
+      const [note] = await findNotePda(TEST_ADDRESSES.lock1);
+
+      expect(certificate1).not.toBe(certificate2);
+      expect(certificate1).not.toBe(note);
+    });
+  });
+
+  describe("findLockSharesPda", () => {
+    it("derives one shares account per lock", async () => {
+      const [shares1] = await findLockSharesPda(TEST_ADDRESSES.lock1);
+      const [shares2] = await findLockSharesPda(TEST_ADDRESSES.lock2);
+      const [certificate] = await findLockCertificatePda(TEST_ADDRESSES.lock1);
+
+      expect(shares1).not.toBe(shares2);
+      expect(shares1).not.toBe(certificate);
+    });
+  });
+
+  describe("findUnlockApprovalsPda", () => {
+    it("derives one approvals account per lock", async () => {
+      const [approvals1] = await findUnlockApprovalsPda(TEST_ADDRESSES.lock1);
+      const [approvals2] = await findUnlockApprovalsPda(TEST_ADDRESSES.lock2);
+      const [shares] = await findLockSharesPda(TEST_ADDRESSES.lock1);
+
+      expect(approvals1).not.toBe(approvals2);
+      expect(approvals1).not.toBe(shares);
+    });
+  });
+
+  describe("findVoteWeightSnapshotPda", () => {
+    it("derives one snapshot per lock", async () => {
+      const [snapshot1] = await findVoteWeightSnapshotPda(TEST_ADDRESSES.lock1);
+      const [snapshot2] = await findVoteWeightSnapshotPda(TEST_ADDRESSES.lock2);
+      const [certificate] = await findLockCertificatePda(TEST_ADDRESSES.lock1);
+
+      expect(snapshot1).not.toBe(snapshot2);
+      expect(snapshot1).not.toBe(certificate);
+    });
+  });
+
+  describe("findMintPolicyPda", () => {
+    it("does not collide with the mint index", async () => {
+      const [policy1] = await findMintPolicyPda(TEST_ADDRESSES.mint1);
+      const [policy2] = await findMintPolicyPda(TEST_ADDRESSES.mint2);
+      const [index] = await findMintIndexPda(TEST_ADDRESSES.mint1);
+
+      expect(policy1).not.toBe(policy2);
+      expect(policy1).not.toBe(index);
+    });
+  });
+
+  describe("findLockProofPda", () => {
+    it("derives one proof per lock", async () => {
+      const [proof1] = await findLockProofPda(TEST_ADDRESSES.lock1);
+      const [proof2] = await findLockProofPda(TEST_ADDRESSES.lock2);
+      const [certificate] = await findLockCertificatePda(TEST_ADDRESSES.lock1);
+
+      expect(proof1).not.toBe(proof2);
+      expect(proof1).not.toBe(certificate);
+    });
+  });
+
+  describe("findVestingPda", () => {
+    it("does not collide with the lock PDA of the same ID", async () => {
+      const { owner1, mint1 } = TEST_ADDRESSES;
+      const [vesting1] = await findVestingPda(owner1, mint1, 1);
+      const [vesting2] = await findVestingPda(owner1, mint1, 2n);
+      const [lock] = await findLockAccountPda(owner1, mint1, 1);
+
+      expect(vesting1).not.toBe(vesting2);
+      expect(vesting1).not.toBe(lock);
+    });
+  });
+
+  describe("findPenaltyVaultPda", () => {
+    it("differs from the fee vault of the same mint", async () => {
+      const { mint1, mint2 } = TEST_ADDRESSES;
+      const [penaltyVault1] = await findPenaltyVaultPda(mint1);
+      const [penaltyVault2] = await findPenaltyVaultPda(mint2);
+      const [feeVault] = await findFeeVaultPda(
+        LOCKSMITH_PROGRAM_ADDRESS,
+        mint1
+      );
+
+      expect(penaltyVault1).not.toBe(penaltyVault2);
+      expect(penaltyVault1).not.toBe(feeVault);
+    });
+  });
+
+  describe("findTokenFeeVaultPda", () => {
+    it("differs from the penalty vault of the same mint", async () => {
+      const { mint1, mint2 } = TEST_ADDRESSES;
+      const [tokenFeeVault1] = await findTokenFeeVaultPda(mint1);
+      const [tokenFeeVault2] = await findTokenFeeVaultPda(mint2);
+      const [penaltyVault] = await findPenaltyVaultPda(mint1);
+
+      expect(tokenFeeVault1).not.toBe(tokenFeeVault2);
+      expect(tokenFeeVault1).not.toBe(penaltyVault);
+    });
+  });
+
+  describe("findFeeCapPda", () => {
+    it("derives one cap per mint", async () => {
+      const { mint1, mint2 } = TEST_ADDRESSES;
+      const [feeCap1] = await findFeeCapPda(mint1);
+      const [feeCap2] = await findFeeCapPda(mint2);
+      const [mintPolicy] = await findMintPolicyPda(mint1);
+
+      expect(feeCap1).not.toBe(feeCap2);
+      expect(feeCap1).not.toBe(mintPolicy);
+    });
+  });
+
+  describe("findSolFeeVaultPda", () => {
+    it("differs from the USDC fee vault", async () => {
+      const [solFeeVault] = await findSolFeeVaultPda();
+      const [feeVault] = await findFeeVaultPda();
+
+      expect(solFeeVault).not.toBe(feeVault);
+    });
+  });
+
+  describe("findOwnerCounterPda", () => {
+    it("derives one counter per owner", async () => {
+      const { owner1, owner2 } = TEST_ADDRESSES;
+      const [counter1] = await findOwnerCounterPda(owner1);
+      const [counter2] = await findOwnerCounterPda(owner2);
+
+      expect(counter1).not.toBe(counter2);
+    });
+  });
+
+  describe("findFeeExemptionPda", () => {
+    it("derives one exemption per wallet", async () => {
+      const { owner1, owner2 } = TEST_ADDRESSES;
+      const [exemption1] = await findFeeExemptionPda(owner1);
+      const [exemption2] = await findFeeExemptionPda(owner2);
+
+      expect(exemption1).not.toBe(exemption2);
+    });
+  });
+
+  describe("findFeeSchedulePda", () => {
+    it("is a singleton distinct from the config", async () => {
+      const [schedule1] = await findFeeSchedulePda();
+      const [schedule2] = await findFeeSchedulePda();
+      const [config] = await findConfigPda();
+
+      expect(schedule1).toBe(schedule2);
+      expect(schedule1).not.toBe(config);
+    });
+  });
+
+  describe("receipt PDAs", () => {
+    it("derives a receipt mint per lock and nonce", async () => {
+      const { lock1, lock2 } = TEST_ADDRESSES;
+      const [receipt1] = await findReceiptMintPda(lock1, 0);
+      const [receipt2] = await findReceiptMintPda(lock2, 0);
+      const [reissued] = await findReceiptMintPda(lock1, 1n);
+      const [authority] = await findReceiptAuthorityPda();
+
+      const pdas = [receipt1, receipt2, reissued, authority];
+      expect(new Set(pdas).size).toBe(pdas.length);
+    });
+
+    it("derives metadata and token accounts per receipt mint", async () => {
+      const [receipt1] = await findReceiptMintPda(TEST_ADDRESSES.lock1, 0);
+      const [receipt2] = await findReceiptMintPda(TEST_ADDRESSES.lock2, 0);
+      const [metadata1] = await findReceiptMetadataPda(receipt1);
+      const [metadata2] = await findReceiptMetadataPda(receipt2);
+      const [ata1] = await findAssociatedTokenPda(
+        TEST_ADDRESSES.owner1,
+        receipt1
+      );
+      const [ata2] = await findAssociatedTokenPda(
+        TEST_ADDRESSES.owner2,
+        receipt1
+      );
+
+      const pdas = [metadata1, metadata2, ata1, ata2];
+      expect(new Set(pdas).size).toBe(pdas.length);
+    });
+  });
+
+  describe("findMintIndexPda", () => {
+    it("derives different PDAs for the index and its entries", async () => {
+      const { mint1, mint2 } = TEST_ADDRESSES;
+      const [index] = await findMintIndexPda(mint1);
+      const [entry1] = await findIndexEntryPda(mint1, 1);
+      const [entry2] = await findIndexEntryPda(mint1, 2);
+      const [otherEntry1] = await findIndexEntryPda(mint2, 1);
+
+      const pdas = [index, entry1, entry2, otherEntry1];
+      expect(new Set(pdas).size).toBe(pdas.length);
+    });
+  });
+
+  describe("findCredentialPda", () => {
+    it("derives different PDAs for different owners", async () => {
+      const issuer = TEST_ADDRESSES.lock1;
+      const [pda1] = await findCredentialPda(TEST_ADDRESSES.owner1, issuer);
+      const [pda2] = await findCredentialPda(TEST_ADDRESSES.owner2, issuer);
+
+      expect(pda1).not.toBe(pda2);
+    });
+
+    it("derives under the issuer program, not Locksmith", async () => {
+      const owner = TEST_ADDRESSES.owner1;
+      const [pda1] = await findCredentialPda(owner, TEST_ADDRESSES.lock1);
+      const [pda2] = await findCredentialPda(owner, TEST_ADDRESSES.lock2);
+      const [pda3] = await findCredentialPda(owner, LOCKSMITH_PROGRAM_ADDRESS);
+
+      expect(pda1).not.toBe(pda2);
+      expect(pda1).not.toBe(pda3);
+    });
+  });
+
   describe("PDA uniqueness across types", () => {
     it("config, fee vault, and lock PDAs are all distinct", async () => {
       const [configPda] = await findConfigPda();
@@ -1,10 +1,16 @@
 import {
   getAddressEncoder,
   getProgramDerivedAddress,
+  getU32Encoder,
   getU64Encoder,
   type Address,
   type ProgramDerivedAddress,
 } from "@solana/kit";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ADDRESS,
+  TOKEN_METADATA_PROGRAM_ADDRESS,
+  USDC_MINT,
+} from "./constants";
 import { LOCKSMITH_PROGRAM_ADDRESS } from "./generated";
 
 // PDA seed constants (matching Rust)
@@ -12,6 +18,35 @@ const CONFIG_SEED = new TextEncoder().encode("config");
 const FEE_VAULT_SEED = new TextEncoder().encode("fee_vault");
 const LOCK_SEED = new TextEncoder().encode("lock");
 const LOCK_TOKEN_SEED = new TextEncoder().encode("lock_token");
+const CREDENTIAL_SEED = new TextEncoder().encode("credential");
+const TEMPLATE_SEED = new TextEncoder().encode("template");
+const STANDING_ORDER_SEED = new TextEncoder().encode("standing_order");
+const FORWARDING_SEED = new TextEncoder().encode("forwarding");
+const HOOK_SEED = new TextEncoder().encode("hook");
+const LENDER_SEED = new TextEncoder().encode("lender");
+const RELAY_SEED = new TextEncoder().encode("relay");
+const SESSION_SEED = new TextEncoder().encode("session");
+const NOTE_SEED = new TextEncoder().encode("note");
+const MINT_INDEX_SEED = new TextEncoder().encode("mint_index");
+const CERTIFICATE_SEED = new TextEncoder().encode("certificate");
+const VESTING_SEED = new TextEncoder().encode("vesting");
+const PENALTY_VAULT_SEED = new TextEncoder().encode("penalty_vault");
+const SOL_FEE_VAULT_SEED = new TextEncoder().encode("sol_fee_vault");
+const FEE_EXEMPTION_SEED = new TextEncoder().encode("fee_exemption");
+const OWNER_COUNTER_SEED = new TextEncoder().encode("owner_counter");
+const FEE_SCHEDULE_SEED = new TextEncoder().encode("fee_schedule");
+const FEE_CAP_SEED = new TextEncoder().encode("fee_cap");
+const TOKEN_FEE_VAULT_SEED = new TextEncoder().encode("token_fee_vault");
+const RECEIPT_SEED = new TextEncoder().encode("receipt");
+const SHARES_SEED = new TextEncoder().encode("shares");
+const APPROVALS_SEED = new TextEncoder().encode("approvals");
+const VOTE_WEIGHT_SEED = new TextEncoder().encode("vote_weight");
+const MINT_POLICY_SEED = new TextEncoder().encode("mint_policy");
+const LOCK_PROOF_SEED = new TextEncoder().encode("lock_proof");
+const METADATA_SEED = new TextEncoder().encode("metadata");
+
+const TOKEN_PROGRAM_ADDRESS =
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
 
 /**
  * Find the Config PDA
@@ -27,15 +62,19 @@ export async function findConfigPda(
 }
 
 /**
- * Find the Fee Vault PDA (USDC token account for collecting fees)
- * Seeds: ["fee_vault"]
+ * Find the Fee Vault PDA (token account collecting fees in `feeMint`)
+ * Seeds: ["fee_vault"] for USDC, ["fee_vault", feeMint] for later fee mints
  */
 export async function findFeeVaultPda(
-  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS,
+  feeMint: Address = USDC_MINT
 ): Promise<ProgramDerivedAddress> {
   return getProgramDerivedAddress({
     programAddress,
-    seeds: [FEE_VAULT_SEED],
+    seeds:
+      feeMint === USDC_MINT
+        ? [FEE_VAULT_SEED]
+        : [FEE_VAULT_SEED, getAddressEncoder().encode(feeMint)],
   });
 }
 
@@ -78,3 +117,472 @@ export async function findLockTokenPda(
     seeds: [LOCK_TOKEN_SEED, addressEncoder.encode(lockAccount)],
   });
 }
+
+/**
+ * Find a Lock Template PDA
+ * Seeds: ["template", template_id.to_le_bytes()]
+ */
+export async function findTemplatePda(
+  templateId: bigint | number,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [TEMPLATE_SEED, getU64Encoder().encode(BigInt(templateId))],
+  });
+}
+
+/**
+ * Find a Standing Order PDA
+ * Seeds: ["standing_order", owner, mint, order_id.to_le_bytes()]
+ */
+export async function findStandingOrderPda(
+  owner: Address,
+  mint: Address,
+  orderId: bigint | number,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  const addressEncoder = getAddressEncoder();
+
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      STANDING_ORDER_SEED,
+      addressEncoder.encode(owner),
+      addressEncoder.encode(mint),
+      getU64Encoder().encode(BigInt(orderId)),
+    ],
+  });
+}
+
+/**
+ * Find a Forwarding Destination PDA
+ * Seeds: ["forwarding", program, instruction_prefix]
+ *
+ * `instructionPrefix` is the deposit instruction's leading data (at most 8
+ * bytes), e.g. an Anchor discriminator or a native program's tag.
+ */
+export async function findForwardingDestinationPda(
+  program: Address,
+  instructionPrefix: Uint8Array,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      FORWARDING_SEED,
+      getAddressEncoder().encode(program),
+      instructionPrefix,
+    ],
+  });
+}
+
+/**
+ * Find a Hook PDA
+ * Seeds: ["hook", program]
+ */
+export async function findHookPda(
+  program: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [HOOK_SEED, getAddressEncoder().encode(program)],
+  });
+}
+
+/**
+ * Find the lender PDA approving an authority to mark locks as collateral
+ * Seeds: ["lender", authority]
+ */
+export async function findLenderPda(
+  authority: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [LENDER_SEED, getAddressEncoder().encode(authority)],
+  });
+}
+
+/**
+ * Find an owner's relay delegate PDA, which the owner approves as delegate
+ * on their token accounts to have locks created from signed messages
+ * Seeds: ["relay", owner]
+ */
+export async function findRelayDelegatePda(
+  owner: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [RELAY_SEED, getAddressEncoder().encode(owner)],
+  });
+}
+
+/**
+ * Find the session PDA authorizing a session key to unlock an owner's locks
+ * Seeds: ["session", owner, session_key]
+ */
+export async function findSessionPda(
+  owner: Address,
+  sessionKey: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      SESSION_SEED,
+      getAddressEncoder().encode(owner),
+      getAddressEncoder().encode(sessionKey),
+    ],
+  });
+}
+
+/**
+ * Find the PDA holding a lock's encrypted note
+ * Seeds: ["note", lock_account]
+ */
+export async function findNotePda(
+  lockAccount: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [NOTE_SEED, getAddressEncoder().encode(lockAccount)],
+  });
+}
+
+/**
+ * Find the on-chain certificate PDA of a lock
+ * Seeds: ["certificate", lock_account]
+ */
+export async function findLockCertificatePda(
+  lockAccount: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [CERTIFICATE_SEED, getAddressEncoder().encode(lockAccount)],
+  });
+}
+
+/**
+ * Find a vesting lock PDA. Its escrow is `findLockTokenPda(vestingAccount)`.
+ * Seeds: ["vesting", owner, mint, vesting_id (u64 LE)]
+ */
+export async function findVestingPda(
+  owner: Address,
+  mint: Address,
+  vestingId: bigint | number,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  const addressEncoder = getAddressEncoder();
+
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      VESTING_SEED,
+      addressEncoder.encode(owner),
+      addressEncoder.encode(mint),
+      getU64Encoder().encode(BigInt(vestingId)),
+    ],
+  });
+}
+
+/**
+ * Find the vault collecting `EmergencyUnlock` penalties in a mint
+ * Seeds: ["penalty_vault", mint]
+ */
+export async function findPenaltyVaultPda(
+  mint: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [PENALTY_VAULT_SEED, getAddressEncoder().encode(mint)],
+  });
+}
+
+/**
+ * Find the vault collecting percentage fees in a mint
+ * Seeds: ["token_fee_vault", mint]
+ */
+export async function findTokenFeeVaultPda(
+  mint: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [TOKEN_FEE_VAULT_SEED, getAddressEncoder().encode(mint)],
+  });
+}
+
+/**
+ * Find the fee cap PDA of a mint, needed by lock creation under a
+ * percentage fee
+ * Seeds: ["fee_cap", mint]
+ */
+export async function findFeeCapPda(
+  mint: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [FEE_CAP_SEED, getAddressEncoder().encode(mint)],
+  });
+}
+
+/**
+ * Find the system account collecting fees paid in lamports
+ * Seeds: ["sol_fee_vault"]
+ */
+export async function findSolFeeVaultPda(
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [SOL_FEE_VAULT_SEED],
+  });
+}
+
+/**
+ * Find the fee exemption PDA of a wallet; locks it pays for are free when
+ * it is among their accounts
+ * Seeds: ["fee_exemption", wallet]
+ */
+export async function findFeeExemptionPda(
+  wallet: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [FEE_EXEMPTION_SEED, getAddressEncoder().encode(wallet)],
+  });
+}
+
+/**
+ * Find the counter PDA of an owner, which lock creation and closing need
+ * among their accounts while the config caps locks per owner
+ * Seeds: ["owner_counter", owner]
+ */
+export async function findOwnerCounterPda(
+  owner: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [OWNER_COUNTER_SEED, getAddressEncoder().encode(owner)],
+  });
+}
+
+/**
+ * Find the fee schedule PDA, needed by lock creation while the fee is
+ * tiered
+ * Seeds: ["fee_schedule"]
+ */
+export async function findFeeSchedulePda(
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [FEE_SCHEDULE_SEED],
+  });
+}
+
+/**
+ * Find the receipt mint PDA of a lock. Receipt mints outlive their locks,
+ * so a lock at a reused address needs a nonce no earlier receipt used,
+ * e.g. its `createdAt`.
+ * Seeds: ["receipt", lock_account, nonce (u64 LE)]
+ */
+export async function findReceiptMintPda(
+  lockAccount: Address,
+  nonce: bigint | number,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      RECEIPT_SEED,
+      getAddressEncoder().encode(lockAccount),
+      getU64Encoder().encode(BigInt(nonce)),
+    ],
+  });
+}
+
+/**
+ * Find the PDA that is every lock receipt's mint, freeze and metadata
+ * update authority. A receipt is genuine if its metadata names it.
+ * Seeds: ["receipt"]
+ */
+export async function findReceiptAuthorityPda(
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [RECEIPT_SEED],
+  });
+}
+
+/**
+ * Find the PDA recording the beneficiaries a lock was split among
+ * Seeds: ["shares", lock_account]
+ */
+export async function findLockSharesPda(
+  lockAccount: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [SHARES_SEED, getAddressEncoder().encode(lockAccount)],
+  });
+}
+
+/**
+ * Find the PDA recording who may approve unlocking a lock, and who has
+ * Seeds: ["approvals", lock_account]
+ */
+export async function findUnlockApprovalsPda(
+  lockAccount: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [APPROVALS_SEED, getAddressEncoder().encode(lockAccount)],
+  });
+}
+
+/**
+ * Find the vote weight snapshot PDA of a lock
+ * Seeds: ["vote_weight", lock_account]
+ */
+export async function findVoteWeightSnapshotPda(
+  lockAccount: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [VOTE_WEIGHT_SEED, getAddressEncoder().encode(lockAccount)],
+  });
+}
+
+/**
+ * Find the PDA recording whether a mint can be locked. Under a mint policy
+ * mode, lock creation needs it among its accounts, created or not.
+ * Seeds: ["mint_policy", mint]
+ */
+export async function findMintPolicyPda(
+  mint: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [MINT_POLICY_SEED, getAddressEncoder().encode(mint)],
+  });
+}
+
+/**
+ * Find the lock proof PDA of a lock, attesting its share of the mint's
+ * supply
+ * Seeds: ["lock_proof", lock_account]
+ */
+export async function findLockProofPda(
+  lockAccount: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [LOCK_PROOF_SEED, getAddressEncoder().encode(lockAccount)],
+  });
+}
+
+/**
+ * Find the Token Metadata PDA of a receipt mint
+ * Seeds: ["metadata", token_metadata_program, receipt_mint] under Token
+ * Metadata
+ */
+export async function findReceiptMetadataPda(
+  receiptMint: Address
+): Promise<ProgramDerivedAddress> {
+  const addressEncoder = getAddressEncoder();
+
+  return getProgramDerivedAddress({
+    programAddress: TOKEN_METADATA_PROGRAM_ADDRESS,
+    seeds: [
+      METADATA_SEED,
+      addressEncoder.encode(TOKEN_METADATA_PROGRAM_ADDRESS),
+      addressEncoder.encode(receiptMint),
+    ],
+  });
+}
+
+/**
+ * Find a wallet's associated token account for a mint, e.g. the account
+ * `MintLockReceipt` mints a receipt to
+ * Seeds: [wallet, token_program, mint] under the Associated Token program
+ */
+export async function findAssociatedTokenPda(
+  wallet: Address,
+  mint: Address
+): Promise<ProgramDerivedAddress> {
+  const addressEncoder = getAddressEncoder();
+
+  return getProgramDerivedAddress({
+    programAddress: ASSOCIATED_TOKEN_PROGRAM_ADDRESS,
+    seeds: [
+      addressEncoder.encode(wallet),
+      addressEncoder.encode(TOKEN_PROGRAM_ADDRESS),
+      addressEncoder.encode(mint),
+    ],
+  });
+}
+
+/**
+ * Find the index PDA counting a mint's indexed locks
+ * Seeds: ["mint_index", mint]
+ */
+export async function findMintIndexPda(
+  mint: Address,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [MINT_INDEX_SEED, getAddressEncoder().encode(mint)],
+  });
+}
+
+/**
+ * Find the PDA of entry `seq` (starting at 1) of a mint's lock index
+ * Seeds: ["mint_index", mint, seq (u32 LE bytes)]
+ */
+export async function findIndexEntryPda(
+  mint: Address,
+  seq: number,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      MINT_INDEX_SEED,
+      getAddressEncoder().encode(mint),
+      getU32Encoder().encode(seq),
+    ],
+  });
+}
+
+/**
+ * Find an owner's credential PDA under a credential issuer program
+ * Seeds: ["credential", owner]
+ */
+export async function findCredentialPda(
+  owner: Address,
+  issuerProgram: Address
+): Promise<ProgramDerivedAddress> {
+  const addressEncoder = getAddressEncoder();
+
+  return getProgramDerivedAddress({
+    programAddress: issuerProgram,
+    seeds: [CREDENTIAL_SEED, addressEncoder.encode(owner)],
+  });
+}
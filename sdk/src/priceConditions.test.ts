@@ -0,0 +1,64 @@
+import { describe, it, expect } from "vitest";
+import { AccountRole, address, type Instruction } from "@solana/kit";
+import {
+  getPriceConditionOptions,
+  hasPriceCondition,
+  withPriceUpdate,
+} from "./priceConditions";
+import type { LockAccount } from "./generated";
+
+// SOL/USD
+const FEED_ID =
+  "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
+
+describe("getPriceConditionOptions", () => {
+  it("scales the price by its decimals", () => {
+    const options = getPriceConditionOptions(FEED_ID, "1.25");
+
+    expect(options.priceThreshold).toBe(125n);
+    expect(options.priceExponent).toBe(-2);
+    expect(options.priceFeedId[0]).toBe(0xef);
+    expect(options.priceFeedId[31]).toBe(0x6d);
+    expect(getPriceConditionOptions(FEED_ID.slice(2), "40")).toMatchObject({
+      priceThreshold: 40n,
+      priceExponent: 0,
+    });
+  });
+
+  it("rejects invalid feeds and prices", () => {
+    expect(() => getPriceConditionOptions("0x1234", "1")).toThrow();
+    expect(() => getPriceConditionOptions(FEED_ID, "0")).toThrow();
+    expect(() => getPriceConditionOptions(FEED_ID, "-1")).toThrow();
+    expect(() => getPriceConditionOptions(FEED_ID, "1e3")).toThrow();
+    expect(() =>
+      getPriceConditionOptions(FEED_ID, "0.0000000000000000001")
+    ).toThrow();
+  });
+});
+
+describe("hasPriceCondition", () => {
+  it("checks for a feed ID", () => {
+    const lock = { priceFeedId: new Uint8Array(32) } as LockAccount;
+
+    expect(hasPriceCondition(lock)).toBe(false);
+    expect(
+      hasPriceCondition({ ...lock, priceFeedId: new Uint8Array(32).fill(1) })
+    ).toBe(true);
+  });
+});
+
+describe("withPriceUpdate", () => {
+  it("appends the price update last", () => {
+    const deposit = address("11111111111111111111111111111111");
+    const priceUpdate = address("SysvarC1ock11111111111111111111111111111111");
+    const instruction: Instruction = {
+      programAddress: deposit,
+      accounts: [{ address: deposit, role: AccountRole.WRITABLE }],
+    };
+
+    expect(withPriceUpdate(instruction, priceUpdate).accounts).toEqual([
+      { address: deposit, role: AccountRole.WRITABLE },
+      { address: priceUpdate, role: AccountRole.READONLY },
+    ]);
+  });
+});
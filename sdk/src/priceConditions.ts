@@ -0,0 +1,63 @@
+import { AccountRole, type Address, type Instruction } from "@solana/kit";
+import { MAX_PRICE_EXPONENT } from "./constants";
+import type { LockAccount, LockOptionsArgs } from "./generated";
+
+const I64_MAX = (1n << 63n) - 1n;
+
+/**
+ * The `priceFeedId`, `priceThreshold` and `priceExponent` lock options
+ * holding a lock back until the price of Pyth feed `feedId` (its 32-byte hex
+ * ID, with or without `0x`) is at least `minPrice`, a decimal string such as
+ * "1.25". `Unlock` and `CrankUnlock` then need a recent price update for the
+ * feed, which `withPriceUpdate` appends.
+ */
+export function getPriceConditionOptions(
+  feedId: string,
+  minPrice: string
+): Pick<LockOptionsArgs, "priceFeedId" | "priceThreshold" | "priceExponent"> {
+  const hex = feedId.replace(/^0x/, "");
+  if (!/^[0-9a-fA-F]{64}$/.test(hex)) {
+    throw new Error("Feed ID must be 32 bytes of hex");
+  }
+  const match = /^(\d+)(?:\.(\d+))?$/.exec(minPrice);
+  if (!match) {
+    throw new Error(`Invalid price: ${minPrice}`);
+  }
+  const decimals = match[2] ?? "";
+  if (decimals.length > MAX_PRICE_EXPONENT) {
+    throw new Error(`Price has more than ${MAX_PRICE_EXPONENT} decimals`);
+  }
+  const priceThreshold = BigInt(match[1] + decimals);
+  if (priceThreshold === 0n || priceThreshold > I64_MAX) {
+    throw new Error("Price must be positive and fit in an i64");
+  }
+  const priceFeedId = new Uint8Array(32);
+  for (let i = 0; i < 32; i++) {
+    priceFeedId[i] = parseInt(hex.slice(2 * i, 2 * i + 2), 16);
+  }
+  // Avoid -0 for whole prices
+  const priceExponent = decimals ? -decimals.length : 0;
+  return { priceFeedId, priceThreshold, priceExponent };
+}
+
+/** Whether the lock also waits for a price condition to unlock */
+export function hasPriceCondition(lock: LockAccount): boolean {
+  return lock.priceFeedId.some((byte) => byte !== 0);
+}
+
+/**
+ * Appends the Pyth price update a price-conditional lock's unlock takes as
+ * its last account, after any forwarding deposit accounts
+ */
+export function withPriceUpdate(
+  instruction: Instruction,
+  priceUpdate: Address
+): Instruction {
+  return {
+    ...instruction,
+    accounts: [
+      ...(instruction.accounts ?? []),
+      { address: priceUpdate, role: AccountRole.READONLY },
+    ],
+  };
+}
@@ -0,0 +1,271 @@
+import { describe, it, expect } from "vitest";
+import {
+  getBase58Decoder,
+  getBase64Decoder,
+  type Address,
+  type GetMultipleAccountsApi,
+  type GetProgramAccountsApi,
+  type Rpc,
+} from "@solana/kit";
+import {
+  LOCK_MINT_OFFSET,
+  LOCK_OWNER_OFFSET,
+  fetchLockAccountPages,
+  fetchLockAccounts,
+  fetchAbandonedLocks,
+  fetchCrankableLocks,
+  fetchLocksExpiringBetween,
+  findLockAccountAddresses,
+  getLockAccountFilters,
+} from "./queries";
+import {
+  LOCKSMITH_PROGRAM_ADDRESS,
+  getLockAccountEncoder,
+  getLockAccountSize,
+} from "./generated";
+import {
+  ABANDONMENT_PERIOD_SECONDS,
+  LEGACY_LOCK_ACCOUNT_SIZE,
+  LOCK_DISCRIMINATOR,
+} from "./constants";
+
+const TEST_ADDRESSES = {
+  owner: "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address,
+  mint: "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address,
+  locks: [
+    "Vote111111111111111111111111111111111111111",
+    "Stake11111111111111111111111111111111111111",
+    "Config1111111111111111111111111111111111111",
+  ] as Address[],
+};
+
+function encodeLock(
+  unlockTimestamp: bigint,
+  size = getLockAccountSize(),
+  recoveryAddress = "11111111111111111111111111111111" as Address
+) {
+  const bytes = getLockAccountEncoder().encode({
+    discriminator: LOCK_DISCRIMINATOR,
+    owner: TEST_ADDRESSES.owner,
+    mint: TEST_ADDRESSES.mint,
+    amount: 1_000n,
+    unlockTimestamp,
+    createdAt: 0n,
+    lockId: 1n,
+    bump: 255,
+    frozen: false,
+    credentialIssuer: "11111111111111111111111111111111" as Address,
+    nonTransferable: false,
+    forwardDestination: "11111111111111111111111111111111" as Address,
+    hook: "11111111111111111111111111111111" as Address,
+    recoveryAddress,
+    donationAddress: "11111111111111111111111111111111" as Address,
+    donationBps: 0,
+    collateralHolder: "11111111111111111111111111111111" as Address,
+    layoutVersion: 1,
+    unlockSlot: 0n,
+    unlockEpoch: 0n,
+    claimKey: new Uint8Array(32),
+    claimExpiresAt: 0n,
+    indexSeq: 0,
+    earlyUnlockAllowed: false,
+    permanent: false,
+    isNft: false,
+    receiptBound: false,
+    label: new Uint8Array(32),
+    metadataUri: new Uint8Array(128),
+    fallbackOwner: "11111111111111111111111111111111" as Address,
+    fallbackAfter: 0n,
+    unlockDestination: "11111111111111111111111111111111" as Address,
+    shared: false,
+    approvalsRequired: false,
+    priceFeedId: new Uint8Array(32),
+    priceThreshold: 0n,
+    priceExponent: 0,
+    arbiter: "11111111111111111111111111111111" as Address,
+    milestoneApproved: false,
+    counterparty: "11111111111111111111111111111111" as Address,
+    ownerCounted: false,
+    wrappedLamports: false,
+    receiptMint: "11111111111111111111111111111111" as Address,
+    feeMode: 0,
+    reserved: new Uint8Array(29),
+  });
+  return getBase64Decoder().decode(bytes.slice(0, size));
+}
+
+/**
+ * Fake RPC backed by a map of address -> unlock timestamp. Addresses
+ * missing from `live` behave as if closed after discovery; addresses in
+ * `legacy` are served at the pre-`frozen` lock size, and addresses in
+ * `recoverable` name the owner as recovery address.
+ */
+function fakeRpc(
+  locks: Record<string, bigint>,
+  live: Set<string> = new Set(Object.keys(locks)),
+  legacy: Set<string> = new Set(),
+  recoverable: Set<string> = new Set()
+) {
+  const calls = { getProgramAccounts: [] as unknown[], getMultiple: 0 };
+  const rpc = {
+    getProgramAccounts: (program: Address, config: unknown) => {
+      calls.getProgramAccounts.push([program, config]);
+      return {
+        send: async () =>
+          Object.keys(locks).map((pubkey) => ({
+            pubkey,
+            account: { data: ["", "base64"] },
+          })),
+      };
+    },
+    getMultipleAccounts: (addresses: Address[]) => {
+      calls.getMultiple += 1;
+      return {
+        send: async () => ({
+          context: { slot: 1n },
+          value: addresses.map((address) =>
+            live.has(address)
+              ? {
+                  data: [
+                    encodeLock(
+                      locks[address],
+                      legacy.has(address)
+                        ? LEGACY_LOCK_ACCOUNT_SIZE
+                        : getLockAccountSize(),
+                      recoverable.has(address)
+                        ? TEST_ADDRESSES.owner
+                        : undefined
+                    ),
+                    "base64",
+                  ],
+                  executable: false,
+                  lamports: 1_000_000n,
+                  owner: LOCKSMITH_PROGRAM_ADDRESS,
+                  rentEpoch: 0n,
+                  space: BigInt(getLockAccountSize()),
+                }
+              : null
+          ),
+        }),
+      };
+    },
+  } as unknown as Rpc<GetProgramAccountsApi & GetMultipleAccountsApi>;
+  return { rpc, calls };
+}
+
+describe("getLockAccountFilters", () => {
+  it("filters on the discriminator only, matching every lock layout", () => {
+    const filters = getLockAccountFilters();
+    expect(filters).toHaveLength(1);
+    expect(filters[0]).toEqual({
+      memcmp: {
+        offset: 0n,
+        bytes: getBase58Decoder().decode(LOCK_DISCRIMINATOR),
+        encoding: "base58",
+      },
+    });
+  });
+
+  it("adds owner and mint memcmp filters at their offsets", () => {
+    const filters = getLockAccountFilters({
+      owner: TEST_ADDRESSES.owner,
+      mint: TEST_ADDRESSES.mint,
+    });
+    expect(filters.slice(1)).toEqual([
+      {
+        memcmp: {
+          offset: BigInt(LOCK_OWNER_OFFSET),
+          bytes: TEST_ADDRESSES.owner,
+          encoding: "base58",
+        },
+      },
+      {
+        memcmp: {
+          offset: BigInt(LOCK_MINT_OFFSET),
+          bytes: TEST_ADDRESSES.mint,
+          encoding: "base58",
+        },
+      },
+    ]);
+  });
+
+  it("uses offsets matching the Rust layout", () => {
+    // [0-7] discriminator, [8-39] owner, [40-71] mint
+    expect(LOCK_OWNER_OFFSET).toBe(8);
+    expect(LOCK_MINT_OFFSET).toBe(40);
+  });
+});
+
+describe("Lock queries", () => {
+  const [a, b, c] = TEST_ADDRESSES.locks;
+
+  it("requests addresses only and sorts them", async () => {
+    const { rpc, calls } = fakeRpc({ [a]: 1n, [b]: 2n, [c]: 3n });
+    const addresses = await findLockAccountAddresses(rpc, {
+      owner: TEST_ADDRESSES.owner,
+    });
+
+    expect(addresses).toEqual([...TEST_ADDRESSES.locks].sort());
+    expect(calls.getProgramAccounts[0]).toMatchObject([
+      LOCKSMITH_PROGRAM_ADDRESS,
+      { dataSlice: { offset: 0, length: 0 } },
+    ]);
+  });
+
+  it("fetches in pages of the requested size", async () => {
+    const { rpc, calls } = fakeRpc({ [a]: 1n, [b]: 2n, [c]: 3n });
+    const pages = [];
+    for await (const page of fetchLockAccountPages(rpc, {}, { pageSize: 2 })) {
+      pages.push(page);
+    }
+
+    expect(pages.map((p) => p.length)).toEqual([2, 1]);
+    expect(calls.getMultiple).toBe(2);
+  });
+
+  it("skips accounts closed after discovery", async () => {
+    const { rpc } = fakeRpc({ [a]: 1n, [b]: 2n }, new Set([a]));
+    const locks = await fetchLockAccounts(rpc);
+
+    expect(locks).toHaveLength(1);
+    expect(locks[0].address).toBe(a);
+    expect(locks[0].data.owner).toBe(TEST_ADDRESSES.owner);
+  });
+
+  it("decodes locks created before the frozen flag", async () => {
+    const { rpc } = fakeRpc({ [a]: 1n, [b]: 2n }, undefined, new Set([a]));
+    const locks = await fetchLockAccounts(rpc);
+
+    const legacy = locks.find((l) => l.address === a);
+    expect(locks).toHaveLength(2);
+    expect(legacy?.data.unlockTimestamp).toBe(1n);
+    expect(legacy?.data.frozen).toBe(false);
+  });
+
+  it("returns locks expiring within the range, soonest first", async () => {
+    const { rpc } = fakeRpc({ [a]: 300n, [b]: 100n, [c]: 500n });
+    const locks = await fetchLocksExpiringBetween(rpc, 100n, 300n);
+
+    expect(locks.map((l) => l.data.unlockTimestamp)).toEqual([100n, 300n]);
+  });
+
+  it("returns abandoned locks that have a recovery address", async () => {
+    const { rpc } = fakeRpc(
+      { [a]: 100n, [b]: 100n, [c]: 200n },
+      undefined,
+      undefined,
+      new Set([a, c])
+    );
+    const now = 150n + ABANDONMENT_PERIOD_SECONDS;
+    const locks = await fetchAbandonedLocks(rpc, now);
+
+    expect(locks.map((l) => l.address)).toEqual([a]);
+  });
+
+  it("returns matured locks for crank operators", async () => {
+    const { rpc } = fakeRpc({ [a]: 100n, [b]: 150n, [c]: 200n });
+    const locks = await fetchCrankableLocks(rpc, 150n);
+
+    expect(locks.map((l) => l.address)).toEqual([a, b]);
+  });
+});
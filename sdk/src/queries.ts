@@ -0,0 +1,210 @@
+import {
+  getBase58Decoder,
+  type Account,
+  type Address,
+  type Base58EncodedBytes,
+  type GetMultipleAccountsApi,
+  type GetProgramAccountsApi,
+  type GetProgramAccountsMemcmpFilter,
+  type Rpc,
+} from "@solana/kit";
+import { LOCKSMITH_PROGRAM_ADDRESS, type LockAccount } from "./generated";
+import { fetchAllMaybeLockAccountCompat } from "./accounts";
+import { ABANDONMENT_PERIOD_SECONDS, LOCK_DISCRIMINATOR } from "./constants";
+
+/**
+ * Byte offsets of filterable LockAccount fields (matching Rust)
+ */
+export const LOCK_OWNER_OFFSET = 8;
+export const LOCK_MINT_OFFSET = 40;
+
+/**
+ * Accounts fetched per getMultipleAccounts call (RPC maximum is 100)
+ */
+export const DEFAULT_QUERY_PAGE_SIZE = 100;
+
+export type LockFilter = {
+  owner?: Address;
+  mint?: Address;
+};
+
+export type LockQueryOptions = {
+  pageSize?: number;
+  programAddress?: Address;
+};
+
+type LockRpc = Rpc<GetProgramAccountsApi & GetMultipleAccountsApi>;
+
+function memcmp(offset: number, bytes: string): GetProgramAccountsMemcmpFilter {
+  return {
+    memcmp: {
+      offset: BigInt(offset),
+      bytes: bytes as Base58EncodedBytes,
+      encoding: "base58",
+    },
+  };
+}
+
+/**
+ * Build getProgramAccounts filters selecting LockAccounts, optionally
+ * narrowed by owner and/or mint.
+ *
+ * Matches on the discriminator only: locks created at older layouts are
+ * shorter, so a dataSize filter would miss them.
+ */
+export function getLockAccountFilters(
+  filter: LockFilter = {}
+): GetProgramAccountsMemcmpFilter[] {
+  const filters = [memcmp(0, getBase58Decoder().decode(LOCK_DISCRIMINATOR))];
+  if (filter.owner) {
+    filters.push(memcmp(LOCK_OWNER_OFFSET, filter.owner));
+  }
+  if (filter.mint) {
+    filters.push(memcmp(LOCK_MINT_OFFSET, filter.mint));
+  }
+  return filters;
+}
+
+/**
+ * Find the addresses of all LockAccounts matching `filter`.
+ *
+ * Only addresses are requested (zero-length data slice), which keeps the
+ * getProgramAccounts response small; sorted for stable pagination.
+ */
+export async function findLockAccountAddresses(
+  rpc: Rpc<GetProgramAccountsApi>,
+  filter: LockFilter = {},
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<Address[]> {
+  const accounts = await rpc
+    .getProgramAccounts(programAddress, {
+      encoding: "base64",
+      dataSlice: { offset: 0, length: 0 },
+      filters: getLockAccountFilters(filter),
+    })
+    .send();
+  return accounts.map((a) => a.pubkey).sort();
+}
+
+/**
+ * Iterate over matching LockAccounts one page at a time.
+ *
+ * Accounts closed between discovery and fetch are skipped.
+ *
+ * @example
+ * ```typescript
+ * for await (const page of fetchLockAccountPages(rpc, { owner })) {
+ *   for (const lock of page) console.log(lock.address, lock.data.amount);
+ * }
+ * ```
+ */
+export async function* fetchLockAccountPages(
+  rpc: LockRpc,
+  filter: LockFilter = {},
+  options: LockQueryOptions = {}
+): AsyncGenerator<Account<LockAccount>[]> {
+  const pageSize = options.pageSize ?? DEFAULT_QUERY_PAGE_SIZE;
+  const addresses = await findLockAccountAddresses(
+    rpc,
+    filter,
+    options.programAddress
+  );
+
+  for (let i = 0; i < addresses.length; i += pageSize) {
+    const page = await fetchAllMaybeLockAccountCompat(
+      rpc,
+      addresses.slice(i, i + pageSize)
+    );
+    yield page.filter((a): a is Account<LockAccount> => a.exists);
+  }
+}
+
+/**
+ * Fetch every LockAccount matching `filter`.
+ */
+export async function fetchLockAccounts(
+  rpc: LockRpc,
+  filter: LockFilter = {},
+  options: LockQueryOptions = {}
+): Promise<Account<LockAccount>[]> {
+  const locks: Account<LockAccount>[] = [];
+  for await (const page of fetchLockAccountPages(rpc, filter, options)) {
+    locks.push(...page);
+  }
+  return locks;
+}
+
+/**
+ * Fetch LockAccounts whose unlock timestamp falls within [from, to],
+ * sorted by unlock timestamp. The range is applied client-side since
+ * getProgramAccounts cannot filter on numeric ranges.
+ */
+export async function fetchLocksExpiringBetween(
+  rpc: LockRpc,
+  from: bigint,
+  to: bigint,
+  filter: LockFilter = {},
+  options: LockQueryOptions = {}
+): Promise<Account<LockAccount>[]> {
+  const locks = await fetchLockAccounts(rpc, filter, options);
+  return locks
+    .filter(
+      (lock) =>
+        lock.data.unlockTimestamp >= from && lock.data.unlockTimestamp <= to
+    )
+    .sort((a, b) =>
+      a.data.unlockTimestamp < b.data.unlockTimestamp
+        ? -1
+        : a.data.unlockTimestamp > b.data.unlockTimestamp
+        ? 1
+        : 0
+    );
+}
+
+/**
+ * Fetch LockAccounts that `SweepAbandonedLock` can sweep at `now` (unix
+ * seconds): a recovery address is set, the lock is not frozen and it has
+ * gone unclaimed for ABANDONMENT_PERIOD_SECONDS since unlock. Intended for
+ * crank operators.
+ */
+export async function fetchAbandonedLocks(
+  rpc: LockRpc,
+  now: bigint,
+  filter: LockFilter = {},
+  options: LockQueryOptions = {}
+): Promise<Account<LockAccount>[]> {
+  const locks = await fetchLockAccounts(rpc, filter, options);
+  return locks.filter(
+    (lock) =>
+      lock.data.recoveryAddress !== "11111111111111111111111111111111" &&
+      !lock.data.frozen &&
+      lock.data.unlockTimestamp + ABANDONMENT_PERIOD_SECONDS <= now
+  );
+}
+
+/**
+ * Fetch LockAccounts that `CrankUnlock` can unlock at `now` (unix seconds)
+ * for the tip: timestamp-based, matured, not permanent, frozen,
+ * collateralized or forwarded, and without a claim code still in force.
+ * Intended for crank operators.
+ */
+export async function fetchCrankableLocks(
+  rpc: LockRpc,
+  now: bigint,
+  filter: LockFilter = {},
+  options: LockQueryOptions = {}
+): Promise<Account<LockAccount>[]> {
+  const locks = await fetchLockAccounts(rpc, filter, options);
+  const none = "11111111111111111111111111111111";
+  return locks.filter(
+    ({ data }) =>
+      data.unlockSlot === 0n &&
+      data.unlockEpoch === 0n &&
+      data.unlockTimestamp <= now &&
+      !data.permanent &&
+      !data.frozen &&
+      data.collateralHolder === none &&
+      data.forwardDestination === none &&
+      (data.claimKey.every((b) => b === 0) || data.claimExpiresAt <= now)
+  );
+}
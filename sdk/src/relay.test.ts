@@ -0,0 +1,83 @@
+import { describe, it, expect } from "vitest";
+import {
+  generateKeyPairSigner,
+  getAddressEncoder,
+  getPublicKeyFromAddress,
+  verifySignature,
+  type Address,
+  type SignatureBytes,
+} from "@solana/kit";
+import {
+  ED25519_PROGRAM_ADDRESS,
+  getEd25519VerifyInstruction,
+  getRelayedLockMessage,
+  signRelayedLock,
+} from "./relay";
+import { LOCKSMITH_PROGRAM_ADDRESS } from "./generated";
+
+const TERMS = {
+  owner: "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address,
+  mint: "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address,
+  amount: 1_000n,
+  unlockTimestamp: 2_000n,
+  lockId: 7,
+  validUntil: 1_500n,
+};
+
+describe("getRelayedLockMessage", () => {
+  it("matches the program's relayed_lock_message layout", () => {
+    const message = getRelayedLockMessage(TERMS);
+    const view = new DataView(message.buffer);
+    const encoder = getAddressEncoder();
+
+    expect(message.length).toBe(153);
+    expect(new TextDecoder().decode(message.slice(0, 25))).toBe(
+      "locksmith-relayed-lock-v1"
+    );
+    expect(message.slice(25, 57)).toEqual(
+      encoder.encode(LOCKSMITH_PROGRAM_ADDRESS)
+    );
+    expect(message.slice(57, 89)).toEqual(encoder.encode(TERMS.owner));
+    expect(message.slice(89, 121)).toEqual(encoder.encode(TERMS.mint));
+    expect(view.getBigUint64(121, true)).toBe(1_000n);
+    expect(view.getBigInt64(129, true)).toBe(2_000n);
+    expect(view.getBigUint64(137, true)).toBe(7n);
+    expect(view.getBigInt64(145, true)).toBe(1_500n);
+  });
+});
+
+describe("signRelayedLock", () => {
+  it("signs the message for the signer as owner", async () => {
+    const owner = await generateKeyPairSigner();
+    const signature = await signRelayedLock(owner, TERMS);
+
+    const message = getRelayedLockMessage({ ...TERMS, owner: owner.address });
+    const publicKey = await getPublicKeyFromAddress(owner.address);
+    expect(await verifySignature(publicKey, signature, message)).toBe(true);
+  });
+});
+
+describe("getEd25519VerifyInstruction", () => {
+  it("keeps the key, signature and message in its own data", () => {
+    const signature = new Uint8Array(64).fill(9) as SignatureBytes;
+    const message = getRelayedLockMessage(TERMS);
+    const ix = getEd25519VerifyInstruction(TERMS.owner, signature, message);
+    const data = ix.data!;
+    const view = new DataView(data.buffer);
+
+    expect(ix.programAddress).toBe(ED25519_PROGRAM_ADDRESS);
+    expect(ix.accounts).toBeUndefined();
+    expect(data[0]).toBe(1);
+    // Signature, public key and message offsets, each in this instruction
+    expect(view.getUint16(2, true)).toBe(48);
+    expect(view.getUint16(6, true)).toBe(16);
+    expect(view.getUint16(10, true)).toBe(112);
+    expect(view.getUint16(12, true)).toBe(message.length);
+    for (const index of [4, 8, 14]) {
+      expect(view.getUint16(index, true)).toBe(0xffff);
+    }
+    expect(data.slice(16, 48)).toEqual(getAddressEncoder().encode(TERMS.owner));
+    expect(data.slice(48, 112)).toEqual(signature);
+    expect(data.slice(112)).toEqual(message);
+  });
+});
@@ -0,0 +1,115 @@
+import {
+  getAddressEncoder,
+  getI64Encoder,
+  getU64Encoder,
+  signBytes,
+  type Address,
+  type Instruction,
+  type KeyPairSigner,
+  type SignatureBytes,
+} from "@solana/kit";
+import { LOCKSMITH_PROGRAM_ADDRESS } from "./generated";
+
+/**
+ * Ed25519 signature verification program address
+ */
+export const ED25519_PROGRAM_ADDRESS =
+  "Ed25519SigVerify111111111111111111111111111" as Address<"Ed25519SigVerify111111111111111111111111111">;
+
+const RELAYED_LOCK_DOMAIN = new TextEncoder().encode(
+  "locksmith-relayed-lock-v1"
+);
+
+/**
+ * Terms of a lock an owner authorizes a relayer to create
+ */
+export type RelayedLockTerms = {
+  owner: Address;
+  mint: Address;
+  amount: bigint;
+  unlockTimestamp: bigint;
+  lockId: bigint | number;
+  /** Required, and no later than `unlockTimestamp` */
+  validUntil: bigint;
+};
+
+/**
+ * The message an owner signs for `InitializeRelayedLock`:
+ * domain || program || owner || mint || amount || unlock_timestamp ||
+ * lock_id || valid_until, integers little-endian
+ */
+export function getRelayedLockMessage(
+  terms: RelayedLockTerms,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Uint8Array {
+  const addressEncoder = getAddressEncoder();
+  const parts = [
+    RELAYED_LOCK_DOMAIN,
+    addressEncoder.encode(programAddress),
+    addressEncoder.encode(terms.owner),
+    addressEncoder.encode(terms.mint),
+    getU64Encoder().encode(terms.amount),
+    getI64Encoder().encode(terms.unlockTimestamp),
+    getU64Encoder().encode(BigInt(terms.lockId)),
+    getI64Encoder().encode(terms.validUntil),
+  ];
+  const message = new Uint8Array(
+    parts.reduce((len, part) => len + part.length, 0)
+  );
+  let offset = 0;
+  for (const part of parts) {
+    message.set(part, offset);
+    offset += part.length;
+  }
+  return message;
+}
+
+/**
+ * Sign the relayed lock message with the owner's key pair. The owner
+ * sends the signature to the relayer; no transaction is signed.
+ */
+export async function signRelayedLock(
+  owner: KeyPairSigner,
+  terms: Omit<RelayedLockTerms, "owner">,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<SignatureBytes> {
+  return signBytes(
+    owner.keyPair.privateKey,
+    getRelayedLockMessage({ ...terms, owner: owner.address }, programAddress)
+  );
+}
+
+/**
+ * Build the ed25519 program instruction verifying `signature`. Place it
+ * directly before `InitializeRelayedLock`, which looks for it there.
+ * Layout: [count:u8 = 1][padding:u8][offsets:7 x u16_le][public_key:32]
+ * [signature:64][message], every instruction index u16::MAX (this one)
+ */
+export function getEd25519VerifyInstruction(
+  publicKey: Address,
+  signature: SignatureBytes,
+  message: Uint8Array
+): Instruction {
+  const publicKeyOffset = 16;
+  const signatureOffset = publicKeyOffset + 32;
+  const messageOffset = signatureOffset + 64;
+  const thisInstruction = 0xffff;
+
+  const data = new Uint8Array(messageOffset + message.length);
+  const view = new DataView(data.buffer);
+  data[0] = 1;
+  [
+    signatureOffset,
+    thisInstruction,
+    publicKeyOffset,
+    thisInstruction,
+    messageOffset,
+    message.length,
+    thisInstruction,
+  ].forEach((value, i) => view.setUint16(2 + 2 * i, value, true));
+  data.set(getAddressEncoder().encode(publicKey), publicKeyOffset);
+  data.set(signature, signatureOffset);
+  data.set(message, messageOffset);
+
+  return { programAddress: ED25519_PROGRAM_ADDRESS, data };
+}
@@ -0,0 +1,214 @@
+import { describe, it, expect } from "vitest";
+import {
+  generateKeyPairSigner,
+  type Address,
+  type Blockhash,
+  type Instruction,
+} from "@solana/kit";
+import { sendAndConfirmLocksmithTransaction } from "./send";
+import { LOCKSMITH_PROGRAM_ADDRESS } from "./generated";
+
+const BLOCKHASHES = [
+  "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV",
+  "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB",
+] as Blockhash[];
+const LOCK = "Vote111111111111111111111111111111111111111" as Address;
+const INSTRUCTION: Instruction = {
+  programAddress: LOCKSMITH_PROGRAM_ADDRESS,
+  data: new Uint8Array([4]),
+};
+const FAST = { retryDelayMs: 0, pollIntervalMs: 0 };
+
+type Status = { confirmationStatus: string; err: unknown } | null;
+
+/**
+ * Scripted RPC. `status` returns the signature status given the number of
+ * sendTransaction calls and blockhashes handed out so far; block height
+ * advances by one per poll and blockhashes expire after `ttl` polls.
+ */
+function fakeRpc(options: {
+  status?: (sends: number, attempt: number) => Status;
+  lockExists?: () => boolean;
+  sendErrors?: unknown[];
+  ttl?: number;
+}) {
+  const calls = { blockhashes: 0, sends: 0, signatures: [] as string[] };
+  let height = 0n;
+  const rpc = {
+    getAccountInfo: (address: Address) => ({
+      send: async () => ({
+        value:
+          address === LOCK && options.lockExists?.()
+            ? {
+                data: ["", "base64"],
+                executable: false,
+                lamports: 1n,
+                owner: LOCKSMITH_PROGRAM_ADDRESS,
+                space: 0n,
+              }
+            : null,
+      }),
+    }),
+    getLatestBlockhash: () => ({
+      send: async () => ({
+        value: {
+          blockhash: BLOCKHASHES[calls.blockhashes++ % BLOCKHASHES.length],
+          lastValidBlockHeight: height + BigInt(options.ttl ?? 10),
+        },
+      }),
+    }),
+    sendTransaction: () => ({
+      send: async () => {
+        calls.sends++;
+        const error = options.sendErrors?.shift();
+        if (error) {
+          throw error;
+        }
+      },
+    }),
+    getSignatureStatuses: (signatures: string[]) => ({
+      send: async () => {
+        calls.signatures.push(signatures[0]);
+        const status = options.status?.(calls.sends, calls.blockhashes);
+        return { value: [status ?? null] };
+      },
+    }),
+    getBlockHeight: () => ({ send: async () => ++height }),
+  };
+  return { rpc: rpc as never, calls };
+}
+
+async function input() {
+  return {
+    feePayer: await generateKeyPairSigner(),
+    instructions: [INSTRUCTION],
+  };
+}
+
+describe("sendAndConfirmLocksmithTransaction", () => {
+  it("confirms on the first attempt", async () => {
+    const { rpc, calls } = fakeRpc({
+      status: () => ({ confirmationStatus: "confirmed", err: null }),
+    });
+    const result = await sendAndConfirmLocksmithTransaction(
+      rpc,
+      await input(),
+      FAST
+    );
+
+    expect(result).toMatchObject({ attempts: 1, alreadyProcessed: false });
+    expect(result.signature).toBe(calls.signatures[0]);
+    expect(calls.sends).toBe(1);
+  });
+
+  it("waits for the requested commitment", async () => {
+    const { rpc, calls } = fakeRpc({
+      status: (sends) => ({
+        confirmationStatus: sends < 3 ? "processed" : "finalized",
+        err: null,
+      }),
+    });
+    await sendAndConfirmLocksmithTransaction(rpc, await input(), {
+      ...FAST,
+      commitment: "finalized",
+    });
+    expect(calls.sends).toBe(3);
+  });
+
+  it("rebroadcasts after transient send errors", async () => {
+    const { rpc, calls } = fakeRpc({
+      sendErrors: [new Error("fetch failed")],
+      status: (sends) =>
+        sends > 1 ? { confirmationStatus: "confirmed", err: null } : null,
+    });
+    const result = await sendAndConfirmLocksmithTransaction(
+      rpc,
+      await input(),
+      FAST
+    );
+
+    expect(result.attempts).toBe(1);
+    expect(calls.blockhashes).toBe(1);
+  });
+
+  it("re-signs only after the previous blockhash expires", async () => {
+    const { rpc, calls } = fakeRpc({
+      ttl: 2,
+      status: (_, attempt) =>
+        attempt > 1 ? { confirmationStatus: "confirmed", err: null } : null,
+    });
+    const result = await sendAndConfirmLocksmithTransaction(
+      rpc,
+      await input(),
+      FAST
+    );
+
+    expect(result.attempts).toBe(2);
+    expect(calls.blockhashes).toBe(2);
+    // Three polls on the first signature until expiry, then the new one
+    expect(calls.signatures[2]).toBe(calls.signatures[0]);
+    expect(calls.signatures[3]).not.toBe(calls.signatures[0]);
+    expect(result.signature).toBe(calls.signatures[3]);
+  });
+
+  it("does not send when the idempotency account already exists", async () => {
+    const { rpc, calls } = fakeRpc({ lockExists: () => true });
+    const result = await sendAndConfirmLocksmithTransaction(
+      rpc,
+      await input(),
+      { ...FAST, idempotencyAccount: LOCK }
+    );
+
+    expect(result).toEqual({
+      signature: null,
+      attempts: 1,
+      alreadyProcessed: true,
+    });
+    expect(calls.sends).toBe(0);
+  });
+
+  it("checks the idempotency account before each new attempt", async () => {
+    let lockExists = false;
+    const { rpc, calls } = fakeRpc({
+      ttl: 1,
+      lockExists: () => lockExists,
+      status: () => {
+        // First signature lands but its status is never observed
+        lockExists = true;
+        return null;
+      },
+    });
+    const result = await sendAndConfirmLocksmithTransaction(
+      rpc,
+      await input(),
+      { ...FAST, idempotencyAccount: LOCK }
+    );
+
+    expect(result).toMatchObject({ attempts: 2, alreadyProcessed: true });
+    expect(calls.blockhashes).toBe(1);
+  });
+
+  it("does not retry transactions that fail on-chain", async () => {
+    const { rpc, calls } = fakeRpc({
+      status: () => ({
+        confirmationStatus: "confirmed",
+        err: { InstructionError: [0, { Custom: 6 }] },
+      }),
+    });
+    await expect(
+      sendAndConfirmLocksmithTransaction(rpc, await input(), FAST)
+    ).rejects.toThrow("failed");
+    expect(calls.sends).toBe(1);
+  });
+
+  it("gives up after maxAttempts expired blockhashes", async () => {
+    const { rpc, calls } = fakeRpc({ ttl: 1 });
+    await expect(
+      sendAndConfirmLocksmithTransaction(rpc, await input(), {
+        ...FAST,
+        maxAttempts: 3,
+      })
+    ).rejects.toThrow("not confirmed after 3 attempts");
+    expect(calls.blockhashes).toBe(3);
+  });
+});
@@ -0,0 +1,235 @@
+import {
+  SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+  fetchEncodedAccount,
+  getBase64EncodedWireTransaction,
+  getSignatureFromTransaction,
+  isSolanaError,
+  signTransactionMessageWithSigners,
+  type Address,
+  type Commitment,
+  type GetAccountInfoApi,
+  type GetBlockHeightApi,
+  type GetLatestBlockhashApi,
+  type GetSignatureStatusesApi,
+  type Rpc,
+  type SendTransactionApi,
+  type Signature,
+} from "@solana/kit";
+import {
+  buildLocksmithTransactionMessage,
+  type LocksmithTransactionInput,
+} from "./transaction";
+
+export type SendAndConfirmOptions = {
+  /** Commitment to wait for; also used for blockhash and account reads */
+  commitment?: Commitment;
+  /** Number of fresh blockhashes to try before giving up */
+  maxAttempts?: number;
+  /** Delay before the second attempt; doubles on each further attempt */
+  retryDelayMs?: number;
+  maxRetryDelayMs?: number;
+  /** How often to poll signature status and rebroadcast */
+  pollIntervalMs?: number;
+  /**
+   * Account the transaction creates, e.g. the lock PDA for InitializeLock.
+   * If it already exists, the transaction is treated as landed and is not
+   * (re)sent, so a retry can never create a second lock or pay the fee twice.
+   */
+  idempotencyAccount?: Address;
+  abortSignal?: AbortSignal;
+};
+
+export type SendAndConfirmResult = {
+  /** Landed signature; null if `idempotencyAccount` existed beforehand */
+  signature: Signature | null;
+  attempts: number;
+  /** True when the idempotency account was found instead of a confirmation */
+  alreadyProcessed: boolean;
+};
+
+type SendAndConfirmRpc = Rpc<
+  GetAccountInfoApi &
+    GetBlockHeightApi &
+    GetLatestBlockhashApi &
+    GetSignatureStatusesApi &
+    SendTransactionApi
+>;
+
+const COMMITMENT_RANK: Record<Commitment, number> = {
+  processed: 0,
+  confirmed: 1,
+  finalized: 2,
+};
+
+function sleep(ms: number, abortSignal?: AbortSignal) {
+  return new Promise<void>((resolve, reject) => {
+    if (abortSignal?.aborted) {
+      reject(abortSignal.reason);
+      return;
+    }
+    const timer = setTimeout(resolve, ms);
+    abortSignal?.addEventListener(
+      "abort",
+      () => {
+        clearTimeout(timer);
+        reject(abortSignal.reason);
+      },
+      { once: true }
+    );
+  });
+}
+
+function stringifyError(err: unknown) {
+  return JSON.stringify(err, (_, v) =>
+    typeof v === "bigint" ? v.toString() : v
+  );
+}
+
+/**
+ * Sign, send and confirm a Locksmith transaction, retrying on flaky RPC.
+ *
+ * Each attempt signs with a fresh blockhash and rebroadcasts until the
+ * transaction confirms or that blockhash expires. A new attempt starts only
+ * after every earlier signature has expired unconfirmed, so at most one of
+ * them can ever land. Transactions that fail on-chain, or in preflight for
+ * a reason other than an existing `idempotencyAccount`, are not retried.
+ *
+ * @example
+ * ```typescript
+ * const [lockAccount] = await findLockAccountPda(owner.address, mint, lockId);
+ * const { signature } = await sendAndConfirmLocksmithTransaction(
+ *   rpc,
+ *   { feePayer: owner, instructions: [initializeLockIx] },
+ *   { idempotencyAccount: lockAccount }
+ * );
+ * ```
+ */
+export async function sendAndConfirmLocksmithTransaction(
+  rpc: SendAndConfirmRpc,
+  input: Omit<LocksmithTransactionInput, "latestBlockhash" | "durableNonce">,
+  options: SendAndConfirmOptions = {}
+): Promise<SendAndConfirmResult> {
+  const commitment = options.commitment ?? "confirmed";
+  const maxAttempts = options.maxAttempts ?? 5;
+  const retryDelayMs = options.retryDelayMs ?? 500;
+  const maxRetryDelayMs = options.maxRetryDelayMs ?? 8_000;
+  const pollIntervalMs = options.pollIntervalMs ?? 1_000;
+  const { abortSignal, idempotencyAccount } = options;
+
+  const alreadyProcessed = async () => {
+    if (!idempotencyAccount) {
+      return false;
+    }
+    const account = await fetchEncodedAccount(rpc, idempotencyAccount, {
+      commitment,
+    });
+    return account.exists;
+  };
+
+  let lastError: unknown;
+  for (let attempt = 1; attempt <= maxAttempts; attempt++) {
+    if (attempt > 1) {
+      await sleep(
+        Math.min(retryDelayMs * 2 ** (attempt - 2), maxRetryDelayMs),
+        abortSignal
+      );
+    }
+
+    let wire: ReturnType<typeof getBase64EncodedWireTransaction>;
+    let signature: Signature;
+    let lastValidBlockHeight: bigint;
+    try {
+      if (await alreadyProcessed()) {
+        return { signature: null, attempts: attempt, alreadyProcessed: true };
+      }
+      const { value: latestBlockhash } = await rpc
+        .getLatestBlockhash({ commitment })
+        .send();
+      const transaction = await signTransactionMessageWithSigners(
+        buildLocksmithTransactionMessage({ ...input, latestBlockhash })
+      );
+      wire = getBase64EncodedWireTransaction(transaction);
+      signature = getSignatureFromTransaction(transaction);
+      lastValidBlockHeight = latestBlockhash.lastValidBlockHeight;
+    } catch (error) {
+      lastError = error;
+      continue;
+    }
+
+    // Rebroadcast and poll until confirmed or the blockhash expires
+    let pollFailures = 0;
+    while (true) {
+      let preflightError: unknown;
+      try {
+        await rpc
+          .sendTransaction(wire, {
+            encoding: "base64",
+            preflightCommitment: commitment,
+            maxRetries: 0n,
+          })
+          .send();
+      } catch (error) {
+        if (
+          isSolanaError(
+            error,
+            SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE
+          )
+        ) {
+          preflightError = error;
+        } else {
+          lastError = error;
+        }
+      }
+
+      let status: {
+        confirmationStatus: Commitment | null;
+        err: unknown;
+      } | null = null;
+      let blockHeight: bigint | undefined;
+      try {
+        [status] = (await rpc.getSignatureStatuses([signature]).send()).value;
+        blockHeight = await rpc.getBlockHeight({ commitment }).send();
+        pollFailures = 0;
+      } catch (error) {
+        lastError = error;
+        if (++pollFailures >= maxAttempts) {
+          throw new Error(
+            `Lost contact with RPC while confirming ${signature}; ` +
+              "check its status before retrying"
+          );
+        }
+      }
+
+      if (
+        status?.confirmationStatus &&
+        COMMITMENT_RANK[status.confirmationStatus] >=
+          COMMITMENT_RANK[commitment]
+      ) {
+        if (status.err) {
+          throw new Error(
+            `Transaction ${signature} failed: ${stringifyError(status.err)}`
+          );
+        }
+        return { signature, attempts: attempt, alreadyProcessed: false };
+      }
+      // Rebroadcasting a transaction that already landed fails preflight;
+      // keep polling it rather than treating that as a failure
+      if (preflightError && !status) {
+        if (await alreadyProcessed()) {
+          return { signature, attempts: attempt, alreadyProcessed: true };
+        }
+        throw preflightError;
+      }
+      if (blockHeight !== undefined && blockHeight > lastValidBlockHeight) {
+        break;
+      }
+
+      await sleep(pollIntervalMs, abortSignal);
+    }
+  }
+
+  throw new Error(
+    `Transaction not confirmed after ${maxAttempts} attempts` +
+      (lastError ? `: ${String(lastError)}` : "")
+  );
+}
@@ -0,0 +1,81 @@
+import { describe, it, expect } from "vitest";
+import type { Address } from "@solana/kit";
+import { getLockSharesArgs, getShareClaimAmount } from "./shares";
+import { LOCK_SHARES_DISCRIMINATOR, MAX_BENEFICIARIES } from "./constants";
+import type { LockSharesAccount } from "./generated";
+
+const ALICE = "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address;
+const BOB = "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde" as Address;
+const CAROL = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address;
+
+function lockShares(claimed = 0): LockSharesAccount {
+  const { beneficiaries, shareBps } = getLockSharesArgs([
+    { beneficiary: ALICE, bps: 3_333 },
+    { beneficiary: BOB, bps: 3_333 },
+    { beneficiary: CAROL, bps: 3_334 },
+  ]);
+  return {
+    discriminator: LOCK_SHARES_DISCRIMINATOR,
+    lock: "11111111111111111111111111111112" as Address,
+    total: 1_000n,
+    beneficiaries,
+    shareBps,
+    claimed,
+    bump: 255,
+  };
+}
+
+describe("getLockSharesArgs", () => {
+  it("pads shares to MAX_BENEFICIARIES slots", () => {
+    const { beneficiaries, shareBps } = getLockSharesArgs([
+      { beneficiary: ALICE, bps: 7_500 },
+      { beneficiary: BOB, bps: 2_500 },
+    ]);
+
+    expect(beneficiaries).toHaveLength(MAX_BENEFICIARIES);
+    expect(beneficiaries.slice(0, 3)).toEqual([
+      ALICE,
+      BOB,
+      "11111111111111111111111111111111",
+    ]);
+    expect(shareBps).toEqual([7_500, 2_500, 0, 0, 0, 0, 0, 0]);
+  });
+
+  it("rejects shares the program would", () => {
+    expect(() => getLockSharesArgs([])).toThrow();
+    expect(() =>
+      getLockSharesArgs([{ beneficiary: ALICE, bps: 9_999 }])
+    ).toThrow();
+    expect(() =>
+      getLockSharesArgs([
+        { beneficiary: ALICE, bps: 10_000 },
+        { beneficiary: BOB, bps: 0 },
+      ])
+    ).toThrow();
+    expect(() =>
+      getLockSharesArgs([
+        { beneficiary: ALICE, bps: 5_000 },
+        { beneficiary: ALICE, bps: 5_000 },
+      ])
+    ).toThrow();
+  });
+});
+
+describe("getShareClaimAmount", () => {
+  it("rounds shares down until the last claim, which takes the rest", () => {
+    expect(getShareClaimAmount(lockShares(), ALICE, 1_000n)).toBe(333n);
+    expect(getShareClaimAmount(lockShares(0b001), CAROL, 667n)).toBe(333n);
+    expect(getShareClaimAmount(lockShares(0b101), BOB, 334n)).toBe(334n);
+  });
+
+  it("pays nothing to strangers or beneficiaries who have claimed", () => {
+    expect(getShareClaimAmount(lockShares(0b001), ALICE, 667n)).toBe(0n);
+    expect(
+      getShareClaimAmount(
+        lockShares(),
+        "11111111111111111111111111111111" as Address,
+        1_000n
+      )
+    ).toBe(0n);
+  });
+});
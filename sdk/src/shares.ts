@@ -0,0 +1,71 @@
+import type { Address } from "@solana/kit";
+import { BPS_DENOMINATOR, MAX_BENEFICIARIES } from "./constants";
+import type {
+  LockSharesAccount,
+  SetLockSharesInstructionDataArgs,
+} from "./generated";
+
+/** Beneficiary of an unused slot */
+const UNUSED_SLOT = "11111111111111111111111111111111" as Address;
+
+/** A beneficiary of a split lock and their share in basis points */
+export interface LockShare {
+  beneficiary: Address;
+  bps: number;
+}
+
+/**
+ * The `beneficiaries` and `shareBps` arguments of
+ * `getSetLockSharesInstruction`, padded to `MAX_BENEFICIARIES` slots.
+ * Beneficiaries must be distinct and their shares positive, summing to
+ * `BPS_DENOMINATOR`.
+ */
+export function getLockSharesArgs(
+  shares: LockShare[]
+): Pick<SetLockSharesInstructionDataArgs, "beneficiaries" | "shareBps"> {
+  if (shares.length === 0 || shares.length > MAX_BENEFICIARIES) {
+    throw new Error(`Expected 1 to ${MAX_BENEFICIARIES} beneficiaries`);
+  }
+  const total = shares.reduce((sum, { bps }) => sum + bps, 0);
+  if (total !== BPS_DENOMINATOR || shares.some(({ bps }) => bps <= 0)) {
+    throw new Error(`Shares must be positive and sum to ${BPS_DENOMINATOR}`);
+  }
+  const distinct = new Set(shares.map(({ beneficiary }) => beneficiary));
+  if (distinct.size < shares.length) {
+    throw new Error("Beneficiaries must be distinct");
+  }
+  const beneficiaries = Array<Address>(MAX_BENEFICIARIES).fill(UNUSED_SLOT);
+  const shareBps = Array<number>(MAX_BENEFICIARIES).fill(0);
+  shares.forEach(({ beneficiary, bps }, i) => {
+    beneficiaries[i] = beneficiary;
+    shareBps[i] = bps;
+  });
+  return { beneficiaries, shareBps };
+}
+
+/**
+ * Amount `ClaimShare` would pay `beneficiary` from a split lock whose
+ * `amount` is `remaining`: their share of `total`, rounded down, or all of
+ * `remaining` if they are the last to claim. 0n if they are not a
+ * beneficiary or have already claimed.
+ */
+export function getShareClaimAmount(
+  shares: LockSharesAccount,
+  beneficiary: Address,
+  remaining: bigint
+): bigint {
+  const claimed = (i: number) => (shares.claimed & (1 << i)) !== 0;
+  const index = shares.beneficiaries.indexOf(beneficiary);
+  if (index < 0 || beneficiary === UNUSED_SLOT || claimed(index)) {
+    return 0n;
+  }
+  const othersClaimed = shares.beneficiaries.every(
+    (b, i) => i === index || b === UNUSED_SLOT || claimed(i)
+  );
+  if (othersClaimed) {
+    return remaining;
+  }
+  const share =
+    (shares.total * BigInt(shares.shareBps[index])) / BigInt(BPS_DENOMINATOR);
+  return share < remaining ? share : remaining;
+}
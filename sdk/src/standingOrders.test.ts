@@ -0,0 +1,165 @@
+import { describe, it, expect } from "vitest";
+import {
+  createNoopSigner,
+  getBase64Decoder,
+  type Address,
+  type GetProgramAccountsApi,
+  type Rpc,
+} from "@solana/kit";
+import {
+  fetchDueStandingOrders,
+  getExecuteStandingOrderInstructionForOrder,
+} from "./standingOrders";
+import {
+  LOCKSMITH_PROGRAM_ADDRESS,
+  getStandingOrderAccountEncoder,
+} from "./generated";
+import {
+  FEE_USDC,
+  LEGACY_STANDING_ORDER_ACCOUNT_SIZE,
+  STANDING_ORDER_DISCRIMINATOR,
+} from "./constants";
+import {
+  findConfigPda,
+  findFeeVaultPda,
+  findLockAccountPda,
+  findLockTokenPda,
+} from "./pdas";
+
+const TEST_ADDRESSES = {
+  owner: "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address,
+  mint: "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address,
+  ownerToken: "Vote111111111111111111111111111111111111111" as Address,
+  ownerUsdc: "Stake11111111111111111111111111111111111111" as Address,
+  cranker: "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde" as Address,
+};
+
+function encodeOrder(
+  nextExecutionAt: bigint,
+  remainingExecutions = 3,
+  size?: number
+) {
+  const bytes = getStandingOrderAccountEncoder().encode({
+    discriminator: STANDING_ORDER_DISCRIMINATOR,
+    owner: TEST_ADDRESSES.owner,
+    mint: TEST_ADDRESSES.mint,
+    ownerTokenAccount: TEST_ADDRESSES.ownerToken,
+    ownerUsdcAccount: TEST_ADDRESSES.ownerUsdc,
+    orderId: 1n,
+    amountPerLock: 100n,
+    intervalSeconds: 60n,
+    lockDurationSeconds: 3_600n,
+    nextExecutionAt,
+    nextLockId: 7n,
+    remainingExecutions,
+    bump: 255,
+    feeUsdc: 400_000n,
+  });
+  return getBase64Decoder().decode(bytes.slice(0, size));
+}
+
+/** Fake RPC serving standing orders keyed by address. */
+function fakeRpc(orders: Record<string, string>) {
+  const calls: unknown[] = [];
+  const rpc = {
+    getProgramAccounts: (program: Address, config: unknown) => {
+      calls.push([program, config]);
+      return {
+        send: async () =>
+          Object.entries(orders).map(([pubkey, data]) => ({
+            pubkey,
+            account: {
+              data: [data, "base64"],
+              executable: false,
+              lamports: 1_000_000n,
+              owner: LOCKSMITH_PROGRAM_ADDRESS,
+              rentEpoch: 0n,
+              space: 197n,
+            },
+          })),
+      };
+    },
+  } as unknown as Rpc<GetProgramAccountsApi>;
+  return { rpc, calls };
+}
+
+describe("fetchDueStandingOrders", () => {
+  it("returns only due orders, earliest first", async () => {
+    const { rpc } = fakeRpc({
+      Config1111111111111111111111111111111111111: encodeOrder(300n),
+      Vote111111111111111111111111111111111111111: encodeOrder(100n),
+      Stake11111111111111111111111111111111111111: encodeOrder(201n),
+    });
+
+    const due = await fetchDueStandingOrders(rpc, 300n);
+
+    expect(due.map((o) => o.data.nextExecutionAt)).toEqual([100n, 201n, 300n]);
+    expect((await fetchDueStandingOrders(rpc, 200n)).length).toBe(1);
+  });
+
+  it("reads orders from before feeUsdc as approving FEE_USDC", async () => {
+    const { rpc } = fakeRpc({
+      Config1111111111111111111111111111111111111: encodeOrder(100n),
+      Vote111111111111111111111111111111111111111: encodeOrder(
+        200n,
+        3,
+        LEGACY_STANDING_ORDER_ACCOUNT_SIZE
+      ),
+    });
+
+    const due = await fetchDueStandingOrders(rpc, 300n);
+
+    expect(due.map((o) => o.data.feeUsdc)).toEqual([400_000n, FEE_USDC]);
+    expect(due[1].data.remainingExecutions).toBe(3);
+  });
+
+  it("filters on the standing order discriminator", async () => {
+    const { rpc, calls } = fakeRpc({});
+    await fetchDueStandingOrders(rpc, 0n);
+
+    const [program, config] = calls[0] as [
+      Address,
+      { filters: { memcmp: { offset: bigint } }[] },
+    ];
+    expect(program).toBe(LOCKSMITH_PROGRAM_ADDRESS);
+    expect(config.filters).toHaveLength(1);
+    expect(config.filters[0].memcmp.offset).toBe(0n);
+  });
+});
+
+describe("getExecuteStandingOrderInstructionForOrder", () => {
+  it("targets the lock PDA of the order's next lock ID", async () => {
+    const { rpc } = fakeRpc({
+      Config1111111111111111111111111111111111111: encodeOrder(0n),
+    });
+    const [order] = await fetchDueStandingOrders(rpc, 0n);
+
+    const ix = await getExecuteStandingOrderInstructionForOrder(
+      createNoopSigner(TEST_ADDRESSES.cranker),
+      order
+    );
+
+    const [lock] = await findLockAccountPda(
+      TEST_ADDRESSES.owner,
+      TEST_ADDRESSES.mint,
+      7n
+    );
+    const [escrow] = await findLockTokenPda(lock);
+    const [feeVault] = await findFeeVaultPda();
+    const [config] = await findConfigPda();
+    expect(ix.accounts?.map((a) => a.address)).toEqual([
+      TEST_ADDRESSES.cranker,
+      TEST_ADDRESSES.owner,
+      order.address,
+      TEST_ADDRESSES.ownerToken,
+      TEST_ADDRESSES.ownerUsdc,
+      TEST_ADDRESSES.mint,
+      lock,
+      escrow,
+      feeVault,
+      "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+      "11111111111111111111111111111111",
+      config,
+    ]);
+  });
+});
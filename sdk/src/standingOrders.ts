@@ -0,0 +1,125 @@
+import {
+  AccountRole,
+  getBase58Decoder,
+  getBase64Encoder,
+  type Account,
+  type Address,
+  type Base58EncodedBytes,
+  type GetProgramAccountsApi,
+  type Instruction,
+  type Rpc,
+  type TransactionSigner,
+} from "@solana/kit";
+import {
+  LOCKSMITH_PROGRAM_ADDRESS,
+  getExecuteStandingOrderInstruction,
+  type StandingOrderAccount,
+} from "./generated";
+import { decodeStandingOrderAccountData } from "./accounts";
+import { STANDING_ORDER_DISCRIMINATOR, USDC_MINT } from "./constants";
+import {
+  findConfigPda,
+  findFeeVaultPda,
+  findLockAccountPda,
+  findLockTokenPda,
+} from "./pdas";
+
+/**
+ * Fetch standing orders whose next execution is due at `now` (unix
+ * seconds), earliest first. Intended for crank operators.
+ */
+export async function fetchDueStandingOrders(
+  rpc: Rpc<GetProgramAccountsApi>,
+  now: bigint,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): Promise<Account<StandingOrderAccount>[]> {
+  const accounts = await rpc
+    .getProgramAccounts(programAddress, {
+      encoding: "base64",
+      filters: [
+        {
+          memcmp: {
+            offset: 0n,
+            bytes: getBase58Decoder().decode(
+              STANDING_ORDER_DISCRIMINATOR
+            ) as Base58EncodedBytes,
+            encoding: "base58",
+          },
+        },
+      ],
+    })
+    .send();
+
+  return accounts
+    .map(({ pubkey, account }) => ({
+      address: pubkey,
+      data: decodeStandingOrderAccountData(
+        getBase64Encoder().encode(account.data[0])
+      ),
+      executable: account.executable,
+      lamports: account.lamports,
+      programAddress: account.owner,
+      space: account.space,
+    }))
+    .filter(
+      (order) =>
+        order.data.remainingExecutions > 0 &&
+        order.data.nextExecutionAt <= now
+    )
+    .sort((a, b) =>
+      a.data.nextExecutionAt < b.data.nextExecutionAt
+        ? -1
+        : a.data.nextExecutionAt > b.data.nextExecutionAt
+        ? 1
+        : 0
+    );
+}
+
+/**
+ * Build the ExecuteStandingOrder instruction for `order`, deriving the
+ * lock, escrow and fee vault PDAs of its next execution and appending the
+ * config PDA. Pass `feeMint` once fees have been migrated off USDC (see
+ * `getFeeMint`).
+ */
+export async function getExecuteStandingOrderInstructionForOrder(
+  cranker: TransactionSigner,
+  order: Account<StandingOrderAccount>,
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS,
+  feeMint: Address = USDC_MINT
+): Promise<Instruction> {
+  const { owner, mint, nextLockId } = order.data;
+  const [lockAccount] = await findLockAccountPda(
+    owner,
+    mint,
+    nextLockId,
+    programAddress
+  );
+  const [lockTokenAccount] = await findLockTokenPda(
+    lockAccount,
+    programAddress
+  );
+  const [feeVault] = await findFeeVaultPda(programAddress, feeMint);
+  const [config] = await findConfigPda(programAddress);
+
+  const instruction = getExecuteStandingOrderInstruction(
+    {
+      cranker,
+      owner,
+      standingOrder: order.address,
+      ownerTokenAccount: order.data.ownerTokenAccount,
+      ownerUsdcAccount: order.data.ownerUsdcAccount,
+      mint,
+      lockAccount,
+      lockTokenAccount,
+      feeVault,
+    },
+    { programAddress }
+  );
+  return {
+    ...instruction,
+    accounts: [
+      ...instruction.accounts,
+      { address: config, role: AccountRole.READONLY },
+    ],
+  };
+}
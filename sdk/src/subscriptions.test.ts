@@ -0,0 +1,206 @@
+import { describe, it, expect } from "vitest";
+import {
+  getBase64Decoder,
+  type Address,
+  type LogsNotificationsApi,
+  type ProgramNotificationsApi,
+  type RpcSubscriptions,
+} from "@solana/kit";
+import { parseUnlockLogs, subscribeLockEvents } from "./subscriptions";
+import { LOCKSMITH_PROGRAM_ADDRESS, getLockAccountEncoder } from "./generated";
+import { LOCK_DISCRIMINATOR } from "./constants";
+
+const OWNER = "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address;
+const MINT = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address;
+const LOCK = "Vote111111111111111111111111111111111111111" as Address;
+const TOKEN_PROGRAM = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+const unlockLogs = [
+  `Program ${LOCKSMITH_PROGRAM_ADDRESS} invoke [1]`,
+  `Program ${TOKEN_PROGRAM} invoke [2]`,
+  "Program log: Instruction: Transfer",
+  "Program log: Unlocked 999 tokens",
+  `Program ${TOKEN_PROGRAM} success`,
+  "Program log: Unlocked 1000 tokens",
+  `Program ${LOCKSMITH_PROGRAM_ADDRESS} consumed 20000 of 200000 compute units`,
+  `Program ${LOCKSMITH_PROGRAM_ADDRESS} success`,
+];
+
+async function* fromArray<T>(items: T[]) {
+  for (const item of items) {
+    yield item;
+  }
+}
+
+function lockData(frozen = false) {
+  const bytes = getLockAccountEncoder().encode({
+    discriminator: LOCK_DISCRIMINATOR,
+    owner: OWNER,
+    mint: MINT,
+    amount: 1_000n,
+    unlockTimestamp: 1_700_000_000n,
+    createdAt: 1_600_000_000n,
+    lockId: 7n,
+    bump: 254,
+    frozen,
+    credentialIssuer: "11111111111111111111111111111111" as Address,
+    nonTransferable: false,
+    forwardDestination: "11111111111111111111111111111111" as Address,
+    hook: "11111111111111111111111111111111" as Address,
+    recoveryAddress: "11111111111111111111111111111111" as Address,
+    donationAddress: "11111111111111111111111111111111" as Address,
+    donationBps: 0,
+    collateralHolder: "11111111111111111111111111111111" as Address,
+    layoutVersion: 1,
+    unlockSlot: 0n,
+    unlockEpoch: 0n,
+    claimKey: new Uint8Array(32),
+    claimExpiresAt: 0n,
+    indexSeq: 0,
+    earlyUnlockAllowed: false,
+    permanent: false,
+    isNft: false,
+    receiptBound: false,
+    label: new Uint8Array(32),
+    metadataUri: new Uint8Array(128),
+    fallbackOwner: "11111111111111111111111111111111" as Address,
+    fallbackAfter: 0n,
+    unlockDestination: "11111111111111111111111111111111" as Address,
+    shared: false,
+    approvalsRequired: false,
+    priceFeedId: new Uint8Array(32),
+    priceThreshold: 0n,
+    priceExponent: 0,
+    arbiter: "11111111111111111111111111111111" as Address,
+    milestoneApproved: false,
+    counterparty: "11111111111111111111111111111111" as Address,
+    ownerCounted: false,
+    wrappedLamports: false,
+    receiptMint: "11111111111111111111111111111111" as Address,
+    feeMode: 0,
+    reserved: new Uint8Array(29),
+  });
+  return getBase64Decoder().decode(bytes);
+}
+
+describe("parseUnlockLogs", () => {
+  it("reads unlock amounts emitted by the program itself", () => {
+    expect(parseUnlockLogs(unlockLogs)).toEqual([1000n]);
+  });
+
+  it("ignores other programs", () => {
+    expect(
+      parseUnlockLogs([
+        `Program ${TOKEN_PROGRAM} invoke [1]`,
+        "Program log: Unlocked 5 tokens",
+        `Program ${TOKEN_PROGRAM} success`,
+      ])
+    ).toEqual([]);
+  });
+});
+
+describe("subscribeLockEvents", () => {
+  it("yields decoded events and reconnects after a failure", async () => {
+    let programAttempts = 0;
+    const subscriptions = {
+      programNotifications: (_program: Address, config: unknown) => ({
+        subscribe: async () => {
+          programAttempts += 1;
+          if (programAttempts === 1) {
+            throw new Error("socket closed");
+          }
+          expect(config).toMatchObject({ encoding: "base64" });
+          return fromArray([
+            {
+              context: { slot: 10n },
+              value: {
+                pubkey: LOCK,
+                account: { data: [lockData(), "base64"] },
+              },
+            },
+          ]);
+        },
+      }),
+      logsNotifications: () => ({
+        subscribe: async () =>
+          fromArray([
+            {
+              context: { slot: 20n },
+              value: { signature: "sig", err: null, logs: unlockLogs },
+            },
+            {
+              context: { slot: 21n },
+              value: { signature: "failed", err: {}, logs: unlockLogs },
+            },
+          ]),
+      }),
+    } as unknown as RpcSubscriptions<
+      ProgramNotificationsApi & LogsNotificationsApi
+    >;
+
+    const abort = new AbortController();
+    const errors: unknown[] = [];
+    const events = [];
+    for await (const event of subscribeLockEvents(subscriptions, OWNER, {
+      abortSignal: abort.signal,
+      retryDelayMs: 0,
+      onError: (e) => errors.push(e),
+    })) {
+      events.push(event);
+      if (new Set(events.map((e) => e.type)).size === 2) {
+        abort.abort();
+      }
+    }
+
+    expect(errors).toHaveLength(1);
+    const created = events.find((e) => e.type === "lockCreated");
+    const unlocked = events.find((e) => e.type === "unlocked");
+    expect(created).toMatchObject({ address: LOCK, slot: 10n });
+    expect(created?.type === "lockCreated" && created.lock.lockId).toBe(7n);
+    expect(unlocked).toMatchObject({ signature: "sig", amount: 1000n });
+  });
+
+  it("reports freezes and unfreezes of known locks", async () => {
+    const notification = (slot: bigint, frozen: boolean) => ({
+      context: { slot },
+      value: {
+        pubkey: LOCK,
+        account: { data: [lockData(frozen), "base64"] },
+      },
+    });
+    const subscriptions = {
+      programNotifications: () => ({
+        subscribe: async () =>
+          fromArray([
+            notification(10n, false),
+            notification(11n, true),
+            notification(12n, true),
+            notification(13n, false),
+          ]),
+      }),
+      logsNotifications: () => ({
+        subscribe: async () => fromArray([]),
+      }),
+    } as unknown as RpcSubscriptions<
+      ProgramNotificationsApi & LogsNotificationsApi
+    >;
+
+    const abort = new AbortController();
+    const events = [];
+    for await (const event of subscribeLockEvents(subscriptions, OWNER, {
+      abortSignal: abort.signal,
+      retryDelayMs: 0,
+    })) {
+      events.push(event);
+      if (events.length === 3) {
+        abort.abort();
+      }
+    }
+
+    expect(events.map((e) => [e.type, e.slot])).toEqual([
+      ["lockCreated", 10n],
+      ["lockFrozen", 11n],
+      ["lockUnfrozen", 13n],
+    ]);
+  });
+});
@@ -0,0 +1,248 @@
+import {
+  getBase64Encoder,
+  type Address,
+  type Commitment,
+  type LogsNotificationsApi,
+  type ProgramNotificationsApi,
+  type RpcSubscriptions,
+  type Signature,
+} from "@solana/kit";
+import { LOCKSMITH_PROGRAM_ADDRESS, type LockAccount } from "./generated";
+import { decodeLockAccountData } from "./accounts";
+import { getLockAccountFilters } from "./queries";
+
+export type LockEvent =
+  | {
+      type: "lockCreated" | "lockFrozen" | "lockUnfrozen";
+      slot: bigint;
+      address: Address;
+      lock: LockAccount;
+    }
+  | {
+      type: "unlocked";
+      slot: bigint;
+      signature: Signature;
+      amount: bigint;
+    };
+
+export type LockSubscriptionOptions = {
+  abortSignal?: AbortSignal;
+  commitment?: Commitment;
+  programAddress?: Address;
+  /** First reconnect delay; doubles on each consecutive failure */
+  retryDelayMs?: number;
+  maxRetryDelayMs?: number;
+  /** Called when a subscription drops, before reconnecting */
+  onError?: (error: unknown) => void;
+};
+
+type LockSubscriptions = RpcSubscriptions<
+  ProgramNotificationsApi & LogsNotificationsApi
+>;
+
+const UNLOCKED_LOG = /^Program log: Unlocked (\d+) tokens$/;
+
+/**
+ * Extract unlock amounts from a transaction's log messages, counting only
+ * lines emitted directly by the Locksmith program (not by CPIs it makes).
+ */
+export function parseUnlockLogs(
+  logs: readonly string[],
+  programAddress: Address = LOCKSMITH_PROGRAM_ADDRESS
+): bigint[] {
+  const stack: string[] = [];
+  const amounts: bigint[] = [];
+
+  for (const line of logs) {
+    const invoke = /^Program (\w+) invoke \[\d+\]$/.exec(line);
+    if (invoke) {
+      stack.push(invoke[1]);
+      continue;
+    }
+    if (/^Program \w+ (success|failed)/.test(line)) {
+      stack.pop();
+      continue;
+    }
+    if (stack[stack.length - 1] !== programAddress) {
+      continue;
+    }
+    const unlocked = UNLOCKED_LOG.exec(line);
+    if (unlocked) {
+      amounts.push(BigInt(unlocked[1]));
+    }
+  }
+
+  return amounts;
+}
+
+function sleep(ms: number, signal: AbortSignal): Promise<void> {
+  return new Promise((resolve) => {
+    const timer = setTimeout(resolve, ms);
+    signal.addEventListener(
+      "abort",
+      () => {
+        clearTimeout(timer);
+        resolve();
+      },
+      { once: true }
+    );
+  });
+}
+
+/**
+ * Keep a subscription alive: reconnect with exponential backoff whenever it
+ * errors or the server closes it, until `signal` is aborted.
+ */
+async function keepSubscribed<T>(
+  connect: (signal: AbortSignal) => Promise<AsyncIterable<T>>,
+  onNotification: (notification: T) => void,
+  signal: AbortSignal,
+  options: LockSubscriptionOptions
+): Promise<void> {
+  const initialDelay = options.retryDelayMs ?? 500;
+  const maxDelay = options.maxRetryDelayMs ?? 30_000;
+  let delay = initialDelay;
+
+  while (!signal.aborted) {
+    try {
+      const notifications = await connect(signal);
+      delay = initialDelay;
+      for await (const notification of notifications) {
+        onNotification(notification);
+      }
+    } catch (error) {
+      if (signal.aborted) {
+        return;
+      }
+      options.onError?.(error);
+    }
+    if (signal.aborted) {
+      return;
+    }
+    await sleep(delay, signal);
+    delay = Math.min(delay * 2, maxDelay);
+  }
+}
+
+/**
+ * Subscribe to lock lifecycle events for `owner`.
+ *
+ * Lock creations come from a program account subscription filtered on the
+ * owner; unlocks come from a logs subscription mentioning the owner, since
+ * Unlock closes the lock account. Both subscriptions reconnect on failure.
+ * Events missed while disconnected are not replayed; reconcile with
+ * `fetchLockAccounts` after long outages.
+ *
+ * Freezes and unfreezes by the compliance authority arrive through the
+ * account subscription too. A lock first seen unfrozen is reported as
+ * created, so unfreezing a lock frozen before the subscription started
+ * shows up as `lockCreated`.
+ *
+ * @example
+ * ```typescript
+ * const abort = new AbortController();
+ * for await (const event of subscribeLockEvents(rpcSubscriptions, owner, {
+ *   abortSignal: abort.signal,
+ * })) {
+ *   if (event.type === "lockCreated") schedule(event.address, event.lock);
+ * }
+ * ```
+ */
+export async function* subscribeLockEvents(
+  rpcSubscriptions: LockSubscriptions,
+  owner: Address,
+  options: LockSubscriptionOptions = {}
+): AsyncGenerator<LockEvent> {
+  const programAddress = options.programAddress ?? LOCKSMITH_PROGRAM_ADDRESS;
+  const commitment = options.commitment ?? "confirmed";
+  const controller = new AbortController();
+  const signal = controller.signal;
+  const stop = () => controller.abort();
+  options.abortSignal?.addEventListener("abort", stop, { once: true });
+  if (options.abortSignal?.aborted) {
+    stop();
+  }
+
+  const queue: LockEvent[] = [];
+  let wake: (() => void) | undefined;
+  const push = (event: LockEvent) => {
+    queue.push(event);
+    wake?.();
+  };
+  signal.addEventListener("abort", () => wake?.(), { once: true });
+
+  const base64 = getBase64Encoder();
+  const frozen = new Map<Address, boolean>();
+
+  void keepSubscribed(
+    (abortSignal) =>
+      rpcSubscriptions
+        .programNotifications(programAddress, {
+          commitment,
+          encoding: "base64",
+          filters: getLockAccountFilters({ owner }),
+        })
+        .subscribe({ abortSignal }),
+    ({ context, value }) => {
+      const lock = decodeLockAccountData(
+        base64.encode(value.account.data[0])
+      );
+      const wasFrozen = frozen.get(value.pubkey);
+      frozen.set(value.pubkey, lock.frozen);
+      if (wasFrozen === lock.frozen) {
+        return;
+      }
+      push({
+        type: lock.frozen
+          ? "lockFrozen"
+          : wasFrozen
+          ? "lockUnfrozen"
+          : "lockCreated",
+        slot: context.slot,
+        address: value.pubkey,
+        lock,
+      });
+    },
+    signal,
+    options
+  );
+
+  void keepSubscribed(
+    (abortSignal) =>
+      rpcSubscriptions
+        .logsNotifications({ mentions: [owner] }, { commitment })
+        .subscribe({ abortSignal }),
+    ({ context, value }) => {
+      if (value.err) {
+        return;
+      }
+      for (const amount of parseUnlockLogs(value.logs, programAddress)) {
+        push({
+          type: "unlocked",
+          slot: context.slot,
+          signature: value.signature,
+          amount,
+        });
+      }
+    },
+    signal,
+    options
+  );
+
+  try {
+    while (!signal.aborted) {
+      const event = queue.shift();
+      if (event) {
+        yield event;
+        continue;
+      }
+      await new Promise<void>((resolve) => {
+        wake = resolve;
+      });
+      wake = undefined;
+    }
+  } finally {
+    options.abortSignal?.removeEventListener("abort", stop);
+    stop();
+  }
+}
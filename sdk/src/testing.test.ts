@@ -0,0 +1,124 @@
+import { beforeAll, describe, it, expect } from "vitest";
+import {
+  generateKeyPairSigner,
+  getAddressEncoder,
+  getBase64Decoder,
+  type Address,
+  type KeyPairSigner,
+} from "@solana/kit";
+import { MINT_ACCOUNT_SIZE, bootstrapTestEnvironment } from "./testing";
+import { USDC_MINT } from "./constants";
+import { findConfigPda, findFeeVaultPda } from "./pdas";
+
+const BLOCKHASH = "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV";
+const AIRDROP_SIGNATURE =
+  "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW";
+
+function encodeMint(authority: Address | null) {
+  const data = new Uint8Array(MINT_ACCOUNT_SIZE);
+  if (authority) {
+    data[0] = 1;
+    data.set(getAddressEncoder().encode(authority), 4);
+  }
+  data[44] = 6;
+  data[45] = 1;
+  return getBase64Decoder().decode(data);
+}
+
+/**
+ * Fake validator that confirms every transaction. `usdcAuthority` of
+ * undefined means the USDC mint is missing.
+ */
+function fakeRpc(usdcAuthority?: Address, configExists = false) {
+  const calls = { airdrops: [] as Address[], sends: 0 };
+  let configAddress: Address | undefined;
+  const rpc = {
+    getAccountInfo: (address: Address) => ({
+      send: async () => {
+        configAddress ??= (await findConfigPda())[0];
+        if (address === USDC_MINT && usdcAuthority) {
+          return { value: account(encodeMint(usdcAuthority)) };
+        }
+        if (address === configAddress && configExists) {
+          return { value: account("") };
+        }
+        return { value: null };
+      },
+    }),
+    requestAirdrop: (recipient: Address) => ({
+      send: async () => {
+        calls.airdrops.push(recipient);
+        return AIRDROP_SIGNATURE;
+      },
+    }),
+    getSignatureStatuses: () => ({
+      send: async () => ({
+        value: [{ confirmationStatus: "confirmed", err: null }],
+      }),
+    }),
+    getMinimumBalanceForRentExemption: (space: bigint) => ({
+      send: async () => 6_960n * (space + 128n),
+    }),
+    getLatestBlockhash: () => ({
+      send: async () => ({
+        value: { blockhash: BLOCKHASH, lastValidBlockHeight: 100n },
+      }),
+    }),
+    sendTransaction: () => ({
+      send: async () => {
+        calls.sends++;
+      },
+    }),
+    getBlockHeight: () => ({ send: async () => 1n }),
+  };
+  return { rpc: rpc as never, calls };
+}
+
+function account(data: string) {
+  return {
+    data: [data, "base64"],
+    executable: false,
+    lamports: 1n,
+    owner: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    space: 0n,
+  };
+}
+
+describe("bootstrapTestEnvironment", () => {
+  let usdcMintAuthority: KeyPairSigner;
+  beforeAll(async () => {
+    usdcMintAuthority = await generateKeyPairSigner();
+  });
+
+  it("funds wallets, creates the mint and initializes config", async () => {
+    const { rpc, calls } = fakeRpc(usdcMintAuthority.address);
+    const env = await bootstrapTestEnvironment(rpc, { usdcMintAuthority });
+
+    expect(calls.airdrops).toEqual([env.admin.address, env.owner.address]);
+    // Token setup, then InitializeConfig
+    expect(calls.sends).toBe(2);
+    expect(env.config).toBe((await findConfigPda())[0]);
+    expect(env.feeVault).toBe((await findFeeVaultPda())[0]);
+    const created = [env.mint, env.ownerTokenAccount, env.ownerUsdcAccount];
+    expect(new Set(created).size).toBe(3);
+  });
+
+  it("reuses an existing config", async () => {
+    const { rpc, calls } = fakeRpc(usdcMintAuthority.address, true);
+    await bootstrapTestEnvironment(rpc, { usdcMintAuthority });
+    expect(calls.sends).toBe(1);
+  });
+
+  it("requires a USDC mint controlled by the given authority", async () => {
+    const missing = fakeRpc();
+    await expect(
+      bootstrapTestEnvironment(missing.rpc, { usdcMintAuthority })
+    ).rejects.toThrow("not found");
+
+    const other = fakeRpc((await generateKeyPairSigner()).address);
+    await expect(
+      bootstrapTestEnvironment(other.rpc, { usdcMintAuthority })
+    ).rejects.toThrow("is not the mint authority");
+    expect(other.calls.airdrops).toEqual([]);
+  });
+});
@@ -0,0 +1,361 @@
+import {
+  AccountRole,
+  fetchEncodedAccount,
+  generateKeyPairSigner,
+  getAddressDecoder,
+  getAddressEncoder,
+  getU64Encoder,
+  lamports,
+  type AccountSignerMeta,
+  type Address,
+  type GetAccountInfoApi,
+  type GetBlockHeightApi,
+  type GetLatestBlockhashApi,
+  type GetMinimumBalanceForRentExemptionApi,
+  type GetSignatureStatusesApi,
+  type Instruction,
+  type KeyPairSigner,
+  type RequestAirdropApi,
+  type Rpc,
+  type SendTransactionApi,
+  type TransactionSigner,
+} from "@solana/kit";
+import {
+  LOCKSMITH_PROGRAM_ADDRESS,
+  getInitializeConfigInstruction,
+} from "./generated";
+import { FEE_USDC, USDC_MINT } from "./constants";
+import { TOKEN_ACCOUNT_SIZE } from "./costs";
+import { findConfigPda, findFeeVaultPda } from "./pdas";
+import { sendAndConfirmLocksmithTransaction } from "./send";
+
+/**
+ * SPL Token mint account size
+ * Layout: [authority_option:u32][authority:32][supply:u64][decimals:u8]...
+ */
+export const MINT_ACCOUNT_SIZE = 82;
+
+const SYSTEM_PROGRAM_ADDRESS =
+  "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
+const TOKEN_PROGRAM_ADDRESS =
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
+
+// System program CreateAccount (u32 tag) and SPL Token instruction tags
+const CREATE_ACCOUNT_TAG = 0;
+const MINT_TO_TAG = 7;
+const INITIALIZE_ACCOUNT_3_TAG = 18;
+const INITIALIZE_MINT_2_TAG = 20;
+
+type TestEnvironmentRpc = Rpc<
+  GetAccountInfoApi &
+    GetBlockHeightApi &
+    GetLatestBlockhashApi &
+    GetMinimumBalanceForRentExemptionApi &
+    GetSignatureStatusesApi &
+    RequestAirdropApi &
+    SendTransactionApi
+>;
+
+export type LocksmithTestEnvironment = {
+  /** Config admin (fee withdrawals, admin transfer) */
+  admin: KeyPairSigner;
+  /** Funded wallet holding `mint` tokens and USDC for lock fees */
+  owner: KeyPairSigner;
+  /** Throwaway token mint; `owner` is its mint authority */
+  mint: Address;
+  ownerTokenAccount: Address;
+  ownerUsdcAccount: Address;
+  config: Address;
+  feeVault: Address;
+};
+
+export type TestEnvironmentOptions = {
+  /**
+   * Mint authority of the USDC mint loaded into the validator. The program
+   * only accepts the mainnet USDC address, so local validators must start
+   * with a USDC mint account whose authority the tests control (e.g.
+   * `solana-test-validator --account EPjF... usdc-mint.json`).
+   */
+  usdcMintAuthority: KeyPairSigner;
+  admin?: KeyPairSigner;
+  owner?: KeyPairSigner;
+  /** Lamports airdropped to admin and owner (default 10 SOL) */
+  airdropLamports?: bigint;
+  decimals?: number;
+  /** Tokens minted to the owner, in base units */
+  supply?: bigint;
+  /** USDC minted to the owner (default enough for 100 locks) */
+  usdcAmount?: bigint;
+  programAddress?: Address;
+};
+
+/**
+ * System program CreateAccount for a token program-owned account
+ * Layout: [tag:u32_le = 0][lamports:u64_le][space:u64_le][owner:32]
+ */
+function createTokenAccountInstruction(
+  payer: TransactionSigner,
+  account: TransactionSigner,
+  rent: bigint,
+  space: number
+): Instruction {
+  const data = new Uint8Array(52);
+  const view = new DataView(data.buffer);
+  view.setUint32(0, CREATE_ACCOUNT_TAG, true);
+  view.setBigUint64(4, rent, true);
+  view.setBigUint64(12, BigInt(space), true);
+  data.set(getAddressEncoder().encode(TOKEN_PROGRAM_ADDRESS), 20);
+  return {
+    programAddress: SYSTEM_PROGRAM_ADDRESS,
+    accounts: [
+      {
+        address: payer.address,
+        role: AccountRole.WRITABLE_SIGNER,
+        signer: payer,
+      },
+      {
+        address: account.address,
+        role: AccountRole.WRITABLE_SIGNER,
+        signer: account,
+      },
+    ] as AccountSignerMeta[],
+    data,
+  };
+}
+
+/**
+ * Layout: [tag:u8 = 20][decimals:u8][mint_authority:32][freeze_option:u8 = 0]
+ */
+function initializeMint2Instruction(
+  mint: Address,
+  decimals: number,
+  mintAuthority: Address
+): Instruction {
+  const data = new Uint8Array(35);
+  data[0] = INITIALIZE_MINT_2_TAG;
+  data[1] = decimals;
+  data.set(getAddressEncoder().encode(mintAuthority), 2);
+  return {
+    programAddress: TOKEN_PROGRAM_ADDRESS,
+    accounts: [{ address: mint, role: AccountRole.WRITABLE }],
+    data,
+  };
+}
+
+/**
+ * Layout: [tag:u8 = 18][owner:32]
+ */
+function initializeAccount3Instruction(
+  account: Address,
+  mint: Address,
+  owner: Address
+): Instruction {
+  const data = new Uint8Array(33);
+  data[0] = INITIALIZE_ACCOUNT_3_TAG;
+  data.set(getAddressEncoder().encode(owner), 1);
+  return {
+    programAddress: TOKEN_PROGRAM_ADDRESS,
+    accounts: [
+      { address: account, role: AccountRole.WRITABLE },
+      { address: mint, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
+
+/**
+ * Layout: [tag:u8 = 7][amount:u64_le]
+ */
+function mintToInstruction(
+  mint: Address,
+  destination: Address,
+  authority: TransactionSigner,
+  amount: bigint
+): Instruction {
+  const data = new Uint8Array(9);
+  data[0] = MINT_TO_TAG;
+  data.set(getU64Encoder().encode(amount), 1);
+  return {
+    programAddress: TOKEN_PROGRAM_ADDRESS,
+    accounts: [
+      { address: mint, role: AccountRole.WRITABLE },
+      { address: destination, role: AccountRole.WRITABLE },
+      {
+        address: authority.address,
+        role: AccountRole.READONLY_SIGNER,
+        signer: authority,
+      },
+    ] as AccountSignerMeta[],
+    data,
+  };
+}
+
+async function airdrop(
+  rpc: TestEnvironmentRpc,
+  recipient: Address,
+  amount: bigint
+) {
+  const signature = await rpc
+    .requestAirdrop(recipient, lamports(amount), { commitment: "confirmed" })
+    .send();
+  for (let i = 0; i < 60; i++) {
+    const {
+      value: [status],
+    } = await rpc.getSignatureStatuses([signature]).send();
+    if (status?.err) {
+      throw new Error(`Airdrop to ${recipient} failed`);
+    }
+    if (status && status.confirmationStatus !== "processed") {
+      return;
+    }
+    await new Promise((resolve) => setTimeout(resolve, 500));
+  }
+  throw new Error(`Airdrop to ${recipient} was not confirmed`);
+}
+
+/**
+ * Check that the validator has a USDC mint controlled by `authority`.
+ */
+async function assertUsdcMintAuthority(
+  rpc: TestEnvironmentRpc,
+  authority: Address
+) {
+  const account = await fetchEncodedAccount(rpc, USDC_MINT);
+  if (!account.exists || account.data.length < MINT_ACCOUNT_SIZE) {
+    throw new Error(
+      `USDC mint ${USDC_MINT} not found; start the validator with a USDC ` +
+        "mint account whose mint authority you control"
+    );
+  }
+  const data = new Uint8Array(account.data);
+  const hasAuthority =
+    new DataView(data.buffer, data.byteOffset).getUint32(0, true) === 1;
+  if (
+    !hasAuthority ||
+    getAddressDecoder().decode(data.slice(4, 36)) !== authority
+  ) {
+    throw new Error(`${authority} is not the mint authority of ${USDC_MINT}`);
+  }
+}
+
+/**
+ * Set up everything an end-to-end test needs on a local validator: funded
+ * admin and owner wallets, a throwaway mint with supply in the owner's
+ * token account, USDC for lock fees, and the program config.
+ *
+ * If the config already exists (a validator shared between test files),
+ * it is reused and `admin` is not its admin.
+ *
+ * @example
+ * ```typescript
+ * const env = await bootstrapTestEnvironment(rpc, { usdcMintAuthority });
+ * const ix = getInitializeLockInstruction({
+ *   owner: env.owner,
+ *   ownerTokenAccount: env.ownerTokenAccount,
+ *   ownerUsdcAccount: env.ownerUsdcAccount,
+ *   mint: env.mint,
+ *   ...
+ * });
+ * ```
+ */
+export async function bootstrapTestEnvironment(
+  rpc: TestEnvironmentRpc,
+  options: TestEnvironmentOptions
+): Promise<LocksmithTestEnvironment> {
+  const programAddress = options.programAddress ?? LOCKSMITH_PROGRAM_ADDRESS;
+  const airdropLamports = options.airdropLamports ?? 10_000_000_000n;
+  const [admin, owner, mint, ownerTokenAccount, ownerUsdcAccount] =
+    await Promise.all([
+      options.admin ?? generateKeyPairSigner(),
+      options.owner ?? generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+    ]);
+
+  await assertUsdcMintAuthority(rpc, options.usdcMintAuthority.address);
+  await Promise.all([
+    airdrop(rpc, admin.address, airdropLamports),
+    airdrop(rpc, owner.address, airdropLamports),
+  ]);
+
+  const [mintRent, tokenAccountRent] = await Promise.all([
+    rpc.getMinimumBalanceForRentExemption(BigInt(MINT_ACCOUNT_SIZE)).send(),
+    rpc.getMinimumBalanceForRentExemption(BigInt(TOKEN_ACCOUNT_SIZE)).send(),
+  ]);
+
+  await sendAndConfirmLocksmithTransaction(rpc, {
+    feePayer: owner,
+    instructions: [
+      createTokenAccountInstruction(owner, mint, mintRent, MINT_ACCOUNT_SIZE),
+      initializeMint2Instruction(
+        mint.address,
+        options.decimals ?? 9,
+        owner.address
+      ),
+      createTokenAccountInstruction(
+        owner,
+        ownerTokenAccount,
+        tokenAccountRent,
+        TOKEN_ACCOUNT_SIZE
+      ),
+      initializeAccount3Instruction(
+        ownerTokenAccount.address,
+        mint.address,
+        owner.address
+      ),
+      mintToInstruction(
+        mint.address,
+        ownerTokenAccount.address,
+        owner,
+        options.supply ?? 1_000_000_000_000_000n
+      ),
+      createTokenAccountInstruction(
+        owner,
+        ownerUsdcAccount,
+        tokenAccountRent,
+        TOKEN_ACCOUNT_SIZE
+      ),
+      initializeAccount3Instruction(
+        ownerUsdcAccount.address,
+        USDC_MINT,
+        owner.address
+      ),
+      mintToInstruction(
+        USDC_MINT,
+        ownerUsdcAccount.address,
+        options.usdcMintAuthority,
+        options.usdcAmount ?? 100n * FEE_USDC
+      ),
+    ],
+  });
+
+  const [config] = await findConfigPda(programAddress);
+  const [feeVault] = await findFeeVaultPda(programAddress);
+  const existing = await fetchEncodedAccount(rpc, config);
+  if (!existing.exists) {
+    await sendAndConfirmLocksmithTransaction(
+      rpc,
+      {
+        feePayer: admin,
+        instructions: [
+          getInitializeConfigInstruction(
+            { admin, config, usdcMint: USDC_MINT, feeVault },
+            { programAddress }
+          ),
+        ],
+      },
+      { idempotencyAccount: config }
+    );
+  }
+
+  return {
+    admin,
+    owner,
+    mint: mint.address,
+    ownerTokenAccount: ownerTokenAccount.address,
+    ownerUsdcAccount: ownerUsdcAccount.address,
+    config,
+    feeVault,
+  };
+}
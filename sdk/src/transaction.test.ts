@@ -0,0 +1,261 @@
+import { describe, it, expect } from "vitest";
+import {
+  createNoopSigner,
+  type Address,
+  type Blockhash,
+  type Nonce,
+  type Rpc,
+  type SimulateTransactionApi,
+} from "@solana/kit";
+import {
+  COMPUTE_BUDGET_PROGRAM_ADDRESS,
+  MAX_COMPUTE_UNIT_LIMIT,
+  applyComputeUnitMargin,
+  buildLocksmithTransactionMessage,
+  buildLocksmithTransactionMessageWithEstimate,
+  estimateComputeUnitLimit,
+  getSetComputeUnitLimitInstruction,
+  getSetComputeUnitPriceInstruction,
+  setComputeUnitLimitFromSimulation,
+  setTransactionMessageComputeBudget,
+} from "./transaction";
+import { getUnlockInstruction, LOCKSMITH_PROGRAM_ADDRESS } from "./generated";
+
+const TEST_ADDRESSES = {
+  owner: "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address,
+  ownerToken: "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde" as Address,
+  lockAccount: "BPFLoaderUpgradeab1e11111111111111111111111" as Address,
+  lockToken: "SysvarRent111111111111111111111111111111111" as Address,
+  lookupTable: "Vote111111111111111111111111111111111111111" as Address,
+};
+
+const latestBlockhash = {
+  blockhash: "11111111111111111111111111111111" as Blockhash,
+  lastValidBlockHeight: 100n,
+};
+
+function unlockInstruction() {
+  return getUnlockInstruction({
+    owner: createNoopSigner(TEST_ADDRESSES.owner),
+    ownerTokenAccount: TEST_ADDRESSES.ownerToken,
+    lockAccount: TEST_ADDRESSES.lockAccount,
+    lockTokenAccount: TEST_ADDRESSES.lockToken,
+    lockId: 1n,
+  });
+}
+
+function fakeRpc(
+  value: Record<string, unknown>
+): Rpc<SimulateTransactionApi> & { calls: unknown[][] } {
+  const calls: unknown[][] = [];
+  return {
+    calls,
+    simulateTransaction: (...args: unknown[]) => {
+      calls.push(args);
+      return { send: async () => ({ context: { slot: 1n }, value }) };
+    },
+  } as unknown as Rpc<SimulateTransactionApi> & { calls: unknown[][] };
+}
+
+describe("Compute budget instructions", () => {
+  it("encodes SetComputeUnitLimit as [2, u32_le]", () => {
+    const ix = getSetComputeUnitLimitInstruction(200_000);
+    expect(ix.programAddress).toBe(COMPUTE_BUDGET_PROGRAM_ADDRESS);
+    expect(Array.from(ix.data!)).toEqual([2, 0x40, 0x0d, 0x03, 0x00]);
+  });
+
+  it("encodes SetComputeUnitPrice as [3, u64_le]", () => {
+    const ix = getSetComputeUnitPriceInstruction(10_000n);
+    expect(ix.programAddress).toBe(COMPUTE_BUDGET_PROGRAM_ADDRESS);
+    expect(Array.from(ix.data!)).toEqual([3, 0x10, 0x27, 0, 0, 0, 0, 0, 0]);
+  });
+});
+
+describe("buildLocksmithTransactionMessage", () => {
+  it("omits compute budget instructions when not requested", () => {
+    const message = buildLocksmithTransactionMessage({
+      feePayer: createNoopSigner(TEST_ADDRESSES.owner),
+      instructions: [unlockInstruction()],
+      latestBlockhash,
+    });
+
+    expect(message.version).toBe(0);
+    expect(message.feePayer.address).toBe(TEST_ADDRESSES.owner);
+    expect(message.instructions).toHaveLength(1);
+    expect(message.instructions[0].programAddress).toBe(
+      LOCKSMITH_PROGRAM_ADDRESS
+    );
+  });
+
+  it("prepends limit then price before program instructions", () => {
+    const message = buildLocksmithTransactionMessage({
+      feePayer: createNoopSigner(TEST_ADDRESSES.owner),
+      instructions: [unlockInstruction()],
+      latestBlockhash,
+      computeUnitLimit: 50_000,
+      computeUnitPrice: 1n,
+    });
+
+    expect(message.instructions).toHaveLength(3);
+    expect(message.instructions[0].data![0]).toBe(2);
+    expect(message.instructions[1].data![0]).toBe(3);
+    expect(message.instructions[2].programAddress).toBe(
+      LOCKSMITH_PROGRAM_ADDRESS
+    );
+  });
+
+  it("keeps AdvanceNonceAccount first with a durable nonce", () => {
+    const message = buildLocksmithTransactionMessage({
+      feePayer: createNoopSigner(TEST_ADDRESSES.owner),
+      instructions: [unlockInstruction()],
+      durableNonce: {
+        nonce: "11111111111111111111111111111111" as Nonce,
+        nonceAccountAddress: TEST_ADDRESSES.lookupTable,
+        nonceAuthorityAddress: TEST_ADDRESSES.owner,
+      },
+      computeUnitPrice: 1n,
+    });
+
+    expect(message.instructions).toHaveLength(3);
+    expect(message.instructions[0].programAddress).toBe(
+      "11111111111111111111111111111111"
+    );
+    expect(message.instructions[1].data![0]).toBe(3);
+
+    const updated = setTransactionMessageComputeBudget(
+      { computeUnitLimit: 1_000 },
+      message
+    );
+    expect(updated.instructions[0]).toBe(message.instructions[0]);
+    expect(updated.instructions[1].data![0]).toBe(2);
+  });
+
+  it("requires a lifetime", () => {
+    expect(() =>
+      buildLocksmithTransactionMessage({
+        feePayer: createNoopSigner(TEST_ADDRESSES.owner),
+        instructions: [unlockInstruction()],
+      })
+    ).toThrow("latestBlockhash or durableNonce");
+  });
+
+  it("compresses accounts found in lookup tables", () => {
+    const message = buildLocksmithTransactionMessage({
+      feePayer: createNoopSigner(TEST_ADDRESSES.owner),
+      instructions: [unlockInstruction()],
+      latestBlockhash,
+      addressLookupTables: {
+        [TEST_ADDRESSES.lookupTable]: [TEST_ADDRESSES.lockToken],
+      },
+    });
+
+    const lockToken = message.instructions[0].accounts!.find(
+      (a) => a.address === TEST_ADDRESSES.lockToken
+    );
+    expect(lockToken).toHaveProperty(
+      "lookupTableAddress",
+      TEST_ADDRESSES.lookupTable
+    );
+  });
+});
+
+describe("Compute unit estimation", () => {
+  it("applies the margin and rounds up", () => {
+    expect(applyComputeUnitMargin(10_000n)).toBe(11_000);
+    expect(applyComputeUnitMargin(101, 50)).toBe(152);
+  });
+
+  it("clamps to the transaction maximum", () => {
+    expect(applyComputeUnitMargin(MAX_COMPUTE_UNIT_LIMIT)).toBe(
+      MAX_COMPUTE_UNIT_LIMIT
+    );
+  });
+
+  it("simulates without signature verification", async () => {
+    const rpc = fakeRpc({ err: null, unitsConsumed: 20_000n, logs: [] });
+    const units = await estimateComputeUnitLimit(rpc, {
+      feePayer: createNoopSigner(TEST_ADDRESSES.owner),
+      instructions: [unlockInstruction()],
+      latestBlockhash,
+    });
+
+    expect(units).toBe(22_000);
+    expect(rpc.calls).toHaveLength(1);
+    expect(rpc.calls[0][1]).toMatchObject({
+      encoding: "base64",
+      sigVerify: false,
+      replaceRecentBlockhash: true,
+    });
+  });
+
+  it("throws when simulation fails", async () => {
+    const rpc = fakeRpc({ err: { InstructionError: [0, { Custom: 3 }] } });
+    await expect(
+      estimateComputeUnitLimit(rpc, {
+        feePayer: createNoopSigner(TEST_ADDRESSES.owner),
+        instructions: [unlockInstruction()],
+        latestBlockhash,
+      })
+    ).rejects.toThrow("Simulation failed");
+  });
+
+  it("builds a message with the estimated limit", async () => {
+    const rpc = fakeRpc({ err: null, unitsConsumed: 30_000n, logs: [] });
+    const message = await buildLocksmithTransactionMessageWithEstimate(rpc, {
+      feePayer: createNoopSigner(TEST_ADDRESSES.owner),
+      instructions: [unlockInstruction()],
+      latestBlockhash,
+      computeUnitPrice: 5n,
+    });
+
+    const limit = message.instructions[0];
+    expect(limit.programAddress).toBe(COMPUTE_BUDGET_PROGRAM_ADDRESS);
+    expect(Array.from(limit.data!.slice(1))).toEqual([0xe8, 0x80, 0x00, 0x00]);
+  });
+});
+
+describe("Compute budget injection", () => {
+  it("replaces an existing limit and keeps the price", () => {
+    const message = buildLocksmithTransactionMessage({
+      feePayer: createNoopSigner(TEST_ADDRESSES.owner),
+      instructions: [unlockInstruction()],
+      latestBlockhash,
+      computeUnitLimit: 50_000,
+      computeUnitPrice: 7n,
+    });
+    const updated = setTransactionMessageComputeBudget(
+      { computeUnitLimit: 1_000 },
+      message
+    );
+
+    expect(updated.instructions).toHaveLength(3);
+    expect(Array.from(updated.instructions[0].data!)).toEqual([
+      2, 0xe8, 0x03, 0, 0,
+    ]);
+    expect(updated.instructions[1].data![0]).toBe(3);
+    expect(updated.instructions[2].programAddress).toBe(
+      LOCKSMITH_PROGRAM_ADDRESS
+    );
+    expect(updated.feePayer).toBe(message.feePayer);
+  });
+
+  it("prepends a simulated limit and price to any message", async () => {
+    const rpc = fakeRpc({ err: null, unitsConsumed: 10_000n, logs: [] });
+    const message = buildLocksmithTransactionMessage({
+      feePayer: createNoopSigner(TEST_ADDRESSES.owner),
+      instructions: [unlockInstruction()],
+      latestBlockhash,
+    });
+    const updated = await setComputeUnitLimitFromSimulation(rpc, message, {
+      marginPercent: 20,
+      computeUnitPrice: 1_000n,
+    });
+
+    expect(updated.instructions).toHaveLength(3);
+    // 10_000 * 1.2 = 12_000 = 0x2ee0
+    expect(Array.from(updated.instructions[0].data!)).toEqual([
+      2, 0xe0, 0x2e, 0, 0,
+    ]);
+    expect(updated.instructions[1].data![0]).toBe(3);
+  });
+});
@@ -0,0 +1,308 @@
+import {
+  appendTransactionMessageInstructions,
+  compileTransaction,
+  compressTransactionMessageUsingAddressLookupTables,
+  createTransactionMessage,
+  getBase64EncodedWireTransaction,
+  getU32Encoder,
+  getU64Encoder,
+  pipe,
+  setTransactionMessageFeePayerSigner,
+  setTransactionMessageLifetimeUsingBlockhash,
+  setTransactionMessageLifetimeUsingDurableNonce,
+  type Address,
+  type AddressesByLookupTableAddress,
+  type BaseTransactionMessage,
+  type Blockhash,
+  type CompilableTransactionMessage,
+  type Instruction,
+  type Rpc,
+  type SimulateTransactionApi,
+  type TransactionSigner,
+} from "@solana/kit";
+import type { DurableNonceConfig } from "./nonce";
+
+/**
+ * Compute Budget program address
+ */
+export const COMPUTE_BUDGET_PROGRAM_ADDRESS =
+  "ComputeBudget111111111111111111111111111111" as Address<"ComputeBudget111111111111111111111111111111">;
+
+/**
+ * Maximum compute units a single transaction may request
+ */
+export const MAX_COMPUTE_UNIT_LIMIT = 1_400_000;
+
+/**
+ * Default headroom applied on top of simulated compute units, in percent
+ */
+export const DEFAULT_COMPUTE_UNIT_MARGIN_PERCENT = 10;
+
+// Compute Budget instruction tags (matching the native program)
+const SET_COMPUTE_UNIT_LIMIT_TAG = 2;
+const SET_COMPUTE_UNIT_PRICE_TAG = 3;
+
+/**
+ * Build a SetComputeUnitLimit instruction
+ * Layout: [tag:u8 = 2][units:u32_le]
+ */
+export function getSetComputeUnitLimitInstruction(units: number): Instruction {
+  const data = new Uint8Array(5);
+  data[0] = SET_COMPUTE_UNIT_LIMIT_TAG;
+  data.set(getU32Encoder().encode(units), 1);
+  return { programAddress: COMPUTE_BUDGET_PROGRAM_ADDRESS, data };
+}
+
+/**
+ * Build a SetComputeUnitPrice instruction (priority fee)
+ * Layout: [tag:u8 = 3][micro_lamports:u64_le]
+ */
+export function getSetComputeUnitPriceInstruction(
+  microLamports: bigint | number
+): Instruction {
+  const data = new Uint8Array(9);
+  data[0] = SET_COMPUTE_UNIT_PRICE_TAG;
+  data.set(getU64Encoder().encode(BigInt(microLamports)), 1);
+  return { programAddress: COMPUTE_BUDGET_PROGRAM_ADDRESS, data };
+}
+
+export type LocksmithTransactionInput = {
+  /** Fee payer, usually the lock owner */
+  feePayer: TransactionSigner;
+  /** Locksmith (or any other) instructions, in execution order */
+  instructions: readonly Instruction[];
+  /** Blockhash lifetime from `getLatestBlockhash` */
+  latestBlockhash?: Readonly<{
+    blockhash: Blockhash;
+    lastValidBlockHeight: bigint;
+  }>;
+  /**
+   * Durable nonce lifetime (see `fetchDurableNonceConfig`), for messages
+   * that must stay valid through long approval flows. Takes precedence
+   * over `latestBlockhash`.
+   */
+  durableNonce?: DurableNonceConfig;
+  /** Compute unit limit; omitted means the runtime default */
+  computeUnitLimit?: number;
+  /** Priority fee in micro-lamports per compute unit */
+  computeUnitPrice?: bigint | number;
+  /** Lookup table contents keyed by lookup table address */
+  addressLookupTables?: AddressesByLookupTableAddress;
+};
+
+/**
+ * Assemble a v0 transaction message for Locksmith instructions.
+ *
+ * Compute-budget instructions are prepended when a limit or price is given,
+ * and addresses found in `addressLookupTables` are compressed into lookups.
+ * Exactly one of `latestBlockhash` or `durableNonce` sets the lifetime; with
+ * a durable nonce the AdvanceNonceAccount instruction comes first.
+ *
+ * @example
+ * ```typescript
+ * const message = buildLocksmithTransactionMessage({
+ *   feePayer: owner,
+ *   instructions: [getInitializeLockInstruction({ ... })],
+ *   latestBlockhash,
+ *   computeUnitPrice: 10_000n,
+ * });
+ * ```
+ */
+export function buildLocksmithTransactionMessage(
+  input: LocksmithTransactionInput
+) {
+  const budget: Instruction[] = [];
+  if (input.computeUnitLimit !== undefined) {
+    budget.push(getSetComputeUnitLimitInstruction(input.computeUnitLimit));
+  }
+  if (input.computeUnitPrice !== undefined) {
+    budget.push(getSetComputeUnitPriceInstruction(input.computeUnitPrice));
+  }
+
+  const { durableNonce, latestBlockhash } = input;
+  if (!durableNonce && !latestBlockhash) {
+    throw new Error("Either latestBlockhash or durableNonce is required");
+  }
+
+  const message = pipe(
+    createTransactionMessage({ version: 0 }),
+    (m) => setTransactionMessageFeePayerSigner(input.feePayer, m),
+    (m) =>
+      durableNonce
+        ? setTransactionMessageLifetimeUsingDurableNonce(durableNonce, m)
+        : setTransactionMessageLifetimeUsingBlockhash(latestBlockhash!, m),
+    (m) =>
+      appendTransactionMessageInstructions(
+        [...budget, ...input.instructions],
+        m
+      )
+  );
+
+  if (!input.addressLookupTables) {
+    return message;
+  }
+  return compressTransactionMessageUsingAddressLookupTables(
+    message,
+    input.addressLookupTables
+  );
+}
+
+/**
+ * Apply a relative margin to a simulated compute unit count, clamped to
+ * the per-transaction maximum.
+ */
+export function applyComputeUnitMargin(
+  unitsConsumed: bigint | number,
+  marginPercent: number = DEFAULT_COMPUTE_UNIT_MARGIN_PERCENT
+): number {
+  const scaled = BigInt(unitsConsumed) * BigInt(100 + marginPercent);
+  const units = Number((scaled + 99n) / 100n);
+  return Math.min(units, MAX_COMPUTE_UNIT_LIMIT);
+}
+
+const SYSTEM_PROGRAM_ADDRESS = "11111111111111111111111111111111";
+// System program AdvanceNonceAccount instruction (u32 little-endian tag 4)
+const ADVANCE_NONCE_ACCOUNT_TAG = [4, 0, 0, 0];
+
+function isAdvanceNonceAccountInstruction(instruction: Instruction) {
+  return (
+    instruction.programAddress === SYSTEM_PROGRAM_ADDRESS &&
+    ADVANCE_NONCE_ACCOUNT_TAG.every((byte, i) => instruction.data?.[i] === byte)
+  );
+}
+
+function isComputeBudgetInstruction(instruction: Instruction, tag: number) {
+  return (
+    instruction.programAddress === COMPUTE_BUDGET_PROGRAM_ADDRESS &&
+    instruction.data?.[0] === tag
+  );
+}
+
+/**
+ * Set the compute unit limit and/or price on an existing transaction
+ * message. Any SetComputeUnitLimit/SetComputeUnitPrice instruction being
+ * replaced is removed, and the new ones are placed first (after the
+ * AdvanceNonceAccount instruction of a durable nonce message, which must
+ * stay at index 0).
+ */
+export function setTransactionMessageComputeBudget<
+  TMessage extends BaseTransactionMessage,
+>(
+  budget: { computeUnitLimit?: number; computeUnitPrice?: bigint | number },
+  message: TMessage
+): TMessage {
+  const replacedTags: number[] = [];
+  const prepended: Instruction[] = [];
+  if (budget.computeUnitLimit !== undefined) {
+    replacedTags.push(SET_COMPUTE_UNIT_LIMIT_TAG);
+    prepended.push(getSetComputeUnitLimitInstruction(budget.computeUnitLimit));
+  }
+  if (budget.computeUnitPrice !== undefined) {
+    replacedTags.push(SET_COMPUTE_UNIT_PRICE_TAG);
+    prepended.push(getSetComputeUnitPriceInstruction(budget.computeUnitPrice));
+  }
+
+  const kept = message.instructions.filter(
+    (ix) => !replacedTags.some((tag) => isComputeBudgetInstruction(ix, tag))
+  );
+  const leading =
+    kept.length > 0 && isAdvanceNonceAccountInstruction(kept[0])
+      ? kept.splice(0, 1)
+      : [];
+  return Object.freeze({
+    ...message,
+    instructions: Object.freeze([...leading, ...prepended, ...kept]),
+  });
+}
+
+/**
+ * Estimate the compute units a transaction message needs by simulating it
+ * with the maximum limit. Signatures are not verified and the blockhash is
+ * replaced, so the message does not need to be signed.
+ */
+export async function estimateComputeUnitLimitForMessage(
+  rpc: Rpc<SimulateTransactionApi>,
+  message: CompilableTransactionMessage,
+  marginPercent: number = DEFAULT_COMPUTE_UNIT_MARGIN_PERCENT
+): Promise<number> {
+  const simulated = setTransactionMessageComputeBudget(
+    { computeUnitLimit: MAX_COMPUTE_UNIT_LIMIT },
+    message
+  );
+  const wire = getBase64EncodedWireTransaction(compileTransaction(simulated));
+  const { value } = await rpc
+    .simulateTransaction(wire, {
+      encoding: "base64",
+      sigVerify: false,
+      replaceRecentBlockhash: true,
+    })
+    .send();
+
+  if (value.err) {
+    throw new Error(
+      `Simulation failed: ${JSON.stringify(value.err, (_, v) =>
+        typeof v === "bigint" ? v.toString() : v
+      )}`
+    );
+  }
+  if (value.unitsConsumed === undefined) {
+    throw new Error("Simulation did not report units consumed");
+  }
+  return applyComputeUnitMargin(value.unitsConsumed, marginPercent);
+}
+
+/**
+ * Simulate an existing transaction message and prepend a compute unit
+ * limit sized from the result (plus margin), optionally with a priority
+ * fee. Use this for messages not built with `buildLocksmithTransactionMessage`.
+ */
+export async function setComputeUnitLimitFromSimulation<
+  TMessage extends CompilableTransactionMessage,
+>(
+  rpc: Rpc<SimulateTransactionApi>,
+  message: TMessage,
+  options: { marginPercent?: number; computeUnitPrice?: bigint | number } = {}
+): Promise<TMessage> {
+  const computeUnitLimit = await estimateComputeUnitLimitForMessage(
+    rpc,
+    message,
+    options.marginPercent
+  );
+  return setTransactionMessageComputeBudget(
+    { computeUnitLimit, computeUnitPrice: options.computeUnitPrice },
+    message
+  );
+}
+
+/**
+ * Estimate the compute units needed for a Locksmith transaction by
+ * simulating it with the maximum limit.
+ */
+export async function estimateComputeUnitLimit(
+  rpc: Rpc<SimulateTransactionApi>,
+  input: Omit<LocksmithTransactionInput, "computeUnitLimit">,
+  marginPercent: number = DEFAULT_COMPUTE_UNIT_MARGIN_PERCENT
+): Promise<number> {
+  return estimateComputeUnitLimitForMessage(
+    rpc,
+    buildLocksmithTransactionMessage(input),
+    marginPercent
+  );
+}
+
+/**
+ * Build a transaction message whose compute unit limit is sized from a
+ * simulation of the same instructions.
+ */
+export async function buildLocksmithTransactionMessageWithEstimate(
+  rpc: Rpc<SimulateTransactionApi>,
+  input: Omit<LocksmithTransactionInput, "computeUnitLimit">,
+  marginPercent: number = DEFAULT_COMPUTE_UNIT_MARGIN_PERCENT
+) {
+  const computeUnitLimit = await estimateComputeUnitLimit(
+    rpc,
+    input,
+    marginPercent
+  );
+  return buildLocksmithTransactionMessage({ ...input, computeUnitLimit });
+}
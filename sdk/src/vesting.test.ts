@@ -0,0 +1,49 @@
+import { describe, it, expect } from "vitest";
+import type { Address } from "@solana/kit";
+import { getClaimableAmount, getVestedAmount } from "./vesting";
+import { VESTING_DISCRIMINATOR } from "./constants";
+import type { VestingLockAccount } from "./generated";
+
+function vesting(claimed = 0n, cliff = 1_000n): VestingLockAccount {
+  return {
+    discriminator: VESTING_DISCRIMINATOR,
+    owner: "7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV" as Address,
+    mint: "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" as Address,
+    total: 1_000n,
+    claimed,
+    start: 1_000n,
+    cliff,
+    end: 2_000n,
+    createdAt: 1_000n,
+    vestingId: 1n,
+    bump: 255,
+    grantor: "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde" as Address,
+    revocable: false,
+  };
+}
+
+describe("getVestedAmount", () => {
+  it("vests linearly between start and end, rounding down", () => {
+    expect(getVestedAmount(vesting(), 0n)).toBe(0n);
+    expect(getVestedAmount(vesting(), 1_000n)).toBe(0n);
+    expect(getVestedAmount(vesting(), 1_001n)).toBe(1n);
+    expect(getVestedAmount(vesting(), 1_500n)).toBe(500n);
+    expect(getVestedAmount(vesting(), 1_999n)).toBe(999n);
+    expect(getVestedAmount(vesting(), 2_000n)).toBe(1_000n);
+    expect(getVestedAmount(vesting(), 5_000n)).toBe(1_000n);
+  });
+
+  it("vests nothing before the cliff and the accrued portion at it", () => {
+    expect(getVestedAmount(vesting(0n, 1_250n), 1_249n)).toBe(0n);
+    expect(getVestedAmount(vesting(0n, 1_250n), 1_250n)).toBe(250n);
+    expect(getVestedAmount(vesting(0n, 2_000n), 1_999n)).toBe(0n);
+    expect(getVestedAmount(vesting(0n, 2_000n), 2_000n)).toBe(1_000n);
+  });
+});
+
+describe("getClaimableAmount", () => {
+  it("subtracts what has already been claimed", () => {
+    expect(getClaimableAmount(vesting(400n), 1_500n)).toBe(100n);
+    expect(getClaimableAmount(vesting(1_000n), 2_000n)).toBe(0n);
+  });
+});
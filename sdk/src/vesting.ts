@@ -0,0 +1,31 @@
+import type { VestingLockAccount } from "./generated";
+
+/**
+ * Amount of a vesting lock vested by `now` (unix seconds), rounding down
+ * like the program. Vesting is linear from `start` to `end`, but nothing
+ * vests before `cliff`.
+ */
+export function getVestedAmount(
+  vesting: VestingLockAccount,
+  now: bigint
+): bigint {
+  if (now < vesting.cliff || now <= vesting.start) {
+    return 0n;
+  }
+  if (now >= vesting.end) {
+    return vesting.total;
+  }
+  return (
+    (vesting.total * (now - vesting.start)) / (vesting.end - vesting.start)
+  );
+}
+
+/**
+ * Amount `ClaimVested` would pay out at `now` (unix seconds)
+ */
+export function getClaimableAmount(
+  vesting: VestingLockAccount,
+  now: bigint
+): bigint {
+  return getVestedAmount(vesting, now) - vesting.claimed;
+}
@@ -0,0 +1,66 @@
+import { describe, it, expect } from "vitest";
+import { getVotingWeight } from "./votingWeight";
+import {
+  MAX_LOCK_DURATION_EPOCHS,
+  MAX_LOCK_DURATION_SECONDS,
+  MAX_LOCK_DURATION_SLOTS,
+} from "./constants";
+import type { LockAccount } from "./generated";
+
+describe("getVotingWeight", () => {
+  const now = 1_700_000_000n;
+  const lock = {
+    amount: 1_000_000n,
+    unlockTimestamp: now + MAX_LOCK_DURATION_SECONDS,
+    unlockSlot: 0n,
+    unlockEpoch: 0n,
+    permanent: false,
+  } as LockAccount;
+  const clock = { slot: 1_000n, epoch: 10n, epochStartTimestamp: now };
+
+  it("decays linearly to 0 at the unlock time", () => {
+    const halfway = now + MAX_LOCK_DURATION_SECONDS / 2n;
+
+    expect(getVotingWeight(lock, now)).toBe(1_000_000n);
+    expect(getVotingWeight(lock, halfway)).toBe(500_000n);
+    expect(getVotingWeight(lock, lock.unlockTimestamp - 1n)).toBe(0n);
+    expect(getVotingWeight(lock, lock.unlockTimestamp)).toBe(0n);
+    expect(getVotingWeight(lock, lock.unlockTimestamp + 1n)).toBe(0n);
+  });
+
+  it("weighs slot- and epoch-based locks by their slot or epoch", () => {
+    const slotLock = {
+      ...lock,
+      unlockSlot: clock.slot + MAX_LOCK_DURATION_SLOTS / 2n,
+    };
+    expect(getVotingWeight(slotLock, now, clock)).toBe(500_000n);
+    // The estimated unlockTimestamp cannot inflate the weight
+    expect(
+      getVotingWeight({ ...lock, unlockSlot: clock.slot + 1n }, now, clock)
+    ).toBe(0n);
+    expect(
+      getVotingWeight(slotLock, now, { ...clock, slot: slotLock.unlockSlot })
+    ).toBe(0n);
+
+    const epochLock = {
+      ...lock,
+      unlockEpoch: clock.epoch + MAX_LOCK_DURATION_EPOCHS,
+    };
+    expect(getVotingWeight(epochLock, now, clock)).toBe(1_000_000n);
+    expect(
+      getVotingWeight(epochLock, now, {
+        ...clock,
+        epoch: epochLock.unlockEpoch,
+      })
+    ).toBe(0n);
+
+    expect(() => getVotingWeight(slotLock, now)).toThrow();
+  });
+
+  it("caps the weight at the amount", () => {
+    expect(getVotingWeight(lock, 0n)).toBe(1_000_000n);
+    expect(getVotingWeight({ ...lock, permanent: true }, now * 2n)).toBe(
+      1_000_000n
+    );
+  });
+});
@@ -0,0 +1,63 @@
+import {
+  MAX_LOCK_DURATION_SECONDS,
+  SLOTS_PER_EPOCH,
+  TARGET_MS_PER_SLOT,
+} from "./constants";
+import type { LockAccount } from "./generated";
+
+/**
+ * The parts of the Clock sysvar that slot- and epoch-based locks are
+ * weighted by
+ */
+export type VotingClock = {
+  slot: bigint;
+  epoch: bigint;
+  epochStartTimestamp: bigint;
+};
+
+/**
+ * Vote-escrow weight of a lock at `now` (unix seconds), rounding down like
+ * `SnapshotWeight`: the amount scaled by the time left until it unlocks over
+ * `MAX_LOCK_DURATION_SECONDS`, decaying linearly to 0. Permanent locks keep
+ * the full amount. Slot- and epoch-based locks need the current `clock`:
+ * their slot or epoch is converted at `TARGET_MS_PER_SLOT`, since
+ * `unlockTimestamp` is only the creator's estimate.
+ */
+export function getVotingWeight(
+  lock: LockAccount,
+  now: bigint,
+  clock?: VotingClock
+): bigint {
+  if (lock.permanent) {
+    return lock.amount;
+  }
+  let remaining: bigint;
+  if (lock.unlockSlot !== 0n || lock.unlockEpoch !== 0n) {
+    if (!clock) {
+      throw new Error("Slot- and epoch-based locks need the current clock");
+    }
+    if (lock.unlockSlot !== 0n) {
+      remaining =
+        ((lock.unlockSlot - clock.slot) * TARGET_MS_PER_SLOT) / 1000n;
+    } else if (clock.epoch >= lock.unlockEpoch) {
+      return 0n;
+    } else {
+      const epochs = lock.unlockEpoch - clock.epoch;
+      let intoEpoch = now - clock.epochStartTimestamp;
+      if (intoEpoch < 0n) {
+        intoEpoch = 0n;
+      }
+      remaining =
+        (epochs * SLOTS_PER_EPOCH * TARGET_MS_PER_SLOT) / 1000n - intoEpoch;
+    }
+  } else {
+    remaining = lock.unlockTimestamp - now;
+  }
+  if (remaining <= 0n) {
+    return 0n;
+  }
+  if (remaining > MAX_LOCK_DURATION_SECONDS) {
+    remaining = MAX_LOCK_DURATION_SECONDS;
+  }
+  return (lock.amount * remaining) / MAX_LOCK_DURATION_SECONDS;
+}
@@ -0,0 +1,250 @@
+//! `cargo xtask localnet`: a `solana-test-validator` ready for integration
+//! tests and frontend work.
+//!
+//! The validator starts with the program deployed from
+//! `target/deploy/locksmith.so` and a USDC mint at the mainnet address whose
+//! mint authority is a local keypair, since the program only accepts the
+//! mainnet USDC mint. `sdk/scripts/localnet.ts` then funds an admin and an
+//! owner, initializes the config and writes `.localnet/manifest.json`.
+//!
+//! Keypairs in `.localnet/` are kept between runs so addresses in the
+//! manifest stay stable; the ledger is reset every time.
+
+use std::fs;
+use std::process::Command;
+
+use crate::{run, workspace_root};
+
+const LOCALNET_DIR: &str = ".localnet";
+const RPC_URL: &str = "http://127.0.0.1:8899";
+const PROGRAM_SO: &str = "target/deploy/locksmith.so";
+
+const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+const USDC_DECIMALS: u8 = 6;
+const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+/// SPL Token mint account size and its rent-exempt minimum.
+const MINT_ACCOUNT_SIZE: usize = 82;
+const MINT_RENT_LAMPORTS: u64 = 1_461_600;
+
+/// Keypairs generated on first run, as `.localnet/<name>.json`.
+const KEYPAIRS: &[&str] = &["usdc-mint-authority", "admin", "owner"];
+
+pub fn localnet(args: &[String]) -> Result<(), String> {
+    if !args.iter().any(|a| a == "--skip-build") {
+        run(
+            "cargo",
+            &[
+                "build-sbf",
+                "--manifest-path",
+                "programs/locksmith/Cargo.toml",
+            ],
+        )?;
+    }
+    let root = workspace_root();
+    if !root.join(PROGRAM_SO).exists() {
+        return Err(format!("{PROGRAM_SO} not found; run without --skip-build"));
+    }
+
+    fs::create_dir_all(root.join(LOCALNET_DIR))
+        .map_err(|e| format!("failed to create {LOCALNET_DIR}: {e}"))?;
+    for name in KEYPAIRS {
+        let path = format!("{LOCALNET_DIR}/{name}.json");
+        if !root.join(&path).exists() {
+            run(
+                "solana-keygen",
+                &[
+                    "new",
+                    "--no-bip39-passphrase",
+                    "--silent",
+                    "--outfile",
+                    &path,
+                ],
+            )?;
+        }
+    }
+
+    let authority = keypair_pubkey(&format!("{LOCALNET_DIR}/usdc-mint-authority.json"))?;
+    let authority: [u8; 32] = decode_base58(&authority)?
+        .try_into()
+        .map_err(|_| format!("`{authority}` is not a 32-byte public key"))?;
+    let usdc_account = format!("{LOCALNET_DIR}/usdc-mint.json");
+    fs::write(root.join(&usdc_account), usdc_mint_account_json(&authority))
+        .map_err(|e| format!("failed to write {usdc_account}: {e}"))?;
+
+    let program_id = program_id()?;
+    let ledger = format!("{LOCALNET_DIR}/ledger");
+    println!("$ solana-test-validator (program {program_id}, ledger {ledger})");
+    let mut validator = Command::new("solana-test-validator")
+        .args(["--reset", "--quiet", "--ledger", &ledger])
+        .args(["--bpf-program", &program_id, PROGRAM_SO])
+        .args(["--account", USDC_MINT, &usdc_account])
+        .current_dir(&root)
+        .spawn()
+        .map_err(|e| format!("failed to spawn `solana-test-validator`: {e}"))?;
+
+    if let Err(e) = run(
+        "npx",
+        &["tsx", "sdk/scripts/localnet.ts", RPC_URL, LOCALNET_DIR],
+    ) {
+        let _ = validator.kill();
+        return Err(e);
+    }
+
+    println!("Localnet running at {RPC_URL}; addresses in {LOCALNET_DIR}/manifest.json");
+    println!("Press Ctrl-C to stop.");
+    let status = validator
+        .wait()
+        .map_err(|e| format!("failed to wait for `solana-test-validator`: {e}"))?;
+    if !status.success() {
+        return Err(format!("`solana-test-validator` exited with {status}"));
+    }
+    Ok(())
+}
+
+fn keypair_pubkey(path: &str) -> Result<String, String> {
+    let output = Command::new("solana-keygen")
+        .args(["pubkey", path])
+        .current_dir(workspace_root())
+        .output()
+        .map_err(|e| format!("failed to spawn `solana-keygen`: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "`solana-keygen pubkey {path}` exited with {}",
+            output.status
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Reads the program id from `declare_id!` in the program crate.
+fn program_id() -> Result<String, String> {
+    let lib = workspace_root().join("programs/locksmith/src/lib.rs");
+    let source = fs::read_to_string(&lib).map_err(|e| format!("failed to read lib.rs: {e}"))?;
+    source
+        .lines()
+        .find_map(|line| {
+            let rest = &line[line.find("declare_id!(\"")? + "declare_id!(\"".len()..];
+            Some(rest[..rest.find('"')?].to_string())
+        })
+        .ok_or_else(|| "declare_id! not found in programs/locksmith/src/lib.rs".to_string())
+}
+
+/// Packs an initialized SPL Token mint with `authority` as mint authority,
+/// no supply and no freeze authority.
+fn mint_account_data(authority: &[u8; 32], decimals: u8) -> [u8; MINT_ACCOUNT_SIZE] {
+    let mut data = [0u8; MINT_ACCOUNT_SIZE];
+    // [0..4] mint_authority: COption::Some
+    data[0] = 1;
+    data[4..36].copy_from_slice(authority);
+    // [36..44] supply = 0
+    data[44] = decimals;
+    // [45] is_initialized
+    data[45] = 1;
+    // [46..82] freeze_authority: COption::None
+    data
+}
+
+/// Account file in the format `solana-test-validator --account` expects.
+fn usdc_mint_account_json(authority: &[u8; 32]) -> String {
+    let data = encode_base64(&mint_account_data(authority, USDC_DECIMALS));
+    format!(
+        r#"{{
+  "pubkey": "{USDC_MINT}",
+  "account": {{
+    "lamports": {MINT_RENT_LAMPORTS},
+    "data": ["{data}", "base64"],
+    "owner": "{TOKEN_PROGRAM_ID}",
+    "executable": false,
+    "rentEpoch": 0,
+    "space": {MINT_ACCOUNT_SIZE}
+  }}
+}}
+"#
+    )
+}
+
+fn decode_base58(s: &str) -> Result<Vec<u8>, String> {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    // Little-endian base-256 digits
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in s.bytes() {
+        let mut carry = ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or_else(|| format!("invalid base58 character `{}`", c as char))?
+            as u32;
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    bytes.extend(s.bytes().take_while(|&c| c == b'1').map(|_| 0));
+    bytes.reverse();
+    Ok(bytes)
+}
+
+fn encode_base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 63] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_base58() {
+        assert_eq!(
+            decode_base58("11111111111111111111111111111111").unwrap(),
+            [0u8; 32]
+        );
+
+        let token_program = decode_base58(TOKEN_PROGRAM_ID).unwrap();
+        assert_eq!(token_program.len(), 32);
+        assert_eq!(&token_program[..4], &[0x06, 0xdd, 0xf6, 0xe1]);
+        assert_eq!(&token_program[28..], &[0x7e, 0xff, 0x00, 0xa9]);
+
+        assert!(decode_base58("0OIl").is_err());
+    }
+
+    #[test]
+    fn test_encode_base64() {
+        assert_eq!(encode_base64(b"Man"), "TWFu");
+        assert_eq!(encode_base64(b"Ma"), "TWE=");
+        assert_eq!(encode_base64(b"M"), "TQ==");
+        assert_eq!(
+            encode_base64(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]),
+            "AAECAwQFBgcICQ=="
+        );
+    }
+
+    #[test]
+    fn test_mint_account_data() {
+        let data = mint_account_data(&[7u8; 32], 6);
+        assert_eq!(&data[0..4], &[1, 0, 0, 0]);
+        assert_eq!(&data[4..36], &[7u8; 32]);
+        assert_eq!(data[44], 6);
+        assert_eq!(data[45], 1);
+        assert!(data[46..].iter().all(|&b| b == 0));
+    }
+}
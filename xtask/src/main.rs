@@ -0,0 +1,119 @@
+//! Workspace automation, invoked as `cargo xtask <command>`.
+//!
+//! The IDL pipeline is:
+//!
+//! 1. `shank idl` extracts `idl/locksmith.json` from the `ShankInstruction`,
+//!    `ShankAccount` and `ShankType` annotations in `programs/locksmith`.
+//! 2. Codama lifts the Shank IDL into a Codama root node, which is written to
+//!    `idl/codama.json` so any Codama renderer (JS, Rust, Python, ...) can
+//!    generate a client from it.
+//! 3. The Codama JS renderer regenerates `sdk/src/generated`.
+//!
+//! `localnet` starts a local validator for integration tests; see
+//! [`localnet`].
+
+mod localnet;
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+
+const USAGE: &str = "\
+Usage: cargo xtask <command>
+
+Commands:
+  idl        Extract the Shank IDL into idl/locksmith.json
+  codama     Convert the Shank IDL into a Codama root node (idl/codama.json)
+  sdk        Regenerate the TypeScript SDK in sdk/src/generated
+  generate   Run idl, codama and sdk in order
+  check      Regenerate everything and fail if the committed output is stale
+  localnet   Start solana-test-validator with the program deployed and config
+             initialized, writing .localnet/manifest.json (--skip-build to
+             reuse target/deploy/locksmith.so)
+";
+
+/// Paths produced by the pipeline, relative to the workspace root.
+const GENERATED_PATHS: &[&str] = &["idl", "sdk/src/generated"];
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let command = args.get(1).map(String::as_str);
+    let result = match command {
+        Some("idl") => idl(),
+        Some("codama") => codama(),
+        Some("sdk") => sdk(),
+        Some("generate") => generate(),
+        Some("check") => check(),
+        Some("localnet") => localnet::localnet(&args[2..]),
+        Some("help") | Some("--help") | Some("-h") => {
+            print!("{USAGE}");
+            Ok(())
+        }
+        _ => {
+            eprint!("{USAGE}");
+            exit(2);
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        exit(1);
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives one level below the workspace root")
+        .to_path_buf()
+}
+
+fn run(program: &str, args: &[&str]) -> Result<(), String> {
+    println!("$ {} {}", program, args.join(" "));
+    let status = Command::new(program)
+        .args(args)
+        .current_dir(workspace_root())
+        .status()
+        .map_err(|e| format!("failed to spawn `{program}`: {e}"))?;
+    if !status.success() {
+        return Err(format!("`{program}` exited with {status}"));
+    }
+    Ok(())
+}
+
+fn idl() -> Result<(), String> {
+    run(
+        "shank",
+        &[
+            "idl",
+            "--crate-root",
+            "programs/locksmith",
+            "--out-dir",
+            "idl",
+        ],
+    )
+}
+
+fn codama() -> Result<(), String> {
+    run("npx", &["tsx", "scripts/export-codama.ts"])
+}
+
+fn sdk() -> Result<(), String> {
+    run("npx", &["tsx", "scripts/generate-sdk.ts"])
+}
+
+fn generate() -> Result<(), String> {
+    idl()?;
+    codama()?;
+    sdk()
+}
+
+fn check() -> Result<(), String> {
+    generate()?;
+    let mut args = vec!["diff", "--exit-code", "--stat", "--"];
+    args.extend_from_slice(GENERATED_PATHS);
+    run("git", &args).map_err(|_| {
+        "generated IDL/SDK is out of date; run `cargo xtask generate` and commit the result"
+            .to_string()
+    })
+}